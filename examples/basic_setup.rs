@@ -1,9 +1,8 @@
 //! Example showing setup code using a profile without any additional configuration.
 
-use std::ffi::c_char;
-
 use ash::vk;
-use vk_profiles_rs::{profiles, vp, VulkanProfiles};
+use vk_profiles_rs::builder::{ProfileDeviceBuilder, ProfileInstanceBuilder};
+use vk_profiles_rs::{profiles, vp, ProfilesResult, VulkanProfiles};
 
 fn main() {
     let profile = profiles::LunargDesktopBaseline2024::profile_properties();
@@ -42,7 +41,7 @@ fn create_instance(
     entry: &ash::Entry,
     vk_profiles: &VulkanProfiles,
     profile_properties: &vp::ProfileProperties,
-) -> Result<ash::Instance, vk::Result> {
+) -> ProfilesResult<ash::Instance> {
     if !unsafe { vk_profiles.get_instance_profile_support(None, &profile_properties)? } {
         panic!(
             "Profile {:?} is not supported for instance creation.",
@@ -51,7 +50,6 @@ fn create_instance(
     }
 
     // LunargDesktopBaseline2024 has VK_KHR_swapchain as a device extension, which requires VK_KHR_SURFACE
-    let extensions: Vec<*const c_char> = vec![ash::khr::surface::NAME.as_ptr()];
     // check if the extension is available
     let available = unsafe { entry.enumerate_instance_extension_properties(None) }
         .expect("Failed to enumerate instance extensions");
@@ -66,16 +64,13 @@ fn create_instance(
         );
     }
 
-    let instance_info = vk::InstanceCreateInfo::default().enabled_extension_names(&extensions);
-    let vp_instance_info = vp::InstanceCreateInfo {
-        p_enabled_full_profiles: profile_properties,
-        enabled_full_profile_count: 1,
-        p_create_info: &instance_info,
-        ..Default::default()
-    };
-
     // vulkan_profiles will activate both manual and profile extensions
-    unsafe { vk_profiles.create_instance(entry, &vp_instance_info, None) }
+    unsafe {
+        ProfileInstanceBuilder::new(vk_profiles)
+            .enabled_extension_names([ash::khr::surface::NAME])
+            .enabled_full_profile(*profile_properties)
+            .build(entry, None)
+    }
 }
 
 /// Creates a device for the specified profile and creates a single queue supporting graphics operations.
@@ -95,49 +90,15 @@ fn create_device(
                 &profile_properties,
             )?
         } {
-            // Find the graphics queue
-            let queues =
-                unsafe { instance.get_physical_device_queue_family_properties(physical_device) };
-            let mut queue_family_index = None;
-            for (queue_index, queue) in queues.iter().enumerate() {
-                if queue.queue_flags.contains(vk::QueueFlags::GRAPHICS) {
-                    queue_family_index = Some(queue_index as u32);
-                    break;
-                }
-            }
-            if queue_family_index.is_none() {
-                continue;
-            }
-            let queue_family_index = queue_family_index.unwrap();
-
-            let queue_priorities: [f32; 1] = [1.0];
-            let queue_info = vk::DeviceQueueCreateInfo {
-                queue_family_index: 0,
-                p_queue_priorities: queue_priorities.as_ptr(),
-                queue_count: queue_priorities.len() as u32,
-                ..Default::default()
+            // The builder selects the first queue family supporting graphics operations
+            let (device, queues) = unsafe {
+                ProfileDeviceBuilder::new(vk_profiles)
+                    .queues_with_flags(vk::QueueFlags::GRAPHICS, &[1.0])
+                    .enabled_full_profile(*profile_properties)
+                    .build(instance, physical_device, None)?
             };
 
-            let device_info = vk::DeviceCreateInfo {
-                p_queue_create_infos: &queue_info,
-                queue_create_info_count: 1,
-                ..Default::default()
-            };
-
-            let vp_device_info = vp::DeviceCreateInfo {
-                p_create_info: &device_info,
-                p_enabled_full_profiles: profile_properties,
-                enabled_full_profile_count: 1,
-                ..Default::default()
-            };
-
-            let device = unsafe {
-                vk_profiles.create_device(instance, physical_device, &vp_device_info, None)?
-            };
-
-            let queue = unsafe { device.get_device_queue(queue_family_index, 0) };
-
-            return Ok((device, queue_family_index, queue));
+            return Ok((device, queues[0].family_index, queues[0].queue));
         }
     }
 
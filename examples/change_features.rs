@@ -5,7 +5,8 @@ extern crate vk_profiles_rs;
 use std::ops::BitOr;
 
 use ash::vk;
-use vk_profiles_rs::{profiles, vp, VulkanProfiles};
+use vk_profiles_rs::builder::{ProfileDeviceBuilder, ProfileInstanceBuilder};
+use vk_profiles_rs::{profiles, vp, ProfilesResult, VulkanProfiles};
 
 fn main() {
     // use vulkan 1.2
@@ -76,7 +77,7 @@ fn create_instance(
     entry: &ash::Entry,
     vk_profiles: &VulkanProfiles,
     profile_properties: &vp::ProfileProperties,
-) -> Result<ash::Instance, vk::Result> {
+) -> ProfilesResult<ash::Instance> {
     if !unsafe { vk_profiles.get_instance_profile_support(None, profile_properties)? } {
         panic!(
             "Profile {:?} is not supported for instance creation.",
@@ -84,15 +85,11 @@ fn create_instance(
         );
     }
 
-    let instance_info = vk::InstanceCreateInfo::default();
-    let vp_instance_info = vp::InstanceCreateInfo {
-        p_create_info: &instance_info,
-        p_enabled_full_profiles: profile_properties,
-        enabled_full_profile_count: 1,
-        ..Default::default()
-    };
-
-    unsafe { vk_profiles.create_instance(entry, &vp_instance_info, None) }
+    unsafe {
+        ProfileInstanceBuilder::new(vk_profiles)
+            .enabled_full_profile(*profile_properties)
+            .build(entry, None)
+    }
 }
 
 /// Creates a device for the specified profile and creates a single queue supporting graphics operations.
@@ -114,67 +111,43 @@ fn create_device(
                 profile_properties,
             )?
         } {
-            // Need to make sure the device supports buffer_device_address
-            let mut features12 = vk::PhysicalDeviceVulkan12Features::default();
-            let mut features = vk::PhysicalDeviceFeatures2::default().push_next(&mut features12);
-
-            unsafe { instance.get_physical_device_features2(physical_device, &mut features) };
-
-            if features12.buffer_device_address == vk::FALSE {
+            // The builder selects the first queue family supporting graphics operations.
+            // buffer_device_address is optional, it is only enabled if the device supports it and
+            // gets merged together with the features required by the profiles
+            let builder = ProfileDeviceBuilder::new(vk_profiles)
+                .queues_with_flags(vk::QueueFlags::GRAPHICS, &[1.0])
+                .push_optional_next(
+                    vk::PhysicalDeviceVulkan12Features::default().buffer_device_address(true),
+                )
+                .enabled_full_profile(*profile_properties);
+
+            let (builder, optional) =
+                unsafe { builder.with_supported_optional(instance, physical_device)? };
+            if !optional
+                .enabled
+                .is_enabled("VkPhysicalDeviceVulkan12Features", "bufferDeviceAddress")
+            {
                 // Device does not support buffer_device_address
                 continue;
             }
 
-            // Find the graphics queue
-            let queues =
-                unsafe { instance.get_physical_device_queue_family_properties(physical_device) };
-            let mut queue_family_index = None;
-            for (queue_index, queue) in queues.iter().enumerate() {
-                if queue.queue_flags.contains(vk::QueueFlags::GRAPHICS) {
-                    queue_family_index = Some(queue_index as u32);
-                    break;
-                }
-            }
-            if queue_family_index.is_none() {
-                continue;
-            }
-            let queue_family_index = queue_family_index.unwrap();
-
-            let queue_priorities = [1f32];
-            let queue_info = vk::DeviceQueueCreateInfo {
-                queue_family_index: 0,
-                p_queue_priorities: queue_priorities.as_ptr(),
-                queue_count: queue_priorities.len() as u32,
-                ..Default::default()
-            };
-
-            // enable the additional features you need
-            // these will get merged together with the features required by the profiles
-            let mut to_enable_features = vk::PhysicalDeviceVulkan12Features {
-                buffer_device_address: vk::TRUE,
-                ..Default::default()
-            };
-            let device_info = vk::DeviceCreateInfo {
-                p_queue_create_infos: &queue_info,
-                queue_create_info_count: 1,
-                ..Default::default()
+            // the result of the merge can be inspected before the device is created
+            if cfg!(debug_assertions) {
+                let enabled = unsafe { builder.preview_features() }?;
+                assert!(
+                    enabled.is_enabled("VkPhysicalDeviceVulkan12Features", "bufferDeviceAddress")
+                );
+                println!("Enabled extensions and features:\n{enabled}");
             }
-            .push_next(&mut to_enable_features);
 
-            let vp_device_info = vp::DeviceCreateInfo {
-                p_create_info: &device_info,
-                p_enabled_full_profiles: profile_properties,
-                enabled_full_profile_count: 1,
-                ..Default::default()
-            };
+            let (device, queues) = unsafe { builder.build(instance, physical_device, None)? };
 
-            let device = unsafe {
-                vk_profiles.create_device(instance, physical_device, &vp_device_info, None)?
-            };
-
-            let queue = unsafe { device.get_device_queue(queue_family_index, 0) };
-
-            return Ok((physical_device, device, queue_family_index, queue));
+            return Ok((
+                physical_device,
+                device,
+                queues[0].family_index,
+                queues[0].queue,
+            ));
         }
     }
 
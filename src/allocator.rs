@@ -0,0 +1,363 @@
+//! Vulkan allocation callbacks backed by a rust allocator.
+//!
+//! The [`TrackingAllocator`] forwards all host allocations requested by the vulkan implementation (and the
+//! vulkan profiles library) to a [`GlobalAlloc`] and records statistics about them. This can be used to
+//! inspect the host memory usage of profile driven instance and device creation.
+//!
+//! ```no_run
+//! use vk_profiles_rs::allocator::TrackingAllocator;
+//! use vk_profiles_rs::builder::ProfileInstanceBuilder;
+//! use vk_profiles_rs::{profiles, VulkanProfiles};
+//!
+//! # fn main() -> vk_profiles_rs::ProfilesResult<()> {
+//! let entry = ash::Entry::linked();
+//! let allocator = TrackingAllocator::new();
+//! let callbacks = allocator.callbacks();
+//!
+//! let instance = unsafe {
+//!     ProfileInstanceBuilder::new(&VulkanProfiles::linked())
+//!         .enabled_full_profile(profiles::KhrRoadmap2022::profile_properties())
+//!         .build(&entry, Some(&callbacks))?
+//! };
+//! println!("{:?}", allocator.stats());
+//!
+//! unsafe { instance.destroy_instance(Some(&callbacks)) };
+//! # Ok(())
+//! # }
+//! ```
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::ffi::c_void;
+use std::mem::size_of;
+use std::ptr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use ash::vk;
+
+/// Number of [`vk::SystemAllocationScope`] values defined by the core specification.
+const SCOPE_COUNT: usize = 5;
+
+/// Header stored directly in front of every allocation handed out to vulkan.
+///
+/// Vulkan does not pass the size or alignment of an allocation to the free callback so we need to
+/// store them ourselves.
+#[derive(Copy, Clone)]
+struct AllocationHeader {
+    size: usize,
+    alignment: usize,
+    scope: vk::SystemAllocationScope,
+}
+
+impl AllocationHeader {
+    /// Returns the offset of the user data from the start of the underlying allocation.
+    fn offset(alignment: usize) -> usize {
+        size_of::<AllocationHeader>().next_multiple_of(alignment)
+    }
+
+    fn layout(&self) -> Option<Layout> {
+        let alignment = self.alignment.max(std::mem::align_of::<AllocationHeader>());
+        Layout::from_size_align(Self::offset(alignment) + self.size, alignment).ok()
+    }
+}
+
+/// A snapshot of the statistics recorded by a [`TrackingAllocator`].
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq)]
+pub struct AllocationStats {
+    /// The total number of successful allocations.
+    pub allocations: usize,
+    /// The total number of successful reallocations.
+    pub reallocations: usize,
+    /// The total number of frees.
+    pub frees: usize,
+    /// The total number of allocations which could not be served.
+    pub failed_allocations: usize,
+    /// The number of allocations which have not been freed yet.
+    pub live_allocations: usize,
+    /// The number of bytes which have not been freed yet.
+    pub live_bytes: usize,
+    /// The highest value of `live_bytes` observed so far.
+    pub peak_bytes: usize,
+    /// The number of live bytes for each [`vk::SystemAllocationScope`] indexed by the raw scope value.
+    pub live_bytes_per_scope: [usize; SCOPE_COUNT],
+    /// The number of bytes the implementation reported as internally allocated.
+    pub internal_bytes: usize,
+}
+
+/// Provides [`vk::AllocationCallbacks`] which forward all allocations to a rust allocator and record
+/// allocation statistics.
+///
+/// All statistics are updated atomically so the callbacks may be used from multiple threads.
+pub struct TrackingAllocator<A: GlobalAlloc + Sync = System> {
+    allocator: A,
+    allocations: AtomicUsize,
+    reallocations: AtomicUsize,
+    frees: AtomicUsize,
+    failed_allocations: AtomicUsize,
+    live_allocations: AtomicUsize,
+    live_bytes: AtomicUsize,
+    peak_bytes: AtomicUsize,
+    live_bytes_per_scope: [AtomicUsize; SCOPE_COUNT],
+    internal_bytes: AtomicUsize,
+}
+
+impl TrackingAllocator<System> {
+    /// Creates a new tracking allocator using the [`System`] allocator.
+    pub fn new() -> Self {
+        Self::with_allocator(System)
+    }
+}
+
+impl Default for TrackingAllocator<System> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A: GlobalAlloc + Sync> TrackingAllocator<A> {
+    /// Creates a new tracking allocator forwarding all allocations to `allocator`.
+    pub fn with_allocator(allocator: A) -> Self {
+        Self {
+            allocator,
+            allocations: AtomicUsize::new(0),
+            reallocations: AtomicUsize::new(0),
+            frees: AtomicUsize::new(0),
+            failed_allocations: AtomicUsize::new(0),
+            live_allocations: AtomicUsize::new(0),
+            live_bytes: AtomicUsize::new(0),
+            peak_bytes: AtomicUsize::new(0),
+            live_bytes_per_scope: Default::default(),
+            internal_bytes: AtomicUsize::new(0),
+        }
+    }
+
+    /// Returns allocation callbacks which forward to this allocator.
+    ///
+    /// The returned callbacks borrow the allocator. Any vulkan object created with them must be destroyed
+    /// (using the same callbacks) before the allocator is dropped.
+    pub fn callbacks(&self) -> vk::AllocationCallbacks<'_> {
+        vk::AllocationCallbacks::default()
+            .user_data(self as *const Self as *mut c_void)
+            .pfn_allocation(Some(Self::allocation))
+            .pfn_reallocation(Some(Self::reallocation))
+            .pfn_free(Some(Self::free))
+            .pfn_internal_allocation(Some(Self::internal_allocation))
+            .pfn_internal_free(Some(Self::internal_free))
+    }
+
+    /// Returns a snapshot of the current statistics.
+    pub fn stats(&self) -> AllocationStats {
+        let mut live_bytes_per_scope = [0; SCOPE_COUNT];
+        for (dst, src) in live_bytes_per_scope
+            .iter_mut()
+            .zip(&self.live_bytes_per_scope)
+        {
+            *dst = src.load(Ordering::Relaxed);
+        }
+
+        AllocationStats {
+            allocations: self.allocations.load(Ordering::Relaxed),
+            reallocations: self.reallocations.load(Ordering::Relaxed),
+            frees: self.frees.load(Ordering::Relaxed),
+            failed_allocations: self.failed_allocations.load(Ordering::Relaxed),
+            live_allocations: self.live_allocations.load(Ordering::Relaxed),
+            live_bytes: self.live_bytes.load(Ordering::Relaxed),
+            peak_bytes: self.peak_bytes.load(Ordering::Relaxed),
+            live_bytes_per_scope,
+            internal_bytes: self.internal_bytes.load(Ordering::Relaxed),
+        }
+    }
+
+    fn scope_index(scope: vk::SystemAllocationScope) -> Option<usize> {
+        usize::try_from(scope.as_raw())
+            .ok()
+            .filter(|index| *index < SCOPE_COUNT)
+    }
+
+    fn record_allocation(&self, size: usize, scope: vk::SystemAllocationScope) {
+        self.live_allocations.fetch_add(1, Ordering::Relaxed);
+        let live = self.live_bytes.fetch_add(size, Ordering::Relaxed) + size;
+        self.peak_bytes.fetch_max(live, Ordering::Relaxed);
+        if let Some(index) = Self::scope_index(scope) {
+            self.live_bytes_per_scope[index].fetch_add(size, Ordering::Relaxed);
+        }
+    }
+
+    fn record_free(&self, size: usize, scope: vk::SystemAllocationScope) {
+        self.live_allocations.fetch_sub(1, Ordering::Relaxed);
+        self.live_bytes.fetch_sub(size, Ordering::Relaxed);
+        if let Some(index) = Self::scope_index(scope) {
+            self.live_bytes_per_scope[index].fetch_sub(size, Ordering::Relaxed);
+        }
+    }
+
+    /// Allocates a block with a header in front of it. Does not update any statistics.
+    unsafe fn allocate_block(
+        &self,
+        size: usize,
+        alignment: usize,
+        scope: vk::SystemAllocationScope,
+    ) -> *mut c_void {
+        if size == 0 || !alignment.is_power_of_two() {
+            return ptr::null_mut();
+        }
+        let header = AllocationHeader {
+            size,
+            alignment,
+            scope,
+        };
+        let Some(layout) = header.layout() else {
+            return ptr::null_mut();
+        };
+
+        let base = self.allocator.alloc(layout);
+        if base.is_null() {
+            return ptr::null_mut();
+        }
+        let memory = base.add(AllocationHeader::offset(layout.align()));
+        ptr::write_unaligned(
+            memory.sub(size_of::<AllocationHeader>()) as *mut AllocationHeader,
+            header,
+        );
+        memory as *mut c_void
+    }
+
+    /// Frees a block allocated by [`Self::allocate_block`] and returns its header.
+    unsafe fn free_block(&self, memory: *mut c_void) -> AllocationHeader {
+        let memory = memory as *mut u8;
+        let header = ptr::read_unaligned(
+            memory.sub(size_of::<AllocationHeader>()) as *const AllocationHeader
+        );
+        // The layout was valid when the block was allocated
+        let layout = header.layout().unwrap();
+        self.allocator
+            .dealloc(memory.sub(AllocationHeader::offset(layout.align())), layout);
+        header
+    }
+
+    unsafe extern "system" fn allocation(
+        p_user_data: *mut c_void,
+        size: usize,
+        alignment: usize,
+        allocation_scope: vk::SystemAllocationScope,
+    ) -> *mut c_void {
+        let this = &*(p_user_data as *const Self);
+        let memory = this.allocate_block(size, alignment, allocation_scope);
+        if memory.is_null() {
+            this.failed_allocations.fetch_add(1, Ordering::Relaxed);
+        } else {
+            this.allocations.fetch_add(1, Ordering::Relaxed);
+            this.record_allocation(size, allocation_scope);
+        }
+        memory
+    }
+
+    unsafe extern "system" fn reallocation(
+        p_user_data: *mut c_void,
+        p_original: *mut c_void,
+        size: usize,
+        alignment: usize,
+        allocation_scope: vk::SystemAllocationScope,
+    ) -> *mut c_void {
+        if p_original.is_null() {
+            return Self::allocation(p_user_data, size, alignment, allocation_scope);
+        }
+        if size == 0 {
+            Self::free(p_user_data, p_original);
+            return ptr::null_mut();
+        }
+
+        let this = &*(p_user_data as *const Self);
+        let memory = this.allocate_block(size, alignment, allocation_scope);
+        if memory.is_null() {
+            // The original allocation must be left untouched on failure
+            this.failed_allocations.fetch_add(1, Ordering::Relaxed);
+            return ptr::null_mut();
+        }
+
+        let original =
+            ptr::read_unaligned((p_original as *const u8).sub(size_of::<AllocationHeader>())
+                as *const AllocationHeader);
+        ptr::copy_nonoverlapping(
+            p_original as *const u8,
+            memory as *mut u8,
+            original.size.min(size),
+        );
+        this.free_block(p_original);
+
+        this.reallocations.fetch_add(1, Ordering::Relaxed);
+        this.record_free(original.size, original.scope);
+        this.record_allocation(size, allocation_scope);
+        memory
+    }
+
+    unsafe extern "system" fn free(p_user_data: *mut c_void, p_memory: *mut c_void) {
+        if p_memory.is_null() {
+            return;
+        }
+        let this = &*(p_user_data as *const Self);
+        let header = this.free_block(p_memory);
+        this.frees.fetch_add(1, Ordering::Relaxed);
+        this.record_free(header.size, header.scope);
+    }
+
+    unsafe extern "system" fn internal_allocation(
+        p_user_data: *mut c_void,
+        size: usize,
+        _allocation_type: vk::InternalAllocationType,
+        _allocation_scope: vk::SystemAllocationScope,
+    ) {
+        let this = &*(p_user_data as *const Self);
+        this.internal_bytes.fetch_add(size, Ordering::Relaxed);
+    }
+
+    unsafe extern "system" fn internal_free(
+        p_user_data: *mut c_void,
+        size: usize,
+        _allocation_type: vk::InternalAllocationType,
+        _allocation_scope: vk::SystemAllocationScope,
+    ) {
+        let this = &*(p_user_data as *const Self);
+        this.internal_bytes.fetch_sub(size, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tracking_allocator() {
+        let allocator = TrackingAllocator::new();
+        let callbacks = allocator.callbacks();
+        let user_data = callbacks.p_user_data;
+        let scope = vk::SystemAllocationScope::DEVICE;
+
+        unsafe {
+            let memory = (callbacks.pfn_allocation.unwrap())(user_data, 100, 64, scope);
+            assert!(!memory.is_null());
+            assert_eq!(memory as usize % 64, 0);
+            ptr::write_bytes(memory as *mut u8, 0xAB, 100);
+
+            let memory = (callbacks.pfn_reallocation.unwrap())(user_data, memory, 300, 64, scope);
+            assert!(!memory.is_null());
+            assert_eq!(memory as usize % 64, 0);
+            assert_eq!(*(memory as *const u8).add(99), 0xAB);
+
+            let stats = allocator.stats();
+            assert_eq!(stats.allocations, 1);
+            assert_eq!(stats.reallocations, 1);
+            assert_eq!(stats.live_allocations, 1);
+            assert_eq!(stats.live_bytes, 300);
+            assert_eq!(stats.peak_bytes, 300);
+            assert_eq!(stats.live_bytes_per_scope[scope.as_raw() as usize], 300);
+
+            (callbacks.pfn_free.unwrap())(user_data, memory);
+        }
+
+        let stats = allocator.stats();
+        assert_eq!(stats.frees, 1);
+        assert_eq!(stats.live_allocations, 0);
+        assert_eq!(stats.live_bytes, 0);
+        assert_eq!(stats.peak_bytes, 300);
+    }
+}
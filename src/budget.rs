@@ -0,0 +1,694 @@
+//! Validation of resource usage against the device limits guaranteed by profiles.
+//!
+//! [`GuaranteedLimits`] combines the limits required by a set of profiles with the minimum limits every
+//! vulkan implementation must support. Descriptions of pipeline layouts, vertex input state and framebuffers
+//! can then be checked against these limits without a device, for example in unit tests:
+//!
+//! ```no_run
+//! use ash::vk;
+//! use vk_profiles_rs::budget::{GuaranteedLimits, PipelineLayoutDesc, Resources};
+//! use vk_profiles_rs::{profiles, VulkanProfiles};
+//!
+//! let limits = unsafe {
+//!     GuaranteedLimits::from_profiles(
+//!         &VulkanProfiles::linked(),
+//!         &[profiles::KhrRoadmap2022::profile_properties()],
+//!         &[],
+//!     )
+//! };
+//!
+//! let resources = Resources {
+//!     pipeline_layouts: vec![PipelineLayoutDesc {
+//!         set_layouts: vec![vec![vk::DescriptorSetLayoutBinding::default()
+//!             .descriptor_type(vk::DescriptorType::COMBINED_IMAGE_SAMPLER)
+//!             .descriptor_count(64)
+//!             .stage_flags(vk::ShaderStageFlags::FRAGMENT)]],
+//!         push_constant_ranges: vec![vk::PushConstantRange::default()
+//!             .stage_flags(vk::ShaderStageFlags::VERTEX)
+//!             .size(128)],
+//!     }],
+//!     ..Default::default()
+//! };
+//! let overruns = limits.validate(&resources);
+//! assert!(overruns.is_empty(), "{overruns:?}");
+//! ```
+
+use std::fmt;
+
+use ash::vk;
+
+use crate::builder::enabled_blocks;
+use crate::vp::{BlockProperties, ProfileProperties};
+use crate::VulkanProfiles;
+
+/// The descriptor set layouts and push constant ranges of a single pipeline layout.
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct PipelineLayoutDesc {
+    /// The bindings of each descriptor set layout. Immutable samplers are ignored.
+    pub set_layouts: Vec<Vec<vk::DescriptorSetLayoutBinding<'static>>>,
+    pub push_constant_ranges: Vec<vk::PushConstantRange>,
+}
+
+/// The vertex input state of a single graphics pipeline.
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct VertexInputDesc {
+    pub bindings: Vec<vk::VertexInputBindingDescription>,
+    pub attributes: Vec<vk::VertexInputAttributeDescription>,
+}
+
+/// The size and attachments of a single framebuffer.
+#[derive(Copy, Clone, Default, PartialEq, Eq, Debug)]
+pub struct FramebufferDesc {
+    pub width: u32,
+    pub height: u32,
+    pub layers: u32,
+    pub color_attachment_count: u32,
+    pub has_depth_stencil_attachment: bool,
+    /// The sample count of all attachments.
+    pub samples: vk::SampleCountFlags,
+}
+
+/// All resources which are checked by [`GuaranteedLimits::validate`].
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct Resources {
+    pub pipeline_layouts: Vec<PipelineLayoutDesc>,
+    pub vertex_inputs: Vec<VertexInputDesc>,
+    pub framebuffers: Vec<FramebufferDesc>,
+}
+
+/// Identifies a resource by its index in the lists of [`Resources`].
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum ResourceRef {
+    PipelineLayout(usize),
+    VertexInput(usize),
+    Framebuffer(usize),
+}
+
+/// A resource which exceeds a limit.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct LimitOverrun {
+    pub resource: ResourceRef,
+    /// The name of the `VkPhysicalDeviceLimits` member.
+    pub limit: &'static str,
+    /// The value used by the resource. Sample counts are stored as raw [`vk::SampleCountFlags`].
+    pub used: u64,
+    /// The guaranteed value of the limit.
+    pub guaranteed: u64,
+}
+
+impl fmt::Display for LimitOverrun {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let resource = match self.resource {
+            ResourceRef::PipelineLayout(index) => format!("pipeline layout {index}"),
+            ResourceRef::VertexInput(index) => format!("vertex input {index}"),
+            ResourceRef::Framebuffer(index) => format!("framebuffer {index}"),
+        };
+        if self.limit.ends_with("SampleCounts") {
+            write!(
+                f,
+                "{resource} uses {:?} samples but {} only guarantees {:?}",
+                vk::SampleCountFlags::from_raw(self.used as u32),
+                self.limit,
+                vk::SampleCountFlags::from_raw(self.guaranteed as u32)
+            )
+        } else {
+            write!(
+                f,
+                "{resource} uses {} but {} only guarantees {}",
+                self.used, self.limit, self.guaranteed
+            )
+        }
+    }
+}
+
+/// The device limits every device supporting a set of profiles is guaranteed to have.
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct GuaranteedLimits {
+    pub limits: vk::PhysicalDeviceLimits,
+}
+
+impl Default for GuaranteedLimits {
+    /// Returns the minimum limits required by the vulkan specification.
+    fn default() -> Self {
+        Self {
+            limits: vulkan_minimums(),
+        }
+    }
+}
+
+impl GuaranteedLimits {
+    /// Combines `limits` with the minimum limits required by the vulkan specification.
+    ///
+    /// Limits which are zero, for example because a profile does not specify them, are raised to the
+    /// specification minimum.
+    pub fn from_limits(limits: &vk::PhysicalDeviceLimits) -> Self {
+        let mut guaranteed = Self::default();
+        merge_limits(&mut guaranteed.limits, limits);
+        guaranteed
+    }
+
+    /// Returns the limits guaranteed by the enabled profiles and profile blocks.
+    ///
+    /// A device supporting all profiles satisfies the limits of every profile, so the strongest value of
+    /// each limit is used.
+    ///
+    /// # Safety
+    /// The profile names of `profiles` and the names of `blocks` must be null terminated.
+    pub unsafe fn from_profiles(
+        vk_profiles: &VulkanProfiles,
+        profiles: &[ProfileProperties],
+        blocks: &[BlockProperties],
+    ) -> Self {
+        let mut guaranteed = Self::default();
+        for (profile, block_name) in enabled_blocks(profiles, blocks) {
+            let mut properties = vk::PhysicalDeviceProperties2::default();
+            vk_profiles.get_profile_properties(profile, block_name, &mut properties);
+            merge_limits(&mut guaranteed.limits, &properties.properties.limits);
+        }
+        guaranteed
+    }
+
+    /// Checks all resources and returns every limit they exceed.
+    pub fn validate(&self, resources: &Resources) -> Vec<LimitOverrun> {
+        let mut overruns = Vec::new();
+        for (index, layout) in resources.pipeline_layouts.iter().enumerate() {
+            self.validate_pipeline_layout(
+                ResourceRef::PipelineLayout(index),
+                layout,
+                &mut overruns,
+            );
+        }
+        for (index, input) in resources.vertex_inputs.iter().enumerate() {
+            self.validate_vertex_input(ResourceRef::VertexInput(index), input, &mut overruns);
+        }
+        for (index, framebuffer) in resources.framebuffers.iter().enumerate() {
+            self.validate_framebuffer(ResourceRef::Framebuffer(index), framebuffer, &mut overruns);
+        }
+        overruns
+    }
+
+    fn validate_pipeline_layout(
+        &self,
+        resource: ResourceRef,
+        layout: &PipelineLayoutDesc,
+        overruns: &mut Vec<LimitOverrun>,
+    ) {
+        let limits = &self.limits;
+        let mut check = |limit, used: u64, guaranteed: u32| {
+            check_max(overruns, resource, limit, used, guaranteed)
+        };
+        check(
+            "maxBoundDescriptorSets",
+            layout.set_layouts.len() as u64,
+            limits.max_bound_descriptor_sets,
+        );
+
+        let bindings: Vec<_> = layout.set_layouts.iter().flatten().collect();
+        let count = |types: &[vk::DescriptorType], stage: vk::ShaderStageFlags| -> u64 {
+            bindings
+                .iter()
+                .filter(|binding| {
+                    types.contains(&binding.descriptor_type)
+                        && binding.stage_flags.intersects(stage)
+                })
+                .map(|binding| u64::from(binding.descriptor_count))
+                .sum()
+        };
+
+        use vk::DescriptorType as D;
+        let samplers = [D::SAMPLER, D::COMBINED_IMAGE_SAMPLER];
+        let uniform_buffers = [D::UNIFORM_BUFFER, D::UNIFORM_BUFFER_DYNAMIC];
+        let storage_buffers = [D::STORAGE_BUFFER, D::STORAGE_BUFFER_DYNAMIC];
+        let sampled_images = [
+            D::COMBINED_IMAGE_SAMPLER,
+            D::SAMPLED_IMAGE,
+            D::UNIFORM_TEXEL_BUFFER,
+        ];
+        let storage_images = [D::STORAGE_IMAGE, D::STORAGE_TEXEL_BUFFER];
+        let input_attachments = [D::INPUT_ATTACHMENT];
+        let per_stage = [
+            (
+                "maxPerStageDescriptorSamplers",
+                &samplers[..],
+                limits.max_per_stage_descriptor_samplers,
+            ),
+            (
+                "maxPerStageDescriptorUniformBuffers",
+                &uniform_buffers,
+                limits.max_per_stage_descriptor_uniform_buffers,
+            ),
+            (
+                "maxPerStageDescriptorStorageBuffers",
+                &storage_buffers,
+                limits.max_per_stage_descriptor_storage_buffers,
+            ),
+            (
+                "maxPerStageDescriptorSampledImages",
+                &sampled_images,
+                limits.max_per_stage_descriptor_sampled_images,
+            ),
+            (
+                "maxPerStageDescriptorStorageImages",
+                &storage_images,
+                limits.max_per_stage_descriptor_storage_images,
+            ),
+            (
+                "maxPerStageDescriptorInputAttachments",
+                &input_attachments,
+                limits.max_per_stage_descriptor_input_attachments,
+            ),
+        ];
+
+        // Each stage is checked on its own, but every limit is reported once per layout
+        let stages: vk::ShaderStageFlags = bindings
+            .iter()
+            .fold(vk::ShaderStageFlags::empty(), |stages, binding| {
+                stages | binding.stage_flags
+            });
+        let stage_bits = (0..32)
+            .map(|bit| vk::ShaderStageFlags::from_raw(1 << bit))
+            .filter(|stage| stages.contains(*stage));
+        let mut used_per_stage = [0; 7];
+        for stage in stage_bits {
+            for (used, (_, types, _)) in used_per_stage.iter_mut().zip(&per_stage) {
+                *used = (*used).max(count(types, stage));
+            }
+            // Combined image samplers count as one resource
+            let resources = [
+                &samplers[..],
+                &uniform_buffers,
+                &storage_buffers,
+                &[D::SAMPLED_IMAGE, D::UNIFORM_TEXEL_BUFFER],
+                &storage_images,
+                &input_attachments,
+            ]
+            .iter()
+            .map(|types| count(types, stage))
+            .sum();
+            used_per_stage[6] = used_per_stage[6].max(resources);
+        }
+        for ((limit, _, guaranteed), used) in per_stage.iter().zip(used_per_stage) {
+            check(limit, used, *guaranteed);
+        }
+        check(
+            "maxPerStageResources",
+            used_per_stage[6],
+            limits.max_per_stage_resources,
+        );
+
+        let all = vk::ShaderStageFlags::ALL;
+        for (limit, types, guaranteed) in [
+            (
+                "maxDescriptorSetSamplers",
+                &samplers[..],
+                limits.max_descriptor_set_samplers,
+            ),
+            (
+                "maxDescriptorSetUniformBuffers",
+                &uniform_buffers,
+                limits.max_descriptor_set_uniform_buffers,
+            ),
+            (
+                "maxDescriptorSetUniformBuffersDynamic",
+                &[D::UNIFORM_BUFFER_DYNAMIC],
+                limits.max_descriptor_set_uniform_buffers_dynamic,
+            ),
+            (
+                "maxDescriptorSetStorageBuffers",
+                &storage_buffers,
+                limits.max_descriptor_set_storage_buffers,
+            ),
+            (
+                "maxDescriptorSetStorageBuffersDynamic",
+                &[D::STORAGE_BUFFER_DYNAMIC],
+                limits.max_descriptor_set_storage_buffers_dynamic,
+            ),
+            (
+                "maxDescriptorSetSampledImages",
+                &sampled_images,
+                limits.max_descriptor_set_sampled_images,
+            ),
+            (
+                "maxDescriptorSetStorageImages",
+                &storage_images,
+                limits.max_descriptor_set_storage_images,
+            ),
+            (
+                "maxDescriptorSetInputAttachments",
+                &input_attachments,
+                limits.max_descriptor_set_input_attachments,
+            ),
+        ] {
+            check(limit, count(types, all), guaranteed);
+        }
+
+        let push_constants_size = layout
+            .push_constant_ranges
+            .iter()
+            .map(|range| u64::from(range.offset) + u64::from(range.size))
+            .max()
+            .unwrap_or(0);
+        check(
+            "maxPushConstantsSize",
+            push_constants_size,
+            limits.max_push_constants_size,
+        );
+    }
+
+    fn validate_vertex_input(
+        &self,
+        resource: ResourceRef,
+        input: &VertexInputDesc,
+        overruns: &mut Vec<LimitOverrun>,
+    ) {
+        let limits = &self.limits;
+        let mut check = |limit, used: u64, guaranteed: u32| {
+            check_max(overruns, resource, limit, used, guaranteed)
+        };
+
+        // Binding numbers and locations must be smaller than the limit, so the highest one plus one is used
+        let bindings = input
+            .bindings
+            .iter()
+            .map(|binding| u64::from(binding.binding) + 1)
+            .chain([input.bindings.len() as u64])
+            .max()
+            .unwrap_or(0);
+        check(
+            "maxVertexInputBindings",
+            bindings,
+            limits.max_vertex_input_bindings,
+        );
+        let attributes = input
+            .attributes
+            .iter()
+            .map(|attribute| u64::from(attribute.location) + 1)
+            .chain([input.attributes.len() as u64])
+            .max()
+            .unwrap_or(0);
+        check(
+            "maxVertexInputAttributes",
+            attributes,
+            limits.max_vertex_input_attributes,
+        );
+        let stride = input.bindings.iter().map(|binding| binding.stride).max();
+        check(
+            "maxVertexInputBindingStride",
+            stride.map_or(0, u64::from),
+            limits.max_vertex_input_binding_stride,
+        );
+        let offset = input
+            .attributes
+            .iter()
+            .map(|attribute| attribute.offset)
+            .max();
+        check(
+            "maxVertexInputAttributeOffset",
+            offset.map_or(0, u64::from),
+            limits.max_vertex_input_attribute_offset,
+        );
+    }
+
+    fn validate_framebuffer(
+        &self,
+        resource: ResourceRef,
+        framebuffer: &FramebufferDesc,
+        overruns: &mut Vec<LimitOverrun>,
+    ) {
+        let limits = &self.limits;
+        for (limit, used, guaranteed) in [
+            (
+                "maxFramebufferWidth",
+                framebuffer.width,
+                limits.max_framebuffer_width,
+            ),
+            (
+                "maxFramebufferHeight",
+                framebuffer.height,
+                limits.max_framebuffer_height,
+            ),
+            (
+                "maxFramebufferLayers",
+                framebuffer.layers,
+                limits.max_framebuffer_layers,
+            ),
+            (
+                "maxColorAttachments",
+                framebuffer.color_attachment_count,
+                limits.max_color_attachments,
+            ),
+        ] {
+            check_max(overruns, resource, limit, u64::from(used), guaranteed);
+        }
+
+        let mut sample_counts = Vec::new();
+        if framebuffer.color_attachment_count > 0 {
+            sample_counts.push((
+                "framebufferColorSampleCounts",
+                limits.framebuffer_color_sample_counts,
+            ));
+        }
+        if framebuffer.has_depth_stencil_attachment {
+            sample_counts.push((
+                "framebufferDepthSampleCounts",
+                limits.framebuffer_depth_sample_counts,
+            ));
+            sample_counts.push((
+                "framebufferStencilSampleCounts",
+                limits.framebuffer_stencil_sample_counts,
+            ));
+        }
+        if sample_counts.is_empty() {
+            sample_counts.push((
+                "framebufferNoAttachmentsSampleCounts",
+                limits.framebuffer_no_attachments_sample_counts,
+            ));
+        }
+        for (limit, guaranteed) in sample_counts {
+            if !guaranteed.contains(framebuffer.samples) {
+                overruns.push(LimitOverrun {
+                    resource,
+                    limit,
+                    used: u64::from(framebuffer.samples.as_raw()),
+                    guaranteed: u64::from(guaranteed.as_raw()),
+                });
+            }
+        }
+    }
+}
+
+fn check_max(
+    overruns: &mut Vec<LimitOverrun>,
+    resource: ResourceRef,
+    limit: &'static str,
+    used: u64,
+    guaranteed: u32,
+) {
+    if used > u64::from(guaranteed) {
+        overruns.push(LimitOverrun {
+            resource,
+            limit,
+            used,
+            guaranteed: u64::from(guaranteed),
+        });
+    }
+}
+
+/// Raises the limits checked by [`GuaranteedLimits`] in `target` to the values of `limits`.
+fn merge_limits(target: &mut vk::PhysicalDeviceLimits, limits: &vk::PhysicalDeviceLimits) {
+    macro_rules! max {
+        ($($member:ident),* $(,)?) => {
+            $(target.$member = target.$member.max(limits.$member);)*
+        };
+    }
+    macro_rules! union {
+        ($($member:ident),* $(,)?) => {
+            $(target.$member |= limits.$member;)*
+        };
+    }
+
+    max!(
+        max_bound_descriptor_sets,
+        max_per_stage_descriptor_samplers,
+        max_per_stage_descriptor_uniform_buffers,
+        max_per_stage_descriptor_storage_buffers,
+        max_per_stage_descriptor_sampled_images,
+        max_per_stage_descriptor_storage_images,
+        max_per_stage_descriptor_input_attachments,
+        max_per_stage_resources,
+        max_descriptor_set_samplers,
+        max_descriptor_set_uniform_buffers,
+        max_descriptor_set_uniform_buffers_dynamic,
+        max_descriptor_set_storage_buffers,
+        max_descriptor_set_storage_buffers_dynamic,
+        max_descriptor_set_sampled_images,
+        max_descriptor_set_storage_images,
+        max_descriptor_set_input_attachments,
+        max_push_constants_size,
+        max_vertex_input_attributes,
+        max_vertex_input_bindings,
+        max_vertex_input_attribute_offset,
+        max_vertex_input_binding_stride,
+        max_framebuffer_width,
+        max_framebuffer_height,
+        max_framebuffer_layers,
+        max_color_attachments,
+    );
+    union!(
+        framebuffer_color_sample_counts,
+        framebuffer_depth_sample_counts,
+        framebuffer_stencil_sample_counts,
+        framebuffer_no_attachments_sample_counts,
+    );
+}
+
+/// The minimum limits required by the vulkan 1.0 specification for the limits checked by
+/// [`GuaranteedLimits`].
+fn vulkan_minimums() -> vk::PhysicalDeviceLimits {
+    let samples = vk::SampleCountFlags::TYPE_1 | vk::SampleCountFlags::TYPE_4;
+    vk::PhysicalDeviceLimits {
+        max_bound_descriptor_sets: 4,
+        max_per_stage_descriptor_samplers: 16,
+        max_per_stage_descriptor_uniform_buffers: 12,
+        max_per_stage_descriptor_storage_buffers: 4,
+        max_per_stage_descriptor_sampled_images: 16,
+        max_per_stage_descriptor_storage_images: 4,
+        max_per_stage_descriptor_input_attachments: 4,
+        max_per_stage_resources: 128,
+        max_descriptor_set_samplers: 96,
+        max_descriptor_set_uniform_buffers: 72,
+        max_descriptor_set_uniform_buffers_dynamic: 8,
+        max_descriptor_set_storage_buffers: 24,
+        max_descriptor_set_storage_buffers_dynamic: 4,
+        max_descriptor_set_sampled_images: 96,
+        max_descriptor_set_storage_images: 24,
+        max_descriptor_set_input_attachments: 4,
+        max_push_constants_size: 128,
+        max_vertex_input_attributes: 16,
+        max_vertex_input_bindings: 16,
+        max_vertex_input_attribute_offset: 2047,
+        max_vertex_input_binding_stride: 2048,
+        max_framebuffer_width: 4096,
+        max_framebuffer_height: 4096,
+        max_framebuffer_layers: 256,
+        max_color_attachments: 4,
+        framebuffer_color_sample_counts: samples,
+        framebuffer_depth_sample_counts: samples,
+        framebuffer_stencil_sample_counts: samples,
+        framebuffer_no_attachments_sample_counts: samples,
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn binding(
+        descriptor_type: vk::DescriptorType,
+        descriptor_count: u32,
+        stage_flags: vk::ShaderStageFlags,
+    ) -> vk::DescriptorSetLayoutBinding<'static> {
+        vk::DescriptorSetLayoutBinding::default()
+            .descriptor_type(descriptor_type)
+            .descriptor_count(descriptor_count)
+            .stage_flags(stage_flags)
+    }
+
+    #[test]
+    fn test_validate() {
+        let limits = GuaranteedLimits::from_limits(&vk::PhysicalDeviceLimits {
+            max_per_stage_descriptor_sampled_images: 200,
+            max_descriptor_set_sampled_images: 1000,
+            max_push_constants_size: 256,
+            ..Default::default()
+        });
+        assert_eq!(limits.limits.max_bound_descriptor_sets, 4);
+        assert_eq!(limits.limits.max_push_constants_size, 256);
+
+        use vk::DescriptorType as D;
+        use vk::ShaderStageFlags as S;
+        let resources = Resources {
+            pipeline_layouts: vec![
+                PipelineLayoutDesc {
+                    set_layouts: vec![
+                        vec![
+                            binding(D::SAMPLED_IMAGE, 150, S::FRAGMENT),
+                            binding(D::UNIFORM_BUFFER, 8, S::VERTEX | S::FRAGMENT),
+                        ],
+                        vec![binding(D::UNIFORM_BUFFER, 8, S::VERTEX)],
+                    ],
+                    push_constant_ranges: vec![vk::PushConstantRange::default()
+                        .offset(128)
+                        .size(128)],
+                },
+                PipelineLayoutDesc {
+                    set_layouts: vec![Vec::new(); 5],
+                    push_constant_ranges: Vec::new(),
+                },
+            ],
+            vertex_inputs: vec![VertexInputDesc {
+                bindings: vec![vk::VertexInputBindingDescription::default()
+                    .binding(16)
+                    .stride(64)],
+                attributes: vec![vk::VertexInputAttributeDescription::default().offset(4096)],
+            }],
+            framebuffers: vec![
+                FramebufferDesc {
+                    width: 4096,
+                    height: 4096,
+                    layers: 1,
+                    color_attachment_count: 4,
+                    has_depth_stencil_attachment: true,
+                    samples: vk::SampleCountFlags::TYPE_4,
+                },
+                FramebufferDesc {
+                    width: 8192,
+                    height: 1024,
+                    layers: 1,
+                    color_attachment_count: 1,
+                    has_depth_stencil_attachment: false,
+                    samples: vk::SampleCountFlags::TYPE_8,
+                },
+            ],
+        };
+
+        let overruns: Vec<_> = limits
+            .validate(&resources)
+            .into_iter()
+            .map(|overrun| (overrun.resource, overrun.limit, overrun.used))
+            .collect();
+        assert_eq!(
+            overruns,
+            [
+                (
+                    ResourceRef::PipelineLayout(0),
+                    "maxPerStageDescriptorUniformBuffers",
+                    16
+                ),
+                (ResourceRef::PipelineLayout(0), "maxPerStageResources", 158),
+                (ResourceRef::PipelineLayout(1), "maxBoundDescriptorSets", 5),
+                (ResourceRef::VertexInput(0), "maxVertexInputBindings", 17),
+                (
+                    ResourceRef::VertexInput(0),
+                    "maxVertexInputAttributeOffset",
+                    4096
+                ),
+                (ResourceRef::Framebuffer(1), "maxFramebufferWidth", 8192),
+                (
+                    ResourceRef::Framebuffer(1),
+                    "framebufferColorSampleCounts",
+                    vk::SampleCountFlags::TYPE_8.as_raw() as u64
+                ),
+            ]
+        );
+        assert_eq!(
+            limits.validate(&resources)[0].to_string(),
+            "pipeline layout 0 uses 16 but maxPerStageDescriptorUniformBuffers only guarantees 12"
+        );
+    }
+}
@@ -0,0 +1,850 @@
+//! Owning builders for profile based instance and device creation.
+//!
+//! The raw [`vp::InstanceCreateInfo`] and [`vp::DeviceCreateInfo`] structs only store pointers to data owned
+//! by the caller. The builders in this module own all of their data and only assemble
+//! the raw vulkan and vulkan profiles structs for the duration of the create call. No raw
+//! pointers are ever handed out to user code.
+//!
+//! [`assemble_instance_create_info`] and [`assemble_device_create_info`] merge the profile requirements
+//! without the vulkan profiles library create functions and return owned create infos which can be
+//! inspected before the plain ash create functions are called.
+
+use std::ffi::{c_char, CStr, CString};
+
+use ash::vk;
+
+use crate::capabilities::PhysicalDeviceQuery;
+use crate::queue_family::QueueAssignment;
+use crate::vp;
+use crate::{ErrorContext, ProfilesError, ProfilesResult, VulkanProfiles};
+
+mod assemble;
+mod optional;
+mod validate;
+
+pub use assemble::{
+    assemble_device_create_info, assemble_instance_create_info, preview_device_features,
+    EnabledDeviceFeatures, OwnedDeviceCreateInfo, OwnedDeviceQueueCreateInfo,
+    OwnedInstanceCreateInfo,
+};
+pub(crate) use assemble::{enabled_blocks, profiles_api_version};
+pub use optional::OptionalSupport;
+pub use validate::{validate_device_create_info, ChainIssue};
+
+/// An owning builder for [`ash::Instance`] objects created through the vulkan profiles library.
+///
+/// ```no_run
+/// use vk_profiles_rs::{builder::ProfileInstanceBuilder, profiles, VulkanProfiles};
+///
+/// # fn main() -> vk_profiles_rs::ProfilesResult<()> {
+/// let entry = ash::Entry::linked();
+/// let vk_profiles = VulkanProfiles::linked();
+///
+/// let instance = unsafe {
+///     ProfileInstanceBuilder::new(&vk_profiles)
+///         .application_name(c"My Application")
+///         .enabled_extension_names([ash::khr::surface::NAME])
+///         .enabled_full_profile(profiles::KhrRoadmap2022::profile_properties())
+///         .build(&entry, None)?
+/// };
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct ProfileInstanceBuilder {
+    vk_profiles: VulkanProfiles,
+    application_name: Option<CString>,
+    application_version: u32,
+    engine_name: Option<CString>,
+    engine_version: u32,
+    api_version: Option<u32>,
+    enabled_layer_names: Vec<CString>,
+    enabled_extension_names: Vec<CString>,
+    instance_flags: vk::InstanceCreateFlags,
+    flags: vp::InstanceCreateFlagBits,
+    enabled_full_profiles: Vec<vp::ProfileProperties>,
+    enabled_profile_blocks: Vec<vp::BlockProperties>,
+}
+
+impl ProfileInstanceBuilder {
+    /// Creates a new empty builder which will use the provided function table to create the instance.
+    pub fn new(vk_profiles: &VulkanProfiles) -> Self {
+        Self {
+            vk_profiles: vk_profiles.clone(),
+            application_name: None,
+            application_version: 0,
+            engine_name: None,
+            engine_version: 0,
+            api_version: None,
+            enabled_layer_names: Vec::new(),
+            enabled_extension_names: Vec::new(),
+            instance_flags: vk::InstanceCreateFlags::empty(),
+            flags: vp::InstanceCreateFlagBits::empty(),
+            enabled_full_profiles: Vec::new(),
+            enabled_profile_blocks: Vec::new(),
+        }
+    }
+
+    #[inline]
+    pub fn application_name(mut self, application_name: &CStr) -> Self {
+        self.application_name = Some(application_name.to_owned());
+        self
+    }
+
+    #[inline]
+    pub fn application_version(mut self, application_version: u32) -> Self {
+        self.application_version = application_version;
+        self
+    }
+
+    #[inline]
+    pub fn engine_name(mut self, engine_name: &CStr) -> Self {
+        self.engine_name = Some(engine_name.to_owned());
+        self
+    }
+
+    #[inline]
+    pub fn engine_version(mut self, engine_version: u32) -> Self {
+        self.engine_version = engine_version;
+        self
+    }
+
+    /// Sets the api version passed in the [`vk::ApplicationInfo`].
+    ///
+    /// If neither this nor any other application info is set no [`vk::ApplicationInfo`] is passed
+    /// and the vulkan profiles library selects the api version required by the enabled profiles.
+    /// If only other application info is set the highest version supported by the loader is used.
+    #[inline]
+    pub fn api_version(mut self, api_version: u32) -> Self {
+        self.api_version = Some(api_version);
+        self
+    }
+
+    /// Appends layers to the list of enabled layers.
+    pub fn enabled_layer_names<'b>(
+        mut self,
+        enabled_layer_names: impl IntoIterator<Item = &'b CStr>,
+    ) -> Self {
+        self.enabled_layer_names
+            .extend(enabled_layer_names.into_iter().map(CStr::to_owned));
+        self
+    }
+
+    /// Appends extensions to the list of enabled extensions. Extensions required by the enabled
+    /// profiles are added by the vulkan profiles library and do not need to be listed here.
+    pub fn enabled_extension_names<'b>(
+        mut self,
+        enabled_extension_names: impl IntoIterator<Item = &'b CStr>,
+    ) -> Self {
+        self.enabled_extension_names
+            .extend(enabled_extension_names.into_iter().map(CStr::to_owned));
+        self
+    }
+
+    /// Sets the flags of the wrapped [`vk::InstanceCreateInfo`].
+    #[inline]
+    pub fn instance_flags(mut self, instance_flags: vk::InstanceCreateFlags) -> Self {
+        self.instance_flags = instance_flags;
+        self
+    }
+
+    #[inline]
+    pub fn flags(mut self, flags: vp::InstanceCreateFlagBits) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    #[inline]
+    pub fn enabled_full_profile(mut self, profile: vp::ProfileProperties) -> Self {
+        self.enabled_full_profiles.push(profile);
+        self
+    }
+
+    /// Appends profiles to the list of enabled profiles.
+    pub fn enabled_full_profiles(mut self, profiles: &[vp::ProfileProperties]) -> Self {
+        self.enabled_full_profiles.extend_from_slice(profiles);
+        self
+    }
+
+    #[inline]
+    pub fn enabled_profile_block(mut self, block: vp::BlockProperties) -> Self {
+        self.enabled_profile_blocks.push(block);
+        self
+    }
+
+    /// Appends profile blocks to the list of enabled profile blocks.
+    pub fn enabled_profile_blocks(mut self, blocks: &[vp::BlockProperties]) -> Self {
+        self.enabled_profile_blocks.extend_from_slice(blocks);
+        self
+    }
+
+    /// Assembles the create info structs and creates the instance.
+    ///
+    /// See [`VulkanProfiles::create_instance`].
+    ///
+    /// # Safety
+    /// `allocation_callbacks` must be valid vulkan allocation callbacks which outlive the instance.
+    pub unsafe fn build(
+        &self,
+        entry: &ash::Entry,
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) -> ProfilesResult<ash::Instance> {
+        let has_application_info = self.application_name.is_some()
+            || self.engine_name.is_some()
+            || self.application_version != 0
+            || self.engine_version != 0
+            || self.api_version.is_some();
+
+        let api_version = match self.api_version {
+            Some(api_version) => api_version,
+            None if has_application_info => entry
+                .try_enumerate_instance_version()
+                .map_err(|result| {
+                    ProfilesError::vulkan(
+                        "vkEnumerateInstanceVersion",
+                        ErrorContext::default(),
+                        result,
+                    )
+                })?
+                .unwrap_or(vk::API_VERSION_1_0),
+            None => 0,
+        };
+
+        let mut application_info = vk::ApplicationInfo::default()
+            .application_version(self.application_version)
+            .engine_version(self.engine_version)
+            .api_version(api_version);
+        if let Some(application_name) = &self.application_name {
+            application_info = application_info.application_name(application_name);
+        }
+        if let Some(engine_name) = &self.engine_name {
+            application_info = application_info.engine_name(engine_name);
+        }
+
+        let layer_names: Vec<*const c_char> = self
+            .enabled_layer_names
+            .iter()
+            .map(|name| name.as_ptr())
+            .collect();
+        let extension_names: Vec<*const c_char> = self
+            .enabled_extension_names
+            .iter()
+            .map(|name| name.as_ptr())
+            .collect();
+
+        let mut instance_info = vk::InstanceCreateInfo::default()
+            .flags(self.instance_flags)
+            .enabled_layer_names(&layer_names)
+            .enabled_extension_names(&extension_names);
+        if has_application_info {
+            instance_info = instance_info.application_info(&application_info);
+        }
+
+        let vp_instance_info = vp::InstanceCreateInfo::default()
+            .create_info(&instance_info)
+            .flags(self.flags)
+            .enabled_full_profiles(&self.enabled_full_profiles)
+            .enabled_profile_blocks(&self.enabled_profile_blocks);
+
+        self.vk_profiles
+            .create_instance(entry, &vp_instance_info, allocation_callbacks)
+    }
+}
+
+/// Selects the queue family a queue request is placed in.
+#[derive(Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+enum QueueFamilySelector {
+    Index(u32),
+    Flags(vk::QueueFlags),
+}
+
+#[derive(Clone)]
+struct QueueRequest {
+    family: QueueFamilySelector,
+    priorities: Vec<f32>,
+}
+
+/// The result of resolving all queue requests against the queue families of a physical device.
+struct ResolvedQueues {
+    /// The queue family index and index of the first queue inside the family for each request.
+    placements: Vec<(u32, u32)>,
+    /// The merged priorities of each used queue family.
+    families: Vec<(u32, Vec<f32>)>,
+}
+
+/// A queue created by [`ProfileDeviceBuilder::build`].
+#[derive(Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct DeviceQueue {
+    pub family_index: u32,
+    pub queue_index: u32,
+    pub queue: vk::Queue,
+}
+
+/// Type erased storage for structs in the [`vk::DeviceCreateInfo`] pNext chain.
+trait DeviceChainElement {
+    fn clone_boxed(&self) -> Box<dyn DeviceChainElement>;
+
+    fn as_base_mut(&mut self) -> *mut vk::BaseOutStructure<'static>;
+}
+
+impl<T: vk::ExtendsDeviceCreateInfo + Copy + 'static> DeviceChainElement for T {
+    fn clone_boxed(&self) -> Box<dyn DeviceChainElement> {
+        Box::new(*self)
+    }
+
+    fn as_base_mut(&mut self) -> *mut vk::BaseOutStructure<'static> {
+        self as *mut T as *mut vk::BaseOutStructure
+    }
+}
+
+/// An owning builder for [`ash::Device`] objects created through the vulkan profiles library.
+///
+/// Queues are requested either for a specific queue family index or for any queue family supporting a
+/// set of [`vk::QueueFlags`]. Families are resolved against the physical device when the device is built and
+/// the created queue handles are returned together with the device.
+///
+/// ```no_run
+/// use ash::vk;
+/// use vk_profiles_rs::{builder::ProfileDeviceBuilder, profiles, VulkanProfiles};
+///
+/// # fn create(instance: &ash::Instance, physical_device: vk::PhysicalDevice) -> vk_profiles_rs::ProfilesResult<()> {
+/// let vk_profiles = VulkanProfiles::linked();
+///
+/// let (device, queues) = unsafe {
+///     ProfileDeviceBuilder::new(&vk_profiles)
+///         .queues_with_flags(vk::QueueFlags::GRAPHICS, &[1.0])
+///         .push_next(vk::PhysicalDeviceVulkan12Features::default().buffer_device_address(true))
+///         .enabled_full_profile(profiles::KhrRoadmap2022::profile_properties())
+///         .build(instance, physical_device, None)?
+/// };
+/// let graphics_queue = queues[0].queue;
+/// # Ok(())
+/// # }
+/// ```
+pub struct ProfileDeviceBuilder {
+    vk_profiles: VulkanProfiles,
+    queue_requests: Vec<QueueRequest>,
+    enabled_extension_names: Vec<CString>,
+    optional_extension_names: Vec<CString>,
+    enabled_features: Option<vk::PhysicalDeviceFeatures>,
+    next_chain: Vec<Box<dyn DeviceChainElement>>,
+    optional_chain: Vec<Box<dyn DeviceChainElement>>,
+    flags: vp::DeviceCreateFlagBits,
+    enabled_full_profiles: Vec<vp::ProfileProperties>,
+    enabled_profile_blocks: Vec<vp::BlockProperties>,
+}
+
+impl Clone for ProfileDeviceBuilder {
+    fn clone(&self) -> Self {
+        Self {
+            vk_profiles: self.vk_profiles.clone(),
+            queue_requests: self.queue_requests.clone(),
+            enabled_extension_names: self.enabled_extension_names.clone(),
+            optional_extension_names: self.optional_extension_names.clone(),
+            enabled_features: self.enabled_features,
+            next_chain: self.next_chain.iter().map(|e| e.clone_boxed()).collect(),
+            optional_chain: self
+                .optional_chain
+                .iter()
+                .map(|e| e.clone_boxed())
+                .collect(),
+            flags: self.flags,
+            enabled_full_profiles: self.enabled_full_profiles.clone(),
+            enabled_profile_blocks: self.enabled_profile_blocks.clone(),
+        }
+    }
+}
+
+impl ProfileDeviceBuilder {
+    /// Creates a new empty builder which will use the provided function table to create the device.
+    pub fn new(vk_profiles: &VulkanProfiles) -> Self {
+        Self {
+            vk_profiles: vk_profiles.clone(),
+            queue_requests: Vec::new(),
+            enabled_extension_names: Vec::new(),
+            optional_extension_names: Vec::new(),
+            enabled_features: None,
+            next_chain: Vec::new(),
+            optional_chain: Vec::new(),
+            flags: vp::DeviceCreateFlagBits::empty(),
+            enabled_full_profiles: Vec::new(),
+            enabled_profile_blocks: Vec::new(),
+        }
+    }
+
+    /// Requests one queue per priority from the queue family with the specified index.
+    ///
+    /// Multiple requests for the same queue family are merged into a single [`vk::DeviceQueueCreateInfo`].
+    /// Requests without priorities cannot be satisfied.
+    pub fn queues(mut self, queue_family_index: u32, priorities: &[f32]) -> Self {
+        self.queue_requests.push(QueueRequest {
+            family: QueueFamilySelector::Index(queue_family_index),
+            priorities: priorities.to_vec(),
+        });
+        self
+    }
+
+    /// Requests one queue per priority from the first queue family supporting all of the specified flags
+    /// which still has enough queues available. Requests without priorities cannot be satisfied.
+    pub fn queues_with_flags(mut self, flags: vk::QueueFlags, priorities: &[f32]) -> Self {
+        self.queue_requests.push(QueueRequest {
+            family: QueueFamilySelector::Flags(flags),
+            priorities: priorities.to_vec(),
+        });
+        self
+    }
+
+    /// Requests the queues of every required queue family from its assigned device queue family, all with
+    /// the same `priority`.
+    ///
+    /// The created queues are returned in the order of the required queue families.
+    pub fn assigned_queues(mut self, assignment: &QueueAssignment, priority: f32) -> Self {
+        for (family_index, queue_count) in assignment
+            .family_indices
+            .iter()
+            .zip(&assignment.queue_counts)
+        {
+            self = self.queues(*family_index, &vec![priority; *queue_count as usize]);
+        }
+        self
+    }
+
+    /// Appends extensions to the list of enabled extensions. Extensions required by the enabled
+    /// profiles are added by the vulkan profiles library and do not need to be listed here.
+    pub fn enabled_extension_names<'b>(
+        mut self,
+        enabled_extension_names: impl IntoIterator<Item = &'b CStr>,
+    ) -> Self {
+        self.enabled_extension_names
+            .extend(enabled_extension_names.into_iter().map(CStr::to_owned));
+        self
+    }
+
+    /// Appends extensions which are only enabled if the physical device supports them.
+    ///
+    /// See [`Self::with_supported_optional`].
+    pub fn optional_extension_names<'b>(
+        mut self,
+        optional_extension_names: impl IntoIterator<Item = &'b CStr>,
+    ) -> Self {
+        self.optional_extension_names
+            .extend(optional_extension_names.into_iter().map(CStr::to_owned));
+        self
+    }
+
+    /// Sets the [`vk::PhysicalDeviceFeatures`] passed as `pEnabledFeatures`.
+    #[inline]
+    pub fn enabled_features(mut self, enabled_features: vk::PhysicalDeviceFeatures) -> Self {
+        self.enabled_features = Some(enabled_features);
+        self
+    }
+
+    /// Appends a struct to the pNext chain of the [`vk::DeviceCreateInfo`]. The struct is copied into the
+    /// builder and its `p_next` pointer is overwritten when the chain is assembled.
+    ///
+    /// Features enabled this way are merged together with the features required by the profiles. The result
+    /// can be inspected with [`Self::preview_features`].
+    pub fn push_next<T: vk::ExtendsDeviceCreateInfo + Copy + 'static>(mut self, next: T) -> Self {
+        self.next_chain.push(Box::new(next));
+        self
+    }
+
+    /// Appends a feature struct whose enabled booleans are only enabled if the physical device supports
+    /// them. Core features can be requested with a [`vk::PhysicalDeviceFeatures2`] struct.
+    ///
+    /// Structs provided by an extension are only enabled if that extension is either required by a profile,
+    /// passed to [`Self::enabled_extension_names`] or a supported optional extension. See
+    /// [`Self::with_supported_optional`].
+    pub fn push_optional_next<T: vk::ExtendsDeviceCreateInfo + Copy + 'static>(
+        mut self,
+        next: T,
+    ) -> Self {
+        self.optional_chain.push(Box::new(next));
+        self
+    }
+
+    #[inline]
+    pub fn flags(mut self, flags: vp::DeviceCreateFlagBits) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    #[inline]
+    pub fn enabled_full_profile(mut self, profile: vp::ProfileProperties) -> Self {
+        self.enabled_full_profiles.push(profile);
+        self
+    }
+
+    /// Appends profiles to the list of enabled profiles.
+    pub fn enabled_full_profiles(mut self, profiles: &[vp::ProfileProperties]) -> Self {
+        self.enabled_full_profiles.extend_from_slice(profiles);
+        self
+    }
+
+    #[inline]
+    pub fn enabled_profile_block(mut self, block: vp::BlockProperties) -> Self {
+        self.enabled_profile_blocks.push(block);
+        self
+    }
+
+    /// Appends profile blocks to the list of enabled profile blocks.
+    pub fn enabled_profile_blocks(mut self, blocks: &[vp::BlockProperties]) -> Self {
+        self.enabled_profile_blocks.extend_from_slice(blocks);
+        self
+    }
+
+    /// Resolves all queue requests against the queue families of the physical device.
+    ///
+    /// If a request cannot be satisfied [`ProfilesError::UnsatisfiableQueueRequest`] is returned. Requests
+    /// without priorities are rejected since they would create a [`vk::DeviceQueueCreateInfo`] without queues.
+    fn resolve_queues(
+        &self,
+        queue_families: &[vk::QueueFamilyProperties],
+    ) -> ProfilesResult<ResolvedQueues> {
+        let mut families: Vec<(u32, Vec<f32>)> = Vec::new();
+        let mut placements = Vec::with_capacity(self.queue_requests.len());
+
+        for request in &self.queue_requests {
+            let used = |family_index: u32| {
+                families
+                    .iter()
+                    .find(|(index, _)| *index == family_index)
+                    .map_or(0, |(_, priorities)| priorities.len())
+            };
+            let fits = |family_index: u32| {
+                !request.priorities.is_empty()
+                    && queue_families
+                        .get(family_index as usize)
+                        .is_some_and(|family| {
+                            used(family_index) + request.priorities.len()
+                                <= family.queue_count as usize
+                        })
+            };
+
+            let (family_index, unsatisfiable) = match request.family {
+                QueueFamilySelector::Index(index) => (
+                    Some(index).filter(|index| fits(*index)),
+                    ProfilesError::UnsatisfiableQueueRequest {
+                        queue_family_index: Some(index),
+                        queue_flags: vk::QueueFlags::empty(),
+                        queue_count: request.priorities.len(),
+                    },
+                ),
+                QueueFamilySelector::Flags(flags) => (
+                    (0..queue_families.len() as u32).find(|index| {
+                        queue_families[*index as usize].queue_flags.contains(flags) && fits(*index)
+                    }),
+                    ProfilesError::UnsatisfiableQueueRequest {
+                        queue_family_index: None,
+                        queue_flags: flags,
+                        queue_count: request.priorities.len(),
+                    },
+                ),
+            };
+            let family_index = family_index.ok_or(unsatisfiable)?;
+
+            placements.push((family_index, used(family_index) as u32));
+            match families
+                .iter_mut()
+                .find(|(index, _)| *index == family_index)
+            {
+                Some((_, priorities)) => priorities.extend_from_slice(&request.priorities),
+                None => families.push((family_index, request.priorities.clone())),
+            }
+        }
+
+        Ok(ResolvedQueues {
+            placements,
+            families,
+        })
+    }
+
+    /// Assembles the create info structs for the duration of `f`.
+    fn with_create_info<R>(
+        &self,
+        queue_infos: &[vk::DeviceQueueCreateInfo],
+        f: impl FnOnce(&vp::DeviceCreateInfo) -> R,
+    ) -> R {
+        let extension_names: Vec<*const c_char> = self
+            .enabled_extension_names
+            .iter()
+            .map(|name| name.as_ptr())
+            .collect();
+
+        let mut next_chain: Vec<Box<dyn DeviceChainElement>> =
+            self.next_chain.iter().map(|e| e.clone_boxed()).collect();
+        let mut p_next: *mut vk::BaseOutStructure = std::ptr::null_mut();
+        for element in next_chain.iter_mut().rev() {
+            let base = element.as_base_mut();
+            // SAFETY: every element starts with the s_type and p_next members
+            unsafe { (*base).p_next = p_next };
+            p_next = base;
+        }
+
+        let mut device_info = vk::DeviceCreateInfo::default()
+            .queue_create_infos(queue_infos)
+            .enabled_extension_names(&extension_names);
+        if let Some(enabled_features) = &self.enabled_features {
+            device_info = device_info.enabled_features(enabled_features);
+        }
+        device_info.p_next = p_next as *const std::ffi::c_void;
+
+        let vp_device_info = vp::DeviceCreateInfo::default()
+            .create_info(&device_info)
+            .flags(self.flags)
+            .enabled_full_profiles(&self.enabled_full_profiles)
+            .enabled_profile_blocks(&self.enabled_profile_blocks);
+
+        f(&vp_device_info)
+    }
+
+    /// Returns the extensions and features the device will be created with.
+    ///
+    /// See [`preview_device_features`].
+    ///
+    /// # Safety
+    /// Pointers contained in the structs passed to [`Self::push_next`] must be valid.
+    pub unsafe fn preview_features(&self) -> ProfilesResult<EnabledDeviceFeatures> {
+        self.with_create_info(&[], |vp_device_info| {
+            preview_device_features(&self.vk_profiles, vp_device_info)
+        })
+    }
+
+    /// Checks the pNext chain built by [`Self::push_next`] and [`Self::enabled_features`] against the
+    /// enabled profiles.
+    ///
+    /// See [`validate_device_create_info`].
+    ///
+    /// # Safety
+    /// Pointers contained in the structs passed to [`Self::push_next`] must be valid. The pNext chain and
+    /// the extension names of the create info are assembled by the builder and always valid otherwise.
+    pub unsafe fn validate_chain(
+        &self,
+        api_version: Option<u32>,
+    ) -> ProfilesResult<Vec<ChainIssue>> {
+        self.with_create_info(&[], |vp_device_info| {
+            validate_device_create_info(
+                &self.vk_profiles,
+                &self.enabled_full_profiles,
+                &self.enabled_profile_blocks,
+                &*vp_device_info.p_create_info,
+                api_version,
+            )
+        })
+    }
+
+    /// Returns a builder in which all optional extensions and features supported by the physical device are
+    /// required, together with a report of the enabled and unsupported ones.
+    ///
+    /// If optional features were requested the features are queried with
+    /// `vkGetPhysicalDeviceFeatures2`.
+    ///
+    /// # Safety
+    /// `physical_device` must be a valid physical device of `instance`. If optional features were requested
+    /// the instance must have been created with vulkan 1.1 or later.
+    pub unsafe fn with_supported_optional(
+        &self,
+        instance: &impl PhysicalDeviceQuery,
+        physical_device: vk::PhysicalDevice,
+    ) -> ProfilesResult<(Self, OptionalSupport)> {
+        let mut builder = self.clone();
+        builder.optional_extension_names.clear();
+        builder.optional_chain.clear();
+        if self.optional_extension_names.is_empty() && self.optional_chain.is_empty() {
+            return Ok((builder, OptionalSupport::default()));
+        }
+
+        let device_extensions = instance
+            .enumerate_device_extension_properties(physical_device)
+            .map_err(|result| {
+                ProfilesError::vulkan(
+                    "vkEnumerateDeviceExtensionProperties",
+                    ErrorContext::default(),
+                    result,
+                )
+            })?;
+        let mut required_extensions = self.enabled_extension_names.clone();
+        for (profile, block_name) in
+            assemble::enabled_blocks(&self.enabled_full_profiles, &self.enabled_profile_blocks)
+        {
+            for extension in self
+                .vk_profiles
+                .get_profile_device_extension_properties(profile, block_name)?
+            {
+                if let Ok(name) = extension.extension_name_as_c_str() {
+                    assemble::push_unique(&mut required_extensions, name);
+                }
+            }
+        }
+
+        let supported = optional::resolve_optional(
+            instance
+                .get_physical_device_properties(physical_device)
+                .api_version,
+            &device_extensions,
+            &required_extensions,
+            &self.optional_extension_names,
+            &self.optional_chain,
+            |features| instance.get_physical_device_features2(physical_device, features),
+        );
+        for name in &supported.extensions {
+            if !required_extensions.contains(name) {
+                builder.enabled_extension_names.push(name.clone());
+            }
+        }
+        for element in supported.chain {
+            optional::merge_optional(
+                &mut builder.next_chain,
+                &mut builder.enabled_features,
+                element,
+            );
+        }
+        Ok((builder, supported.support))
+    }
+
+    /// Assembles the create info structs and creates the device.
+    ///
+    /// The returned queues are ordered in the same way they were requested. Each request returns one queue
+    /// per priority. Supported optional extensions and features are enabled, see
+    /// [`Self::build_with_optional`].
+    ///
+    /// See [`VulkanProfiles::create_device`].
+    ///
+    /// # Safety
+    /// `physical_device` must be a valid physical device of `instance`, which must have been created with
+    /// vulkan 1.1 or later if optional features were requested. `allocation_callbacks` must be valid vulkan
+    /// allocation callbacks which outlive the device. Pointers contained in the structs passed to
+    /// [`Self::push_next`] must be valid.
+    pub unsafe fn build(
+        &self,
+        instance: &ash::Instance,
+        physical_device: vk::PhysicalDevice,
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) -> ProfilesResult<(ash::Device, Vec<DeviceQueue>)> {
+        self.build_with_optional(instance, physical_device, allocation_callbacks)
+            .map(|(device, queues, _)| (device, queues))
+    }
+
+    /// Like [`Self::build`], but additionally returns which optional extensions and features were enabled.
+    ///
+    /// See [`Self::with_supported_optional`].
+    ///
+    /// # Safety
+    /// The requirements of [`Self::build`] must be met.
+    pub unsafe fn build_with_optional(
+        &self,
+        instance: &ash::Instance,
+        physical_device: vk::PhysicalDevice,
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) -> ProfilesResult<(ash::Device, Vec<DeviceQueue>, OptionalSupport)> {
+        let (builder, support) = self.with_supported_optional(instance, physical_device)?;
+        let (device, queues) =
+            builder.build_required(instance, physical_device, allocation_callbacks)?;
+        Ok((device, queues, support))
+    }
+
+    /// Creates the device with the required extensions and features only.
+    unsafe fn build_required(
+        &self,
+        instance: &ash::Instance,
+        physical_device: vk::PhysicalDevice,
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) -> ProfilesResult<(ash::Device, Vec<DeviceQueue>)> {
+        let queue_families = instance.get_physical_device_queue_family_properties(physical_device);
+        let resolved = self.resolve_queues(&queue_families)?;
+
+        let queue_infos: Vec<vk::DeviceQueueCreateInfo> = resolved
+            .families
+            .iter()
+            .map(|(family_index, priorities)| {
+                vk::DeviceQueueCreateInfo::default()
+                    .queue_family_index(*family_index)
+                    .queue_priorities(priorities)
+            })
+            .collect();
+
+        let device = self.with_create_info(&queue_infos, |vp_device_info| {
+            self.vk_profiles.create_device(
+                instance,
+                physical_device,
+                vp_device_info,
+                allocation_callbacks,
+            )
+        })?;
+
+        let queues = resolved
+            .placements
+            .iter()
+            .zip(&self.queue_requests)
+            .flat_map(|(&(family_index, first_index), request)| {
+                (0..request.priorities.len() as u32).map(move |i| (family_index, first_index + i))
+            })
+            .map(|(family_index, queue_index)| DeviceQueue {
+                family_index,
+                queue_index,
+                queue: device.get_device_queue(family_index, queue_index),
+            })
+            .collect();
+
+        Ok((device, queues))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_queues() {
+        let queue_families = [
+            vk::QueueFamilyProperties {
+                queue_flags: vk::QueueFlags::GRAPHICS | vk::QueueFlags::COMPUTE,
+                queue_count: 2,
+                ..Default::default()
+            },
+            vk::QueueFamilyProperties {
+                queue_flags: vk::QueueFlags::TRANSFER,
+                queue_count: 1,
+                ..Default::default()
+            },
+        ];
+        let builder = ProfileDeviceBuilder::new(&VulkanProfiles::linked())
+            .queues_with_flags(vk::QueueFlags::COMPUTE, &[1.0])
+            .queues(0, &[0.5])
+            .queues_with_flags(vk::QueueFlags::TRANSFER, &[1.0]);
+        let resolved = builder.resolve_queues(&queue_families).unwrap();
+        assert_eq!(resolved.placements, [(0, 0), (0, 1), (1, 0)]);
+        assert_eq!(resolved.families, [(0, vec![1.0, 0.5]), (1, vec![1.0])]);
+
+        let result = builder
+            .clone()
+            .queues(0, &[1.0])
+            .resolve_queues(&queue_families);
+        assert!(matches!(
+            result,
+            Err(ProfilesError::UnsatisfiableQueueRequest {
+                queue_family_index: Some(0),
+                queue_count: 1,
+                ..
+            })
+        ));
+
+        for builder in [
+            ProfileDeviceBuilder::new(&VulkanProfiles::linked()).queues(1, &[]),
+            ProfileDeviceBuilder::new(&VulkanProfiles::linked())
+                .queues_with_flags(vk::QueueFlags::TRANSFER, &[]),
+        ] {
+            let err = builder.resolve_queues(&queue_families).err().unwrap();
+            assert!(matches!(
+                err,
+                ProfilesError::UnsatisfiableQueueRequest { queue_count: 0, .. }
+            ));
+            assert_eq!(
+                err.to_string(),
+                "queue requests must contain at least one priority"
+            );
+        }
+    }
+}
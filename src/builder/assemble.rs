@@ -0,0 +1,675 @@
+//! Assembly of create infos with all profile requirements merged in.
+//!
+//! [`VulkanProfiles::create_instance`] and [`VulkanProfiles::create_device`] merge the requirements of the
+//! enabled profiles into the create info inside of the vulkan profiles library. The functions in this
+//! module perform the same merge through the profile query functions and return the result as owned
+//! values, which can be inspected and passed to the plain ash create functions.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::ffi::{c_char, CStr, CString};
+use std::fmt;
+
+use ash::vk;
+use serde::Serialize;
+
+use super::validate::core_features;
+use crate::json::{self, StructValue};
+use crate::reflect::{self, StructCategory, StructChain};
+use crate::vp::{self, BlockProperties, ProfileProperties};
+use crate::{profiles, ErrorContext, ProfilesError, ProfilesResult, VulkanProfiles};
+
+/// An owned [`vk::InstanceCreateInfo`] containing the requirements of all enabled profiles.
+#[derive(Clone, Default, PartialEq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct OwnedInstanceCreateInfo {
+    pub flags: vk::InstanceCreateFlags,
+    pub application_name: Option<CString>,
+    pub application_version: u32,
+    pub engine_name: Option<CString>,
+    pub engine_version: u32,
+    pub api_version: u32,
+    pub enabled_layer_names: Vec<CString>,
+    pub enabled_extension_names: Vec<CString>,
+    /// The structure types of the pNext chain of the user create info. The structs are not copied.
+    pub skipped_structure_types: Vec<vk::StructureType>,
+}
+
+impl OwnedInstanceCreateInfo {
+    /// Creates the instance with [`ash::Entry::create_instance`].
+    ///
+    /// # Safety
+    /// `allocation_callbacks` must be valid vulkan allocation callbacks which outlive the instance.
+    pub unsafe fn create_instance(
+        &self,
+        entry: &ash::Entry,
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) -> ProfilesResult<ash::Instance> {
+        let mut application_info = vk::ApplicationInfo::default()
+            .application_version(self.application_version)
+            .engine_version(self.engine_version)
+            .api_version(self.api_version);
+        if let Some(application_name) = &self.application_name {
+            application_info = application_info.application_name(application_name);
+        }
+        if let Some(engine_name) = &self.engine_name {
+            application_info = application_info.engine_name(engine_name);
+        }
+        let layer_names = name_pointers(&self.enabled_layer_names);
+        let extension_names = name_pointers(&self.enabled_extension_names);
+
+        let instance_info = vk::InstanceCreateInfo::default()
+            .flags(self.flags)
+            .application_info(&application_info)
+            .enabled_layer_names(&layer_names)
+            .enabled_extension_names(&extension_names);
+
+        entry
+            .create_instance(&instance_info, allocation_callbacks)
+            .map_err(|result| {
+                ProfilesError::vulkan("vkCreateInstance", ErrorContext::default(), result)
+            })
+    }
+}
+
+/// An owned [`vk::DeviceQueueCreateInfo`].
+#[derive(Clone, Default, PartialEq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct OwnedDeviceQueueCreateInfo {
+    pub flags: vk::DeviceQueueCreateFlags,
+    pub queue_family_index: u32,
+    pub queue_priorities: Vec<f32>,
+}
+
+/// An owned [`vk::DeviceCreateInfo`] containing the requirements of all enabled profiles.
+///
+/// All enabled features are stored in a pNext chain starting with [`vk::PhysicalDeviceFeatures2`], which
+/// contains every feature struct required by a profile or passed by the user.
+#[derive(Clone)]
+pub struct OwnedDeviceCreateInfo {
+    pub flags: vk::DeviceCreateFlags,
+    pub queue_create_infos: Vec<OwnedDeviceQueueCreateInfo>,
+    pub enabled_extension_names: Vec<CString>,
+    features: StructChain,
+}
+
+impl OwnedDeviceCreateInfo {
+    /// Returns the enabled features keyed by struct name in their JSON representation.
+    ///
+    /// Only enabled features are listed, structs without enabled features are omitted.
+    pub fn enabled_features(&self) -> BTreeMap<String, StructValue> {
+        json::chain_map(&self.features)
+    }
+
+    /// Returns true if the feature `member` of the struct `struct_name`, for example
+    /// `VkPhysicalDeviceVulkan12Features` and `timelineSemaphore`, is enabled.
+    pub fn is_feature_enabled(&self, struct_name: &str, member: &str) -> bool {
+        let Some(info) = reflect::find_struct(struct_name) else {
+            return false;
+        };
+        self.features.read().any(|(other, value)| {
+            std::ptr::eq(info, other) && value.get(member) == Some(&true.into())
+        })
+    }
+
+    /// Returns the enabled extensions and features.
+    pub fn effective_features(&self) -> EnabledDeviceFeatures {
+        EnabledDeviceFeatures {
+            extensions: self
+                .enabled_extension_names
+                .iter()
+                .map(|name| name.to_string_lossy().into_owned())
+                .collect(),
+            features: self
+                .enabled_features()
+                .into_iter()
+                .map(|(name, value)| {
+                    let members = value
+                        .into_iter()
+                        .filter(|(_, value)| *value == true)
+                        .map(|(member, _)| member)
+                        .collect();
+                    (name, members)
+                })
+                .collect(),
+        }
+    }
+
+    /// Creates the device with [`ash::Instance::create_device`].
+    ///
+    /// # Safety
+    /// `physical_device` must be a valid physical device of `instance` and `allocation_callbacks` must be
+    /// valid vulkan allocation callbacks which outlive the device.
+    pub unsafe fn create_device(
+        &self,
+        instance: &ash::Instance,
+        physical_device: vk::PhysicalDevice,
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) -> ProfilesResult<ash::Device> {
+        let queue_infos: Vec<vk::DeviceQueueCreateInfo> = self
+            .queue_create_infos
+            .iter()
+            .map(|queue_info| {
+                vk::DeviceQueueCreateInfo::default()
+                    .flags(queue_info.flags)
+                    .queue_family_index(queue_info.queue_family_index)
+                    .queue_priorities(&queue_info.queue_priorities)
+            })
+            .collect();
+        let extension_names = name_pointers(&self.enabled_extension_names);
+        let mut features = self.features.clone();
+
+        let mut device_info = vk::DeviceCreateInfo::default()
+            .flags(self.flags)
+            .queue_create_infos(&queue_infos)
+            .enabled_extension_names(&extension_names);
+        device_info.p_next = features.link() as *const std::ffi::c_void;
+
+        instance
+            .create_device(physical_device, &device_info, allocation_callbacks)
+            .map_err(|result| {
+                ProfilesError::vulkan("vkCreateDevice", ErrorContext::default(), result)
+            })
+    }
+}
+
+impl fmt::Debug for OwnedDeviceCreateInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("OwnedDeviceCreateInfo");
+        #[cfg(feature = "debug")]
+        debug
+            .field("flags", &self.flags)
+            .field("queue_create_infos", &self.queue_create_infos);
+        debug
+            .field("enabled_extension_names", &self.enabled_extension_names)
+            .field("enabled_features", &self.enabled_features())
+            .finish_non_exhaustive()
+    }
+}
+
+/// The extensions and features a device is created with.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct EnabledDeviceFeatures {
+    pub extensions: BTreeSet<String>,
+    /// The names of the enabled feature members keyed by struct name.
+    pub features: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl EnabledDeviceFeatures {
+    /// Returns true if the extension is enabled.
+    pub fn has_extension(&self, name: &str) -> bool {
+        self.extensions.contains(name)
+    }
+
+    /// Returns true if the feature `member` of the struct `struct_name`, for example
+    /// `VkPhysicalDeviceVulkan12Features` and `timelineSemaphore`, is enabled.
+    pub fn is_enabled(&self, struct_name: &str, member: &str) -> bool {
+        self.features
+            .get(struct_name)
+            .is_some_and(|members| members.contains(member))
+    }
+}
+
+impl fmt::Display for EnabledDeviceFeatures {
+    /// Writes one enabled extension or `struct.member` feature per line.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for extension in &self.extensions {
+            writeln!(f, "{extension}")?;
+        }
+        for (name, members) in &self.features {
+            for member in members {
+                writeln!(f, "{name}.{member}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns the extensions and features [`VulkanProfiles::create_device`] enables for `create_info`.
+///
+/// The merge is performed like in [`assemble_device_create_info`] and fails for the same pNext chains.
+///
+/// # Safety
+/// All pointers of `create_info` must be valid or null, including the pointers of the user create info
+/// and its pNext chain.
+pub unsafe fn preview_device_features(
+    vk_profiles: &VulkanProfiles,
+    create_info: &vp::DeviceCreateInfo,
+) -> ProfilesResult<EnabledDeviceFeatures> {
+    let user_info = create_info
+        .p_create_info
+        .as_ref()
+        .copied()
+        .unwrap_or_default();
+    let info = assemble_device_create_info(
+        vk_profiles,
+        raw_slice(
+            create_info.p_enabled_full_profiles,
+            create_info.enabled_full_profile_count,
+        ),
+        raw_slice(
+            create_info.p_enabled_profile_blocks,
+            create_info.enabled_profile_block_count,
+        ),
+        &user_info,
+        create_info.flags,
+    )?;
+    Ok(info.effective_features())
+}
+
+/// Merges the requirements of the enabled profiles and profile blocks into an instance create info.
+///
+/// Profile instance extensions are appended to the user extensions. If the user create info does not
+/// specify an api version the highest minimum api version of the enabled profiles is used, which is only
+/// known for profiles defined in [`crate::profiles`] and for profile blocks. Instances with api version 1.0
+/// additionally enable `VK_KHR_get_physical_device_properties2`.
+///
+/// # Safety
+/// The application info, the layer and extension names and the pNext chain of `user_info` must be valid
+/// or null. The profile names of `profiles` and the names of `blocks` must be null terminated.
+pub unsafe fn assemble_instance_create_info(
+    vk_profiles: &VulkanProfiles,
+    profiles: &[ProfileProperties],
+    blocks: &[BlockProperties],
+    user_info: &vk::InstanceCreateInfo,
+) -> ProfilesResult<OwnedInstanceCreateInfo> {
+    let application_info = user_info.p_application_info.as_ref();
+    let mut info = OwnedInstanceCreateInfo {
+        flags: user_info.flags,
+        application_name: application_info
+            .and_then(|info| cstr_ptr(info.p_application_name))
+            .map(CStr::to_owned),
+        application_version: application_info.map_or(0, |info| info.application_version),
+        engine_name: application_info
+            .and_then(|info| cstr_ptr(info.p_engine_name))
+            .map(CStr::to_owned),
+        engine_version: application_info.map_or(0, |info| info.engine_version),
+        api_version: application_info.map_or(0, |info| info.api_version),
+        enabled_layer_names: names(
+            user_info.pp_enabled_layer_names,
+            user_info.enabled_layer_count,
+        ),
+        enabled_extension_names: names(
+            user_info.pp_enabled_extension_names,
+            user_info.enabled_extension_count,
+        ),
+        skipped_structure_types: structure_types(user_info.p_next),
+    };
+
+    for (profile, block_name) in enabled_blocks(profiles, blocks) {
+        for extension in
+            vk_profiles.get_profile_instance_extension_properties(profile, block_name)?
+        {
+            if let Ok(name) = extension.extension_name_as_c_str() {
+                push_unique(&mut info.enabled_extension_names, name);
+            }
+        }
+    }
+
+    if info.api_version == 0 {
+        info.api_version = profiles_api_version(profiles, blocks);
+    }
+    if info.api_version < vk::API_VERSION_1_1 {
+        push_unique(
+            &mut info.enabled_extension_names,
+            ash::khr::get_physical_device_properties2::NAME,
+        );
+    }
+    Ok(info)
+}
+
+/// Merges the requirements of the enabled profiles and profile blocks into a device create info.
+///
+/// Profile device extensions are appended to the user extensions. The features of `pEnabledFeatures` and of
+/// all feature structs of the user pNext chain are combined with the features required by the profiles,
+/// after which the robust access features disabled by `flags` are removed. Promoted feature structs are
+/// merged into the `VkPhysicalDeviceVulkanXYFeatures` struct of their core version if that is also part of
+/// the chain. Queue create infos are copied without their pNext chains.
+///
+/// [`ProfilesError::UnsupportedStructure`] is returned if the user pNext chain contains a struct which is
+/// not a feature struct known to this crate, as it cannot be copied.
+///
+/// # Safety
+/// The queue create infos, extension names, `pEnabledFeatures` and the pNext chain of `user_info` must be
+/// valid or null. The profile names of `profiles` and the names of `blocks` must be null terminated.
+pub unsafe fn assemble_device_create_info(
+    vk_profiles: &VulkanProfiles,
+    profiles: &[ProfileProperties],
+    blocks: &[BlockProperties],
+    user_info: &vk::DeviceCreateInfo,
+    flags: vp::DeviceCreateFlagBits,
+) -> ProfilesResult<OwnedDeviceCreateInfo> {
+    let enabled_blocks = enabled_blocks(profiles, blocks);
+
+    let mut enabled_extension_names = names(
+        user_info.pp_enabled_extension_names,
+        user_info.enabled_extension_count,
+    );
+    let mut feature_structure_types = Vec::new();
+    for (profile, block_name) in &enabled_blocks {
+        for extension in
+            vk_profiles.get_profile_device_extension_properties(profile, *block_name)?
+        {
+            if let Ok(name) = extension.extension_name_as_c_str() {
+                push_unique(&mut enabled_extension_names, name);
+            }
+        }
+        feature_structure_types
+            .extend(vk_profiles.get_profile_feature_structure_types(profile, *block_name)?);
+    }
+    let mut features = user_features(user_info, &mut feature_structure_types)?;
+    for (profile, block_name) in &enabled_blocks {
+        let mut required = StructChain::new(
+            vk::StructureType::PHYSICAL_DEVICE_FEATURES_2,
+            &feature_structure_types,
+        );
+        vk_profiles.get_profile_features(
+            profile,
+            *block_name,
+            &mut *(required.link() as *mut vk::PhysicalDeviceFeatures2),
+        );
+        features.merge_chain(&required, core_features);
+    }
+
+    if flags.contains(vp::DeviceCreateFlagBits::DISABLE_ROBUST_BUFFER_ACCESS) {
+        features.clear_bool("robustBufferAccess");
+    }
+    if flags.contains(vp::DeviceCreateFlagBits::DISABLE_ROBUST_IMAGE_ACCESS) {
+        features.clear_bool("robustImageAccess");
+    }
+
+    let queue_infos = raw_slice(
+        user_info.p_queue_create_infos,
+        user_info.queue_create_info_count,
+    );
+    Ok(OwnedDeviceCreateInfo {
+        flags: user_info.flags,
+        queue_create_infos: queue_infos
+            .iter()
+            .map(|queue_info| OwnedDeviceQueueCreateInfo {
+                flags: queue_info.flags,
+                queue_family_index: queue_info.queue_family_index,
+                queue_priorities: raw_slice(queue_info.p_queue_priorities, queue_info.queue_count)
+                    .to_vec(),
+            })
+            .collect(),
+        enabled_extension_names,
+        features,
+    })
+}
+
+/// Copies `pEnabledFeatures` and the feature structs of the pNext chain of `user_info` into a chain which
+/// also contains the feature structs of `structure_types`. The user structure types are appended to
+/// `structure_types`.
+///
+/// A feature struct which was promoted to a core version is merged into the
+/// `VkPhysicalDeviceVulkanXYFeatures` struct of that version if the chain contains both, as they must not
+/// be combined (VUID-VkDeviceCreateInfo-pNext-02830).
+unsafe fn user_features(
+    user_info: &vk::DeviceCreateInfo,
+    structure_types: &mut Vec<vk::StructureType>,
+) -> ProfilesResult<StructChain> {
+    for structure_type in self::structure_types(user_info.p_next) {
+        let is_feature_struct = structure_type == vk::StructureType::PHYSICAL_DEVICE_FEATURES_2
+            || reflect::find_struct_by_type(structure_type)
+                .is_some_and(|info| info.category == Some(StructCategory::Features));
+        if !is_feature_struct {
+            return Err(ProfilesError::UnsupportedStructure { structure_type });
+        }
+        structure_types.push(structure_type);
+    }
+
+    let chain_structure_types: Vec<_> = structure_types
+        .iter()
+        .copied()
+        .filter(|structure_type| {
+            !reflect::find_struct_by_type(*structure_type)
+                .and_then(core_features)
+                .and_then(|core| core.structure_type)
+                .is_some_and(|core| structure_types.contains(&core))
+        })
+        .collect();
+    let mut features = StructChain::new(
+        vk::StructureType::PHYSICAL_DEVICE_FEATURES_2,
+        &chain_structure_types,
+    );
+    let root = reflect::find_struct("VkPhysicalDeviceFeatures")
+        .expect("VkPhysicalDeviceFeatures is not reflected");
+    if let Some(enabled_features) = user_info.p_enabled_features.as_ref() {
+        features.merge_bools(root, enabled_features as *const _ as *const u8);
+    }
+
+    let mut next = user_info.p_next as *const vk::BaseInStructure;
+    while let Some(base) = next.as_ref() {
+        if base.s_type == vk::StructureType::PHYSICAL_DEVICE_FEATURES_2 {
+            let features2 = &*(next as *const vk::PhysicalDeviceFeatures2);
+            features.merge_bools(root, &features2.features as *const _ as *const u8);
+        } else if let Some(info) = reflect::find_struct_by_type(base.s_type) {
+            if !features.merge_bools(info, next as *const u8) {
+                if let Some(core) = core_features(info) {
+                    features.merge_bools_by_name(core, info, next as *const u8);
+                }
+            }
+        }
+        next = base.p_next;
+    }
+    Ok(features)
+}
+
+/// Returns the highest minimum api version of the enabled profiles and profile blocks.
+///
+/// Minimum api versions are only known for profiles defined in [`crate::profiles`] and for profile blocks.
+pub(crate) fn profiles_api_version(
+    profiles: &[ProfileProperties],
+    blocks: &[BlockProperties],
+) -> u32 {
+    enabled_blocks(profiles, blocks)
+        .into_iter()
+        .filter_map(|(profile, _)| profiles::min_api_version(profile.profile_name_as_c_str().ok()?))
+        .chain(blocks.iter().map(|block| block.api_version))
+        .fold(vk::API_VERSION_1_0, u32::max)
+}
+
+/// Returns the profile and optional block name of every enabled profile and profile block.
+pub(crate) fn enabled_blocks<'a>(
+    profiles: &'a [ProfileProperties],
+    blocks: &'a [BlockProperties],
+) -> Vec<(&'a ProfileProperties, Option<&'a CStr>)> {
+    profiles
+        .iter()
+        .map(|profile| (profile, None))
+        .chain(
+            blocks.iter().filter_map(|block| {
+                Some((&block.profiles, Some(block.block_name_as_c_str().ok()?)))
+            }),
+        )
+        .collect()
+}
+
+pub(super) unsafe fn raw_slice<'a, T>(data: *const T, len: u32) -> &'a [T] {
+    if data.is_null() {
+        &[]
+    } else {
+        std::slice::from_raw_parts(data, len as usize)
+    }
+}
+
+unsafe fn cstr_ptr<'a>(ptr: *const c_char) -> Option<&'a CStr> {
+    (!ptr.is_null()).then(|| CStr::from_ptr(ptr))
+}
+
+/// Copies a list of names without duplicates.
+pub(super) unsafe fn names(names: *const *const c_char, count: u32) -> Vec<CString> {
+    let mut owned = Vec::new();
+    for name in raw_slice(names, count) {
+        if let Some(name) = cstr_ptr(*name) {
+            push_unique(&mut owned, name);
+        }
+    }
+    owned
+}
+
+pub(super) fn push_unique(names: &mut Vec<CString>, name: &CStr) {
+    if !names.iter().any(|existing| existing.as_c_str() == name) {
+        names.push(name.to_owned());
+    }
+}
+
+fn name_pointers(names: &[CString]) -> Vec<*const c_char> {
+    names.iter().map(|name| name.as_ptr()).collect()
+}
+
+/// Returns the structure types of a pNext chain.
+pub(super) unsafe fn structure_types(p_next: *const std::ffi::c_void) -> Vec<vk::StructureType> {
+    let mut structure_types = Vec::new();
+    let mut next = p_next as *const vk::BaseInStructure;
+    while let Some(base) = next.as_ref() {
+        structure_types.push(base.s_type);
+        next = base.p_next;
+    }
+    structure_types
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_assemble_device_create_info() {
+        let extension_names = [
+            ash::khr::swapchain::NAME.as_ptr(),
+            ash::khr::swapchain::NAME.as_ptr(),
+        ];
+        let enabled_features = vk::PhysicalDeviceFeatures::default()
+            .robust_buffer_access(true)
+            .wide_lines(true);
+        let mut vulkan12 = vk::PhysicalDeviceVulkan12Features::default().timeline_semaphore(true);
+        let priorities = [1.0, 0.5];
+        let queue_infos = [vk::DeviceQueueCreateInfo::default()
+            .queue_family_index(1)
+            .queue_priorities(&priorities)];
+        let user_info = vk::DeviceCreateInfo::default()
+            .queue_create_infos(&queue_infos)
+            .enabled_extension_names(&extension_names)
+            .enabled_features(&enabled_features)
+            .push_next(&mut vulkan12);
+
+        let info = unsafe {
+            assemble_device_create_info(
+                &VulkanProfiles::linked(),
+                &[],
+                &[],
+                &user_info,
+                vp::DeviceCreateFlagBits::DISABLE_ROBUST_BUFFER_ACCESS,
+            )
+            .unwrap()
+        };
+        assert_eq!(
+            info.enabled_extension_names,
+            [ash::khr::swapchain::NAME.to_owned()]
+        );
+        assert_eq!(
+            info.queue_create_infos,
+            [OwnedDeviceQueueCreateInfo {
+                flags: vk::DeviceQueueCreateFlags::empty(),
+                queue_family_index: 1,
+                queue_priorities: priorities.to_vec(),
+            }]
+        );
+        assert!(info.is_feature_enabled("VkPhysicalDeviceFeatures", "wideLines"));
+        assert!(!info.is_feature_enabled("VkPhysicalDeviceFeatures", "robustBufferAccess"));
+        let effective = info.effective_features();
+        assert!(effective.has_extension("VK_KHR_swapchain"));
+        assert!(effective.is_enabled("VkPhysicalDeviceVulkan12Features", "timelineSemaphore"));
+        assert_eq!(
+            effective.to_string(),
+            "VK_KHR_swapchain\n\
+             VkPhysicalDeviceFeatures.wideLines\n\
+             VkPhysicalDeviceVulkan12Features.timelineSemaphore\n"
+        );
+        assert_eq!(
+            serde_json::to_value(info.enabled_features()).unwrap(),
+            serde_json::json!({
+                "VkPhysicalDeviceFeatures": { "wideLines": true },
+                "VkPhysicalDeviceVulkan12Features": { "timelineSemaphore": true }
+            })
+        );
+
+        let mut device_group = vk::DeviceGroupDeviceCreateInfo::default();
+        let user_info = user_info.push_next(&mut device_group);
+        let error = unsafe {
+            assemble_device_create_info(
+                &VulkanProfiles::linked(),
+                &[],
+                &[],
+                &user_info,
+                vp::DeviceCreateFlagBits::empty(),
+            )
+            .unwrap_err()
+        };
+        assert_eq!(
+            error,
+            ProfilesError::UnsupportedStructure {
+                structure_type: vk::StructureType::DEVICE_GROUP_DEVICE_CREATE_INFO
+            }
+        );
+    }
+
+    #[test]
+    fn test_user_features_promoted() {
+        use vk::StructureType as S;
+
+        let read = |features: &StructChain| {
+            features
+                .read()
+                .map(|(info, value)| (info.name, serde_json::Value::Object(value)))
+                .collect::<Vec<_>>()
+        };
+
+        // The profile requires the core struct and the user passes the promoted struct
+        let mut descriptor_indexing =
+            vk::PhysicalDeviceDescriptorIndexingFeatures::default().runtime_descriptor_array(true);
+        let user_info = vk::DeviceCreateInfo::default().push_next(&mut descriptor_indexing);
+        let mut structure_types = vec![S::PHYSICAL_DEVICE_VULKAN_1_2_FEATURES];
+        let features = unsafe { user_features(&user_info, &mut structure_types).unwrap() };
+        assert_eq!(
+            structure_types,
+            [
+                S::PHYSICAL_DEVICE_VULKAN_1_2_FEATURES,
+                S::PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_FEATURES
+            ]
+        );
+        assert_eq!(
+            read(&features),
+            [(
+                "VkPhysicalDeviceVulkan12Features",
+                serde_json::json!({ "runtimeDescriptorArray": true })
+            )]
+        );
+
+        // The profile requires the promoted struct and the user passes the core struct
+        let mut vulkan12 = vk::PhysicalDeviceVulkan12Features::default().timeline_semaphore(true);
+        let user_info = vk::DeviceCreateInfo::default().push_next(&mut vulkan12);
+        let mut structure_types = vec![S::PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_FEATURES];
+        let mut features = unsafe { user_features(&user_info, &mut structure_types).unwrap() };
+        let mut required = StructChain::new(S::PHYSICAL_DEVICE_FEATURES_2, &structure_types);
+        let descriptor_indexing = vk::PhysicalDeviceDescriptorIndexingFeatures::default()
+            .descriptor_binding_partially_bound(true);
+        unsafe {
+            required.merge_bools(
+                reflect::find_struct("VkPhysicalDeviceDescriptorIndexingFeatures").unwrap(),
+                &descriptor_indexing as *const _ as *const u8,
+            )
+        };
+        features.merge_chain(&required, core_features);
+        assert_eq!(
+            read(&features),
+            [(
+                "VkPhysicalDeviceVulkan12Features",
+                serde_json::json!({
+                    "descriptorBindingPartiallyBound": true,
+                    "timelineSemaphore": true
+                })
+            )]
+        );
+    }
+}
@@ -0,0 +1,244 @@
+//! Optional extensions and features which are only enabled if the physical device supports them.
+
+use std::ffi::CString;
+
+use ash::vk;
+
+use super::{DeviceChainElement, EnabledDeviceFeatures};
+use crate::reflect::{self, MemberType, StructChain};
+
+/// The result of resolving optional extensions and features against a physical device.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct OptionalSupport {
+    /// The optional extensions and features which are supported and will be enabled.
+    pub enabled: EnabledDeviceFeatures,
+    /// The optional extensions and features which are not supported and stay disabled.
+    pub unsupported: EnabledDeviceFeatures,
+}
+
+/// The optional extensions and feature structs reduced to what the physical device supports.
+pub(super) struct SupportedOptional {
+    pub(super) extensions: Vec<CString>,
+    pub(super) chain: Vec<Box<dyn DeviceChainElement>>,
+    pub(super) support: OptionalSupport,
+}
+
+/// Keeps the optional extensions listed in `device_extensions` and disables every boolean of the optional
+/// feature structs which is not reported by `query_features`.
+///
+/// A feature struct is only queried if it is provided by `api_version`, by one of `required_extensions` or
+/// by one of the supported optional extensions. Otherwise all of its features are unsupported.
+pub(super) unsafe fn resolve_optional(
+    api_version: u32,
+    device_extensions: &[vk::ExtensionProperties],
+    required_extensions: &[CString],
+    optional_extensions: &[CString],
+    optional_chain: &[Box<dyn DeviceChainElement>],
+    query_features: impl FnOnce(&mut vk::PhysicalDeviceFeatures2),
+) -> SupportedOptional {
+    let mut support = OptionalSupport::default();
+    let mut extensions = Vec::new();
+    for name in optional_extensions {
+        let supported = device_extensions
+            .iter()
+            .any(|properties| properties.extension_name_as_c_str() == Ok(name.as_c_str()));
+        let report = if supported {
+            extensions.push(name.clone());
+            &mut support.enabled
+        } else {
+            &mut support.unsupported
+        };
+        report
+            .extensions
+            .insert(name.to_string_lossy().into_owned());
+    }
+
+    let extension_names: Vec<&str> = required_extensions
+        .iter()
+        .chain(&extensions)
+        .filter_map(|name| name.to_str().ok())
+        .collect();
+    let mut chain: Vec<Box<dyn DeviceChainElement>> = optional_chain
+        .iter()
+        .map(|element| element.clone_boxed())
+        .collect();
+    let structs: Vec<_> = chain
+        .iter_mut()
+        .map(|element| {
+            reflect::resolve_chain_struct(element.as_base_mut()).filter(|(info, _)| {
+                reflect::is_provided(info.providers, api_version, &extension_names)
+            })
+        })
+        .collect();
+
+    let structure_types: Vec<vk::StructureType> = structs
+        .iter()
+        .flatten()
+        .filter_map(|(info, _)| info.structure_type)
+        .collect();
+    let mut queried = StructChain::new(
+        vk::StructureType::PHYSICAL_DEVICE_FEATURES_2,
+        &structure_types,
+    );
+    if structs.iter().any(Option::is_some) {
+        query_features(&mut *(queried.link() as *mut vk::PhysicalDeviceFeatures2));
+    }
+
+    let mut supported_chain = Vec::new();
+    for (mut element, resolved) in chain.into_iter().zip(structs) {
+        let Some((info, data)) =
+            resolved.or_else(|| reflect::resolve_chain_struct(element.as_base_mut()))
+        else {
+            continue;
+        };
+        let device_data = resolved.and_then(|(info, _)| queried.get(info));
+
+        let mut any_enabled = false;
+        for member in info.members {
+            if !matches!(member.ty, MemberType::Bool32) {
+                continue;
+            }
+            let value = data.add(member.offset) as *mut vk::Bool32;
+            if value.read_unaligned() == vk::FALSE {
+                continue;
+            }
+            let supported = device_data.is_some_and(|device_data| {
+                (device_data.add(member.offset) as *const vk::Bool32).read_unaligned() != vk::FALSE
+            });
+            let report = if supported {
+                any_enabled = true;
+                &mut support.enabled
+            } else {
+                value.write_unaligned(vk::FALSE);
+                &mut support.unsupported
+            };
+            report
+                .features
+                .entry(info.name.to_string())
+                .or_default()
+                .insert(member.name.to_string());
+        }
+        if any_enabled {
+            supported_chain.push(element);
+        }
+    }
+
+    SupportedOptional {
+        extensions,
+        chain: supported_chain,
+        support,
+    }
+}
+
+/// Adds the features of a supported optional struct to the required features.
+///
+/// The booleans are merged into `enabled_features` or into a struct of the same type already part of
+/// `chain`. Otherwise the struct is appended to the chain.
+pub(super) unsafe fn merge_optional(
+    chain: &mut Vec<Box<dyn DeviceChainElement>>,
+    enabled_features: &mut Option<vk::PhysicalDeviceFeatures>,
+    mut element: Box<dyn DeviceChainElement>,
+) {
+    let base = element.as_base_mut();
+    let Some((info, data)) = reflect::resolve_chain_struct(base) else {
+        return;
+    };
+    if (*base).s_type == vk::StructureType::PHYSICAL_DEVICE_FEATURES_2 {
+        if let Some(enabled_features) = enabled_features {
+            let target = enabled_features as *mut vk::PhysicalDeviceFeatures as *mut u8;
+            reflect::or_bools(info, data, target);
+            return;
+        }
+    }
+    for existing in chain.iter_mut() {
+        let existing = existing.as_base_mut();
+        if (*existing).s_type == (*base).s_type {
+            if let Some((_, target)) = reflect::resolve_chain_struct(existing) {
+                reflect::or_bools(info, data, target);
+            }
+            return;
+        }
+    }
+    chain.push(element);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn extension(name: &std::ffi::CStr) -> vk::ExtensionProperties {
+        vk::ExtensionProperties::default()
+            .extension_name(name)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_resolve_optional() {
+        let optional_chain: Vec<Box<dyn DeviceChainElement>> = vec![
+            Box::new(
+                vk::PhysicalDeviceVulkan12Features::default()
+                    .buffer_device_address(true)
+                    .shader_float16(true),
+            ),
+            Box::new(
+                vk::PhysicalDeviceMeshShaderFeaturesEXT::default()
+                    .mesh_shader(true)
+                    .task_shader(true),
+            ),
+            Box::new(
+                vk::PhysicalDeviceFeatures2::default()
+                    .features(vk::PhysicalDeviceFeatures::default().geometry_shader(true)),
+            ),
+        ];
+        let supported = unsafe {
+            resolve_optional(
+                vk::API_VERSION_1_2,
+                &[extension(ash::khr::swapchain::NAME)],
+                &[],
+                &[
+                    ash::khr::swapchain::NAME.to_owned(),
+                    ash::ext::mesh_shader::NAME.to_owned(),
+                ],
+                &optional_chain,
+                |features| {
+                    features.features.geometry_shader = vk::TRUE;
+                    let next = &mut *(features.p_next as *mut vk::BaseOutStructure);
+                    assert_eq!(
+                        next.s_type,
+                        vk::StructureType::PHYSICAL_DEVICE_VULKAN_1_2_FEATURES
+                    );
+                    assert!(next.p_next.is_null());
+                    (*(next as *mut _ as *mut vk::PhysicalDeviceVulkan12Features))
+                        .buffer_device_address = vk::TRUE;
+                },
+            )
+        };
+
+        assert_eq!(supported.extensions, [ash::khr::swapchain::NAME.to_owned()]);
+        assert_eq!(supported.chain.len(), 2);
+        let enabled = &supported.support.enabled;
+        assert!(enabled.has_extension("VK_KHR_swapchain"));
+        assert!(enabled.is_enabled("VkPhysicalDeviceVulkan12Features", "bufferDeviceAddress"));
+        assert!(enabled.is_enabled("VkPhysicalDeviceFeatures", "geometryShader"));
+        let unsupported = &supported.support.unsupported;
+        assert!(unsupported.has_extension("VK_EXT_mesh_shader"));
+        assert!(unsupported.is_enabled("VkPhysicalDeviceVulkan12Features", "shaderFloat16"));
+        assert!(unsupported.is_enabled("VkPhysicalDeviceMeshShaderFeaturesEXT", "meshShader"));
+        assert!(unsupported.is_enabled("VkPhysicalDeviceMeshShaderFeaturesEXT", "taskShader"));
+
+        let mut chain: Vec<Box<dyn DeviceChainElement>> = vec![Box::new(
+            vk::PhysicalDeviceVulkan12Features::default().timeline_semaphore(true),
+        )];
+        let mut enabled_features = Some(vk::PhysicalDeviceFeatures::default());
+        for element in supported.chain {
+            unsafe { merge_optional(&mut chain, &mut enabled_features, element) };
+        }
+        assert_eq!(chain.len(), 1);
+        let features12 =
+            unsafe { &*(chain[0].as_base_mut() as *const vk::PhysicalDeviceVulkan12Features) };
+        assert_eq!(features12.timeline_semaphore, vk::TRUE);
+        assert_eq!(features12.buffer_device_address, vk::TRUE);
+        assert_eq!(features12.shader_float16, vk::FALSE);
+        assert_eq!(enabled_features.unwrap().geometry_shader, vk::TRUE);
+    }
+}
@@ -0,0 +1,303 @@
+//! Validation of the pNext chain of device create infos against the enabled profiles.
+
+use std::fmt;
+
+use ash::vk;
+
+use super::assemble::{enabled_blocks, names, profiles_api_version, push_unique, structure_types};
+use crate::reflect::{self, StructCategory, StructInfo};
+use crate::vp::{BlockProperties, ProfileProperties};
+use crate::{ProfilesResult, VulkanProfiles};
+
+/// A problem of a device create info which makes device creation fail or violates valid usage.
+#[derive(Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub enum ChainIssue {
+    /// A structure type appears more than once in the pNext chain.
+    Duplicate { structure_type: vk::StructureType },
+    /// `pEnabledFeatures` is set although the pNext chain contains [`vk::PhysicalDeviceFeatures2`].
+    EnabledFeaturesWithFeatures2,
+    /// A struct of the pNext chain is also provided by one of the enabled profiles.
+    ProvidedByProfile { structure_type: vk::StructureType },
+    /// A feature struct which was promoted to a core version is combined with the
+    /// `VkPhysicalDeviceVulkanXYFeatures` struct of that version.
+    CoreConflict {
+        structure_type: vk::StructureType,
+        core_structure_type: vk::StructureType,
+        /// True if the conflicting struct is provided by a profile instead of the pNext chain.
+        core_from_profile: bool,
+    },
+    /// A struct is neither part of the api version nor of an enabled extension.
+    Unavailable {
+        structure_type: vk::StructureType,
+        api_version: u32,
+    },
+}
+
+impl fmt::Display for ChainIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Duplicate { structure_type } => {
+                write!(f, "{} appears more than once", struct_name(*structure_type))
+            }
+            Self::EnabledFeaturesWithFeatures2 => write!(
+                f,
+                "pEnabledFeatures must be null if VkPhysicalDeviceFeatures2 is part of the pNext chain"
+            ),
+            Self::ProvidedByProfile { structure_type } => write!(
+                f,
+                "{} is also provided by an enabled profile",
+                struct_name(*structure_type)
+            ),
+            Self::CoreConflict {
+                structure_type,
+                core_structure_type,
+                core_from_profile,
+            } => write!(
+                f,
+                "{} must not be combined with {}{}",
+                struct_name(*structure_type),
+                struct_name(*core_structure_type),
+                if *core_from_profile {
+                    " which is provided by an enabled profile"
+                } else {
+                    ""
+                }
+            ),
+            Self::Unavailable {
+                structure_type,
+                api_version,
+            } => write!(
+                f,
+                "{} requires one of {:?} which is neither part of vulkan {}.{} nor enabled",
+                struct_name(*structure_type),
+                reflect::find_struct_by_type(*structure_type).map_or(&[][..], |info| info.providers),
+                vk::api_version_major(*api_version),
+                vk::api_version_minor(*api_version),
+            ),
+        }
+    }
+}
+
+fn struct_name(structure_type: vk::StructureType) -> String {
+    match reflect::find_struct_by_type(structure_type) {
+        Some(info) => info.name.to_string(),
+        None if structure_type == vk::StructureType::PHYSICAL_DEVICE_FEATURES_2 => {
+            "VkPhysicalDeviceFeatures2".to_string()
+        }
+        None => format!("structure type {}", structure_type.as_raw()),
+    }
+}
+
+/// Checks the pNext chain of `user_info` against the feature structs required by the enabled profiles and
+/// profile blocks before the device is created with them.
+///
+/// Structs are checked against `api_version` or, if it is [`None`], the api version returned by
+/// [`assemble_instance_create_info`](super::assemble_instance_create_info) for the same profiles, and
+/// against the user and profile device extensions. Promoted feature structs conflict with the
+/// `VkPhysicalDeviceVulkanXYFeatures` struct of their core version if either of them is part of the user
+/// chain.
+///
+/// # Safety
+/// `user_info.p_next` must be null or a valid pNext chain and `user_info.pp_enabled_extension_names` must
+/// be null or point to `enabled_extension_count` valid null terminated strings. The profile names of
+/// `profiles` and the names of `blocks` must be null terminated.
+pub unsafe fn validate_device_create_info(
+    vk_profiles: &VulkanProfiles,
+    profiles: &[ProfileProperties],
+    blocks: &[BlockProperties],
+    user_info: &vk::DeviceCreateInfo,
+    api_version: Option<u32>,
+) -> ProfilesResult<Vec<ChainIssue>> {
+    let api_version = api_version.unwrap_or_else(|| profiles_api_version(profiles, blocks));
+
+    let mut extensions = names(
+        user_info.pp_enabled_extension_names,
+        user_info.enabled_extension_count,
+    );
+    let mut profile_types = Vec::new();
+    for (profile, block_name) in enabled_blocks(profiles, blocks) {
+        for extension in vk_profiles.get_profile_device_extension_properties(profile, block_name)? {
+            if let Ok(name) = extension.extension_name_as_c_str() {
+                push_unique(&mut extensions, name);
+            }
+        }
+        for structure_type in
+            vk_profiles.get_profile_feature_structure_types(profile, block_name)?
+        {
+            if !profile_types.contains(&structure_type) {
+                profile_types.push(structure_type);
+            }
+        }
+    }
+    let extensions: Vec<&str> = extensions
+        .iter()
+        .filter_map(|name| name.to_str().ok())
+        .collect();
+
+    Ok(validate_chain(
+        &structure_types(user_info.p_next),
+        !user_info.p_enabled_features.is_null(),
+        &profile_types,
+        api_version,
+        &extensions,
+    ))
+}
+
+/// Checks the structure types of a pNext chain against the structure types provided by profiles.
+pub(super) fn validate_chain(
+    user_types: &[vk::StructureType],
+    has_enabled_features: bool,
+    profile_types: &[vk::StructureType],
+    api_version: u32,
+    extensions: &[&str],
+) -> Vec<ChainIssue> {
+    let mut issues = Vec::new();
+    for (index, structure_type) in user_types.iter().enumerate() {
+        let previous = user_types[..index]
+            .iter()
+            .filter(|other| *other == structure_type)
+            .count();
+        if previous > 0 {
+            // Every duplicated struct is only reported once
+            if previous == 1 {
+                issues.push(ChainIssue::Duplicate {
+                    structure_type: *structure_type,
+                });
+            }
+            continue;
+        }
+        if *structure_type == vk::StructureType::PHYSICAL_DEVICE_FEATURES_2 && has_enabled_features
+        {
+            issues.push(ChainIssue::EnabledFeaturesWithFeatures2);
+        }
+        if profile_types.contains(structure_type) {
+            issues.push(ChainIssue::ProvidedByProfile {
+                structure_type: *structure_type,
+            });
+        }
+
+        let Some(info) = reflect::find_struct_by_type(*structure_type) else {
+            continue;
+        };
+        if !reflect::is_provided(info.providers, api_version, extensions) {
+            issues.push(ChainIssue::Unavailable {
+                structure_type: *structure_type,
+                api_version,
+            });
+        }
+    }
+
+    // Conflicts are reported once per pair, from the point of view of the promoted struct
+    let all_types = user_types
+        .iter()
+        .enumerate()
+        .filter(|(index, structure_type)| !user_types[..*index].contains(structure_type))
+        .map(|(_, structure_type)| (structure_type, false));
+    let all_types = all_types.chain(
+        profile_types
+            .iter()
+            .filter(|structure_type| !user_types.contains(structure_type))
+            .map(|structure_type| (structure_type, true)),
+    );
+    for (structure_type, from_profile) in all_types.clone() {
+        let Some(core) = reflect::find_struct_by_type(*structure_type).and_then(core_features)
+        else {
+            continue;
+        };
+        let core_structure_type = core.structure_type.unwrap();
+        let Some((_, core_from_profile)) = all_types
+            .clone()
+            .find(|(other, _)| **other == core_structure_type)
+        else {
+            continue;
+        };
+        if !(from_profile && core_from_profile) {
+            issues.push(ChainIssue::CoreConflict {
+                structure_type: *structure_type,
+                core_structure_type,
+                core_from_profile,
+            });
+        }
+    }
+    issues
+}
+
+/// Returns the `VkPhysicalDeviceVulkanXYFeatures` struct containing the features of a feature struct which
+/// was promoted to core version X.Y.
+pub(super) fn core_features(info: &'static StructInfo) -> Option<&'static StructInfo> {
+    if info.category != Some(StructCategory::Features) || is_core_features(info) {
+        return None;
+    }
+    info.providers.iter().find_map(|provider| {
+        let version = provider.strip_prefix("VK_VERSION_")?.replace('_', "");
+        reflect::find_struct(&format!("VkPhysicalDeviceVulkan{version}Features"))
+    })
+}
+
+fn is_core_features(info: &StructInfo) -> bool {
+    info.name
+        .strip_prefix("VkPhysicalDeviceVulkan")
+        .and_then(|name| name.strip_suffix("Features"))
+        .is_some_and(|version| version.bytes().all(|byte| byte.is_ascii_digit()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_chain() {
+        use vk::StructureType as S;
+
+        let issues = validate_chain(
+            &[
+                S::PHYSICAL_DEVICE_VULKAN_1_2_FEATURES,
+                S::PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_FEATURES,
+                S::PHYSICAL_DEVICE_FEATURES_2,
+                S::PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_FEATURES,
+                S::PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_FEATURES,
+                S::PHYSICAL_DEVICE_MESH_SHADER_FEATURES_EXT,
+            ],
+            true,
+            &[
+                S::PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_FEATURES,
+                S::PHYSICAL_DEVICE_VULKAN_1_3_FEATURES,
+                S::PHYSICAL_DEVICE_DYNAMIC_RENDERING_FEATURES,
+            ],
+            vk::API_VERSION_1_2,
+            &[],
+        );
+        assert_eq!(
+            issues,
+            [
+                ChainIssue::EnabledFeaturesWithFeatures2,
+                ChainIssue::ProvidedByProfile {
+                    structure_type: S::PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_FEATURES
+                },
+                ChainIssue::Duplicate {
+                    structure_type: S::PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_FEATURES
+                },
+                ChainIssue::Unavailable {
+                    structure_type: S::PHYSICAL_DEVICE_MESH_SHADER_FEATURES_EXT,
+                    api_version: vk::API_VERSION_1_2,
+                },
+                ChainIssue::CoreConflict {
+                    structure_type: S::PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_FEATURES,
+                    core_structure_type: S::PHYSICAL_DEVICE_VULKAN_1_2_FEATURES,
+                    core_from_profile: false,
+                },
+                ChainIssue::CoreConflict {
+                    structure_type: S::PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_FEATURES,
+                    core_structure_type: S::PHYSICAL_DEVICE_VULKAN_1_2_FEATURES,
+                    core_from_profile: false,
+                },
+            ]
+        );
+        assert_eq!(
+            issues[4].to_string(),
+            "VkPhysicalDeviceDescriptorIndexingFeatures must not be combined with \
+             VkPhysicalDeviceVulkan12Features"
+        );
+    }
+}
@@ -0,0 +1,744 @@
+//! Offline device capabilities which can be checked against profiles without a vulkan driver.
+//!
+//! [`DeviceCapabilities`] can be captured from a live physical device with [`capture_physical_device`] or
+//! parsed from the reports written by `vulkaninfo --json`, which use the profile JSON format with a single
+//! `VP_VULKANINFO_*` profile. A device can then be checked against library
+//! profiles and profiles of JSON documents with the same rules as
+//! [`VulkanProfiles::get_physical_device_profile_support`](crate::VulkanProfiles::get_physical_device_profile_support).
+//! [`FleetCoverage`] aggregates these checks over many devices and [`common_profile`] derives a profile
+//! from the capabilities shared by a set of devices. The per member comparison rules are exposed through
+//! [`member_comparison`] and [`value_satisfies`].
+//!
+//! ```no_run
+//! use vk_profiles_rs::capabilities::DeviceCapabilities;
+//! use vk_profiles_rs::{profiles, VulkanProfiles};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let report = std::fs::read_to_string("device.json")?;
+//! let device = DeviceCapabilities::from_vulkaninfo_json(&report)?;
+//!
+//! let support = unsafe {
+//!     device.check_library_profile(
+//!         &VulkanProfiles::linked(),
+//!         &profiles::KhrRoadmap2022::profile_properties(),
+//!     )?
+//! };
+//! for failure in support.failures() {
+//!     println!("{failure}");
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use ash::vk;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::json::{self, Capability, JsonError, ProfilesDocument, StructValue};
+use crate::queue_family::{self, OwnedQueueFamilyProperties, QueueAssignment};
+use crate::reflect::{self, LimitType, MemberType, StructInfo};
+use crate::vp::ProfileProperties;
+use crate::{profiles, ProfilesResult, VulkanProfiles};
+use compare::{limit_type, member_satisfied, nested_limit_type};
+
+mod capture;
+mod common;
+mod compare;
+mod coverage;
+
+pub use capture::{capture_physical_device, PhysicalDeviceQuery};
+pub(crate) use common::intersect_capabilities;
+pub use common::{common_capability, common_profile};
+pub use compare::{member_comparison, value_satisfies, Comparison};
+pub use coverage::{
+    covering_profiles, Coverage, FleetCoverage, ProfileCoverage, RequirementFailures,
+};
+
+/// Everything a physical device reports which can be required by a profile.
+///
+/// Device extensions, features, properties, formats and queue families are stored like in a profile
+/// capability block, so a device can be written to and read from JSON.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeviceCapabilities {
+    pub device_name: String,
+    /// The api version of the device in the form `major.minor.patch`.
+    pub api_version: String,
+    #[serde(flatten)]
+    pub capability: Capability,
+}
+
+impl DeviceCapabilities {
+    /// Parses a report written by `vulkaninfo --json`.
+    pub fn from_vulkaninfo_json(source: &str) -> Result<Self, JsonError> {
+        Self::from_vulkaninfo(&source.parse()?)
+    }
+
+    /// Extracts the device from a parsed `vulkaninfo --json` report.
+    ///
+    /// The report must contain a profile whose name starts with `VP_VULKANINFO`. If the device name or api
+    /// version are not part of the reported `VkPhysicalDeviceProperties` they are taken from the label and
+    /// api version of that profile.
+    pub fn from_vulkaninfo(document: &ProfilesDocument) -> Result<Self, JsonError> {
+        let (profile_name, profile) = document
+            .profiles
+            .iter()
+            .find(|(name, _)| name.starts_with("VP_VULKANINFO"))
+            .ok_or_else(|| JsonError::UnknownProfile("VP_VULKANINFO_*".to_string()))?;
+
+        let mut capability = Capability::default();
+        for block in document.profile_capabilities(profile_name, None)? {
+            capability.extensions.extend(block.extensions.clone());
+            capability.features.extend(block.features.clone());
+            capability.properties.extend(block.properties.clone());
+            capability.formats.extend(block.formats.clone());
+            capability
+                .queue_families_properties
+                .extend(block.queue_families_properties.clone());
+        }
+
+        let properties = capability.properties.get("VkPhysicalDeviceProperties");
+        let device_name = properties
+            .and_then(|properties| properties.get("deviceName"))
+            .and_then(Value::as_str)
+            .map_or_else(|| profile.label.clone(), str::to_string);
+        let api_version = properties
+            .and_then(|properties| properties.get("apiVersion"))
+            .and_then(Value::as_u64)
+            .map_or_else(
+                || profile.api_version.clone(),
+                |api_version| json::api_version_string(api_version as u32),
+            );
+
+        Ok(Self {
+            device_name,
+            api_version,
+            capability,
+        })
+    }
+
+    /// Parses [`Self::api_version`] into a packed vulkan api version.
+    pub fn api_version(&self) -> Option<u32> {
+        json::parse_api_version(&self.api_version)
+    }
+
+    /// Checks the device against the requirements of a capability block.
+    ///
+    /// If `api_version` is specified the device must support at least that version. Requirements of
+    /// structs unknown to this crate are ignored.
+    pub fn check(&self, api_version: Option<u32>, required: &Capability) -> SupportReport {
+        let mut report = SupportReport::default();
+        if let Some(api_version) = api_version {
+            report.checks.push(self.check_api_version(api_version));
+        }
+        self.check_capability(None, required, &mut report.checks);
+        report
+    }
+
+    /// Checks the device against a profile of the linked vulkan profiles library.
+    ///
+    /// The minimum api version is only checked for profiles defined in [`crate::profiles`].
+    ///
+    /// # Safety
+    /// The profile name of `profile` must be null terminated.
+    pub unsafe fn check_library_profile(
+        &self,
+        vk_profiles: &VulkanProfiles,
+        profile: &ProfileProperties,
+    ) -> ProfilesResult<SupportReport> {
+        let required = Capability::from_library(vk_profiles, profile, None)?;
+        let api_version = profile
+            .profile_name_as_c_str()
+            .ok()
+            .and_then(profiles::min_api_version);
+        Ok(self.check(api_version, &required))
+    }
+
+    /// Checks the device against a profile of a JSON document.
+    ///
+    /// Required profiles are checked as well. For lists of alternative capability blocks the checks of the
+    /// first supported alternative are reported, or the checks of all alternatives if none is supported.
+    pub fn check_document_profile(
+        &self,
+        document: &ProfilesDocument,
+        profile_name: &str,
+    ) -> Result<SupportReport, JsonError> {
+        let mut report = SupportReport::default();
+        for required in document.required_profile_names(profile_name)? {
+            self.check_document_profile_into(document, required, &mut report.checks)?;
+        }
+        self.check_document_profile_into(document, profile_name, &mut report.checks)?;
+        Ok(report)
+    }
+
+    /// Checks the requirements of a single profile of a document, ignoring its required profiles.
+    fn check_document_profile_into(
+        &self,
+        document: &ProfilesDocument,
+        profile_name: &str,
+        checks: &mut Vec<RequirementCheck>,
+    ) -> Result<(), JsonError> {
+        let profile = document.profile(profile_name)?;
+        if let Some(api_version) = profile.api_version() {
+            checks.push(self.check_api_version(api_version));
+        }
+
+        for reference in &profile.capabilities {
+            let mut alternatives = Vec::new();
+            for name in reference.names() {
+                let capability = document
+                    .capabilities
+                    .get(name)
+                    .ok_or_else(|| JsonError::UnknownCapability(name.clone()))?;
+                let mut block_checks = Vec::new();
+                self.check_capability(Some(name), capability, &mut block_checks);
+                alternatives.push(block_checks);
+            }
+
+            match alternatives
+                .iter()
+                .position(|block_checks| block_checks.iter().all(|check| check.satisfied))
+            {
+                Some(index) => checks.append(&mut alternatives[index]),
+                None => checks.extend(alternatives.into_iter().flatten()),
+            }
+        }
+        Ok(())
+    }
+
+    fn check_api_version(&self, required: u32) -> RequirementCheck {
+        // The variant bits are not part of the comparison
+        let actual = self.api_version().map(|version| version & 0x1FFF_FFFF);
+        RequirementCheck {
+            kind: RequirementKind::ApiVersion,
+            block: None,
+            name: "apiVersion".to_string(),
+            required: json::api_version_string(required).into(),
+            actual: actual.map(|version| json::api_version_string(version).into()),
+            satisfied: actual.is_some_and(|version| version >= required & 0x1FFF_FFFF),
+        }
+    }
+
+    fn check_capability(
+        &self,
+        block: Option<&String>,
+        required: &Capability,
+        checks: &mut Vec<RequirementCheck>,
+    ) {
+        let mut push = |kind, name: String, required: Value, actual: Option<Value>, satisfied| {
+            checks.push(RequirementCheck {
+                kind,
+                block: block.cloned(),
+                name,
+                required,
+                actual,
+                satisfied,
+            })
+        };
+
+        let device_extensions: BTreeMap<_, _> = self.capability.device_extensions().collect();
+        for (name, spec_version) in required.device_extensions() {
+            // Like the library only the extension name is compared
+            let actual = device_extensions.get(name);
+            push(
+                RequirementKind::Extension,
+                name.clone(),
+                (*spec_version).into(),
+                actual.map(|spec_version| (**spec_version).into()),
+                actual.is_some(),
+            );
+        }
+
+        for (kind, required, device) in [
+            (
+                RequirementKind::Feature,
+                &required.features,
+                &self.capability.features,
+            ),
+            (
+                RequirementKind::Property,
+                &required.properties,
+                &self.capability.properties,
+            ),
+        ] {
+            for (name, value) in required {
+                let Some(info) = reflect::find_struct(name) else {
+                    continue;
+                };
+                let required = struct_storage(info, Some(value));
+                let device = device
+                    .iter()
+                    .find(|(name, _)| {
+                        reflect::find_struct(name).is_some_and(|other| std::ptr::eq(info, other))
+                    })
+                    .map(|(_, value)| struct_storage(info, Some(value)));
+
+                // SAFETY: both storages contain an instance of the struct described by info
+                unsafe {
+                    compare_struct(
+                        info,
+                        None,
+                        info.name,
+                        required.as_ptr() as *const u8,
+                        device.as_ref().map(|device| device.as_ptr() as *const u8),
+                        &mut |name, required, actual, satisfied| {
+                            push(kind, name, required, actual, satisfied)
+                        },
+                    )
+                };
+            }
+        }
+
+        let feature_flags = reflect::find_flags("VkFormatFeatureFlags2")
+            .expect("VkFormatFeatureFlags2 is not reflected");
+        let flag_names = |flags: vk::FormatFeatureFlags2| -> Value {
+            feature_flags.names_of(flags.as_raw()).into()
+        };
+        for (format_name, structs) in &required.formats {
+            let Ok(required) = json::format_requirement(structs, true) else {
+                continue;
+            };
+            let device = self
+                .capability
+                .formats
+                .get(format_name)
+                .map(|structs| json::format_requirement(structs, true).unwrap_or_default());
+            for (member, required, actual) in [
+                (
+                    "linearTilingFeatures",
+                    required.linear_tiling_features,
+                    device.map(|device| device.linear_tiling_features),
+                ),
+                (
+                    "optimalTilingFeatures",
+                    required.optimal_tiling_features,
+                    device.map(|device| device.optimal_tiling_features),
+                ),
+                (
+                    "bufferFeatures",
+                    required.buffer_features,
+                    device.map(|device| device.buffer_features),
+                ),
+            ] {
+                if !required.is_empty() {
+                    push(
+                        RequirementKind::Format,
+                        format!("{format_name}.{member}"),
+                        flag_names(required),
+                        actual.map(flag_names),
+                        actual.is_some_and(|actual| actual.contains(required)),
+                    );
+                }
+            }
+        }
+
+        let device_families = self.queue_families();
+        for (index, family) in required.queue_families_properties.iter().enumerate() {
+            let Ok(required) = json::queue_family_properties(family, true) else {
+                continue;
+            };
+            let matching: Vec<Value> = device_families
+                .iter()
+                .enumerate()
+                .filter(|(_, device)| device.satisfies(&required))
+                .map(|(index, _)| index.into())
+                .collect();
+            let satisfied = !matching.is_empty();
+            push(
+                RequirementKind::QueueFamily,
+                format!("queueFamiliesProperties[{index}]"),
+                serde_json::to_value(family).unwrap_or_default(),
+                satisfied.then(|| matching.into()),
+                satisfied,
+            );
+        }
+    }
+
+    /// Assigns a queue family of the device to each queue family required by a capability block.
+    ///
+    /// Unknown structs and members of the required queue families are ignored. See
+    /// [`queue_family::assign_queue_families`].
+    pub fn assign_queue_families(&self, required: &Capability) -> Option<QueueAssignment> {
+        let required_families: Vec<_> = required
+            .queue_families_properties
+            .iter()
+            .map(|family| json::queue_family_properties(family, true).unwrap_or_default())
+            .collect();
+        queue_family::assign_queue_families(&required_families, &self.queue_families())
+    }
+
+    /// Returns the queue families of the device.
+    ///
+    /// Unknown structs and members are ignored.
+    pub fn queue_families(&self) -> Vec<OwnedQueueFamilyProperties> {
+        self.capability
+            .queue_families_properties
+            .iter()
+            .map(|family| json::queue_family_properties(family, true).unwrap_or_default())
+            .collect()
+    }
+}
+
+/// The result of checking a device against all requirements of a profile.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SupportReport {
+    pub checks: Vec<RequirementCheck>,
+}
+
+impl SupportReport {
+    /// Returns true if all requirements are satisfied.
+    pub fn is_supported(&self) -> bool {
+        self.checks.iter().all(|check| check.satisfied)
+    }
+
+    /// Returns all requirements which are not satisfied.
+    pub fn failures(&self) -> impl Iterator<Item = &RequirementCheck> {
+        self.checks.iter().filter(|check| !check.satisfied)
+    }
+}
+
+impl fmt::Display for SupportReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let failures = self.failures().count();
+        if failures == 0 {
+            return write!(f, "all {} requirements are satisfied", self.checks.len());
+        }
+        write!(
+            f,
+            "{failures} of {} requirements are not satisfied",
+            self.checks.len()
+        )?;
+        for failure in self.failures() {
+            write!(f, "\n  {failure}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The kind of a profile requirement.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum RequirementKind {
+    ApiVersion,
+    Extension,
+    Feature,
+    Property,
+    Format,
+    QueueFamily,
+}
+
+/// The result of checking a single profile requirement.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RequirementCheck {
+    pub kind: RequirementKind,
+    /// The capability block the requirement is part of, if known.
+    pub block: Option<String>,
+    /// The name of the requirement, for example `VkPhysicalDeviceProperties.limits.maxImageDimension2D`,
+    /// `VK_KHR_swapchain` or `VK_FORMAT_R8G8B8A8_UNORM.optimalTilingFeatures`.
+    pub name: String,
+    /// The required value in its JSON representation.
+    pub required: Value,
+    /// The value reported by the device or [`None`] if the device does not report it.
+    pub actual: Option<Value>,
+    pub satisfied: bool,
+}
+
+impl fmt::Display for RequirementCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} {}", self.kind, self.name)?;
+        if let Some(block) = &self.block {
+            write!(f, " (block {block})")?;
+        }
+        write!(f, ": required {}", self.required)?;
+        match &self.actual {
+            Some(actual) => write!(f, ", found {actual}"),
+            None => write!(f, ", not reported"),
+        }
+    }
+}
+
+/// Writes a JSON struct value into zero initialized storage for the struct described by `info`.
+fn struct_storage(info: &'static StructInfo, value: Option<&StructValue>) -> Box<[u64]> {
+    let mut storage = reflect::zeroed_storage(info.size);
+    if let Some(value) = value {
+        // SAFETY: the storage is large enough and zero is a valid value for all members
+        unsafe { reflect::write_struct_lenient(info, value, storage.as_mut_ptr() as *mut u8) };
+    }
+    storage
+}
+
+/// Compares all members which are not zero in `required` against `device`.
+unsafe fn compare_struct(
+    info: &'static StructInfo,
+    parent: Option<LimitType>,
+    path: &str,
+    required: *const u8,
+    device: Option<*const u8>,
+    push: &mut dyn FnMut(String, Value, Option<Value>, bool),
+) {
+    for member in info.members {
+        let required = required.add(member.offset);
+        if reflect::is_zero(required, member.ty.size()) {
+            continue;
+        }
+        let name = format!("{path}.{}", member.name);
+        let actual = device.map(|device| device.add(member.offset));
+
+        let limit_type = limit_type(info, member, parent);
+        if let MemberType::Struct(nested) = member.ty {
+            let parent = nested_limit_type(limit_type);
+            compare_struct(nested, parent, &name, required, actual, push);
+            continue;
+        }
+        let Some(satisfied) = actual.map_or(Some(false), |actual| {
+            member_satisfied(limit_type, &member.ty, required, actual)
+        }) else {
+            continue;
+        };
+        push(
+            name,
+            reflect::read_member(&member.ty, required).unwrap_or_default(),
+            actual.and_then(|actual| reflect::read_member(&member.ty, actual)),
+            satisfied,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REPORT: &str = r#"{
+        "$schema": "https://schema.khronos.org/vulkan/profiles-0.8.2-281.json#",
+        "capabilities": {
+            "device": {
+                "extensions": { "VK_KHR_swapchain": 70, "VK_KHR_maintenance4": 2 },
+                "features": {
+                    "VkPhysicalDeviceFeatures": { "samplerAnisotropy": true, "wideLines": false },
+                    "VkPhysicalDeviceVulkan12Features": { "timelineSemaphore": true, "someFutureFeature": true }
+                },
+                "properties": {
+                    "VkPhysicalDeviceProperties": {
+                        "apiVersion": 4206830,
+                        "deviceName": "Test GPU",
+                        "limits": {
+                            "maxImageDimension2D": 16384,
+                            "minUniformBufferOffsetAlignment": 64,
+                            "pointSizeRange": [1.0, 64.0],
+                            "framebufferColorSampleCounts": ["VK_SAMPLE_COUNT_1_BIT", "VK_SAMPLE_COUNT_2_BIT"]
+                        }
+                    }
+                },
+                "formats": {
+                    "VK_FORMAT_R8G8B8A8_UNORM": {
+                        "VkFormatProperties": { "optimalTilingFeatures": ["VK_FORMAT_FEATURE_SAMPLED_IMAGE_BIT"] }
+                    }
+                },
+                "queueFamiliesProperties": [
+                    {
+                        "VkQueueFamilyProperties": { "queueFlags": ["VK_QUEUE_GRAPHICS_BIT", "VK_QUEUE_COMPUTE_BIT"], "queueCount": 16 },
+                        "VkQueueFamilyGlobalPriorityPropertiesKHR": { "priorityCount": 1, "priorities": ["VK_QUEUE_GLOBAL_PRIORITY_MEDIUM_KHR"] }
+                    }
+                ]
+            }
+        },
+        "profiles": {
+            "VP_VULKANINFO_Test_GPU_1_0": {
+                "version": 1,
+                "api-version": "1.3.250",
+                "label": "VulkanInfo-generated profile",
+                "description": "Exported from vulkaninfo",
+                "capabilities": ["device"]
+            }
+        }
+    }"#;
+
+    const PROFILE: &str = r#"{
+        "$schema": "https://schema.khronos.org/vulkan/profiles-0.8.2-281.json#",
+        "capabilities": {
+            "baseline": {
+                "extensions": { "VK_KHR_swapchain": 70 },
+                "features": {
+                    "VkPhysicalDeviceFeatures": { "samplerAnisotropy": true },
+                    "VkPhysicalDeviceVulkan12Features": { "timelineSemaphore": true }
+                },
+                "properties": {
+                    "VkPhysicalDeviceProperties": {
+                        "limits": {
+                            "maxImageDimension2D": 8192,
+                            "minUniformBufferOffsetAlignment": 256,
+                            "pointSizeRange": [1.0, 64.0],
+                            "framebufferColorSampleCounts": ["VK_SAMPLE_COUNT_1_BIT"]
+                        }
+                    }
+                },
+                "queueFamiliesProperties": [
+                    { "VkQueueFamilyProperties": { "queueFlags": ["VK_QUEUE_GRAPHICS_BIT"], "queueCount": 1 } }
+                ]
+            },
+            "wide_lines": { "features": { "VkPhysicalDeviceFeatures": { "wideLines": true } } },
+            "storage": {
+                "extensions": { "VK_EXT_robustness2": 1 },
+                "formats": {
+                    "VK_FORMAT_R8G8B8A8_UNORM": {
+                        "VkFormatProperties3": { "optimalTilingFeatures": ["VK_FORMAT_FEATURE_2_STORAGE_IMAGE_BIT"] }
+                    }
+                }
+            }
+        },
+        "profiles": {
+            "VP_TEST_baseline": {
+                "version": 1,
+                "api-version": "1.3.204",
+                "label": "Baseline",
+                "description": "Baseline",
+                "capabilities": ["baseline", ["wide_lines", "baseline"]]
+            },
+            "VP_TEST_storage": {
+                "version": 1,
+                "api-version": "1.3.204",
+                "label": "Storage",
+                "description": "Storage",
+                "profiles": ["VP_TEST_baseline"],
+                "capabilities": ["storage"]
+            },
+            "VP_TEST_future": {
+                "version": 1,
+                "api-version": "1.4.0",
+                "label": "Future",
+                "description": "Future",
+                "capabilities": ["baseline"]
+            }
+        }
+    }"#;
+
+    #[test]
+    fn test_vulkaninfo_report() {
+        let device = DeviceCapabilities::from_vulkaninfo_json(REPORT).unwrap();
+        assert_eq!(device.device_name, "Test GPU");
+        assert_eq!(
+            device.api_version(),
+            Some(vk::make_api_version(0, 1, 3, 238))
+        );
+
+        let families = device.queue_families();
+        assert_eq!(families.len(), 1);
+        assert_eq!(families[0].properties.queue_count, 16);
+        assert_eq!(families[0].global_priority.unwrap().priority_count, 1);
+
+        let required: Capability = serde_json::from_str(
+            r#"{ "queueFamiliesProperties": [
+                { "VkQueueFamilyProperties": { "queueFlags": ["VK_QUEUE_GRAPHICS_BIT"], "queueCount": 1 } },
+                { "VkQueueFamilyProperties": { "queueFlags": ["VK_QUEUE_COMPUTE_BIT"], "queueCount": 4 } }
+            ] }"#,
+        )
+        .unwrap();
+        let assignment = device.assign_queue_families(&required).unwrap();
+        assert_eq!(assignment.family_indices, [0, 0]);
+        assert_eq!(assignment.queue_counts, [1, 4]);
+
+        let serialized = serde_json::to_string(&device).unwrap();
+        assert_eq!(
+            serde_json::from_str::<DeviceCapabilities>(&serialized).unwrap(),
+            device
+        );
+    }
+
+    #[test]
+    fn test_check_document_profile() {
+        let device = DeviceCapabilities::from_vulkaninfo_json(REPORT).unwrap();
+        let document: ProfilesDocument = PROFILE.parse().unwrap();
+
+        let baseline = device
+            .check_document_profile(&document, "VP_TEST_baseline")
+            .unwrap();
+        assert!(baseline.is_supported(), "{baseline}");
+        assert!(baseline
+            .checks
+            .iter()
+            .any(|check| check.name == "VkPhysicalDeviceProperties.limits.pointSizeRange"));
+
+        let storage = device
+            .check_document_profile(&document, "VP_TEST_storage")
+            .unwrap();
+        let failures: Vec<_> = storage
+            .failures()
+            .map(|check| (check.kind, check.name.as_str()))
+            .collect();
+        assert_eq!(
+            failures,
+            [
+                (RequirementKind::Extension, "VK_EXT_robustness2"),
+                (
+                    RequirementKind::Format,
+                    "VK_FORMAT_R8G8B8A8_UNORM.optimalTilingFeatures"
+                ),
+            ]
+        );
+
+        let future = device
+            .check_document_profile(&document, "VP_TEST_future")
+            .unwrap();
+        assert_eq!(
+            future
+                .failures()
+                .map(|check| check.kind)
+                .collect::<Vec<_>>(),
+            [RequirementKind::ApiVersion]
+        );
+
+        let recursive: ProfilesDocument = PROFILE
+            .replace(
+                r#""capabilities": ["baseline", ["wide_lines", "baseline"]]"#,
+                r#""capabilities": ["baseline"], "profiles": ["VP_TEST_storage"]"#,
+            )
+            .parse()
+            .unwrap();
+        assert!(matches!(
+            device.check_document_profile(&recursive, "VP_TEST_storage"),
+            Err(JsonError::RecursiveProfile(name)) if name == "VP_TEST_storage"
+        ));
+    }
+
+    #[test]
+    fn test_check_limits() {
+        let device = DeviceCapabilities::from_vulkaninfo_json(REPORT).unwrap();
+        let document: ProfilesDocument = PROFILE
+            .replace(
+                "\"maxImageDimension2D\": 8192",
+                "\"maxImageDimension2D\": 32768",
+            )
+            .replace("256", "16")
+            .replace("[1.0, 64.0]", "[0.5, 64.0]")
+            .replace("[\"VK_SAMPLE_COUNT_1_BIT\"]", "[\"VK_SAMPLE_COUNT_4_BIT\"]")
+            .parse()
+            .unwrap();
+
+        let report = device
+            .check_document_profile(&document, "VP_TEST_baseline")
+            .unwrap();
+        // Both alternatives fail, so the limits of the baseline block are reported twice
+        let mut failures: Vec<_> = report
+            .failures()
+            .filter(|check| check.kind == RequirementKind::Property)
+            .map(|check| {
+                check
+                    .name
+                    .trim_start_matches("VkPhysicalDeviceProperties.limits.")
+            })
+            .collect();
+        failures.sort();
+        failures.dedup();
+        assert_eq!(
+            failures,
+            [
+                "framebufferColorSampleCounts",
+                "maxImageDimension2D",
+                "minUniformBufferOffsetAlignment",
+                "pointSizeRange"
+            ]
+        );
+    }
+}
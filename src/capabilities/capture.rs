@@ -0,0 +1,523 @@
+//! Capturing the capabilities of a live physical device.
+
+use std::collections::BTreeMap;
+
+use ash::prelude::VkResult;
+use ash::vk;
+
+use super::DeviceCapabilities;
+use crate::json::{self, Capability};
+use crate::queue_family::QueueFamilyQuery;
+use crate::reflect::{self, StructCategory, StructChain};
+use crate::{ErrorContext, ProfilesError, ProfilesResult};
+
+/// The physical device queries used by [`capture_physical_device`].
+///
+/// The functions have the same signatures as the matching functions of [`ash::Instance`], which implements
+/// this trait. Other implementations can provide stubbed device data, for example in tests.
+pub trait PhysicalDeviceQuery {
+    /// See [`ash::Instance::get_physical_device_properties`].
+    ///
+    /// # Safety
+    /// `physical_device` must be a valid physical device of the instance.
+    unsafe fn get_physical_device_properties(
+        &self,
+        physical_device: vk::PhysicalDevice,
+    ) -> vk::PhysicalDeviceProperties;
+
+    /// See [`ash::Instance::enumerate_device_extension_properties`].
+    ///
+    /// # Safety
+    /// `physical_device` must be a valid physical device of the instance.
+    unsafe fn enumerate_device_extension_properties(
+        &self,
+        physical_device: vk::PhysicalDevice,
+    ) -> VkResult<Vec<vk::ExtensionProperties>>;
+
+    /// See [`ash::Instance::get_physical_device_features`].
+    ///
+    /// # Safety
+    /// `physical_device` must be a valid physical device of the instance.
+    unsafe fn get_physical_device_features(
+        &self,
+        physical_device: vk::PhysicalDevice,
+    ) -> vk::PhysicalDeviceFeatures;
+
+    /// See [`ash::Instance::get_physical_device_features2`].
+    ///
+    /// # Safety
+    /// `physical_device` must be a valid physical device of the instance, which must have been created
+    /// with vulkan 1.1 or later. The pNext chain of `features` must be valid.
+    unsafe fn get_physical_device_features2(
+        &self,
+        physical_device: vk::PhysicalDevice,
+        features: &mut vk::PhysicalDeviceFeatures2<'_>,
+    );
+
+    /// See [`ash::Instance::get_physical_device_properties2`].
+    ///
+    /// # Safety
+    /// `physical_device` must be a valid physical device of the instance, which must have been created
+    /// with vulkan 1.1 or later. The pNext chain of `properties` must be valid.
+    unsafe fn get_physical_device_properties2(
+        &self,
+        physical_device: vk::PhysicalDevice,
+        properties: &mut vk::PhysicalDeviceProperties2<'_>,
+    );
+
+    /// See [`ash::Instance::get_physical_device_format_properties`].
+    ///
+    /// # Safety
+    /// `physical_device` must be a valid physical device of the instance.
+    unsafe fn get_physical_device_format_properties(
+        &self,
+        physical_device: vk::PhysicalDevice,
+        format: vk::Format,
+    ) -> vk::FormatProperties;
+
+    /// See [`ash::Instance::get_physical_device_format_properties2`].
+    ///
+    /// # Safety
+    /// `physical_device` must be a valid physical device of the instance, which must have been created
+    /// with vulkan 1.1 or later. The pNext chain of `format_properties` must be valid.
+    unsafe fn get_physical_device_format_properties2(
+        &self,
+        physical_device: vk::PhysicalDevice,
+        format: vk::Format,
+        format_properties: &mut vk::FormatProperties2<'_>,
+    );
+
+    /// See [`ash::Instance::get_physical_device_queue_family_properties`].
+    ///
+    /// # Safety
+    /// `physical_device` must be a valid physical device of the instance.
+    unsafe fn get_physical_device_queue_family_properties(
+        &self,
+        physical_device: vk::PhysicalDevice,
+    ) -> Vec<vk::QueueFamilyProperties>;
+
+    /// See [`ash::Instance::get_physical_device_queue_family_properties2_len`].
+    ///
+    /// # Safety
+    /// `physical_device` must be a valid physical device of the instance, which must have been created
+    /// with vulkan 1.1 or later.
+    unsafe fn get_physical_device_queue_family_properties2_len(
+        &self,
+        physical_device: vk::PhysicalDevice,
+    ) -> usize;
+
+    /// See [`ash::Instance::get_physical_device_queue_family_properties2`].
+    ///
+    /// # Safety
+    /// `physical_device` must be a valid physical device of the instance, which must have been created
+    /// with vulkan 1.1 or later. The pNext chains of all `queue_family_properties` must be valid.
+    unsafe fn get_physical_device_queue_family_properties2(
+        &self,
+        physical_device: vk::PhysicalDevice,
+        queue_family_properties: &mut [vk::QueueFamilyProperties2<'_>],
+    );
+}
+
+impl PhysicalDeviceQuery for ash::Instance {
+    unsafe fn get_physical_device_properties(
+        &self,
+        physical_device: vk::PhysicalDevice,
+    ) -> vk::PhysicalDeviceProperties {
+        self.get_physical_device_properties(physical_device)
+    }
+
+    unsafe fn enumerate_device_extension_properties(
+        &self,
+        physical_device: vk::PhysicalDevice,
+    ) -> VkResult<Vec<vk::ExtensionProperties>> {
+        self.enumerate_device_extension_properties(physical_device)
+    }
+
+    unsafe fn get_physical_device_features(
+        &self,
+        physical_device: vk::PhysicalDevice,
+    ) -> vk::PhysicalDeviceFeatures {
+        self.get_physical_device_features(physical_device)
+    }
+
+    unsafe fn get_physical_device_features2(
+        &self,
+        physical_device: vk::PhysicalDevice,
+        features: &mut vk::PhysicalDeviceFeatures2<'_>,
+    ) {
+        self.get_physical_device_features2(physical_device, features)
+    }
+
+    unsafe fn get_physical_device_properties2(
+        &self,
+        physical_device: vk::PhysicalDevice,
+        properties: &mut vk::PhysicalDeviceProperties2<'_>,
+    ) {
+        self.get_physical_device_properties2(physical_device, properties)
+    }
+
+    unsafe fn get_physical_device_format_properties(
+        &self,
+        physical_device: vk::PhysicalDevice,
+        format: vk::Format,
+    ) -> vk::FormatProperties {
+        self.get_physical_device_format_properties(physical_device, format)
+    }
+
+    unsafe fn get_physical_device_format_properties2(
+        &self,
+        physical_device: vk::PhysicalDevice,
+        format: vk::Format,
+        format_properties: &mut vk::FormatProperties2<'_>,
+    ) {
+        self.get_physical_device_format_properties2(physical_device, format, format_properties)
+    }
+
+    unsafe fn get_physical_device_queue_family_properties(
+        &self,
+        physical_device: vk::PhysicalDevice,
+    ) -> Vec<vk::QueueFamilyProperties> {
+        self.get_physical_device_queue_family_properties(physical_device)
+    }
+
+    unsafe fn get_physical_device_queue_family_properties2_len(
+        &self,
+        physical_device: vk::PhysicalDevice,
+    ) -> usize {
+        self.get_physical_device_queue_family_properties2_len(physical_device)
+    }
+
+    unsafe fn get_physical_device_queue_family_properties2(
+        &self,
+        physical_device: vk::PhysicalDevice,
+        queue_family_properties: &mut [vk::QueueFamilyProperties2<'_>],
+    ) {
+        self.get_physical_device_queue_family_properties2(physical_device, queue_family_properties)
+    }
+}
+
+/// Captures everything a physical device reports which can be required by a profile.
+///
+/// Every feature, property, format property and queue family struct known to this crate is queried if
+/// the device supports the core version or one of the extensions providing it, which covers all structs
+/// used by the profiles in [`crate::profiles`]. Formats and structs which only contain zero values are
+/// omitted.
+///
+/// For devices supporting vulkan 1.1 or later the `*2` queries are used.
+///
+/// # Safety
+/// `physical_device` must be a valid physical device of `instance`. For devices supporting vulkan 1.1 or
+/// later the instance must have been created with vulkan 1.1 or later.
+pub unsafe fn capture_physical_device(
+    instance: &impl PhysicalDeviceQuery,
+    physical_device: vk::PhysicalDevice,
+) -> ProfilesResult<DeviceCapabilities> {
+    let device_properties = instance.get_physical_device_properties(physical_device);
+    let api_version = device_properties.api_version;
+    let extensions = instance
+        .enumerate_device_extension_properties(physical_device)
+        .map_err(|result| {
+            ProfilesError::vulkan(
+                "vkEnumerateDeviceExtensionProperties",
+                ErrorContext::default(),
+                result,
+            )
+        })?;
+    let extension_map = json::extension_map(&extensions);
+    let extension_names: Vec<&str> = extension_map.keys().map(String::as_str).collect();
+
+    let available = |category: StructCategory| -> Vec<vk::StructureType> {
+        reflect::structs()
+            .iter()
+            .filter(|info| info.category == Some(category))
+            .filter(|info| reflect::is_provided(info.providers, api_version, &extension_names))
+            .filter_map(|info| info.structure_type)
+            .collect()
+    };
+    let formats = reflect::formats().filter(|format| {
+        reflect::is_provided(
+            reflect::format_providers(*format),
+            api_version,
+            &extension_names,
+        )
+    });
+
+    let mut capability = Capability {
+        extensions: extension_map.clone(),
+        ..Default::default()
+    };
+    if api_version < vk::API_VERSION_1_1 {
+        let features = instance.get_physical_device_features(physical_device);
+        capability.features = struct_map("VkPhysicalDeviceFeatures", &features);
+        capability.properties = struct_map("VkPhysicalDeviceProperties", &device_properties);
+        for format in formats {
+            let properties =
+                instance.get_physical_device_format_properties(physical_device, format);
+            let structs = struct_map("VkFormatProperties", &properties);
+            if let (Some(name), false) = (reflect::format_name(format), structs.is_empty()) {
+                capability.formats.insert(name.to_string(), structs);
+            }
+        }
+        let families: Vec<_> = instance
+            .get_physical_device_queue_family_properties(physical_device)
+            .into_iter()
+            .map(Into::into)
+            .collect();
+        capability.queue_families_properties = json::queue_family_maps(&families);
+    } else {
+        let mut features = StructChain::new(
+            vk::StructureType::PHYSICAL_DEVICE_FEATURES_2,
+            &available(StructCategory::Features),
+        );
+        instance.get_physical_device_features2(
+            physical_device,
+            &mut *(features.link() as *mut vk::PhysicalDeviceFeatures2),
+        );
+        capability.features = json::chain_map(&features);
+
+        let mut properties = StructChain::new(
+            vk::StructureType::PHYSICAL_DEVICE_PROPERTIES_2,
+            &available(StructCategory::Properties),
+        );
+        instance.get_physical_device_properties2(
+            physical_device,
+            &mut *(properties.link() as *mut vk::PhysicalDeviceProperties2),
+        );
+        capability.properties = json::chain_map(&properties);
+
+        let format_structure_types = available(StructCategory::FormatProperties);
+        for format in formats {
+            let mut chain = StructChain::new(
+                vk::StructureType::FORMAT_PROPERTIES_2,
+                &format_structure_types,
+            );
+            instance.get_physical_device_format_properties2(
+                physical_device,
+                format,
+                &mut *(chain.link() as *mut vk::FormatProperties2),
+            );
+            json::insert_format(&mut capability.formats, format, &chain);
+        }
+
+        let count = instance.get_physical_device_queue_family_properties2_len(physical_device);
+        let mut query =
+            QueueFamilyQuery::new(count, &available(StructCategory::QueueFamilyProperties));
+        instance.get_physical_device_queue_family_properties2(physical_device, query.link());
+        capability.queue_families_properties = json::queue_family_maps(&query.into_owned());
+    }
+
+    Ok(DeviceCapabilities {
+        device_name: device_properties
+            .device_name_as_c_str()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default(),
+        api_version: json::api_version_string(api_version),
+        capability,
+    })
+}
+
+/// Reads a struct without structure type into a map containing only that struct.
+fn struct_map<T>(name: &str, value: &T) -> BTreeMap<String, json::StructValue> {
+    let info = reflect::find_struct(name).expect("struct is not reflected");
+    debug_assert_eq!(info.size, std::mem::size_of::<T>());
+    // SAFETY: value is the struct described by info
+    let value = unsafe { reflect::read_struct(info, value as *const T as *const u8) };
+    if value.is_empty() {
+        BTreeMap::new()
+    } else {
+        BTreeMap::from([(info.name.to_string(), value)])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::ProfilesDocument;
+
+    /// A vulkan 1.2 device with a few features, limits and formats.
+    struct StubDevice;
+
+    impl StubDevice {
+        fn properties() -> vk::PhysicalDeviceProperties {
+            let mut properties = vk::PhysicalDeviceProperties {
+                api_version: vk::make_api_version(0, 1, 2, 190),
+                ..Default::default()
+            };
+            properties.device_name[..4]
+                .copy_from_slice(&[b'S' as _, b't' as _, b'u' as _, b'b' as _]);
+            properties.limits.max_image_dimension2_d = 16384;
+            properties.limits.min_uniform_buffer_offset_alignment = 64;
+            properties.limits.framebuffer_color_sample_counts =
+                vk::SampleCountFlags::TYPE_1 | vk::SampleCountFlags::TYPE_4;
+            properties
+        }
+
+        fn format_properties(format: vk::Format) -> vk::FormatProperties {
+            match format {
+                vk::Format::R8G8B8A8_UNORM => vk::FormatProperties {
+                    optimal_tiling_features: vk::FormatFeatureFlags::SAMPLED_IMAGE
+                        | vk::FormatFeatureFlags::COLOR_ATTACHMENT,
+                    ..Default::default()
+                },
+                _ => vk::FormatProperties::default(),
+            }
+        }
+    }
+
+    impl PhysicalDeviceQuery for StubDevice {
+        unsafe fn get_physical_device_properties(
+            &self,
+            _: vk::PhysicalDevice,
+        ) -> vk::PhysicalDeviceProperties {
+            Self::properties()
+        }
+
+        unsafe fn enumerate_device_extension_properties(
+            &self,
+            _: vk::PhysicalDevice,
+        ) -> VkResult<Vec<vk::ExtensionProperties>> {
+            Ok(vec![vk::ExtensionProperties::default()
+                .extension_name(ash::khr::swapchain::NAME)
+                .unwrap()
+                .spec_version(70)])
+        }
+
+        unsafe fn get_physical_device_features(
+            &self,
+            _: vk::PhysicalDevice,
+        ) -> vk::PhysicalDeviceFeatures {
+            unreachable!("the stub device supports vulkan 1.2")
+        }
+
+        unsafe fn get_physical_device_features2(
+            &self,
+            _: vk::PhysicalDevice,
+            features: &mut vk::PhysicalDeviceFeatures2<'_>,
+        ) {
+            features.features.sampler_anisotropy = vk::TRUE;
+            let mut next = features.p_next as *mut vk::BaseOutStructure;
+            while !next.is_null() {
+                match (*next).s_type {
+                    vk::StructureType::PHYSICAL_DEVICE_VULKAN_1_2_FEATURES => {
+                        (*(next as *mut vk::PhysicalDeviceVulkan12Features)).timeline_semaphore =
+                            vk::TRUE
+                    }
+                    vk::StructureType::PHYSICAL_DEVICE_VULKAN_1_3_FEATURES => {
+                        panic!("vulkan 1.3 features queried on a vulkan 1.2 device")
+                    }
+                    _ => {}
+                }
+                next = (*next).p_next;
+            }
+        }
+
+        unsafe fn get_physical_device_properties2(
+            &self,
+            _: vk::PhysicalDevice,
+            properties: &mut vk::PhysicalDeviceProperties2<'_>,
+        ) {
+            properties.properties = Self::properties();
+        }
+
+        unsafe fn get_physical_device_format_properties(
+            &self,
+            _: vk::PhysicalDevice,
+            _: vk::Format,
+        ) -> vk::FormatProperties {
+            unreachable!("the stub device supports vulkan 1.2")
+        }
+
+        unsafe fn get_physical_device_format_properties2(
+            &self,
+            _: vk::PhysicalDevice,
+            format: vk::Format,
+            format_properties: &mut vk::FormatProperties2<'_>,
+        ) {
+            format_properties.format_properties = Self::format_properties(format);
+        }
+
+        unsafe fn get_physical_device_queue_family_properties(
+            &self,
+            _: vk::PhysicalDevice,
+        ) -> Vec<vk::QueueFamilyProperties> {
+            unreachable!("the stub device supports vulkan 1.2")
+        }
+
+        unsafe fn get_physical_device_queue_family_properties2_len(
+            &self,
+            _: vk::PhysicalDevice,
+        ) -> usize {
+            1
+        }
+
+        unsafe fn get_physical_device_queue_family_properties2(
+            &self,
+            _: vk::PhysicalDevice,
+            queue_family_properties: &mut [vk::QueueFamilyProperties2<'_>],
+        ) {
+            queue_family_properties[0].queue_family_properties = vk::QueueFamilyProperties {
+                queue_flags: vk::QueueFlags::GRAPHICS | vk::QueueFlags::COMPUTE,
+                queue_count: 4,
+                ..Default::default()
+            };
+        }
+    }
+
+    const PROFILE: &str = r#"{
+        "$schema": "https://schema.khronos.org/vulkan/profiles-0.8.2-281.json#",
+        "capabilities": {
+            "baseline": {
+                "extensions": { "VK_KHR_swapchain": 70 },
+                "features": {
+                    "VkPhysicalDeviceFeatures": { "samplerAnisotropy": true },
+                    "VkPhysicalDeviceVulkan12Features": { "timelineSemaphore": true }
+                },
+                "properties": {
+                    "VkPhysicalDeviceProperties": {
+                        "limits": { "maxImageDimension2D": 8192, "minUniformBufferOffsetAlignment": 256 }
+                    }
+                },
+                "formats": {
+                    "VK_FORMAT_R8G8B8A8_UNORM": {
+                        "VkFormatProperties": { "optimalTilingFeatures": ["VK_FORMAT_FEATURE_SAMPLED_IMAGE_BIT"] }
+                    }
+                },
+                "queueFamiliesProperties": [
+                    { "VkQueueFamilyProperties": { "queueFlags": ["VK_QUEUE_GRAPHICS_BIT"], "queueCount": 1 } }
+                ]
+            }
+        },
+        "profiles": {
+            "VP_TEST_profile": {
+                "version": 1,
+                "api-version": "1.2.148",
+                "label": "Test",
+                "description": "Test profile",
+                "capabilities": ["baseline"]
+            }
+        }
+    }"#;
+
+    #[test]
+    fn test_capture_physical_device() {
+        let device =
+            unsafe { capture_physical_device(&StubDevice, vk::PhysicalDevice::null()) }.unwrap();
+        assert_eq!(device.device_name, "Stub");
+        assert_eq!(device.api_version, "1.2.190");
+        assert_eq!(
+            device.capability.features["VkPhysicalDeviceVulkan12Features"]["timelineSemaphore"],
+            true
+        );
+        assert_eq!(device.capability.formats.len(), 1);
+        assert_eq!(device.queue_families()[0].properties.queue_count, 4);
+
+        let serialized = serde_json::to_string_pretty(&device).unwrap();
+        let device: DeviceCapabilities = serde_json::from_str(&serialized).unwrap();
+
+        let document: ProfilesDocument = PROFILE.parse().unwrap();
+        let report = device
+            .check_document_profile(&document, "VP_TEST_profile")
+            .unwrap();
+        assert!(report.is_supported(), "{report}");
+    }
+}
@@ -0,0 +1,559 @@
+//! Derivation of the capabilities shared by a set of devices.
+
+use std::collections::BTreeMap;
+
+use ash::vk;
+
+use super::compare::{comparison, flag_bits, limit_type, nested_limit_type, scalar};
+use super::{struct_storage, Comparison, DeviceCapabilities};
+use crate::format::FormatRequirement;
+use crate::json::{self, Capability, CapabilityReference, Profile, ProfilesDocument, StructValue};
+use crate::reflect::{self, LimitType, MemberType, StructInfo};
+
+/// Returns the requirements satisfied by all `devices`.
+///
+/// The result contains every device extension, feature, property, format and queue family all devices
+/// support, reduced to what can be required by a profile:
+/// - extensions are required with the lowest reported spec version,
+/// - booleans are required if all devices support them and flags contain the bits set on all devices,
+/// - maximum limits are the lowest and minimum limits the highest reported value,
+/// - granularities are the least common multiple of the reported values,
+/// - ranges are the largest range contained in all reported ranges,
+/// - enums are only required if all devices report the same value,
+/// - identifying members like device names or UUIDs and structs unknown to this crate are dropped.
+///
+/// A member is only required if every device reports it. Queue families are matched by their queue flags
+/// and only `VkQueueFamilyProperties` is taken into account.
+pub fn common_capability(devices: &[DeviceCapabilities]) -> Capability {
+    let capabilities: Vec<_> = devices.iter().map(|device| &device.capability).collect();
+    let mut capability = intersect_capabilities(&capabilities);
+    capability.extensions = capability
+        .device_extensions()
+        .map(|(name, spec_version)| (name.clone(), *spec_version))
+        .collect();
+    capability
+}
+
+/// Returns the requirements satisfied by everything satisfying any of the `capabilities`.
+///
+/// The capabilities are combined like the devices of [`common_capability`], so each limit takes the weaker
+/// value, flags and booleans are combined with a logical and and only common extensions are kept. Instance
+/// extensions are combined like device extensions.
+pub(crate) fn intersect_capabilities(capabilities: &[&Capability]) -> Capability {
+    if capabilities.is_empty() {
+        return Capability::default();
+    }
+
+    Capability {
+        extensions: common_extensions(capabilities),
+        features: common_structs(capabilities, |capability| &capability.features),
+        properties: common_structs(capabilities, |capability| &capability.properties),
+        formats: common_formats(capabilities),
+        queue_families_properties: common_queue_families(capabilities),
+    }
+}
+
+/// Creates a document with a single profile requiring the capabilities shared by all `devices`.
+///
+/// The profile and its only capability block are named `profile_name`, its api version is the lowest
+/// reported device api version. The requirements are described in [`common_capability`].
+pub fn common_profile(profile_name: &str, devices: &[DeviceCapabilities]) -> ProfilesDocument {
+    let api_version = devices
+        .iter()
+        .filter_map(|device| device.api_version())
+        .map(|version| version & 0x1FFF_FFFF)
+        .min()
+        .unwrap_or(vk::API_VERSION_1_0);
+
+    let mut document = ProfilesDocument {
+        schema: json::SCHEMA.to_string(),
+        ..Default::default()
+    };
+    document
+        .capabilities
+        .insert(profile_name.to_string(), common_capability(devices));
+    document.profiles.insert(
+        profile_name.to_string(),
+        Profile {
+            version: 1,
+            api_version: json::api_version_string(api_version),
+            label: profile_name.to_string(),
+            description: format!("Capabilities shared by {} devices", devices.len()),
+            capabilities: vec![CapabilityReference::Required(profile_name.to_string())],
+            ..Default::default()
+        },
+    );
+    document
+}
+
+/// Returns the extensions of all capabilities with their lowest spec version.
+fn common_extensions(capabilities: &[&Capability]) -> BTreeMap<String, u32> {
+    let mut common = BTreeMap::new();
+    for (name, spec_version) in &capabilities[0].extensions {
+        let spec_version = capabilities[1..]
+            .iter()
+            .try_fold(*spec_version, |min, capability| {
+                Some(min.min(*capability.extensions.get(name)?))
+            });
+        if let Some(spec_version) = spec_version {
+            common.insert(name.clone(), spec_version);
+        }
+    }
+    common
+}
+
+/// Combines the feature or property structs of all capabilities.
+fn common_structs(
+    capabilities: &[&Capability],
+    structs: impl Fn(&Capability) -> &BTreeMap<String, StructValue>,
+) -> BTreeMap<String, StructValue> {
+    let mut common = BTreeMap::new();
+    for name in structs(capabilities[0]).keys() {
+        let Some(info) = reflect::find_struct(name) else {
+            continue;
+        };
+        // Devices may report a struct under an alias
+        let Some(values) = capabilities
+            .iter()
+            .map(|capability| {
+                structs(capability).iter().find_map(|(name, value)| {
+                    reflect::find_struct(name)
+                        .is_some_and(|other| std::ptr::eq(info, other))
+                        .then_some(value)
+                })
+            })
+            .collect::<Option<Vec<_>>>()
+        else {
+            continue;
+        };
+
+        let value = combine(info, &values);
+        if !value.is_empty() {
+            common.insert(info.name.to_string(), value);
+        }
+    }
+    common
+}
+
+/// Combines the format features of all capabilities.
+fn common_formats(capabilities: &[&Capability]) -> BTreeMap<String, BTreeMap<String, StructValue>> {
+    let mut common = BTreeMap::new();
+    for format_name in capabilities[0].formats.keys() {
+        let Some(requirement) = capabilities.iter().try_fold(None, |common, capability| {
+            let structs = capability.formats.get(format_name)?;
+            let requirement = json::format_requirement(structs, true).unwrap_or_default();
+            Some(Some(match common {
+                Some(common) => requirement.intersection(&common),
+                None => requirement,
+            }))
+        }) else {
+            continue;
+        };
+        let requirement: FormatRequirement = requirement.unwrap_or_default();
+        if requirement == FormatRequirement::default() {
+            continue;
+        }
+
+        // The bits known to VkFormatFeatureFlags are written as VkFormatProperties to keep the requirement
+        // usable by devices without VK_KHR_format_feature_flags2 and only the remaining bits as
+        // VkFormatProperties3. Some of the lower 32 bits only exist in VkFormatFeatureFlags2, for example
+        // VK_FORMAT_FEATURE_2_STORAGE_READ_WITHOUT_FORMAT_BIT.
+        let known = reflect::find_flags("VkFormatFeatureFlags")
+            .expect("VkFormatFeatureFlags is not reflected")
+            .bits
+            .iter()
+            .fold(0, |known, (_, bit)| known | *bit as u32);
+        let low = |flags: vk::FormatFeatureFlags2| {
+            vk::FormatFeatureFlags::from_raw(flags.as_raw() as u32 & known)
+        };
+        let rest = |flags: vk::FormatFeatureFlags2| {
+            vk::FormatFeatureFlags2::from_raw(flags.as_raw() & !u64::from(known))
+        };
+        let properties = vk::FormatProperties {
+            linear_tiling_features: low(requirement.linear_tiling_features),
+            optimal_tiling_features: low(requirement.optimal_tiling_features),
+            buffer_features: low(requirement.buffer_features),
+        };
+        let properties3 = vk::FormatProperties3 {
+            linear_tiling_features: rest(requirement.linear_tiling_features),
+            optimal_tiling_features: rest(requirement.optimal_tiling_features),
+            buffer_features: rest(requirement.buffer_features),
+            ..Default::default()
+        };
+
+        let mut structs = BTreeMap::new();
+        insert_struct(&mut structs, "VkFormatProperties", &properties);
+        insert_struct(&mut structs, "VkFormatProperties3", &properties3);
+        common.insert(format_name.clone(), structs);
+    }
+    common
+}
+
+/// Combines the queue families of all capabilities.
+///
+/// Every queue family of the first capability is combined with the family of each other capability sharing
+/// the most queue flags. Families without any common queue flag are dropped.
+fn common_queue_families(capabilities: &[&Capability]) -> Vec<BTreeMap<String, StructValue>> {
+    const NAME: &str = "VkQueueFamilyProperties";
+    let info = reflect::find_struct(NAME).expect("VkQueueFamilyProperties is not reflected");
+    let families: Vec<_> = capabilities
+        .iter()
+        .map(|capability| {
+            capability
+                .queue_families_properties
+                .iter()
+                .filter_map(|family| family.get(NAME))
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+    let mut common = Vec::new();
+    for candidate in &families[0] {
+        let flags = queue_flags(candidate);
+        let Some(values) = families
+            .iter()
+            .map(|families| {
+                families
+                    .iter()
+                    .copied()
+                    .filter(|family| !(queue_flags(family) & flags).is_empty())
+                    .max_by_key(|family| (queue_flags(family) & flags).as_raw().count_ones())
+            })
+            .collect::<Option<Vec<_>>>()
+        else {
+            continue;
+        };
+
+        let value = combine(info, &values);
+        if !value.contains_key("queueFlags") {
+            continue;
+        }
+        let family = BTreeMap::from([(NAME.to_string(), value)]);
+        if !common.contains(&family) {
+            common.push(family);
+        }
+    }
+    common
+}
+
+fn queue_flags(family: &StructValue) -> vk::QueueFlags {
+    let mut properties = vk::QueueFamilyProperties::default();
+    let info = reflect::find_struct("VkQueueFamilyProperties").unwrap();
+    // SAFETY: info describes VkQueueFamilyProperties
+    unsafe {
+        reflect::write_struct_lenient(info, family, &mut properties as *mut _ as *mut u8);
+    }
+    properties.queue_flags
+}
+
+fn insert_struct<T>(structs: &mut BTreeMap<String, StructValue>, name: &str, value: &T) {
+    let info = reflect::find_struct(name).expect("format struct is not reflected");
+    debug_assert_eq!(info.size, std::mem::size_of::<T>());
+    // SAFETY: value is the struct described by info
+    let value = unsafe { reflect::read_struct(info, value as *const T as *const u8) };
+    if !value.is_empty() {
+        structs.insert(name.to_string(), value);
+    }
+}
+
+/// Combines the values of a struct reported by all devices into the value every device satisfies.
+fn combine(info: &'static StructInfo, values: &[&StructValue]) -> StructValue {
+    let storages: Vec<_> = values
+        .iter()
+        .map(|value| struct_storage(info, Some(value)))
+        .collect();
+    let sources: Vec<_> = values
+        .iter()
+        .zip(&storages)
+        .map(|(value, storage)| (*value, storage.as_ptr() as *const u8))
+        .collect();
+    let mut common = reflect::zeroed_storage(info.size);
+    // SAFETY: all storages contain an instance of the struct described by info
+    unsafe {
+        combine_struct(info, None, &sources, common.as_mut_ptr() as *mut u8);
+        reflect::read_struct(info, common.as_ptr() as *const u8)
+    }
+}
+
+/// Writes all members reported by every source into `target`.
+unsafe fn combine_struct(
+    info: &'static StructInfo,
+    parent: Option<LimitType>,
+    sources: &[(&StructValue, *const u8)],
+    target: *mut u8,
+) {
+    for member in info.members {
+        let Some(nested_values) = sources
+            .iter()
+            .map(|(value, data)| Some((value.get(member.name)?, data.add(member.offset))))
+            .collect::<Option<Vec<_>>>()
+        else {
+            continue;
+        };
+        let target = target.add(member.offset);
+        let limit_type = limit_type(info, member, parent);
+
+        if let MemberType::Struct(nested) = member.ty {
+            let Some(nested_sources) = nested_values
+                .iter()
+                .map(|(value, data)| Some((value.as_object()?, *data)))
+                .collect::<Option<Vec<_>>>()
+            else {
+                continue;
+            };
+            combine_struct(
+                nested,
+                nested_limit_type(limit_type),
+                &nested_sources,
+                target,
+            );
+        } else {
+            let data: Vec<_> = nested_values.iter().map(|(_, data)| *data).collect();
+            combine_member(limit_type, &member.ty, &data, target);
+        }
+    }
+}
+
+/// Writes the value of a member which is satisfied by all `values` into `target`.
+unsafe fn combine_member(
+    limit_type: Option<LimitType>,
+    ty: &MemberType,
+    values: &[*const u8],
+    target: *mut u8,
+) {
+    let size = ty.size();
+    let comparison = comparison(limit_type, ty);
+    if let (MemberType::Array(element, len), false) = (
+        ty,
+        matches!(comparison, Comparison::Ignored | Comparison::Range),
+    ) {
+        let stride = element.size();
+        for index in 0..*len {
+            let offset = index * stride;
+            let values: Vec<_> = values.iter().map(|value| value.add(offset)).collect();
+            combine_member(limit_type, element, &values, target.add(offset));
+        }
+        return;
+    }
+
+    match comparison {
+        Comparison::Ignored => {}
+        Comparison::Bool | Comparison::Exact => {
+            let first = std::slice::from_raw_parts(values[0], size);
+            if values
+                .iter()
+                .all(|value| std::slice::from_raw_parts(*value, size) == first)
+            {
+                target.copy_from_nonoverlapping(values[0], size);
+            }
+        }
+        Comparison::Flags => {
+            let bits = values
+                .iter()
+                .fold(u64::MAX, |bits, value| bits & flag_bits(ty, *value));
+            if size == 8 {
+                (target as *mut u64).write_unaligned(bits);
+            } else {
+                (target as *mut u32).write_unaligned(bits as u32);
+            }
+        }
+        Comparison::Max => copy_extreme(ty, values, false, target),
+        Comparison::Min => copy_extreme(ty, values, true, target),
+        Comparison::Multiple => combine_multiple(ty, values, target),
+        Comparison::Range => {
+            let MemberType::Array(element, _) = ty else {
+                return;
+            };
+            let stride = element.size();
+            let highs: Vec<_> = values.iter().map(|value| value.add(stride)).collect();
+            copy_extreme(element, values, true, target);
+            copy_extreme(element, &highs, false, target.add(stride));
+        }
+    }
+}
+
+/// Writes the least common multiple of all granularities into `target`.
+///
+/// A granularity of zero is only satisfied by zero and wins. Floats are combined like minimums.
+unsafe fn combine_multiple(ty: &MemberType, values: &[*const u8], target: *mut u8) {
+    if let MemberType::Float32 = ty {
+        return copy_extreme(ty, values, true, target);
+    }
+    let mut multiple = 1;
+    for value in values {
+        let Some(value) = scalar(ty, *value) else {
+            return;
+        };
+        if value == 0.0 {
+            multiple = 0;
+            break;
+        }
+        multiple = lcm(multiple, value as u64);
+    }
+    match ty.size() {
+        8 => (target as *mut u64).write_unaligned(multiple),
+        4 => (target as *mut u32).write_unaligned(multiple as u32),
+        _ => {}
+    }
+}
+
+fn lcm(a: u64, b: u64) -> u64 {
+    let (mut x, mut y) = (a, b);
+    while y != 0 {
+        (x, y) = (y, x % y);
+    }
+    a / x * b
+}
+
+/// Copies the largest or smallest numeric value into `target`.
+unsafe fn copy_extreme(ty: &MemberType, values: &[*const u8], largest: bool, target: *mut u8) {
+    let mut extreme: Option<(f64, *const u8)> = None;
+    for value in values {
+        let Some(number) = scalar(ty, *value) else {
+            return;
+        };
+        if extreme.is_none_or(|(current, _)| {
+            if largest {
+                number > current
+            } else {
+                number < current
+            }
+        }) {
+            extreme = Some((number, *value));
+        }
+    }
+    if let Some((_, value)) = extreme {
+        target.copy_from_nonoverlapping(value, ty.size());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn device(capability: serde_json::Value) -> DeviceCapabilities {
+        serde_json::from_value(capability).unwrap()
+    }
+
+    #[test]
+    fn test_common_profile() {
+        let devices = [
+            device(serde_json::json!({
+                "deviceName": "A",
+                "apiVersion": "1.3.250",
+                "extensions": { "VK_KHR_swapchain": 70, "VK_KHR_maintenance4": 2 },
+                "features": {
+                    "VkPhysicalDeviceFeatures": { "wideLines": true, "largePoints": true },
+                    "VkPhysicalDeviceTimelineSemaphoreFeaturesKHR": { "timelineSemaphore": true }
+                },
+                "properties": {
+                    "VkPhysicalDeviceProperties": {
+                        "deviceName": "A",
+                        "limits": {
+                            "maxImageDimension2D": 16384,
+                            "minUniformBufferOffsetAlignment": 64,
+                            "pointSizeRange": [1.0, 64.0],
+                            "maxComputeWorkGroupCount": [65535, 65535, 65535],
+                            "framebufferColorSampleCounts": ["VK_SAMPLE_COUNT_1_BIT", "VK_SAMPLE_COUNT_4_BIT"]
+                        }
+                    }
+                },
+                "formats": {
+                    "VK_FORMAT_R8G8B8A8_UNORM": {
+                        "VkFormatProperties": {
+                            "optimalTilingFeatures": ["VK_FORMAT_FEATURE_SAMPLED_IMAGE_BIT", "VK_FORMAT_FEATURE_STORAGE_IMAGE_BIT"]
+                        }
+                    },
+                    "VK_FORMAT_R16_SFLOAT": {
+                        "VkFormatProperties": { "bufferFeatures": ["VK_FORMAT_FEATURE_VERTEX_BUFFER_BIT"] }
+                    }
+                },
+                "queueFamiliesProperties": [
+                    { "VkQueueFamilyProperties": { "queueFlags": ["VK_QUEUE_GRAPHICS_BIT", "VK_QUEUE_COMPUTE_BIT"], "queueCount": 16, "timestampValidBits": 64, "minImageTransferGranularity": { "width": 1, "height": 1, "depth": 1 } } },
+                    { "VkQueueFamilyProperties": { "queueFlags": ["VK_QUEUE_TRANSFER_BIT"], "queueCount": 2, "minImageTransferGranularity": { "width": 4, "height": 4, "depth": 1 } } }
+                ]
+            })),
+            device(serde_json::json!({
+                "deviceName": "B",
+                "apiVersion": "1.2.198",
+                "extensions": { "VK_KHR_swapchain": 68 },
+                "features": {
+                    "VkPhysicalDeviceFeatures": { "wideLines": true, "largePoints": false },
+                    "VkPhysicalDeviceTimelineSemaphoreFeatures": { "timelineSemaphore": true }
+                },
+                "properties": {
+                    "VkPhysicalDeviceProperties": {
+                        "deviceName": "B",
+                        "limits": {
+                            "maxImageDimension2D": 8192,
+                            "minUniformBufferOffsetAlignment": 256,
+                            "pointSizeRange": [0.5, 32.0],
+                            "maxComputeWorkGroupCount": [65535, 1024, 65535],
+                            "framebufferColorSampleCounts": ["VK_SAMPLE_COUNT_1_BIT", "VK_SAMPLE_COUNT_8_BIT"]
+                        }
+                    }
+                },
+                "formats": {
+                    "VK_FORMAT_R8G8B8A8_UNORM": {
+                        "VkFormatProperties": { "optimalTilingFeatures": ["VK_FORMAT_FEATURE_SAMPLED_IMAGE_BIT"] }
+                    }
+                },
+                "queueFamiliesProperties": [
+                    { "VkQueueFamilyProperties": { "queueFlags": ["VK_QUEUE_GRAPHICS_BIT", "VK_QUEUE_COMPUTE_BIT", "VK_QUEUE_TRANSFER_BIT"], "queueCount": 1, "timestampValidBits": 36, "minImageTransferGranularity": { "width": 2, "height": 8, "depth": 1 } } }
+                ]
+            })),
+        ];
+
+        let document = common_profile("VP_TEST_baseline", &devices);
+        let capability = &document.capabilities["VP_TEST_baseline"];
+        assert_eq!(
+            capability.extensions,
+            BTreeMap::from([("VK_KHR_swapchain".to_string(), 68)])
+        );
+        assert_eq!(
+            serde_json::to_value(&capability.features).unwrap(),
+            serde_json::json!({
+                "VkPhysicalDeviceFeatures": { "wideLines": true },
+                "VkPhysicalDeviceTimelineSemaphoreFeatures": { "timelineSemaphore": true }
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(&capability.properties).unwrap(),
+            serde_json::json!({
+                "VkPhysicalDeviceProperties": {
+                    "limits": {
+                        "maxImageDimension2D": 8192,
+                        "minUniformBufferOffsetAlignment": 256,
+                        "pointSizeRange": [1.0, 32.0],
+                        "maxComputeWorkGroupCount": [65535, 1024, 65535],
+                        "framebufferColorSampleCounts": ["VK_SAMPLE_COUNT_1_BIT"]
+                    }
+                }
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(&capability.formats).unwrap(),
+            serde_json::json!({
+                "VK_FORMAT_R8G8B8A8_UNORM": {
+                    "VkFormatProperties": { "optimalTilingFeatures": ["VK_FORMAT_FEATURE_SAMPLED_IMAGE_BIT"] }
+                }
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(&capability.queue_families_properties).unwrap(),
+            serde_json::json!([
+                { "VkQueueFamilyProperties": { "queueFlags": ["VK_QUEUE_GRAPHICS_BIT", "VK_QUEUE_COMPUTE_BIT"], "queueCount": 1, "timestampValidBits": 36, "minImageTransferGranularity": { "width": 2, "height": 8, "depth": 1 } } },
+                { "VkQueueFamilyProperties": { "queueFlags": ["VK_QUEUE_TRANSFER_BIT"], "queueCount": 1, "minImageTransferGranularity": { "width": 4, "height": 8, "depth": 1 } } }
+            ])
+        );
+        assert_eq!(document.profiles["VP_TEST_baseline"].api_version, "1.2.198");
+
+        let document: ProfilesDocument = document.to_json_string().parse().unwrap();
+        for device in &devices {
+            let report = device
+                .check_document_profile(&document, "VP_TEST_baseline")
+                .unwrap();
+            assert!(report.is_supported(), "{report}");
+        }
+    }
+}
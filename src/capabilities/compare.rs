@@ -0,0 +1,396 @@
+//! The rules deciding whether a device value satisfies a required profile value.
+//!
+//! Profiles require limits in different ways: `maxImageDimension2D` is a maximum the device must reach,
+//! `minUniformBufferOffsetAlignment` a minimum it must not exceed, sample counts are bitmasks which must
+//! contain the required bits and `pointSizeRange` is a range which must contain the required range. The rule
+//! of a member follows from its limit type, see [`reflect::find_limit_type`].
+//! [`member_comparison`] returns the rule of a struct member and [`value_satisfies`] applies it to JSON values.
+
+use ash::vk;
+use serde_json::Value;
+
+use crate::json::StructValue;
+use crate::reflect::{self, LimitType, MemberInfo, MemberType, StructInfo};
+
+/// How the device value of a member is compared against a required value.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Comparison {
+    /// Identifying and textual members which are never compared.
+    Ignored,
+    /// Required booleans must be true.
+    Bool,
+    /// All required bits must be set.
+    Flags,
+    /// The values must be equal.
+    Exact,
+    /// The device value must be at least as large as the required one.
+    Max,
+    /// The device value must not exceed the required one.
+    Min,
+    /// The required value must be a multiple of the device value. A device value of zero only satisfies a
+    /// required zero. Floats are compared like [`Self::Min`].
+    Multiple,
+    /// The device range must contain the required range.
+    Range,
+}
+
+/// Returns how a member with the registry limit type `limit_type` is compared.
+///
+/// Members without limit type are compared by their type: booleans and flags are bitmasks and enums must
+/// be equal. Other members without limit type and nested structs are not compared. Arrays other than
+/// ranges are compared elementwise.
+pub(super) fn comparison(limit_type: Option<LimitType>, ty: &MemberType) -> Comparison {
+    let element = match ty {
+        MemberType::Array(element, _) => element,
+        ty => ty,
+    };
+    if let MemberType::String(_) | MemberType::Struct(_) = element {
+        return Comparison::Ignored;
+    }
+    match limit_type {
+        Some(LimitType::NoAuto | LimitType::Not | LimitType::Struct) => Comparison::Ignored,
+        Some(LimitType::Bitmask) | None => match element {
+            MemberType::Bool32 => Comparison::Bool,
+            MemberType::Flags(_) => Comparison::Flags,
+            MemberType::Enum(_) if limit_type.is_none() => Comparison::Exact,
+            _ => Comparison::Ignored,
+        },
+        Some(LimitType::Exact) => Comparison::Exact,
+        Some(LimitType::Range) => match ty {
+            MemberType::Array(_, 2) => Comparison::Range,
+            _ => Comparison::Ignored,
+        },
+        Some(LimitType::Min) => Comparison::Min,
+        Some(LimitType::Max | LimitType::Bits) => Comparison::Max,
+        Some(LimitType::MinMul) => Comparison::Multiple,
+    }
+}
+
+/// Returns the limit type deciding how `member` of `info` is compared.
+///
+/// Members of nested structs are compared like the member containing them, so the width of a
+/// `minImageTransferGranularity` extent is a granularity as well. `parent` is the limit type returned by
+/// [`nested_limit_type`] for the member containing them.
+pub(super) fn limit_type(
+    info: &StructInfo,
+    member: &MemberInfo,
+    parent: Option<LimitType>,
+) -> Option<LimitType> {
+    parent.or_else(|| reflect::find_limit_type(info.name, member.name))
+}
+
+/// Returns the limit type passed to [`limit_type`] for the members of a nested struct.
+///
+/// Structs like `limits` which have limit types for their own members pass on nothing.
+pub(super) fn nested_limit_type(limit_type: Option<LimitType>) -> Option<LimitType> {
+    limit_type.filter(|limit_type| *limit_type != LimitType::Struct)
+}
+
+/// Reads the bits of a flags member.
+pub(super) unsafe fn flag_bits(ty: &MemberType, data: *const u8) -> u64 {
+    if ty.size() == 8 {
+        (data as *const u64).read_unaligned()
+    } else {
+        u64::from((data as *const u32).read_unaligned())
+    }
+}
+
+/// Returns true if the device value of a member satisfies the required value or [`None`] if the member is
+/// not compared.
+///
+/// The rules are described in [`comparison`].
+pub(super) unsafe fn member_satisfied(
+    limit_type: Option<LimitType>,
+    ty: &MemberType,
+    required: *const u8,
+    actual: *const u8,
+) -> Option<bool> {
+    let comparison = comparison(limit_type, ty);
+    let elementwise = !matches!(comparison, Comparison::Ignored | Comparison::Range);
+    if let (MemberType::Array(element, len), true) = (ty, elementwise) {
+        let stride = element.size();
+        for index in 0..*len {
+            let offset = index * stride;
+            if !member_satisfied(
+                limit_type,
+                element,
+                required.add(offset),
+                actual.add(offset),
+            )? {
+                return Some(false);
+            }
+        }
+        return Some(true);
+    }
+
+    Some(match comparison {
+        Comparison::Ignored => return None,
+        Comparison::Bool => (actual as *const vk::Bool32).read_unaligned() != vk::FALSE,
+        Comparison::Flags => {
+            flag_bits(ty, actual) & flag_bits(ty, required) == flag_bits(ty, required)
+        }
+        Comparison::Exact => {
+            std::slice::from_raw_parts(actual, ty.size())
+                == std::slice::from_raw_parts(required, ty.size())
+        }
+        Comparison::Range => {
+            let MemberType::Array(element, _) = ty else {
+                return None;
+            };
+            let stride = element.size();
+            scalar(element, actual)? <= scalar(element, required)?
+                && scalar(element, actual.add(stride))? >= scalar(element, required.add(stride))?
+        }
+        Comparison::Min => scalar(ty, actual)? <= scalar(ty, required)?,
+        Comparison::Max => scalar(ty, actual)? >= scalar(ty, required)?,
+        Comparison::Multiple => {
+            let (required, actual) = (scalar(ty, required)?, scalar(ty, actual)?);
+            match ty {
+                MemberType::Float32 => actual <= required,
+                _ if actual == 0.0 => required == 0.0,
+                _ => required % actual == 0.0,
+            }
+        }
+    })
+}
+
+/// Reads a numeric or flags member as a float for comparisons.
+///
+/// Flags are read as numbers for limits like `maxFragmentShadingRateRasterizationSamples` which contain a
+/// single bit.
+pub(super) unsafe fn scalar(ty: &MemberType, data: *const u8) -> Option<f64> {
+    Some(match ty {
+        MemberType::Uint8 => f64::from(data.read()),
+        MemberType::Uint32 => f64::from((data as *const u32).read_unaligned()),
+        MemberType::Int32 => f64::from((data as *const i32).read_unaligned()),
+        MemberType::Uint64 => (data as *const u64).read_unaligned() as f64,
+        MemberType::Int64 => (data as *const i64).read_unaligned() as f64,
+        MemberType::Size => (data as *const usize).read_unaligned() as f64,
+        MemberType::Float32 => f64::from((data as *const f32).read_unaligned()),
+        MemberType::Flags(_) => flag_bits(ty, data) as f64,
+        _ => return None,
+    })
+}
+
+/// Returns how the member at `member_path` of the struct `struct_name` is compared.
+///
+/// Members of nested structs are separated by dots, for example `limits.minUniformBufferOffsetAlignment` of
+/// `VkPhysicalDeviceProperties`. Returns [`None`] if the struct or member is not known.
+pub fn member_comparison(struct_name: &str, member_path: &str) -> Option<Comparison> {
+    let (_, member, _, limit_type) = resolve_member(struct_name, member_path)?;
+    Some(comparison(limit_type, &member.ty))
+}
+
+/// Returns true if the device value `actual` of a member satisfies the `required` profile value.
+///
+/// Both values use the profile JSON representation, so flags are arrays of bit names and enums are variant
+/// names. Returns [`None`] if the member is not known, is never compared or if a value is invalid.
+pub fn value_satisfies(
+    struct_name: &str,
+    member_path: &str,
+    required: &Value,
+    actual: &Value,
+) -> Option<bool> {
+    let (info, member, offset, limit_type) = resolve_member(struct_name, member_path)?;
+    let write = |value: &Value| {
+        let mut storage = reflect::zeroed_storage(info.size);
+        // SAFETY: the storage is large enough and zero is a valid value for all members
+        unsafe {
+            reflect::write_struct(
+                info,
+                &nested_value(member_path, value),
+                storage.as_mut_ptr() as *mut u8,
+            )
+        }
+        .ok()
+        .map(|()| storage)
+    };
+    let (required, actual) = (write(required)?, write(actual)?);
+    // SAFETY: both storages contain the struct with the member at the offset
+    unsafe {
+        member_satisfied(
+            limit_type,
+            &member.ty,
+            (required.as_ptr() as *const u8).add(offset),
+            (actual.as_ptr() as *const u8).add(offset),
+        )
+    }
+}
+
+/// Returns the root struct, the member at `member_path`, its offset inside the root struct and the limit type
+/// deciding how it is compared.
+fn resolve_member(
+    struct_name: &str,
+    member_path: &str,
+) -> Option<(
+    &'static StructInfo,
+    &'static MemberInfo,
+    usize,
+    Option<LimitType>,
+)> {
+    let root = reflect::find_struct(struct_name)?;
+    let (mut info, mut offset, mut parent) = (root, 0, None);
+    let mut names = member_path.split('.').peekable();
+    while let Some(name) = names.next() {
+        let member = info.member(name)?;
+        offset += member.offset;
+        let limit_type = limit_type(info, member, parent);
+        if names.peek().is_none() {
+            return Some((root, member, offset, limit_type));
+        }
+        let MemberType::Struct(nested) = member.ty else {
+            return None;
+        };
+        parent = nested_limit_type(limit_type);
+        info = nested;
+    }
+    None
+}
+
+/// Wraps a value into the objects of a dot separated member path.
+fn nested_value(member_path: &str, value: &Value) -> StructValue {
+    let (name, rest) = member_path.split_once('.').unwrap_or((member_path, ""));
+    let value = if rest.is_empty() {
+        value.clone()
+    } else {
+        Value::Object(nested_value(rest, value))
+    };
+    StructValue::from_iter([(name.to_string(), value)])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_member_comparison() {
+        let limit = |member: &str| {
+            member_comparison("VkPhysicalDeviceProperties", &format!("limits.{member}"))
+        };
+        assert_eq!(limit("maxImageDimension2D"), Some(Comparison::Max));
+        assert_eq!(
+            limit("minUniformBufferOffsetAlignment"),
+            Some(Comparison::Min)
+        );
+        assert_eq!(
+            limit("framebufferColorSampleCounts"),
+            Some(Comparison::Flags)
+        );
+        assert_eq!(limit("pointSizeRange"), Some(Comparison::Range));
+        assert_eq!(limit("maxComputeWorkGroupSize"), Some(Comparison::Max));
+        assert_eq!(limit("unknown"), None);
+        assert_eq!(
+            member_comparison("VkPhysicalDeviceProperties", "deviceName"),
+            Some(Comparison::Ignored)
+        );
+        assert_eq!(
+            member_comparison(
+                "VkQueueFamilyProperties",
+                "minImageTransferGranularity.width"
+            ),
+            Some(Comparison::Multiple)
+        );
+        assert_eq!(
+            member_comparison(
+                "VkPhysicalDeviceDriverProperties",
+                "conformanceVersion.major"
+            ),
+            Some(Comparison::Ignored)
+        );
+        assert_eq!(
+            member_comparison("VkPhysicalDeviceFeatures", "robustBufferAccess"),
+            Some(Comparison::Bool)
+        );
+    }
+
+    #[test]
+    fn test_value_satisfies() {
+        let limit = |member: &str, required: Value, actual: Value| {
+            value_satisfies(
+                "VkPhysicalDeviceProperties",
+                &format!("limits.{member}"),
+                &required,
+                &actual,
+            )
+        };
+        assert_eq!(
+            limit("maxImageDimension2D", json!(8192), json!(16384)),
+            Some(true)
+        );
+        assert_eq!(
+            limit("maxImageDimension2D", json!(8192), json!(4096)),
+            Some(false)
+        );
+        assert_eq!(
+            limit("minUniformBufferOffsetAlignment", json!(256), json!(64)),
+            Some(true)
+        );
+        assert_eq!(
+            limit("minUniformBufferOffsetAlignment", json!(64), json!(256)),
+            Some(false)
+        );
+        assert_eq!(
+            limit(
+                "framebufferColorSampleCounts",
+                json!(["VK_SAMPLE_COUNT_1_BIT", "VK_SAMPLE_COUNT_4_BIT"]),
+                json!([
+                    "VK_SAMPLE_COUNT_1_BIT",
+                    "VK_SAMPLE_COUNT_4_BIT",
+                    "VK_SAMPLE_COUNT_8_BIT"
+                ]),
+            ),
+            Some(true)
+        );
+        assert_eq!(
+            limit(
+                "framebufferColorSampleCounts",
+                json!(["VK_SAMPLE_COUNT_8_BIT"]),
+                json!(["VK_SAMPLE_COUNT_1_BIT", "VK_SAMPLE_COUNT_4_BIT"]),
+            ),
+            Some(false)
+        );
+        assert_eq!(
+            limit("pointSizeRange", json!([1.0, 64.0]), json!([0.5, 256.0])),
+            Some(true)
+        );
+        assert_eq!(
+            limit("pointSizeRange", json!([0.5, 64.0]), json!([1.0, 256.0])),
+            Some(false)
+        );
+        assert_eq!(
+            limit(
+                "maxComputeWorkGroupSize",
+                json!([1024, 1024, 64]),
+                json!([1024, 1024, 32])
+            ),
+            Some(false)
+        );
+        let width = |required: Value, actual: Value| {
+            value_satisfies(
+                "VkQueueFamilyProperties",
+                "minImageTransferGranularity.width",
+                &required,
+                &actual,
+            )
+        };
+        assert_eq!(width(json!(1), json!(0)), Some(false));
+        assert_eq!(width(json!(0), json!(0)), Some(true));
+        assert_eq!(width(json!(8), json!(4)), Some(true));
+        assert_eq!(width(json!(8), json!(16)), Some(false));
+        assert_eq!(width(json!(6), json!(4)), Some(false));
+        assert_eq!(
+            limit("maxImageDimension2D", json!("large"), json!(4096)),
+            None
+        );
+        assert_eq!(
+            value_satisfies(
+                "VkPhysicalDeviceProperties",
+                "deviceName",
+                &json!("a"),
+                &json!("b")
+            ),
+            None
+        );
+    }
+}
@@ -0,0 +1,442 @@
+//! Coverage of devices and requirement sets by profiles.
+
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use super::{DeviceCapabilities, RequirementCheck, RequirementKind, SupportReport};
+use crate::json::{self, Capability, JsonError, ProfilesDocument};
+use crate::vp::ProfileProperties;
+use crate::{profiles, ProfilesResult, VulkanProfiles};
+
+/// The share of a fleet of devices each profile covers.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FleetCoverage {
+    /// The number of analyzed devices.
+    pub device_count: usize,
+    pub profiles: Vec<ProfileCoverage>,
+}
+
+/// The coverage of a single profile.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ProfileCoverage {
+    pub profile_name: String,
+    /// The number of devices supporting the profile.
+    pub supported_devices: usize,
+    /// The requirements not satisfied by at least one device, sorted by the number of failing devices in
+    /// descending order.
+    pub failures: Vec<RequirementFailures>,
+}
+
+/// The number of devices which do not satisfy a single requirement.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RequirementFailures {
+    pub kind: RequirementKind,
+    /// The name of the requirement as in [`super::RequirementCheck::name`].
+    pub name: String,
+    pub failing_devices: usize,
+}
+
+impl FleetCoverage {
+    /// Computes the coverage of capability blocks with optional minimum api versions.
+    pub fn new<'a>(
+        devices: &[DeviceCapabilities],
+        profiles: impl IntoIterator<Item = (&'a str, Option<u32>, &'a Capability)>,
+    ) -> Self {
+        Self {
+            device_count: devices.len(),
+            profiles: profiles
+                .into_iter()
+                .map(|(profile_name, api_version, required)| {
+                    ProfileCoverage::new(
+                        profile_name,
+                        devices
+                            .iter()
+                            .map(|device| device.check(api_version, required)),
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    /// Computes the coverage of all profiles defined in [`crate::profiles`].
+    ///
+    /// # Safety
+    /// The function pointers of `vk_profiles` must be valid. The names of the built-in profiles are always
+    /// null terminated.
+    pub unsafe fn for_builtin_profiles(
+        vk_profiles: &VulkanProfiles,
+        devices: &[DeviceCapabilities],
+    ) -> ProfilesResult<Self> {
+        let mut profiles = Vec::new();
+        for profile in profiles::all_profiles() {
+            let required = Capability::from_library(vk_profiles, &profile, None)?;
+            let api_version = profile
+                .profile_name_as_c_str()
+                .ok()
+                .and_then(profiles::min_api_version);
+            profiles.push((profile.profile_name_to_string(), api_version, required));
+        }
+        Ok(Self::new(
+            devices,
+            profiles
+                .iter()
+                .map(|(name, api_version, required)| (name.as_str(), *api_version, required)),
+        ))
+    }
+
+    /// Computes the coverage of all profiles of a JSON document.
+    pub fn for_document(
+        document: &ProfilesDocument,
+        devices: &[DeviceCapabilities],
+    ) -> Result<Self, JsonError> {
+        let mut profiles = Vec::new();
+        for profile_name in document.profiles.keys() {
+            let reports = devices
+                .iter()
+                .map(|device| device.check_document_profile(document, profile_name))
+                .collect::<Result<Vec<_>, _>>()?;
+            profiles.push(ProfileCoverage::new(profile_name, reports.into_iter()));
+        }
+        Ok(Self {
+            device_count: devices.len(),
+            profiles,
+        })
+    }
+
+    /// Sorts the profiles by the number of supported devices in descending order.
+    pub fn sort_by_coverage(&mut self) {
+        self.profiles
+            .sort_by_key(|profile| Reverse(profile.supported_devices));
+    }
+
+    /// Returns a CSV table with one row per profile.
+    ///
+    /// The columns are `profile`, `supported_devices`, `device_count` and `coverage` with the coverage in
+    /// percent.
+    pub fn profiles_csv(&self) -> String {
+        let mut csv = String::from("profile,supported_devices,device_count,coverage\n");
+        for profile in &self.profiles {
+            writeln!(
+                csv,
+                "{},{},{},{:.2}",
+                csv_field(&profile.profile_name),
+                profile.supported_devices,
+                self.device_count,
+                profile.coverage(self.device_count) * 100.0
+            )
+            .unwrap();
+        }
+        csv
+    }
+
+    /// Returns a CSV table with one row per profile and failing requirement.
+    ///
+    /// The columns are `profile`, `kind`, `requirement` and `failing_devices`.
+    pub fn failures_csv(&self) -> String {
+        let mut csv = String::from("profile,kind,requirement,failing_devices\n");
+        for profile in &self.profiles {
+            for failure in &profile.failures {
+                writeln!(
+                    csv,
+                    "{},{:?},{},{}",
+                    csv_field(&profile.profile_name),
+                    failure.kind,
+                    csv_field(&failure.name),
+                    failure.failing_devices
+                )
+                .unwrap();
+            }
+        }
+        csv
+    }
+}
+
+impl ProfileCoverage {
+    /// Aggregates the support reports of all devices for a single profile.
+    pub fn new(profile_name: &str, reports: impl Iterator<Item = SupportReport>) -> Self {
+        let mut supported_devices = 0;
+        let mut failures: BTreeMap<(RequirementKind, String), usize> = BTreeMap::new();
+        for report in reports {
+            if report.is_supported() {
+                supported_devices += 1;
+            }
+
+            // A requirement may be reported once per alternative block but counts once per device
+            let mut device_failures: Vec<_> = report
+                .failures()
+                .map(|check| (check.kind, check.name.clone()))
+                .collect();
+            device_failures.sort();
+            device_failures.dedup();
+            for failure in device_failures {
+                *failures.entry(failure).or_default() += 1;
+            }
+        }
+
+        let mut failures: Vec<_> = failures
+            .into_iter()
+            .map(|((kind, name), failing_devices)| RequirementFailures {
+                kind,
+                name,
+                failing_devices,
+            })
+            .collect();
+        failures.sort_by_key(|failure| Reverse(failure.failing_devices));
+
+        Self {
+            profile_name: profile_name.to_string(),
+            supported_devices,
+            failures,
+        }
+    }
+
+    /// Returns the share of `device_count` devices supporting the profile between 0 and 1.
+    pub fn coverage(&self, device_count: usize) -> f64 {
+        if device_count == 0 {
+            0.0
+        } else {
+            self.supported_devices as f64 / device_count as f64
+        }
+    }
+}
+
+/// How far a profile guarantees a set of requirements.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Coverage {
+    /// The minimum api version of the profile, if known.
+    pub api_version: Option<u32>,
+    /// The number of requirements the profile itself makes. Profiles with fewer requirements are less
+    /// demanding.
+    pub profile_requirements: usize,
+    /// The requirements checked against the guarantees of the profile.
+    pub report: SupportReport,
+}
+
+impl Coverage {
+    /// Checks `requirements` against the guarantees of a profile.
+    ///
+    /// A requirement is guaranteed if every device supporting the profile satisfies it, so the profile is
+    /// checked like a device reporting exactly the required values.
+    pub fn new(profile: &Capability, api_version: Option<u32>, requirements: &Capability) -> Self {
+        let guarantees = DeviceCapabilities {
+            device_name: String::new(),
+            api_version: json::api_version_string(api_version.unwrap_or(ash::vk::API_VERSION_1_0)),
+            capability: profile.clone(),
+        };
+        Self {
+            api_version,
+            profile_requirements: guarantees.check(None, profile).checks.len(),
+            report: guarantees.check(None, requirements),
+        }
+    }
+
+    /// Returns true if the profile guarantees all requirements.
+    pub fn covers_all(&self) -> bool {
+        self.report.is_supported()
+    }
+
+    /// Returns all requirements the profile does not guarantee.
+    pub fn uncovered(&self) -> impl Iterator<Item = &RequirementCheck> {
+        self.report.failures()
+    }
+
+    /// Orders profiles covering all requirements before others, then by the number of uncovered
+    /// requirements, the api version and the number of profile requirements.
+    fn demand(&self) -> (usize, Option<u32>, usize) {
+        (
+            self.uncovered().count(),
+            self.api_version,
+            self.profile_requirements,
+        )
+    }
+}
+
+/// Checks `requirements` against all profiles defined in [`crate::profiles`].
+///
+/// The profiles are sorted from the least demanding profile guaranteeing all requirements to the profile
+/// leaving the most requirements uncovered, so the first entry is the best choice if it
+/// [covers all](Coverage::covers_all) requirements. Instance extensions are not checked.
+///
+/// # Safety
+/// The function pointers of `vk_profiles` must be valid. The names of the built-in profiles are always
+/// null terminated.
+pub unsafe fn covering_profiles(
+    vk_profiles: &VulkanProfiles,
+    requirements: &Capability,
+) -> ProfilesResult<Vec<(ProfileProperties, Coverage)>> {
+    let mut profiles = Vec::new();
+    for profile in profiles::all_profiles() {
+        let guarantees = Capability::from_library(vk_profiles, &profile, None)?;
+        let api_version = profile
+            .profile_name_as_c_str()
+            .ok()
+            .and_then(profiles::min_api_version);
+        let coverage = Coverage::new(&guarantees, api_version, requirements);
+        profiles.push((profile, coverage));
+    }
+    sort_by_demand(&mut profiles);
+    Ok(profiles)
+}
+
+fn sort_by_demand<T>(profiles: &mut [(T, Coverage)]) {
+    profiles.sort_by_key(|(_, coverage)| coverage.demand());
+}
+
+/// Quotes a CSV field if necessary.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ash::vk;
+
+    fn device(api_version: &str, features: serde_json::Value) -> DeviceCapabilities {
+        DeviceCapabilities {
+            device_name: "Test".to_string(),
+            api_version: api_version.to_string(),
+            capability: Capability {
+                features: serde_json::from_value(features).unwrap(),
+                ..Default::default()
+            },
+        }
+    }
+
+    #[test]
+    fn test_fleet_coverage() {
+        let devices = [
+            device(
+                "1.3.250",
+                serde_json::json!({ "VkPhysicalDeviceFeatures": { "wideLines": true, "largePoints": true } }),
+            ),
+            device(
+                "1.3.250",
+                serde_json::json!({ "VkPhysicalDeviceFeatures": { "wideLines": true } }),
+            ),
+            device(
+                "1.1.100",
+                serde_json::json!({ "VkPhysicalDeviceFeatures": { "largePoints": true } }),
+            ),
+        ];
+        let wide_lines = Capability {
+            features: serde_json::from_value(
+                serde_json::json!({ "VkPhysicalDeviceFeatures": { "wideLines": true } }),
+            )
+            .unwrap(),
+            ..Default::default()
+        };
+        let both = Capability {
+            features: serde_json::from_value(serde_json::json!({
+                "VkPhysicalDeviceFeatures": { "wideLines": true, "largePoints": true }
+            }))
+            .unwrap(),
+            ..Default::default()
+        };
+
+        let mut coverage = FleetCoverage::new(
+            &devices,
+            [
+                ("VP_TEST_both", None, &both),
+                (
+                    "VP_TEST_wide_lines",
+                    Some(ash::vk::API_VERSION_1_3),
+                    &wide_lines,
+                ),
+            ],
+        );
+        assert_eq!(coverage.profiles[0].supported_devices, 1);
+        assert_eq!(
+            coverage.profiles[0].failures,
+            [
+                RequirementFailures {
+                    kind: RequirementKind::Feature,
+                    name: "VkPhysicalDeviceFeatures.largePoints".to_string(),
+                    failing_devices: 1,
+                },
+                RequirementFailures {
+                    kind: RequirementKind::Feature,
+                    name: "VkPhysicalDeviceFeatures.wideLines".to_string(),
+                    failing_devices: 1,
+                },
+            ]
+        );
+
+        coverage.sort_by_coverage();
+        assert_eq!(coverage.profiles[0].profile_name, "VP_TEST_wide_lines");
+        assert_eq!(
+            coverage.profiles_csv(),
+            "profile,supported_devices,device_count,coverage\n\
+             VP_TEST_wide_lines,2,3,66.67\n\
+             VP_TEST_both,1,3,33.33\n"
+        );
+        assert_eq!(
+            coverage.failures_csv().lines().nth(1),
+            Some("VP_TEST_wide_lines,ApiVersion,apiVersion,1")
+        );
+    }
+
+    #[test]
+    fn test_covering_profiles() {
+        let capability =
+            |value: serde_json::Value| -> Capability { serde_json::from_value(value).unwrap() };
+        let small = capability(serde_json::json!({
+            "features": { "VkPhysicalDeviceFeatures": { "wideLines": true } },
+            "properties": { "VkPhysicalDeviceProperties": { "limits": { "maxImageDimension2D": 4096 } } }
+        }));
+        let large = capability(serde_json::json!({
+            "extensions": { "VK_KHR_swapchain": 70 },
+            "features": { "VkPhysicalDeviceFeatures": { "wideLines": true, "largePoints": true } },
+            "properties": { "VkPhysicalDeviceProperties": { "limits": { "maxImageDimension2D": 16384 } } }
+        }));
+        let larger = capability(serde_json::json!({
+            "extensions": { "VK_KHR_swapchain": 70, "VK_KHR_maintenance4": 1 },
+            "features": { "VkPhysicalDeviceFeatures": { "wideLines": true, "largePoints": true } },
+            "properties": { "VkPhysicalDeviceProperties": { "limits": { "maxImageDimension2D": 16384 } } }
+        }));
+        let requirements = capability(serde_json::json!({
+            "extensions": { "VK_KHR_swapchain": 1 },
+            "features": { "VkPhysicalDeviceFeatures": { "wideLines": true } },
+            "properties": { "VkPhysicalDeviceProperties": { "limits": { "maxImageDimension2D": 8192 } } }
+        }));
+
+        let mut profiles = vec![
+            (
+                "larger",
+                Coverage::new(&larger, Some(vk::API_VERSION_1_1), &requirements),
+            ),
+            (
+                "small",
+                Coverage::new(&small, Some(vk::API_VERSION_1_0), &requirements),
+            ),
+            (
+                "large",
+                Coverage::new(&large, Some(vk::API_VERSION_1_1), &requirements),
+            ),
+        ];
+        sort_by_demand(&mut profiles);
+
+        assert_eq!(
+            profiles.iter().map(|(name, _)| *name).collect::<Vec<_>>(),
+            ["large", "larger", "small"]
+        );
+        assert!(profiles[0].1.covers_all());
+        assert_eq!(profiles[0].1.profile_requirements, 4);
+        assert_eq!(
+            profiles[2]
+                .1
+                .uncovered()
+                .map(|check| check.name.as_str())
+                .collect::<Vec<_>>(),
+            [
+                "VK_KHR_swapchain",
+                "VkPhysicalDeviceProperties.limits.maxImageDimension2D"
+            ]
+        );
+    }
+}
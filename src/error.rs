@@ -0,0 +1,234 @@
+//! Error types returned by the vulkan profiles wrappers.
+//!
+//! The vulkan profiles library only reports bare [`vk::Result`] codes. The [`ProfilesError`] type
+//! additionally records which library call failed and which profiles and blocks were involved.
+
+use std::ffi::CStr;
+use std::fmt;
+
+use ash::vk;
+
+use crate::vp::{BlockProperties, ProfileProperties};
+
+/// Result type used by all fallible functions of this crate.
+pub type ProfilesResult<T> = Result<T, ProfilesError>;
+
+/// The profiles and profile blocks involved in a failed call.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ErrorContext {
+    pub profiles: Vec<String>,
+    /// The blocks in `profile:block` notation, for example `VP_KHR_roadmap_2022:baseline`.
+    pub blocks: Vec<String>,
+}
+
+impl ErrorContext {
+    /// Creates a context for a query of a single profile and optional block.
+    pub(crate) fn profile(profile: &ProfileProperties, block_name: Option<&CStr>) -> Self {
+        let profile_name = profile.profile_name_to_string();
+        Self {
+            blocks: block_name
+                .map(|block_name| block_id(&profile_name, &block_name.to_string_lossy()))
+                .into_iter()
+                .collect(),
+            profiles: vec![profile_name],
+        }
+    }
+
+    /// Creates a context from the enabled profile and block arrays of a create info struct.
+    pub(crate) unsafe fn enabled(
+        profiles: *const ProfileProperties,
+        profile_count: u32,
+        blocks: *const BlockProperties,
+        block_count: u32,
+    ) -> Self {
+        let profiles = if profiles.is_null() {
+            &[]
+        } else {
+            std::slice::from_raw_parts(profiles, profile_count as usize)
+        };
+        let blocks = if blocks.is_null() {
+            &[]
+        } else {
+            std::slice::from_raw_parts(blocks, block_count as usize)
+        };
+
+        Self {
+            profiles: profiles
+                .iter()
+                .map(ProfileProperties::profile_name_to_string)
+                .collect(),
+            blocks: blocks
+                .iter()
+                .map(|block| {
+                    block_id(
+                        &block.profiles.profile_name_to_string(),
+                        &block.block_name_to_string(),
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Formats a block of a profile like the blocks of an [`ErrorContext`].
+fn block_id(profile_name: &str, block_name: &str) -> String {
+    format!("{profile_name}:{block_name}")
+}
+
+impl fmt::Display for ErrorContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "profiles: [{}]", self.profiles.join(", "))?;
+        if !self.blocks.is_empty() {
+            write!(f, ", blocks: [{}]", self.blocks.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// An error returned by the vulkan profiles wrappers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProfilesError {
+    /// A vulkan or vulkan profiles library call returned an error code.
+    Vulkan {
+        /// The name of the failed function, for example `vpCreateDevice`.
+        call: &'static str,
+        context: ErrorContext,
+        result: vk::Result,
+    },
+    /// A query returned a different number of elements than the caller provided space for.
+    CountMismatch {
+        call: &'static str,
+        context: ErrorContext,
+        expected: usize,
+        actual: usize,
+    },
+    /// A queue request of a [`crate::builder::ProfileDeviceBuilder`] cannot be satisfied by any queue
+    /// family of the physical device. A `queue_count` of zero means the request had no priorities.
+    UnsatisfiableQueueRequest {
+        queue_family_index: Option<u32>,
+        queue_flags: vk::QueueFlags,
+        queue_count: usize,
+    },
+    /// A struct of the pNext chain of a device create info is not a feature struct known to this crate and
+    /// cannot be copied into an [`crate::builder::OwnedDeviceCreateInfo`].
+    UnsupportedStructure { structure_type: vk::StructureType },
+}
+
+impl ProfilesError {
+    pub(crate) fn vulkan(call: &'static str, context: ErrorContext, result: vk::Result) -> Self {
+        Self::Vulkan {
+            call,
+            context,
+            result,
+        }
+    }
+
+    /// Returns the [`vk::Result`] which best describes this error.
+    ///
+    /// Count mismatches are reported as [`vk::Result::INCOMPLETE`], unsatisfiable queue requests as
+    /// [`vk::Result::ERROR_INITIALIZATION_FAILED`] and unsupported structures as
+    /// [`vk::Result::ERROR_FEATURE_NOT_PRESENT`].
+    pub fn result(&self) -> vk::Result {
+        match self {
+            Self::Vulkan { result, .. } => *result,
+            Self::CountMismatch { .. } => vk::Result::INCOMPLETE,
+            Self::UnsatisfiableQueueRequest { .. } => vk::Result::ERROR_INITIALIZATION_FAILED,
+            Self::UnsupportedStructure { .. } => vk::Result::ERROR_FEATURE_NOT_PRESENT,
+        }
+    }
+
+    /// Returns the profiles and blocks involved in the failed call if any.
+    pub fn context(&self) -> Option<&ErrorContext> {
+        match self {
+            Self::Vulkan { context, .. } | Self::CountMismatch { context, .. } => Some(context),
+            Self::UnsatisfiableQueueRequest { .. } | Self::UnsupportedStructure { .. } => None,
+        }
+    }
+}
+
+impl fmt::Display for ProfilesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Vulkan {
+                call,
+                context,
+                result,
+            } => write!(f, "{call} failed with {result:?} ({context})"),
+            Self::CountMismatch {
+                call,
+                context,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "{call} returned {actual} elements but {expected} were expected ({context})"
+            ),
+            Self::UnsatisfiableQueueRequest { queue_count: 0, .. } => {
+                write!(f, "queue requests must contain at least one priority")
+            }
+            Self::UnsatisfiableQueueRequest {
+                queue_family_index: Some(index),
+                queue_count,
+                ..
+            } => write!(
+                f,
+                "queue family {index} cannot provide {queue_count} additional queues"
+            ),
+            Self::UnsatisfiableQueueRequest {
+                queue_family_index: None,
+                queue_flags,
+                queue_count,
+            } => write!(
+                f,
+                "no queue family supporting {queue_flags:?} can provide {queue_count} additional queues"
+            ),
+            Self::UnsupportedStructure { structure_type } => write!(
+                f,
+                "structure type {} of the pNext chain is not a known feature struct",
+                structure_type.as_raw()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ProfilesError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Vulkan { result, .. } => Some(result),
+            _ => None,
+        }
+    }
+}
+
+impl From<ProfilesError> for vk::Result {
+    fn from(error: ProfilesError) -> Self {
+        error.result()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::profiles;
+
+    #[test]
+    fn test_error_context() {
+        let profile = profiles::KhrRoadmap2022::profile_properties();
+        let mut block = BlockProperties {
+            profiles: profile,
+            api_version: vk::API_VERSION_1_3,
+            block_name: unsafe { std::mem::zeroed() },
+        };
+        for (target, byte) in block.block_name.iter_mut().zip(b"baseline") {
+            *target = *byte as std::ffi::c_char;
+        }
+
+        let queried = ErrorContext::profile(&profile, Some(c"baseline"));
+        let enabled = unsafe { ErrorContext::enabled(&profile, 1, &block, 1) };
+        assert_eq!(queried, enabled);
+        assert_eq!(
+            queried.to_string(),
+            "profiles: [VP_KHR_roadmap_2022], blocks: [VP_KHR_roadmap_2022:baseline]"
+        );
+    }
+}
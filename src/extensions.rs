@@ -0,0 +1,272 @@
+//! Resolution of extension dependencies and core promotions.
+//!
+//! The vulkan profiles library only returns the flat extension lists of a profile. When additional extensions
+//! are enabled on top of a profile their dependencies must be enabled as well, and extensions which are part
+//! of the core api version do not need to be enabled anymore. [`resolve_extensions`] does both using the
+//! registry data of [`crate::reflect::find_extension`].
+
+use std::ffi::{CStr, CString};
+
+use crate::builder::{enabled_blocks, profiles_api_version};
+use crate::reflect::{self, Dependency, ExtensionType};
+use crate::vp::{BlockProperties, ProfileProperties};
+use crate::{ProfilesResult, VulkanProfiles};
+
+/// The instance and device extensions to enable for a set of requested extensions.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ExtensionResolution {
+    pub instance_extensions: Vec<CString>,
+    pub device_extensions: Vec<CString>,
+    /// The extensions which were added because a requested extension depends on them.
+    pub added: Vec<CString>,
+    /// The requested extensions which were removed because they are promoted to the api version.
+    pub promoted: Vec<CString>,
+    /// The requested extensions whose dependencies can not be satisfied with the api version.
+    pub unsatisfied: Vec<CString>,
+    /// The requested extensions which are not known to this crate. They are kept as is.
+    pub unknown: Vec<CString>,
+}
+
+/// Adds the dependencies of the requested extensions and removes the extensions promoted to `api_version`.
+///
+/// If a dependency has alternatives which are not satisfied yet, the first alternative which can be satisfied
+/// is enabled. Device extensions may depend on instance extensions, for example `VK_KHR_swapchain` on
+/// `VK_KHR_surface`, which are added to the instance extensions. Unknown extensions stay in the list they
+/// were requested in.
+pub fn resolve_extensions(
+    api_version: u32,
+    instance_extensions: &[&CStr],
+    device_extensions: &[&CStr],
+) -> ExtensionResolution {
+    let mut resolution = ExtensionResolution::default();
+
+    let mut requested: Vec<(&CStr, ExtensionType)> = Vec::new();
+    let types = [ExtensionType::Instance, ExtensionType::Device];
+    for (extensions, ty) in [instance_extensions, device_extensions]
+        .into_iter()
+        .zip(types)
+    {
+        for name in extensions {
+            if !requested.iter().any(|(requested, _)| requested == name) {
+                requested.push((name, ty));
+            }
+        }
+    }
+
+    let mut enabled: Vec<&str> = Vec::new();
+    for (name, ty) in &requested {
+        match name.to_str().ok().and_then(reflect::find_extension) {
+            Some(info) => enabled.push(info.name),
+            None => {
+                resolution.unknown.push((*name).to_owned());
+                match ty {
+                    ExtensionType::Instance => &mut resolution.instance_extensions,
+                    ExtensionType::Device => &mut resolution.device_extensions,
+                }
+                .push((*name).to_owned());
+            }
+        }
+    }
+
+    // Newly enabled extensions are appended and resolved in later iterations
+    let requested_count = enabled.len();
+    let mut index = 0;
+    while index < enabled.len() {
+        let info = reflect::find_extension(enabled[index]).unwrap();
+        index += 1;
+        if info.is_promoted(api_version) {
+            continue;
+        }
+        if let Some(dependency) = info.dependencies() {
+            if !enable_dependency(&dependency, api_version, &mut enabled) {
+                resolution.unsatisfied.push(to_cstring(info.name));
+            }
+        }
+    }
+
+    for (index, name) in enabled.into_iter().enumerate() {
+        let info = reflect::find_extension(name).unwrap();
+        if info.is_promoted(api_version) {
+            if index < requested_count {
+                resolution.promoted.push(to_cstring(name));
+            }
+            continue;
+        }
+        if index >= requested_count {
+            resolution.added.push(to_cstring(name));
+        }
+        match info.ty {
+            ExtensionType::Instance => &mut resolution.instance_extensions,
+            ExtensionType::Device => &mut resolution.device_extensions,
+        }
+        .push(to_cstring(name));
+    }
+    resolution
+}
+
+/// Resolves the extensions of the enabled profiles and profile blocks together with additional extensions.
+///
+/// If `api_version` is [`None`] the api version returned by
+/// [`assemble_instance_create_info`](crate::builder::assemble_instance_create_info) for the same profiles is
+/// used. See [`resolve_extensions`].
+///
+/// # Safety
+/// The profile names of `profiles` and the names of `blocks` must be null terminated.
+pub unsafe fn resolve_profile_extensions(
+    vk_profiles: &VulkanProfiles,
+    profiles: &[ProfileProperties],
+    blocks: &[BlockProperties],
+    api_version: Option<u32>,
+    instance_extensions: &[&CStr],
+    device_extensions: &[&CStr],
+) -> ProfilesResult<ExtensionResolution> {
+    let mut profile_instance_extensions = Vec::new();
+    let mut profile_device_extensions = Vec::new();
+    for (profile, block_name) in enabled_blocks(profiles, blocks) {
+        profile_instance_extensions
+            .extend(vk_profiles.get_profile_instance_extension_properties(profile, block_name)?);
+        profile_device_extensions
+            .extend(vk_profiles.get_profile_device_extension_properties(profile, block_name)?);
+    }
+
+    let instance_names: Vec<&CStr> = profile_instance_extensions
+        .iter()
+        .filter_map(|properties| properties.extension_name_as_c_str().ok())
+        .chain(instance_extensions.iter().copied())
+        .collect();
+    let device_names: Vec<&CStr> = profile_device_extensions
+        .iter()
+        .filter_map(|properties| properties.extension_name_as_c_str().ok())
+        .chain(device_extensions.iter().copied())
+        .collect();
+
+    Ok(resolve_extensions(
+        api_version.unwrap_or_else(|| profiles_api_version(profiles, blocks)),
+        &instance_names,
+        &device_names,
+    ))
+}
+
+/// Enables the extensions required to satisfy `dependency`. Returns false if it can not be satisfied.
+fn enable_dependency(dependency: &Dependency, api_version: u32, enabled: &mut Vec<&str>) -> bool {
+    if dependency.is_satisfied(api_version, enabled) {
+        return true;
+    }
+    match dependency {
+        Dependency::Provider(provider) => match reflect::find_extension(provider) {
+            Some(info) => {
+                enabled.push(info.name);
+                true
+            }
+            // Core versions above the api version
+            None => false,
+        },
+        Dependency::All(dependencies) => dependencies
+            .iter()
+            .all(|dependency| enable_dependency(dependency, api_version, enabled)),
+        Dependency::Any(dependencies) => dependencies.iter().any(|dependency| {
+            let mut alternative = enabled.clone();
+            let satisfied = enable_dependency(dependency, api_version, &mut alternative);
+            if satisfied {
+                *enabled = alternative;
+            }
+            satisfied
+        }),
+    }
+}
+
+fn to_cstring(name: &str) -> CString {
+    CString::new(name).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ash::vk;
+
+    fn names(extensions: &[CString]) -> Vec<&str> {
+        extensions
+            .iter()
+            .map(|name| name.to_str().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_resolve_extensions() {
+        let resolution = resolve_extensions(
+            vk::API_VERSION_1_0,
+            &[],
+            &[
+                c"VK_KHR_swapchain",
+                c"VK_KHR_dynamic_rendering",
+                c"VK_VENDOR_unknown",
+            ],
+        );
+        assert_eq!(
+            names(&resolution.instance_extensions),
+            ["VK_KHR_surface", "VK_KHR_get_physical_device_properties2"]
+        );
+        assert_eq!(
+            names(&resolution.device_extensions),
+            [
+                "VK_VENDOR_unknown",
+                "VK_KHR_swapchain",
+                "VK_KHR_dynamic_rendering",
+                "VK_KHR_depth_stencil_resolve",
+                "VK_KHR_create_renderpass2",
+                "VK_KHR_multiview",
+                "VK_KHR_maintenance2",
+            ]
+        );
+        assert_eq!(names(&resolution.unknown), ["VK_VENDOR_unknown"]);
+        assert!(resolution.promoted.is_empty());
+        assert!(resolution.unsatisfied.is_empty());
+
+        let multiview = reflect::find_extension("VK_KHR_multiview")
+            .unwrap()
+            .dependencies()
+            .unwrap();
+        assert_eq!(
+            multiview,
+            Dependency::Any(vec![
+                Dependency::Provider("VK_KHR_get_physical_device_properties2"),
+                Dependency::Provider("VK_VERSION_1_1"),
+            ])
+        );
+        assert!(multiview.is_satisfied(vk::API_VERSION_1_1, &[]));
+
+        let resolution = resolve_extensions(
+            vk::API_VERSION_1_1,
+            &[],
+            &[c"VK_KHR_dynamic_rendering", c"VK_KHR_multiview"],
+        );
+        assert!(resolution.instance_extensions.is_empty());
+        assert_eq!(
+            names(&resolution.device_extensions),
+            [
+                "VK_KHR_dynamic_rendering",
+                "VK_KHR_depth_stencil_resolve",
+                "VK_KHR_create_renderpass2",
+            ]
+        );
+        assert_eq!(names(&resolution.promoted), ["VK_KHR_multiview"]);
+        assert!(resolution.unsatisfied.is_empty());
+
+        let resolution = resolve_extensions(
+            vk::API_VERSION_1_2,
+            &[],
+            &[
+                c"VK_KHR_dynamic_rendering",
+                c"VK_KHR_timeline_semaphore",
+                c"VK_EXT_shader_tile_image",
+            ],
+        );
+        assert_eq!(
+            names(&resolution.device_extensions),
+            ["VK_KHR_dynamic_rendering", "VK_EXT_shader_tile_image"]
+        );
+        assert!(resolution.added.is_empty());
+        assert_eq!(names(&resolution.promoted), ["VK_KHR_timeline_semaphore"]);
+        assert_eq!(names(&resolution.unsatisfied), ["VK_EXT_shader_tile_image"]);
+    }
+}
@@ -0,0 +1,305 @@
+//! Typed access to the format requirements of profiles.
+
+use std::collections::BTreeMap;
+use std::ffi::CStr;
+
+use ash::vk;
+
+use crate::vp::ProfileProperties;
+use crate::{ErrorContext, ProfilesError, ProfilesResult, VulkanProfiles};
+
+/// The format features a profile requires for a single format.
+///
+/// The features of [`vk::FormatProperties`] and [`vk::FormatProperties3`] are combined into 64-bit
+/// [`vk::FormatFeatureFlags2`] values. The lower 32 bits of both flag types have the same meaning.
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash, Debug)]
+pub struct FormatRequirement {
+    pub linear_tiling_features: vk::FormatFeatureFlags2,
+    pub optimal_tiling_features: vk::FormatFeatureFlags2,
+    pub buffer_features: vk::FormatFeatureFlags2,
+}
+
+impl FormatRequirement {
+    /// Converts 32-bit format properties.
+    pub fn from_properties(properties: &vk::FormatProperties) -> Self {
+        let convert = |flags: vk::FormatFeatureFlags| {
+            vk::FormatFeatureFlags2::from_raw(u64::from(flags.as_raw()))
+        };
+        Self {
+            linear_tiling_features: convert(properties.linear_tiling_features),
+            optimal_tiling_features: convert(properties.optimal_tiling_features),
+            buffer_features: convert(properties.buffer_features),
+        }
+    }
+
+    /// Converts 64-bit format properties.
+    pub fn from_properties3(properties: &vk::FormatProperties3) -> Self {
+        Self {
+            linear_tiling_features: properties.linear_tiling_features,
+            optimal_tiling_features: properties.optimal_tiling_features,
+            buffer_features: properties.buffer_features,
+        }
+    }
+
+    /// Returns the union of the features of `self` and `other`.
+    pub fn union(&self, other: &FormatRequirement) -> Self {
+        Self {
+            linear_tiling_features: self.linear_tiling_features | other.linear_tiling_features,
+            optimal_tiling_features: self.optimal_tiling_features | other.optimal_tiling_features,
+            buffer_features: self.buffer_features | other.buffer_features,
+        }
+    }
+
+    /// Returns the features which are part of both `self` and `other`.
+    pub fn intersection(&self, other: &FormatRequirement) -> Self {
+        Self {
+            linear_tiling_features: self.linear_tiling_features & other.linear_tiling_features,
+            optimal_tiling_features: self.optimal_tiling_features & other.optimal_tiling_features,
+            buffer_features: self.buffer_features & other.buffer_features,
+        }
+    }
+
+    /// Returns the features for images with the specified tiling or [`None`] for tilings other than
+    /// [`vk::ImageTiling::LINEAR`] and [`vk::ImageTiling::OPTIMAL`].
+    pub fn tiling_features(&self, tiling: vk::ImageTiling) -> Option<vk::FormatFeatureFlags2> {
+        match tiling {
+            vk::ImageTiling::LINEAR => Some(self.linear_tiling_features),
+            vk::ImageTiling::OPTIMAL => Some(self.optimal_tiling_features),
+            _ => None,
+        }
+    }
+
+    /// Returns true if images with the specified tiling support all `required` features.
+    pub fn supports(&self, tiling: vk::ImageTiling, required: vk::FormatFeatureFlags2) -> bool {
+        self.tiling_features(tiling)
+            .is_some_and(|features| features.contains(required))
+    }
+
+    /// Returns true if all features of `requirement` are also part of `self`.
+    pub fn contains(&self, requirement: &FormatRequirement) -> bool {
+        self.linear_tiling_features
+            .contains(requirement.linear_tiling_features)
+            && self
+                .optimal_tiling_features
+                .contains(requirement.optimal_tiling_features)
+            && self.buffer_features.contains(requirement.buffer_features)
+    }
+}
+
+/// Returns the first candidate whose requirement guarantees all `required` features for images with the
+/// specified tiling.
+pub fn choose_guaranteed_format(
+    requirements: &BTreeMap<vk::Format, FormatRequirement>,
+    candidates: &[vk::Format],
+    tiling: vk::ImageTiling,
+    required: vk::FormatFeatureFlags2,
+) -> Option<vk::Format> {
+    candidates.iter().copied().find(|format| {
+        requirements
+            .get(format)
+            .is_some_and(|requirement| requirement.supports(tiling, required))
+    })
+}
+
+/// Queries the format features supported by a physical device.
+///
+/// If the physical device supports vulkan 1.3 or `VK_KHR_format_feature_flags2` the 64-bit features are
+/// queried as well.
+///
+/// # Safety
+/// `physical_device` must be a valid physical device of `instance`. For devices supporting vulkan 1.1 or
+/// later the instance must have been created with vulkan 1.1 or later.
+pub unsafe fn get_physical_device_format_requirement(
+    instance: &ash::Instance,
+    physical_device: vk::PhysicalDevice,
+    format: vk::Format,
+) -> ProfilesResult<FormatRequirement> {
+    let api_version = instance
+        .get_physical_device_properties(physical_device)
+        .api_version;
+    if api_version < vk::API_VERSION_1_1 {
+        let properties = instance.get_physical_device_format_properties(physical_device, format);
+        return Ok(FormatRequirement::from_properties(&properties));
+    }
+
+    let has_properties3 = api_version >= vk::API_VERSION_1_3
+        || instance
+            .enumerate_device_extension_properties(physical_device)
+            .map_err(|result| {
+                ProfilesError::vulkan(
+                    "vkEnumerateDeviceExtensionProperties",
+                    ErrorContext::default(),
+                    result,
+                )
+            })?
+            .iter()
+            .any(|extension| {
+                extension.extension_name_as_c_str() == Ok(ash::khr::format_feature_flags2::NAME)
+            });
+
+    let mut properties3 = vk::FormatProperties3::default();
+    let mut properties2 = vk::FormatProperties2::default();
+    if has_properties3 {
+        properties2 = properties2.push_next(&mut properties3);
+    }
+    instance.get_physical_device_format_properties2(physical_device, format, &mut properties2);
+
+    Ok(
+        FormatRequirement::from_properties(&properties2.format_properties)
+            .union(&FormatRequirement::from_properties3(&properties3)),
+    )
+}
+
+impl VulkanProfiles {
+    /// Returns the format features required by a profile for each format it defines.
+    ///
+    /// Both [`vk::FormatProperties2`] and [`vk::FormatProperties3`] structures defined by the profile are queried
+    /// and combined.
+    ///
+    /// # Safety
+    /// The profile name of `profile` must be null terminated.
+    pub unsafe fn profile_format_requirements(
+        &self,
+        profile: &ProfileProperties,
+        block_name: Option<&CStr>,
+    ) -> ProfilesResult<BTreeMap<vk::Format, FormatRequirement>> {
+        let formats = self.get_profile_formats(profile, block_name)?;
+        let structure_types = self.get_profile_format_structure_types(profile, block_name)?;
+        let has_properties3 = structure_types.contains(&vk::StructureType::FORMAT_PROPERTIES_3);
+
+        let mut requirements = BTreeMap::new();
+        for format in formats {
+            let mut properties3 = vk::FormatProperties3::default();
+            let mut properties2 = vk::FormatProperties2::default();
+            if has_properties3 {
+                properties2 = properties2.push_next(&mut properties3);
+            }
+
+            self.get_profile_format_properties(
+                profile,
+                block_name,
+                format,
+                &mut *(&mut properties2 as *mut vk::FormatProperties2 as *mut vk::BaseOutStructure),
+            );
+
+            let requirement = FormatRequirement::from_properties(&properties2.format_properties)
+                .union(&FormatRequirement::from_properties3(&properties3));
+            requirements.insert(format, requirement);
+        }
+
+        Ok(requirements)
+    }
+
+    /// Returns the first candidate format for which the profile guarantees all `required` features for images
+    /// with the specified tiling.
+    ///
+    /// Candidates should be ordered by preference. See [`choose_guaranteed_format`].
+    ///
+    /// # Safety
+    /// The profile name of `profile` must be null terminated.
+    pub unsafe fn choose_format(
+        &self,
+        profile: &ProfileProperties,
+        candidates: &[vk::Format],
+        tiling: vk::ImageTiling,
+        required: vk::FormatFeatureFlags2,
+    ) -> ProfilesResult<Option<vk::Format>> {
+        let requirements = self.profile_format_requirements(profile, None)?;
+        Ok(choose_guaranteed_format(
+            &requirements,
+            candidates,
+            tiling,
+            required,
+        ))
+    }
+
+    /// Like [`Self::choose_format`] but falls back to probing the physical device if the profile does not
+    /// guarantee any of the candidates.
+    ///
+    /// Candidates guaranteed by the profile are always preferred and returned without querying the physical
+    /// device. Otherwise the remaining candidates are probed and the first one the physical device supports
+    /// is returned. See [`get_physical_device_format_requirement`].
+    ///
+    /// # Safety
+    /// The profile name of `profile` must be null terminated and the requirements of
+    /// [`get_physical_device_format_requirement`] must be met.
+    pub unsafe fn choose_format_for_device(
+        &self,
+        instance: &ash::Instance,
+        physical_device: vk::PhysicalDevice,
+        profile: &ProfileProperties,
+        candidates: &[vk::Format],
+        tiling: vk::ImageTiling,
+        required: vk::FormatFeatureFlags2,
+    ) -> ProfilesResult<Option<vk::Format>> {
+        let requirements = self.profile_format_requirements(profile, None)?;
+        if let Some(format) = choose_guaranteed_format(&requirements, candidates, tiling, required)
+        {
+            return Ok(Some(format));
+        }
+
+        // Only the candidates the profile does not guarantee are probed
+        let guaranteed = |format: &vk::Format| {
+            requirements
+                .get(format)
+                .is_some_and(|requirement| requirement.supports(tiling, required))
+        };
+        for format in candidates.iter().filter(|format| !guaranteed(format)) {
+            if get_physical_device_format_requirement(instance, physical_device, *format)?
+                .supports(tiling, required)
+            {
+                return Ok(Some(*format));
+            }
+        }
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_choose_guaranteed_format() {
+        let depth = vk::FormatFeatureFlags2::DEPTH_STENCIL_ATTACHMENT;
+        let requirements = BTreeMap::from([
+            (
+                vk::Format::D24_UNORM_S8_UINT,
+                FormatRequirement {
+                    linear_tiling_features: depth,
+                    ..Default::default()
+                },
+            ),
+            (
+                vk::Format::D32_SFLOAT,
+                FormatRequirement {
+                    optimal_tiling_features: depth | vk::FormatFeatureFlags2::SAMPLED_IMAGE,
+                    ..Default::default()
+                },
+            ),
+        ]);
+
+        let candidates = [
+            vk::Format::D32_SFLOAT_S8_UINT,
+            vk::Format::D24_UNORM_S8_UINT,
+            vk::Format::D32_SFLOAT,
+        ];
+        assert_eq!(
+            choose_guaranteed_format(&requirements, &candidates, vk::ImageTiling::OPTIMAL, depth),
+            Some(vk::Format::D32_SFLOAT)
+        );
+        assert_eq!(
+            choose_guaranteed_format(&requirements, &candidates, vk::ImageTiling::LINEAR, depth),
+            Some(vk::Format::D24_UNORM_S8_UINT)
+        );
+        assert_eq!(
+            choose_guaranteed_format(
+                &requirements,
+                &candidates,
+                vk::ImageTiling::DRM_FORMAT_MODIFIER_EXT,
+                depth
+            ),
+            None
+        );
+    }
+}
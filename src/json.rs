@@ -0,0 +1,1025 @@
+//! A pure rust model of vulkan profile JSON files.
+//!
+//! [`ProfilesDocument`] mirrors the format used by the `profiles/*.json` files of the vulkan profiles
+//! repository. The query functions map the described capabilities onto the same ash structs which are filled
+//! by the library functions of [`crate::VulkanProfiles`], so both sources can be compared directly.
+//!
+//! ```no_run
+//! use ash::vk;
+//! use vk_profiles_rs::json::ProfilesDocument;
+//!
+//! # fn main() -> Result<(), vk_profiles_rs::json::JsonError> {
+//! let document: ProfilesDocument = std::fs::read_to_string("VP_EXAMPLE_profile.json")
+//!     .unwrap()
+//!     .parse()?;
+//!
+//! let mut vulkan_12_features = vk::PhysicalDeviceVulkan12Features::default();
+//! let mut features = vk::PhysicalDeviceFeatures2::default().push_next(&mut vulkan_12_features);
+//! unsafe {
+//!     document.get_profile_features(
+//!         "VP_EXAMPLE_profile",
+//!         None,
+//!         &mut *(&mut features as *mut _ as *mut vk::BaseOutStructure),
+//!     )?
+//! };
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+use ash::vk;
+use serde::{Deserialize, Serialize};
+
+use crate::format::FormatRequirement;
+use crate::prelude::c_char_array_from_str;
+use crate::queue_family::OwnedQueueFamilyProperties;
+use crate::reflect::{self, ExtensionType, StructCategory, StructInfo};
+
+mod export;
+mod spanned;
+pub mod validate;
+
+pub use export::SCHEMA;
+pub(crate) use export::{chain_map, extension_map, insert_format, queue_family_maps};
+
+/// The members of a single struct keyed by their vulkan names.
+pub type StructValue = serde_json::Map<String, serde_json::Value>;
+
+/// A complete profile JSON document.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ProfilesDocument {
+    /// The URI of the JSON schema the document conforms to.
+    #[serde(rename = "$schema")]
+    pub schema: String,
+    /// Named capability blocks which can be referenced by profiles.
+    #[serde(default)]
+    pub capabilities: BTreeMap<String, Capability>,
+    #[serde(default)]
+    pub profiles: BTreeMap<String, Profile>,
+}
+
+/// A named block of requirements.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Capability {
+    /// Required instance and device extensions and their minimum spec version.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub extensions: BTreeMap<String, u32>,
+    /// Required features keyed by struct name.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub features: BTreeMap<String, StructValue>,
+    /// Required properties keyed by struct name.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub properties: BTreeMap<String, StructValue>,
+    /// Required format properties keyed by format name and struct name.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub formats: BTreeMap<String, BTreeMap<String, StructValue>>,
+    /// Required queue families. Each entry is keyed by struct name.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub queue_families_properties: Vec<BTreeMap<String, StructValue>>,
+}
+
+impl Capability {
+    /// Returns the required instance extensions.
+    pub fn instance_extensions(&self) -> impl Iterator<Item = (&String, &u32)> {
+        self.extensions
+            .iter()
+            .filter(|(name, _)| extension_type(name) == ExtensionType::Instance)
+    }
+
+    /// Returns the required device extensions. Extensions unknown to this crate are treated as device
+    /// extensions.
+    pub fn device_extensions(&self) -> impl Iterator<Item = (&String, &u32)> {
+        self.extensions
+            .iter()
+            .filter(|(name, _)| extension_type(name) == ExtensionType::Device)
+    }
+}
+
+fn extension_type(name: &str) -> ExtensionType {
+    reflect::find_extension(name).map_or(ExtensionType::Device, |info| info.ty)
+}
+
+/// A profile definition.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Profile {
+    pub version: u32,
+    /// The minimum vulkan api version in `major.minor.patch` notation.
+    #[serde(rename = "api-version")]
+    pub api_version: String,
+    pub label: String,
+    pub description: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(default)]
+    pub contributors: BTreeMap<String, Contributor>,
+    #[serde(default)]
+    pub history: Vec<Revision>,
+    /// Names of other profiles whose requirements are included in this profile.
+    #[serde(rename = "profiles", default, skip_serializing_if = "Vec::is_empty")]
+    pub required_profiles: Vec<String>,
+    /// Fallback profiles in order of preference.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fallback: Vec<String>,
+    pub capabilities: Vec<CapabilityReference>,
+}
+
+impl Profile {
+    /// Parses [`Self::api_version`] into a packed vulkan api version.
+    pub fn api_version(&self) -> Option<u32> {
+        parse_api_version(&self.api_version)
+    }
+}
+
+/// A reference to capability blocks from a profile.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum CapabilityReference {
+    /// A block that is always required.
+    Required(String),
+    /// A list of alternative blocks of which at least one must be supported.
+    Alternatives(Vec<String>),
+}
+
+impl CapabilityReference {
+    /// Returns the referenced block names.
+    pub fn names(&self) -> &[String] {
+        match self {
+            Self::Required(name) => std::slice::from_ref(name),
+            Self::Alternatives(names) => names,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Contributor {
+    pub company: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub github: Option<String>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub contact: bool,
+}
+
+/// An entry of the revision history of a profile.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Revision {
+    pub revision: u32,
+    pub date: String,
+    pub author: String,
+    pub comment: String,
+}
+
+/// An error returned when parsing or querying profile JSON documents.
+#[derive(Debug)]
+pub enum JsonError {
+    /// The document is not valid JSON or does not match the profile document structure.
+    Parse(serde_json::Error),
+    UnknownProfile(String),
+    UnknownCapability(String),
+    /// A struct name is not known or is used in the wrong section of a capability.
+    UnknownStruct(String),
+    UnknownMember {
+        structure: String,
+        member: String,
+    },
+    UnknownFormat(String),
+    /// A profile requires itself, either directly or through other required profiles.
+    RecursiveProfile(String),
+    InvalidValue {
+        structure: String,
+        member: String,
+        /// A description of the expected value.
+        expected: String,
+    },
+}
+
+impl fmt::Display for JsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse(err) => write!(f, "failed to parse profile document: {err}"),
+            Self::UnknownProfile(name) => write!(f, "unknown profile {name}"),
+            Self::UnknownCapability(name) => write!(f, "unknown capability block {name}"),
+            Self::UnknownStruct(name) => write!(f, "unknown struct {name}"),
+            Self::UnknownMember { structure, member } => {
+                write!(f, "{structure} has no member {member}")
+            }
+            Self::UnknownFormat(name) => write!(f, "unknown format {name}"),
+            Self::RecursiveProfile(name) => write!(f, "profile {name} requires itself"),
+            Self::InvalidValue {
+                structure,
+                member,
+                expected,
+            } => write!(f, "{structure}::{member} must be {expected}"),
+        }
+    }
+}
+
+impl std::error::Error for JsonError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Parse(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for JsonError {
+    fn from(err: serde_json::Error) -> Self {
+        Self::Parse(err)
+    }
+}
+
+impl FromStr for ProfilesDocument {
+    type Err = JsonError;
+
+    fn from_str(json: &str) -> Result<Self, Self::Err> {
+        Ok(serde_json::from_str(json)?)
+    }
+}
+
+impl ProfilesDocument {
+    /// Parses a document from a reader.
+    pub fn from_reader(reader: impl std::io::Read) -> Result<Self, JsonError> {
+        Ok(serde_json::from_reader(reader)?)
+    }
+
+    /// Returns the profile with the specified name.
+    pub fn profile(&self, profile_name: &str) -> Result<&Profile, JsonError> {
+        self.profiles
+            .get(profile_name)
+            .ok_or_else(|| JsonError::UnknownProfile(profile_name.to_string()))
+    }
+
+    /// Returns the names of all profiles required by a profile, directly or indirectly.
+    ///
+    /// Required profiles are listed before the profiles requiring them and each profile is listed once.
+    /// The profile itself is not included.
+    pub fn required_profile_names(&self, profile_name: &str) -> Result<Vec<&str>, JsonError> {
+        let mut names = Vec::new();
+        self.collect_required_profiles(profile_name, &mut vec![profile_name], &mut names)?;
+        Ok(names)
+    }
+
+    fn collect_required_profiles<'a: 'b, 'b>(
+        &'a self,
+        profile_name: &str,
+        path: &mut Vec<&'b str>,
+        names: &mut Vec<&'a str>,
+    ) -> Result<(), JsonError> {
+        for required in &self.profile(profile_name)?.required_profiles {
+            if path.contains(&required.as_str()) {
+                return Err(JsonError::RecursiveProfile(required.clone()));
+            }
+            if names.contains(&required.as_str()) {
+                continue;
+            }
+            path.push(required);
+            self.collect_required_profiles(required, path, names)?;
+            path.pop();
+            names.push(required);
+        }
+        Ok(())
+    }
+
+    /// Returns the capability blocks a query of a profile is made up of.
+    ///
+    /// If `block_name` is [`None`] the blocks of all required profiles are returned first followed by the
+    /// blocks referenced by the profile itself. For lists of alternatives only the first block is used. If
+    /// `block_name` is specified only that block is returned, which must be referenced by the profile.
+    pub fn profile_capabilities(
+        &self,
+        profile_name: &str,
+        block_name: Option<&str>,
+    ) -> Result<Vec<&Capability>, JsonError> {
+        let profile = self.profile(profile_name)?;
+        let capability = |name: &String| {
+            self.capabilities
+                .get(name)
+                .ok_or_else(|| JsonError::UnknownCapability(name.clone()))
+        };
+
+        if let Some(block_name) = block_name {
+            return profile
+                .capabilities
+                .iter()
+                .flat_map(CapabilityReference::names)
+                .find(|name| *name == block_name)
+                .ok_or_else(|| JsonError::UnknownCapability(block_name.to_string()))
+                .and_then(capability)
+                .map(|capability| vec![capability]);
+        }
+
+        let mut capabilities = Vec::new();
+        for name in self.required_profile_names(profile_name)? {
+            for reference in &self.profile(name)?.capabilities {
+                if let Some(name) = reference.names().first() {
+                    capabilities.push(capability(name)?);
+                }
+            }
+        }
+        for reference in &profile.capabilities {
+            if let Some(name) = reference.names().first() {
+                capabilities.push(capability(name)?);
+            }
+        }
+        Ok(capabilities)
+    }
+
+    /// Returns the instance extensions required by a profile.
+    pub fn get_profile_instance_extension_properties(
+        &self,
+        profile_name: &str,
+        block_name: Option<&str>,
+    ) -> Result<Vec<vk::ExtensionProperties>, JsonError> {
+        let capabilities = self.profile_capabilities(profile_name, block_name)?;
+        collect_extensions(
+            capabilities
+                .iter()
+                .flat_map(|capability| capability.instance_extensions()),
+        )
+    }
+
+    /// Returns the device extensions required by a profile.
+    pub fn get_profile_device_extension_properties(
+        &self,
+        profile_name: &str,
+        block_name: Option<&str>,
+    ) -> Result<Vec<vk::ExtensionProperties>, JsonError> {
+        let capabilities = self.profile_capabilities(profile_name, block_name)?;
+        collect_extensions(
+            capabilities
+                .iter()
+                .flat_map(|capability| capability.device_extensions()),
+        )
+    }
+
+    /// Writes the features required by a profile into a [`vk::PhysicalDeviceFeatures2`] chain.
+    ///
+    /// Like the library function only structs which are part of the chain are written. Structs unknown to
+    /// this crate are ignored.
+    ///
+    /// # Safety
+    /// `p_features` must be the head of a valid pNext chain of structs extending
+    /// [`vk::PhysicalDeviceFeatures2`], with each struct matching its `s_type`.
+    pub unsafe fn get_profile_features(
+        &self,
+        profile_name: &str,
+        block_name: Option<&str>,
+        p_features: &mut vk::BaseOutStructure,
+    ) -> Result<(), JsonError> {
+        let capabilities = self.profile_capabilities(profile_name, block_name)?;
+        for capability in capabilities {
+            write_chain(&capability.features, StructCategory::Features, p_features)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the structure types of all feature structs a profile defines.
+    pub fn get_profile_feature_structure_types(
+        &self,
+        profile_name: &str,
+        block_name: Option<&str>,
+    ) -> Result<Vec<vk::StructureType>, JsonError> {
+        let capabilities = self.profile_capabilities(profile_name, block_name)?;
+        structure_types(
+            capabilities.iter().map(|capability| &capability.features),
+            StructCategory::Features,
+        )
+    }
+
+    /// Writes the properties required by a profile into a [`vk::PhysicalDeviceProperties2`] chain.
+    ///
+    /// Like the library function only structs which are part of the chain are written. Structs unknown to
+    /// this crate are ignored.
+    ///
+    /// # Safety
+    /// `p_properties` must be the head of a valid pNext chain of structs extending
+    /// [`vk::PhysicalDeviceProperties2`], with each struct matching its `s_type`.
+    pub unsafe fn get_profile_properties(
+        &self,
+        profile_name: &str,
+        block_name: Option<&str>,
+        p_properties: &mut vk::BaseOutStructure,
+    ) -> Result<(), JsonError> {
+        let capabilities = self.profile_capabilities(profile_name, block_name)?;
+        for capability in capabilities {
+            write_chain(
+                &capability.properties,
+                StructCategory::Properties,
+                p_properties,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Returns the structure types of all property structs a profile defines.
+    pub fn get_profile_property_structure_types(
+        &self,
+        profile_name: &str,
+        block_name: Option<&str>,
+    ) -> Result<Vec<vk::StructureType>, JsonError> {
+        let capabilities = self.profile_capabilities(profile_name, block_name)?;
+        structure_types(
+            capabilities.iter().map(|capability| &capability.properties),
+            StructCategory::Properties,
+        )
+    }
+
+    /// Returns the queue families required by a profile including all extension structs it defines.
+    pub fn profile_queue_family_properties(
+        &self,
+        profile_name: &str,
+        block_name: Option<&str>,
+    ) -> Result<Vec<OwnedQueueFamilyProperties>, JsonError> {
+        let capabilities = self.profile_capabilities(profile_name, block_name)?;
+        capabilities
+            .iter()
+            .flat_map(|capability| capability.queue_families_properties.iter())
+            .map(|family| queue_family_properties(family, false))
+            .collect()
+    }
+
+    /// Returns the format features required by a profile for each format it defines.
+    ///
+    /// Requirements for the same format from multiple blocks are combined.
+    pub fn profile_format_requirements(
+        &self,
+        profile_name: &str,
+        block_name: Option<&str>,
+    ) -> Result<BTreeMap<vk::Format, FormatRequirement>, JsonError> {
+        let capabilities = self.profile_capabilities(profile_name, block_name)?;
+
+        let mut requirements = BTreeMap::new();
+        for (format_name, structs) in capabilities
+            .iter()
+            .flat_map(|capability| capability.formats.iter())
+        {
+            let format = reflect::format_from_name(format_name)
+                .ok_or_else(|| JsonError::UnknownFormat(format_name.clone()))?;
+
+            let requirement = format_requirement(structs, false)?;
+            requirements
+                .entry(format)
+                .and_modify(|existing: &mut FormatRequirement| {
+                    *existing = existing.union(&requirement)
+                })
+                .or_insert(requirement);
+        }
+        Ok(requirements)
+    }
+
+    /// Returns all formats a profile defines requirements for.
+    pub fn get_profile_formats(
+        &self,
+        profile_name: &str,
+        block_name: Option<&str>,
+    ) -> Result<Vec<vk::Format>, JsonError> {
+        let capabilities = self.profile_capabilities(profile_name, block_name)?;
+        let mut formats = Vec::new();
+        for name in capabilities
+            .iter()
+            .flat_map(|capability| capability.formats.keys())
+        {
+            let format = reflect::format_from_name(name)
+                .ok_or_else(|| JsonError::UnknownFormat(name.clone()))?;
+            if !formats.contains(&format) {
+                formats.push(format);
+            }
+        }
+        Ok(formats)
+    }
+
+    /// Writes the properties a profile requires for a format into a [`vk::FormatProperties2`] chain.
+    ///
+    /// Like the library function only structs which are part of the chain are written. Structs unknown to
+    /// this crate are ignored.
+    ///
+    /// # Safety
+    /// `p_next` must be the head of a valid pNext chain of structs extending [`vk::FormatProperties2`],
+    /// with each struct matching its `s_type`.
+    pub unsafe fn get_profile_format_properties(
+        &self,
+        profile_name: &str,
+        block_name: Option<&str>,
+        format: vk::Format,
+        p_next: &mut vk::BaseOutStructure,
+    ) -> Result<(), JsonError> {
+        let capabilities = self.profile_capabilities(profile_name, block_name)?;
+        for (name, structs) in capabilities
+            .iter()
+            .flat_map(|capability| capability.formats.iter())
+        {
+            if reflect::format_from_name(name) == Some(format) {
+                write_chain(structs, StructCategory::FormatProperties, p_next)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the structure types of all format property structs a profile defines.
+    pub fn get_profile_format_structure_types(
+        &self,
+        profile_name: &str,
+        block_name: Option<&str>,
+    ) -> Result<Vec<vk::StructureType>, JsonError> {
+        let capabilities = self.profile_capabilities(profile_name, block_name)?;
+        structure_types(
+            capabilities
+                .iter()
+                .flat_map(|capability| capability.formats.values()),
+            StructCategory::FormatProperties,
+        )
+    }
+}
+
+fn collect_extensions<'a>(
+    extensions: impl Iterator<Item = (&'a String, &'a u32)>,
+) -> Result<Vec<vk::ExtensionProperties>, JsonError> {
+    let mut merged: BTreeMap<&String, u32> = BTreeMap::new();
+    for (name, spec_version) in extensions {
+        let entry = merged.entry(name).or_default();
+        *entry = (*entry).max(*spec_version);
+    }
+
+    merged
+        .into_iter()
+        .map(|(name, spec_version)| {
+            let extension_name =
+                c_char_array_from_str(name).ok_or_else(|| JsonError::InvalidValue {
+                    structure: "VkExtensionProperties".to_string(),
+                    member: "extensionName".to_string(),
+                    expected: format!(
+                        "a string shorter than {} bytes",
+                        vk::MAX_EXTENSION_NAME_SIZE
+                    ),
+                })?;
+            Ok(vk::ExtensionProperties {
+                extension_name,
+                spec_version,
+            })
+        })
+        .collect()
+}
+
+/// Parses an api version of the form `major.minor` or `major.minor.patch`.
+pub(crate) fn parse_api_version(api_version: &str) -> Option<u32> {
+    let mut parts = api_version.split('.').map(u32::from_str);
+    let major = parts.next()?.ok()?;
+    let minor = parts.next()?.ok()?;
+    let patch = parts.next().unwrap_or(Ok(0)).ok()?;
+    if parts.next().is_some() {
+        return None;
+    }
+    Some(vk::make_api_version(0, major, minor, patch))
+}
+
+/// Formats a packed api version like the `api-version` field of profiles, for example `1.3.204`.
+pub(crate) fn api_version_string(api_version: u32) -> String {
+    format!(
+        "{}.{}.{}",
+        vk::api_version_major(api_version),
+        vk::api_version_minor(api_version),
+        vk::api_version_patch(api_version)
+    )
+}
+
+/// Converts the structs of a single `queueFamiliesProperties` entry.
+///
+/// If `lenient` is true unknown structs, unknown members and invalid values are skipped.
+pub(crate) fn queue_family_properties(
+    family: &BTreeMap<String, StructValue>,
+    lenient: bool,
+) -> Result<OwnedQueueFamilyProperties, JsonError> {
+    let mut properties = OwnedQueueFamilyProperties::default();
+    for (name, value) in family {
+        let info = match find_struct(name, StructCategory::QueueFamilyProperties) {
+            Err(_) if lenient => continue,
+            result => result?,
+        };
+        // SAFETY: every target matches the struct described by info
+        unsafe {
+            match info.structure_type {
+                None => write_value(info, value, lenient, &mut properties.properties)?,
+                Some(vk::StructureType::QUEUE_FAMILY_GLOBAL_PRIORITY_PROPERTIES_KHR) => {
+                    write_value(
+                        info,
+                        value,
+                        lenient,
+                        properties
+                            .global_priority
+                            .get_or_insert_with(Default::default),
+                    )?
+                }
+                Some(vk::StructureType::QUEUE_FAMILY_VIDEO_PROPERTIES_KHR) => write_value(
+                    info,
+                    value,
+                    lenient,
+                    properties.video.get_or_insert_with(Default::default),
+                )?,
+                Some(vk::StructureType::QUEUE_FAMILY_QUERY_RESULT_STATUS_PROPERTIES_KHR) => {
+                    write_value(
+                        info,
+                        value,
+                        lenient,
+                        properties
+                            .query_result_status
+                            .get_or_insert_with(Default::default),
+                    )?
+                }
+                Some(_) => {}
+            }
+        }
+    }
+    Ok(properties)
+}
+
+/// Combines the format property structs of a single `formats` entry.
+///
+/// If `lenient` is true unknown structs, unknown members and invalid values are skipped.
+pub(crate) fn format_requirement(
+    structs: &BTreeMap<String, StructValue>,
+    lenient: bool,
+) -> Result<FormatRequirement, JsonError> {
+    let mut requirement = FormatRequirement::default();
+    for (name, value) in structs {
+        let info = match find_struct(name, StructCategory::FormatProperties) {
+            Err(_) if lenient => continue,
+            result => result?,
+        };
+        // SAFETY: every target matches the struct described by info
+        unsafe {
+            match info.structure_type {
+                None => {
+                    let mut properties = vk::FormatProperties::default();
+                    write_value(info, value, lenient, &mut properties)?;
+                    requirement =
+                        requirement.union(&FormatRequirement::from_properties(&properties));
+                }
+                Some(vk::StructureType::FORMAT_PROPERTIES_3) => {
+                    let mut properties = vk::FormatProperties3::default();
+                    write_value(info, value, lenient, &mut properties)?;
+                    requirement =
+                        requirement.union(&FormatRequirement::from_properties3(&properties));
+                }
+                Some(_) => {}
+            }
+        }
+    }
+    Ok(requirement)
+}
+
+/// Looks up a struct and checks that it belongs to the expected section.
+fn find_struct(name: &str, category: StructCategory) -> Result<&'static StructInfo, JsonError> {
+    reflect::find_struct(name)
+        .filter(|info| info.category == Some(category))
+        .ok_or_else(|| JsonError::UnknownStruct(name.to_string()))
+}
+
+/// Writes a JSON struct value into `target` which must be the ash struct described by `info`.
+unsafe fn write_value<T>(
+    info: &'static StructInfo,
+    value: &StructValue,
+    lenient: bool,
+    target: &mut T,
+) -> Result<(), JsonError> {
+    debug_assert_eq!(info.size, std::mem::size_of::<T>());
+    let data = target as *mut T as *mut u8;
+    if lenient {
+        reflect::write_struct_lenient(info, value, data);
+        Ok(())
+    } else {
+        reflect::write_struct(info, value, data)
+    }
+}
+
+unsafe fn write_chain(
+    structs: &BTreeMap<String, StructValue>,
+    category: StructCategory,
+    chain: &mut vk::BaseOutStructure,
+) -> Result<(), JsonError> {
+    for (name, value) in structs {
+        let Some(info) = reflect::find_struct(name) else {
+            continue;
+        };
+        if info.category != Some(category) {
+            return Err(JsonError::UnknownStruct(name.clone()));
+        }
+
+        let mut next: *mut vk::BaseOutStructure = chain;
+        while !next.is_null() {
+            if let Some((chain_info, data)) = reflect::resolve_chain_struct(next) {
+                if std::ptr::eq(chain_info, info) {
+                    reflect::write_struct(info, value, data)?;
+                }
+            }
+            next = (*next).p_next;
+        }
+    }
+    Ok(())
+}
+
+fn structure_types<'a>(
+    structs: impl Iterator<Item = &'a BTreeMap<String, StructValue>>,
+    category: StructCategory,
+) -> Result<Vec<vk::StructureType>, JsonError> {
+    let mut structure_types = Vec::new();
+    for name in structs.flat_map(BTreeMap::keys) {
+        let info = find_struct(name, category)?;
+        if let Some(structure_type) = info.query_structure_type() {
+            if !structure_types.contains(&structure_type) {
+                structure_types.push(structure_type);
+            }
+        }
+    }
+    Ok(structure_types)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DOCUMENT: &str = r#"{
+        "$schema": "https://schema.khronos.org/vulkan/profiles-0.8.2-281.json#",
+        "capabilities": {
+            "baseline": {
+                "extensions": { "VK_KHR_swapchain": 70, "VK_KHR_surface": 25 },
+                "features": {
+                    "VkPhysicalDeviceFeatures": { "samplerAnisotropy": true },
+                    "VkPhysicalDeviceVulkan12Features": { "timelineSemaphore": true }
+                },
+                "properties": {
+                    "VkPhysicalDeviceProperties": {
+                        "limits": {
+                            "maxImageDimension2D": 8192,
+                            "maxComputeWorkGroupCount": [65535, 65535, 65535],
+                            "framebufferColorSampleCounts": ["VK_SAMPLE_COUNT_1_BIT", "VK_SAMPLE_COUNT_4_BIT"]
+                        }
+                    }
+                },
+                "formats": {
+                    "VK_FORMAT_R8G8B8A8_UNORM": {
+                        "VkFormatProperties": { "optimalTilingFeatures": ["VK_FORMAT_FEATURE_SAMPLED_IMAGE_BIT"] },
+                        "VkFormatProperties3": { "optimalTilingFeatures": ["VK_FORMAT_FEATURE_2_STORAGE_READ_WITHOUT_FORMAT_BIT"] }
+                    }
+                },
+                "queueFamiliesProperties": [
+                    { "VkQueueFamilyProperties": { "queueFlags": ["VK_QUEUE_GRAPHICS_BIT"], "queueCount": 1 } }
+                ]
+            },
+            "optional": {
+                "features": { "VkPhysicalDeviceFeatures": { "wideLines": true } }
+            }
+        },
+        "profiles": {
+            "VP_TEST_profile": {
+                "version": 1,
+                "api-version": "1.3.204",
+                "label": "Test",
+                "description": "Test profile",
+                "contributors": { "Tester": { "company": "Test", "contact": true } },
+                "history": [ { "revision": 1, "date": "2024-01-01", "author": "Tester", "comment": "Initial" } ],
+                "capabilities": [ "baseline", [ "optional" ] ]
+            }
+        }
+    }"#;
+
+    #[test]
+    fn test_profile_document() {
+        let document: ProfilesDocument = DOCUMENT.parse().unwrap();
+        let profile = document.profile("VP_TEST_profile").unwrap();
+        assert_eq!(
+            profile.api_version(),
+            Some(vk::make_api_version(0, 1, 3, 204))
+        );
+        assert!(profile.contributors["Tester"].contact);
+
+        let extensions = document
+            .get_profile_device_extension_properties("VP_TEST_profile", None)
+            .unwrap();
+        assert_eq!(extensions.len(), 1);
+        assert_eq!(
+            extensions[0].extension_name_as_c_str(),
+            Ok(ash::khr::swapchain::NAME)
+        );
+        let instance_extensions = document
+            .get_profile_instance_extension_properties("VP_TEST_profile", None)
+            .unwrap();
+        assert_eq!(instance_extensions.len(), 1);
+        assert_eq!(
+            instance_extensions[0].extension_name_as_c_str(),
+            Ok(ash::khr::surface::NAME)
+        );
+        assert_eq!(instance_extensions[0].spec_version, 25);
+
+        let mut vulkan_12_features = vk::PhysicalDeviceVulkan12Features::default();
+        let mut features =
+            vk::PhysicalDeviceFeatures2::default().push_next(&mut vulkan_12_features);
+        unsafe {
+            document
+                .get_profile_features(
+                    "VP_TEST_profile",
+                    None,
+                    &mut *(&mut features as *mut _ as *mut vk::BaseOutStructure),
+                )
+                .unwrap()
+        };
+        assert_eq!(features.features.sampler_anisotropy, vk::TRUE);
+        assert_eq!(features.features.wide_lines, vk::TRUE);
+        assert_eq!(vulkan_12_features.timeline_semaphore, vk::TRUE);
+        assert_eq!(
+            document
+                .get_profile_feature_structure_types("VP_TEST_profile", Some("baseline"))
+                .unwrap(),
+            vec![
+                vk::StructureType::PHYSICAL_DEVICE_FEATURES_2,
+                vk::StructureType::PHYSICAL_DEVICE_VULKAN_1_2_FEATURES
+            ]
+        );
+
+        let mut properties = vk::PhysicalDeviceProperties2::default();
+        unsafe {
+            document
+                .get_profile_properties(
+                    "VP_TEST_profile",
+                    None,
+                    &mut *(&mut properties as *mut _ as *mut vk::BaseOutStructure),
+                )
+                .unwrap()
+        };
+        let limits = &properties.properties.limits;
+        assert_eq!(limits.max_image_dimension2_d, 8192);
+        assert_eq!(limits.max_compute_work_group_count, [65535; 3]);
+        assert_eq!(
+            limits.framebuffer_color_sample_counts,
+            vk::SampleCountFlags::TYPE_1 | vk::SampleCountFlags::TYPE_4
+        );
+
+        let formats = document
+            .profile_format_requirements("VP_TEST_profile", None)
+            .unwrap();
+        assert_eq!(
+            formats[&vk::Format::R8G8B8A8_UNORM].optimal_tiling_features,
+            vk::FormatFeatureFlags2::SAMPLED_IMAGE
+                | vk::FormatFeatureFlags2::STORAGE_READ_WITHOUT_FORMAT
+        );
+
+        let families = document
+            .profile_queue_family_properties("VP_TEST_profile", None)
+            .unwrap();
+        assert_eq!(families.len(), 1);
+        assert_eq!(families[0].properties.queue_flags, vk::QueueFlags::GRAPHICS);
+        assert_eq!(families[0].properties.queue_count, 1);
+    }
+
+    #[test]
+    fn test_recursive_profile() {
+        let document: ProfilesDocument = DOCUMENT
+            .replace(
+                r#""capabilities": [ "baseline", [ "optional" ] ]"#,
+                r#""capabilities": [ "baseline", [ "optional" ] ], "profiles": ["VP_TEST_profile"]"#,
+            )
+            .parse()
+            .unwrap();
+        assert!(matches!(
+            document.profile_capabilities("VP_TEST_profile", None),
+            Err(JsonError::RecursiveProfile(name)) if name == "VP_TEST_profile"
+        ));
+    }
+
+    #[test]
+    fn test_invalid_member() {
+        let document: ProfilesDocument = DOCUMENT
+            .replace("samplerAnisotropy", "samplerAnisotrophy")
+            .parse()
+            .unwrap();
+        let mut features = vk::PhysicalDeviceFeatures2::default();
+        let result = unsafe {
+            document.get_profile_features(
+                "VP_TEST_profile",
+                None,
+                &mut *(&mut features as *mut _ as *mut vk::BaseOutStructure),
+            )
+        };
+        assert!(matches!(
+            result,
+            Err(JsonError::UnknownMember { member, .. }) if member == "samplerAnisotrophy"
+        ));
+    }
+
+    #[test]
+    fn test_export_round_trip() {
+        let document: ProfilesDocument = DOCUMENT.parse().unwrap();
+        let flattened = document.flatten_profile("VP_TEST_profile", None).unwrap();
+        assert_eq!(flattened.extensions["VK_KHR_swapchain"], 70);
+        assert_eq!(flattened.extensions["VK_KHR_surface"], 25);
+        assert_eq!(
+            flattened.features["VkPhysicalDeviceFeatures"],
+            serde_json::json!({ "samplerAnisotropy": true, "wideLines": true })
+                .as_object()
+                .unwrap()
+                .clone()
+        );
+
+        let mut exported = ProfilesDocument {
+            schema: SCHEMA.to_string(),
+            ..Default::default()
+        };
+        exported
+            .capabilities
+            .insert("VP_TEST_profile".to_string(), flattened.clone());
+        exported.profiles.insert(
+            "VP_TEST_profile".to_string(),
+            Profile {
+                capabilities: vec![CapabilityReference::Required("VP_TEST_profile".to_string())],
+                ..document.profile("VP_TEST_profile").unwrap().clone()
+            },
+        );
+        let union = exported
+            .add_union_profile("VP_TEST_union", &["VP_TEST_profile"])
+            .unwrap();
+        assert_eq!(union.api_version, "1.3.204");
+        let intersection = exported
+            .add_intersection_profile(
+                "VP_TEST_intersection",
+                &["VP_TEST_profile", "VP_TEST_union"],
+            )
+            .unwrap();
+        assert_eq!(intersection.api_version, "1.3.204");
+
+        let parsed: ProfilesDocument = exported.to_json_string().parse().unwrap();
+        assert_eq!(parsed, exported);
+        for profile_name in ["VP_TEST_profile", "VP_TEST_union", "VP_TEST_intersection"] {
+            assert_eq!(
+                parsed.flatten_profile(profile_name, None).unwrap(),
+                flattened
+            );
+            assert_eq!(
+                parsed
+                    .profile_format_requirements(profile_name, None)
+                    .unwrap(),
+                document
+                    .profile_format_requirements("VP_TEST_profile", None)
+                    .unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_intersection_profile() {
+        let mut document: ProfilesDocument = DOCUMENT
+            .replace(
+                r#""optional": {"#,
+                r#""weak": {
+                    "extensions": { "VK_KHR_swapchain": 68, "VK_KHR_maintenance4": 1 },
+                    "features": { "VkPhysicalDeviceFeatures": { "samplerAnisotropy": true } },
+                    "properties": {
+                        "VkPhysicalDeviceProperties": {
+                            "limits": {
+                                "maxImageDimension2D": 4096,
+                                "framebufferColorSampleCounts": ["VK_SAMPLE_COUNT_1_BIT", "VK_SAMPLE_COUNT_8_BIT"]
+                            }
+                        }
+                    }
+                },
+                "optional": {"#,
+            )
+            .replace(
+                r#""profiles": {"#,
+                r#""profiles": {
+                "VP_TEST_weak": {
+                    "version": 1,
+                    "api-version": "1.2.198",
+                    "label": "Weak",
+                    "description": "Weak profile",
+                    "capabilities": [ "weak" ]
+                },"#,
+            )
+            .parse()
+            .unwrap();
+
+        let intersection = document
+            .add_intersection_profile("VP_TEST_intersection", &["VP_TEST_profile", "VP_TEST_weak"])
+            .unwrap();
+        assert_eq!(intersection.api_version, "1.2.198");
+        assert_eq!(
+            serde_json::to_value(&document.capabilities["VP_TEST_intersection"]).unwrap(),
+            serde_json::json!({
+                "extensions": { "VK_KHR_swapchain": 68 },
+                "features": { "VkPhysicalDeviceFeatures": { "samplerAnisotropy": true } },
+                "properties": {
+                    "VkPhysicalDeviceProperties": {
+                        "limits": {
+                            "maxImageDimension2D": 4096,
+                            "framebufferColorSampleCounts": ["VK_SAMPLE_COUNT_1_BIT"]
+                        }
+                    }
+                }
+            })
+        );
+    }
+}
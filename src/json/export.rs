@@ -0,0 +1,352 @@
+//! Conversion of profiles into profile JSON documents.
+//!
+//! Profiles of the library and of other documents are read through their query functions, so an exported
+//! document describes exactly the requirements those queries return.
+
+use std::collections::BTreeMap;
+use std::ffi::CStr;
+
+use ash::vk;
+
+use super::{
+    api_version_string, Capability, CapabilityReference, JsonError, Profile, ProfilesDocument,
+    StructValue,
+};
+use crate::capabilities::intersect_capabilities;
+use crate::queue_family::OwnedQueueFamilyProperties;
+use crate::reflect::{self, StructChain};
+use crate::vp::ProfileProperties;
+use crate::{profiles, ProfilesResult, VulkanProfiles};
+
+/// The schema URI written into exported documents.
+pub const SCHEMA: &str = "https://schema.khronos.org/vulkan/profiles-0.8.2-281.json#";
+
+impl Capability {
+    /// Reads all requirements of a library profile into a capability block.
+    ///
+    /// If `block_name` is [`None`] the requirements of all blocks of the profile are combined.
+    ///
+    /// # Safety
+    /// The profile name of `profile` and `block_name` must be null terminated.
+    pub unsafe fn from_library(
+        vk_profiles: &VulkanProfiles,
+        profile: &ProfileProperties,
+        block_name: Option<&CStr>,
+    ) -> ProfilesResult<Self> {
+        let mut features = StructChain::new(
+            vk::StructureType::PHYSICAL_DEVICE_FEATURES_2,
+            &vk_profiles.get_profile_feature_structure_types(profile, block_name)?,
+        );
+        vk_profiles.get_profile_features(
+            profile,
+            block_name,
+            &mut *(features.link() as *mut vk::PhysicalDeviceFeatures2),
+        );
+
+        let mut properties = StructChain::new(
+            vk::StructureType::PHYSICAL_DEVICE_PROPERTIES_2,
+            &vk_profiles.get_profile_property_structure_types(profile, block_name)?,
+        );
+        vk_profiles.get_profile_properties(
+            profile,
+            block_name,
+            &mut *(properties.link() as *mut vk::PhysicalDeviceProperties2),
+        );
+
+        let format_structure_types =
+            vk_profiles.get_profile_format_structure_types(profile, block_name)?;
+        let mut formats = BTreeMap::new();
+        for format in vk_profiles.get_profile_formats(profile, block_name)? {
+            let mut chain = StructChain::new(
+                vk::StructureType::FORMAT_PROPERTIES_2,
+                &format_structure_types,
+            );
+            vk_profiles.get_profile_format_properties(
+                profile,
+                block_name,
+                format,
+                &mut *chain.link(),
+            );
+            insert_format(&mut formats, format, &chain);
+        }
+
+        let mut extensions = extension_map(
+            &vk_profiles.get_profile_instance_extension_properties(profile, block_name)?,
+        );
+        extensions.extend(extension_map(
+            &vk_profiles.get_profile_device_extension_properties(profile, block_name)?,
+        ));
+        Ok(Self {
+            extensions,
+            features: chain_map(&features),
+            properties: chain_map(&properties),
+            formats,
+            queue_families_properties: queue_family_maps(
+                &vk_profiles.enumerate_profile_queue_family_properties(profile, block_name)?,
+            ),
+        })
+    }
+}
+
+impl ProfilesDocument {
+    /// Creates a document containing the specified library profiles.
+    ///
+    /// Every profile is exported as a single capability block with the name of the profile. The
+    /// `api-version` of profiles defined in [`crate::profiles`] is their minimum api version, `1.0.0` is
+    /// used for all other profiles. Fallback profiles are referenced by name but not exported.
+    ///
+    /// # Safety
+    /// The profile names of `profiles` must be null terminated.
+    pub unsafe fn from_library(
+        vk_profiles: &VulkanProfiles,
+        profiles: &[ProfileProperties],
+    ) -> ProfilesResult<Self> {
+        let mut document = Self {
+            schema: SCHEMA.to_string(),
+            ..Default::default()
+        };
+        for profile in profiles {
+            let name = profile.profile_name_to_string();
+            let api_version = profile
+                .profile_name_as_c_str()
+                .ok()
+                .and_then(profiles::min_api_version)
+                .unwrap_or(vk::API_VERSION_1_0);
+
+            document.capabilities.insert(
+                name.clone(),
+                Capability::from_library(vk_profiles, profile, None)?,
+            );
+            document.profiles.insert(
+                name.clone(),
+                Profile {
+                    version: profile.spec_version,
+                    api_version: api_version_string(api_version),
+                    label: name.clone(),
+                    description: format!("{name} exported from the vulkan profiles library"),
+                    fallback: vk_profiles
+                        .get_profile_fallbacks(profile)?
+                        .iter()
+                        .map(ProfileProperties::profile_name_to_string)
+                        .collect(),
+                    capabilities: vec![CapabilityReference::Required(name)],
+                    ..Default::default()
+                },
+            );
+        }
+        Ok(document)
+    }
+
+    /// Reads all requirements of a profile of this document into a single capability block.
+    ///
+    /// Required profiles and the first alternative of every capability reference are combined like in
+    /// [`Self::profile_capabilities`].
+    pub fn flatten_profile(
+        &self,
+        profile_name: &str,
+        block_name: Option<&str>,
+    ) -> Result<Capability, JsonError> {
+        let mut features = StructChain::new(
+            vk::StructureType::PHYSICAL_DEVICE_FEATURES_2,
+            &self.get_profile_feature_structure_types(profile_name, block_name)?,
+        );
+        let mut properties = StructChain::new(
+            vk::StructureType::PHYSICAL_DEVICE_PROPERTIES_2,
+            &self.get_profile_property_structure_types(profile_name, block_name)?,
+        );
+        let format_structure_types =
+            self.get_profile_format_structure_types(profile_name, block_name)?;
+
+        let mut formats = BTreeMap::new();
+        // SAFETY: the chains are valid pNext chains of the matching query structs
+        unsafe {
+            self.get_profile_features(profile_name, block_name, &mut *features.link())?;
+            self.get_profile_properties(profile_name, block_name, &mut *properties.link())?;
+            for format in self.get_profile_formats(profile_name, block_name)? {
+                let mut chain = StructChain::new(
+                    vk::StructureType::FORMAT_PROPERTIES_2,
+                    &format_structure_types,
+                );
+                self.get_profile_format_properties(
+                    profile_name,
+                    block_name,
+                    format,
+                    &mut *chain.link(),
+                )?;
+                insert_format(&mut formats, format, &chain);
+            }
+        }
+
+        let mut extensions = extension_map(
+            &self.get_profile_instance_extension_properties(profile_name, block_name)?,
+        );
+        extensions.extend(extension_map(
+            &self.get_profile_device_extension_properties(profile_name, block_name)?,
+        ));
+        Ok(Capability {
+            extensions,
+            features: chain_map(&features),
+            properties: chain_map(&properties),
+            formats,
+            queue_families_properties: queue_family_maps(
+                &self.profile_queue_family_properties(profile_name, block_name)?,
+            ),
+        })
+    }
+
+    /// Adds a profile which requires everything required by all `profile_names`.
+    ///
+    /// The new profile references the capability blocks and required profiles of the combined profiles, so
+    /// their requirements are preserved exactly. Its api version is the highest one of the combined
+    /// profiles.
+    pub fn add_union_profile(
+        &mut self,
+        name: &str,
+        profile_names: &[&str],
+    ) -> Result<&Profile, JsonError> {
+        let mut union = Profile {
+            version: 1,
+            api_version: "1.0.0".to_string(),
+            label: name.to_string(),
+            description: format!("Union of {}", profile_names.join(", ")),
+            ..Default::default()
+        };
+        let mut api_version = vk::API_VERSION_1_0;
+        for profile_name in profile_names {
+            let profile = self.profile(profile_name)?;
+            if let Some(profile_api_version) = profile.api_version() {
+                api_version = api_version.max(profile_api_version);
+            }
+            for required in &profile.required_profiles {
+                if !union.required_profiles.contains(required) {
+                    union.required_profiles.push(required.clone());
+                }
+            }
+            for reference in &profile.capabilities {
+                if !union.capabilities.contains(reference) {
+                    union.capabilities.push(reference.clone());
+                }
+            }
+        }
+        union.api_version = api_version_string(api_version);
+
+        self.profiles.insert(name.to_string(), union);
+        Ok(&self.profiles[name])
+    }
+
+    /// Adds a profile which requires only what is required by every one of `profile_names`.
+    ///
+    /// The requirements of each profile are flattened with [`Self::flatten_profile`] and combined into a new
+    /// capability block named `name`: each limit takes the weaker value, flags and booleans are combined with
+    /// a logical and and only extensions required by all profiles are kept. Its api version is the lowest
+    /// one of the combined profiles.
+    pub fn add_intersection_profile(
+        &mut self,
+        name: &str,
+        profile_names: &[&str],
+    ) -> Result<&Profile, JsonError> {
+        let mut api_versions = Vec::new();
+        let mut capabilities = Vec::new();
+        for profile_name in profile_names {
+            api_versions.extend(self.profile(profile_name)?.api_version());
+            capabilities.push(self.flatten_profile(profile_name, None)?);
+        }
+        let api_version = api_versions
+            .into_iter()
+            .min()
+            .unwrap_or(vk::API_VERSION_1_0);
+        let capabilities: Vec<_> = capabilities.iter().collect();
+
+        self.capabilities
+            .insert(name.to_string(), intersect_capabilities(&capabilities));
+        self.profiles.insert(
+            name.to_string(),
+            Profile {
+                version: 1,
+                api_version: api_version_string(api_version),
+                label: name.to_string(),
+                description: format!("Intersection of {}", profile_names.join(", ")),
+                capabilities: vec![CapabilityReference::Required(name.to_string())],
+                ..Default::default()
+            },
+        );
+        Ok(&self.profiles[name])
+    }
+
+    /// Serializes the document as pretty printed JSON.
+    pub fn to_json_string(&self) -> String {
+        serde_json::to_string_pretty(self).expect("profile documents are always serializable")
+    }
+}
+
+pub(crate) fn extension_map(extensions: &[vk::ExtensionProperties]) -> BTreeMap<String, u32> {
+    extensions
+        .iter()
+        .filter_map(|extension| {
+            let name = extension.extension_name_as_c_str().ok()?;
+            Some((name.to_string_lossy().into_owned(), extension.spec_version))
+        })
+        .collect()
+}
+
+pub(crate) fn chain_map(chain: &StructChain) -> BTreeMap<String, StructValue> {
+    chain
+        .read()
+        .map(|(info, value)| (info.name.to_string(), value))
+        .collect()
+}
+
+pub(crate) fn insert_format(
+    formats: &mut BTreeMap<String, BTreeMap<String, StructValue>>,
+    format: vk::Format,
+    chain: &StructChain,
+) {
+    let structs = chain_map(chain);
+    if let (Some(name), false) = (reflect::format_name(format), structs.is_empty()) {
+        formats.insert(name.to_string(), structs);
+    }
+}
+
+pub(crate) fn queue_family_maps(
+    families: &[OwnedQueueFamilyProperties],
+) -> Vec<BTreeMap<String, StructValue>> {
+    fn insert<T>(family: &mut BTreeMap<String, StructValue>, name: &str, value: &T) {
+        let info = reflect::find_struct(name).expect("queue family struct is not reflected");
+        debug_assert_eq!(info.size, std::mem::size_of::<T>());
+        // SAFETY: value is the struct described by info
+        let value = unsafe { reflect::read_struct(info, value as *const T as *const u8) };
+        if !value.is_empty() {
+            family.insert(info.name.to_string(), value);
+        }
+    }
+
+    families
+        .iter()
+        .map(|properties| {
+            let mut family = BTreeMap::new();
+            insert(
+                &mut family,
+                "VkQueueFamilyProperties",
+                &properties.properties,
+            );
+            if let Some(global_priority) = &properties.global_priority {
+                insert(
+                    &mut family,
+                    "VkQueueFamilyGlobalPriorityPropertiesKHR",
+                    global_priority,
+                );
+            }
+            if let Some(video) = &properties.video {
+                insert(&mut family, "VkQueueFamilyVideoPropertiesKHR", video);
+            }
+            if let Some(query_result_status) = &properties.query_result_status {
+                insert(
+                    &mut family,
+                    "VkQueueFamilyQueryResultStatusPropertiesKHR",
+                    query_result_status,
+                );
+            }
+            family
+        })
+        .collect()
+}
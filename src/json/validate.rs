@@ -0,0 +1,724 @@
+//! Validation of profile JSON documents with source locations.
+//!
+//! [`validate`] checks a document against the structure of the vulkan profiles JSON schema and against the
+//! struct, member, flag, enum, format and extension names known to this crate. Every problem is reported as
+//! a [`Diagnostic`] with a JSON path as well as a line and column, which makes it suitable for reporting
+//! problems in custom profiles from a build script:
+//!
+//! ```no_run
+//! let source = std::fs::read_to_string("profiles/VP_CUSTOM_profile.json").unwrap();
+//! let diagnostics = vk_profiles_rs::json::validate::validate(&source);
+//! for diagnostic in &diagnostics {
+//!     println!("cargo:warning=VP_CUSTOM_profile.json:{diagnostic}");
+//! }
+//! if diagnostics.iter().any(|diagnostic| diagnostic.is_error()) {
+//!     panic!("invalid profile");
+//! }
+//! ```
+
+use std::collections::BTreeSet;
+use std::fmt;
+
+use crate::reflect::{self, MemberType, StructCategory, StructInfo};
+
+use super::spanned::{self, Key, Node, NodeValue};
+
+/// The severity of a [`Diagnostic`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// The document is likely valid but contains something unexpected, for example an extension which is
+    /// not known to this crate.
+    Warning,
+    /// The document is invalid.
+    Error,
+}
+
+/// A problem found in a profile document.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    /// The path of the offending value, for example `$.capabilities.baseline.features`.
+    pub path: String,
+    /// The 1-based line of the offending value or key.
+    pub line: usize,
+    /// The 1-based column (in characters) of the offending value or key.
+    pub column: usize,
+    pub message: String,
+}
+
+impl Diagnostic {
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let severity = match self.severity {
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        };
+        write!(
+            f,
+            "{}:{}: {severity}: {} (at {})",
+            self.line, self.column, self.message, self.path
+        )
+    }
+}
+
+/// Validates a profile JSON document.
+///
+/// Returns all problems found ordered by their position in the document. Syntax errors stop the validation
+/// and are reported as a single diagnostic.
+pub fn validate(source: &str) -> Vec<Diagnostic> {
+    let mut validator = Validator {
+        source,
+        line_starts: std::iter::once(0)
+            .chain(source.match_indices('\n').map(|(index, _)| index + 1))
+            .collect(),
+        capabilities: BTreeSet::new(),
+        profiles: BTreeSet::new(),
+        diagnostics: Vec::new(),
+    };
+
+    match spanned::parse(source) {
+        Ok(root) => validator.document(&root),
+        Err(err) => validator.report(Severity::Error, "$", err.offset, err.message),
+    }
+
+    validator
+        .diagnostics
+        .sort_by_key(|diagnostic| (diagnostic.line, diagnostic.column));
+    validator.diagnostics
+}
+
+/// Appends an object key to a path.
+fn child(path: &str, key: &str) -> String {
+    let identifier = key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') && !key.is_empty();
+    if identifier {
+        format!("{path}.{key}")
+    } else {
+        format!("{path}[{key:?}]")
+    }
+}
+
+fn element(path: &str, index: usize) -> String {
+    format!("{path}[{index}]")
+}
+
+/// Returns the candidate closest to `name` if it is similar enough to be a likely typo.
+fn suggest<'a>(name: &str, candidates: impl IntoIterator<Item = &'a str>) -> Option<&'a str> {
+    fn distance(a: &str, b: &str) -> usize {
+        let b: Vec<char> = b.chars().collect();
+        let mut row: Vec<usize> = (0..=b.len()).collect();
+        for (i, ca) in a.chars().enumerate() {
+            let mut previous = row[0];
+            row[0] = i + 1;
+            for (j, cb) in b.iter().enumerate() {
+                let substitution = previous + usize::from(ca != *cb);
+                previous = row[j + 1];
+                row[j + 1] = substitution.min(previous + 1).min(row[j] + 1);
+            }
+        }
+        row[b.len()]
+    }
+
+    let limit = (name.chars().count() / 4).clamp(1, 4);
+    candidates
+        .into_iter()
+        .map(|candidate| (distance(name, candidate), candidate))
+        .filter(|(distance, _)| *distance <= limit)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+fn with_suggestion(message: String, suggestion: Option<&str>) -> String {
+    match suggestion {
+        Some(suggestion) => format!("{message}, did you mean {suggestion}?"),
+        None => message,
+    }
+}
+
+const CAPABILITY_KEYS: [&str; 5] = [
+    "extensions",
+    "features",
+    "properties",
+    "formats",
+    "queueFamiliesProperties",
+];
+
+struct Validator<'a> {
+    source: &'a str,
+    line_starts: Vec<usize>,
+    capabilities: BTreeSet<String>,
+    profiles: BTreeSet<String>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl<'a> Validator<'a> {
+    fn report(&mut self, severity: Severity, path: &str, offset: usize, message: String) {
+        let line = self.line_starts.partition_point(|start| *start <= offset);
+        let line_start = self.line_starts[line - 1];
+        let column = self.source[line_start..offset.min(self.source.len())]
+            .chars()
+            .count()
+            + 1;
+        self.diagnostics.push(Diagnostic {
+            severity,
+            path: path.to_string(),
+            line,
+            column,
+            message,
+        });
+    }
+
+    fn error(&mut self, path: &str, offset: usize, message: String) {
+        self.report(Severity::Error, path, offset, message);
+    }
+
+    fn expected(&mut self, path: &str, node: &Node, expected: &str) {
+        let message = format!("expected {expected} but found {}", node.value.type_name());
+        self.error(path, node.offset, message);
+    }
+
+    /// Returns the entries of an object and reports duplicate keys.
+    fn object<'n>(&mut self, path: &str, node: &'n Node) -> Option<&'n [(Key, Node)]> {
+        let NodeValue::Object(entries) = &node.value else {
+            self.expected(path, node, "an object");
+            return None;
+        };
+
+        for (index, (key, _)) in entries.iter().enumerate() {
+            if entries[..index]
+                .iter()
+                .any(|(previous, _)| previous.name == key.name)
+            {
+                let message = format!("duplicate key {:?}", key.name);
+                self.error(&child(path, &key.name), key.offset, message);
+            }
+        }
+        Some(entries)
+    }
+
+    fn array<'n>(&mut self, path: &str, node: &'n Node) -> Option<&'n [Node]> {
+        match &node.value {
+            NodeValue::Array(elements) => Some(elements),
+            _ => {
+                self.expected(path, node, "an array");
+                None
+            }
+        }
+    }
+
+    fn string<'n>(&mut self, path: &str, node: &'n Node) -> Option<&'n str> {
+        match &node.value {
+            NodeValue::String(value) => Some(value),
+            _ => {
+                self.expected(path, node, "a string");
+                None
+            }
+        }
+    }
+
+    fn integer(&mut self, path: &str, node: &Node, min: i128, max: i128) {
+        let value = match &node.value {
+            NodeValue::Number(number) => number.parse::<i128>().ok(),
+            _ => None,
+        };
+        match value {
+            Some(value) if (min..=max).contains(&value) => {}
+            Some(_) => self.error(
+                path,
+                node.offset,
+                format!("expected an integer between {min} and {max}"),
+            ),
+            None => self.expected(path, node, "an integer"),
+        }
+    }
+
+    fn document(&mut self, root: &Node) {
+        let Some(entries) = self.object("$", root) else {
+            return;
+        };
+
+        // Collect all names first so references can be checked independent of their order
+        for (key, value) in entries {
+            if let NodeValue::Object(children) = &value.value {
+                let names = children.iter().map(|(key, _)| key.name.clone());
+                match key.name.as_str() {
+                    "capabilities" => self.capabilities.extend(names),
+                    "profiles" => self.profiles.extend(names),
+                    _ => {}
+                }
+            }
+        }
+
+        if !entries.iter().any(|(key, _)| key.name == "$schema") {
+            self.report(
+                Severity::Warning,
+                "$",
+                root.offset,
+                "missing \"$schema\"".to_string(),
+            );
+        }
+        for (key, value) in entries {
+            let path = child("$", &key.name);
+            match key.name.as_str() {
+                "$schema" => {
+                    self.string(&path, value);
+                }
+                "capabilities" => {
+                    if let Some(capabilities) = self.object(&path, value) {
+                        for (key, value) in capabilities {
+                            self.capability(&child(&path, &key.name), value);
+                        }
+                    }
+                }
+                "profiles" => {
+                    if let Some(profiles) = self.object(&path, value) {
+                        for (key, value) in profiles {
+                            self.profile(&child(&path, &key.name), value);
+                        }
+                    }
+                }
+                name => {
+                    let message = format!("unknown property {name:?}");
+                    self.report(Severity::Warning, &path, key.offset, message);
+                }
+            }
+        }
+    }
+
+    fn capability(&mut self, path: &str, node: &Node) {
+        let Some(entries) = self.object(path, node) else {
+            return;
+        };
+
+        for (key, value) in entries {
+            let path = child(path, &key.name);
+            match key.name.as_str() {
+                "extensions" => self.extensions(&path, value),
+                "features" => self.structs(&path, value, StructCategory::Features),
+                "properties" => self.structs(&path, value, StructCategory::Properties),
+                "formats" => {
+                    let Some(formats) = self.object(&path, value) else {
+                        continue;
+                    };
+                    for (key, value) in formats {
+                        let format_path = child(&path, &key.name);
+                        if reflect::format_from_name(&key.name).is_none() {
+                            let formats = reflect::find_enum("VkFormat").unwrap().values;
+                            let message = with_suggestion(
+                                format!("unknown format {:?}", key.name),
+                                suggest(&key.name, formats.iter().map(|(name, _)| *name)),
+                            );
+                            self.error(&format_path, key.offset, message);
+                        }
+                        self.structs(&format_path, value, StructCategory::FormatProperties);
+                    }
+                }
+                "queueFamiliesProperties" => {
+                    if let Some(families) = self.array(&path, value) {
+                        for (index, family) in families.iter().enumerate() {
+                            self.structs(
+                                &element(&path, index),
+                                family,
+                                StructCategory::QueueFamilyProperties,
+                            );
+                        }
+                    }
+                }
+                name => {
+                    let message = with_suggestion(
+                        format!("unknown capability property {name:?}"),
+                        suggest(name, CAPABILITY_KEYS),
+                    );
+                    self.error(&path, key.offset, message);
+                }
+            }
+        }
+    }
+
+    fn extensions(&mut self, path: &str, node: &Node) {
+        let Some(entries) = self.object(path, node) else {
+            return;
+        };
+
+        for (key, value) in entries {
+            let path = child(path, &key.name);
+            self.integer(&path, value, 0, u32::MAX.into());
+            if reflect::extension_spec_version(&key.name).is_none() {
+                let message = with_suggestion(
+                    format!("unknown extension {:?}", key.name),
+                    suggest(&key.name, reflect::extension_names()),
+                );
+                self.report(Severity::Warning, &path, key.offset, message);
+            }
+        }
+    }
+
+    fn structs(&mut self, path: &str, node: &Node, category: StructCategory) {
+        let Some(entries) = self.object(path, node) else {
+            return;
+        };
+
+        for (key, value) in entries {
+            let path = child(path, &key.name);
+            match reflect::find_struct(&key.name) {
+                Some(info) if info.category == Some(category) => {
+                    self.struct_value(&path, value, info)
+                }
+                Some(info) => {
+                    let message = format!("{} cannot be used in this section", info.name);
+                    self.error(&path, key.offset, message);
+                }
+                None => {
+                    let candidates = reflect::structs()
+                        .iter()
+                        .filter(|info| info.category == Some(category))
+                        .map(|info| info.name);
+                    let message = with_suggestion(
+                        format!("unknown struct {:?}", key.name),
+                        suggest(&key.name, candidates),
+                    );
+                    self.error(&path, key.offset, message);
+                }
+            }
+        }
+    }
+
+    fn struct_value(&mut self, path: &str, node: &Node, info: &'static StructInfo) {
+        let Some(entries) = self.object(path, node) else {
+            return;
+        };
+
+        for (key, value) in entries {
+            let path = child(path, &key.name);
+            match info.member(&key.name) {
+                Some(member) => self.member(&path, value, &member.ty),
+                None => {
+                    let message = with_suggestion(
+                        format!("{} has no member {:?}", info.name, key.name),
+                        suggest(&key.name, info.members.iter().map(|member| member.name)),
+                    );
+                    self.error(&path, key.offset, message);
+                }
+            }
+        }
+    }
+
+    fn member(&mut self, path: &str, node: &Node, ty: &MemberType) {
+        match ty {
+            MemberType::Bool32 => {
+                if !matches!(node.value, NodeValue::Bool(_)) {
+                    self.expected(path, node, "a boolean");
+                }
+            }
+            MemberType::Uint8 => self.integer(path, node, 0, u8::MAX.into()),
+            MemberType::Uint32 => self.integer(path, node, 0, u32::MAX.into()),
+            MemberType::Int32 => self.integer(path, node, i32::MIN.into(), i32::MAX.into()),
+            MemberType::Uint64 | MemberType::Size => self.integer(path, node, 0, u64::MAX.into()),
+            MemberType::Int64 => self.integer(path, node, i64::MIN.into(), i64::MAX.into()),
+            MemberType::Float32 => {
+                if !matches!(node.value, NodeValue::Number(_)) {
+                    self.expected(path, node, "a number");
+                }
+            }
+            MemberType::String(capacity) => {
+                if let Some(value) = self.string(path, node) {
+                    if value.len() >= *capacity {
+                        let message = format!("string must be shorter than {capacity} bytes");
+                        self.error(path, node.offset, message);
+                    }
+                }
+            }
+            MemberType::Array(element_type, len) => {
+                if let Some(elements) = self.array(path, node) {
+                    if elements.len() != *len {
+                        let message = format!(
+                            "expected an array of {len} elements but found {}",
+                            elements.len()
+                        );
+                        self.error(path, node.offset, message);
+                    }
+                    for (index, value) in elements.iter().enumerate() {
+                        self.member(&element(path, index), value, element_type);
+                    }
+                }
+            }
+            MemberType::Flags(info) => {
+                let Some(elements) = self.array(path, node) else {
+                    return;
+                };
+                for (index, value) in elements.iter().enumerate() {
+                    let path = element(path, index);
+                    let Some(name) = self.string(&path, value) else {
+                        continue;
+                    };
+                    if info.bit(name).is_none() {
+                        let message = with_suggestion(
+                            format!("unknown {} bit {name:?}", info.name),
+                            suggest(name, info.bits.iter().map(|(name, _)| *name)),
+                        );
+                        self.error(&path, value.offset, message);
+                    }
+                }
+            }
+            MemberType::Enum(info) => {
+                if let Some(name) = self.string(path, node) {
+                    if info.value(name).is_none() {
+                        let message = with_suggestion(
+                            format!("unknown {} value {name:?}", info.name),
+                            suggest(name, info.values.iter().map(|(name, _)| *name)),
+                        );
+                        self.error(path, node.offset, message);
+                    }
+                }
+            }
+            MemberType::Struct(info) => self.struct_value(path, node, info),
+        }
+    }
+
+    fn profile(&mut self, path: &str, node: &Node) {
+        let Some(entries) = self.object(path, node) else {
+            return;
+        };
+
+        for required in [
+            "version",
+            "api-version",
+            "label",
+            "description",
+            "capabilities",
+        ] {
+            if !entries.iter().any(|(key, _)| key.name == required) {
+                let message = format!("missing required property {required:?}");
+                self.error(path, node.offset, message);
+            }
+        }
+
+        for (key, value) in entries {
+            let path = child(path, &key.name);
+            match key.name.as_str() {
+                "version" => self.integer(&path, value, 0, u32::MAX.into()),
+                "api-version" => {
+                    let valid = self.string(&path, value).map(|version| {
+                        let parts: Vec<_> = version.split('.').collect();
+                        (2..=3).contains(&parts.len())
+                            && parts.iter().all(|part| part.parse::<u32>().is_ok())
+                    });
+                    if valid == Some(false) {
+                        let message = "expected a version of the form \"1.3.204\"".to_string();
+                        self.error(&path, value.offset, message);
+                    }
+                }
+                "label" | "description" | "status" => {
+                    self.string(&path, value);
+                }
+                "contributors" => {
+                    let Some(contributors) = self.object(&path, value) else {
+                        continue;
+                    };
+                    for (key, value) in contributors {
+                        self.contributor(&child(&path, &key.name), value);
+                    }
+                }
+                "history" => {
+                    let Some(revisions) = self.array(&path, value) else {
+                        continue;
+                    };
+                    for (index, value) in revisions.iter().enumerate() {
+                        self.revision(&element(&path, index), value);
+                    }
+                }
+                "capabilities" => self.capability_references(&path, value),
+                "profiles" | "fallback" => {
+                    let Some(names) = self.array(&path, value) else {
+                        continue;
+                    };
+                    for (index, value) in names.iter().enumerate() {
+                        let path = element(&path, index);
+                        if let Some(name) = self.string(&path, value) {
+                            if !self.profiles.contains(name) {
+                                let message =
+                                    format!("profile {name:?} is not defined in this document");
+                                self.report(Severity::Warning, &path, value.offset, message);
+                            }
+                        }
+                    }
+                }
+                name => {
+                    let message = format!("unknown profile property {name:?}");
+                    self.report(Severity::Warning, &path, key.offset, message);
+                }
+            }
+        }
+    }
+
+    fn capability_references(&mut self, path: &str, node: &Node) {
+        let Some(references) = self.array(path, node) else {
+            return;
+        };
+
+        let check = |validator: &mut Self, path: &str, node: &Node| {
+            if let Some(name) = validator.string(path, node) {
+                if !validator.capabilities.contains(name) {
+                    let message = with_suggestion(
+                        format!("unknown capability block {name:?}"),
+                        suggest(name, validator.capabilities.iter().map(String::as_str)),
+                    );
+                    validator.error(path, node.offset, message);
+                }
+            }
+        };
+
+        for (index, reference) in references.iter().enumerate() {
+            let path = element(path, index);
+            match &reference.value {
+                NodeValue::Array(alternatives) => {
+                    if alternatives.is_empty() {
+                        let message = "alternatives must not be empty".to_string();
+                        self.error(&path, reference.offset, message);
+                    }
+                    for (index, alternative) in alternatives.iter().enumerate() {
+                        check(self, &element(&path, index), alternative);
+                    }
+                }
+                _ => check(self, &path, reference),
+            }
+        }
+    }
+
+    fn contributor(&mut self, path: &str, node: &Node) {
+        let Some(entries) = self.object(path, node) else {
+            return;
+        };
+
+        if !entries.iter().any(|(key, _)| key.name == "company") {
+            self.error(
+                path,
+                node.offset,
+                "missing required property \"company\"".to_string(),
+            );
+        }
+        for (key, value) in entries {
+            let path = child(path, &key.name);
+            match key.name.as_str() {
+                "company" | "email" | "github" => {
+                    self.string(&path, value);
+                }
+                "contact" => {
+                    if !matches!(value.value, NodeValue::Bool(_)) {
+                        self.expected(&path, value, "a boolean");
+                    }
+                }
+                name => {
+                    let message = format!("unknown contributor property {name:?}");
+                    self.report(Severity::Warning, &path, key.offset, message);
+                }
+            }
+        }
+    }
+
+    fn revision(&mut self, path: &str, node: &Node) {
+        let Some(entries) = self.object(path, node) else {
+            return;
+        };
+
+        for required in ["revision", "date", "author", "comment"] {
+            if !entries.iter().any(|(key, _)| key.name == required) {
+                let message = format!("missing required property {required:?}");
+                self.error(path, node.offset, message);
+            }
+        }
+        for (key, value) in entries {
+            let path = child(path, &key.name);
+            match key.name.as_str() {
+                "revision" => self.integer(&path, value, 0, u32::MAX.into()),
+                "date" | "author" | "comment" => {
+                    self.string(&path, value);
+                }
+                name => {
+                    let message = format!("unknown history property {name:?}");
+                    self.report(Severity::Warning, &path, key.offset, message);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate() {
+        let source = r#"{
+    "$schema": "https://schema.khronos.org/vulkan/profiles-0.8.2-281.json#",
+    "capabilities": {
+        "baseline": {
+            "extensions": { "VK_KHR_swapchain": 70 },
+            "features": {
+                "VkPhysicalDeviceFeatures": { "robustBufferAcess": true },
+                "VkPhysicalDeviceVulkan12Feature": { "timelineSemaphore": true }
+            },
+            "properties": {
+                "VkPhysicalDeviceProperties": {
+                    "limits": { "framebufferColorSampleCounts": ["VK_SAMPLE_COUNT_3_BIT"] }
+                }
+            }
+        }
+    },
+    "profiles": {
+        "VP_TEST_profile": {
+            "version": 1,
+            "api-version": "1.3",
+            "label": "Test",
+            "description": "Test",
+            "capabilities": [ "baseline", [ "basline" ] ]
+        }
+    }
+}"#;
+
+        let diagnostics = validate(source);
+        let summary: Vec<_> = diagnostics
+            .iter()
+            .map(|diagnostic| (diagnostic.line, diagnostic.column, diagnostic.path.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (
+                    7,
+                    47,
+                    "$.capabilities.baseline.features.VkPhysicalDeviceFeatures.robustBufferAcess"
+                ),
+                (
+                    8,
+                    17,
+                    "$.capabilities.baseline.features.VkPhysicalDeviceVulkan12Feature"
+                ),
+                (
+                    12,
+                    66,
+                    "$.capabilities.baseline.properties.VkPhysicalDeviceProperties.limits.framebufferColorSampleCounts[0]"
+                ),
+                (23, 45, "$.profiles.VP_TEST_profile.capabilities[1][0]"),
+            ]
+        );
+        assert!(diagnostics.iter().all(Diagnostic::is_error));
+        assert!(diagnostics[0]
+            .message
+            .ends_with("did you mean robustBufferAccess?"));
+        assert!(diagnostics[1]
+            .message
+            .ends_with("did you mean VkPhysicalDeviceVulkan12Features?"));
+    }
+
+    #[test]
+    fn test_syntax_error() {
+        let diagnostics = validate("{\n  \"profiles\": {,\n}");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!((diagnostics[0].line, diagnostics[0].column), (2, 16));
+    }
+}
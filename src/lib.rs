@@ -6,7 +6,7 @@
 //! use ash::vk;
 //! use vk_profiles_rs::{profiles, vp};
 //!
-//! # fn main() -> ash::prelude::VkResult<()> {
+//! # fn main() -> vk_profiles_rs::ProfilesResult<()> {
 //! // Load the function pointers
 //! let vk_profiles = vk_profiles_rs::VulkanProfiles::linked();
 //!
@@ -28,6 +28,9 @@
 //! # }
 //! ```
 //!
+//! Alternatively the owning [`builder::ProfileInstanceBuilder`] can be used which does not require
+//! any of the raw create info structs to be kept alive manually.
+//!
 //! ## Ash design patterns
 //!
 //! This crate uses [ash](https://github.com/ash-rs/ash) design patterns wherever possible to allow for seamless usage. Structs
@@ -39,16 +42,28 @@
 
 extern crate link_cplusplus;
 
+pub mod allocator;
+pub mod budget;
+pub mod builder;
+pub mod capabilities;
 #[cfg(feature = "debug")]
 #[doc(hidden)]
 pub mod enum_debugs;
+mod error;
+pub mod extensions;
+pub mod format;
+pub mod json;
 mod prelude;
 pub mod profiles;
+pub mod queue_family;
+pub mod reflect;
+pub mod spirv;
 pub mod vp;
 
-use ash::prelude::VkResult;
 use ash::vk;
+pub use error::{ErrorContext, ProfilesError, ProfilesResult};
 use prelude::*;
+use queue_family::{OwnedQueueFamilyProperties, QueueFamilyQuery};
 use std::ffi::{c_void, CStr};
 use vp::*;
 
@@ -73,18 +88,28 @@ impl VulkanProfiles {
     }
 
     /// See <https://vulkan.lunarg.com/doc/view/1.4.335.0/windows/profiles_api_library.html#user-content-query-profiles>
-    pub unsafe fn get_profiles(&self) -> VkResult<Vec<ProfileProperties>> {
+    pub unsafe fn get_profiles(&self) -> ProfilesResult<Vec<ProfileProperties>> {
         read_into_uninitialized_vector(|count, data| (self.profiles_fn.get_profiles)(count, data))
+            .map_err(|result| {
+                ProfilesError::vulkan("vpGetProfiles", ErrorContext::default(), result)
+            })
     }
 
     /// See <https://vulkan.lunarg.com/doc/view/1.4.335.0/windows/profiles_api_library.html#user-content-query-profile-fallbacks>
     pub unsafe fn get_profile_fallbacks(
         &self,
         profile: &ProfileProperties,
-    ) -> VkResult<Vec<ProfileProperties>> {
+    ) -> ProfilesResult<Vec<ProfileProperties>> {
         read_into_uninitialized_vector(|count, data| {
             (self.profiles_fn.get_profile_fallbacks)(profile, count, data)
         })
+        .map_err(|result| {
+            ProfilesError::vulkan(
+                "vpGetProfileFallbacks",
+                ErrorContext::profile(profile, None),
+                result,
+            )
+        })
     }
 
     /// See <https://vulkan.lunarg.com/doc/view/1.4.335.0/windows/profiles_api_library.html#user-content-check-instance-level-support>
@@ -92,11 +117,19 @@ impl VulkanProfiles {
         &self,
         layer: Option<&CStr>,
         profile: &ProfileProperties,
-    ) -> VkResult<bool> {
+    ) -> ProfilesResult<bool> {
         let layer = cstr_opt_ptr(layer);
 
         let mut supported: vk::Bool32 = 0;
-        (self.profiles_fn.get_instance_profile_support)(layer, profile, &mut supported).result()?;
+        (self.profiles_fn.get_instance_profile_support)(layer, profile, &mut supported)
+            .result()
+            .map_err(|result| {
+                ProfilesError::vulkan(
+                    "vpGetInstanceProfileSupport",
+                    ErrorContext::profile(profile, None),
+                    result,
+                )
+            })?;
         Ok(supported == 1)
     }
 
@@ -105,15 +138,25 @@ impl VulkanProfiles {
         &self,
         entry: &ash::Entry,
         create_info: &InstanceCreateInfo,
-        allocator: Option<vk::AllocationCallbacks>,
-    ) -> VkResult<ash::Instance> {
-        let allocator = match allocator {
-            Some(allocator) => &allocator,
-            _ => std::ptr::null(),
-        };
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) -> ProfilesResult<ash::Instance> {
+        let allocator = allocation_callbacks.map_or(std::ptr::null(), |callbacks| callbacks);
 
         let mut instance = std::mem::zeroed();
-        (self.profiles_fn.create_instance)(create_info, allocator, &mut instance).result()?;
+        (self.profiles_fn.create_instance)(create_info, allocator, &mut instance)
+            .result()
+            .map_err(|result| {
+                ProfilesError::vulkan(
+                    "vpCreateInstance",
+                    ErrorContext::enabled(
+                        create_info.p_enabled_full_profiles,
+                        create_info.enabled_full_profile_count,
+                        create_info.p_enabled_profile_blocks,
+                        create_info.enabled_profile_block_count,
+                    ),
+                    result,
+                )
+            })?;
         Ok(ash::Instance::load(entry.static_fn(), instance))
     }
 
@@ -123,7 +166,7 @@ impl VulkanProfiles {
         instance: &ash::Instance,
         physical_device: vk::PhysicalDevice,
         profile: &ProfileProperties,
-    ) -> VkResult<bool> {
+    ) -> ProfilesResult<bool> {
         let mut supported: vk::Bool32 = 0;
         (self.profiles_fn.get_physical_device_profile_support)(
             instance.handle(),
@@ -131,7 +174,14 @@ impl VulkanProfiles {
             profile,
             &mut supported,
         )
-        .result()?;
+        .result()
+        .map_err(|result| {
+            ProfilesError::vulkan(
+                "vpGetPhysicalDeviceProfileSupport",
+                ErrorContext::profile(profile, None),
+                result,
+            )
+        })?;
         if supported == 0 {
             Ok(false)
         } else {
@@ -145,16 +195,25 @@ impl VulkanProfiles {
         instance: &ash::Instance,
         physical_device: vk::PhysicalDevice,
         create_info: &DeviceCreateInfo,
-        allocator: Option<vk::AllocationCallbacks>,
-    ) -> VkResult<ash::Device> {
-        let allocator = match allocator {
-            Some(allocator) => &allocator,
-            _ => std::ptr::null(),
-        };
+        allocation_callbacks: Option<&vk::AllocationCallbacks>,
+    ) -> ProfilesResult<ash::Device> {
+        let allocator = allocation_callbacks.map_or(std::ptr::null(), |callbacks| callbacks);
 
         let mut device = std::mem::zeroed();
         (self.profiles_fn.create_device)(physical_device, create_info, allocator, &mut device)
-            .result()?;
+            .result()
+            .map_err(|result| {
+                ProfilesError::vulkan(
+                    "vpCreateDevice",
+                    ErrorContext::enabled(
+                        create_info.p_enabled_full_profiles,
+                        create_info.enabled_full_profile_count,
+                        create_info.p_enabled_profile_blocks,
+                        create_info.enabled_profile_block_count,
+                    ),
+                    result,
+                )
+            })?;
         Ok(ash::Device::load(instance.fp_v1_0(), device))
     }
 
@@ -163,11 +222,21 @@ impl VulkanProfiles {
         &self,
         profile: &ProfileProperties,
         block_name: Option<&CStr>,
-    ) -> VkResult<Vec<vk::ExtensionProperties>> {
-        let block_name = cstr_opt_ptr(block_name);
+    ) -> ProfilesResult<Vec<vk::ExtensionProperties>> {
+        let block_name_ptr = cstr_opt_ptr(block_name);
         read_into_uninitialized_vector(|count, data| {
             (self.profiles_fn.get_profile_instance_extension_properties)(
-                profile, block_name, count, data,
+                profile,
+                block_name_ptr,
+                count,
+                data,
+            )
+        })
+        .map_err(|result| {
+            ProfilesError::vulkan(
+                "vpGetProfileInstanceExtensionProperties",
+                ErrorContext::profile(profile, block_name),
+                result,
             )
         })
     }
@@ -177,11 +246,21 @@ impl VulkanProfiles {
         &self,
         profile: &ProfileProperties,
         block_name: Option<&CStr>,
-    ) -> VkResult<Vec<vk::ExtensionProperties>> {
-        let block_name = cstr_opt_ptr(block_name);
+    ) -> ProfilesResult<Vec<vk::ExtensionProperties>> {
+        let block_name_ptr = cstr_opt_ptr(block_name);
         read_into_uninitialized_vector(|count, data| {
             (self.profiles_fn.get_profile_device_extension_properties)(
-                profile, block_name, count, data,
+                profile,
+                block_name_ptr,
+                count,
+                data,
+            )
+        })
+        .map_err(|result| {
+            ProfilesError::vulkan(
+                "vpGetProfileDeviceExtensionProperties",
+                ErrorContext::profile(profile, block_name),
+                result,
             )
         })
     }
@@ -208,10 +287,22 @@ impl VulkanProfiles {
         &self,
         profile: &ProfileProperties,
         block_name: Option<&CStr>,
-    ) -> VkResult<Vec<vk::StructureType>> {
-        let block_name = cstr_opt_ptr(block_name);
+    ) -> ProfilesResult<Vec<vk::StructureType>> {
+        let block_name_ptr = cstr_opt_ptr(block_name);
         read_into_uninitialized_vector(|count, data| {
-            (self.profiles_fn.get_profile_feature_structure_types)(profile, block_name, count, data)
+            (self.profiles_fn.get_profile_feature_structure_types)(
+                profile,
+                block_name_ptr,
+                count,
+                data,
+            )
+        })
+        .map_err(|result| {
+            ProfilesError::vulkan(
+                "vpGetProfileFeatureStructureTypes",
+                ErrorContext::profile(profile, block_name),
+                result,
+            )
         })
     }
 
@@ -237,45 +328,150 @@ impl VulkanProfiles {
         &self,
         profile: &ProfileProperties,
         block_name: Option<&CStr>,
-    ) -> VkResult<Vec<vk::StructureType>> {
-        let block_name = cstr_opt_ptr(block_name);
+    ) -> ProfilesResult<Vec<vk::StructureType>> {
+        let block_name_ptr = cstr_opt_ptr(block_name);
         read_into_uninitialized_vector(|count, data| {
             (self.profiles_fn.get_profile_property_structure_types)(
-                profile, block_name, count, data,
+                profile,
+                block_name_ptr,
+                count,
+                data,
+            )
+        })
+        .map_err(|result| {
+            ProfilesError::vulkan(
+                "vpGetProfilePropertyStructureTypes",
+                ErrorContext::profile(profile, block_name),
+                result,
             )
         })
     }
 
+    /// Fills a pre-sized slice with the queue family properties of a profile.
+    ///
+    /// If the length of `properties` does not match the number of queue families defined by the profile
+    /// [`ProfilesError::CountMismatch`] is returned and `properties` is left untouched.
+    ///
     /// See <https://vulkan.lunarg.com/doc/view/1.4.335.0/windows/profiles_api_library.html#user-content-query-profile-queue-family-properties>
     pub unsafe fn get_profile_queue_family_properties(
         &self,
         profile: &ProfileProperties,
         block_name: Option<&CStr>,
         properties: &mut [vk::QueueFamilyProperties2],
-    ) -> VkResult<()> {
-        let block_name = cstr_opt_ptr(block_name);
-        let mut count = properties.len() as u32;
+    ) -> ProfilesResult<()> {
+        const CALL: &str = "vpGetProfileQueueFamilyProperties";
+        let block_name_ptr = cstr_opt_ptr(block_name);
+        let error_context = || ErrorContext::profile(profile, block_name);
+
+        let mut count = 0;
         (self.profiles_fn.get_profile_queue_family_properties)(
             profile,
-            block_name,
+            block_name_ptr,
+            &mut count,
+            std::ptr::null_mut(),
+        )
+        .result()
+        .map_err(|result| ProfilesError::vulkan(CALL, error_context(), result))?;
+        if count as usize != properties.len() {
+            return Err(ProfilesError::CountMismatch {
+                call: CALL,
+                context: error_context(),
+                expected: properties.len(),
+                actual: count as usize,
+            });
+        }
+
+        (self.profiles_fn.get_profile_queue_family_properties)(
+            profile,
+            block_name_ptr,
             &mut count,
             properties.as_mut_ptr(),
         )
-        .result()?;
-        assert_eq!(count as usize, properties.len());
+        .result()
+        .map_err(|result| ProfilesError::vulkan(CALL, error_context(), result))?;
+        if count as usize != properties.len() {
+            return Err(ProfilesError::CountMismatch {
+                call: CALL,
+                context: error_context(),
+                expected: properties.len(),
+                actual: count as usize,
+            });
+        }
         Ok(())
     }
 
+    /// Enumerates the queue family properties of a profile.
+    ///
+    /// Unlike [`Self::get_profile_queue_family_properties`] this does not require a pre-sized slice. All
+    /// extension structs listed by [`Self::get_profile_queue_family_structure_types`] (for example global
+    /// priority or video properties) are queried as well.
+    ///
+    /// See <https://vulkan.lunarg.com/doc/view/1.4.335.0/windows/profiles_api_library.html#user-content-query-profile-queue-family-properties>
+    ///
+    /// # Safety
+    /// The profile name of `profile` must be null terminated.
+    pub unsafe fn enumerate_profile_queue_family_properties(
+        &self,
+        profile: &ProfileProperties,
+        block_name: Option<&CStr>,
+    ) -> ProfilesResult<Vec<OwnedQueueFamilyProperties>> {
+        const CALL: &str = "vpGetProfileQueueFamilyProperties";
+        let structure_types = self.get_profile_queue_family_structure_types(profile, block_name)?;
+        let block_name_ptr = cstr_opt_ptr(block_name);
+        let error_context = || ErrorContext::profile(profile, block_name);
+
+        let mut count = 0;
+        (self.profiles_fn.get_profile_queue_family_properties)(
+            profile,
+            block_name_ptr,
+            &mut count,
+            std::ptr::null_mut(),
+        )
+        .result()
+        .map_err(|result| ProfilesError::vulkan(CALL, error_context(), result))?;
+
+        let mut query = QueueFamilyQuery::new(count as usize, &structure_types);
+        let mut written = count;
+        (self.profiles_fn.get_profile_queue_family_properties)(
+            profile,
+            block_name_ptr,
+            &mut written,
+            query.link().as_mut_ptr(),
+        )
+        .result()
+        .map_err(|result| ProfilesError::vulkan(CALL, error_context(), result))?;
+        if written != count {
+            return Err(ProfilesError::CountMismatch {
+                call: CALL,
+                context: error_context(),
+                expected: count as usize,
+                actual: written as usize,
+            });
+        }
+
+        Ok(query.into_owned())
+    }
+
     /// See <https://vulkan.lunarg.com/doc/view/1.4.335.0/windows/profiles_api_library.html#user-content-query-profile-queue-family-properties>
     pub unsafe fn get_profile_queue_family_structure_types(
         &self,
         profile: &ProfileProperties,
         block_name: Option<&CStr>,
-    ) -> VkResult<Vec<vk::StructureType>> {
-        let block_name = cstr_opt_ptr(block_name);
+    ) -> ProfilesResult<Vec<vk::StructureType>> {
+        let block_name_ptr = cstr_opt_ptr(block_name);
         read_into_uninitialized_vector(|count, data| {
             (self.profiles_fn.get_profile_queue_family_structure_types)(
-                profile, block_name, count, data,
+                profile,
+                block_name_ptr,
+                count,
+                data,
+            )
+        })
+        .map_err(|result| {
+            ProfilesError::vulkan(
+                "vpGetProfileQueueFamilyStructureTypes",
+                ErrorContext::profile(profile, block_name),
+                result,
             )
         })
     }
@@ -285,10 +481,17 @@ impl VulkanProfiles {
         &self,
         profile: &ProfileProperties,
         block_name: Option<&CStr>,
-    ) -> VkResult<Vec<vk::Format>> {
-        let block_name = cstr_opt_ptr(block_name);
+    ) -> ProfilesResult<Vec<vk::Format>> {
+        let block_name_ptr = cstr_opt_ptr(block_name);
         read_into_uninitialized_vector(|count, data| {
-            (self.profiles_fn.get_profile_formats)(profile, block_name, count, data)
+            (self.profiles_fn.get_profile_formats)(profile, block_name_ptr, count, data)
+        })
+        .map_err(|result| {
+            ProfilesError::vulkan(
+                "vpGetProfileFormats",
+                ErrorContext::profile(profile, block_name),
+                result,
+            )
         })
     }
 
@@ -314,16 +517,29 @@ impl VulkanProfiles {
         &self,
         profile: &ProfileProperties,
         block_name: Option<&CStr>,
-    ) -> VkResult<Vec<vk::StructureType>> {
-        let block_name = cstr_opt_ptr(block_name);
+    ) -> ProfilesResult<Vec<vk::StructureType>> {
+        let block_name_ptr = cstr_opt_ptr(block_name);
         read_into_uninitialized_vector(|count, data| {
-            (self.profiles_fn.get_profile_format_structure_types)(profile, block_name, count, data)
+            (self.profiles_fn.get_profile_format_structure_types)(
+                profile,
+                block_name_ptr,
+                count,
+                data,
+            )
+        })
+        .map_err(|result| {
+            ProfilesError::vulkan(
+                "vpGetProfileFormatStructureTypes",
+                ErrorContext::profile(profile, block_name),
+                result,
+            )
         })
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::builder::{ProfileDeviceBuilder, ProfileInstanceBuilder};
     use crate::profiles;
     use crate::vp;
     use crate::VulkanProfiles;
@@ -414,11 +630,21 @@ mod tests {
                     .get_profile_queue_family_structure_types(&profile, block_name)
                     .unwrap()
             };
+            unsafe {
+                vk_profiles
+                    .enumerate_profile_queue_family_properties(&profile, block_name)
+                    .unwrap()
+            };
             unsafe {
                 vk_profiles
                     .get_profile_formats(&profile, block_name)
                     .unwrap()
             };
+            unsafe {
+                vk_profiles
+                    .profile_format_requirements(&profile, block_name)
+                    .unwrap()
+            };
             unsafe {
                 vk_profiles
                     .get_profile_property_structure_types(&profile, block_name)
@@ -436,13 +662,25 @@ mod tests {
     }
 
     #[test]
-    fn test_create_device() {
-        let vk_profiles = VulkanProfiles::linked();
+    fn test_instance_builder() {
         let entry = ash::Entry::linked();
+        let instance = unsafe {
+            ProfileInstanceBuilder::new(&VulkanProfiles::linked())
+                .application_name(c"vk-profiles-rs test")
+                .enabled_full_profile(profiles::KhrRoadmap2024::profile_properties())
+                .build(&entry, None)
+                .unwrap()
+        };
 
-        let (profiles, instance) = create_instance(&entry, &vk_profiles);
+        unsafe { instance.destroy_instance(None) };
+    }
 
-        let physical_device = unsafe { instance.enumerate_physical_devices().unwrap() }
+    fn find_physical_device(
+        vk_profiles: &VulkanProfiles,
+        instance: &ash::Instance,
+        profiles: &[vp::ProfileProperties],
+    ) -> vk::PhysicalDevice {
+        unsafe { instance.enumerate_physical_devices().unwrap() }
             .into_iter()
             .find(|device| {
                 let props = unsafe { instance.get_physical_device_properties(*device) };
@@ -453,7 +691,7 @@ mod tests {
                 for profile in profiles {
                     let supports_this = unsafe {
                         vk_profiles
-                            .get_physical_device_profile_support(&instance, *device, &profile)
+                            .get_physical_device_profile_support(instance, *device, profile)
                             .expect("Error queueing physical device support")
                     };
                     if !supports_this {
@@ -463,7 +701,17 @@ mod tests {
                 }
                 !unsupported
             })
-            .expect("Failed to find suitable physical device");
+            .expect("Failed to find suitable physical device")
+    }
+
+    #[test]
+    fn test_create_device() {
+        let vk_profiles = VulkanProfiles::linked();
+        let entry = ash::Entry::linked();
+
+        let (profiles, instance) = create_instance(&entry, &vk_profiles);
+
+        let physical_device = find_physical_device(&vk_profiles, &instance, &profiles);
 
         let queue_priorities: [f32; 1] = [1.0];
         let queue_info = vk::DeviceQueueCreateInfo {
@@ -493,4 +741,26 @@ mod tests {
 
         println!("{:?}", vk::ImageUsageFlags::COLOR_ATTACHMENT);
     }
+
+    #[test]
+    fn test_device_builder() {
+        let vk_profiles = VulkanProfiles::linked();
+        let entry = ash::Entry::linked();
+
+        let (profiles, instance) = create_instance(&entry, &vk_profiles);
+        let physical_device = find_physical_device(&vk_profiles, &instance, &profiles);
+
+        let (device, queues) = unsafe {
+            ProfileDeviceBuilder::new(&vk_profiles)
+                .queues_with_flags(vk::QueueFlags::GRAPHICS, &[1.0])
+                .enabled_full_profiles(&profiles)
+                .build(&instance, physical_device, None)
+                .expect("Failed to create device")
+        };
+        assert_eq!(queues.len(), 1);
+
+        unsafe { device.destroy_device(None) };
+
+        unsafe { instance.destroy_instance(None) };
+    }
 }
@@ -2,7 +2,7 @@
 //!
 //! Many of these functions are copied directly from ash as they are not declared public.
 
-use std::ffi::{c_char, CStr};
+use std::ffi::{c_char, CStr, FromBytesUntilNulError};
 use std::ptr;
 
 use ash::prelude::VkResult;
@@ -90,6 +90,14 @@ pub(crate) const fn c_char_array_from_cstr<const N: usize>(
     Some(result)
 }
 
+/// Creates a fixed size c_char array from a string.
+///
+/// If the string contains nul characters or is too large for the array None is returned.
+pub(crate) fn c_char_array_from_str<const N: usize>(data: &str) -> Option<[c_char; N]> {
+    let data = std::ffi::CString::new(data).ok()?;
+    c_char_array_from_cstr(&data)
+}
+
 /// Get pointer from inside Option<&CStr>, or return ptr::null() if None
 pub(crate) fn cstr_opt_ptr(cstr_opt: Option<&CStr>) -> *const c_char {
     match cstr_opt {
@@ -97,3 +105,12 @@ pub(crate) fn cstr_opt_ptr(cstr_opt: Option<&CStr>) -> *const c_char {
         None => ptr::null(),
     }
 }
+
+/// Interprets a fixed size c_char array as a CStr ending at the first nul character.
+///
+/// Equivalent to ash's `extension_name_as_c_str` style accessors.
+pub(crate) fn c_char_array_as_c_str(data: &[c_char]) -> Result<&CStr, FromBytesUntilNulError> {
+    // SAFETY: c_char and u8 have the same size and alignment
+    let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), data.len()) };
+    CStr::from_bytes_until_nul(bytes)
+}
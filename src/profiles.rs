@@ -100,3 +100,73 @@ profile!(
     1,
     vk::make_api_version(0, 1, 3, 204)
 );
+
+/// The properties and minimum api versions of all profiles defined in this module.
+const KNOWN_PROFILES: [(ProfileProperties, u32); 13] = [
+    (
+        AndroidBaseline2022::profile_properties(),
+        AndroidBaseline2022::MIN_API_VERSION,
+    ),
+    (
+        Android15Minimums::profile_properties(),
+        Android15Minimums::MIN_API_VERSION,
+    ),
+    (
+        Android16Minimums::profile_properties(),
+        Android16Minimums::MIN_API_VERSION,
+    ),
+    (
+        AndroidBaseline2021::profile_properties(),
+        AndroidBaseline2021::MIN_API_VERSION,
+    ),
+    (
+        KhrRoadmap2022::profile_properties(),
+        KhrRoadmap2022::MIN_API_VERSION,
+    ),
+    (
+        KhrRoadmap2024::profile_properties(),
+        KhrRoadmap2024::MIN_API_VERSION,
+    ),
+    (
+        LunargDesktopBaseline2022::profile_properties(),
+        LunargDesktopBaseline2022::MIN_API_VERSION,
+    ),
+    (
+        LunargDesktopBaseline2023::profile_properties(),
+        LunargDesktopBaseline2023::MIN_API_VERSION,
+    ),
+    (
+        LunargDesktopBaseline2024::profile_properties(),
+        LunargDesktopBaseline2024::MIN_API_VERSION,
+    ),
+    (
+        LunargMinimumRequirements1_0::profile_properties(),
+        LunargMinimumRequirements1_0::MIN_API_VERSION,
+    ),
+    (
+        LunargMinimumRequirements1_1::profile_properties(),
+        LunargMinimumRequirements1_1::MIN_API_VERSION,
+    ),
+    (
+        LunargMinimumRequirements1_2::profile_properties(),
+        LunargMinimumRequirements1_2::MIN_API_VERSION,
+    ),
+    (
+        LunargMinimumRequirements1_3::profile_properties(),
+        LunargMinimumRequirements1_3::MIN_API_VERSION,
+    ),
+];
+
+/// Returns the minimum api version of a profile defined in this module or [`None`] if the profile is not
+/// known.
+pub fn min_api_version(profile_name: &CStr) -> Option<u32> {
+    KNOWN_PROFILES
+        .iter()
+        .find(|(profile, _)| profile.profile_name_as_c_str() == Ok(profile_name))
+        .map(|(_, min_api_version)| *min_api_version)
+}
+
+/// Returns the properties of all profiles defined in this module.
+pub fn all_profiles() -> impl Iterator<Item = ProfileProperties> {
+    KNOWN_PROFILES.into_iter().map(|(profile, _)| profile)
+}
@@ -0,0 +1,456 @@
+//! Owned queue family properties and matching of profile queue families to device queue families.
+//!
+//! [`match_queue_families`] lists every device queue family satisfying a requirement while
+//! [`assign_queue_families`] picks one device queue family per requirement, which can be used to create
+//! the queues of a device with [`ProfileDeviceBuilder::assigned_queues`](crate::builder::ProfileDeviceBuilder::assigned_queues).
+
+use ash::vk;
+
+use crate::builder::{enabled_blocks, OwnedDeviceQueueCreateInfo};
+use crate::vp::{BlockProperties, ProfileProperties};
+use crate::{ProfilesResult, VulkanProfiles};
+
+/// Owned queue family properties including the extension structs which can be part of a
+/// [`vk::QueueFamilyProperties2`] chain.
+///
+/// Extension structs are [`None`] if they were not part of the queried chain. The `p_next` pointers of all
+/// contained structs are always null.
+#[derive(Copy, Clone, Default)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct OwnedQueueFamilyProperties {
+    pub properties: vk::QueueFamilyProperties,
+    pub global_priority: Option<vk::QueueFamilyGlobalPriorityPropertiesKHR<'static>>,
+    pub video: Option<vk::QueueFamilyVideoPropertiesKHR<'static>>,
+    pub query_result_status: Option<vk::QueueFamilyQueryResultStatusPropertiesKHR<'static>>,
+}
+
+impl From<vk::QueueFamilyProperties> for OwnedQueueFamilyProperties {
+    fn from(properties: vk::QueueFamilyProperties) -> Self {
+        Self {
+            properties,
+            ..Default::default()
+        }
+    }
+}
+
+impl OwnedQueueFamilyProperties {
+    /// Returns true if this queue family (usually a device queue family) satisfies all requirements of
+    /// `requirement` (usually a profile queue family).
+    ///
+    /// Queue flags and video codec operations must be a superset, the queue count and timestamp valid bits
+    /// must be at least as large and all required global priorities must be supported. Graphics and compute
+    /// queues implicitly support transfer operations, so they satisfy a required transfer flag. A zero
+    /// `min_image_transfer_granularity` in the requirement is treated as unspecified.
+    pub fn satisfies(&self, requirement: &OwnedQueueFamilyProperties) -> bool {
+        let properties = &self.properties;
+        let required = &requirement.properties;
+
+        let mut queue_flags = properties.queue_flags;
+        if queue_flags.intersects(vk::QueueFlags::GRAPHICS | vk::QueueFlags::COMPUTE) {
+            queue_flags |= vk::QueueFlags::TRANSFER;
+        }
+        if !queue_flags.contains(required.queue_flags)
+            || properties.queue_count < required.queue_count
+            || properties.timestamp_valid_bits < required.timestamp_valid_bits
+        {
+            return false;
+        }
+
+        let granularity = properties.min_image_transfer_granularity;
+        let required_granularity = required.min_image_transfer_granularity;
+        if required_granularity != vk::Extent3D::default()
+            && (granularity == vk::Extent3D::default()
+                || granularity.width > required_granularity.width
+                || granularity.height > required_granularity.height
+                || granularity.depth > required_granularity.depth)
+        {
+            return false;
+        }
+
+        if let Some(required) = &requirement.global_priority {
+            let Some(available) = &self.global_priority else {
+                return false;
+            };
+            let available = &available.priorities[..available.priority_count as usize];
+            if !required.priorities[..required.priority_count as usize]
+                .iter()
+                .all(|priority| available.contains(priority))
+            {
+                return false;
+            }
+        }
+
+        if let Some(required) = &requirement.video {
+            if self.video.is_none_or(|video| {
+                !video
+                    .video_codec_operations
+                    .contains(required.video_codec_operations)
+            }) {
+                return false;
+            }
+        }
+
+        if let Some(required) = &requirement.query_result_status {
+            if required.query_result_status_support != vk::FALSE
+                && self
+                    .query_result_status
+                    .is_none_or(|status| status.query_result_status_support == vk::FALSE)
+            {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+/// Returns for each required queue family the indices of all `device_families` which satisfy it.
+///
+/// See [`OwnedQueueFamilyProperties::satisfies`] for the matching rules.
+pub fn match_queue_families(
+    required_families: &[OwnedQueueFamilyProperties],
+    device_families: &[OwnedQueueFamilyProperties],
+) -> Vec<Vec<u32>> {
+    required_families
+        .iter()
+        .map(|required| {
+            device_families
+                .iter()
+                .enumerate()
+                .filter(|(_, family)| family.satisfies(required))
+                .map(|(index, _)| index as u32)
+                .collect()
+        })
+        .collect()
+}
+
+/// The device queue families assigned to a list of required queue families.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct QueueAssignment {
+    /// The index of the device queue family assigned to each required queue family.
+    pub family_indices: Vec<u32>,
+    /// The number of queues of each required queue family. A required queue count of zero counts as one.
+    pub queue_counts: Vec<u32>,
+}
+
+impl QueueAssignment {
+    /// Returns the device queue families used by the assignment in ascending order.
+    pub fn used_families(&self) -> Vec<u32> {
+        let mut families = self.family_indices.clone();
+        families.sort_unstable();
+        families.dedup();
+        families
+    }
+
+    /// Returns one queue create info per used device queue family which creates the queues of all required
+    /// queue families assigned to it with the same `priority`.
+    pub fn queue_create_infos(&self, priority: f32) -> Vec<OwnedDeviceQueueCreateInfo> {
+        self.used_families()
+            .into_iter()
+            .map(|family_index| {
+                let count: u32 = self
+                    .family_indices
+                    .iter()
+                    .zip(&self.queue_counts)
+                    .filter(|(index, _)| **index == family_index)
+                    .map(|(_, count)| count)
+                    .sum();
+                OwnedDeviceQueueCreateInfo {
+                    flags: vk::DeviceQueueCreateFlags::empty(),
+                    queue_family_index: family_index,
+                    queue_priorities: vec![priority; count as usize],
+                }
+            })
+            .collect()
+    }
+}
+
+/// Assigns a device queue family to each required queue family.
+///
+/// Every assigned family must satisfy its requirement, see [`OwnedQueueFamilyProperties::satisfies`].
+/// Distinct device families are preferred, but a device family may be shared by several requirements if it
+/// has enough queues for all of them. Returns [`None`] if no such assignment exists.
+pub fn assign_queue_families(
+    required_families: &[OwnedQueueFamilyProperties],
+    device_families: &[OwnedQueueFamilyProperties],
+) -> Option<QueueAssignment> {
+    fn assign(
+        candidates: &[Vec<u32>],
+        queue_counts: &[u32],
+        available: &mut [u32],
+        family_indices: &mut Vec<u32>,
+    ) -> bool {
+        let Some(candidates_of_next) = candidates.get(family_indices.len()) else {
+            return true;
+        };
+        let count = queue_counts[family_indices.len()];
+        // Unused families first so families are only shared if necessary
+        let mut order: Vec<u32> = candidates_of_next.clone();
+        order.sort_by_key(|index| family_indices.contains(index));
+        for index in order {
+            if available[index as usize] < count {
+                continue;
+            }
+            available[index as usize] -= count;
+            family_indices.push(index);
+            if assign(candidates, queue_counts, available, family_indices) {
+                return true;
+            }
+            family_indices.pop();
+            available[index as usize] += count;
+        }
+        false
+    }
+
+    let candidates = match_queue_families(required_families, device_families);
+    let queue_counts: Vec<u32> = required_families
+        .iter()
+        .map(|family| family.properties.queue_count.max(1))
+        .collect();
+    let mut available: Vec<u32> = device_families
+        .iter()
+        .map(|family| family.properties.queue_count)
+        .collect();
+    let mut family_indices = Vec::with_capacity(required_families.len());
+    assign(
+        &candidates,
+        &queue_counts,
+        &mut available,
+        &mut family_indices,
+    )
+    .then_some(QueueAssignment {
+        family_indices,
+        queue_counts,
+    })
+}
+
+/// Assigns a device queue family to each queue family required by the enabled profiles and profile blocks.
+///
+/// The required queue families are listed in the order of the profiles and blocks. See
+/// [`assign_queue_families`].
+///
+/// # Safety
+/// The profile names of `profiles` and the names of `blocks` must be null terminated.
+pub unsafe fn assign_profile_queue_families(
+    vk_profiles: &VulkanProfiles,
+    profiles: &[ProfileProperties],
+    blocks: &[BlockProperties],
+    device_families: &[OwnedQueueFamilyProperties],
+) -> ProfilesResult<Option<QueueAssignment>> {
+    let mut required_families = Vec::new();
+    for (profile, block_name) in enabled_blocks(profiles, blocks) {
+        required_families
+            .extend(vk_profiles.enumerate_profile_queue_family_properties(profile, block_name)?);
+    }
+    Ok(assign_queue_families(&required_families, device_families))
+}
+
+/// Queries the queue family properties of a physical device including the extension structs listed in
+/// `structure_types`. Structure types which are not part of a [`vk::QueueFamilyProperties2`] chain are
+/// ignored.
+///
+/// # Safety
+/// `physical_device` must be a valid physical device of `instance` and `instance` must have been created
+/// with api version 1.1 or higher. The required extensions or api version for the extension structs must be
+/// supported by the physical device.
+pub unsafe fn get_physical_device_queue_family_properties(
+    instance: &ash::Instance,
+    physical_device: vk::PhysicalDevice,
+    structure_types: &[vk::StructureType],
+) -> Vec<OwnedQueueFamilyProperties> {
+    let count = instance.get_physical_device_queue_family_properties2_len(physical_device);
+    let mut query = QueueFamilyQuery::new(count, structure_types);
+    instance.get_physical_device_queue_family_properties2(physical_device, query.link());
+    query.into_owned()
+}
+
+/// Storage for a list of [`vk::QueueFamilyProperties2`] structs and their pNext chains.
+pub(crate) struct QueueFamilyQuery {
+    properties: Vec<vk::QueueFamilyProperties2<'static>>,
+    global_priority: Vec<vk::QueueFamilyGlobalPriorityPropertiesKHR<'static>>,
+    video: Vec<vk::QueueFamilyVideoPropertiesKHR<'static>>,
+    query_result_status: Vec<vk::QueueFamilyQueryResultStatusPropertiesKHR<'static>>,
+}
+
+impl QueueFamilyQuery {
+    /// Allocates storage for `count` queue families with the extension structs listed in `structure_types`.
+    pub(crate) fn new(count: usize, structure_types: &[vk::StructureType]) -> Self {
+        fn storage<T: Default>(
+            count: usize,
+            structure_types: &[vk::StructureType],
+            ty: vk::StructureType,
+        ) -> Vec<T> {
+            if structure_types.contains(&ty) {
+                (0..count).map(|_| T::default()).collect()
+            } else {
+                Vec::new()
+            }
+        }
+
+        Self {
+            properties: vec![vk::QueueFamilyProperties2::default(); count],
+            global_priority: storage(
+                count,
+                structure_types,
+                vk::StructureType::QUEUE_FAMILY_GLOBAL_PRIORITY_PROPERTIES_KHR,
+            ),
+            video: storage(
+                count,
+                structure_types,
+                vk::StructureType::QUEUE_FAMILY_VIDEO_PROPERTIES_KHR,
+            ),
+            query_result_status: storage(
+                count,
+                structure_types,
+                vk::StructureType::QUEUE_FAMILY_QUERY_RESULT_STATUS_PROPERTIES_KHR,
+            ),
+        }
+    }
+
+    /// Links the pNext chains and returns the list of [`vk::QueueFamilyProperties2`] to be filled.
+    ///
+    /// The returned structs point into the storage of `self` so `self` must not be moved while they are used.
+    pub(crate) fn link(&mut self) -> &mut [vk::QueueFamilyProperties2<'static>] {
+        for (index, properties) in self.properties.iter_mut().enumerate() {
+            let mut chain: Vec<*mut vk::BaseOutStructure> = Vec::new();
+            if let Some(next) = self.global_priority.get_mut(index) {
+                chain.push(next as *mut _ as *mut vk::BaseOutStructure);
+            }
+            if let Some(next) = self.video.get_mut(index) {
+                chain.push(next as *mut _ as *mut vk::BaseOutStructure);
+            }
+            if let Some(next) = self.query_result_status.get_mut(index) {
+                chain.push(next as *mut _ as *mut vk::BaseOutStructure);
+            }
+
+            let mut p_next: *mut vk::BaseOutStructure = std::ptr::null_mut();
+            for next in chain.into_iter().rev() {
+                // SAFETY: all pointers point to valid structs owned by self
+                unsafe { (*next).p_next = p_next };
+                p_next = next;
+            }
+            properties.p_next = p_next as *mut std::ffi::c_void;
+        }
+        &mut self.properties
+    }
+
+    /// Copies the filled structs into owned values with null pNext pointers.
+    pub(crate) fn into_owned(self) -> Vec<OwnedQueueFamilyProperties> {
+        fn unlink<T: Copy>(list: &[T], index: usize, p_next: impl Fn(&mut T)) -> Option<T> {
+            list.get(index).map(|value| {
+                let mut value = *value;
+                p_next(&mut value);
+                value
+            })
+        }
+
+        (0..self.properties.len())
+            .map(|index| OwnedQueueFamilyProperties {
+                properties: self.properties[index].queue_family_properties,
+                global_priority: unlink(&self.global_priority, index, |v| {
+                    v.p_next = std::ptr::null_mut()
+                }),
+                video: unlink(&self.video, index, |v| v.p_next = std::ptr::null_mut()),
+                query_result_status: unlink(&self.query_result_status, index, |v| {
+                    v.p_next = std::ptr::null_mut()
+                }),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn family(flags: vk::QueueFlags, count: u32) -> OwnedQueueFamilyProperties {
+        vk::QueueFamilyProperties {
+            queue_flags: flags,
+            queue_count: count,
+            timestamp_valid_bits: 64,
+            min_image_transfer_granularity: vk::Extent3D {
+                width: 1,
+                height: 1,
+                depth: 1,
+            },
+        }
+        .into()
+    }
+
+    #[test]
+    fn test_match_queue_families() {
+        let device = [
+            family(vk::QueueFlags::GRAPHICS | vk::QueueFlags::COMPUTE, 1),
+            family(vk::QueueFlags::COMPUTE | vk::QueueFlags::TRANSFER, 4),
+        ];
+
+        let mut graphics = OwnedQueueFamilyProperties::default();
+        graphics.properties.queue_flags = vk::QueueFlags::GRAPHICS;
+        let mut compute = OwnedQueueFamilyProperties::default();
+        compute.properties.queue_flags = vk::QueueFlags::COMPUTE;
+        compute.properties.queue_count = 2;
+
+        let mut video = graphics;
+        video.video = Some(
+            vk::QueueFamilyVideoPropertiesKHR::default()
+                .video_codec_operations(vk::VideoCodecOperationFlagsKHR::DECODE_H264),
+        );
+        // Graphics and compute families implicitly support transfer operations
+        let mut transfer = OwnedQueueFamilyProperties::default();
+        transfer.properties.queue_flags = vk::QueueFlags::GRAPHICS | vk::QueueFlags::TRANSFER;
+        let mut sparse = OwnedQueueFamilyProperties::default();
+        sparse.properties.queue_flags = vk::QueueFlags::TRANSFER | vk::QueueFlags::SPARSE_BINDING;
+
+        assert_eq!(
+            match_queue_families(&[graphics, compute, video, transfer, sparse], &device),
+            vec![vec![0], vec![1], vec![], vec![0], vec![]]
+        );
+    }
+
+    #[test]
+    fn test_assign_queue_families() {
+        let device = [
+            family(vk::QueueFlags::GRAPHICS | vk::QueueFlags::COMPUTE, 2),
+            family(vk::QueueFlags::COMPUTE | vk::QueueFlags::TRANSFER, 1),
+        ];
+        let mut graphics = OwnedQueueFamilyProperties::default();
+        graphics.properties.queue_flags = vk::QueueFlags::GRAPHICS;
+        let mut compute = OwnedQueueFamilyProperties::default();
+        compute.properties.queue_flags = vk::QueueFlags::COMPUTE;
+
+        // The second compute requirement must share the graphics family
+        let assignment = assign_queue_families(&[graphics, compute, compute], &device).unwrap();
+        assert_eq!(assignment.family_indices, [0, 1, 0]);
+        assert_eq!(assignment.queue_counts, [1, 1, 1]);
+        assert_eq!(assignment.used_families(), [0, 1]);
+        assert_eq!(
+            assignment.queue_create_infos(1.0),
+            [
+                OwnedDeviceQueueCreateInfo {
+                    flags: vk::DeviceQueueCreateFlags::empty(),
+                    queue_family_index: 0,
+                    queue_priorities: vec![1.0, 1.0],
+                },
+                OwnedDeviceQueueCreateInfo {
+                    flags: vk::DeviceQueueCreateFlags::empty(),
+                    queue_family_index: 1,
+                    queue_priorities: vec![1.0],
+                },
+            ]
+        );
+
+        // Sharing is only legal while the family has enough queues
+        assert_eq!(
+            assign_queue_families(&[graphics, compute, compute, compute], &device),
+            None
+        );
+        let mut two_compute = compute;
+        two_compute.properties.queue_count = 2;
+        let assignment = assign_queue_families(&[two_compute, compute], &device).unwrap();
+        assert_eq!(assignment.family_indices, [0, 1]);
+        assert_eq!(
+            assign_queue_families(&[], &device),
+            Some(QueueAssignment::default())
+        );
+    }
+}
@@ -0,0 +1,1297 @@
+//! Reflection data for the vulkan structs, flags and enums which can be described by profile JSON files.
+//!
+//! Every struct which can extend [`vk::PhysicalDeviceFeatures2`], [`vk::PhysicalDeviceProperties2`],
+//! [`vk::FormatProperties2`] or [`vk::QueueFamilyProperties2`] is described together with its members, their
+//! byte offsets and types. Members are named like in the vulkan registry (for example `robustBufferAccess`)
+//! so that JSON values can be mapped onto the matching ash structs.
+//!
+//! The type, dependencies and core promotion of every known extension are available through
+//! [`find_extension`]. The SPIR-V capabilities and extensions a shader may declare and the features,
+//! properties, extensions or versions which allow them are available through [`find_spirv_capability`] and
+//! [`find_spirv_extension`]. How the values of property members are compared is available through
+//! [`find_limit_type`].
+
+use ash::vk;
+use serde_json::Value;
+
+use crate::json::JsonError;
+
+mod extensions;
+mod limits;
+mod spirv;
+mod tables;
+
+/// The kind of query a struct is part of.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StructCategory {
+    Features,
+    Properties,
+    FormatProperties,
+    QueueFamilyProperties,
+}
+
+/// The type of a struct member.
+#[derive(Copy, Clone, Debug)]
+pub enum MemberType {
+    Bool32,
+    Uint8,
+    Uint32,
+    Int32,
+    Uint64,
+    Int64,
+    Float32,
+    Size,
+    /// A null terminated `c_char` array with the specified capacity.
+    String(usize),
+    Array(&'static MemberType, usize),
+    Flags(&'static FlagsInfo),
+    Enum(&'static EnumInfo),
+    Struct(&'static StructInfo),
+}
+
+/// A member of a reflected struct.
+#[derive(Copy, Clone, Debug)]
+pub struct MemberInfo {
+    /// The member name as used in the vulkan registry.
+    pub name: &'static str,
+    /// The byte offset of the member in the ash struct.
+    pub offset: usize,
+    pub ty: MemberType,
+}
+
+/// A reflected vulkan struct.
+///
+/// The `s_type` and `p_next` members are not part of [`Self::members`]. Members with pointer types are
+/// omitted as well.
+#[derive(Copy, Clone, Debug)]
+pub struct StructInfo {
+    /// The vulkan name of the struct, for example `VkPhysicalDeviceFeatures`.
+    pub name: &'static str,
+    /// The structure type of extension structs. [`None`] for structs without `s_type` member.
+    pub structure_type: Option<vk::StructureType>,
+    /// The query this struct is part of or [`None`] for structs which are only used as members.
+    pub category: Option<StructCategory>,
+    /// The size of the ash struct in bytes.
+    pub size: usize,
+    /// The core versions (for example `VK_VERSION_1_2`) and extensions which provide the struct. Empty for
+    /// structs without structure type.
+    pub providers: &'static [&'static str],
+    pub members: &'static [MemberInfo],
+}
+
+impl StructInfo {
+    /// Returns the member with the specified name.
+    pub fn member(&self, name: &str) -> Option<&'static MemberInfo> {
+        self.members.iter().find(|member| member.name == name)
+    }
+
+    /// Returns the structure type used to query this struct.
+    ///
+    /// The root structs [`vk::PhysicalDeviceFeatures`], [`vk::PhysicalDeviceProperties`],
+    /// [`vk::FormatProperties`] and [`vk::QueueFamilyProperties`] are queried through their `*2` wrapper
+    /// structs.
+    pub fn query_structure_type(&self) -> Option<vk::StructureType> {
+        self.structure_type.or_else(|| {
+            WRAPPERS
+                .iter()
+                .find(|wrapper| std::ptr::eq(wrapper.root, self))
+                .map(|wrapper| wrapper.structure_type)
+        })
+    }
+}
+
+/// How a property member is compared, from the `limittype` attribute of the vulkan registry.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LimitType {
+    /// Smaller values are better.
+    Min,
+    /// Larger values are better.
+    Max,
+    /// Not a limit, the member is never compared.
+    Not,
+    /// A number of bits, larger values are better.
+    Bits,
+    /// Bitmasks and booleans, a set bit is better than an unset one.
+    Bitmask,
+    /// A two element array where the first element is a minimum and the second one a maximum.
+    Range,
+    /// The values must be equal.
+    Exact,
+    /// Smaller values are better and a supported value must be a multiple of it.
+    MinMul,
+    /// A struct whose members have their own limit types.
+    Struct,
+    /// Identifying or informational members which can not be compared automatically.
+    NoAuto,
+}
+
+/// A reflected vulkan flags type.
+#[derive(Copy, Clone, Debug)]
+pub struct FlagsInfo {
+    /// The vulkan name of the flags type, for example `VkSampleCountFlags`.
+    pub name: &'static str,
+    /// The size of the flags type in bytes.
+    pub size: usize,
+    /// The names and values of all known bits. Extension aliases of promoted bits are listed as well.
+    pub bits: &'static [(&'static str, u64)],
+}
+
+impl FlagsInfo {
+    /// Returns the value of the bit with the specified name.
+    pub fn bit(&self, name: &str) -> Option<u64> {
+        self.bits
+            .iter()
+            .find(|(bit_name, _)| *bit_name == name)
+            .map(|(_, value)| *value)
+    }
+
+    /// Returns the names of all bits set in `value`, preferring core names over extension aliases.
+    ///
+    /// Bits without a known name are skipped.
+    pub fn names_of(&self, value: u64) -> Vec<&'static str> {
+        (0..64)
+            .map(|bit| 1u64 << bit)
+            .filter(|bit| value & bit != 0)
+            .filter_map(|bit| {
+                preferred_name(
+                    self.bits
+                        .iter()
+                        .filter(|(_, value)| *value == bit)
+                        .map(|(name, _)| *name),
+                )
+            })
+            .collect()
+    }
+}
+
+/// Returns the first name without vendor suffix or the first name if all names have one.
+fn preferred_name(mut names: impl Iterator<Item = &'static str> + Clone) -> Option<&'static str> {
+    const VENDORS: &[&str] = &[
+        "KHR", "EXT", "AMD", "AMDX", "ANDROID", "ARM", "FB", "GGP", "GOOGLE", "HUAWEI", "IMG",
+        "INTEL", "LUNARG", "MESA", "MSFT", "MVK", "NN", "NV", "NVX", "QCOM", "QNX", "SEC", "VALVE",
+    ];
+    let is_core = |name: &&str| {
+        name.rsplit('_')
+            .next()
+            .is_none_or(|suffix| !VENDORS.contains(&suffix))
+    };
+    names.clone().find(is_core).or_else(|| names.next())
+}
+
+/// A reflected vulkan enum.
+#[derive(Copy, Clone, Debug)]
+pub struct EnumInfo {
+    /// The vulkan name of the enum, for example `VkFormat`.
+    pub name: &'static str,
+    /// The names and values of all known variants. Extension aliases of promoted variants are listed as well.
+    pub values: &'static [(&'static str, i32)],
+}
+
+impl EnumInfo {
+    /// Returns the value of the variant with the specified name.
+    pub fn value(&self, name: &str) -> Option<i32> {
+        self.values
+            .iter()
+            .find(|(value_name, _)| *value_name == name)
+            .map(|(_, value)| *value)
+    }
+
+    /// Returns the name of the specified value, preferring core names over extension aliases.
+    pub fn name_of(&self, value: i32) -> Option<&'static str> {
+        preferred_name(
+            self.values
+                .iter()
+                .filter(|(_, variant)| *variant == value)
+                .map(|(name, _)| *name),
+        )
+    }
+}
+
+/// Whether an extension is enabled on the instance or on the device.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ExtensionType {
+    Instance,
+    Device,
+}
+
+/// Registry information about a vulkan extension.
+#[derive(Copy, Clone, Debug)]
+pub struct ExtensionInfo {
+    /// The name of the extension, for example `VK_KHR_swapchain`.
+    pub name: &'static str,
+    pub ty: ExtensionType,
+    /// The `depends` expression of the registry, for example
+    /// `VK_KHR_get_physical_device_properties2,VK_VERSION_1_1`. Empty if the extension has no dependencies.
+    pub depends: &'static str,
+    /// The core version the extension was promoted to, for example `VK_VERSION_1_2`.
+    pub promoted_to: Option<&'static str>,
+}
+
+impl ExtensionInfo {
+    /// Returns the parsed [`Self::depends`] expression or [`None`] if the extension has no dependencies.
+    pub fn dependencies(&self) -> Option<Dependency> {
+        if self.depends.is_empty() {
+            return None;
+        }
+        let dependency = Dependency::parse(self.depends);
+        debug_assert!(dependency.is_some(), "invalid depends of {}", self.name);
+        dependency
+    }
+
+    /// Returns true if the extension is part of the core api of `api_version`.
+    pub fn is_promoted(&self, api_version: u32) -> bool {
+        self.promoted_to
+            .is_some_and(|version| is_provided(&[version], api_version, &[]))
+    }
+}
+
+/// A dependency expression of an extension.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Dependency {
+    /// A core version like `VK_VERSION_1_1` or an extension.
+    Provider(&'static str),
+    /// All of the dependencies are required.
+    All(Vec<Dependency>),
+    /// One of the dependencies is required.
+    Any(Vec<Dependency>),
+}
+
+impl Dependency {
+    /// Returns true if the expression is satisfied by `api_version` and `extensions`. Extensions promoted to
+    /// `api_version` are always satisfied.
+    pub fn is_satisfied(&self, api_version: u32, extensions: &[&str]) -> bool {
+        match self {
+            Self::Provider(provider) => {
+                is_provided(&[provider], api_version, extensions)
+                    || find_extension(provider).is_some_and(|info| info.is_promoted(api_version))
+            }
+            Self::All(dependencies) => dependencies
+                .iter()
+                .all(|dependency| dependency.is_satisfied(api_version, extensions)),
+            Self::Any(dependencies) => dependencies
+                .iter()
+                .any(|dependency| dependency.is_satisfied(api_version, extensions)),
+        }
+    }
+
+    /// Parses a registry dependency expression like `VK_KHR_a+VK_KHR_b,VK_VERSION_1_1`. Returns [`None`] if
+    /// the expression is empty or not completely consumed.
+    pub fn parse(expression: &'static str) -> Option<Self> {
+        let mut tokens = expression;
+        let dependency = Self::parse_any(&mut tokens);
+        (!expression.is_empty() && tokens.is_empty()).then_some(dependency)
+    }
+
+    /// Parses alternatives separated by `,`.
+    fn parse_any(tokens: &mut &'static str) -> Self {
+        let mut alternatives = vec![Self::parse_all(tokens)];
+        while let Some(rest) = tokens.strip_prefix(',') {
+            *tokens = rest;
+            alternatives.push(Self::parse_all(tokens));
+        }
+        Self::flatten(alternatives, Self::Any)
+    }
+
+    /// Parses terms separated by `+`, which binds stronger than `,`.
+    fn parse_all(tokens: &mut &'static str) -> Self {
+        let mut terms = vec![Self::parse_term(tokens)];
+        while let Some(rest) = tokens.strip_prefix('+') {
+            *tokens = rest;
+            terms.push(Self::parse_term(tokens));
+        }
+        Self::flatten(terms, Self::All)
+    }
+
+    fn parse_term(tokens: &mut &'static str) -> Self {
+        if let Some(rest) = tokens.strip_prefix('(') {
+            *tokens = rest;
+            let dependency = Self::parse_any(tokens);
+            *tokens = tokens.strip_prefix(')').unwrap_or(tokens);
+            return dependency;
+        }
+        let end = tokens
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or(tokens.len());
+        let (name, rest) = tokens.split_at(end);
+        *tokens = rest;
+        Self::Provider(name)
+    }
+
+    fn flatten(mut dependencies: Vec<Self>, combine: fn(Vec<Self>) -> Self) -> Self {
+        if dependencies.len() == 1 {
+            dependencies.pop().unwrap()
+        } else {
+            combine(dependencies)
+        }
+    }
+}
+
+/// One way of allowing a SPIR-V capability or extension in a vulkan shader module.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SpirvEnable {
+    /// The core version, for example `VK_VERSION_1_1`.
+    Version(&'static str),
+    /// A device extension.
+    Extension(&'static str),
+    /// A feature which must be enabled.
+    Feature {
+        /// The vulkan name of the features struct, for example `VkPhysicalDeviceVulkan12Features`.
+        struct_name: &'static str,
+        feature: &'static str,
+        /// The dependency expression which makes the struct available.
+        requires: &'static str,
+    },
+    /// A property which must be supported.
+    Property {
+        /// The vulkan name of the properties struct, for example `VkPhysicalDeviceVulkan11Properties`.
+        struct_name: &'static str,
+        member: &'static str,
+        /// `VK_TRUE` for boolean members, otherwise the name of a bit which must be set.
+        value: &'static str,
+        /// The dependency expression which makes the struct available.
+        requires: &'static str,
+    },
+}
+
+impl SpirvEnable {
+    /// Returns the dependency expression which must be satisfied for this enable to be usable.
+    pub fn requires(&self) -> Option<Dependency> {
+        match *self {
+            Self::Version(provider) | Self::Extension(provider) => {
+                Some(Dependency::Provider(provider))
+            }
+            Self::Feature { requires, .. } | Self::Property { requires, .. } => {
+                Dependency::parse(requires)
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for SpirvEnable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Version(name) | Self::Extension(name) => f.write_str(name),
+            Self::Feature {
+                struct_name,
+                feature,
+                ..
+            } => write!(f, "{struct_name}.{feature}"),
+            Self::Property {
+                struct_name,
+                member,
+                value,
+                ..
+            } if *value == "VK_TRUE" => write!(f, "{struct_name}.{member}"),
+            Self::Property {
+                struct_name,
+                member,
+                value,
+                ..
+            } => write!(f, "{value} in {struct_name}.{member}"),
+        }
+    }
+}
+
+/// A SPIR-V capability which may be declared by vulkan shader modules.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SpirvCapabilityInfo {
+    /// The SPIR-V name of the capability, for example `Float64`.
+    pub name: &'static str,
+    /// The value of the capability operand of `OpCapability`.
+    pub id: u32,
+    /// The capability is allowed if any of these is satisfied.
+    pub enables: &'static [SpirvEnable],
+}
+
+/// A SPIR-V extension which may be declared by vulkan shader modules.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SpirvExtensionInfo {
+    /// The name of the extension, for example `SPV_KHR_multiview`.
+    pub name: &'static str,
+    /// The extension is allowed if any of these is satisfied.
+    pub enables: &'static [SpirvEnable],
+}
+
+/// A `*2` query struct which contains one of the root structs as member.
+struct Wrapper {
+    structure_type: vk::StructureType,
+    root: &'static StructInfo,
+    offset: usize,
+    size: usize,
+}
+
+static WRAPPERS: [Wrapper; 4] = [
+    Wrapper {
+        structure_type: vk::StructureType::PHYSICAL_DEVICE_FEATURES_2,
+        root: &tables::PHYSICAL_DEVICE_FEATURES,
+        offset: std::mem::offset_of!(vk::PhysicalDeviceFeatures2<'static>, features),
+        size: std::mem::size_of::<vk::PhysicalDeviceFeatures2<'static>>(),
+    },
+    Wrapper {
+        structure_type: vk::StructureType::PHYSICAL_DEVICE_PROPERTIES_2,
+        root: &tables::PHYSICAL_DEVICE_PROPERTIES,
+        offset: std::mem::offset_of!(vk::PhysicalDeviceProperties2<'static>, properties),
+        size: std::mem::size_of::<vk::PhysicalDeviceProperties2<'static>>(),
+    },
+    Wrapper {
+        structure_type: vk::StructureType::FORMAT_PROPERTIES_2,
+        root: &tables::FORMAT_PROPERTIES,
+        offset: std::mem::offset_of!(vk::FormatProperties2<'static>, format_properties),
+        size: std::mem::size_of::<vk::FormatProperties2<'static>>(),
+    },
+    Wrapper {
+        structure_type: vk::StructureType::QUEUE_FAMILY_PROPERTIES_2,
+        root: &tables::QUEUE_FAMILY_PROPERTIES,
+        offset: std::mem::offset_of!(vk::QueueFamilyProperties2<'static>, queue_family_properties),
+        size: std::mem::size_of::<vk::QueueFamilyProperties2<'static>>(),
+    },
+];
+
+/// Returns all reflected structs.
+pub fn structs() -> &'static [&'static StructInfo] {
+    tables::STRUCTS
+}
+
+/// Returns the struct with the specified vulkan name. Aliases (for example the extension names of promoted
+/// structs) are resolved.
+pub fn find_struct(name: &str) -> Option<&'static StructInfo> {
+    let name = tables::STRUCT_ALIASES
+        .iter()
+        .find(|(alias, _)| *alias == name)
+        .map_or(name, |(_, target)| *target);
+    tables::STRUCTS
+        .iter()
+        .copied()
+        .find(|info| info.name == name)
+}
+
+/// Returns the limit type of a struct member or [`None`] if none is known. Aliases of the struct name are
+/// resolved.
+///
+/// The limit types are provisional until the table is generated from the `limittype` attributes of vk.xml.
+pub fn find_limit_type(struct_name: &str, member: &str) -> Option<LimitType> {
+    let name = find_struct(struct_name)?.name;
+    let index = limits::LIMIT_TYPES
+        .binary_search_by(|(other, _)| other.cmp(&name))
+        .ok()?;
+    let members = limits::LIMIT_TYPES[index].1;
+    let index = members
+        .binary_search_by(|(other, _)| other.cmp(&member))
+        .ok()?;
+    Some(members[index].1)
+}
+
+/// Returns the extension struct with the specified structure type.
+pub fn find_struct_by_type(structure_type: vk::StructureType) -> Option<&'static StructInfo> {
+    tables::STRUCTS
+        .iter()
+        .copied()
+        .find(|info| info.structure_type == Some(structure_type))
+}
+
+/// Returns the latest spec version of the extension with the specified name or [`None`] if the extension is
+/// not known.
+pub fn extension_spec_version(name: &str) -> Option<u32> {
+    tables::EXTENSIONS
+        .iter()
+        .find(|(extension, _)| extension.to_bytes() == name.as_bytes())
+        .map(|(_, spec_version)| *spec_version)
+}
+
+/// Returns the names of all known extensions.
+pub fn extension_names() -> impl Iterator<Item = &'static str> {
+    tables::EXTENSIONS
+        .iter()
+        .filter_map(|(extension, _)| extension.to_str().ok())
+}
+
+/// Returns the registry information of the extension with the specified name or [`None`] if the extension is
+/// not known.
+pub fn find_extension(name: &str) -> Option<&'static ExtensionInfo> {
+    extensions::EXTENSION_INFOS
+        .iter()
+        .find(|info| info.name == name)
+}
+
+/// Returns all SPIR-V capabilities which may be used in vulkan.
+pub fn spirv_capabilities() -> &'static [SpirvCapabilityInfo] {
+    spirv::SPIRV_CAPABILITIES
+}
+
+/// Returns all SPIR-V extensions which may be used in vulkan.
+pub fn spirv_extensions() -> &'static [SpirvExtensionInfo] {
+    spirv::SPIRV_EXTENSIONS
+}
+
+/// Returns the SPIR-V capability with the specified id or [`None`] if the capability may not be used in
+/// vulkan.
+pub fn find_spirv_capability(id: u32) -> Option<&'static SpirvCapabilityInfo> {
+    spirv::SPIRV_CAPABILITIES.iter().find(|info| info.id == id)
+}
+
+/// Returns the SPIR-V extension with the specified name or [`None`] if the extension may not be used in
+/// vulkan.
+pub fn find_spirv_extension(name: &str) -> Option<&'static SpirvExtensionInfo> {
+    spirv::SPIRV_EXTENSIONS
+        .iter()
+        .find(|info| info.name == name)
+}
+
+/// Returns the flags type with the specified vulkan name.
+pub fn find_flags(name: &str) -> Option<&'static FlagsInfo> {
+    tables::FLAGS.iter().copied().find(|info| info.name == name)
+}
+
+/// Returns the enum with the specified vulkan name.
+pub fn find_enum(name: &str) -> Option<&'static EnumInfo> {
+    tables::ENUMS.iter().copied().find(|info| info.name == name)
+}
+
+/// Returns the format with the specified vulkan name, for example `VK_FORMAT_R8G8B8A8_UNORM`.
+pub fn format_from_name(name: &str) -> Option<vk::Format> {
+    tables::FORMAT.value(name).map(vk::Format::from_raw)
+}
+
+/// Returns the vulkan name of a format.
+pub fn format_name(format: vk::Format) -> Option<&'static str> {
+    tables::FORMAT.name_of(format.as_raw())
+}
+
+/// Returns all formats known to this crate.
+pub fn formats() -> impl Iterator<Item = vk::Format> {
+    let mut formats: Vec<_> = tables::FORMAT
+        .values
+        .iter()
+        .map(|(_, value)| vk::Format::from_raw(*value))
+        .filter(|format| *format != vk::Format::UNDEFINED)
+        .collect();
+    formats.sort();
+    formats.dedup();
+    formats.into_iter()
+}
+
+/// Returns the core versions (for example `VK_VERSION_1_2`) and extensions which provide a format. Empty
+/// for vulkan 1.0 formats.
+pub fn format_providers(format: vk::Format) -> &'static [&'static str] {
+    tables::FORMAT_PROVIDERS
+        .iter()
+        .find(|(provider_format, _)| *provider_format == format)
+        .map_or(&[], |(_, providers)| providers)
+}
+
+/// Returns true if any of the `providers` is either a core version up to `api_version` or one of the
+/// `extensions`. An empty list of providers is always available.
+pub fn is_provided(providers: &[&str], api_version: u32, extensions: &[&str]) -> bool {
+    providers.is_empty()
+        || providers.iter().any(|provider| {
+            match provider
+                .strip_prefix("VK_VERSION_")
+                .and_then(|version| version.split_once('_'))
+            {
+                Some((major, minor)) => major.parse().is_ok_and(|major| {
+                    minor
+                        .parse()
+                        .is_ok_and(|minor| vk::make_api_version(0, major, minor, 0) <= api_version)
+                }),
+                None => extensions.contains(provider),
+            }
+        })
+}
+
+/// Returns the struct described by a struct in a pNext chain and a pointer to its data.
+///
+/// For the `*2` query structs the contained root struct is returned.
+pub(crate) unsafe fn resolve_chain_struct(
+    base: *mut vk::BaseOutStructure,
+) -> Option<(&'static StructInfo, *mut u8)> {
+    let structure_type = (*base).s_type;
+    if let Some(wrapper) = WRAPPERS
+        .iter()
+        .find(|wrapper| wrapper.structure_type == structure_type)
+    {
+        return Some((wrapper.root, (base as *mut u8).add(wrapper.offset)));
+    }
+    find_struct_by_type(structure_type).map(|info| (info, base as *mut u8))
+}
+
+/// Writes the members described by a JSON object into a struct.
+///
+/// Flags are merged into the existing value, all other members are overwritten.
+///
+/// # Safety
+/// `data` must point to a valid instance of the struct described by `info`.
+pub(crate) unsafe fn write_struct(
+    info: &'static StructInfo,
+    object: &serde_json::Map<String, Value>,
+    data: *mut u8,
+) -> Result<(), JsonError> {
+    for (name, value) in object {
+        let member = info.member(name).ok_or_else(|| JsonError::UnknownMember {
+            structure: info.name.to_string(),
+            member: name.clone(),
+        })?;
+        write_member(&member.ty, value, data.add(member.offset)).map_err(|err| match err {
+            WriteError::Expected(expected) => JsonError::InvalidValue {
+                structure: info.name.to_string(),
+                member: name.clone(),
+                expected,
+            },
+            WriteError::Nested(err) => err,
+        })?;
+    }
+    Ok(())
+}
+
+/// Writes the members described by a JSON object into a struct like [`write_struct`], but skips unknown
+/// members and invalid values instead of failing. Arrays may be shorter than the member.
+///
+/// This is meant for device reports, which may contain members unknown to this crate.
+///
+/// # Safety
+/// `data` must point to a valid instance of the struct described by `info`.
+pub(crate) unsafe fn write_struct_lenient(
+    info: &'static StructInfo,
+    object: &serde_json::Map<String, Value>,
+    data: *mut u8,
+) {
+    for (name, value) in object {
+        let Some(member) = info.member(name) else {
+            continue;
+        };
+        let data = data.add(member.offset);
+        match (&member.ty, value) {
+            (MemberType::Struct(nested), Value::Object(object)) => {
+                write_struct_lenient(nested, object, data)
+            }
+            (MemberType::Array(element, len), Value::Array(values)) => {
+                for (index, value) in values.iter().take(*len).enumerate() {
+                    let _ = write_member(element, value, data.add(index * element.size()));
+                }
+            }
+            (ty, value) => {
+                let _ = write_member(ty, value, data);
+            }
+        }
+    }
+}
+
+enum WriteError {
+    /// A description of the expected value.
+    Expected(String),
+    /// An error inside a nested struct.
+    Nested(JsonError),
+}
+
+impl From<&str> for WriteError {
+    fn from(expected: &str) -> Self {
+        Self::Expected(expected.to_string())
+    }
+}
+
+impl From<String> for WriteError {
+    fn from(expected: String) -> Self {
+        Self::Expected(expected)
+    }
+}
+
+/// Writes a single JSON value.
+unsafe fn write_member(ty: &MemberType, value: &Value, data: *mut u8) -> Result<(), WriteError> {
+    fn number<T: TryFrom<i64> + TryFrom<u64>>(value: &Value) -> Option<T> {
+        match value {
+            Value::Number(number) => number
+                .as_u64()
+                .and_then(|value| T::try_from(value).ok())
+                .or_else(|| number.as_i64().and_then(|value| T::try_from(value).ok())),
+            _ => None,
+        }
+    }
+
+    match ty {
+        MemberType::Bool32 => {
+            let value = value.as_bool().ok_or("a boolean")?;
+            (data as *mut vk::Bool32).write_unaligned(value.into());
+        }
+        MemberType::Uint8 => {
+            data.write_unaligned(number(value).ok_or("an 8-bit unsigned integer")?)
+        }
+        MemberType::Uint32 => {
+            (data as *mut u32).write_unaligned(number(value).ok_or("a 32-bit unsigned integer")?)
+        }
+        MemberType::Int32 => {
+            (data as *mut i32).write_unaligned(number(value).ok_or("a 32-bit signed integer")?)
+        }
+        MemberType::Uint64 => {
+            (data as *mut u64).write_unaligned(number(value).ok_or("a 64-bit unsigned integer")?)
+        }
+        MemberType::Int64 => {
+            (data as *mut i64).write_unaligned(number(value).ok_or("a 64-bit signed integer")?)
+        }
+        MemberType::Size => {
+            (data as *mut usize).write_unaligned(number(value).ok_or("an unsigned size")?)
+        }
+        MemberType::Float32 => {
+            let value = value.as_f64().ok_or("a number")?;
+            (data as *mut f32).write_unaligned(value as f32);
+        }
+        MemberType::String(capacity) => {
+            let value = value
+                .as_str()
+                .filter(|value| value.len() < *capacity && !value.contains('\0'))
+                .ok_or_else(|| format!("a string shorter than {capacity} bytes"))?;
+            std::ptr::write_bytes(data, 0, *capacity);
+            std::ptr::copy_nonoverlapping(value.as_ptr(), data, value.len());
+        }
+        MemberType::Array(element, len) => {
+            let values = value
+                .as_array()
+                .filter(|values| values.len() == *len)
+                .ok_or_else(|| format!("an array of {len} elements"))?;
+            let stride = element.size();
+            for (index, value) in values.iter().enumerate() {
+                write_member(element, value, data.add(index * stride))?;
+            }
+        }
+        MemberType::Flags(flags) => {
+            let expected = || format!("an array of {} bit names", flags.name);
+            let mut bits = 0;
+            for name in value.as_array().ok_or_else(expected)? {
+                bits |= name
+                    .as_str()
+                    .and_then(|name| flags.bit(name))
+                    .ok_or_else(expected)?;
+            }
+            if ty.size() == 8 {
+                let data = data as *mut u64;
+                data.write_unaligned(data.read_unaligned() | bits);
+            } else {
+                let data = data as *mut u32;
+                data.write_unaligned(data.read_unaligned() | bits as u32);
+            }
+        }
+        MemberType::Enum(info) => {
+            let value = value
+                .as_str()
+                .and_then(|name| info.value(name))
+                .ok_or_else(|| format!("a {} name", info.name))?;
+            (data as *mut i32).write_unaligned(value);
+        }
+        MemberType::Struct(info) => {
+            let object = value
+                .as_object()
+                .ok_or_else(|| format!("a {} object", info.name))?;
+            write_struct(info, object, data).map_err(WriteError::Nested)?;
+        }
+    }
+    Ok(())
+}
+
+/// Reads all members of a struct which are not zero into a JSON object.
+///
+/// Writing the returned object into a zero initialized struct with [`write_struct`] restores the original
+/// values, except for flag bits unknown to this crate.
+///
+/// # Safety
+/// `data` must point to a valid instance of the struct described by `info`.
+pub(crate) unsafe fn read_struct(
+    info: &'static StructInfo,
+    data: *const u8,
+) -> serde_json::Map<String, Value> {
+    info.members
+        .iter()
+        .filter(|member| !is_zero(data.add(member.offset), member.ty.size()))
+        .filter_map(|member| {
+            read_member(&member.ty, data.add(member.offset))
+                .map(|value| (member.name.to_string(), value))
+        })
+        .collect()
+}
+
+/// Returns true if all `size` bytes at `data` are zero.
+pub(crate) unsafe fn is_zero(data: *const u8, size: usize) -> bool {
+    std::slice::from_raw_parts(data, size)
+        .iter()
+        .all(|byte| *byte == 0)
+}
+
+/// Reads a single member. Returns [`None`] for values which cannot be represented in JSON.
+pub(crate) unsafe fn read_member(ty: &MemberType, data: *const u8) -> Option<Value> {
+    Some(match ty {
+        MemberType::Bool32 => Value::Bool((data as *const vk::Bool32).read_unaligned() != 0),
+        MemberType::Uint8 => data.read().into(),
+        MemberType::Uint32 => (data as *const u32).read_unaligned().into(),
+        MemberType::Int32 => (data as *const i32).read_unaligned().into(),
+        MemberType::Uint64 => (data as *const u64).read_unaligned().into(),
+        MemberType::Int64 => (data as *const i64).read_unaligned().into(),
+        MemberType::Size => (data as *const usize).read_unaligned().into(),
+        MemberType::Float32 => {
+            serde_json::Number::from_f64((data as *const f32).read_unaligned() as f64)?.into()
+        }
+        MemberType::String(capacity) => {
+            let bytes = std::slice::from_raw_parts(data, *capacity);
+            let len = bytes
+                .iter()
+                .position(|byte| *byte == 0)
+                .unwrap_or(*capacity);
+            String::from_utf8_lossy(&bytes[..len]).into_owned().into()
+        }
+        MemberType::Array(element, len) => {
+            let stride = element.size();
+            Value::Array(
+                (0..*len)
+                    .map(|index| read_member(element, data.add(index * stride)))
+                    .collect::<Option<_>>()?,
+            )
+        }
+        MemberType::Flags(flags) => {
+            let bits = if ty.size() == 8 {
+                (data as *const u64).read_unaligned()
+            } else {
+                (data as *const u32).read_unaligned() as u64
+            };
+            flags.names_of(bits).into_iter().map(Value::from).collect()
+        }
+        MemberType::Enum(info) => info.name_of((data as *const i32).read_unaligned())?.into(),
+        MemberType::Struct(info) => Value::Object(read_struct(info, data)),
+    })
+}
+
+/// Enables every boolean member of the struct described by `info` in `target` which is enabled in `data`.
+pub(crate) unsafe fn or_bools(info: &'static StructInfo, data: *const u8, target: *mut u8) {
+    for member in info.members {
+        let (data, target) = (data.add(member.offset), target.add(member.offset));
+        match member.ty {
+            MemberType::Bool32 if (data as *const vk::Bool32).read_unaligned() != vk::FALSE => {
+                (target as *mut vk::Bool32).write_unaligned(vk::TRUE)
+            }
+            MemberType::Struct(nested) => or_bools(nested, data, target),
+            _ => {}
+        }
+    }
+}
+
+/// Allocates zero initialized storage of at least `size` bytes with 8 byte alignment, which is sufficient
+/// for all reflected structs.
+pub(crate) fn zeroed_storage(size: usize) -> Box<[u64]> {
+    vec![0u64; size.div_ceil(8)].into_boxed_slice()
+}
+
+/// Zero initialized storage for a pNext chain of reflected structs.
+///
+/// The chain always starts with a `*2` query struct, for example [`vk::PhysicalDeviceFeatures2`], followed
+/// by all other requested structs. Structure types unknown to this crate are skipped.
+#[derive(Clone)]
+pub(crate) struct StructChain {
+    structs: Vec<ChainStruct>,
+}
+
+#[derive(Clone)]
+struct ChainStruct {
+    info: &'static StructInfo,
+    /// The offset of the reflected struct inside the storage.
+    offset: usize,
+    storage: Box<[u64]>,
+}
+
+impl StructChain {
+    /// Allocates a chain starting with the `head` query struct.
+    ///
+    /// # Panics
+    /// Panics if `head` is not the structure type of a `*2` query struct.
+    pub(crate) fn new(head: vk::StructureType, structure_types: &[vk::StructureType]) -> Self {
+        let wrapper_index = |structure_type: vk::StructureType| {
+            WRAPPERS
+                .iter()
+                .position(|wrapper| wrapper.structure_type == structure_type)
+        };
+        let allocate = |structure_type: vk::StructureType| {
+            let (info, offset, size) = match wrapper_index(structure_type) {
+                Some(index) => {
+                    let wrapper = &WRAPPERS[index];
+                    (wrapper.root, wrapper.offset, wrapper.size)
+                }
+                None => {
+                    let info = find_struct_by_type(structure_type)?;
+                    (info, 0, info.size)
+                }
+            };
+            let mut storage = zeroed_storage(size);
+            // SAFETY: all chainable structs start with the s_type member
+            unsafe { (storage.as_mut_ptr() as *mut vk::StructureType).write(structure_type) };
+            Some(ChainStruct {
+                info,
+                offset,
+                storage,
+            })
+        };
+
+        assert!(
+            wrapper_index(head).is_some(),
+            "{head:?} is not a query struct"
+        );
+        let mut structs: Vec<ChainStruct> = allocate(head).into_iter().collect();
+        for structure_type in structure_types {
+            // The *2 structs of other queries can not be part of this chain
+            if *structure_type != head && wrapper_index(*structure_type).is_none() {
+                if let Some(chain_struct) = allocate(*structure_type) {
+                    if !structs
+                        .iter()
+                        .any(|existing| std::ptr::eq(existing.info, chain_struct.info))
+                    {
+                        structs.push(chain_struct);
+                    }
+                }
+            }
+        }
+        Self { structs }
+    }
+
+    /// Links all structs and returns the head of the chain.
+    ///
+    /// The returned pointer stays valid until the chain is dropped.
+    pub(crate) fn link(&mut self) -> *mut vk::BaseOutStructure<'static> {
+        let mut next = std::ptr::null_mut();
+        for chain_struct in self.structs.iter_mut().rev() {
+            let base = chain_struct.storage.as_mut_ptr() as *mut vk::BaseOutStructure;
+            // SAFETY: all chainable structs start with the s_type and p_next members
+            unsafe { (*base).p_next = next };
+            next = base;
+        }
+        next
+    }
+
+    /// Enables all booleans of `data` which is an instance of the struct described by `info` in the matching
+    /// struct of the chain. Returns false if the chain does not contain that struct.
+    pub(crate) unsafe fn merge_bools(
+        &mut self,
+        info: &'static StructInfo,
+        data: *const u8,
+    ) -> bool {
+        let Some(chain_struct) = self
+            .structs
+            .iter_mut()
+            .find(|chain_struct| std::ptr::eq(chain_struct.info, info))
+        else {
+            return false;
+        };
+        let target = (chain_struct.storage.as_mut_ptr() as *mut u8).add(chain_struct.offset);
+        or_bools(info, data, target);
+        true
+    }
+
+    /// Enables all booleans of `data` which is an instance of the struct described by `info` in the members
+    /// with the same names of the struct `target` of the chain. Returns false if the chain does not contain
+    /// `target`.
+    pub(crate) unsafe fn merge_bools_by_name(
+        &mut self,
+        target: &'static StructInfo,
+        info: &'static StructInfo,
+        data: *const u8,
+    ) -> bool {
+        let Some(chain_struct) = self
+            .structs
+            .iter_mut()
+            .find(|chain_struct| std::ptr::eq(chain_struct.info, target))
+        else {
+            return false;
+        };
+        let base = (chain_struct.storage.as_mut_ptr() as *mut u8).add(chain_struct.offset);
+        for member in info.members {
+            if !matches!(member.ty, MemberType::Bool32)
+                || (data.add(member.offset) as *const vk::Bool32).read_unaligned() == vk::FALSE
+            {
+                continue;
+            }
+            if let Some(target_member) = target.member(member.name) {
+                if matches!(target_member.ty, MemberType::Bool32) {
+                    (base.add(target_member.offset) as *mut vk::Bool32).write_unaligned(vk::TRUE);
+                }
+            }
+        }
+        true
+    }
+
+    /// Enables all booleans of `other` in the matching structs of this chain.
+    ///
+    /// Structs which are not part of this chain are merged by member name into the struct returned by
+    /// `fallback` for them, if any.
+    pub(crate) fn merge_chain(
+        &mut self,
+        other: &StructChain,
+        fallback: impl Fn(&'static StructInfo) -> Option<&'static StructInfo>,
+    ) {
+        for chain_struct in &other.structs {
+            let data = chain_struct.storage.as_ptr() as *const u8;
+            // SAFETY: the storage contains an instance of the struct at the offset
+            unsafe {
+                let data = data.add(chain_struct.offset);
+                if !self.merge_bools(chain_struct.info, data) {
+                    if let Some(target) = fallback(chain_struct.info) {
+                        self.merge_bools_by_name(target, chain_struct.info, data);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns a pointer to the struct described by `info` if it is part of the chain.
+    ///
+    /// The pointer stays valid until the chain is dropped.
+    pub(crate) fn get(&self, info: &'static StructInfo) -> Option<*const u8> {
+        self.structs
+            .iter()
+            .find(|chain_struct| std::ptr::eq(chain_struct.info, info))
+            // SAFETY: the offset is inside the storage
+            .map(|chain_struct| unsafe {
+                (chain_struct.storage.as_ptr() as *const u8).add(chain_struct.offset)
+            })
+    }
+
+    /// Disables the boolean member with the specified name in all structs of the chain.
+    pub(crate) fn clear_bool(&mut self, member_name: &str) {
+        for chain_struct in &mut self.structs {
+            if let Some(member) = chain_struct.info.member(member_name) {
+                if matches!(member.ty, MemberType::Bool32) {
+                    // SAFETY: the member is part of the struct at the offset
+                    unsafe {
+                        let data = (chain_struct.storage.as_mut_ptr() as *mut u8)
+                            .add(chain_struct.offset + member.offset);
+                        (data as *mut vk::Bool32).write_unaligned(vk::FALSE);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Reads all structs of the chain which contain non zero values.
+    pub(crate) fn read(
+        &self,
+    ) -> impl Iterator<Item = (&'static StructInfo, serde_json::Map<String, Value>)> + '_ {
+        self.structs.iter().filter_map(|chain_struct| {
+            // SAFETY: the storage contains an instance of the struct at the offset
+            let value = unsafe {
+                read_struct(
+                    chain_struct.info,
+                    (chain_struct.storage.as_ptr() as *const u8).add(chain_struct.offset),
+                )
+            };
+            (!value.is_empty()).then_some((chain_struct.info, value))
+        })
+    }
+}
+
+impl MemberType {
+    /// Returns the size of the member in bytes.
+    pub fn size(&self) -> usize {
+        match self {
+            Self::Bool32 | Self::Uint32 | Self::Int32 | Self::Float32 | Self::Enum(_) => 4,
+            Self::Uint8 => 1,
+            Self::Uint64 | Self::Int64 => 8,
+            Self::Size => std::mem::size_of::<usize>(),
+            Self::String(capacity) => *capacity,
+            Self::Array(element, len) => element.size() * len,
+            Self::Flags(info) => info.size,
+            Self::Struct(info) => info.size,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extension_dependencies() {
+        for info in extensions::EXTENSION_INFOS {
+            assert!(
+                extension_spec_version(info.name).is_some(),
+                "{} is not a known extension",
+                info.name
+            );
+            // Parsing asserts that the whole expression is consumed
+            if let Some(dependency) = info.dependencies() {
+                let mut providers = vec![&dependency];
+                while let Some(dependency) = providers.pop() {
+                    match dependency {
+                        Dependency::Provider(provider) => assert!(
+                            provider.starts_with("VK_VERSION_")
+                                || find_extension(provider).is_some(),
+                            "{} depends on unknown {provider}",
+                            info.name
+                        ),
+                        Dependency::All(dependencies) | Dependency::Any(dependencies) => {
+                            providers.extend(dependencies)
+                        }
+                    }
+                }
+            }
+        }
+
+        let dependency = find_extension("VK_KHR_dynamic_rendering")
+            .unwrap()
+            .dependencies()
+            .unwrap();
+        assert_eq!(
+            dependency,
+            Dependency::Any(vec![
+                Dependency::All(vec![
+                    Dependency::Provider("VK_KHR_depth_stencil_resolve"),
+                    Dependency::Provider("VK_KHR_get_physical_device_properties2"),
+                ]),
+                Dependency::Provider("VK_VERSION_1_2"),
+            ])
+        );
+        assert!(dependency.is_satisfied(vk::API_VERSION_1_2, &[]));
+        assert!(!dependency.is_satisfied(vk::API_VERSION_1_0, &["VK_KHR_depth_stencil_resolve"]));
+    }
+
+    #[test]
+    fn test_spirv_enables() {
+        let enables = spirv::SPIRV_CAPABILITIES
+            .iter()
+            .map(|info| (info.name, info.enables))
+            .chain(
+                spirv::SPIRV_EXTENSIONS
+                    .iter()
+                    .map(|info| (info.name, info.enables)),
+            );
+        for (name, enables) in enables {
+            assert!(!enables.is_empty(), "{name} can not be enabled");
+            for enable in enables {
+                let mut providers = vec![enable
+                    .requires()
+                    .unwrap_or_else(|| panic!("{name} has an invalid requires"))];
+                while let Some(dependency) = providers.pop() {
+                    match dependency {
+                        Dependency::Provider(provider) => assert!(
+                            provider.starts_with("VK_VERSION_")
+                                || find_extension(provider).is_some(),
+                            "{name} requires unknown {provider}"
+                        ),
+                        Dependency::All(dependencies) | Dependency::Any(dependencies) => {
+                            providers.extend(dependencies)
+                        }
+                    }
+                }
+                let (struct_name, member, value) = match *enable {
+                    SpirvEnable::Feature {
+                        struct_name,
+                        feature,
+                        ..
+                    } => (struct_name, feature, "VK_TRUE"),
+                    SpirvEnable::Property {
+                        struct_name,
+                        member,
+                        value,
+                        ..
+                    } => (struct_name, member, value),
+                    _ => continue,
+                };
+                let member = find_struct(struct_name)
+                    .unwrap_or_else(|| panic!("{name} uses unknown {struct_name}"))
+                    .member(member)
+                    .unwrap_or_else(|| panic!("{name} uses unknown {struct_name}.{member}"));
+                match member.ty {
+                    MemberType::Bool32 => assert_eq!(value, "VK_TRUE", "{name}"),
+                    MemberType::Flags(flags) => {
+                        assert!(flags.bit(value).is_some(), "{name} uses unknown {value}")
+                    }
+                    _ => panic!(
+                        "{name} uses {struct_name}.{} of unsupported type",
+                        member.name
+                    ),
+                }
+            }
+        }
+
+        let mut ids: Vec<_> = spirv::SPIRV_CAPABILITIES
+            .iter()
+            .map(|info| info.id)
+            .collect();
+        ids.sort_unstable();
+        ids.dedup();
+        assert_eq!(ids.len(), spirv::SPIRV_CAPABILITIES.len());
+
+        assert_eq!(find_spirv_capability(10).unwrap().name, "Float64");
+        assert_eq!(
+            find_spirv_capability(10).unwrap().enables[0].to_string(),
+            "VkPhysicalDeviceFeatures.shaderFloat64"
+        );
+        assert!(find_spirv_capability(5).is_none());
+        assert!(find_spirv_extension("SPV_KHR_multiview").is_some());
+    }
+
+    #[test]
+    fn test_limit_types() {
+        assert!(limits::LIMIT_TYPES
+            .windows(2)
+            .all(|pair| pair[0].0 < pair[1].0));
+        for (struct_name, members) in limits::LIMIT_TYPES {
+            let info = find_struct(struct_name).unwrap();
+            assert_eq!(info.name, *struct_name);
+            assert!(members.windows(2).all(|pair| pair[0].0 < pair[1].0));
+            for (member, _) in *members {
+                assert!(info.member(member).is_some(), "{struct_name}.{member}");
+            }
+        }
+
+        assert_eq!(
+            find_limit_type("VkPhysicalDeviceLimits", "maxImageDimension2D"),
+            Some(LimitType::Max)
+        );
+        assert_eq!(
+            find_limit_type("VkQueueFamilyProperties", "minImageTransferGranularity"),
+            Some(LimitType::MinMul)
+        );
+        assert_eq!(
+            find_limit_type("VkPhysicalDeviceDriverPropertiesKHR", "driverID"),
+            Some(LimitType::NoAuto)
+        );
+        assert_eq!(find_limit_type("VkPhysicalDeviceLimits", "unknown"), None);
+        assert_eq!(
+            find_limit_type("VkPhysicalDeviceFeatures", "robustBufferAccess"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_struct_layouts() {
+        for info in structs() {
+            for member in info.members {
+                assert!(
+                    member.offset + member.ty.size() <= info.size,
+                    "{}::{} exceeds the struct size",
+                    info.name,
+                    member.name
+                );
+            }
+            if let Some(structure_type) = info.structure_type {
+                assert!(std::ptr::eq(
+                    find_struct_by_type(structure_type).unwrap(),
+                    *info
+                ));
+            }
+        }
+
+        let limits = find_struct("VkPhysicalDeviceLimits").unwrap();
+        assert_eq!(
+            limits.member("maxImageDimension2D").unwrap().offset,
+            std::mem::offset_of!(vk::PhysicalDeviceLimits, max_image_dimension2_d)
+        );
+        assert_eq!(
+            find_struct("VkPhysicalDeviceFloat16Int8FeaturesKHR")
+                .unwrap()
+                .name,
+            "VkPhysicalDeviceShaderFloat16Int8Features"
+        );
+        assert_eq!(
+            format_from_name("VK_FORMAT_ASTC_4x4_UNORM_BLOCK"),
+            Some(vk::Format::ASTC_4X4_UNORM_BLOCK)
+        );
+        assert_eq!(
+            find_flags("VkSampleCountFlags")
+                .unwrap()
+                .bit("VK_SAMPLE_COUNT_4_BIT"),
+            Some(vk::SampleCountFlags::TYPE_4.as_raw() as u64)
+        );
+    }
+}
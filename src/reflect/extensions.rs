@@ -0,0 +1,600 @@
+//! Extension types, dependencies and core promotions.
+//!
+//! Dependencies use the syntax of the `depends` attribute of the vulkan registry: `+` is a logical and, `,` a
+//! logical or and parentheses group sub expressions. Extensions which are neither promoted nor depend on
+//! anything are listed as well so that their type is known.
+//!
+//! This table is maintained by hand in the layout written by
+//! `cargo run --manifest-path xtask/Cargo.toml -- <path to vk.xml> <path to grammar>`. It has not been
+//! generated from vk.xml yet, so it should be replaced by the output of that command.
+
+use super::{ExtensionInfo, ExtensionType};
+
+const fn instance(name: &'static str, depends: &'static str) -> ExtensionInfo {
+    ExtensionInfo {
+        name,
+        ty: ExtensionType::Instance,
+        depends,
+        promoted_to: None,
+    }
+}
+
+const fn device(name: &'static str, depends: &'static str) -> ExtensionInfo {
+    ExtensionInfo {
+        name,
+        ty: ExtensionType::Device,
+        depends,
+        promoted_to: None,
+    }
+}
+
+impl ExtensionInfo {
+    const fn promoted_to(mut self, version: &'static str) -> Self {
+        self.promoted_to = Some(version);
+        self
+    }
+}
+
+/// The most common dependency of extensions introduced after vulkan 1.0.
+const PROPERTIES2: &str = "VK_KHR_get_physical_device_properties2,VK_VERSION_1_1";
+
+const V1_1: &str = "VK_VERSION_1_1";
+const V1_2: &str = "VK_VERSION_1_2";
+const V1_3: &str = "VK_VERSION_1_3";
+
+pub(super) static EXTENSION_INFOS: &[ExtensionInfo] = &[
+    instance("VK_KHR_surface", ""),
+    device("VK_KHR_swapchain", "VK_KHR_surface"),
+    instance("VK_KHR_display", "VK_KHR_surface"),
+    device("VK_KHR_display_swapchain", "VK_KHR_swapchain+VK_KHR_display"),
+    instance("VK_KHR_xlib_surface", "VK_KHR_surface"),
+    instance("VK_KHR_xcb_surface", "VK_KHR_surface"),
+    instance("VK_KHR_wayland_surface", "VK_KHR_surface"),
+    instance("VK_KHR_android_surface", "VK_KHR_surface"),
+    instance("VK_KHR_win32_surface", "VK_KHR_surface"),
+    device("VK_ANDROID_native_buffer", ""),
+    instance("VK_EXT_debug_report", ""),
+    device("VK_NV_glsl_shader", ""),
+    device("VK_EXT_depth_range_unrestricted", ""),
+    device("VK_KHR_sampler_mirror_clamp_to_edge", "").promoted_to(V1_2),
+    device("VK_IMG_filter_cubic", ""),
+    device("VK_AMD_rasterization_order", ""),
+    device("VK_AMD_shader_trinary_minmax", ""),
+    device("VK_AMD_shader_explicit_vertex_parameter", ""),
+    device("VK_EXT_debug_marker", "VK_EXT_debug_report"),
+    device("VK_KHR_video_queue", "VK_VERSION_1_1+VK_KHR_synchronization2"),
+    device("VK_KHR_video_decode_queue", "VK_KHR_video_queue+VK_KHR_synchronization2"),
+    device("VK_AMD_gcn_shader", ""),
+    device("VK_NV_dedicated_allocation", ""),
+    device("VK_EXT_transform_feedback", PROPERTIES2),
+    device("VK_NVX_binary_import", ""),
+    device("VK_NVX_image_view_handle", ""),
+    device("VK_AMD_draw_indirect_count", ""),
+    device("VK_AMD_negative_viewport_height", ""),
+    device("VK_AMD_gpu_shader_half_float", ""),
+    device("VK_AMD_shader_ballot", ""),
+    device("VK_KHR_video_encode_h264", "VK_KHR_video_encode_queue"),
+    device("VK_KHR_video_encode_h265", "VK_KHR_video_encode_queue"),
+    device("VK_KHR_video_decode_h264", "VK_KHR_video_decode_queue"),
+    device("VK_AMD_texture_gather_bias_lod", PROPERTIES2),
+    device("VK_AMD_shader_info", ""),
+    device(
+        "VK_KHR_dynamic_rendering",
+        "(VK_KHR_depth_stencil_resolve+VK_KHR_get_physical_device_properties2),VK_VERSION_1_2",
+    )
+    .promoted_to(V1_3),
+    device("VK_AMD_shader_image_load_store_lod", ""),
+    instance("VK_GGP_stream_descriptor_surface", "VK_KHR_surface"),
+    device("VK_NV_corner_sampled_image", PROPERTIES2),
+    device("VK_KHR_multiview", PROPERTIES2).promoted_to(V1_1),
+    device("VK_IMG_format_pvrtc", ""),
+    instance("VK_NV_external_memory_capabilities", ""),
+    device("VK_NV_external_memory", "VK_NV_external_memory_capabilities"),
+    device("VK_NV_external_memory_win32", "VK_NV_external_memory"),
+    device("VK_NV_win32_keyed_mutex", "VK_NV_external_memory_win32"),
+    instance("VK_KHR_get_physical_device_properties2", "").promoted_to(V1_1),
+    device("VK_KHR_device_group", "VK_KHR_device_group_creation").promoted_to(V1_1),
+    instance("VK_EXT_validation_flags", ""),
+    instance("VK_NN_vi_surface", "VK_KHR_surface"),
+    device("VK_KHR_shader_draw_parameters", "").promoted_to(V1_1),
+    device("VK_EXT_shader_subgroup_ballot", ""),
+    device("VK_EXT_shader_subgroup_vote", ""),
+    device("VK_EXT_texture_compression_astc_hdr", PROPERTIES2).promoted_to(V1_3),
+    device("VK_EXT_astc_decode_mode", PROPERTIES2),
+    device("VK_EXT_pipeline_robustness", PROPERTIES2),
+    device("VK_KHR_maintenance1", "").promoted_to(V1_1),
+    instance("VK_KHR_device_group_creation", "").promoted_to(V1_1),
+    instance("VK_KHR_external_memory_capabilities", PROPERTIES2).promoted_to(V1_1),
+    device("VK_KHR_external_memory", "VK_KHR_external_memory_capabilities").promoted_to(V1_1),
+    device("VK_KHR_external_memory_win32", "VK_KHR_external_memory,VK_VERSION_1_1"),
+    device("VK_KHR_external_memory_fd", "VK_KHR_external_memory,VK_VERSION_1_1"),
+    device("VK_KHR_win32_keyed_mutex", "VK_KHR_external_memory_win32"),
+    instance("VK_KHR_external_semaphore_capabilities", PROPERTIES2).promoted_to(V1_1),
+    device("VK_KHR_external_semaphore", "VK_KHR_external_semaphore_capabilities").promoted_to(V1_1),
+    device("VK_KHR_external_semaphore_win32", "VK_KHR_external_semaphore,VK_VERSION_1_1"),
+    device("VK_KHR_external_semaphore_fd", "VK_KHR_external_semaphore,VK_VERSION_1_1"),
+    device("VK_KHR_push_descriptor", PROPERTIES2),
+    device("VK_EXT_conditional_rendering", PROPERTIES2),
+    device("VK_KHR_shader_float16_int8", PROPERTIES2).promoted_to(V1_2),
+    device(
+        "VK_KHR_16bit_storage",
+        "(VK_KHR_get_physical_device_properties2+VK_KHR_storage_buffer_storage_class),VK_VERSION_1_1",
+    )
+    .promoted_to(V1_1),
+    device("VK_KHR_incremental_present", "VK_KHR_swapchain"),
+    device("VK_KHR_descriptor_update_template", "").promoted_to(V1_1),
+    device("VK_NV_clip_space_w_scaling", ""),
+    instance("VK_EXT_direct_mode_display", "VK_KHR_display"),
+    instance("VK_EXT_acquire_xlib_display", "VK_EXT_direct_mode_display"),
+    instance("VK_EXT_display_surface_counter", "VK_KHR_display"),
+    device("VK_EXT_display_control", "VK_EXT_display_surface_counter+VK_KHR_swapchain"),
+    device("VK_GOOGLE_display_timing", "VK_KHR_swapchain"),
+    device("VK_NV_sample_mask_override_coverage", ""),
+    device("VK_NV_geometry_shader_passthrough", ""),
+    device("VK_NV_viewport_array2", ""),
+    device("VK_NVX_multiview_per_view_attributes", "VK_KHR_multiview,VK_VERSION_1_1"),
+    device("VK_NV_viewport_swizzle", ""),
+    device("VK_EXT_discard_rectangles", PROPERTIES2),
+    device("VK_EXT_conservative_rasterization", PROPERTIES2),
+    device("VK_EXT_depth_clip_enable", PROPERTIES2),
+    instance("VK_EXT_swapchain_colorspace", "VK_KHR_surface"),
+    device("VK_EXT_hdr_metadata", "VK_KHR_swapchain"),
+    device(
+        "VK_KHR_imageless_framebuffer",
+        "((VK_KHR_get_physical_device_properties2+VK_KHR_maintenance2),VK_VERSION_1_1)+VK_KHR_image_format_list",
+    )
+    .promoted_to(V1_2),
+    device(
+        "VK_KHR_create_renderpass2",
+        "(VK_KHR_multiview+VK_KHR_maintenance2),VK_VERSION_1_1",
+    )
+    .promoted_to(V1_2),
+    device("VK_IMG_relaxed_line_rasterization", PROPERTIES2),
+    device(
+        "VK_KHR_shared_presentable_image",
+        "VK_KHR_swapchain+VK_KHR_get_surface_capabilities2+(VK_KHR_get_physical_device_properties2,VK_VERSION_1_1)",
+    ),
+    instance("VK_KHR_external_fence_capabilities", PROPERTIES2).promoted_to(V1_1),
+    device("VK_KHR_external_fence", "VK_KHR_external_fence_capabilities").promoted_to(V1_1),
+    device("VK_KHR_external_fence_win32", "VK_KHR_external_fence,VK_VERSION_1_1"),
+    device("VK_KHR_external_fence_fd", "VK_KHR_external_fence,VK_VERSION_1_1"),
+    device("VK_KHR_performance_query", PROPERTIES2),
+    device("VK_KHR_maintenance2", "").promoted_to(V1_1),
+    instance("VK_KHR_get_surface_capabilities2", "VK_KHR_surface"),
+    device(
+        "VK_KHR_variable_pointers",
+        "(VK_KHR_get_physical_device_properties2+VK_KHR_storage_buffer_storage_class),VK_VERSION_1_1",
+    )
+    .promoted_to(V1_1),
+    instance("VK_KHR_get_display_properties2", "VK_KHR_display"),
+    instance("VK_MVK_ios_surface", "VK_KHR_surface"),
+    instance("VK_MVK_macos_surface", "VK_KHR_surface"),
+    device("VK_EXT_external_memory_dma_buf", "VK_KHR_external_memory_fd"),
+    device("VK_EXT_queue_family_foreign", "VK_KHR_external_memory,VK_VERSION_1_1"),
+    device("VK_KHR_dedicated_allocation", "VK_KHR_get_memory_requirements2").promoted_to(V1_1),
+    instance("VK_EXT_debug_utils", ""),
+    device(
+        "VK_ANDROID_external_memory_android_hardware_buffer",
+        "((VK_KHR_sampler_ycbcr_conversion+VK_KHR_external_memory+VK_KHR_dedicated_allocation),VK_VERSION_1_1)+VK_EXT_queue_family_foreign",
+    ),
+    device("VK_EXT_sampler_filter_minmax", PROPERTIES2).promoted_to(V1_2),
+    device("VK_KHR_storage_buffer_storage_class", "").promoted_to(V1_1),
+    device("VK_AMD_gpu_shader_int16", ""),
+    device(
+        "VK_AMDX_shader_enqueue",
+        "VK_KHR_get_physical_device_properties2+VK_KHR_synchronization2+VK_KHR_pipeline_library+VK_KHR_spirv_1_4",
+    ),
+    device("VK_AMD_mixed_attachment_samples", ""),
+    device("VK_AMD_shader_fragment_mask", ""),
+    device(
+        "VK_EXT_inline_uniform_block",
+        "(VK_KHR_get_physical_device_properties2+VK_KHR_maintenance1),VK_VERSION_1_1",
+    )
+    .promoted_to(V1_3),
+    device("VK_EXT_shader_stencil_export", ""),
+    device("VK_EXT_sample_locations", PROPERTIES2),
+    device("VK_KHR_relaxed_block_layout", "").promoted_to(V1_1),
+    device("VK_KHR_get_memory_requirements2", "").promoted_to(V1_1),
+    device("VK_KHR_image_format_list", "").promoted_to(V1_2),
+    device("VK_EXT_blend_operation_advanced", PROPERTIES2),
+    device("VK_NV_fragment_coverage_to_color", ""),
+    device(
+        "VK_KHR_acceleration_structure",
+        "((VK_VERSION_1_1+VK_EXT_descriptor_indexing+VK_KHR_buffer_device_address),VK_VERSION_1_2)+VK_KHR_deferred_host_operations",
+    ),
+    device("VK_KHR_ray_tracing_pipeline", "VK_KHR_spirv_1_4+VK_KHR_acceleration_structure"),
+    device("VK_KHR_ray_query", "VK_KHR_spirv_1_4+VK_KHR_acceleration_structure"),
+    device("VK_NV_framebuffer_mixed_samples", ""),
+    device("VK_NV_fill_rectangle", ""),
+    device("VK_NV_shader_sm_builtins", V1_1),
+    device("VK_EXT_post_depth_coverage", ""),
+    device(
+        "VK_KHR_sampler_ycbcr_conversion",
+        "(VK_KHR_maintenance1+VK_KHR_bind_memory2+VK_KHR_get_memory_requirements2+VK_KHR_get_physical_device_properties2),VK_VERSION_1_1",
+    )
+    .promoted_to(V1_1),
+    device("VK_KHR_bind_memory2", "").promoted_to(V1_1),
+    device(
+        "VK_EXT_image_drm_format_modifier",
+        "((VK_KHR_bind_memory2+VK_KHR_get_physical_device_properties2+VK_KHR_sampler_ycbcr_conversion),VK_VERSION_1_1)+(VK_KHR_image_format_list,VK_VERSION_1_2)",
+    ),
+    device("VK_EXT_validation_cache", ""),
+    device(
+        "VK_EXT_descriptor_indexing",
+        "(VK_KHR_get_physical_device_properties2+VK_KHR_maintenance3),VK_VERSION_1_1",
+    )
+    .promoted_to(V1_2),
+    device("VK_EXT_shader_viewport_index_layer", "").promoted_to(V1_2),
+    device("VK_KHR_portability_subset", PROPERTIES2),
+    device("VK_NV_shading_rate_image", PROPERTIES2),
+    device(
+        "VK_NV_ray_tracing",
+        "(VK_KHR_get_physical_device_properties2+VK_KHR_get_memory_requirements2),VK_VERSION_1_1",
+    ),
+    device("VK_NV_representative_fragment_test", PROPERTIES2),
+    device("VK_KHR_maintenance3", PROPERTIES2).promoted_to(V1_1),
+    device("VK_KHR_draw_indirect_count", "").promoted_to(V1_2),
+    device("VK_EXT_filter_cubic", ""),
+    device("VK_QCOM_render_pass_shader_resolve", ""),
+    device("VK_EXT_global_priority", ""),
+    device("VK_KHR_shader_subgroup_extended_types", V1_1).promoted_to(V1_2),
+    device(
+        "VK_KHR_8bit_storage",
+        "(VK_KHR_get_physical_device_properties2+VK_KHR_storage_buffer_storage_class),VK_VERSION_1_1",
+    )
+    .promoted_to(V1_2),
+    device("VK_EXT_external_memory_host", "VK_KHR_external_memory,VK_VERSION_1_1"),
+    device("VK_AMD_buffer_marker", ""),
+    device("VK_KHR_shader_atomic_int64", PROPERTIES2).promoted_to(V1_2),
+    device("VK_KHR_shader_clock", PROPERTIES2),
+    device("VK_AMD_pipeline_compiler_control", ""),
+    device("VK_EXT_calibrated_timestamps", PROPERTIES2),
+    device("VK_AMD_shader_core_properties", PROPERTIES2),
+    device("VK_KHR_video_decode_h265", "VK_KHR_video_decode_queue"),
+    device("VK_KHR_global_priority", PROPERTIES2),
+    device("VK_AMD_memory_overallocation_behavior", ""),
+    device("VK_EXT_vertex_attribute_divisor", PROPERTIES2),
+    device("VK_GGP_frame_token", "VK_KHR_swapchain+VK_GGP_stream_descriptor_surface"),
+    device("VK_EXT_pipeline_creation_feedback", "").promoted_to(V1_3),
+    device("VK_KHR_driver_properties", PROPERTIES2).promoted_to(V1_2),
+    device("VK_KHR_shader_float_controls", PROPERTIES2).promoted_to(V1_2),
+    device("VK_NV_shader_subgroup_partitioned", V1_1),
+    device("VK_KHR_depth_stencil_resolve", "VK_KHR_create_renderpass2").promoted_to(V1_2),
+    device(
+        "VK_KHR_swapchain_mutable_format",
+        "VK_KHR_swapchain+((VK_KHR_maintenance2+VK_KHR_image_format_list),(VK_VERSION_1_1+VK_KHR_image_format_list),VK_VERSION_1_2)",
+    ),
+    device("VK_NV_compute_shader_derivatives", PROPERTIES2),
+    device("VK_NV_mesh_shader", PROPERTIES2),
+    device("VK_NV_fragment_shader_barycentric", PROPERTIES2),
+    device("VK_NV_shader_image_footprint", PROPERTIES2),
+    device("VK_NV_scissor_exclusive", PROPERTIES2),
+    device("VK_NV_device_diagnostic_checkpoints", PROPERTIES2),
+    device("VK_KHR_timeline_semaphore", PROPERTIES2).promoted_to(V1_2),
+    device("VK_INTEL_shader_integer_functions2", PROPERTIES2),
+    device("VK_INTEL_performance_query", ""),
+    device("VK_KHR_vulkan_memory_model", PROPERTIES2).promoted_to(V1_2),
+    device("VK_EXT_pci_bus_info", PROPERTIES2),
+    device(
+        "VK_AMD_display_native_hdr",
+        "(VK_KHR_get_physical_device_properties2,VK_VERSION_1_1)+VK_KHR_get_surface_capabilities2+VK_KHR_swapchain",
+    ),
+    instance("VK_FUCHSIA_imagepipe_surface", "VK_KHR_surface"),
+    device("VK_KHR_shader_terminate_invocation", PROPERTIES2).promoted_to(V1_3),
+    instance("VK_EXT_metal_surface", "VK_KHR_surface"),
+    device("VK_EXT_fragment_density_map", PROPERTIES2),
+    device("VK_EXT_scalar_block_layout", PROPERTIES2).promoted_to(V1_2),
+    device("VK_GOOGLE_hlsl_functionality1", ""),
+    device("VK_GOOGLE_decorate_string", ""),
+    device("VK_EXT_subgroup_size_control", V1_1).promoted_to(V1_3),
+    device(
+        "VK_KHR_fragment_shading_rate",
+        "(VK_KHR_create_renderpass2,VK_VERSION_1_2)+(VK_KHR_get_physical_device_properties2,VK_VERSION_1_1)",
+    ),
+    device("VK_AMD_shader_core_properties2", "VK_AMD_shader_core_properties"),
+    device("VK_AMD_device_coherent_memory", PROPERTIES2),
+    device("VK_KHR_dynamic_rendering_local_read", "VK_KHR_dynamic_rendering,VK_VERSION_1_3"),
+    device("VK_EXT_shader_image_atomic_int64", PROPERTIES2),
+    device(
+        "VK_KHR_shader_quad_control",
+        "VK_VERSION_1_1+(VK_KHR_vulkan_memory_model,VK_VERSION_1_2)+VK_KHR_shader_maximal_reconvergence",
+    ),
+    device("VK_KHR_spirv_1_4", "VK_VERSION_1_1+VK_KHR_shader_float_controls").promoted_to(V1_2),
+    device("VK_EXT_memory_budget", PROPERTIES2),
+    device("VK_EXT_memory_priority", PROPERTIES2),
+    instance(
+        "VK_KHR_surface_protected_capabilities",
+        "VK_VERSION_1_1+VK_KHR_get_surface_capabilities2",
+    ),
+    device(
+        "VK_NV_dedicated_allocation_image_aliasing",
+        "(VK_KHR_dedicated_allocation+VK_KHR_get_physical_device_properties2),VK_VERSION_1_1",
+    ),
+    device(
+        "VK_KHR_separate_depth_stencil_layouts",
+        "(VK_KHR_get_physical_device_properties2+VK_KHR_create_renderpass2),VK_VERSION_1_2",
+    )
+    .promoted_to(V1_2),
+    device("VK_EXT_buffer_device_address", PROPERTIES2),
+    device("VK_EXT_tooling_info", "").promoted_to(V1_3),
+    device("VK_EXT_separate_stencil_usage", "").promoted_to(V1_2),
+    instance("VK_EXT_validation_features", ""),
+    device("VK_KHR_present_wait", "VK_KHR_swapchain+VK_KHR_present_id"),
+    device("VK_NV_cooperative_matrix", PROPERTIES2),
+    device(
+        "VK_NV_coverage_reduction_mode",
+        "VK_NV_framebuffer_mixed_samples+(VK_KHR_get_physical_device_properties2,VK_VERSION_1_1)",
+    ),
+    device("VK_EXT_fragment_shader_interlock", PROPERTIES2),
+    device("VK_EXT_ycbcr_image_arrays", "VK_KHR_sampler_ycbcr_conversion,VK_VERSION_1_1"),
+    device("VK_KHR_uniform_buffer_standard_layout", PROPERTIES2).promoted_to(V1_2),
+    device("VK_EXT_provoking_vertex", PROPERTIES2),
+    device(
+        "VK_EXT_full_screen_exclusive",
+        "(VK_KHR_get_physical_device_properties2,VK_VERSION_1_1)+VK_KHR_surface+VK_KHR_get_surface_capabilities2+VK_KHR_swapchain",
+    ),
+    instance("VK_EXT_headless_surface", "VK_KHR_surface"),
+    device(
+        "VK_KHR_buffer_device_address",
+        "(VK_KHR_get_physical_device_properties2+VK_KHR_device_group),VK_VERSION_1_1",
+    )
+    .promoted_to(V1_2),
+    device("VK_EXT_line_rasterization", PROPERTIES2),
+    device("VK_EXT_shader_atomic_float", PROPERTIES2),
+    device("VK_EXT_host_query_reset", PROPERTIES2).promoted_to(V1_2),
+    device("VK_EXT_index_type_uint8", PROPERTIES2),
+    device("VK_EXT_extended_dynamic_state", PROPERTIES2).promoted_to(V1_3),
+    device("VK_KHR_deferred_host_operations", ""),
+    device("VK_KHR_pipeline_executable_properties", PROPERTIES2),
+    device(
+        "VK_EXT_host_image_copy",
+        "((VK_KHR_get_physical_device_properties2,VK_VERSION_1_1)+VK_KHR_copy_commands2+VK_KHR_format_feature_flags2),VK_VERSION_1_3",
+    ),
+    device("VK_KHR_map_memory2", ""),
+    device("VK_EXT_map_memory_placed", "VK_KHR_map_memory2"),
+    device("VK_EXT_shader_atomic_float2", "VK_EXT_shader_atomic_float"),
+    instance("VK_EXT_surface_maintenance1", "VK_KHR_surface+VK_KHR_get_surface_capabilities2"),
+    device(
+        "VK_EXT_swapchain_maintenance1",
+        "VK_KHR_swapchain+VK_EXT_surface_maintenance1+(VK_KHR_get_physical_device_properties2,VK_VERSION_1_1)",
+    ),
+    device("VK_EXT_shader_demote_to_helper_invocation", PROPERTIES2).promoted_to(V1_3),
+    device(
+        "VK_NV_device_generated_commands",
+        "(VK_VERSION_1_1+VK_KHR_buffer_device_address),VK_VERSION_1_2",
+    ),
+    device("VK_NV_inherited_viewport_scissor", PROPERTIES2),
+    device("VK_KHR_shader_integer_dot_product", PROPERTIES2).promoted_to(V1_3),
+    device("VK_EXT_texel_buffer_alignment", PROPERTIES2).promoted_to(V1_3),
+    device("VK_QCOM_render_pass_transform", "VK_KHR_swapchain+VK_KHR_surface"),
+    device("VK_EXT_depth_bias_control", PROPERTIES2),
+    device("VK_EXT_device_memory_report", PROPERTIES2),
+    instance("VK_EXT_acquire_drm_display", "VK_EXT_direct_mode_display"),
+    device("VK_EXT_robustness2", PROPERTIES2),
+    device("VK_EXT_custom_border_color", PROPERTIES2),
+    device("VK_GOOGLE_user_type", ""),
+    device("VK_KHR_pipeline_library", ""),
+    device(
+        "VK_NV_present_barrier",
+        "(VK_KHR_get_physical_device_properties2,VK_VERSION_1_1)+VK_KHR_surface+VK_KHR_get_surface_capabilities2+VK_KHR_swapchain",
+    ),
+    device("VK_KHR_shader_non_semantic_info", "").promoted_to(V1_3),
+    device(
+        "VK_KHR_present_id",
+        "VK_KHR_swapchain+(VK_KHR_get_physical_device_properties2,VK_VERSION_1_1)",
+    ),
+    device("VK_EXT_private_data", PROPERTIES2).promoted_to(V1_3),
+    device("VK_EXT_pipeline_creation_cache_control", PROPERTIES2).promoted_to(V1_3),
+    device("VK_KHR_video_encode_queue", "VK_KHR_video_queue+VK_KHR_synchronization2"),
+    device("VK_NV_device_diagnostics_config", PROPERTIES2),
+    device("VK_QCOM_render_pass_store_ops", ""),
+    device("VK_NV_cuda_kernel_launch", ""),
+    device("VK_NV_low_latency", ""),
+    device("VK_EXT_metal_objects", ""),
+    device("VK_KHR_synchronization2", PROPERTIES2).promoted_to(V1_3),
+    device(
+        "VK_EXT_descriptor_buffer",
+        "(((VK_KHR_get_physical_device_properties2,VK_VERSION_1_1)+VK_KHR_buffer_device_address+VK_EXT_descriptor_indexing),VK_VERSION_1_2)+(VK_KHR_synchronization2,VK_VERSION_1_3)",
+    ),
+    device(
+        "VK_EXT_graphics_pipeline_library",
+        "(VK_KHR_get_physical_device_properties2,VK_VERSION_1_1)+VK_KHR_pipeline_library",
+    ),
+    device("VK_AMD_shader_early_and_late_fragment_tests", PROPERTIES2),
+    device("VK_KHR_fragment_shader_barycentric", PROPERTIES2),
+    device("VK_KHR_shader_subgroup_uniform_control_flow", V1_1),
+    device("VK_KHR_zero_initialize_workgroup_memory", PROPERTIES2).promoted_to(V1_3),
+    device("VK_NV_fragment_shading_rate_enums", "VK_KHR_fragment_shading_rate"),
+    device("VK_NV_ray_tracing_motion_blur", "VK_KHR_ray_tracing_pipeline"),
+    device("VK_EXT_mesh_shader", "VK_KHR_spirv_1_4"),
+    device(
+        "VK_EXT_ycbcr_2plane_444_formats",
+        "VK_KHR_sampler_ycbcr_conversion,VK_VERSION_1_1",
+    )
+    .promoted_to(V1_3),
+    device("VK_EXT_fragment_density_map2", "VK_EXT_fragment_density_map"),
+    device("VK_QCOM_rotated_copy_commands", "VK_KHR_swapchain+VK_KHR_copy_commands2"),
+    device("VK_EXT_image_robustness", PROPERTIES2).promoted_to(V1_3),
+    device("VK_KHR_workgroup_memory_explicit_layout", PROPERTIES2),
+    device("VK_KHR_copy_commands2", PROPERTIES2).promoted_to(V1_3),
+    device("VK_EXT_image_compression_control", PROPERTIES2),
+    device("VK_EXT_attachment_feedback_loop_layout", PROPERTIES2),
+    device("VK_EXT_4444_formats", PROPERTIES2).promoted_to(V1_3),
+    device("VK_EXT_device_fault", PROPERTIES2),
+    device("VK_ARM_rasterization_order_attachment_access", PROPERTIES2),
+    device("VK_EXT_rgba10x6_formats", "VK_KHR_sampler_ycbcr_conversion,VK_VERSION_1_1"),
+    device("VK_NV_acquire_winrt_display", "VK_EXT_direct_mode_display"),
+    instance("VK_EXT_directfb_surface", "VK_KHR_surface"),
+    device("VK_VALVE_mutable_descriptor_type", "VK_KHR_maintenance3"),
+    device("VK_EXT_vertex_input_dynamic_state", PROPERTIES2),
+    device("VK_EXT_physical_device_drm", PROPERTIES2),
+    device(
+        "VK_EXT_device_address_binding_report",
+        "(VK_KHR_get_physical_device_properties2,VK_VERSION_1_1)+VK_EXT_debug_utils",
+    ),
+    device("VK_EXT_depth_clip_control", PROPERTIES2),
+    device("VK_EXT_primitive_topology_list_restart", PROPERTIES2),
+    device("VK_KHR_format_feature_flags2", PROPERTIES2).promoted_to(V1_3),
+    device(
+        "VK_FUCHSIA_external_memory",
+        "(VK_KHR_external_memory_capabilities+VK_KHR_external_memory),VK_VERSION_1_1",
+    ),
+    device(
+        "VK_FUCHSIA_external_semaphore",
+        "VK_KHR_external_semaphore_capabilities+VK_KHR_external_semaphore",
+    ),
+    device(
+        "VK_FUCHSIA_buffer_collection",
+        "VK_FUCHSIA_external_memory+(VK_KHR_sampler_ycbcr_conversion,VK_VERSION_1_1)",
+    ),
+    device(
+        "VK_HUAWEI_subpass_shading",
+        "(VK_KHR_create_renderpass2,VK_VERSION_1_2)+(VK_KHR_synchronization2,VK_VERSION_1_3)",
+    ),
+    device(
+        "VK_HUAWEI_invocation_mask",
+        "VK_KHR_ray_tracing_pipeline+(VK_KHR_synchronization2,VK_VERSION_1_3)",
+    ),
+    device("VK_NV_external_memory_rdma", "VK_KHR_external_memory,VK_VERSION_1_1"),
+    device("VK_EXT_pipeline_properties", PROPERTIES2),
+    device("VK_EXT_frame_boundary", ""),
+    device(
+        "VK_EXT_multisampled_render_to_single_sampled",
+        "(VK_KHR_create_renderpass2+VK_KHR_depth_stencil_resolve),VK_VERSION_1_2",
+    ),
+    device("VK_EXT_extended_dynamic_state2", PROPERTIES2).promoted_to(V1_3),
+    instance("VK_QNX_screen_surface", "VK_KHR_surface"),
+    device("VK_EXT_color_write_enable", PROPERTIES2),
+    device("VK_EXT_primitives_generated_query", "VK_EXT_transform_feedback"),
+    device("VK_KHR_ray_tracing_maintenance1", "VK_KHR_acceleration_structure"),
+    device(
+        "VK_EXT_global_priority_query",
+        "VK_EXT_global_priority+(VK_KHR_get_physical_device_properties2,VK_VERSION_1_1)",
+    ),
+    device("VK_EXT_image_view_min_lod", PROPERTIES2),
+    device("VK_EXT_multi_draw", PROPERTIES2),
+    device(
+        "VK_EXT_image_2d_view_of_3d",
+        "(VK_KHR_maintenance1+VK_KHR_get_physical_device_properties2),VK_VERSION_1_1",
+    ),
+    instance("VK_KHR_portability_enumeration", ""),
+    device("VK_EXT_shader_tile_image", V1_3),
+    device(
+        "VK_EXT_opacity_micromap",
+        "VK_KHR_acceleration_structure+(VK_KHR_synchronization2,VK_VERSION_1_3)",
+    ),
+    device("VK_NV_displacement_micromap", "VK_EXT_opacity_micromap"),
+    device("VK_EXT_load_store_op_none", ""),
+    device("VK_HUAWEI_cluster_culling_shader", PROPERTIES2),
+    device("VK_EXT_border_color_swizzle", "VK_EXT_custom_border_color"),
+    device("VK_EXT_pageable_device_local_memory", "VK_EXT_memory_priority"),
+    device("VK_KHR_maintenance4", V1_1).promoted_to(V1_3),
+    device("VK_ARM_shader_core_properties", V1_1),
+    device("VK_KHR_shader_subgroup_rotate", ""),
+    device("VK_ARM_scheduling_controls", "VK_ARM_shader_core_builtins"),
+    device(
+        "VK_EXT_image_sliced_view_of_3d",
+        "(VK_KHR_maintenance1+VK_KHR_get_physical_device_properties2),VK_VERSION_1_1",
+    ),
+    device("VK_VALVE_descriptor_set_host_mapping", PROPERTIES2),
+    device("VK_EXT_depth_clamp_zero_one", PROPERTIES2),
+    device("VK_EXT_non_seamless_cube_map", PROPERTIES2),
+    device(
+        "VK_ARM_render_pass_striped",
+        "(VK_KHR_get_physical_device_properties2,VK_VERSION_1_1)+(VK_KHR_synchronization2,VK_VERSION_1_3)",
+    ),
+    device(
+        "VK_QCOM_fragment_density_map_offset",
+        "(VK_KHR_get_physical_device_properties2,VK_VERSION_1_1)+VK_EXT_fragment_density_map",
+    ),
+    device(
+        "VK_NV_copy_memory_indirect",
+        "(VK_KHR_get_physical_device_properties2+VK_KHR_buffer_device_address),VK_VERSION_1_2",
+    ),
+    device(
+        "VK_NV_memory_decompression",
+        "(VK_KHR_get_physical_device_properties2+VK_KHR_buffer_device_address),VK_VERSION_1_2",
+    ),
+    device("VK_NV_device_generated_commands_compute", "VK_NV_device_generated_commands"),
+    device("VK_NV_linear_color_attachment", PROPERTIES2),
+    instance("VK_GOOGLE_surfaceless_query", "VK_KHR_surface"),
+    device("VK_KHR_shader_maximal_reconvergence", V1_1),
+    device("VK_EXT_image_compression_control_swapchain", "VK_EXT_image_compression_control"),
+    device("VK_QCOM_image_processing", "VK_KHR_format_feature_flags2"),
+    device("VK_EXT_nested_command_buffer", PROPERTIES2),
+    device("VK_EXT_external_memory_acquire_unmodified", "VK_KHR_external_memory,VK_VERSION_1_1"),
+    device("VK_EXT_extended_dynamic_state3", PROPERTIES2),
+    device("VK_EXT_subpass_merge_feedback", PROPERTIES2),
+    instance("VK_LUNARG_direct_driver_loading", ""),
+    device(
+        "VK_EXT_shader_module_identifier",
+        "(VK_KHR_get_physical_device_properties2,VK_VERSION_1_1)+VK_EXT_pipeline_creation_cache_control",
+    ),
+    device("VK_EXT_rasterization_order_attachment_access", PROPERTIES2),
+    device(
+        "VK_NV_optical_flow",
+        "(VK_KHR_get_physical_device_properties2,VK_VERSION_1_1)+VK_KHR_format_feature_flags2+VK_KHR_synchronization2",
+    ),
+    device("VK_EXT_legacy_dithering", PROPERTIES2),
+    device("VK_EXT_pipeline_protected_access", PROPERTIES2),
+    device(
+        "VK_ANDROID_external_format_resolve",
+        "VK_ANDROID_external_memory_android_hardware_buffer",
+    ),
+    device("VK_KHR_maintenance5", "VK_VERSION_1_1+VK_KHR_dynamic_rendering"),
+    device("VK_KHR_ray_tracing_position_fetch", "VK_KHR_acceleration_structure"),
+    device(
+        "VK_EXT_shader_object",
+        "((VK_KHR_get_physical_device_properties2,VK_VERSION_1_1)+VK_KHR_dynamic_rendering),VK_VERSION_1_3",
+    ),
+    device("VK_QCOM_tile_properties", PROPERTIES2),
+    device("VK_SEC_amigo_profiling", PROPERTIES2),
+    device("VK_QCOM_multiview_per_view_viewports", PROPERTIES2),
+    device("VK_NV_ray_tracing_invocation_reorder", "VK_KHR_ray_tracing_pipeline"),
+    device("VK_NV_extended_sparse_address_space", ""),
+    device("VK_EXT_mutable_descriptor_type", "VK_KHR_maintenance3,VK_VERSION_1_1"),
+    instance("VK_EXT_layer_settings", ""),
+    device("VK_ARM_shader_core_builtins", PROPERTIES2),
+    device(
+        "VK_EXT_pipeline_library_group_handles",
+        "VK_KHR_ray_tracing_pipeline+VK_KHR_pipeline_library",
+    ),
+    device(
+        "VK_EXT_dynamic_rendering_unused_attachments",
+        "(VK_KHR_get_physical_device_properties2+VK_KHR_dynamic_rendering),VK_VERSION_1_3",
+    ),
+    device("VK_NV_low_latency2", "VK_VERSION_1_2,VK_KHR_timeline_semaphore"),
+    device("VK_KHR_cooperative_matrix", PROPERTIES2),
+    device("VK_QCOM_multiview_per_view_render_areas", ""),
+    device("VK_KHR_video_decode_av1", "VK_KHR_video_decode_queue"),
+    device("VK_KHR_video_maintenance1", "VK_KHR_video_queue"),
+    device("VK_NV_per_stage_descriptor_set", "VK_KHR_maintenance6"),
+    device("VK_QCOM_image_processing2", "VK_QCOM_image_processing"),
+    device("VK_QCOM_filter_cubic_weights", "VK_EXT_filter_cubic"),
+    device("VK_QCOM_ycbcr_degamma", ""),
+    device(
+        "VK_QCOM_filter_cubic_clamp",
+        "(VK_EXT_filter_cubic+VK_VERSION_1_2),VK_EXT_sampler_filter_minmax",
+    ),
+    device(
+        "VK_EXT_attachment_feedback_loop_dynamic_state",
+        "(VK_KHR_get_physical_device_properties2,VK_VERSION_1_1)+VK_EXT_attachment_feedback_loop_layout",
+    ),
+    device("VK_KHR_vertex_attribute_divisor", PROPERTIES2),
+    device("VK_KHR_load_store_op_none", ""),
+    device("VK_KHR_shader_float_controls2", "VK_VERSION_1_1+VK_KHR_shader_float_controls"),
+    device(
+        "VK_QNX_external_memory_screen_buffer",
+        "((VK_KHR_sampler_ycbcr_conversion+VK_KHR_external_memory+VK_KHR_dedicated_allocation),VK_VERSION_1_1)+VK_EXT_queue_family_foreign",
+    ),
+    device("VK_MSFT_layered_driver", PROPERTIES2),
+    device("VK_KHR_index_type_uint8", PROPERTIES2),
+    device("VK_KHR_line_rasterization", PROPERTIES2),
+    device("VK_KHR_calibrated_timestamps", PROPERTIES2),
+    device("VK_KHR_shader_expect_assume", PROPERTIES2),
+    device("VK_KHR_maintenance6", V1_1),
+    device("VK_NV_descriptor_pool_overallocation", V1_1),
+    device("VK_NV_raw_access_chains", ""),
+    device("VK_NV_shader_atomic_float16_vector", ""),
+    device("VK_NV_ray_tracing_validation", ""),
+];
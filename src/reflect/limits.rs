@@ -0,0 +1,1097 @@
+//! Limit types of the reflected struct members.
+//!
+//! Structs and their members are sorted by name for binary searches. Members without a limit type are not
+//! listed.
+//!
+//! This table is provisional and was not read from the vulkan registry: the limit types were assigned from
+//! the member names and types (for example `max*` members are maxima and `*Granularity` members
+//! granularities). It must be replaced by the output of
+//! `cargo run --manifest-path xtask/Cargo.toml -- <path to vk.xml> <path to grammar>`, which reads the
+//! `limittype` attributes of vk.xml.
+
+use super::LimitType::{self, *};
+
+pub(super) static LIMIT_TYPES: &[(&str, &[(&str, LimitType)])] = &[
+    (
+        "VkDrmFormatModifierPropertiesList2EXT",
+        &[("drmFormatModifierCount", Max)],
+    ),
+    (
+        "VkDrmFormatModifierPropertiesListEXT",
+        &[("drmFormatModifierCount", Max)],
+    ),
+    (
+        "VkFormatProperties",
+        &[
+            ("bufferFeatures", Bitmask),
+            ("linearTilingFeatures", Bitmask),
+            ("optimalTilingFeatures", Bitmask),
+        ],
+    ),
+    (
+        "VkFormatProperties3",
+        &[
+            ("bufferFeatures", Bitmask),
+            ("linearTilingFeatures", Bitmask),
+            ("optimalTilingFeatures", Bitmask),
+        ],
+    ),
+    (
+        "VkPhysicalDeviceAccelerationStructurePropertiesKHR",
+        &[
+            ("maxDescriptorSetAccelerationStructures", Max),
+            ("maxDescriptorSetUpdateAfterBindAccelerationStructures", Max),
+            ("maxGeometryCount", Max),
+            ("maxInstanceCount", Max),
+            ("maxPerStageDescriptorAccelerationStructures", Max),
+            (
+                "maxPerStageDescriptorUpdateAfterBindAccelerationStructures",
+                Max,
+            ),
+            ("maxPrimitiveCount", Max),
+            ("minAccelerationStructureScratchOffsetAlignment", Min),
+        ],
+    ),
+    (
+        "VkPhysicalDeviceCooperativeMatrixPropertiesKHR",
+        &[("cooperativeMatrixSupportedStages", Bitmask)],
+    ),
+    (
+        "VkPhysicalDeviceCooperativeMatrixPropertiesNV",
+        &[("cooperativeMatrixSupportedStages", Bitmask)],
+    ),
+    (
+        "VkPhysicalDeviceCopyMemoryIndirectPropertiesNV",
+        &[("supportedQueues", Bitmask)],
+    ),
+    (
+        "VkPhysicalDeviceCudaKernelLaunchPropertiesNV",
+        &[
+            ("computeCapabilityMajor", Max),
+            ("computeCapabilityMinor", Max),
+        ],
+    ),
+    (
+        "VkPhysicalDeviceCustomBorderColorPropertiesEXT",
+        &[("maxCustomBorderColorSamplers", Max)],
+    ),
+    (
+        "VkPhysicalDeviceDepthStencilResolveProperties",
+        &[
+            ("independentResolve", Bitmask),
+            ("independentResolveNone", Bitmask),
+            ("supportedDepthResolveModes", Bitmask),
+            ("supportedStencilResolveModes", Bitmask),
+        ],
+    ),
+    (
+        "VkPhysicalDeviceDescriptorBufferPropertiesEXT",
+        &[
+            (
+                "accelerationStructureCaptureReplayDescriptorDataSize",
+                NoAuto,
+            ),
+            ("accelerationStructureDescriptorSize", NoAuto),
+            ("allowSamplerImageViewPostSubmitCreation", Bitmask),
+            ("bufferCaptureReplayDescriptorDataSize", NoAuto),
+            ("bufferlessPushDescriptors", Bitmask),
+            ("combinedImageSamplerDescriptorSingleArray", Bitmask),
+            ("combinedImageSamplerDescriptorSize", NoAuto),
+            ("descriptorBufferAddressSpaceSize", Max),
+            ("descriptorBufferOffsetAlignment", Min),
+            ("imageCaptureReplayDescriptorDataSize", NoAuto),
+            ("imageViewCaptureReplayDescriptorDataSize", NoAuto),
+            ("inputAttachmentDescriptorSize", NoAuto),
+            ("maxDescriptorBufferBindings", Max),
+            ("maxEmbeddedImmutableSamplerBindings", Max),
+            ("maxEmbeddedImmutableSamplers", Max),
+            ("maxResourceDescriptorBufferBindings", Max),
+            ("maxResourceDescriptorBufferRange", Max),
+            ("maxSamplerDescriptorBufferBindings", Max),
+            ("maxSamplerDescriptorBufferRange", Max),
+            ("resourceDescriptorBufferAddressSpaceSize", Max),
+            ("robustStorageBufferDescriptorSize", NoAuto),
+            ("robustStorageTexelBufferDescriptorSize", NoAuto),
+            ("robustUniformBufferDescriptorSize", NoAuto),
+            ("robustUniformTexelBufferDescriptorSize", NoAuto),
+            ("sampledImageDescriptorSize", NoAuto),
+            ("samplerCaptureReplayDescriptorDataSize", NoAuto),
+            ("samplerDescriptorBufferAddressSpaceSize", Max),
+            ("samplerDescriptorSize", NoAuto),
+            ("storageBufferDescriptorSize", NoAuto),
+            ("storageImageDescriptorSize", NoAuto),
+            ("storageTexelBufferDescriptorSize", NoAuto),
+            ("uniformBufferDescriptorSize", NoAuto),
+            ("uniformTexelBufferDescriptorSize", NoAuto),
+        ],
+    ),
+    (
+        "VkPhysicalDeviceDescriptorIndexingProperties",
+        &[
+            ("maxDescriptorSetUpdateAfterBindInputAttachments", Max),
+            ("maxDescriptorSetUpdateAfterBindSampledImages", Max),
+            ("maxDescriptorSetUpdateAfterBindSamplers", Max),
+            ("maxDescriptorSetUpdateAfterBindStorageBuffers", Max),
+            ("maxDescriptorSetUpdateAfterBindStorageBuffersDynamic", Max),
+            ("maxDescriptorSetUpdateAfterBindStorageImages", Max),
+            ("maxDescriptorSetUpdateAfterBindUniformBuffers", Max),
+            ("maxDescriptorSetUpdateAfterBindUniformBuffersDynamic", Max),
+            ("maxPerStageDescriptorUpdateAfterBindInputAttachments", Max),
+            ("maxPerStageDescriptorUpdateAfterBindSampledImages", Max),
+            ("maxPerStageDescriptorUpdateAfterBindSamplers", Max),
+            ("maxPerStageDescriptorUpdateAfterBindStorageBuffers", Max),
+            ("maxPerStageDescriptorUpdateAfterBindStorageImages", Max),
+            ("maxPerStageDescriptorUpdateAfterBindUniformBuffers", Max),
+            ("maxPerStageUpdateAfterBindResources", Max),
+            ("maxUpdateAfterBindDescriptorsInAllPools", Max),
+            ("quadDivergentImplicitLod", Bitmask),
+            ("robustBufferAccessUpdateAfterBind", Bitmask),
+            (
+                "shaderInputAttachmentArrayNonUniformIndexingNative",
+                Bitmask,
+            ),
+            ("shaderSampledImageArrayNonUniformIndexingNative", Bitmask),
+            ("shaderStorageBufferArrayNonUniformIndexingNative", Bitmask),
+            ("shaderStorageImageArrayNonUniformIndexingNative", Bitmask),
+            ("shaderUniformBufferArrayNonUniformIndexingNative", Bitmask),
+        ],
+    ),
+    (
+        "VkPhysicalDeviceDiscardRectanglePropertiesEXT",
+        &[("maxDiscardRectangles", Max)],
+    ),
+    (
+        "VkPhysicalDeviceDisplacementMicromapPropertiesNV",
+        &[("maxDisplacementMicromapSubdivisionLevel", Max)],
+    ),
+    (
+        "VkPhysicalDeviceDriverProperties",
+        &[
+            ("conformanceVersion", NoAuto),
+            ("driverID", NoAuto),
+            ("driverInfo", NoAuto),
+            ("driverName", NoAuto),
+        ],
+    ),
+    (
+        "VkPhysicalDeviceDrmPropertiesEXT",
+        &[
+            ("hasPrimary", NoAuto),
+            ("hasRender", NoAuto),
+            ("primaryMajor", NoAuto),
+            ("primaryMinor", NoAuto),
+            ("renderMajor", NoAuto),
+            ("renderMinor", NoAuto),
+        ],
+    ),
+    (
+        "VkPhysicalDeviceExtendedDynamicState3PropertiesEXT",
+        &[("dynamicPrimitiveTopologyUnrestricted", Bitmask)],
+    ),
+    (
+        "VkPhysicalDeviceExternalMemoryHostPropertiesEXT",
+        &[("minImportedHostPointerAlignment", Min)],
+    ),
+    (
+        "VkPhysicalDeviceFloatControlsProperties",
+        &[
+            ("denormBehaviorIndependence", Exact),
+            ("roundingModeIndependence", Exact),
+            ("shaderDenormFlushToZeroFloat16", Bitmask),
+            ("shaderDenormFlushToZeroFloat32", Bitmask),
+            ("shaderDenormFlushToZeroFloat64", Bitmask),
+            ("shaderDenormPreserveFloat16", Bitmask),
+            ("shaderDenormPreserveFloat32", Bitmask),
+            ("shaderDenormPreserveFloat64", Bitmask),
+            ("shaderRoundingModeRTEFloat16", Bitmask),
+            ("shaderRoundingModeRTEFloat32", Bitmask),
+            ("shaderRoundingModeRTEFloat64", Bitmask),
+            ("shaderRoundingModeRTZFloat16", Bitmask),
+            ("shaderRoundingModeRTZFloat32", Bitmask),
+            ("shaderRoundingModeRTZFloat64", Bitmask),
+            ("shaderSignedZeroInfNanPreserveFloat16", Bitmask),
+            ("shaderSignedZeroInfNanPreserveFloat32", Bitmask),
+            ("shaderSignedZeroInfNanPreserveFloat64", Bitmask),
+        ],
+    ),
+    (
+        "VkPhysicalDeviceFragmentDensityMap2PropertiesEXT",
+        &[
+            ("maxDescriptorSetSubsampledSamplers", Max),
+            ("maxSubsampledArrayLayers", Max),
+            ("subsampledCoarseReconstructionEarlyAccess", Bitmask),
+            ("subsampledLoads", Bitmask),
+        ],
+    ),
+    (
+        "VkPhysicalDeviceFragmentDensityMapPropertiesEXT",
+        &[
+            ("fragmentDensityInvocations", Bitmask),
+            ("maxFragmentDensityTexelSize", Max),
+            ("minFragmentDensityTexelSize", Min),
+        ],
+    ),
+    (
+        "VkPhysicalDeviceFragmentShadingRatePropertiesKHR",
+        &[
+            ("fragmentShadingRateNonTrivialCombinerOps", Bitmask),
+            ("fragmentShadingRateStrictMultiplyCombiner", Bitmask),
+            ("fragmentShadingRateWithConservativeRasterization", Bitmask),
+            ("fragmentShadingRateWithCustomSampleLocations", Bitmask),
+            ("fragmentShadingRateWithFragmentShaderInterlock", Bitmask),
+            ("fragmentShadingRateWithSampleMask", Bitmask),
+            ("fragmentShadingRateWithShaderDepthStencilWrites", Bitmask),
+            ("fragmentShadingRateWithShaderSampleMask", Bitmask),
+            ("layeredShadingRateAttachments", Bitmask),
+            ("maxFragmentShadingRateAttachmentTexelSize", Max),
+            ("maxFragmentShadingRateAttachmentTexelSizeAspectRatio", Max),
+            ("maxFragmentShadingRateCoverageSamples", Max),
+            ("maxFragmentShadingRateRasterizationSamples", Max),
+            ("maxFragmentSize", Max),
+            ("maxFragmentSizeAspectRatio", Max),
+            ("minFragmentShadingRateAttachmentTexelSize", Min),
+            ("primitiveFragmentShadingRateWithMultipleViewports", Bitmask),
+        ],
+    ),
+    (
+        "VkPhysicalDeviceHostImageCopyPropertiesEXT",
+        &[
+            ("copyDstLayoutCount", NoAuto),
+            ("copySrcLayoutCount", NoAuto),
+            ("identicalMemoryTypeRequirements", Bitmask),
+            ("optimalTilingLayoutUUID", NoAuto),
+        ],
+    ),
+    (
+        "VkPhysicalDeviceIDProperties",
+        &[
+            ("deviceLUID", NoAuto),
+            ("deviceLUIDValid", NoAuto),
+            ("deviceNodeMask", NoAuto),
+            ("deviceUUID", NoAuto),
+            ("driverUUID", NoAuto),
+        ],
+    ),
+    (
+        "VkPhysicalDeviceImageProcessing2PropertiesQCOM",
+        &[("maxBlockMatchWindow", Max)],
+    ),
+    (
+        "VkPhysicalDeviceImageProcessingPropertiesQCOM",
+        &[
+            ("maxBlockMatchRegion", Max),
+            ("maxBoxFilterBlockSize", Max),
+            ("maxWeightFilterDimension", Max),
+            ("maxWeightFilterPhases", Max),
+        ],
+    ),
+    (
+        "VkPhysicalDeviceInlineUniformBlockProperties",
+        &[
+            ("maxDescriptorSetInlineUniformBlocks", Max),
+            ("maxDescriptorSetUpdateAfterBindInlineUniformBlocks", Max),
+            ("maxInlineUniformBlockSize", Max),
+            ("maxPerStageDescriptorInlineUniformBlocks", Max),
+            (
+                "maxPerStageDescriptorUpdateAfterBindInlineUniformBlocks",
+                Max,
+            ),
+        ],
+    ),
+    (
+        "VkPhysicalDeviceLayeredDriverPropertiesMSFT",
+        &[("underlyingApi", Exact)],
+    ),
+    (
+        "VkPhysicalDeviceLimits",
+        &[
+            ("bufferImageGranularity", MinMul),
+            ("discreteQueuePriorities", Max),
+            ("framebufferColorSampleCounts", Bitmask),
+            ("framebufferDepthSampleCounts", Bitmask),
+            ("framebufferNoAttachmentsSampleCounts", Bitmask),
+            ("framebufferStencilSampleCounts", Bitmask),
+            ("lineWidthGranularity", MinMul),
+            ("lineWidthRange", Range),
+            ("maxBoundDescriptorSets", Max),
+            ("maxClipDistances", Max),
+            ("maxColorAttachments", Max),
+            ("maxCombinedClipAndCullDistances", Max),
+            ("maxComputeSharedMemorySize", Max),
+            ("maxComputeWorkGroupCount", Max),
+            ("maxComputeWorkGroupInvocations", Max),
+            ("maxComputeWorkGroupSize", Max),
+            ("maxCullDistances", Max),
+            ("maxDescriptorSetInputAttachments", Max),
+            ("maxDescriptorSetSampledImages", Max),
+            ("maxDescriptorSetSamplers", Max),
+            ("maxDescriptorSetStorageBuffers", Max),
+            ("maxDescriptorSetStorageBuffersDynamic", Max),
+            ("maxDescriptorSetStorageImages", Max),
+            ("maxDescriptorSetUniformBuffers", Max),
+            ("maxDescriptorSetUniformBuffersDynamic", Max),
+            ("maxDrawIndexedIndexValue", Max),
+            ("maxDrawIndirectCount", Max),
+            ("maxFragmentCombinedOutputResources", Max),
+            ("maxFragmentDualSrcAttachments", Max),
+            ("maxFragmentInputComponents", Max),
+            ("maxFragmentOutputAttachments", Max),
+            ("maxFramebufferHeight", Max),
+            ("maxFramebufferLayers", Max),
+            ("maxFramebufferWidth", Max),
+            ("maxGeometryInputComponents", Max),
+            ("maxGeometryOutputComponents", Max),
+            ("maxGeometryOutputVertices", Max),
+            ("maxGeometryShaderInvocations", Max),
+            ("maxGeometryTotalOutputComponents", Max),
+            ("maxImageArrayLayers", Max),
+            ("maxImageDimension1D", Max),
+            ("maxImageDimension2D", Max),
+            ("maxImageDimension3D", Max),
+            ("maxImageDimensionCube", Max),
+            ("maxInterpolationOffset", Max),
+            ("maxMemoryAllocationCount", Max),
+            ("maxPerStageDescriptorInputAttachments", Max),
+            ("maxPerStageDescriptorSampledImages", Max),
+            ("maxPerStageDescriptorSamplers", Max),
+            ("maxPerStageDescriptorStorageBuffers", Max),
+            ("maxPerStageDescriptorStorageImages", Max),
+            ("maxPerStageDescriptorUniformBuffers", Max),
+            ("maxPerStageResources", Max),
+            ("maxPushConstantsSize", Max),
+            ("maxSampleMaskWords", Max),
+            ("maxSamplerAllocationCount", Max),
+            ("maxSamplerAnisotropy", Max),
+            ("maxSamplerLodBias", Max),
+            ("maxStorageBufferRange", Max),
+            ("maxTessellationControlPerPatchOutputComponents", Max),
+            ("maxTessellationControlPerVertexInputComponents", Max),
+            ("maxTessellationControlPerVertexOutputComponents", Max),
+            ("maxTessellationControlTotalOutputComponents", Max),
+            ("maxTessellationEvaluationInputComponents", Max),
+            ("maxTessellationEvaluationOutputComponents", Max),
+            ("maxTessellationGenerationLevel", Max),
+            ("maxTessellationPatchSize", Max),
+            ("maxTexelBufferElements", Max),
+            ("maxTexelGatherOffset", Max),
+            ("maxTexelOffset", Max),
+            ("maxUniformBufferRange", Max),
+            ("maxVertexInputAttributeOffset", Max),
+            ("maxVertexInputAttributes", Max),
+            ("maxVertexInputBindingStride", Max),
+            ("maxVertexInputBindings", Max),
+            ("maxVertexOutputComponents", Max),
+            ("maxViewportDimensions", Max),
+            ("maxViewports", Max),
+            ("minInterpolationOffset", Min),
+            ("minMemoryMapAlignment", Min),
+            ("minStorageBufferOffsetAlignment", Min),
+            ("minTexelBufferOffsetAlignment", Min),
+            ("minTexelGatherOffset", Min),
+            ("minTexelOffset", Min),
+            ("minUniformBufferOffsetAlignment", Min),
+            ("mipmapPrecisionBits", Bits),
+            ("nonCoherentAtomSize", Min),
+            ("optimalBufferCopyOffsetAlignment", Min),
+            ("optimalBufferCopyRowPitchAlignment", Min),
+            ("pointSizeGranularity", MinMul),
+            ("pointSizeRange", Range),
+            ("sampledImageColorSampleCounts", Bitmask),
+            ("sampledImageDepthSampleCounts", Bitmask),
+            ("sampledImageIntegerSampleCounts", Bitmask),
+            ("sampledImageStencilSampleCounts", Bitmask),
+            ("sparseAddressSpaceSize", Max),
+            ("standardSampleLocations", Bitmask),
+            ("storageImageSampleCounts", Bitmask),
+            ("strictLines", Bitmask),
+            ("subPixelInterpolationOffsetBits", Bits),
+            ("subPixelPrecisionBits", Bits),
+            ("subTexelPrecisionBits", Bits),
+            ("timestampComputeAndGraphics", Bitmask),
+            ("timestampPeriod", NoAuto),
+            ("viewportBoundsRange", Range),
+            ("viewportSubPixelBits", Bits),
+        ],
+    ),
+    (
+        "VkPhysicalDeviceLineRasterizationPropertiesKHR",
+        &[("lineSubPixelPrecisionBits", Bits)],
+    ),
+    (
+        "VkPhysicalDeviceMaintenance3Properties",
+        &[
+            ("maxMemoryAllocationSize", Max),
+            ("maxPerSetDescriptors", Max),
+        ],
+    ),
+    (
+        "VkPhysicalDeviceMaintenance4Properties",
+        &[("maxBufferSize", Max)],
+    ),
+    (
+        "VkPhysicalDeviceMaintenance5PropertiesKHR",
+        &[
+            ("depthStencilSwizzleOneSupport", Bitmask),
+            (
+                "earlyFragmentMultisampleCoverageAfterSampleCounting",
+                Bitmask,
+            ),
+            ("earlyFragmentSampleMaskTestBeforeSampleCounting", Bitmask),
+            ("nonStrictSinglePixelWideLinesUseParallelogram", Bitmask),
+            ("nonStrictWideLinesUseParallelogram", Bitmask),
+            ("polygonModePointSize", Bitmask),
+        ],
+    ),
+    (
+        "VkPhysicalDeviceMaintenance6PropertiesKHR",
+        &[
+            ("blockTexelViewCompatibleMultipleLayers", Bitmask),
+            ("fragmentShadingRateClampCombinerInputs", Bitmask),
+            ("maxCombinedImageSamplerDescriptorCount", Max),
+        ],
+    ),
+    (
+        "VkPhysicalDeviceMapMemoryPlacedPropertiesEXT",
+        &[("minPlacedMemoryMapAlignment", Min)],
+    ),
+    (
+        "VkPhysicalDeviceMemoryDecompressionPropertiesNV",
+        &[
+            ("decompressionMethods", Bitmask),
+            ("maxDecompressionIndirectCount", Max),
+        ],
+    ),
+    (
+        "VkPhysicalDeviceMeshShaderPropertiesEXT",
+        &[
+            ("maxMeshMultiviewViewCount", Max),
+            ("maxMeshOutputComponents", Max),
+            ("maxMeshOutputLayers", Max),
+            ("maxMeshOutputMemorySize", Max),
+            ("maxMeshOutputPrimitives", Max),
+            ("maxMeshOutputVertices", Max),
+            ("maxMeshPayloadAndOutputMemorySize", Max),
+            ("maxMeshPayloadAndSharedMemorySize", Max),
+            ("maxMeshSharedMemorySize", Max),
+            ("maxMeshWorkGroupCount", Max),
+            ("maxMeshWorkGroupInvocations", Max),
+            ("maxMeshWorkGroupSize", Max),
+            ("maxMeshWorkGroupTotalCount", Max),
+            ("maxPreferredMeshWorkGroupInvocations", Max),
+            ("maxPreferredTaskWorkGroupInvocations", Max),
+            ("maxTaskPayloadAndSharedMemorySize", Max),
+            ("maxTaskPayloadSize", Max),
+            ("maxTaskSharedMemorySize", Max),
+            ("maxTaskWorkGroupCount", Max),
+            ("maxTaskWorkGroupInvocations", Max),
+            ("maxTaskWorkGroupSize", Max),
+            ("maxTaskWorkGroupTotalCount", Max),
+            ("meshOutputPerPrimitiveGranularity", MinMul),
+            ("meshOutputPerVertexGranularity", MinMul),
+            ("prefersCompactPrimitiveOutput", Bitmask),
+            ("prefersCompactVertexOutput", Bitmask),
+            ("prefersLocalInvocationPrimitiveOutput", Bitmask),
+            ("prefersLocalInvocationVertexOutput", Bitmask),
+        ],
+    ),
+    (
+        "VkPhysicalDeviceMeshShaderPropertiesNV",
+        &[
+            ("maxDrawMeshTasksCount", Max),
+            ("maxMeshMultiviewViewCount", Max),
+            ("maxMeshOutputPrimitives", Max),
+            ("maxMeshOutputVertices", Max),
+            ("maxMeshTotalMemorySize", Max),
+            ("maxMeshWorkGroupInvocations", Max),
+            ("maxMeshWorkGroupSize", Max),
+            ("maxTaskOutputCount", Max),
+            ("maxTaskTotalMemorySize", Max),
+            ("maxTaskWorkGroupInvocations", Max),
+            ("maxTaskWorkGroupSize", Max),
+            ("meshOutputPerPrimitiveGranularity", MinMul),
+            ("meshOutputPerVertexGranularity", MinMul),
+        ],
+    ),
+    (
+        "VkPhysicalDeviceMultiDrawPropertiesEXT",
+        &[("maxMultiDrawCount", Max)],
+    ),
+    (
+        "VkPhysicalDeviceMultiviewProperties",
+        &[
+            ("maxMultiviewInstanceIndex", Max),
+            ("maxMultiviewViewCount", Max),
+        ],
+    ),
+    (
+        "VkPhysicalDeviceNestedCommandBufferPropertiesEXT",
+        &[("maxCommandBufferNestingLevel", Max)],
+    ),
+    (
+        "VkPhysicalDeviceOpacityMicromapPropertiesEXT",
+        &[
+            ("maxOpacity2StateSubdivisionLevel", Max),
+            ("maxOpacity4StateSubdivisionLevel", Max),
+        ],
+    ),
+    (
+        "VkPhysicalDeviceOpticalFlowPropertiesNV",
+        &[
+            ("bidirectionalFlowSupported", Bitmask),
+            ("costSupported", Bitmask),
+            ("globalFlowSupported", Bitmask),
+            ("hintSupported", Bitmask),
+            ("maxHeight", Max),
+            ("maxNumRegionsOfInterest", Max),
+            ("maxWidth", Max),
+            ("minHeight", Min),
+            ("minWidth", Min),
+            ("supportedHintGridSizes", Bitmask),
+            ("supportedOutputGridSizes", Bitmask),
+        ],
+    ),
+    (
+        "VkPhysicalDevicePCIBusInfoPropertiesEXT",
+        &[
+            ("pciBus", NoAuto),
+            ("pciDevice", NoAuto),
+            ("pciDomain", NoAuto),
+            ("pciFunction", NoAuto),
+        ],
+    ),
+    (
+        "VkPhysicalDevicePerformanceQueryPropertiesKHR",
+        &[("allowCommandBufferQueryCopies", Bitmask)],
+    ),
+    (
+        "VkPhysicalDevicePipelineRobustnessPropertiesEXT",
+        &[
+            ("defaultRobustnessImages", Exact),
+            ("defaultRobustnessStorageBuffers", Exact),
+            ("defaultRobustnessUniformBuffers", Exact),
+            ("defaultRobustnessVertexInputs", Exact),
+        ],
+    ),
+    (
+        "VkPhysicalDevicePointClippingProperties",
+        &[("pointClippingBehavior", Exact)],
+    ),
+    (
+        "VkPhysicalDevicePortabilitySubsetPropertiesKHR",
+        &[("minVertexInputBindingStrideAlignment", Min)],
+    ),
+    (
+        "VkPhysicalDeviceProperties",
+        &[
+            ("apiVersion", NoAuto),
+            ("deviceID", NoAuto),
+            ("deviceName", NoAuto),
+            ("deviceType", NoAuto),
+            ("driverVersion", NoAuto),
+            ("limits", Struct),
+            ("pipelineCacheUUID", NoAuto),
+            ("sparseProperties", Struct),
+            ("vendorID", NoAuto),
+        ],
+    ),
+    (
+        "VkPhysicalDeviceProtectedMemoryProperties",
+        &[("protectedNoFault", Bitmask)],
+    ),
+    (
+        "VkPhysicalDeviceProvokingVertexPropertiesEXT",
+        &[
+            ("provokingVertexModePerPipeline", Bitmask),
+            (
+                "transformFeedbackPreservesTriangleFanProvokingVertex",
+                Bitmask,
+            ),
+        ],
+    ),
+    (
+        "VkPhysicalDevicePushDescriptorPropertiesKHR",
+        &[("maxPushDescriptors", Max)],
+    ),
+    (
+        "VkPhysicalDeviceRayTracingPipelinePropertiesKHR",
+        &[
+            ("maxRayDispatchInvocationCount", Max),
+            ("maxRayHitAttributeSize", Max),
+            ("maxRayRecursionDepth", Max),
+            ("maxShaderGroupStride", Max),
+            ("shaderGroupBaseAlignment", Min),
+            ("shaderGroupHandleAlignment", Min),
+            ("shaderGroupHandleCaptureReplaySize", Exact),
+            ("shaderGroupHandleSize", Exact),
+        ],
+    ),
+    (
+        "VkPhysicalDeviceRayTracingPropertiesNV",
+        &[
+            ("maxDescriptorSetAccelerationStructures", Max),
+            ("maxGeometryCount", Max),
+            ("maxInstanceCount", Max),
+            ("maxRecursionDepth", Max),
+            ("maxShaderGroupStride", Max),
+            ("maxTriangleCount", Max),
+            ("shaderGroupBaseAlignment", Min),
+            ("shaderGroupHandleSize", Exact),
+        ],
+    ),
+    (
+        "VkPhysicalDeviceRenderPassStripedPropertiesARM",
+        &[
+            ("maxRenderPassStripes", Max),
+            ("renderPassStripeGranularity", MinMul),
+        ],
+    ),
+    (
+        "VkPhysicalDeviceRobustness2PropertiesEXT",
+        &[
+            ("robustStorageBufferAccessSizeAlignment", Min),
+            ("robustUniformBufferAccessSizeAlignment", Min),
+        ],
+    ),
+    (
+        "VkPhysicalDeviceSampleLocationsPropertiesEXT",
+        &[
+            ("maxSampleLocationGridSize", Max),
+            ("sampleLocationCoordinateRange", Range),
+            ("sampleLocationSampleCounts", Bitmask),
+            ("sampleLocationSubPixelBits", Bits),
+            ("variableSampleLocations", Bitmask),
+        ],
+    ),
+    (
+        "VkPhysicalDeviceSamplerFilterMinmaxProperties",
+        &[
+            ("filterMinmaxImageComponentMapping", Bitmask),
+            ("filterMinmaxSingleComponentFormats", Bitmask),
+        ],
+    ),
+    (
+        "VkPhysicalDeviceSchedulingControlsPropertiesARM",
+        &[("schedulingControlsFlags", Bitmask)],
+    ),
+    (
+        "VkPhysicalDeviceShaderCoreBuiltinsPropertiesARM",
+        &[
+            ("shaderCoreCount", Max),
+            ("shaderCoreMask", Bitmask),
+            ("shaderWarpsPerCore", Max),
+        ],
+    ),
+    (
+        "VkPhysicalDeviceShaderCoreProperties2AMD",
+        &[
+            ("activeComputeUnitCount", Max),
+            ("shaderCoreFeatures", Bitmask),
+        ],
+    ),
+    (
+        "VkPhysicalDeviceShaderCorePropertiesAMD",
+        &[
+            ("computeUnitsPerShaderArray", Max),
+            ("maxSgprAllocation", Max),
+            ("maxVgprAllocation", Max),
+            ("minSgprAllocation", Min),
+            ("minVgprAllocation", Min),
+            ("sgprAllocationGranularity", MinMul),
+            ("sgprsPerSimd", Max),
+            ("shaderArraysPerEngineCount", Max),
+            ("shaderEngineCount", Max),
+            ("simdPerComputeUnit", Max),
+            ("vgprAllocationGranularity", MinMul),
+            ("vgprsPerSimd", Max),
+            ("wavefrontSize", Max),
+            ("wavefrontsPerSimd", Max),
+        ],
+    ),
+    (
+        "VkPhysicalDeviceShaderCorePropertiesARM",
+        &[("fmaRate", Max), ("pixelRate", Max), ("texelRate", Max)],
+    ),
+    (
+        "VkPhysicalDeviceShaderEnqueuePropertiesAMDX",
+        &[
+            ("executionGraphDispatchAddressAlignment", Min),
+            ("maxExecutionGraphDepth", Max),
+            ("maxExecutionGraphShaderOutputNodes", Max),
+            ("maxExecutionGraphShaderPayloadCount", Max),
+            ("maxExecutionGraphShaderPayloadSize", Max),
+        ],
+    ),
+    (
+        "VkPhysicalDeviceShaderIntegerDotProductProperties",
+        &[
+            ("integerDotProduct16BitMixedSignednessAccelerated", Bitmask),
+            ("integerDotProduct16BitSignedAccelerated", Bitmask),
+            ("integerDotProduct16BitUnsignedAccelerated", Bitmask),
+            ("integerDotProduct32BitMixedSignednessAccelerated", Bitmask),
+            ("integerDotProduct32BitSignedAccelerated", Bitmask),
+            ("integerDotProduct32BitUnsignedAccelerated", Bitmask),
+            (
+                "integerDotProduct4x8BitPackedMixedSignednessAccelerated",
+                Bitmask,
+            ),
+            ("integerDotProduct4x8BitPackedSignedAccelerated", Bitmask),
+            ("integerDotProduct4x8BitPackedUnsignedAccelerated", Bitmask),
+            ("integerDotProduct64BitMixedSignednessAccelerated", Bitmask),
+            ("integerDotProduct64BitSignedAccelerated", Bitmask),
+            ("integerDotProduct64BitUnsignedAccelerated", Bitmask),
+            ("integerDotProduct8BitMixedSignednessAccelerated", Bitmask),
+            ("integerDotProduct8BitSignedAccelerated", Bitmask),
+            ("integerDotProduct8BitUnsignedAccelerated", Bitmask),
+            (
+                "integerDotProductAccumulatingSaturating16BitMixedSignednessAccelerated",
+                Bitmask,
+            ),
+            (
+                "integerDotProductAccumulatingSaturating16BitSignedAccelerated",
+                Bitmask,
+            ),
+            (
+                "integerDotProductAccumulatingSaturating16BitUnsignedAccelerated",
+                Bitmask,
+            ),
+            (
+                "integerDotProductAccumulatingSaturating32BitMixedSignednessAccelerated",
+                Bitmask,
+            ),
+            (
+                "integerDotProductAccumulatingSaturating32BitSignedAccelerated",
+                Bitmask,
+            ),
+            (
+                "integerDotProductAccumulatingSaturating32BitUnsignedAccelerated",
+                Bitmask,
+            ),
+            (
+                "integerDotProductAccumulatingSaturating4x8BitPackedSignedAccelerated",
+                Bitmask,
+            ),
+            (
+                "integerDotProductAccumulatingSaturating4x8BitPackedUnsignedAccelerated",
+                Bitmask,
+            ),
+            (
+                "integerDotProductAccumulatingSaturating64BitMixedSignednessAccelerated",
+                Bitmask,
+            ),
+            (
+                "integerDotProductAccumulatingSaturating64BitSignedAccelerated",
+                Bitmask,
+            ),
+            (
+                "integerDotProductAccumulatingSaturating64BitUnsignedAccelerated",
+                Bitmask,
+            ),
+            (
+                "integerDotProductAccumulatingSaturating8BitMixedSignednessAccelerated",
+                Bitmask,
+            ),
+            (
+                "integerDotProductAccumulatingSaturating8BitSignedAccelerated",
+                Bitmask,
+            ),
+            (
+                "integerDotProductAccumulatingSaturating8BitUnsignedAccelerated",
+                Bitmask,
+            ),
+        ],
+    ),
+    (
+        "VkPhysicalDeviceShaderObjectPropertiesEXT",
+        &[("shaderBinaryUUID", NoAuto), ("shaderBinaryVersion", Exact)],
+    ),
+    (
+        "VkPhysicalDeviceShaderSMBuiltinsPropertiesNV",
+        &[("shaderSMCount", Max), ("shaderWarpsPerSM", Max)],
+    ),
+    (
+        "VkPhysicalDeviceShaderTileImagePropertiesEXT",
+        &[
+            ("shaderTileImageCoherentReadAccelerated", Bitmask),
+            ("shaderTileImageReadFromHelperInvocation", Bitmask),
+            ("shaderTileImageReadSampleFromPixelRateInvocation", Bitmask),
+        ],
+    ),
+    (
+        "VkPhysicalDeviceShadingRateImagePropertiesNV",
+        &[
+            ("shadingRateMaxCoarseSamples", Max),
+            ("shadingRatePaletteSize", Max),
+            ("shadingRateTexelSize", Exact),
+        ],
+    ),
+    (
+        "VkPhysicalDeviceSparseProperties",
+        &[
+            ("residencyAlignedMipSize", Bitmask),
+            ("residencyNonResidentStrict", Bitmask),
+            ("residencyStandard2DBlockShape", Bitmask),
+            ("residencyStandard2DMultisampleBlockShape", Bitmask),
+            ("residencyStandard3DBlockShape", Bitmask),
+        ],
+    ),
+    (
+        "VkPhysicalDeviceSubgroupProperties",
+        &[
+            ("quadOperationsInAllStages", Bitmask),
+            ("subgroupSize", Max),
+            ("supportedOperations", Bitmask),
+            ("supportedStages", Bitmask),
+        ],
+    ),
+    (
+        "VkPhysicalDeviceSubgroupSizeControlProperties",
+        &[
+            ("maxComputeWorkgroupSubgroups", Max),
+            ("maxSubgroupSize", Max),
+            ("minSubgroupSize", Min),
+            ("requiredSubgroupSizeStages", Bitmask),
+        ],
+    ),
+    (
+        "VkPhysicalDeviceSubpassShadingPropertiesHUAWEI",
+        &[("maxSubpassShadingWorkgroupSizeAspectRatio", Max)],
+    ),
+    (
+        "VkPhysicalDeviceTexelBufferAlignmentProperties",
+        &[
+            ("storageTexelBufferOffsetAlignmentBytes", Min),
+            ("storageTexelBufferOffsetSingleTexelAlignment", Bitmask),
+            ("uniformTexelBufferOffsetAlignmentBytes", Min),
+            ("uniformTexelBufferOffsetSingleTexelAlignment", Bitmask),
+        ],
+    ),
+    (
+        "VkPhysicalDeviceTimelineSemaphoreProperties",
+        &[("maxTimelineSemaphoreValueDifference", Max)],
+    ),
+    (
+        "VkPhysicalDeviceTransformFeedbackPropertiesEXT",
+        &[
+            ("maxTransformFeedbackBufferDataSize", Max),
+            ("maxTransformFeedbackBufferDataStride", Max),
+            ("maxTransformFeedbackBufferSize", Max),
+            ("maxTransformFeedbackBuffers", Max),
+            ("maxTransformFeedbackStreamDataSize", Max),
+            ("maxTransformFeedbackStreams", Max),
+            ("transformFeedbackDraw", Bitmask),
+            ("transformFeedbackQueries", Bitmask),
+            ("transformFeedbackRasterizationStreamSelect", Bitmask),
+            ("transformFeedbackStreamsLinesTriangles", Bitmask),
+        ],
+    ),
+    (
+        "VkPhysicalDeviceVulkan11Properties",
+        &[
+            ("deviceLUID", NoAuto),
+            ("deviceLUIDValid", NoAuto),
+            ("deviceNodeMask", NoAuto),
+            ("deviceUUID", NoAuto),
+            ("driverUUID", NoAuto),
+            ("maxMemoryAllocationSize", Max),
+            ("maxMultiviewInstanceIndex", Max),
+            ("maxMultiviewViewCount", Max),
+            ("maxPerSetDescriptors", Max),
+            ("pointClippingBehavior", Exact),
+            ("protectedNoFault", Bitmask),
+            ("subgroupQuadOperationsInAllStages", Bitmask),
+            ("subgroupSize", Max),
+            ("subgroupSupportedOperations", Bitmask),
+            ("subgroupSupportedStages", Bitmask),
+        ],
+    ),
+    (
+        "VkPhysicalDeviceVulkan12Properties",
+        &[
+            ("conformanceVersion", NoAuto),
+            ("denormBehaviorIndependence", Exact),
+            ("driverID", NoAuto),
+            ("driverInfo", NoAuto),
+            ("driverName", NoAuto),
+            ("filterMinmaxImageComponentMapping", Bitmask),
+            ("filterMinmaxSingleComponentFormats", Bitmask),
+            ("framebufferIntegerColorSampleCounts", Bitmask),
+            ("independentResolve", Bitmask),
+            ("independentResolveNone", Bitmask),
+            ("maxDescriptorSetUpdateAfterBindInputAttachments", Max),
+            ("maxDescriptorSetUpdateAfterBindSampledImages", Max),
+            ("maxDescriptorSetUpdateAfterBindSamplers", Max),
+            ("maxDescriptorSetUpdateAfterBindStorageBuffers", Max),
+            ("maxDescriptorSetUpdateAfterBindStorageBuffersDynamic", Max),
+            ("maxDescriptorSetUpdateAfterBindStorageImages", Max),
+            ("maxDescriptorSetUpdateAfterBindUniformBuffers", Max),
+            ("maxDescriptorSetUpdateAfterBindUniformBuffersDynamic", Max),
+            ("maxPerStageDescriptorUpdateAfterBindInputAttachments", Max),
+            ("maxPerStageDescriptorUpdateAfterBindSampledImages", Max),
+            ("maxPerStageDescriptorUpdateAfterBindSamplers", Max),
+            ("maxPerStageDescriptorUpdateAfterBindStorageBuffers", Max),
+            ("maxPerStageDescriptorUpdateAfterBindStorageImages", Max),
+            ("maxPerStageDescriptorUpdateAfterBindUniformBuffers", Max),
+            ("maxPerStageUpdateAfterBindResources", Max),
+            ("maxTimelineSemaphoreValueDifference", Max),
+            ("maxUpdateAfterBindDescriptorsInAllPools", Max),
+            ("quadDivergentImplicitLod", Bitmask),
+            ("robustBufferAccessUpdateAfterBind", Bitmask),
+            ("roundingModeIndependence", Exact),
+            ("shaderDenormFlushToZeroFloat16", Bitmask),
+            ("shaderDenormFlushToZeroFloat32", Bitmask),
+            ("shaderDenormFlushToZeroFloat64", Bitmask),
+            ("shaderDenormPreserveFloat16", Bitmask),
+            ("shaderDenormPreserveFloat32", Bitmask),
+            ("shaderDenormPreserveFloat64", Bitmask),
+            (
+                "shaderInputAttachmentArrayNonUniformIndexingNative",
+                Bitmask,
+            ),
+            ("shaderRoundingModeRTEFloat16", Bitmask),
+            ("shaderRoundingModeRTEFloat32", Bitmask),
+            ("shaderRoundingModeRTEFloat64", Bitmask),
+            ("shaderRoundingModeRTZFloat16", Bitmask),
+            ("shaderRoundingModeRTZFloat32", Bitmask),
+            ("shaderRoundingModeRTZFloat64", Bitmask),
+            ("shaderSampledImageArrayNonUniformIndexingNative", Bitmask),
+            ("shaderSignedZeroInfNanPreserveFloat16", Bitmask),
+            ("shaderSignedZeroInfNanPreserveFloat32", Bitmask),
+            ("shaderSignedZeroInfNanPreserveFloat64", Bitmask),
+            ("shaderStorageBufferArrayNonUniformIndexingNative", Bitmask),
+            ("shaderStorageImageArrayNonUniformIndexingNative", Bitmask),
+            ("shaderUniformBufferArrayNonUniformIndexingNative", Bitmask),
+            ("supportedDepthResolveModes", Bitmask),
+            ("supportedStencilResolveModes", Bitmask),
+        ],
+    ),
+    (
+        "VkPhysicalDeviceVulkan13Properties",
+        &[
+            ("integerDotProduct16BitMixedSignednessAccelerated", Bitmask),
+            ("integerDotProduct16BitSignedAccelerated", Bitmask),
+            ("integerDotProduct16BitUnsignedAccelerated", Bitmask),
+            ("integerDotProduct32BitMixedSignednessAccelerated", Bitmask),
+            ("integerDotProduct32BitSignedAccelerated", Bitmask),
+            ("integerDotProduct32BitUnsignedAccelerated", Bitmask),
+            (
+                "integerDotProduct4x8BitPackedMixedSignednessAccelerated",
+                Bitmask,
+            ),
+            ("integerDotProduct4x8BitPackedSignedAccelerated", Bitmask),
+            ("integerDotProduct4x8BitPackedUnsignedAccelerated", Bitmask),
+            ("integerDotProduct64BitMixedSignednessAccelerated", Bitmask),
+            ("integerDotProduct64BitSignedAccelerated", Bitmask),
+            ("integerDotProduct64BitUnsignedAccelerated", Bitmask),
+            ("integerDotProduct8BitMixedSignednessAccelerated", Bitmask),
+            ("integerDotProduct8BitSignedAccelerated", Bitmask),
+            ("integerDotProduct8BitUnsignedAccelerated", Bitmask),
+            (
+                "integerDotProductAccumulatingSaturating16BitMixedSignednessAccelerated",
+                Bitmask,
+            ),
+            (
+                "integerDotProductAccumulatingSaturating16BitSignedAccelerated",
+                Bitmask,
+            ),
+            (
+                "integerDotProductAccumulatingSaturating16BitUnsignedAccelerated",
+                Bitmask,
+            ),
+            (
+                "integerDotProductAccumulatingSaturating32BitMixedSignednessAccelerated",
+                Bitmask,
+            ),
+            (
+                "integerDotProductAccumulatingSaturating32BitSignedAccelerated",
+                Bitmask,
+            ),
+            (
+                "integerDotProductAccumulatingSaturating32BitUnsignedAccelerated",
+                Bitmask,
+            ),
+            (
+                "integerDotProductAccumulatingSaturating4x8BitPackedSignedAccelerated",
+                Bitmask,
+            ),
+            (
+                "integerDotProductAccumulatingSaturating4x8BitPackedUnsignedAccelerated",
+                Bitmask,
+            ),
+            (
+                "integerDotProductAccumulatingSaturating64BitMixedSignednessAccelerated",
+                Bitmask,
+            ),
+            (
+                "integerDotProductAccumulatingSaturating64BitSignedAccelerated",
+                Bitmask,
+            ),
+            (
+                "integerDotProductAccumulatingSaturating64BitUnsignedAccelerated",
+                Bitmask,
+            ),
+            (
+                "integerDotProductAccumulatingSaturating8BitMixedSignednessAccelerated",
+                Bitmask,
+            ),
+            (
+                "integerDotProductAccumulatingSaturating8BitSignedAccelerated",
+                Bitmask,
+            ),
+            (
+                "integerDotProductAccumulatingSaturating8BitUnsignedAccelerated",
+                Bitmask,
+            ),
+            ("maxBufferSize", Max),
+            ("maxComputeWorkgroupSubgroups", Max),
+            ("maxDescriptorSetInlineUniformBlocks", Max),
+            ("maxDescriptorSetUpdateAfterBindInlineUniformBlocks", Max),
+            ("maxInlineUniformBlockSize", Max),
+            ("maxInlineUniformTotalSize", Max),
+            ("maxPerStageDescriptorInlineUniformBlocks", Max),
+            (
+                "maxPerStageDescriptorUpdateAfterBindInlineUniformBlocks",
+                Max,
+            ),
+            ("maxSubgroupSize", Max),
+            ("minSubgroupSize", Min),
+            ("requiredSubgroupSizeStages", Bitmask),
+            ("storageTexelBufferOffsetAlignmentBytes", Min),
+            ("storageTexelBufferOffsetSingleTexelAlignment", Bitmask),
+            ("uniformTexelBufferOffsetAlignmentBytes", Min),
+            ("uniformTexelBufferOffsetSingleTexelAlignment", Bitmask),
+        ],
+    ),
+    (
+        "VkQueueFamilyCheckpointProperties2NV",
+        &[("checkpointExecutionStageMask", Bitmask)],
+    ),
+    (
+        "VkQueueFamilyCheckpointPropertiesNV",
+        &[("checkpointExecutionStageMask", Bitmask)],
+    ),
+    (
+        "VkQueueFamilyGlobalPriorityPropertiesKHR",
+        &[("priorities", Exact), ("priorityCount", Max)],
+    ),
+    (
+        "VkQueueFamilyProperties",
+        &[
+            ("minImageTransferGranularity", MinMul),
+            ("queueCount", Max),
+            ("queueFlags", Bitmask),
+            ("timestampValidBits", Bits),
+        ],
+    ),
+    (
+        "VkQueueFamilyQueryResultStatusPropertiesKHR",
+        &[("queryResultStatusSupport", Bitmask)],
+    ),
+    (
+        "VkQueueFamilyVideoPropertiesKHR",
+        &[("videoCodecOperations", Bitmask)],
+    ),
+    (
+        "VkSubpassResolvePerformanceQueryEXT",
+        &[("optimal", Bitmask)],
+    ),
+];
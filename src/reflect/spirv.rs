@@ -0,0 +1,1955 @@
+//! The SPIR-V capabilities and extensions allowed in vulkan and what enables them, in the form of the
+//! `spirvcapabilities` and `spirvextensions` sections of the vulkan registry.
+//!
+//! Capability ids are taken from the SPIR-V grammar. Capabilities which share an id, for example
+//! `ShaderViewportIndexLayerEXT` and `ShaderViewportIndexLayerNV`, are listed once under the first name with
+//! the enables of all aliases. Features of core structs like `VkPhysicalDeviceVulkan12Features` are listed
+//! for the struct of the promoted extension as well. The `requires` attributes use the syntax of extension
+//! dependencies.
+//!
+//! This table is maintained by hand in the layout written by
+//! `cargo run --manifest-path xtask/Cargo.toml -- <path to vk.xml> <path to grammar>`. It has not been
+//! generated from vk.xml and `spirv.core.grammar.json` yet, so it should be replaced by the output of that
+//! command.
+
+use super::{SpirvCapabilityInfo, SpirvEnable, SpirvExtensionInfo};
+
+const fn capability(
+    name: &'static str,
+    id: u32,
+    enables: &'static [SpirvEnable],
+) -> SpirvCapabilityInfo {
+    SpirvCapabilityInfo { name, id, enables }
+}
+
+const fn extension(name: &'static str, enables: &'static [SpirvEnable]) -> SpirvExtensionInfo {
+    SpirvExtensionInfo { name, enables }
+}
+
+const fn feature(
+    struct_name: &'static str,
+    feature: &'static str,
+    requires: &'static str,
+) -> SpirvEnable {
+    SpirvEnable::Feature {
+        struct_name,
+        feature,
+        requires,
+    }
+}
+
+const fn property(
+    struct_name: &'static str,
+    member: &'static str,
+    value: &'static str,
+    requires: &'static str,
+) -> SpirvEnable {
+    SpirvEnable::Property {
+        struct_name,
+        member,
+        value,
+        requires,
+    }
+}
+
+use SpirvEnable::{Extension as Ext, Version};
+
+const V1_0: &str = "VK_VERSION_1_0";
+const V1_1: &str = "VK_VERSION_1_1";
+const V1_2: &str = "VK_VERSION_1_2";
+const V1_3: &str = "VK_VERSION_1_3";
+
+pub(super) static SPIRV_CAPABILITIES: &[SpirvCapabilityInfo] = &[
+    capability("Matrix", 0, &[Version(V1_0)]),
+    capability("Shader", 1, &[Version(V1_0)]),
+    capability(
+        "Geometry",
+        2,
+        &[feature("VkPhysicalDeviceFeatures", "geometryShader", V1_0)],
+    ),
+    capability(
+        "Tessellation",
+        3,
+        &[feature(
+            "VkPhysicalDeviceFeatures",
+            "tessellationShader",
+            V1_0,
+        )],
+    ),
+    capability(
+        "Float16",
+        9,
+        &[
+            feature("VkPhysicalDeviceVulkan12Features", "shaderFloat16", V1_2),
+            feature(
+                "VkPhysicalDeviceShaderFloat16Int8Features",
+                "shaderFloat16",
+                "VK_VERSION_1_2,VK_KHR_shader_float16_int8",
+            ),
+            Ext("VK_AMD_gpu_shader_half_float"),
+        ],
+    ),
+    capability(
+        "Float64",
+        10,
+        &[feature("VkPhysicalDeviceFeatures", "shaderFloat64", V1_0)],
+    ),
+    capability(
+        "Int64",
+        11,
+        &[feature("VkPhysicalDeviceFeatures", "shaderInt64", V1_0)],
+    ),
+    capability(
+        "Int64Atomics",
+        12,
+        &[
+            feature(
+                "VkPhysicalDeviceVulkan12Features",
+                "shaderBufferInt64Atomics",
+                V1_2,
+            ),
+            feature(
+                "VkPhysicalDeviceShaderAtomicInt64Features",
+                "shaderBufferInt64Atomics",
+                "VK_VERSION_1_2,VK_KHR_shader_atomic_int64",
+            ),
+            feature(
+                "VkPhysicalDeviceVulkan12Features",
+                "shaderSharedInt64Atomics",
+                V1_2,
+            ),
+            feature(
+                "VkPhysicalDeviceShaderAtomicInt64Features",
+                "shaderSharedInt64Atomics",
+                "VK_VERSION_1_2,VK_KHR_shader_atomic_int64",
+            ),
+            feature(
+                "VkPhysicalDeviceShaderImageAtomicInt64FeaturesEXT",
+                "shaderImageInt64Atomics",
+                "VK_EXT_shader_image_atomic_int64",
+            ),
+        ],
+    ),
+    capability("Groups", 18, &[Ext("VK_AMD_shader_ballot")]),
+    capability(
+        "Int16",
+        22,
+        &[feature("VkPhysicalDeviceFeatures", "shaderInt16", V1_0)],
+    ),
+    capability(
+        "TessellationPointSize",
+        23,
+        &[feature(
+            "VkPhysicalDeviceFeatures",
+            "shaderTessellationAndGeometryPointSize",
+            V1_0,
+        )],
+    ),
+    capability(
+        "GeometryPointSize",
+        24,
+        &[feature(
+            "VkPhysicalDeviceFeatures",
+            "shaderTessellationAndGeometryPointSize",
+            V1_0,
+        )],
+    ),
+    capability(
+        "ImageGatherExtended",
+        25,
+        &[feature(
+            "VkPhysicalDeviceFeatures",
+            "shaderImageGatherExtended",
+            V1_0,
+        )],
+    ),
+    capability(
+        "StorageImageMultisample",
+        27,
+        &[feature(
+            "VkPhysicalDeviceFeatures",
+            "shaderStorageImageMultisample",
+            V1_0,
+        )],
+    ),
+    capability(
+        "UniformBufferArrayDynamicIndexing",
+        28,
+        &[feature(
+            "VkPhysicalDeviceFeatures",
+            "shaderUniformBufferArrayDynamicIndexing",
+            V1_0,
+        )],
+    ),
+    capability(
+        "SampledImageArrayDynamicIndexing",
+        29,
+        &[feature(
+            "VkPhysicalDeviceFeatures",
+            "shaderSampledImageArrayDynamicIndexing",
+            V1_0,
+        )],
+    ),
+    capability(
+        "StorageBufferArrayDynamicIndexing",
+        30,
+        &[feature(
+            "VkPhysicalDeviceFeatures",
+            "shaderStorageBufferArrayDynamicIndexing",
+            V1_0,
+        )],
+    ),
+    capability(
+        "StorageImageArrayDynamicIndexing",
+        31,
+        &[feature(
+            "VkPhysicalDeviceFeatures",
+            "shaderStorageImageArrayDynamicIndexing",
+            V1_0,
+        )],
+    ),
+    capability(
+        "ClipDistance",
+        32,
+        &[feature(
+            "VkPhysicalDeviceFeatures",
+            "shaderClipDistance",
+            V1_0,
+        )],
+    ),
+    capability(
+        "CullDistance",
+        33,
+        &[feature(
+            "VkPhysicalDeviceFeatures",
+            "shaderCullDistance",
+            V1_0,
+        )],
+    ),
+    capability(
+        "ImageCubeArray",
+        34,
+        &[feature("VkPhysicalDeviceFeatures", "imageCubeArray", V1_0)],
+    ),
+    capability(
+        "SampleRateShading",
+        35,
+        &[feature(
+            "VkPhysicalDeviceFeatures",
+            "sampleRateShading",
+            V1_0,
+        )],
+    ),
+    capability(
+        "Int8",
+        39,
+        &[
+            feature("VkPhysicalDeviceVulkan12Features", "shaderInt8", V1_2),
+            feature(
+                "VkPhysicalDeviceShaderFloat16Int8Features",
+                "shaderInt8",
+                "VK_VERSION_1_2,VK_KHR_shader_float16_int8",
+            ),
+        ],
+    ),
+    capability("InputAttachment", 40, &[Version(V1_0)]),
+    capability(
+        "SparseResidency",
+        41,
+        &[feature(
+            "VkPhysicalDeviceFeatures",
+            "shaderResourceResidency",
+            V1_0,
+        )],
+    ),
+    capability(
+        "MinLod",
+        42,
+        &[feature(
+            "VkPhysicalDeviceFeatures",
+            "shaderResourceMinLod",
+            V1_0,
+        )],
+    ),
+    capability("Sampled1D", 43, &[Version(V1_0)]),
+    capability("Image1D", 44, &[Version(V1_0)]),
+    capability(
+        "SampledCubeArray",
+        45,
+        &[feature("VkPhysicalDeviceFeatures", "imageCubeArray", V1_0)],
+    ),
+    capability("SampledBuffer", 46, &[Version(V1_0)]),
+    capability("ImageBuffer", 47, &[Version(V1_0)]),
+    capability(
+        "ImageMSArray",
+        48,
+        &[feature(
+            "VkPhysicalDeviceFeatures",
+            "shaderStorageImageMultisample",
+            V1_0,
+        )],
+    ),
+    capability("StorageImageExtendedFormats", 49, &[Version(V1_0)]),
+    capability("ImageQuery", 50, &[Version(V1_0)]),
+    capability("DerivativeControl", 51, &[Version(V1_0)]),
+    capability(
+        "InterpolationFunction",
+        52,
+        &[feature(
+            "VkPhysicalDeviceFeatures",
+            "sampleRateShading",
+            V1_0,
+        )],
+    ),
+    capability(
+        "TransformFeedback",
+        53,
+        &[feature(
+            "VkPhysicalDeviceTransformFeedbackFeaturesEXT",
+            "transformFeedback",
+            "VK_EXT_transform_feedback",
+        )],
+    ),
+    capability(
+        "GeometryStreams",
+        54,
+        &[feature(
+            "VkPhysicalDeviceTransformFeedbackFeaturesEXT",
+            "geometryStreams",
+            "VK_EXT_transform_feedback",
+        )],
+    ),
+    capability(
+        "StorageImageReadWithoutFormat",
+        55,
+        &[
+            feature(
+                "VkPhysicalDeviceFeatures",
+                "shaderStorageImageReadWithoutFormat",
+                V1_0,
+            ),
+            Version(V1_3),
+            Ext("VK_KHR_format_feature_flags2"),
+        ],
+    ),
+    capability(
+        "StorageImageWriteWithoutFormat",
+        56,
+        &[
+            feature(
+                "VkPhysicalDeviceFeatures",
+                "shaderStorageImageWriteWithoutFormat",
+                V1_0,
+            ),
+            Version(V1_3),
+            Ext("VK_KHR_format_feature_flags2"),
+        ],
+    ),
+    capability(
+        "MultiViewport",
+        57,
+        &[feature("VkPhysicalDeviceFeatures", "multiViewport", V1_0)],
+    ),
+    capability(
+        "GroupNonUniform",
+        61,
+        &[property(
+            "VkPhysicalDeviceVulkan11Properties",
+            "subgroupSupportedOperations",
+            "VK_SUBGROUP_FEATURE_BASIC_BIT",
+            V1_1,
+        )],
+    ),
+    capability(
+        "GroupNonUniformVote",
+        62,
+        &[property(
+            "VkPhysicalDeviceVulkan11Properties",
+            "subgroupSupportedOperations",
+            "VK_SUBGROUP_FEATURE_VOTE_BIT",
+            V1_1,
+        )],
+    ),
+    capability(
+        "GroupNonUniformArithmetic",
+        63,
+        &[property(
+            "VkPhysicalDeviceVulkan11Properties",
+            "subgroupSupportedOperations",
+            "VK_SUBGROUP_FEATURE_ARITHMETIC_BIT",
+            V1_1,
+        )],
+    ),
+    capability(
+        "GroupNonUniformBallot",
+        64,
+        &[property(
+            "VkPhysicalDeviceVulkan11Properties",
+            "subgroupSupportedOperations",
+            "VK_SUBGROUP_FEATURE_BALLOT_BIT",
+            V1_1,
+        )],
+    ),
+    capability(
+        "GroupNonUniformShuffle",
+        65,
+        &[property(
+            "VkPhysicalDeviceVulkan11Properties",
+            "subgroupSupportedOperations",
+            "VK_SUBGROUP_FEATURE_SHUFFLE_BIT",
+            V1_1,
+        )],
+    ),
+    capability(
+        "GroupNonUniformShuffleRelative",
+        66,
+        &[property(
+            "VkPhysicalDeviceVulkan11Properties",
+            "subgroupSupportedOperations",
+            "VK_SUBGROUP_FEATURE_SHUFFLE_RELATIVE_BIT",
+            V1_1,
+        )],
+    ),
+    capability(
+        "GroupNonUniformClustered",
+        67,
+        &[property(
+            "VkPhysicalDeviceVulkan11Properties",
+            "subgroupSupportedOperations",
+            "VK_SUBGROUP_FEATURE_CLUSTERED_BIT",
+            V1_1,
+        )],
+    ),
+    capability(
+        "GroupNonUniformQuad",
+        68,
+        &[property(
+            "VkPhysicalDeviceVulkan11Properties",
+            "subgroupSupportedOperations",
+            "VK_SUBGROUP_FEATURE_QUAD_BIT",
+            V1_1,
+        )],
+    ),
+    capability(
+        "ShaderLayer",
+        69,
+        &[feature(
+            "VkPhysicalDeviceVulkan12Features",
+            "shaderOutputLayer",
+            V1_2,
+        )],
+    ),
+    capability(
+        "ShaderViewportIndex",
+        70,
+        &[feature(
+            "VkPhysicalDeviceVulkan12Features",
+            "shaderOutputViewportIndex",
+            V1_2,
+        )],
+    ),
+    capability(
+        "CoreBuiltinsARM",
+        4165,
+        &[feature(
+            "VkPhysicalDeviceShaderCoreBuiltinsFeaturesARM",
+            "shaderCoreBuiltins",
+            "VK_ARM_shader_core_builtins",
+        )],
+    ),
+    capability(
+        "TileImageColorReadAccessEXT",
+        4166,
+        &[feature(
+            "VkPhysicalDeviceShaderTileImageFeaturesEXT",
+            "shaderTileImageColorReadAccess",
+            "VK_EXT_shader_tile_image",
+        )],
+    ),
+    capability(
+        "TileImageDepthReadAccessEXT",
+        4167,
+        &[feature(
+            "VkPhysicalDeviceShaderTileImageFeaturesEXT",
+            "shaderTileImageDepthReadAccess",
+            "VK_EXT_shader_tile_image",
+        )],
+    ),
+    capability(
+        "TileImageStencilReadAccessEXT",
+        4168,
+        &[feature(
+            "VkPhysicalDeviceShaderTileImageFeaturesEXT",
+            "shaderTileImageStencilReadAccess",
+            "VK_EXT_shader_tile_image",
+        )],
+    ),
+    capability(
+        "FragmentShadingRateKHR",
+        4422,
+        &[
+            feature(
+                "VkPhysicalDeviceFragmentShadingRateFeaturesKHR",
+                "pipelineFragmentShadingRate",
+                "VK_KHR_fragment_shading_rate",
+            ),
+            feature(
+                "VkPhysicalDeviceFragmentShadingRateFeaturesKHR",
+                "primitiveFragmentShadingRate",
+                "VK_KHR_fragment_shading_rate",
+            ),
+            feature(
+                "VkPhysicalDeviceFragmentShadingRateFeaturesKHR",
+                "attachmentFragmentShadingRate",
+                "VK_KHR_fragment_shading_rate",
+            ),
+        ],
+    ),
+    capability(
+        "SubgroupBallotKHR",
+        4423,
+        &[Ext("VK_EXT_shader_subgroup_ballot")],
+    ),
+    capability(
+        "DrawParameters",
+        4427,
+        &[
+            feature(
+                "VkPhysicalDeviceVulkan11Features",
+                "shaderDrawParameters",
+                V1_2,
+            ),
+            feature(
+                "VkPhysicalDeviceShaderDrawParametersFeatures",
+                "shaderDrawParameters",
+                V1_1,
+            ),
+            Ext("VK_KHR_shader_draw_parameters"),
+        ],
+    ),
+    capability(
+        "WorkgroupMemoryExplicitLayoutKHR",
+        4428,
+        &[feature(
+            "VkPhysicalDeviceWorkgroupMemoryExplicitLayoutFeaturesKHR",
+            "workgroupMemoryExplicitLayout",
+            "VK_KHR_workgroup_memory_explicit_layout",
+        )],
+    ),
+    capability(
+        "WorkgroupMemoryExplicitLayout8BitAccessKHR",
+        4429,
+        &[feature(
+            "VkPhysicalDeviceWorkgroupMemoryExplicitLayoutFeaturesKHR",
+            "workgroupMemoryExplicitLayout8BitAccess",
+            "VK_KHR_workgroup_memory_explicit_layout",
+        )],
+    ),
+    capability(
+        "WorkgroupMemoryExplicitLayout16BitAccessKHR",
+        4430,
+        &[feature(
+            "VkPhysicalDeviceWorkgroupMemoryExplicitLayoutFeaturesKHR",
+            "workgroupMemoryExplicitLayout16BitAccess",
+            "VK_KHR_workgroup_memory_explicit_layout",
+        )],
+    ),
+    capability(
+        "SubgroupVoteKHR",
+        4431,
+        &[Ext("VK_EXT_shader_subgroup_vote")],
+    ),
+    capability(
+        "StorageBuffer16BitAccess",
+        4433,
+        &[
+            feature(
+                "VkPhysicalDeviceVulkan11Features",
+                "storageBuffer16BitAccess",
+                V1_2,
+            ),
+            feature(
+                "VkPhysicalDevice16BitStorageFeatures",
+                "storageBuffer16BitAccess",
+                "VK_VERSION_1_1,VK_KHR_16bit_storage",
+            ),
+        ],
+    ),
+    capability(
+        "UniformAndStorageBuffer16BitAccess",
+        4434,
+        &[
+            feature(
+                "VkPhysicalDeviceVulkan11Features",
+                "uniformAndStorageBuffer16BitAccess",
+                V1_2,
+            ),
+            feature(
+                "VkPhysicalDevice16BitStorageFeatures",
+                "uniformAndStorageBuffer16BitAccess",
+                "VK_VERSION_1_1,VK_KHR_16bit_storage",
+            ),
+        ],
+    ),
+    capability(
+        "StoragePushConstant16",
+        4435,
+        &[
+            feature(
+                "VkPhysicalDeviceVulkan11Features",
+                "storagePushConstant16",
+                V1_2,
+            ),
+            feature(
+                "VkPhysicalDevice16BitStorageFeatures",
+                "storagePushConstant16",
+                "VK_VERSION_1_1,VK_KHR_16bit_storage",
+            ),
+        ],
+    ),
+    capability(
+        "StorageInputOutput16",
+        4436,
+        &[
+            feature(
+                "VkPhysicalDeviceVulkan11Features",
+                "storageInputOutput16",
+                V1_2,
+            ),
+            feature(
+                "VkPhysicalDevice16BitStorageFeatures",
+                "storageInputOutput16",
+                "VK_VERSION_1_1,VK_KHR_16bit_storage",
+            ),
+        ],
+    ),
+    capability(
+        "DeviceGroup",
+        4437,
+        &[Version(V1_1), Ext("VK_KHR_device_group")],
+    ),
+    capability(
+        "MultiView",
+        4439,
+        &[
+            feature("VkPhysicalDeviceVulkan11Features", "multiview", V1_2),
+            feature(
+                "VkPhysicalDeviceMultiviewFeatures",
+                "multiview",
+                "VK_VERSION_1_1,VK_KHR_multiview",
+            ),
+        ],
+    ),
+    capability(
+        "VariablePointersStorageBuffer",
+        4441,
+        &[
+            feature(
+                "VkPhysicalDeviceVulkan11Features",
+                "variablePointersStorageBuffer",
+                V1_2,
+            ),
+            feature(
+                "VkPhysicalDeviceVariablePointersFeatures",
+                "variablePointersStorageBuffer",
+                "VK_VERSION_1_1,VK_KHR_variable_pointers",
+            ),
+        ],
+    ),
+    capability(
+        "VariablePointers",
+        4442,
+        &[
+            feature("VkPhysicalDeviceVulkan11Features", "variablePointers", V1_2),
+            feature(
+                "VkPhysicalDeviceVariablePointersFeatures",
+                "variablePointers",
+                "VK_VERSION_1_1,VK_KHR_variable_pointers",
+            ),
+        ],
+    ),
+    capability(
+        "SampleMaskPostDepthCoverage",
+        4447,
+        &[Ext("VK_EXT_post_depth_coverage")],
+    ),
+    capability(
+        "StorageBuffer8BitAccess",
+        4448,
+        &[
+            feature(
+                "VkPhysicalDeviceVulkan12Features",
+                "storageBuffer8BitAccess",
+                V1_2,
+            ),
+            feature(
+                "VkPhysicalDevice8BitStorageFeatures",
+                "storageBuffer8BitAccess",
+                "VK_VERSION_1_2,VK_KHR_8bit_storage",
+            ),
+        ],
+    ),
+    capability(
+        "UniformAndStorageBuffer8BitAccess",
+        4449,
+        &[
+            feature(
+                "VkPhysicalDeviceVulkan12Features",
+                "uniformAndStorageBuffer8BitAccess",
+                V1_2,
+            ),
+            feature(
+                "VkPhysicalDevice8BitStorageFeatures",
+                "uniformAndStorageBuffer8BitAccess",
+                "VK_VERSION_1_2,VK_KHR_8bit_storage",
+            ),
+        ],
+    ),
+    capability(
+        "StoragePushConstant8",
+        4450,
+        &[
+            feature(
+                "VkPhysicalDeviceVulkan12Features",
+                "storagePushConstant8",
+                V1_2,
+            ),
+            feature(
+                "VkPhysicalDevice8BitStorageFeatures",
+                "storagePushConstant8",
+                "VK_VERSION_1_2,VK_KHR_8bit_storage",
+            ),
+        ],
+    ),
+    capability(
+        "DenormPreserve",
+        4464,
+        &[
+            property(
+                "VkPhysicalDeviceVulkan12Properties",
+                "shaderDenormPreserveFloat16",
+                "VK_TRUE",
+                "VK_VERSION_1_2,VK_KHR_shader_float_controls",
+            ),
+            property(
+                "VkPhysicalDeviceVulkan12Properties",
+                "shaderDenormPreserveFloat32",
+                "VK_TRUE",
+                "VK_VERSION_1_2,VK_KHR_shader_float_controls",
+            ),
+            property(
+                "VkPhysicalDeviceVulkan12Properties",
+                "shaderDenormPreserveFloat64",
+                "VK_TRUE",
+                "VK_VERSION_1_2,VK_KHR_shader_float_controls",
+            ),
+        ],
+    ),
+    capability(
+        "DenormFlushToZero",
+        4465,
+        &[
+            property(
+                "VkPhysicalDeviceVulkan12Properties",
+                "shaderDenormFlushToZeroFloat16",
+                "VK_TRUE",
+                "VK_VERSION_1_2,VK_KHR_shader_float_controls",
+            ),
+            property(
+                "VkPhysicalDeviceVulkan12Properties",
+                "shaderDenormFlushToZeroFloat32",
+                "VK_TRUE",
+                "VK_VERSION_1_2,VK_KHR_shader_float_controls",
+            ),
+            property(
+                "VkPhysicalDeviceVulkan12Properties",
+                "shaderDenormFlushToZeroFloat64",
+                "VK_TRUE",
+                "VK_VERSION_1_2,VK_KHR_shader_float_controls",
+            ),
+        ],
+    ),
+    capability(
+        "SignedZeroInfNanPreserve",
+        4466,
+        &[
+            property(
+                "VkPhysicalDeviceVulkan12Properties",
+                "shaderSignedZeroInfNanPreserveFloat16",
+                "VK_TRUE",
+                "VK_VERSION_1_2,VK_KHR_shader_float_controls",
+            ),
+            property(
+                "VkPhysicalDeviceVulkan12Properties",
+                "shaderSignedZeroInfNanPreserveFloat32",
+                "VK_TRUE",
+                "VK_VERSION_1_2,VK_KHR_shader_float_controls",
+            ),
+            property(
+                "VkPhysicalDeviceVulkan12Properties",
+                "shaderSignedZeroInfNanPreserveFloat64",
+                "VK_TRUE",
+                "VK_VERSION_1_2,VK_KHR_shader_float_controls",
+            ),
+        ],
+    ),
+    capability(
+        "RoundingModeRTE",
+        4467,
+        &[
+            property(
+                "VkPhysicalDeviceVulkan12Properties",
+                "shaderRoundingModeRTEFloat16",
+                "VK_TRUE",
+                "VK_VERSION_1_2,VK_KHR_shader_float_controls",
+            ),
+            property(
+                "VkPhysicalDeviceVulkan12Properties",
+                "shaderRoundingModeRTEFloat32",
+                "VK_TRUE",
+                "VK_VERSION_1_2,VK_KHR_shader_float_controls",
+            ),
+            property(
+                "VkPhysicalDeviceVulkan12Properties",
+                "shaderRoundingModeRTEFloat64",
+                "VK_TRUE",
+                "VK_VERSION_1_2,VK_KHR_shader_float_controls",
+            ),
+        ],
+    ),
+    capability(
+        "RoundingModeRTZ",
+        4468,
+        &[
+            property(
+                "VkPhysicalDeviceVulkan12Properties",
+                "shaderRoundingModeRTZFloat16",
+                "VK_TRUE",
+                "VK_VERSION_1_2,VK_KHR_shader_float_controls",
+            ),
+            property(
+                "VkPhysicalDeviceVulkan12Properties",
+                "shaderRoundingModeRTZFloat32",
+                "VK_TRUE",
+                "VK_VERSION_1_2,VK_KHR_shader_float_controls",
+            ),
+            property(
+                "VkPhysicalDeviceVulkan12Properties",
+                "shaderRoundingModeRTZFloat64",
+                "VK_TRUE",
+                "VK_VERSION_1_2,VK_KHR_shader_float_controls",
+            ),
+        ],
+    ),
+    capability(
+        "RayQueryKHR",
+        4472,
+        &[feature(
+            "VkPhysicalDeviceRayQueryFeaturesKHR",
+            "rayQuery",
+            "VK_KHR_ray_query",
+        )],
+    ),
+    capability(
+        "RayTraversalPrimitiveCullingKHR",
+        4478,
+        &[
+            feature(
+                "VkPhysicalDeviceRayTracingPipelineFeaturesKHR",
+                "rayTraversalPrimitiveCulling",
+                "VK_KHR_ray_tracing_pipeline",
+            ),
+            feature(
+                "VkPhysicalDeviceRayQueryFeaturesKHR",
+                "rayQuery",
+                "VK_KHR_ray_query",
+            ),
+        ],
+    ),
+    capability(
+        "RayTracingKHR",
+        4479,
+        &[feature(
+            "VkPhysicalDeviceRayTracingPipelineFeaturesKHR",
+            "rayTracingPipeline",
+            "VK_KHR_ray_tracing_pipeline",
+        )],
+    ),
+    capability(
+        "TextureSampleWeightedQCOM",
+        4484,
+        &[feature(
+            "VkPhysicalDeviceImageProcessingFeaturesQCOM",
+            "textureSampleWeighted",
+            "VK_QCOM_image_processing",
+        )],
+    ),
+    capability(
+        "TextureBoxFilterQCOM",
+        4485,
+        &[feature(
+            "VkPhysicalDeviceImageProcessingFeaturesQCOM",
+            "textureBoxFilter",
+            "VK_QCOM_image_processing",
+        )],
+    ),
+    capability(
+        "TextureBlockMatchQCOM",
+        4486,
+        &[feature(
+            "VkPhysicalDeviceImageProcessingFeaturesQCOM",
+            "textureBlockMatch",
+            "VK_QCOM_image_processing",
+        )],
+    ),
+    capability(
+        "TextureBlockMatch2QCOM",
+        4498,
+        &[feature(
+            "VkPhysicalDeviceImageProcessing2FeaturesQCOM",
+            "textureBlockMatch2",
+            "VK_QCOM_image_processing2",
+        )],
+    ),
+    capability(
+        "ImageGatherBiasLodAMD",
+        5009,
+        &[Ext("VK_AMD_texture_gather_bias_lod")],
+    ),
+    capability(
+        "FragmentMaskAMD",
+        5010,
+        &[Ext("VK_AMD_shader_fragment_mask")],
+    ),
+    capability(
+        "StencilExportEXT",
+        5013,
+        &[Ext("VK_EXT_shader_stencil_export")],
+    ),
+    capability(
+        "ImageReadWriteLodAMD",
+        5015,
+        &[Ext("VK_AMD_shader_image_load_store_lod")],
+    ),
+    capability(
+        "Int64ImageEXT",
+        5016,
+        &[feature(
+            "VkPhysicalDeviceShaderImageAtomicInt64FeaturesEXT",
+            "shaderImageInt64Atomics",
+            "VK_EXT_shader_image_atomic_int64",
+        )],
+    ),
+    capability("ShaderClockKHR", 5055, &[Ext("VK_KHR_shader_clock")]),
+    capability(
+        "QuadControlKHR",
+        5087,
+        &[feature(
+            "VkPhysicalDeviceShaderQuadControlFeaturesKHR",
+            "shaderQuadControl",
+            "VK_KHR_shader_quad_control",
+        )],
+    ),
+    capability(
+        "SampleMaskOverrideCoverageNV",
+        5249,
+        &[Ext("VK_NV_sample_mask_override_coverage")],
+    ),
+    capability(
+        "GeometryShaderPassthroughNV",
+        5251,
+        &[Ext("VK_NV_geometry_shader_passthrough")],
+    ),
+    capability(
+        "ShaderViewportIndexLayerEXT",
+        5254,
+        &[
+            Ext("VK_EXT_shader_viewport_index_layer"),
+            Ext("VK_NV_viewport_array2"),
+        ],
+    ),
+    capability(
+        "ShaderViewportMaskNV",
+        5255,
+        &[Ext("VK_NV_viewport_array2")],
+    ),
+    capability(
+        "PerViewAttributesNV",
+        5260,
+        &[Ext("VK_NVX_multiview_per_view_attributes")],
+    ),
+    capability(
+        "FragmentFullyCoveredEXT",
+        5265,
+        &[Ext("VK_EXT_conservative_rasterization")],
+    ),
+    capability("MeshShadingNV", 5266, &[Ext("VK_NV_mesh_shader")]),
+    capability(
+        "ImageFootprintNV",
+        5282,
+        &[feature(
+            "VkPhysicalDeviceShaderImageFootprintFeaturesNV",
+            "imageFootprint",
+            "VK_NV_shader_image_footprint",
+        )],
+    ),
+    capability("MeshShadingEXT", 5283, &[Ext("VK_EXT_mesh_shader")]),
+    capability(
+        "FragmentBarycentricKHR",
+        5284,
+        &[feature(
+            "VkPhysicalDeviceFragmentShaderBarycentricFeaturesKHR",
+            "fragmentShaderBarycentric",
+            "VK_KHR_fragment_shader_barycentric,VK_NV_fragment_shader_barycentric",
+        )],
+    ),
+    capability(
+        "ComputeDerivativeGroupQuadsNV",
+        5288,
+        &[feature(
+            "VkPhysicalDeviceComputeShaderDerivativesFeaturesNV",
+            "computeDerivativeGroupQuads",
+            "VK_NV_compute_shader_derivatives",
+        )],
+    ),
+    capability(
+        "FragmentDensityEXT",
+        5291,
+        &[
+            feature(
+                "VkPhysicalDeviceFragmentDensityMapFeaturesEXT",
+                "fragmentDensityMap",
+                "VK_EXT_fragment_density_map",
+            ),
+            feature(
+                "VkPhysicalDeviceShadingRateImageFeaturesNV",
+                "shadingRateImage",
+                "VK_NV_shading_rate_image",
+            ),
+        ],
+    ),
+    capability(
+        "GroupNonUniformPartitionedNV",
+        5297,
+        &[property(
+            "VkPhysicalDeviceVulkan11Properties",
+            "subgroupSupportedOperations",
+            "VK_SUBGROUP_FEATURE_PARTITIONED_BIT_NV",
+            "VK_NV_shader_subgroup_partitioned",
+        )],
+    ),
+    capability(
+        "ShaderNonUniform",
+        5301,
+        &[Version(V1_2), Ext("VK_EXT_descriptor_indexing")],
+    ),
+    capability(
+        "RuntimeDescriptorArray",
+        5302,
+        &[
+            feature(
+                "VkPhysicalDeviceVulkan12Features",
+                "runtimeDescriptorArray",
+                V1_2,
+            ),
+            feature(
+                "VkPhysicalDeviceDescriptorIndexingFeatures",
+                "runtimeDescriptorArray",
+                "VK_VERSION_1_2,VK_EXT_descriptor_indexing",
+            ),
+        ],
+    ),
+    capability(
+        "InputAttachmentArrayDynamicIndexing",
+        5303,
+        &[
+            feature(
+                "VkPhysicalDeviceVulkan12Features",
+                "shaderInputAttachmentArrayDynamicIndexing",
+                V1_2,
+            ),
+            feature(
+                "VkPhysicalDeviceDescriptorIndexingFeatures",
+                "shaderInputAttachmentArrayDynamicIndexing",
+                "VK_VERSION_1_2,VK_EXT_descriptor_indexing",
+            ),
+        ],
+    ),
+    capability(
+        "UniformTexelBufferArrayDynamicIndexing",
+        5304,
+        &[
+            feature(
+                "VkPhysicalDeviceVulkan12Features",
+                "shaderUniformTexelBufferArrayDynamicIndexing",
+                V1_2,
+            ),
+            feature(
+                "VkPhysicalDeviceDescriptorIndexingFeatures",
+                "shaderUniformTexelBufferArrayDynamicIndexing",
+                "VK_VERSION_1_2,VK_EXT_descriptor_indexing",
+            ),
+        ],
+    ),
+    capability(
+        "StorageTexelBufferArrayDynamicIndexing",
+        5305,
+        &[
+            feature(
+                "VkPhysicalDeviceVulkan12Features",
+                "shaderStorageTexelBufferArrayDynamicIndexing",
+                V1_2,
+            ),
+            feature(
+                "VkPhysicalDeviceDescriptorIndexingFeatures",
+                "shaderStorageTexelBufferArrayDynamicIndexing",
+                "VK_VERSION_1_2,VK_EXT_descriptor_indexing",
+            ),
+        ],
+    ),
+    capability(
+        "UniformBufferArrayNonUniformIndexing",
+        5306,
+        &[
+            feature(
+                "VkPhysicalDeviceVulkan12Features",
+                "shaderUniformBufferArrayNonUniformIndexing",
+                V1_2,
+            ),
+            feature(
+                "VkPhysicalDeviceDescriptorIndexingFeatures",
+                "shaderUniformBufferArrayNonUniformIndexing",
+                "VK_VERSION_1_2,VK_EXT_descriptor_indexing",
+            ),
+        ],
+    ),
+    capability(
+        "SampledImageArrayNonUniformIndexing",
+        5307,
+        &[
+            feature(
+                "VkPhysicalDeviceVulkan12Features",
+                "shaderSampledImageArrayNonUniformIndexing",
+                V1_2,
+            ),
+            feature(
+                "VkPhysicalDeviceDescriptorIndexingFeatures",
+                "shaderSampledImageArrayNonUniformIndexing",
+                "VK_VERSION_1_2,VK_EXT_descriptor_indexing",
+            ),
+        ],
+    ),
+    capability(
+        "StorageBufferArrayNonUniformIndexing",
+        5308,
+        &[
+            feature(
+                "VkPhysicalDeviceVulkan12Features",
+                "shaderStorageBufferArrayNonUniformIndexing",
+                V1_2,
+            ),
+            feature(
+                "VkPhysicalDeviceDescriptorIndexingFeatures",
+                "shaderStorageBufferArrayNonUniformIndexing",
+                "VK_VERSION_1_2,VK_EXT_descriptor_indexing",
+            ),
+        ],
+    ),
+    capability(
+        "StorageImageArrayNonUniformIndexing",
+        5309,
+        &[
+            feature(
+                "VkPhysicalDeviceVulkan12Features",
+                "shaderStorageImageArrayNonUniformIndexing",
+                V1_2,
+            ),
+            feature(
+                "VkPhysicalDeviceDescriptorIndexingFeatures",
+                "shaderStorageImageArrayNonUniformIndexing",
+                "VK_VERSION_1_2,VK_EXT_descriptor_indexing",
+            ),
+        ],
+    ),
+    capability(
+        "InputAttachmentArrayNonUniformIndexing",
+        5310,
+        &[
+            feature(
+                "VkPhysicalDeviceVulkan12Features",
+                "shaderInputAttachmentArrayNonUniformIndexing",
+                V1_2,
+            ),
+            feature(
+                "VkPhysicalDeviceDescriptorIndexingFeatures",
+                "shaderInputAttachmentArrayNonUniformIndexing",
+                "VK_VERSION_1_2,VK_EXT_descriptor_indexing",
+            ),
+        ],
+    ),
+    capability(
+        "UniformTexelBufferArrayNonUniformIndexing",
+        5311,
+        &[
+            feature(
+                "VkPhysicalDeviceVulkan12Features",
+                "shaderUniformTexelBufferArrayNonUniformIndexing",
+                V1_2,
+            ),
+            feature(
+                "VkPhysicalDeviceDescriptorIndexingFeatures",
+                "shaderUniformTexelBufferArrayNonUniformIndexing",
+                "VK_VERSION_1_2,VK_EXT_descriptor_indexing",
+            ),
+        ],
+    ),
+    capability(
+        "StorageTexelBufferArrayNonUniformIndexing",
+        5312,
+        &[
+            feature(
+                "VkPhysicalDeviceVulkan12Features",
+                "shaderStorageTexelBufferArrayNonUniformIndexing",
+                V1_2,
+            ),
+            feature(
+                "VkPhysicalDeviceDescriptorIndexingFeatures",
+                "shaderStorageTexelBufferArrayNonUniformIndexing",
+                "VK_VERSION_1_2,VK_EXT_descriptor_indexing",
+            ),
+        ],
+    ),
+    capability(
+        "RayTracingPositionFetchKHR",
+        5336,
+        &[feature(
+            "VkPhysicalDeviceRayTracingPositionFetchFeaturesKHR",
+            "rayTracingPositionFetch",
+            "VK_KHR_ray_tracing_position_fetch",
+        )],
+    ),
+    capability("RayTracingNV", 5340, &[Ext("VK_NV_ray_tracing")]),
+    capability(
+        "RayTracingMotionBlurNV",
+        5341,
+        &[feature(
+            "VkPhysicalDeviceRayTracingMotionBlurFeaturesNV",
+            "rayTracingMotionBlur",
+            "VK_NV_ray_tracing_motion_blur",
+        )],
+    ),
+    capability(
+        "VulkanMemoryModel",
+        5345,
+        &[
+            feature(
+                "VkPhysicalDeviceVulkan12Features",
+                "vulkanMemoryModel",
+                V1_2,
+            ),
+            feature(
+                "VkPhysicalDeviceVulkanMemoryModelFeatures",
+                "vulkanMemoryModel",
+                "VK_VERSION_1_2,VK_KHR_vulkan_memory_model",
+            ),
+        ],
+    ),
+    capability(
+        "VulkanMemoryModelDeviceScope",
+        5346,
+        &[
+            feature(
+                "VkPhysicalDeviceVulkan12Features",
+                "vulkanMemoryModelDeviceScope",
+                V1_2,
+            ),
+            feature(
+                "VkPhysicalDeviceVulkanMemoryModelFeatures",
+                "vulkanMemoryModelDeviceScope",
+                "VK_VERSION_1_2,VK_KHR_vulkan_memory_model",
+            ),
+        ],
+    ),
+    capability(
+        "PhysicalStorageBufferAddresses",
+        5347,
+        &[
+            feature(
+                "VkPhysicalDeviceVulkan12Features",
+                "bufferDeviceAddress",
+                V1_2,
+            ),
+            feature(
+                "VkPhysicalDeviceBufferDeviceAddressFeatures",
+                "bufferDeviceAddress",
+                "VK_VERSION_1_2,VK_KHR_buffer_device_address",
+            ),
+            feature(
+                "VkPhysicalDeviceBufferDeviceAddressFeaturesEXT",
+                "bufferDeviceAddress",
+                "VK_EXT_buffer_device_address",
+            ),
+        ],
+    ),
+    capability(
+        "ComputeDerivativeGroupLinearNV",
+        5350,
+        &[feature(
+            "VkPhysicalDeviceComputeShaderDerivativesFeaturesNV",
+            "computeDerivativeGroupLinear",
+            "VK_NV_compute_shader_derivatives",
+        )],
+    ),
+    capability(
+        "CooperativeMatrixNV",
+        5357,
+        &[feature(
+            "VkPhysicalDeviceCooperativeMatrixFeaturesNV",
+            "cooperativeMatrix",
+            "VK_NV_cooperative_matrix",
+        )],
+    ),
+    capability(
+        "FragmentShaderSampleInterlockEXT",
+        5363,
+        &[feature(
+            "VkPhysicalDeviceFragmentShaderInterlockFeaturesEXT",
+            "fragmentShaderSampleInterlock",
+            "VK_EXT_fragment_shader_interlock",
+        )],
+    ),
+    capability(
+        "FragmentShaderShadingRateInterlockEXT",
+        5372,
+        &[
+            feature(
+                "VkPhysicalDeviceFragmentShaderInterlockFeaturesEXT",
+                "fragmentShaderShadingRateInterlock",
+                "VK_EXT_fragment_shader_interlock",
+            ),
+            feature(
+                "VkPhysicalDeviceShadingRateImageFeaturesNV",
+                "shadingRateImage",
+                "VK_NV_shading_rate_image",
+            ),
+        ],
+    ),
+    capability(
+        "ShaderSMBuiltinsNV",
+        5373,
+        &[feature(
+            "VkPhysicalDeviceShaderSMBuiltinsFeaturesNV",
+            "shaderSMBuiltins",
+            "VK_NV_shader_sm_builtins",
+        )],
+    ),
+    capability(
+        "FragmentShaderPixelInterlockEXT",
+        5378,
+        &[feature(
+            "VkPhysicalDeviceFragmentShaderInterlockFeaturesEXT",
+            "fragmentShaderPixelInterlock",
+            "VK_EXT_fragment_shader_interlock",
+        )],
+    ),
+    capability(
+        "DemoteToHelperInvocation",
+        5379,
+        &[
+            feature(
+                "VkPhysicalDeviceVulkan13Features",
+                "shaderDemoteToHelperInvocation",
+                V1_3,
+            ),
+            feature(
+                "VkPhysicalDeviceShaderDemoteToHelperInvocationFeatures",
+                "shaderDemoteToHelperInvocation",
+                "VK_VERSION_1_3,VK_EXT_shader_demote_to_helper_invocation",
+            ),
+        ],
+    ),
+    capability(
+        "DisplacementMicromapNV",
+        5380,
+        &[feature(
+            "VkPhysicalDeviceDisplacementMicromapFeaturesNV",
+            "displacementMicromap",
+            "VK_NV_displacement_micromap",
+        )],
+    ),
+    capability(
+        "RayTracingOpacityMicromapEXT",
+        5381,
+        &[Ext("VK_EXT_opacity_micromap")],
+    ),
+    capability(
+        "ShaderInvocationReorderNV",
+        5383,
+        &[Ext("VK_NV_ray_tracing_invocation_reorder")],
+    ),
+    capability(
+        "RayQueryPositionFetchKHR",
+        5391,
+        &[feature(
+            "VkPhysicalDeviceRayTracingPositionFetchFeaturesKHR",
+            "rayTracingPositionFetch",
+            "VK_KHR_ray_tracing_position_fetch",
+        )],
+    ),
+    capability(
+        "AtomicFloat16VectorNV",
+        5404,
+        &[feature(
+            "VkPhysicalDeviceShaderAtomicFloat16VectorFeaturesNV",
+            "shaderFloat16VectorAtomics",
+            "VK_NV_shader_atomic_float16_vector",
+        )],
+    ),
+    capability(
+        "RayTracingDisplacementMicromapNV",
+        5409,
+        &[feature(
+            "VkPhysicalDeviceDisplacementMicromapFeaturesNV",
+            "displacementMicromap",
+            "VK_NV_displacement_micromap",
+        )],
+    ),
+    capability(
+        "RawAccessChainsNV",
+        5414,
+        &[feature(
+            "VkPhysicalDeviceRawAccessChainsFeaturesNV",
+            "shaderRawAccessChains",
+            "VK_NV_raw_access_chains",
+        )],
+    ),
+    capability(
+        "IntegerFunctions2INTEL",
+        5584,
+        &[feature(
+            "VkPhysicalDeviceShaderIntegerFunctions2FeaturesINTEL",
+            "shaderIntegerFunctions2",
+            "VK_INTEL_shader_integer_functions2",
+        )],
+    ),
+    capability(
+        "AtomicFloat32MinMaxEXT",
+        5612,
+        &[
+            feature(
+                "VkPhysicalDeviceShaderAtomicFloat2FeaturesEXT",
+                "shaderBufferFloat32AtomicMinMax",
+                "VK_EXT_shader_atomic_float2",
+            ),
+            feature(
+                "VkPhysicalDeviceShaderAtomicFloat2FeaturesEXT",
+                "shaderSharedFloat32AtomicMinMax",
+                "VK_EXT_shader_atomic_float2",
+            ),
+            feature(
+                "VkPhysicalDeviceShaderAtomicFloat2FeaturesEXT",
+                "shaderImageFloat32AtomicMinMax",
+                "VK_EXT_shader_atomic_float2",
+            ),
+        ],
+    ),
+    capability(
+        "AtomicFloat64MinMaxEXT",
+        5613,
+        &[
+            feature(
+                "VkPhysicalDeviceShaderAtomicFloat2FeaturesEXT",
+                "shaderBufferFloat64AtomicMinMax",
+                "VK_EXT_shader_atomic_float2",
+            ),
+            feature(
+                "VkPhysicalDeviceShaderAtomicFloat2FeaturesEXT",
+                "shaderSharedFloat64AtomicMinMax",
+                "VK_EXT_shader_atomic_float2",
+            ),
+        ],
+    ),
+    capability(
+        "AtomicFloat16MinMaxEXT",
+        5616,
+        &[
+            feature(
+                "VkPhysicalDeviceShaderAtomicFloat2FeaturesEXT",
+                "shaderBufferFloat16AtomicMinMax",
+                "VK_EXT_shader_atomic_float2",
+            ),
+            feature(
+                "VkPhysicalDeviceShaderAtomicFloat2FeaturesEXT",
+                "shaderSharedFloat16AtomicMinMax",
+                "VK_EXT_shader_atomic_float2",
+            ),
+        ],
+    ),
+    capability(
+        "ExpectAssumeKHR",
+        5629,
+        &[feature(
+            "VkPhysicalDeviceShaderExpectAssumeFeaturesKHR",
+            "shaderExpectAssume",
+            "VK_KHR_shader_expect_assume",
+        )],
+    ),
+    capability(
+        "DotProductInputAll",
+        6016,
+        &[
+            feature(
+                "VkPhysicalDeviceVulkan13Features",
+                "shaderIntegerDotProduct",
+                V1_3,
+            ),
+            feature(
+                "VkPhysicalDeviceShaderIntegerDotProductFeatures",
+                "shaderIntegerDotProduct",
+                "VK_VERSION_1_3,VK_KHR_shader_integer_dot_product",
+            ),
+        ],
+    ),
+    capability(
+        "DotProductInput4x8Bit",
+        6017,
+        &[
+            feature(
+                "VkPhysicalDeviceVulkan13Features",
+                "shaderIntegerDotProduct",
+                V1_3,
+            ),
+            feature(
+                "VkPhysicalDeviceShaderIntegerDotProductFeatures",
+                "shaderIntegerDotProduct",
+                "VK_VERSION_1_3,VK_KHR_shader_integer_dot_product",
+            ),
+        ],
+    ),
+    capability(
+        "DotProductInput4x8BitPacked",
+        6018,
+        &[
+            feature(
+                "VkPhysicalDeviceVulkan13Features",
+                "shaderIntegerDotProduct",
+                V1_3,
+            ),
+            feature(
+                "VkPhysicalDeviceShaderIntegerDotProductFeatures",
+                "shaderIntegerDotProduct",
+                "VK_VERSION_1_3,VK_KHR_shader_integer_dot_product",
+            ),
+        ],
+    ),
+    capability(
+        "DotProduct",
+        6019,
+        &[
+            feature(
+                "VkPhysicalDeviceVulkan13Features",
+                "shaderIntegerDotProduct",
+                V1_3,
+            ),
+            feature(
+                "VkPhysicalDeviceShaderIntegerDotProductFeatures",
+                "shaderIntegerDotProduct",
+                "VK_VERSION_1_3,VK_KHR_shader_integer_dot_product",
+            ),
+        ],
+    ),
+    capability(
+        "RayCullMaskKHR",
+        6020,
+        &[feature(
+            "VkPhysicalDeviceRayTracingMaintenance1FeaturesKHR",
+            "rayTracingMaintenance1",
+            "VK_KHR_ray_tracing_maintenance1",
+        )],
+    ),
+    capability(
+        "CooperativeMatrixKHR",
+        6022,
+        &[feature(
+            "VkPhysicalDeviceCooperativeMatrixFeaturesKHR",
+            "cooperativeMatrix",
+            "VK_KHR_cooperative_matrix",
+        )],
+    ),
+    capability(
+        "GroupNonUniformRotateKHR",
+        6026,
+        &[feature(
+            "VkPhysicalDeviceShaderSubgroupRotateFeaturesKHR",
+            "shaderSubgroupRotate",
+            "VK_KHR_shader_subgroup_rotate",
+        )],
+    ),
+    capability(
+        "FloatControls2",
+        6029,
+        &[feature(
+            "VkPhysicalDeviceShaderFloatControls2FeaturesKHR",
+            "shaderFloatControls2",
+            "VK_KHR_shader_float_controls2",
+        )],
+    ),
+    capability(
+        "AtomicFloat32AddEXT",
+        6033,
+        &[
+            feature(
+                "VkPhysicalDeviceShaderAtomicFloatFeaturesEXT",
+                "shaderBufferFloat32AtomicAdd",
+                "VK_EXT_shader_atomic_float",
+            ),
+            feature(
+                "VkPhysicalDeviceShaderAtomicFloatFeaturesEXT",
+                "shaderSharedFloat32AtomicAdd",
+                "VK_EXT_shader_atomic_float",
+            ),
+            feature(
+                "VkPhysicalDeviceShaderAtomicFloatFeaturesEXT",
+                "shaderImageFloat32AtomicAdd",
+                "VK_EXT_shader_atomic_float",
+            ),
+        ],
+    ),
+    capability(
+        "AtomicFloat64AddEXT",
+        6034,
+        &[
+            feature(
+                "VkPhysicalDeviceShaderAtomicFloatFeaturesEXT",
+                "shaderBufferFloat64AtomicAdd",
+                "VK_EXT_shader_atomic_float",
+            ),
+            feature(
+                "VkPhysicalDeviceShaderAtomicFloatFeaturesEXT",
+                "shaderSharedFloat64AtomicAdd",
+                "VK_EXT_shader_atomic_float",
+            ),
+        ],
+    ),
+    capability(
+        "AtomicFloat16AddEXT",
+        6095,
+        &[
+            feature(
+                "VkPhysicalDeviceShaderAtomicFloat2FeaturesEXT",
+                "shaderBufferFloat16AtomicAdd",
+                "VK_EXT_shader_atomic_float2",
+            ),
+            feature(
+                "VkPhysicalDeviceShaderAtomicFloat2FeaturesEXT",
+                "shaderSharedFloat16AtomicAdd",
+                "VK_EXT_shader_atomic_float2",
+            ),
+        ],
+    ),
+];
+
+pub(super) static SPIRV_EXTENSIONS: &[SpirvExtensionInfo] = &[
+    extension(
+        "SPV_KHR_variable_pointers",
+        &[Version(V1_1), Ext("VK_KHR_variable_pointers")],
+    ),
+    extension(
+        "SPV_AMD_shader_explicit_vertex_parameter",
+        &[Ext("VK_AMD_shader_explicit_vertex_parameter")],
+    ),
+    extension("SPV_AMD_gcn_shader", &[Ext("VK_AMD_gcn_shader")]),
+    extension(
+        "SPV_AMD_gpu_shader_half_float",
+        &[Ext("VK_AMD_gpu_shader_half_float")],
+    ),
+    extension(
+        "SPV_AMD_gpu_shader_int16",
+        &[Ext("VK_AMD_gpu_shader_int16")],
+    ),
+    extension("SPV_AMD_shader_ballot", &[Ext("VK_AMD_shader_ballot")]),
+    extension(
+        "SPV_AMD_shader_fragment_mask",
+        &[Ext("VK_AMD_shader_fragment_mask")],
+    ),
+    extension(
+        "SPV_AMD_shader_image_load_store_lod",
+        &[Ext("VK_AMD_shader_image_load_store_lod")],
+    ),
+    extension(
+        "SPV_AMD_shader_trinary_minmax",
+        &[Ext("VK_AMD_shader_trinary_minmax")],
+    ),
+    extension(
+        "SPV_AMD_texture_gather_bias_lod",
+        &[Ext("VK_AMD_texture_gather_bias_lod")],
+    ),
+    extension(
+        "SPV_AMD_shader_early_and_late_fragment_tests",
+        &[Ext("VK_AMD_shader_early_and_late_fragment_tests")],
+    ),
+    extension(
+        "SPV_KHR_shader_draw_parameters",
+        &[Version(V1_1), Ext("VK_KHR_shader_draw_parameters")],
+    ),
+    extension(
+        "SPV_KHR_8bit_storage",
+        &[Version(V1_2), Ext("VK_KHR_8bit_storage")],
+    ),
+    extension(
+        "SPV_KHR_16bit_storage",
+        &[Version(V1_1), Ext("VK_KHR_16bit_storage")],
+    ),
+    extension(
+        "SPV_KHR_device_group",
+        &[Version(V1_1), Ext("VK_KHR_device_group")],
+    ),
+    extension(
+        "SPV_KHR_multiview",
+        &[Version(V1_1), Ext("VK_KHR_multiview")],
+    ),
+    extension(
+        "SPV_NVX_multiview_per_view_attributes",
+        &[Ext("VK_NVX_multiview_per_view_attributes")],
+    ),
+    extension("SPV_NV_viewport_array2", &[Ext("VK_NV_viewport_array2")]),
+    extension(
+        "SPV_NV_sample_mask_override_coverage",
+        &[Ext("VK_NV_sample_mask_override_coverage")],
+    ),
+    extension(
+        "SPV_NV_geometry_shader_passthrough",
+        &[Ext("VK_NV_geometry_shader_passthrough")],
+    ),
+    extension(
+        "SPV_KHR_storage_buffer_storage_class",
+        &[Version(V1_1), Ext("VK_KHR_storage_buffer_storage_class")],
+    ),
+    extension(
+        "SPV_KHR_post_depth_coverage",
+        &[Ext("VK_EXT_post_depth_coverage")],
+    ),
+    extension(
+        "SPV_EXT_shader_stencil_export",
+        &[Ext("VK_EXT_shader_stencil_export")],
+    ),
+    extension(
+        "SPV_KHR_shader_ballot",
+        &[Ext("VK_EXT_shader_subgroup_ballot")],
+    ),
+    extension(
+        "SPV_KHR_subgroup_vote",
+        &[Ext("VK_EXT_shader_subgroup_vote")],
+    ),
+    extension(
+        "SPV_KHR_float_controls",
+        &[Version(V1_2), Ext("VK_KHR_shader_float_controls")],
+    ),
+    extension(
+        "SPV_KHR_vulkan_memory_model",
+        &[Version(V1_2), Ext("VK_KHR_vulkan_memory_model")],
+    ),
+    extension(
+        "SPV_EXT_shader_viewport_index_layer",
+        &[Version(V1_2), Ext("VK_EXT_shader_viewport_index_layer")],
+    ),
+    extension(
+        "SPV_EXT_descriptor_indexing",
+        &[Version(V1_2), Ext("VK_EXT_descriptor_indexing")],
+    ),
+    extension(
+        "SPV_EXT_fragment_fully_covered",
+        &[Ext("VK_EXT_conservative_rasterization")],
+    ),
+    extension(
+        "SPV_KHR_no_integer_wrap_decoration",
+        &[Version(V1_2), Ext("VK_KHR_spirv_1_4")],
+    ),
+    extension(
+        "SPV_KHR_physical_storage_buffer",
+        &[Version(V1_2), Ext("VK_KHR_buffer_device_address")],
+    ),
+    extension(
+        "SPV_EXT_physical_storage_buffer",
+        &[Ext("VK_EXT_buffer_device_address")],
+    ),
+    extension(
+        "SPV_NV_compute_shader_derivatives",
+        &[Ext("VK_NV_compute_shader_derivatives")],
+    ),
+    extension(
+        "SPV_NV_fragment_shader_barycentric",
+        &[Ext("VK_NV_fragment_shader_barycentric")],
+    ),
+    extension("SPV_NV_mesh_shader", &[Ext("VK_NV_mesh_shader")]),
+    extension(
+        "SPV_NV_shader_image_footprint",
+        &[Ext("VK_NV_shader_image_footprint")],
+    ),
+    extension("SPV_NV_shading_rate", &[Ext("VK_NV_shading_rate_image")]),
+    extension("SPV_NV_ray_tracing", &[Ext("VK_NV_ray_tracing")]),
+    extension("SPV_KHR_ray_tracing", &[Ext("VK_KHR_ray_tracing_pipeline")]),
+    extension("SPV_KHR_ray_query", &[Ext("VK_KHR_ray_query")]),
+    extension(
+        "SPV_KHR_ray_cull_mask",
+        &[Ext("VK_KHR_ray_tracing_maintenance1")],
+    ),
+    extension(
+        "SPV_GOOGLE_hlsl_functionality1",
+        &[Ext("VK_GOOGLE_hlsl_functionality1")],
+    ),
+    extension("SPV_GOOGLE_user_type", &[Ext("VK_GOOGLE_user_type")]),
+    extension(
+        "SPV_GOOGLE_decorate_string",
+        &[Ext("VK_GOOGLE_decorate_string")],
+    ),
+    extension(
+        "SPV_EXT_fragment_invocation_density",
+        &[Ext("VK_EXT_fragment_density_map")],
+    ),
+    extension(
+        "SPV_NV_cooperative_matrix",
+        &[Ext("VK_NV_cooperative_matrix")],
+    ),
+    extension(
+        "SPV_NV_shader_sm_builtins",
+        &[Ext("VK_NV_shader_sm_builtins")],
+    ),
+    extension(
+        "SPV_EXT_fragment_shader_interlock",
+        &[Ext("VK_EXT_fragment_shader_interlock")],
+    ),
+    extension(
+        "SPV_EXT_demote_to_helper_invocation",
+        &[
+            Version(V1_3),
+            Ext("VK_EXT_shader_demote_to_helper_invocation"),
+        ],
+    ),
+    extension(
+        "SPV_KHR_fragment_shading_rate",
+        &[Ext("VK_KHR_fragment_shading_rate")],
+    ),
+    extension(
+        "SPV_KHR_non_semantic_info",
+        &[Version(V1_3), Ext("VK_KHR_shader_non_semantic_info")],
+    ),
+    extension(
+        "SPV_EXT_shader_image_int64",
+        &[Ext("VK_EXT_shader_image_atomic_int64")],
+    ),
+    extension(
+        "SPV_KHR_terminate_invocation",
+        &[Version(V1_3), Ext("VK_KHR_shader_terminate_invocation")],
+    ),
+    extension(
+        "SPV_KHR_workgroup_memory_explicit_layout",
+        &[Ext("VK_KHR_workgroup_memory_explicit_layout")],
+    ),
+    extension(
+        "SPV_EXT_shader_atomic_float_add",
+        &[Ext("VK_EXT_shader_atomic_float")],
+    ),
+    extension(
+        "SPV_KHR_fragment_shader_barycentric",
+        &[Ext("VK_KHR_fragment_shader_barycentric")],
+    ),
+    extension(
+        "SPV_KHR_subgroup_uniform_control_flow",
+        &[
+            Version(V1_3),
+            Ext("VK_KHR_shader_subgroup_uniform_control_flow"),
+        ],
+    ),
+    extension(
+        "SPV_EXT_shader_atomic_float_min_max",
+        &[Ext("VK_EXT_shader_atomic_float2")],
+    ),
+    extension(
+        "SPV_EXT_shader_atomic_float16_add",
+        &[Ext("VK_EXT_shader_atomic_float2")],
+    ),
+    extension(
+        "SPV_NV_shader_atomic_fp16_vector",
+        &[Ext("VK_NV_shader_atomic_float16_vector")],
+    ),
+    extension(
+        "SPV_KHR_integer_dot_product",
+        &[Version(V1_3), Ext("VK_KHR_shader_integer_dot_product")],
+    ),
+    extension(
+        "SPV_INTEL_shader_integer_functions2",
+        &[Ext("VK_INTEL_shader_integer_functions2")],
+    ),
+    extension(
+        "SPV_QCOM_image_processing",
+        &[Ext("VK_QCOM_image_processing")],
+    ),
+    extension(
+        "SPV_QCOM_image_processing2",
+        &[Ext("VK_QCOM_image_processing2")],
+    ),
+    extension("SPV_EXT_mesh_shader", &[Ext("VK_EXT_mesh_shader")]),
+    extension(
+        "SPV_KHR_ray_tracing_position_fetch",
+        &[Ext("VK_KHR_ray_tracing_position_fetch")],
+    ),
+    extension(
+        "SPV_EXT_shader_tile_image",
+        &[Ext("VK_EXT_shader_tile_image")],
+    ),
+    extension(
+        "SPV_EXT_opacity_micromap",
+        &[Ext("VK_EXT_opacity_micromap")],
+    ),
+    extension(
+        "SPV_NV_displacement_micromap",
+        &[Ext("VK_NV_displacement_micromap")],
+    ),
+    extension(
+        "SPV_KHR_cooperative_matrix",
+        &[Ext("VK_KHR_cooperative_matrix")],
+    ),
+    extension(
+        "SPV_ARM_core_builtins",
+        &[Ext("VK_ARM_shader_core_builtins")],
+    ),
+    extension(
+        "SPV_HUAWEI_cluster_culling_shader",
+        &[Ext("VK_HUAWEI_cluster_culling_shader")],
+    ),
+    extension(
+        "SPV_HUAWEI_subpass_shading",
+        &[Ext("VK_HUAWEI_subpass_shading")],
+    ),
+    extension(
+        "SPV_NV_ray_tracing_motion_blur",
+        &[Ext("VK_NV_ray_tracing_motion_blur")],
+    ),
+    extension(
+        "SPV_NV_shader_invocation_reorder",
+        &[Ext("VK_NV_ray_tracing_invocation_reorder")],
+    ),
+    extension(
+        "SPV_KHR_subgroup_rotate",
+        &[Ext("VK_KHR_shader_subgroup_rotate")],
+    ),
+    extension(
+        "SPV_KHR_expect_assume",
+        &[Ext("VK_KHR_shader_expect_assume")],
+    ),
+    extension(
+        "SPV_KHR_float_controls2",
+        &[Ext("VK_KHR_shader_float_controls2")],
+    ),
+    extension("SPV_KHR_quad_control", &[Ext("VK_KHR_shader_quad_control")]),
+    extension(
+        "SPV_KHR_maximal_reconvergence",
+        &[Ext("VK_KHR_shader_maximal_reconvergence")],
+    ),
+    extension(
+        "SPV_NV_raw_access_chains",
+        &[Ext("VK_NV_raw_access_chains")],
+    ),
+    extension("SPV_KHR_shader_clock", &[Ext("VK_KHR_shader_clock")]),
+];
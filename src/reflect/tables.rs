@@ -0,0 +1,14431 @@
+//! Reflection tables for the structs, flags and enums which can appear in profile JSON files.
+
+use std::ffi::CStr;
+use std::mem::size_of;
+
+use ash::vk;
+
+use super::{EnumInfo, FlagsInfo, MemberInfo, MemberType as T, StructCategory, StructInfo};
+
+macro_rules! member {
+    ($ty:ty, $field:ident, $name:literal, $member_type:expr) => {
+        MemberInfo {
+            name: $name,
+            offset: std::mem::offset_of!($ty, $field),
+            ty: $member_type,
+        }
+    };
+}
+
+pub(super) static STRUCTS: &[&StructInfo] = &[
+    &PHYSICAL_DEVICE_LIMITS,
+    &PHYSICAL_DEVICE_SPARSE_PROPERTIES,
+    &CONFORMANCE_VERSION,
+    &EXTENT2_D,
+    &EXTENT3_D,
+    &PHYSICAL_DEVICE_FEATURES,
+    &PHYSICAL_DEVICE16_BIT_STORAGE_FEATURES,
+    &PHYSICAL_DEVICE4444_FORMATS_FEATURES_EXT,
+    &PHYSICAL_DEVICE8_BIT_STORAGE_FEATURES,
+    &PHYSICAL_DEVICE_ASTC_DECODE_FEATURES_EXT,
+    &PHYSICAL_DEVICE_ACCELERATION_STRUCTURE_FEATURES_KHR,
+    &PHYSICAL_DEVICE_ADDRESS_BINDING_REPORT_FEATURES_EXT,
+    &PHYSICAL_DEVICE_AMIGO_PROFILING_FEATURES_SEC,
+    &PHYSICAL_DEVICE_ATTACHMENT_FEEDBACK_LOOP_DYNAMIC_STATE_FEATURES_EXT,
+    &PHYSICAL_DEVICE_ATTACHMENT_FEEDBACK_LOOP_LAYOUT_FEATURES_EXT,
+    &PHYSICAL_DEVICE_BLEND_OPERATION_ADVANCED_FEATURES_EXT,
+    &PHYSICAL_DEVICE_BORDER_COLOR_SWIZZLE_FEATURES_EXT,
+    &PHYSICAL_DEVICE_BUFFER_DEVICE_ADDRESS_FEATURES,
+    &PHYSICAL_DEVICE_BUFFER_DEVICE_ADDRESS_FEATURES_EXT,
+    &PHYSICAL_DEVICE_CLUSTER_CULLING_SHADER_FEATURES_HUAWEI,
+    &PHYSICAL_DEVICE_COHERENT_MEMORY_FEATURES_AMD,
+    &PHYSICAL_DEVICE_COLOR_WRITE_ENABLE_FEATURES_EXT,
+    &PHYSICAL_DEVICE_COMPUTE_SHADER_DERIVATIVES_FEATURES_NV,
+    &PHYSICAL_DEVICE_CONDITIONAL_RENDERING_FEATURES_EXT,
+    &PHYSICAL_DEVICE_COOPERATIVE_MATRIX_FEATURES_KHR,
+    &PHYSICAL_DEVICE_COOPERATIVE_MATRIX_FEATURES_NV,
+    &PHYSICAL_DEVICE_COPY_MEMORY_INDIRECT_FEATURES_NV,
+    &PHYSICAL_DEVICE_CORNER_SAMPLED_IMAGE_FEATURES_NV,
+    &PHYSICAL_DEVICE_COVERAGE_REDUCTION_MODE_FEATURES_NV,
+    &PHYSICAL_DEVICE_CUBIC_CLAMP_FEATURES_QCOM,
+    &PHYSICAL_DEVICE_CUBIC_WEIGHTS_FEATURES_QCOM,
+    &PHYSICAL_DEVICE_CUDA_KERNEL_LAUNCH_FEATURES_NV,
+    &PHYSICAL_DEVICE_CUSTOM_BORDER_COLOR_FEATURES_EXT,
+    &PHYSICAL_DEVICE_DEDICATED_ALLOCATION_IMAGE_ALIASING_FEATURES_NV,
+    &PHYSICAL_DEVICE_DEPTH_BIAS_CONTROL_FEATURES_EXT,
+    &PHYSICAL_DEVICE_DEPTH_CLAMP_ZERO_ONE_FEATURES_EXT,
+    &PHYSICAL_DEVICE_DEPTH_CLIP_CONTROL_FEATURES_EXT,
+    &PHYSICAL_DEVICE_DEPTH_CLIP_ENABLE_FEATURES_EXT,
+    &PHYSICAL_DEVICE_DESCRIPTOR_BUFFER_FEATURES_EXT,
+    &PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_FEATURES,
+    &PHYSICAL_DEVICE_DESCRIPTOR_POOL_OVERALLOCATION_FEATURES_NV,
+    &PHYSICAL_DEVICE_DESCRIPTOR_SET_HOST_MAPPING_FEATURES_VALVE,
+    &PHYSICAL_DEVICE_DEVICE_GENERATED_COMMANDS_COMPUTE_FEATURES_NV,
+    &PHYSICAL_DEVICE_DEVICE_GENERATED_COMMANDS_FEATURES_NV,
+    &PHYSICAL_DEVICE_DEVICE_MEMORY_REPORT_FEATURES_EXT,
+    &PHYSICAL_DEVICE_DIAGNOSTICS_CONFIG_FEATURES_NV,
+    &PHYSICAL_DEVICE_DISPLACEMENT_MICROMAP_FEATURES_NV,
+    &PHYSICAL_DEVICE_DYNAMIC_RENDERING_FEATURES,
+    &PHYSICAL_DEVICE_DYNAMIC_RENDERING_LOCAL_READ_FEATURES_KHR,
+    &PHYSICAL_DEVICE_DYNAMIC_RENDERING_UNUSED_ATTACHMENTS_FEATURES_EXT,
+    &PHYSICAL_DEVICE_EXCLUSIVE_SCISSOR_FEATURES_NV,
+    &PHYSICAL_DEVICE_EXTENDED_DYNAMIC_STATE2_FEATURES_EXT,
+    &PHYSICAL_DEVICE_EXTENDED_DYNAMIC_STATE3_FEATURES_EXT,
+    &PHYSICAL_DEVICE_EXTENDED_DYNAMIC_STATE_FEATURES_EXT,
+    &PHYSICAL_DEVICE_EXTENDED_SPARSE_ADDRESS_SPACE_FEATURES_NV,
+    &PHYSICAL_DEVICE_EXTERNAL_FORMAT_RESOLVE_FEATURES_ANDROID,
+    &PHYSICAL_DEVICE_EXTERNAL_MEMORY_RDMA_FEATURES_NV,
+    &PHYSICAL_DEVICE_EXTERNAL_MEMORY_SCREEN_BUFFER_FEATURES_QNX,
+    &PHYSICAL_DEVICE_FAULT_FEATURES_EXT,
+    &PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP2_FEATURES_EXT,
+    &PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_FEATURES_EXT,
+    &PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_OFFSET_FEATURES_QCOM,
+    &PHYSICAL_DEVICE_FRAGMENT_SHADER_BARYCENTRIC_FEATURES_KHR,
+    &PHYSICAL_DEVICE_FRAGMENT_SHADER_INTERLOCK_FEATURES_EXT,
+    &PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_ENUMS_FEATURES_NV,
+    &PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_FEATURES_KHR,
+    &PHYSICAL_DEVICE_FRAME_BOUNDARY_FEATURES_EXT,
+    &PHYSICAL_DEVICE_GLOBAL_PRIORITY_QUERY_FEATURES_KHR,
+    &PHYSICAL_DEVICE_GRAPHICS_PIPELINE_LIBRARY_FEATURES_EXT,
+    &PHYSICAL_DEVICE_HOST_IMAGE_COPY_FEATURES_EXT,
+    &PHYSICAL_DEVICE_HOST_QUERY_RESET_FEATURES,
+    &PHYSICAL_DEVICE_IMAGE2_D_VIEW_OF3_D_FEATURES_EXT,
+    &PHYSICAL_DEVICE_IMAGE_COMPRESSION_CONTROL_FEATURES_EXT,
+    &PHYSICAL_DEVICE_IMAGE_COMPRESSION_CONTROL_SWAPCHAIN_FEATURES_EXT,
+    &PHYSICAL_DEVICE_IMAGE_PROCESSING2_FEATURES_QCOM,
+    &PHYSICAL_DEVICE_IMAGE_PROCESSING_FEATURES_QCOM,
+    &PHYSICAL_DEVICE_IMAGE_ROBUSTNESS_FEATURES,
+    &PHYSICAL_DEVICE_IMAGE_SLICED_VIEW_OF3_D_FEATURES_EXT,
+    &PHYSICAL_DEVICE_IMAGE_VIEW_MIN_LOD_FEATURES_EXT,
+    &PHYSICAL_DEVICE_IMAGELESS_FRAMEBUFFER_FEATURES,
+    &PHYSICAL_DEVICE_INDEX_TYPE_UINT8_FEATURES_KHR,
+    &PHYSICAL_DEVICE_INHERITED_VIEWPORT_SCISSOR_FEATURES_NV,
+    &PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_FEATURES,
+    &PHYSICAL_DEVICE_INVOCATION_MASK_FEATURES_HUAWEI,
+    &PHYSICAL_DEVICE_LEGACY_DITHERING_FEATURES_EXT,
+    &PHYSICAL_DEVICE_LINE_RASTERIZATION_FEATURES_KHR,
+    &PHYSICAL_DEVICE_LINEAR_COLOR_ATTACHMENT_FEATURES_NV,
+    &PHYSICAL_DEVICE_MAINTENANCE4_FEATURES,
+    &PHYSICAL_DEVICE_MAINTENANCE5_FEATURES_KHR,
+    &PHYSICAL_DEVICE_MAINTENANCE6_FEATURES_KHR,
+    &PHYSICAL_DEVICE_MAP_MEMORY_PLACED_FEATURES_EXT,
+    &PHYSICAL_DEVICE_MEMORY_DECOMPRESSION_FEATURES_NV,
+    &PHYSICAL_DEVICE_MEMORY_PRIORITY_FEATURES_EXT,
+    &PHYSICAL_DEVICE_MESH_SHADER_FEATURES_EXT,
+    &PHYSICAL_DEVICE_MESH_SHADER_FEATURES_NV,
+    &PHYSICAL_DEVICE_MULTI_DRAW_FEATURES_EXT,
+    &PHYSICAL_DEVICE_MULTISAMPLED_RENDER_TO_SINGLE_SAMPLED_FEATURES_EXT,
+    &PHYSICAL_DEVICE_MULTIVIEW_FEATURES,
+    &PHYSICAL_DEVICE_MULTIVIEW_PER_VIEW_RENDER_AREAS_FEATURES_QCOM,
+    &PHYSICAL_DEVICE_MULTIVIEW_PER_VIEW_VIEWPORTS_FEATURES_QCOM,
+    &PHYSICAL_DEVICE_MUTABLE_DESCRIPTOR_TYPE_FEATURES_EXT,
+    &PHYSICAL_DEVICE_NESTED_COMMAND_BUFFER_FEATURES_EXT,
+    &PHYSICAL_DEVICE_NON_SEAMLESS_CUBE_MAP_FEATURES_EXT,
+    &PHYSICAL_DEVICE_OPACITY_MICROMAP_FEATURES_EXT,
+    &PHYSICAL_DEVICE_OPTICAL_FLOW_FEATURES_NV,
+    &PHYSICAL_DEVICE_PAGEABLE_DEVICE_LOCAL_MEMORY_FEATURES_EXT,
+    &PHYSICAL_DEVICE_PER_STAGE_DESCRIPTOR_SET_FEATURES_NV,
+    &PHYSICAL_DEVICE_PERFORMANCE_QUERY_FEATURES_KHR,
+    &PHYSICAL_DEVICE_PIPELINE_CREATION_CACHE_CONTROL_FEATURES,
+    &PHYSICAL_DEVICE_PIPELINE_EXECUTABLE_PROPERTIES_FEATURES_KHR,
+    &PHYSICAL_DEVICE_PIPELINE_LIBRARY_GROUP_HANDLES_FEATURES_EXT,
+    &PHYSICAL_DEVICE_PIPELINE_PROPERTIES_FEATURES_EXT,
+    &PHYSICAL_DEVICE_PIPELINE_PROTECTED_ACCESS_FEATURES_EXT,
+    &PHYSICAL_DEVICE_PIPELINE_ROBUSTNESS_FEATURES_EXT,
+    &PHYSICAL_DEVICE_PORTABILITY_SUBSET_FEATURES_KHR,
+    &PHYSICAL_DEVICE_PRESENT_BARRIER_FEATURES_NV,
+    &PHYSICAL_DEVICE_PRESENT_ID_FEATURES_KHR,
+    &PHYSICAL_DEVICE_PRESENT_WAIT_FEATURES_KHR,
+    &PHYSICAL_DEVICE_PRIMITIVE_TOPOLOGY_LIST_RESTART_FEATURES_EXT,
+    &PHYSICAL_DEVICE_PRIMITIVES_GENERATED_QUERY_FEATURES_EXT,
+    &PHYSICAL_DEVICE_PRIVATE_DATA_FEATURES,
+    &PHYSICAL_DEVICE_PROTECTED_MEMORY_FEATURES,
+    &PHYSICAL_DEVICE_PROVOKING_VERTEX_FEATURES_EXT,
+    &PHYSICAL_DEVICE_RGBA10_X6_FORMATS_FEATURES_EXT,
+    &PHYSICAL_DEVICE_RASTERIZATION_ORDER_ATTACHMENT_ACCESS_FEATURES_EXT,
+    &PHYSICAL_DEVICE_RAW_ACCESS_CHAINS_FEATURES_NV,
+    &PHYSICAL_DEVICE_RAY_QUERY_FEATURES_KHR,
+    &PHYSICAL_DEVICE_RAY_TRACING_INVOCATION_REORDER_FEATURES_NV,
+    &PHYSICAL_DEVICE_RAY_TRACING_MAINTENANCE1_FEATURES_KHR,
+    &PHYSICAL_DEVICE_RAY_TRACING_MOTION_BLUR_FEATURES_NV,
+    &PHYSICAL_DEVICE_RAY_TRACING_PIPELINE_FEATURES_KHR,
+    &PHYSICAL_DEVICE_RAY_TRACING_POSITION_FETCH_FEATURES_KHR,
+    &PHYSICAL_DEVICE_RAY_TRACING_VALIDATION_FEATURES_NV,
+    &PHYSICAL_DEVICE_RELAXED_LINE_RASTERIZATION_FEATURES_IMG,
+    &PHYSICAL_DEVICE_RENDER_PASS_STRIPED_FEATURES_ARM,
+    &PHYSICAL_DEVICE_REPRESENTATIVE_FRAGMENT_TEST_FEATURES_NV,
+    &PHYSICAL_DEVICE_ROBUSTNESS2_FEATURES_EXT,
+    &PHYSICAL_DEVICE_SAMPLER_YCBCR_CONVERSION_FEATURES,
+    &PHYSICAL_DEVICE_SCALAR_BLOCK_LAYOUT_FEATURES,
+    &PHYSICAL_DEVICE_SCHEDULING_CONTROLS_FEATURES_ARM,
+    &PHYSICAL_DEVICE_SEPARATE_DEPTH_STENCIL_LAYOUTS_FEATURES,
+    &PHYSICAL_DEVICE_SHADER_ATOMIC_FLOAT16_VECTOR_FEATURES_NV,
+    &PHYSICAL_DEVICE_SHADER_ATOMIC_FLOAT2_FEATURES_EXT,
+    &PHYSICAL_DEVICE_SHADER_ATOMIC_FLOAT_FEATURES_EXT,
+    &PHYSICAL_DEVICE_SHADER_ATOMIC_INT64_FEATURES,
+    &PHYSICAL_DEVICE_SHADER_CLOCK_FEATURES_KHR,
+    &PHYSICAL_DEVICE_SHADER_CORE_BUILTINS_FEATURES_ARM,
+    &PHYSICAL_DEVICE_SHADER_DEMOTE_TO_HELPER_INVOCATION_FEATURES,
+    &PHYSICAL_DEVICE_SHADER_DRAW_PARAMETERS_FEATURES,
+    &PHYSICAL_DEVICE_SHADER_EARLY_AND_LATE_FRAGMENT_TESTS_FEATURES_AMD,
+    &PHYSICAL_DEVICE_SHADER_ENQUEUE_FEATURES_AMDX,
+    &PHYSICAL_DEVICE_SHADER_EXPECT_ASSUME_FEATURES_KHR,
+    &PHYSICAL_DEVICE_SHADER_FLOAT16_INT8_FEATURES,
+    &PHYSICAL_DEVICE_SHADER_FLOAT_CONTROLS2_FEATURES_KHR,
+    &PHYSICAL_DEVICE_SHADER_IMAGE_ATOMIC_INT64_FEATURES_EXT,
+    &PHYSICAL_DEVICE_SHADER_IMAGE_FOOTPRINT_FEATURES_NV,
+    &PHYSICAL_DEVICE_SHADER_INTEGER_DOT_PRODUCT_FEATURES,
+    &PHYSICAL_DEVICE_SHADER_INTEGER_FUNCTIONS2_FEATURES_INTEL,
+    &PHYSICAL_DEVICE_SHADER_MAXIMAL_RECONVERGENCE_FEATURES_KHR,
+    &PHYSICAL_DEVICE_SHADER_MODULE_IDENTIFIER_FEATURES_EXT,
+    &PHYSICAL_DEVICE_SHADER_OBJECT_FEATURES_EXT,
+    &PHYSICAL_DEVICE_SHADER_QUAD_CONTROL_FEATURES_KHR,
+    &PHYSICAL_DEVICE_SHADER_SM_BUILTINS_FEATURES_NV,
+    &PHYSICAL_DEVICE_SHADER_SUBGROUP_EXTENDED_TYPES_FEATURES,
+    &PHYSICAL_DEVICE_SHADER_SUBGROUP_ROTATE_FEATURES_KHR,
+    &PHYSICAL_DEVICE_SHADER_SUBGROUP_UNIFORM_CONTROL_FLOW_FEATURES_KHR,
+    &PHYSICAL_DEVICE_SHADER_TERMINATE_INVOCATION_FEATURES,
+    &PHYSICAL_DEVICE_SHADER_TILE_IMAGE_FEATURES_EXT,
+    &PHYSICAL_DEVICE_SHADING_RATE_IMAGE_FEATURES_NV,
+    &PHYSICAL_DEVICE_SUBGROUP_SIZE_CONTROL_FEATURES,
+    &PHYSICAL_DEVICE_SUBPASS_MERGE_FEEDBACK_FEATURES_EXT,
+    &PHYSICAL_DEVICE_SUBPASS_SHADING_FEATURES_HUAWEI,
+    &PHYSICAL_DEVICE_SWAPCHAIN_MAINTENANCE1_FEATURES_EXT,
+    &PHYSICAL_DEVICE_SYNCHRONIZATION2_FEATURES,
+    &PHYSICAL_DEVICE_TEXEL_BUFFER_ALIGNMENT_FEATURES_EXT,
+    &PHYSICAL_DEVICE_TEXTURE_COMPRESSION_ASTCHDR_FEATURES,
+    &PHYSICAL_DEVICE_TILE_PROPERTIES_FEATURES_QCOM,
+    &PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_FEATURES,
+    &PHYSICAL_DEVICE_TRANSFORM_FEEDBACK_FEATURES_EXT,
+    &PHYSICAL_DEVICE_UNIFORM_BUFFER_STANDARD_LAYOUT_FEATURES,
+    &PHYSICAL_DEVICE_VARIABLE_POINTERS_FEATURES,
+    &PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_FEATURES_KHR,
+    &PHYSICAL_DEVICE_VERTEX_INPUT_DYNAMIC_STATE_FEATURES_EXT,
+    &PHYSICAL_DEVICE_VIDEO_MAINTENANCE1_FEATURES_KHR,
+    &PHYSICAL_DEVICE_VULKAN11_FEATURES,
+    &PHYSICAL_DEVICE_VULKAN12_FEATURES,
+    &PHYSICAL_DEVICE_VULKAN13_FEATURES,
+    &PHYSICAL_DEVICE_VULKAN_MEMORY_MODEL_FEATURES,
+    &PHYSICAL_DEVICE_WORKGROUP_MEMORY_EXPLICIT_LAYOUT_FEATURES_KHR,
+    &PHYSICAL_DEVICE_YCBCR2_PLANE444_FORMATS_FEATURES_EXT,
+    &PHYSICAL_DEVICE_YCBCR_DEGAMMA_FEATURES_QCOM,
+    &PHYSICAL_DEVICE_YCBCR_IMAGE_ARRAYS_FEATURES_EXT,
+    &PHYSICAL_DEVICE_ZERO_INITIALIZE_WORKGROUP_MEMORY_FEATURES,
+    &PHYSICAL_DEVICE_PROPERTIES,
+    &PHYSICAL_DEVICE_ACCELERATION_STRUCTURE_PROPERTIES_KHR,
+    &PHYSICAL_DEVICE_BLEND_OPERATION_ADVANCED_PROPERTIES_EXT,
+    &PHYSICAL_DEVICE_CLUSTER_CULLING_SHADER_PROPERTIES_HUAWEI,
+    &PHYSICAL_DEVICE_CONSERVATIVE_RASTERIZATION_PROPERTIES_EXT,
+    &PHYSICAL_DEVICE_COOPERATIVE_MATRIX_PROPERTIES_KHR,
+    &PHYSICAL_DEVICE_COOPERATIVE_MATRIX_PROPERTIES_NV,
+    &PHYSICAL_DEVICE_COPY_MEMORY_INDIRECT_PROPERTIES_NV,
+    &PHYSICAL_DEVICE_CUDA_KERNEL_LAUNCH_PROPERTIES_NV,
+    &PHYSICAL_DEVICE_CUSTOM_BORDER_COLOR_PROPERTIES_EXT,
+    &PHYSICAL_DEVICE_DEPTH_STENCIL_RESOLVE_PROPERTIES,
+    &PHYSICAL_DEVICE_DESCRIPTOR_BUFFER_DENSITY_MAP_PROPERTIES_EXT,
+    &PHYSICAL_DEVICE_DESCRIPTOR_BUFFER_PROPERTIES_EXT,
+    &PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_PROPERTIES,
+    &PHYSICAL_DEVICE_DEVICE_GENERATED_COMMANDS_PROPERTIES_NV,
+    &PHYSICAL_DEVICE_DISCARD_RECTANGLE_PROPERTIES_EXT,
+    &PHYSICAL_DEVICE_DISPLACEMENT_MICROMAP_PROPERTIES_NV,
+    &PHYSICAL_DEVICE_DRIVER_PROPERTIES,
+    &PHYSICAL_DEVICE_DRM_PROPERTIES_EXT,
+    &PHYSICAL_DEVICE_EXTENDED_DYNAMIC_STATE3_PROPERTIES_EXT,
+    &PHYSICAL_DEVICE_EXTENDED_SPARSE_ADDRESS_SPACE_PROPERTIES_NV,
+    &PHYSICAL_DEVICE_EXTERNAL_FORMAT_RESOLVE_PROPERTIES_ANDROID,
+    &PHYSICAL_DEVICE_EXTERNAL_MEMORY_HOST_PROPERTIES_EXT,
+    &PHYSICAL_DEVICE_FLOAT_CONTROLS_PROPERTIES,
+    &PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP2_PROPERTIES_EXT,
+    &PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_OFFSET_PROPERTIES_QCOM,
+    &PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_PROPERTIES_EXT,
+    &PHYSICAL_DEVICE_FRAGMENT_SHADER_BARYCENTRIC_PROPERTIES_KHR,
+    &PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_ENUMS_PROPERTIES_NV,
+    &PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_PROPERTIES_KHR,
+    &PHYSICAL_DEVICE_GRAPHICS_PIPELINE_LIBRARY_PROPERTIES_EXT,
+    &PHYSICAL_DEVICE_HOST_IMAGE_COPY_PROPERTIES_EXT,
+    &PHYSICAL_DEVICE_ID_PROPERTIES,
+    &PHYSICAL_DEVICE_IMAGE_PROCESSING2_PROPERTIES_QCOM,
+    &PHYSICAL_DEVICE_IMAGE_PROCESSING_PROPERTIES_QCOM,
+    &PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_PROPERTIES,
+    &PHYSICAL_DEVICE_LAYERED_DRIVER_PROPERTIES_MSFT,
+    &PHYSICAL_DEVICE_LINE_RASTERIZATION_PROPERTIES_KHR,
+    &PHYSICAL_DEVICE_MAINTENANCE3_PROPERTIES,
+    &PHYSICAL_DEVICE_MAINTENANCE4_PROPERTIES,
+    &PHYSICAL_DEVICE_MAINTENANCE5_PROPERTIES_KHR,
+    &PHYSICAL_DEVICE_MAINTENANCE6_PROPERTIES_KHR,
+    &PHYSICAL_DEVICE_MAP_MEMORY_PLACED_PROPERTIES_EXT,
+    &PHYSICAL_DEVICE_MEMORY_DECOMPRESSION_PROPERTIES_NV,
+    &PHYSICAL_DEVICE_MESH_SHADER_PROPERTIES_EXT,
+    &PHYSICAL_DEVICE_MESH_SHADER_PROPERTIES_NV,
+    &PHYSICAL_DEVICE_MULTI_DRAW_PROPERTIES_EXT,
+    &PHYSICAL_DEVICE_MULTIVIEW_PER_VIEW_ATTRIBUTES_PROPERTIES_NVX,
+    &PHYSICAL_DEVICE_MULTIVIEW_PROPERTIES,
+    &PHYSICAL_DEVICE_NESTED_COMMAND_BUFFER_PROPERTIES_EXT,
+    &PHYSICAL_DEVICE_OPACITY_MICROMAP_PROPERTIES_EXT,
+    &PHYSICAL_DEVICE_OPTICAL_FLOW_PROPERTIES_NV,
+    &PHYSICAL_DEVICE_PCI_BUS_INFO_PROPERTIES_EXT,
+    &PHYSICAL_DEVICE_PERFORMANCE_QUERY_PROPERTIES_KHR,
+    &PHYSICAL_DEVICE_PIPELINE_ROBUSTNESS_PROPERTIES_EXT,
+    &PHYSICAL_DEVICE_POINT_CLIPPING_PROPERTIES,
+    &PHYSICAL_DEVICE_PORTABILITY_SUBSET_PROPERTIES_KHR,
+    &PHYSICAL_DEVICE_PROTECTED_MEMORY_PROPERTIES,
+    &PHYSICAL_DEVICE_PROVOKING_VERTEX_PROPERTIES_EXT,
+    &PHYSICAL_DEVICE_PUSH_DESCRIPTOR_PROPERTIES_KHR,
+    &PHYSICAL_DEVICE_RAY_TRACING_INVOCATION_REORDER_PROPERTIES_NV,
+    &PHYSICAL_DEVICE_RAY_TRACING_PIPELINE_PROPERTIES_KHR,
+    &PHYSICAL_DEVICE_RAY_TRACING_PROPERTIES_NV,
+    &PHYSICAL_DEVICE_RENDER_PASS_STRIPED_PROPERTIES_ARM,
+    &PHYSICAL_DEVICE_ROBUSTNESS2_PROPERTIES_EXT,
+    &PHYSICAL_DEVICE_SAMPLE_LOCATIONS_PROPERTIES_EXT,
+    &PHYSICAL_DEVICE_SAMPLER_FILTER_MINMAX_PROPERTIES,
+    &PHYSICAL_DEVICE_SCHEDULING_CONTROLS_PROPERTIES_ARM,
+    &PHYSICAL_DEVICE_SHADER_CORE_BUILTINS_PROPERTIES_ARM,
+    &PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES2_AMD,
+    &PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES_AMD,
+    &PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES_ARM,
+    &PHYSICAL_DEVICE_SHADER_ENQUEUE_PROPERTIES_AMDX,
+    &PHYSICAL_DEVICE_SHADER_INTEGER_DOT_PRODUCT_PROPERTIES,
+    &PHYSICAL_DEVICE_SHADER_MODULE_IDENTIFIER_PROPERTIES_EXT,
+    &PHYSICAL_DEVICE_SHADER_OBJECT_PROPERTIES_EXT,
+    &PHYSICAL_DEVICE_SHADER_SM_BUILTINS_PROPERTIES_NV,
+    &PHYSICAL_DEVICE_SHADER_TILE_IMAGE_PROPERTIES_EXT,
+    &PHYSICAL_DEVICE_SHADING_RATE_IMAGE_PROPERTIES_NV,
+    &PHYSICAL_DEVICE_SUBGROUP_PROPERTIES,
+    &PHYSICAL_DEVICE_SUBGROUP_SIZE_CONTROL_PROPERTIES,
+    &PHYSICAL_DEVICE_SUBPASS_SHADING_PROPERTIES_HUAWEI,
+    &PHYSICAL_DEVICE_TEXEL_BUFFER_ALIGNMENT_PROPERTIES,
+    &PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_PROPERTIES,
+    &PHYSICAL_DEVICE_TRANSFORM_FEEDBACK_PROPERTIES_EXT,
+    &PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_PROPERTIES_EXT,
+    &PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_PROPERTIES_KHR,
+    &PHYSICAL_DEVICE_VULKAN11_PROPERTIES,
+    &PHYSICAL_DEVICE_VULKAN12_PROPERTIES,
+    &PHYSICAL_DEVICE_VULKAN13_PROPERTIES,
+    &FORMAT_PROPERTIES,
+    &DRM_FORMAT_MODIFIER_PROPERTIES_LIST2_EXT,
+    &DRM_FORMAT_MODIFIER_PROPERTIES_LIST_EXT,
+    &FORMAT_PROPERTIES3,
+    &SUBPASS_RESOLVE_PERFORMANCE_QUERY_EXT,
+    &QUEUE_FAMILY_PROPERTIES,
+    &QUEUE_FAMILY_CHECKPOINT_PROPERTIES2_NV,
+    &QUEUE_FAMILY_CHECKPOINT_PROPERTIES_NV,
+    &QUEUE_FAMILY_GLOBAL_PRIORITY_PROPERTIES_KHR,
+    &QUEUE_FAMILY_QUERY_RESULT_STATUS_PROPERTIES_KHR,
+    &QUEUE_FAMILY_VIDEO_PROPERTIES_KHR,
+];
+
+/// Alternative names of structs, usually the extension names of promoted structs.
+pub(super) static STRUCT_ALIASES: &[(&str, &str)] = &[
+    (
+        "VkPhysicalDevicePrivateDataFeaturesEXT",
+        "VkPhysicalDevicePrivateDataFeatures",
+    ),
+    ("VkConformanceVersionKHR", "VkConformanceVersion"),
+    (
+        "VkPhysicalDeviceDriverPropertiesKHR",
+        "VkPhysicalDeviceDriverProperties",
+    ),
+    (
+        "VkPhysicalDeviceVariablePointersFeaturesKHR",
+        "VkPhysicalDeviceVariablePointersFeatures",
+    ),
+    (
+        "VkPhysicalDeviceVariablePointerFeaturesKHR",
+        "VkPhysicalDeviceVariablePointersFeatures",
+    ),
+    (
+        "VkPhysicalDeviceVariablePointerFeatures",
+        "VkPhysicalDeviceVariablePointersFeatures",
+    ),
+    (
+        "VkPhysicalDeviceIDPropertiesKHR",
+        "VkPhysicalDeviceIDProperties",
+    ),
+    (
+        "VkPhysicalDeviceMultiviewFeaturesKHR",
+        "VkPhysicalDeviceMultiviewFeatures",
+    ),
+    (
+        "VkPhysicalDeviceMultiviewPropertiesKHR",
+        "VkPhysicalDeviceMultiviewProperties",
+    ),
+    (
+        "VkPhysicalDevice16BitStorageFeaturesKHR",
+        "VkPhysicalDevice16BitStorageFeatures",
+    ),
+    (
+        "VkPhysicalDeviceShaderSubgroupExtendedTypesFeaturesKHR",
+        "VkPhysicalDeviceShaderSubgroupExtendedTypesFeatures",
+    ),
+    (
+        "VkPhysicalDevicePointClippingPropertiesKHR",
+        "VkPhysicalDevicePointClippingProperties",
+    ),
+    (
+        "VkPhysicalDeviceSamplerYcbcrConversionFeaturesKHR",
+        "VkPhysicalDeviceSamplerYcbcrConversionFeatures",
+    ),
+    (
+        "VkPhysicalDeviceSamplerFilterMinmaxPropertiesEXT",
+        "VkPhysicalDeviceSamplerFilterMinmaxProperties",
+    ),
+    (
+        "VkPhysicalDeviceInlineUniformBlockFeaturesEXT",
+        "VkPhysicalDeviceInlineUniformBlockFeatures",
+    ),
+    (
+        "VkPhysicalDeviceInlineUniformBlockPropertiesEXT",
+        "VkPhysicalDeviceInlineUniformBlockProperties",
+    ),
+    (
+        "VkPhysicalDeviceMaintenance3PropertiesKHR",
+        "VkPhysicalDeviceMaintenance3Properties",
+    ),
+    (
+        "VkPhysicalDeviceMaintenance4FeaturesKHR",
+        "VkPhysicalDeviceMaintenance4Features",
+    ),
+    (
+        "VkPhysicalDeviceMaintenance4PropertiesKHR",
+        "VkPhysicalDeviceMaintenance4Properties",
+    ),
+    (
+        "VkPhysicalDeviceShaderDrawParameterFeatures",
+        "VkPhysicalDeviceShaderDrawParametersFeatures",
+    ),
+    (
+        "VkPhysicalDeviceShaderFloat16Int8FeaturesKHR",
+        "VkPhysicalDeviceShaderFloat16Int8Features",
+    ),
+    (
+        "VkPhysicalDeviceFloat16Int8FeaturesKHR",
+        "VkPhysicalDeviceShaderFloat16Int8Features",
+    ),
+    (
+        "VkPhysicalDeviceFloatControlsPropertiesKHR",
+        "VkPhysicalDeviceFloatControlsProperties",
+    ),
+    (
+        "VkPhysicalDeviceHostQueryResetFeaturesEXT",
+        "VkPhysicalDeviceHostQueryResetFeatures",
+    ),
+    (
+        "VkPhysicalDeviceGlobalPriorityQueryFeaturesEXT",
+        "VkPhysicalDeviceGlobalPriorityQueryFeaturesKHR",
+    ),
+    (
+        "VkQueueFamilyGlobalPriorityPropertiesEXT",
+        "VkQueueFamilyGlobalPriorityPropertiesKHR",
+    ),
+    (
+        "VkPhysicalDeviceDescriptorIndexingFeaturesEXT",
+        "VkPhysicalDeviceDescriptorIndexingFeatures",
+    ),
+    (
+        "VkPhysicalDeviceDescriptorIndexingPropertiesEXT",
+        "VkPhysicalDeviceDescriptorIndexingProperties",
+    ),
+    (
+        "VkPhysicalDeviceTimelineSemaphoreFeaturesKHR",
+        "VkPhysicalDeviceTimelineSemaphoreFeatures",
+    ),
+    (
+        "VkPhysicalDeviceTimelineSemaphorePropertiesKHR",
+        "VkPhysicalDeviceTimelineSemaphoreProperties",
+    ),
+    (
+        "VkPhysicalDevice8BitStorageFeaturesKHR",
+        "VkPhysicalDevice8BitStorageFeatures",
+    ),
+    (
+        "VkPhysicalDeviceVulkanMemoryModelFeaturesKHR",
+        "VkPhysicalDeviceVulkanMemoryModelFeatures",
+    ),
+    (
+        "VkPhysicalDeviceShaderAtomicInt64FeaturesKHR",
+        "VkPhysicalDeviceShaderAtomicInt64Features",
+    ),
+    (
+        "VkPhysicalDeviceVertexAttributeDivisorFeaturesEXT",
+        "VkPhysicalDeviceVertexAttributeDivisorFeaturesKHR",
+    ),
+    (
+        "VkPhysicalDeviceDepthStencilResolvePropertiesKHR",
+        "VkPhysicalDeviceDepthStencilResolveProperties",
+    ),
+    (
+        "VkPhysicalDeviceFragmentShaderBarycentricFeaturesNV",
+        "VkPhysicalDeviceFragmentShaderBarycentricFeaturesKHR",
+    ),
+    (
+        "VkPhysicalDeviceScalarBlockLayoutFeaturesEXT",
+        "VkPhysicalDeviceScalarBlockLayoutFeatures",
+    ),
+    (
+        "VkPhysicalDeviceUniformBufferStandardLayoutFeaturesKHR",
+        "VkPhysicalDeviceUniformBufferStandardLayoutFeatures",
+    ),
+    (
+        "VkPhysicalDeviceBufferDeviceAddressFeaturesKHR",
+        "VkPhysicalDeviceBufferDeviceAddressFeatures",
+    ),
+    (
+        "VkPhysicalDeviceBufferAddressFeaturesEXT",
+        "VkPhysicalDeviceBufferDeviceAddressFeaturesEXT",
+    ),
+    (
+        "VkPhysicalDeviceImagelessFramebufferFeaturesKHR",
+        "VkPhysicalDeviceImagelessFramebufferFeatures",
+    ),
+    (
+        "VkPhysicalDeviceTextureCompressionASTCHDRFeaturesEXT",
+        "VkPhysicalDeviceTextureCompressionASTCHDRFeatures",
+    ),
+    (
+        "VkPhysicalDeviceIndexTypeUint8FeaturesEXT",
+        "VkPhysicalDeviceIndexTypeUint8FeaturesKHR",
+    ),
+    (
+        "VkPhysicalDeviceSeparateDepthStencilLayoutsFeaturesKHR",
+        "VkPhysicalDeviceSeparateDepthStencilLayoutsFeatures",
+    ),
+    (
+        "VkPhysicalDeviceShaderDemoteToHelperInvocationFeaturesEXT",
+        "VkPhysicalDeviceShaderDemoteToHelperInvocationFeatures",
+    ),
+    (
+        "VkPhysicalDeviceTexelBufferAlignmentPropertiesEXT",
+        "VkPhysicalDeviceTexelBufferAlignmentProperties",
+    ),
+    (
+        "VkPhysicalDeviceSubgroupSizeControlFeaturesEXT",
+        "VkPhysicalDeviceSubgroupSizeControlFeatures",
+    ),
+    (
+        "VkPhysicalDeviceSubgroupSizeControlPropertiesEXT",
+        "VkPhysicalDeviceSubgroupSizeControlProperties",
+    ),
+    (
+        "VkPhysicalDeviceLineRasterizationFeaturesEXT",
+        "VkPhysicalDeviceLineRasterizationFeaturesKHR",
+    ),
+    (
+        "VkPhysicalDeviceLineRasterizationPropertiesEXT",
+        "VkPhysicalDeviceLineRasterizationPropertiesKHR",
+    ),
+    (
+        "VkPhysicalDevicePipelineCreationCacheControlFeaturesEXT",
+        "VkPhysicalDevicePipelineCreationCacheControlFeatures",
+    ),
+    (
+        "VkPhysicalDeviceZeroInitializeWorkgroupMemoryFeaturesKHR",
+        "VkPhysicalDeviceZeroInitializeWorkgroupMemoryFeatures",
+    ),
+    (
+        "VkPhysicalDeviceImageRobustnessFeaturesEXT",
+        "VkPhysicalDeviceImageRobustnessFeatures",
+    ),
+    (
+        "VkPhysicalDeviceShaderTerminateInvocationFeaturesKHR",
+        "VkPhysicalDeviceShaderTerminateInvocationFeatures",
+    ),
+    (
+        "VkPhysicalDeviceMutableDescriptorTypeFeaturesVALVE",
+        "VkPhysicalDeviceMutableDescriptorTypeFeaturesEXT",
+    ),
+    (
+        "VkPhysicalDeviceSynchronization2FeaturesKHR",
+        "VkPhysicalDeviceSynchronization2Features",
+    ),
+    (
+        "VkPhysicalDeviceShaderIntegerDotProductFeaturesKHR",
+        "VkPhysicalDeviceShaderIntegerDotProductFeatures",
+    ),
+    (
+        "VkPhysicalDeviceShaderIntegerDotProductPropertiesKHR",
+        "VkPhysicalDeviceShaderIntegerDotProductProperties",
+    ),
+    ("VkFormatProperties3KHR", "VkFormatProperties3"),
+    (
+        "VkPhysicalDeviceDynamicRenderingFeaturesKHR",
+        "VkPhysicalDeviceDynamicRenderingFeatures",
+    ),
+    (
+        "VkPhysicalDeviceRasterizationOrderAttachmentAccessFeaturesARM",
+        "VkPhysicalDeviceRasterizationOrderAttachmentAccessFeaturesEXT",
+    ),
+];
+
+/// The names and spec versions of all known extensions.
+pub(super) static EXTENSIONS: &[(&CStr, u32)] = &[
+    (vk::KHR_SURFACE_NAME, vk::KHR_SURFACE_SPEC_VERSION),
+    (vk::KHR_SWAPCHAIN_NAME, vk::KHR_SWAPCHAIN_SPEC_VERSION),
+    (vk::KHR_DISPLAY_NAME, vk::KHR_DISPLAY_SPEC_VERSION),
+    (
+        vk::KHR_DISPLAY_SWAPCHAIN_NAME,
+        vk::KHR_DISPLAY_SWAPCHAIN_SPEC_VERSION,
+    ),
+    (vk::KHR_XLIB_SURFACE_NAME, vk::KHR_XLIB_SURFACE_SPEC_VERSION),
+    (vk::KHR_XCB_SURFACE_NAME, vk::KHR_XCB_SURFACE_SPEC_VERSION),
+    (
+        vk::KHR_WAYLAND_SURFACE_NAME,
+        vk::KHR_WAYLAND_SURFACE_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_ANDROID_SURFACE_NAME,
+        vk::KHR_ANDROID_SURFACE_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_WIN32_SURFACE_NAME,
+        vk::KHR_WIN32_SURFACE_SPEC_VERSION,
+    ),
+    (
+        vk::ANDROID_NATIVE_BUFFER_NAME,
+        vk::ANDROID_NATIVE_BUFFER_SPEC_VERSION,
+    ),
+    (vk::EXT_DEBUG_REPORT_NAME, vk::EXT_DEBUG_REPORT_SPEC_VERSION),
+    (vk::NV_GLSL_SHADER_NAME, vk::NV_GLSL_SHADER_SPEC_VERSION),
+    (
+        vk::EXT_DEPTH_RANGE_UNRESTRICTED_NAME,
+        vk::EXT_DEPTH_RANGE_UNRESTRICTED_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_SAMPLER_MIRROR_CLAMP_TO_EDGE_NAME,
+        vk::KHR_SAMPLER_MIRROR_CLAMP_TO_EDGE_SPEC_VERSION,
+    ),
+    (vk::IMG_FILTER_CUBIC_NAME, vk::IMG_FILTER_CUBIC_SPEC_VERSION),
+    (
+        vk::AMD_RASTERIZATION_ORDER_NAME,
+        vk::AMD_RASTERIZATION_ORDER_SPEC_VERSION,
+    ),
+    (
+        vk::AMD_SHADER_TRINARY_MINMAX_NAME,
+        vk::AMD_SHADER_TRINARY_MINMAX_SPEC_VERSION,
+    ),
+    (
+        vk::AMD_SHADER_EXPLICIT_VERTEX_PARAMETER_NAME,
+        vk::AMD_SHADER_EXPLICIT_VERTEX_PARAMETER_SPEC_VERSION,
+    ),
+    (vk::EXT_DEBUG_MARKER_NAME, vk::EXT_DEBUG_MARKER_SPEC_VERSION),
+    (vk::KHR_VIDEO_QUEUE_NAME, vk::KHR_VIDEO_QUEUE_SPEC_VERSION),
+    (
+        vk::KHR_VIDEO_DECODE_QUEUE_NAME,
+        vk::KHR_VIDEO_DECODE_QUEUE_SPEC_VERSION,
+    ),
+    (vk::AMD_GCN_SHADER_NAME, vk::AMD_GCN_SHADER_SPEC_VERSION),
+    (
+        vk::NV_DEDICATED_ALLOCATION_NAME,
+        vk::NV_DEDICATED_ALLOCATION_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_TRANSFORM_FEEDBACK_NAME,
+        vk::EXT_TRANSFORM_FEEDBACK_SPEC_VERSION,
+    ),
+    (
+        vk::NVX_BINARY_IMPORT_NAME,
+        vk::NVX_BINARY_IMPORT_SPEC_VERSION,
+    ),
+    (
+        vk::NVX_IMAGE_VIEW_HANDLE_NAME,
+        vk::NVX_IMAGE_VIEW_HANDLE_SPEC_VERSION,
+    ),
+    (
+        vk::AMD_DRAW_INDIRECT_COUNT_NAME,
+        vk::AMD_DRAW_INDIRECT_COUNT_SPEC_VERSION,
+    ),
+    (
+        vk::AMD_NEGATIVE_VIEWPORT_HEIGHT_NAME,
+        vk::AMD_NEGATIVE_VIEWPORT_HEIGHT_SPEC_VERSION,
+    ),
+    (
+        vk::AMD_GPU_SHADER_HALF_FLOAT_NAME,
+        vk::AMD_GPU_SHADER_HALF_FLOAT_SPEC_VERSION,
+    ),
+    (
+        vk::AMD_SHADER_BALLOT_NAME,
+        vk::AMD_SHADER_BALLOT_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_VIDEO_ENCODE_H264_NAME,
+        vk::KHR_VIDEO_ENCODE_H264_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_VIDEO_ENCODE_H265_NAME,
+        vk::KHR_VIDEO_ENCODE_H265_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_VIDEO_DECODE_H264_NAME,
+        vk::KHR_VIDEO_DECODE_H264_SPEC_VERSION,
+    ),
+    (
+        vk::AMD_TEXTURE_GATHER_BIAS_LOD_NAME,
+        vk::AMD_TEXTURE_GATHER_BIAS_LOD_SPEC_VERSION,
+    ),
+    (vk::AMD_SHADER_INFO_NAME, vk::AMD_SHADER_INFO_SPEC_VERSION),
+    (
+        vk::KHR_DYNAMIC_RENDERING_NAME,
+        vk::KHR_DYNAMIC_RENDERING_SPEC_VERSION,
+    ),
+    (
+        vk::AMD_SHADER_IMAGE_LOAD_STORE_LOD_NAME,
+        vk::AMD_SHADER_IMAGE_LOAD_STORE_LOD_SPEC_VERSION,
+    ),
+    (
+        vk::GGP_STREAM_DESCRIPTOR_SURFACE_NAME,
+        vk::GGP_STREAM_DESCRIPTOR_SURFACE_SPEC_VERSION,
+    ),
+    (
+        vk::NV_CORNER_SAMPLED_IMAGE_NAME,
+        vk::NV_CORNER_SAMPLED_IMAGE_SPEC_VERSION,
+    ),
+    (vk::KHR_MULTIVIEW_NAME, vk::KHR_MULTIVIEW_SPEC_VERSION),
+    (vk::IMG_FORMAT_PVRTC_NAME, vk::IMG_FORMAT_PVRTC_SPEC_VERSION),
+    (
+        vk::NV_EXTERNAL_MEMORY_CAPABILITIES_NAME,
+        vk::NV_EXTERNAL_MEMORY_CAPABILITIES_SPEC_VERSION,
+    ),
+    (
+        vk::NV_EXTERNAL_MEMORY_NAME,
+        vk::NV_EXTERNAL_MEMORY_SPEC_VERSION,
+    ),
+    (
+        vk::NV_EXTERNAL_MEMORY_WIN32_NAME,
+        vk::NV_EXTERNAL_MEMORY_WIN32_SPEC_VERSION,
+    ),
+    (
+        vk::NV_WIN32_KEYED_MUTEX_NAME,
+        vk::NV_WIN32_KEYED_MUTEX_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_GET_PHYSICAL_DEVICE_PROPERTIES2_NAME,
+        vk::KHR_GET_PHYSICAL_DEVICE_PROPERTIES2_SPEC_VERSION,
+    ),
+    (vk::KHR_DEVICE_GROUP_NAME, vk::KHR_DEVICE_GROUP_SPEC_VERSION),
+    (
+        vk::EXT_VALIDATION_FLAGS_NAME,
+        vk::EXT_VALIDATION_FLAGS_SPEC_VERSION,
+    ),
+    (vk::NN_VI_SURFACE_NAME, vk::NN_VI_SURFACE_SPEC_VERSION),
+    (
+        vk::KHR_SHADER_DRAW_PARAMETERS_NAME,
+        vk::KHR_SHADER_DRAW_PARAMETERS_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_SHADER_SUBGROUP_BALLOT_NAME,
+        vk::EXT_SHADER_SUBGROUP_BALLOT_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_SHADER_SUBGROUP_VOTE_NAME,
+        vk::EXT_SHADER_SUBGROUP_VOTE_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_TEXTURE_COMPRESSION_ASTC_HDR_NAME,
+        vk::EXT_TEXTURE_COMPRESSION_ASTC_HDR_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_ASTC_DECODE_MODE_NAME,
+        vk::EXT_ASTC_DECODE_MODE_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_PIPELINE_ROBUSTNESS_NAME,
+        vk::EXT_PIPELINE_ROBUSTNESS_SPEC_VERSION,
+    ),
+    (vk::KHR_MAINTENANCE1_NAME, vk::KHR_MAINTENANCE1_SPEC_VERSION),
+    (
+        vk::KHR_DEVICE_GROUP_CREATION_NAME,
+        vk::KHR_DEVICE_GROUP_CREATION_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_EXTERNAL_MEMORY_CAPABILITIES_NAME,
+        vk::KHR_EXTERNAL_MEMORY_CAPABILITIES_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_EXTERNAL_MEMORY_NAME,
+        vk::KHR_EXTERNAL_MEMORY_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_EXTERNAL_MEMORY_WIN32_NAME,
+        vk::KHR_EXTERNAL_MEMORY_WIN32_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_EXTERNAL_MEMORY_FD_NAME,
+        vk::KHR_EXTERNAL_MEMORY_FD_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_WIN32_KEYED_MUTEX_NAME,
+        vk::KHR_WIN32_KEYED_MUTEX_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_EXTERNAL_SEMAPHORE_CAPABILITIES_NAME,
+        vk::KHR_EXTERNAL_SEMAPHORE_CAPABILITIES_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_EXTERNAL_SEMAPHORE_NAME,
+        vk::KHR_EXTERNAL_SEMAPHORE_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_EXTERNAL_SEMAPHORE_WIN32_NAME,
+        vk::KHR_EXTERNAL_SEMAPHORE_WIN32_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_EXTERNAL_SEMAPHORE_FD_NAME,
+        vk::KHR_EXTERNAL_SEMAPHORE_FD_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_PUSH_DESCRIPTOR_NAME,
+        vk::KHR_PUSH_DESCRIPTOR_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_CONDITIONAL_RENDERING_NAME,
+        vk::EXT_CONDITIONAL_RENDERING_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_SHADER_FLOAT16_INT8_NAME,
+        vk::KHR_SHADER_FLOAT16_INT8_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_16BIT_STORAGE_NAME,
+        vk::KHR_16BIT_STORAGE_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_INCREMENTAL_PRESENT_NAME,
+        vk::KHR_INCREMENTAL_PRESENT_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_DESCRIPTOR_UPDATE_TEMPLATE_NAME,
+        vk::KHR_DESCRIPTOR_UPDATE_TEMPLATE_SPEC_VERSION,
+    ),
+    (
+        vk::NV_CLIP_SPACE_W_SCALING_NAME,
+        vk::NV_CLIP_SPACE_W_SCALING_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_DIRECT_MODE_DISPLAY_NAME,
+        vk::EXT_DIRECT_MODE_DISPLAY_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_ACQUIRE_XLIB_DISPLAY_NAME,
+        vk::EXT_ACQUIRE_XLIB_DISPLAY_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_DISPLAY_SURFACE_COUNTER_NAME,
+        vk::EXT_DISPLAY_SURFACE_COUNTER_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_DISPLAY_CONTROL_NAME,
+        vk::EXT_DISPLAY_CONTROL_SPEC_VERSION,
+    ),
+    (
+        vk::GOOGLE_DISPLAY_TIMING_NAME,
+        vk::GOOGLE_DISPLAY_TIMING_SPEC_VERSION,
+    ),
+    (
+        vk::NV_SAMPLE_MASK_OVERRIDE_COVERAGE_NAME,
+        vk::NV_SAMPLE_MASK_OVERRIDE_COVERAGE_SPEC_VERSION,
+    ),
+    (
+        vk::NV_GEOMETRY_SHADER_PASSTHROUGH_NAME,
+        vk::NV_GEOMETRY_SHADER_PASSTHROUGH_SPEC_VERSION,
+    ),
+    (
+        vk::NV_VIEWPORT_ARRAY2_NAME,
+        vk::NV_VIEWPORT_ARRAY2_SPEC_VERSION,
+    ),
+    (
+        vk::NVX_MULTIVIEW_PER_VIEW_ATTRIBUTES_NAME,
+        vk::NVX_MULTIVIEW_PER_VIEW_ATTRIBUTES_SPEC_VERSION,
+    ),
+    (
+        vk::NV_VIEWPORT_SWIZZLE_NAME,
+        vk::NV_VIEWPORT_SWIZZLE_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_DISCARD_RECTANGLES_NAME,
+        vk::EXT_DISCARD_RECTANGLES_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_CONSERVATIVE_RASTERIZATION_NAME,
+        vk::EXT_CONSERVATIVE_RASTERIZATION_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_DEPTH_CLIP_ENABLE_NAME,
+        vk::EXT_DEPTH_CLIP_ENABLE_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_SWAPCHAIN_COLORSPACE_NAME,
+        vk::EXT_SWAPCHAIN_COLORSPACE_SPEC_VERSION,
+    ),
+    (vk::EXT_HDR_METADATA_NAME, vk::EXT_HDR_METADATA_SPEC_VERSION),
+    (
+        vk::KHR_IMAGELESS_FRAMEBUFFER_NAME,
+        vk::KHR_IMAGELESS_FRAMEBUFFER_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_CREATE_RENDERPASS2_NAME,
+        vk::KHR_CREATE_RENDERPASS2_SPEC_VERSION,
+    ),
+    (
+        vk::IMG_RELAXED_LINE_RASTERIZATION_NAME,
+        vk::IMG_RELAXED_LINE_RASTERIZATION_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_SHARED_PRESENTABLE_IMAGE_NAME,
+        vk::KHR_SHARED_PRESENTABLE_IMAGE_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_EXTERNAL_FENCE_CAPABILITIES_NAME,
+        vk::KHR_EXTERNAL_FENCE_CAPABILITIES_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_EXTERNAL_FENCE_NAME,
+        vk::KHR_EXTERNAL_FENCE_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_EXTERNAL_FENCE_WIN32_NAME,
+        vk::KHR_EXTERNAL_FENCE_WIN32_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_EXTERNAL_FENCE_FD_NAME,
+        vk::KHR_EXTERNAL_FENCE_FD_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_PERFORMANCE_QUERY_NAME,
+        vk::KHR_PERFORMANCE_QUERY_SPEC_VERSION,
+    ),
+    (vk::KHR_MAINTENANCE2_NAME, vk::KHR_MAINTENANCE2_SPEC_VERSION),
+    (
+        vk::KHR_GET_SURFACE_CAPABILITIES2_NAME,
+        vk::KHR_GET_SURFACE_CAPABILITIES2_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_VARIABLE_POINTERS_NAME,
+        vk::KHR_VARIABLE_POINTERS_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_GET_DISPLAY_PROPERTIES2_NAME,
+        vk::KHR_GET_DISPLAY_PROPERTIES2_SPEC_VERSION,
+    ),
+    (vk::MVK_IOS_SURFACE_NAME, vk::MVK_IOS_SURFACE_SPEC_VERSION),
+    (
+        vk::MVK_MACOS_SURFACE_NAME,
+        vk::MVK_MACOS_SURFACE_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_EXTERNAL_MEMORY_DMA_BUF_NAME,
+        vk::EXT_EXTERNAL_MEMORY_DMA_BUF_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_QUEUE_FAMILY_FOREIGN_NAME,
+        vk::EXT_QUEUE_FAMILY_FOREIGN_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_DEDICATED_ALLOCATION_NAME,
+        vk::KHR_DEDICATED_ALLOCATION_SPEC_VERSION,
+    ),
+    (vk::EXT_DEBUG_UTILS_NAME, vk::EXT_DEBUG_UTILS_SPEC_VERSION),
+    (
+        vk::ANDROID_EXTERNAL_MEMORY_ANDROID_HARDWARE_BUFFER_NAME,
+        vk::ANDROID_EXTERNAL_MEMORY_ANDROID_HARDWARE_BUFFER_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_SAMPLER_FILTER_MINMAX_NAME,
+        vk::EXT_SAMPLER_FILTER_MINMAX_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_STORAGE_BUFFER_STORAGE_CLASS_NAME,
+        vk::KHR_STORAGE_BUFFER_STORAGE_CLASS_SPEC_VERSION,
+    ),
+    (
+        vk::AMD_GPU_SHADER_INT16_NAME,
+        vk::AMD_GPU_SHADER_INT16_SPEC_VERSION,
+    ),
+    (
+        vk::AMDX_SHADER_ENQUEUE_NAME,
+        vk::AMDX_SHADER_ENQUEUE_SPEC_VERSION,
+    ),
+    (
+        vk::AMD_MIXED_ATTACHMENT_SAMPLES_NAME,
+        vk::AMD_MIXED_ATTACHMENT_SAMPLES_SPEC_VERSION,
+    ),
+    (
+        vk::AMD_SHADER_FRAGMENT_MASK_NAME,
+        vk::AMD_SHADER_FRAGMENT_MASK_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_INLINE_UNIFORM_BLOCK_NAME,
+        vk::EXT_INLINE_UNIFORM_BLOCK_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_SHADER_STENCIL_EXPORT_NAME,
+        vk::EXT_SHADER_STENCIL_EXPORT_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_SAMPLE_LOCATIONS_NAME,
+        vk::EXT_SAMPLE_LOCATIONS_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_RELAXED_BLOCK_LAYOUT_NAME,
+        vk::KHR_RELAXED_BLOCK_LAYOUT_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_GET_MEMORY_REQUIREMENTS2_NAME,
+        vk::KHR_GET_MEMORY_REQUIREMENTS2_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_IMAGE_FORMAT_LIST_NAME,
+        vk::KHR_IMAGE_FORMAT_LIST_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_BLEND_OPERATION_ADVANCED_NAME,
+        vk::EXT_BLEND_OPERATION_ADVANCED_SPEC_VERSION,
+    ),
+    (
+        vk::NV_FRAGMENT_COVERAGE_TO_COLOR_NAME,
+        vk::NV_FRAGMENT_COVERAGE_TO_COLOR_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_ACCELERATION_STRUCTURE_NAME,
+        vk::KHR_ACCELERATION_STRUCTURE_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_RAY_TRACING_PIPELINE_NAME,
+        vk::KHR_RAY_TRACING_PIPELINE_SPEC_VERSION,
+    ),
+    (vk::KHR_RAY_QUERY_NAME, vk::KHR_RAY_QUERY_SPEC_VERSION),
+    (
+        vk::NV_FRAMEBUFFER_MIXED_SAMPLES_NAME,
+        vk::NV_FRAMEBUFFER_MIXED_SAMPLES_SPEC_VERSION,
+    ),
+    (
+        vk::NV_FILL_RECTANGLE_NAME,
+        vk::NV_FILL_RECTANGLE_SPEC_VERSION,
+    ),
+    (
+        vk::NV_SHADER_SM_BUILTINS_NAME,
+        vk::NV_SHADER_SM_BUILTINS_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_POST_DEPTH_COVERAGE_NAME,
+        vk::EXT_POST_DEPTH_COVERAGE_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_SAMPLER_YCBCR_CONVERSION_NAME,
+        vk::KHR_SAMPLER_YCBCR_CONVERSION_SPEC_VERSION,
+    ),
+    (vk::KHR_BIND_MEMORY2_NAME, vk::KHR_BIND_MEMORY2_SPEC_VERSION),
+    (
+        vk::EXT_IMAGE_DRM_FORMAT_MODIFIER_NAME,
+        vk::EXT_IMAGE_DRM_FORMAT_MODIFIER_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_VALIDATION_CACHE_NAME,
+        vk::EXT_VALIDATION_CACHE_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_DESCRIPTOR_INDEXING_NAME,
+        vk::EXT_DESCRIPTOR_INDEXING_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_SHADER_VIEWPORT_INDEX_LAYER_NAME,
+        vk::EXT_SHADER_VIEWPORT_INDEX_LAYER_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_PORTABILITY_SUBSET_NAME,
+        vk::KHR_PORTABILITY_SUBSET_SPEC_VERSION,
+    ),
+    (
+        vk::NV_SHADING_RATE_IMAGE_NAME,
+        vk::NV_SHADING_RATE_IMAGE_SPEC_VERSION,
+    ),
+    (vk::NV_RAY_TRACING_NAME, vk::NV_RAY_TRACING_SPEC_VERSION),
+    (
+        vk::NV_REPRESENTATIVE_FRAGMENT_TEST_NAME,
+        vk::NV_REPRESENTATIVE_FRAGMENT_TEST_SPEC_VERSION,
+    ),
+    (vk::KHR_MAINTENANCE3_NAME, vk::KHR_MAINTENANCE3_SPEC_VERSION),
+    (
+        vk::KHR_DRAW_INDIRECT_COUNT_NAME,
+        vk::KHR_DRAW_INDIRECT_COUNT_SPEC_VERSION,
+    ),
+    (vk::EXT_FILTER_CUBIC_NAME, vk::EXT_FILTER_CUBIC_SPEC_VERSION),
+    (
+        vk::QCOM_RENDER_PASS_SHADER_RESOLVE_NAME,
+        vk::QCOM_RENDER_PASS_SHADER_RESOLVE_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_GLOBAL_PRIORITY_NAME,
+        vk::EXT_GLOBAL_PRIORITY_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_SHADER_SUBGROUP_EXTENDED_TYPES_NAME,
+        vk::KHR_SHADER_SUBGROUP_EXTENDED_TYPES_SPEC_VERSION,
+    ),
+    (vk::KHR_8BIT_STORAGE_NAME, vk::KHR_8BIT_STORAGE_SPEC_VERSION),
+    (
+        vk::EXT_EXTERNAL_MEMORY_HOST_NAME,
+        vk::EXT_EXTERNAL_MEMORY_HOST_SPEC_VERSION,
+    ),
+    (
+        vk::AMD_BUFFER_MARKER_NAME,
+        vk::AMD_BUFFER_MARKER_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_SHADER_ATOMIC_INT64_NAME,
+        vk::KHR_SHADER_ATOMIC_INT64_SPEC_VERSION,
+    ),
+    (vk::KHR_SHADER_CLOCK_NAME, vk::KHR_SHADER_CLOCK_SPEC_VERSION),
+    (
+        vk::AMD_PIPELINE_COMPILER_CONTROL_NAME,
+        vk::AMD_PIPELINE_COMPILER_CONTROL_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_CALIBRATED_TIMESTAMPS_NAME,
+        vk::EXT_CALIBRATED_TIMESTAMPS_SPEC_VERSION,
+    ),
+    (
+        vk::AMD_SHADER_CORE_PROPERTIES_NAME,
+        vk::AMD_SHADER_CORE_PROPERTIES_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_VIDEO_DECODE_H265_NAME,
+        vk::KHR_VIDEO_DECODE_H265_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_GLOBAL_PRIORITY_NAME,
+        vk::KHR_GLOBAL_PRIORITY_SPEC_VERSION,
+    ),
+    (
+        vk::AMD_MEMORY_OVERALLOCATION_BEHAVIOR_NAME,
+        vk::AMD_MEMORY_OVERALLOCATION_BEHAVIOR_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_VERTEX_ATTRIBUTE_DIVISOR_NAME,
+        vk::EXT_VERTEX_ATTRIBUTE_DIVISOR_SPEC_VERSION,
+    ),
+    (vk::GGP_FRAME_TOKEN_NAME, vk::GGP_FRAME_TOKEN_SPEC_VERSION),
+    (
+        vk::EXT_PIPELINE_CREATION_FEEDBACK_NAME,
+        vk::EXT_PIPELINE_CREATION_FEEDBACK_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_DRIVER_PROPERTIES_NAME,
+        vk::KHR_DRIVER_PROPERTIES_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_SHADER_FLOAT_CONTROLS_NAME,
+        vk::KHR_SHADER_FLOAT_CONTROLS_SPEC_VERSION,
+    ),
+    (
+        vk::NV_SHADER_SUBGROUP_PARTITIONED_NAME,
+        vk::NV_SHADER_SUBGROUP_PARTITIONED_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_DEPTH_STENCIL_RESOLVE_NAME,
+        vk::KHR_DEPTH_STENCIL_RESOLVE_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_SWAPCHAIN_MUTABLE_FORMAT_NAME,
+        vk::KHR_SWAPCHAIN_MUTABLE_FORMAT_SPEC_VERSION,
+    ),
+    (
+        vk::NV_COMPUTE_SHADER_DERIVATIVES_NAME,
+        vk::NV_COMPUTE_SHADER_DERIVATIVES_SPEC_VERSION,
+    ),
+    (vk::NV_MESH_SHADER_NAME, vk::NV_MESH_SHADER_SPEC_VERSION),
+    (
+        vk::NV_FRAGMENT_SHADER_BARYCENTRIC_NAME,
+        vk::NV_FRAGMENT_SHADER_BARYCENTRIC_SPEC_VERSION,
+    ),
+    (
+        vk::NV_SHADER_IMAGE_FOOTPRINT_NAME,
+        vk::NV_SHADER_IMAGE_FOOTPRINT_SPEC_VERSION,
+    ),
+    (
+        vk::NV_SCISSOR_EXCLUSIVE_NAME,
+        vk::NV_SCISSOR_EXCLUSIVE_SPEC_VERSION,
+    ),
+    (
+        vk::NV_DEVICE_DIAGNOSTIC_CHECKPOINTS_NAME,
+        vk::NV_DEVICE_DIAGNOSTIC_CHECKPOINTS_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_TIMELINE_SEMAPHORE_NAME,
+        vk::KHR_TIMELINE_SEMAPHORE_SPEC_VERSION,
+    ),
+    (
+        vk::INTEL_SHADER_INTEGER_FUNCTIONS2_NAME,
+        vk::INTEL_SHADER_INTEGER_FUNCTIONS2_SPEC_VERSION,
+    ),
+    (
+        vk::INTEL_PERFORMANCE_QUERY_NAME,
+        vk::INTEL_PERFORMANCE_QUERY_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_VULKAN_MEMORY_MODEL_NAME,
+        vk::KHR_VULKAN_MEMORY_MODEL_SPEC_VERSION,
+    ),
+    (vk::EXT_PCI_BUS_INFO_NAME, vk::EXT_PCI_BUS_INFO_SPEC_VERSION),
+    (
+        vk::AMD_DISPLAY_NATIVE_HDR_NAME,
+        vk::AMD_DISPLAY_NATIVE_HDR_SPEC_VERSION,
+    ),
+    (
+        vk::FUCHSIA_IMAGEPIPE_SURFACE_NAME,
+        vk::FUCHSIA_IMAGEPIPE_SURFACE_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_SHADER_TERMINATE_INVOCATION_NAME,
+        vk::KHR_SHADER_TERMINATE_INVOCATION_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_METAL_SURFACE_NAME,
+        vk::EXT_METAL_SURFACE_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_FRAGMENT_DENSITY_MAP_NAME,
+        vk::EXT_FRAGMENT_DENSITY_MAP_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_SCALAR_BLOCK_LAYOUT_NAME,
+        vk::EXT_SCALAR_BLOCK_LAYOUT_SPEC_VERSION,
+    ),
+    (
+        vk::GOOGLE_HLSL_FUNCTIONALITY1_NAME,
+        vk::GOOGLE_HLSL_FUNCTIONALITY1_SPEC_VERSION,
+    ),
+    (
+        vk::GOOGLE_DECORATE_STRING_NAME,
+        vk::GOOGLE_DECORATE_STRING_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_SUBGROUP_SIZE_CONTROL_NAME,
+        vk::EXT_SUBGROUP_SIZE_CONTROL_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_FRAGMENT_SHADING_RATE_NAME,
+        vk::KHR_FRAGMENT_SHADING_RATE_SPEC_VERSION,
+    ),
+    (
+        vk::AMD_SHADER_CORE_PROPERTIES2_NAME,
+        vk::AMD_SHADER_CORE_PROPERTIES2_SPEC_VERSION,
+    ),
+    (
+        vk::AMD_DEVICE_COHERENT_MEMORY_NAME,
+        vk::AMD_DEVICE_COHERENT_MEMORY_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_DYNAMIC_RENDERING_LOCAL_READ_NAME,
+        vk::KHR_DYNAMIC_RENDERING_LOCAL_READ_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_SHADER_IMAGE_ATOMIC_INT64_NAME,
+        vk::EXT_SHADER_IMAGE_ATOMIC_INT64_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_SHADER_QUAD_CONTROL_NAME,
+        vk::KHR_SHADER_QUAD_CONTROL_SPEC_VERSION,
+    ),
+    (vk::KHR_SPIRV_1_4_NAME, vk::KHR_SPIRV_1_4_SPEC_VERSION),
+    (
+        vk::EXT_MEMORY_BUDGET_NAME,
+        vk::EXT_MEMORY_BUDGET_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_MEMORY_PRIORITY_NAME,
+        vk::EXT_MEMORY_PRIORITY_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_SURFACE_PROTECTED_CAPABILITIES_NAME,
+        vk::KHR_SURFACE_PROTECTED_CAPABILITIES_SPEC_VERSION,
+    ),
+    (
+        vk::NV_DEDICATED_ALLOCATION_IMAGE_ALIASING_NAME,
+        vk::NV_DEDICATED_ALLOCATION_IMAGE_ALIASING_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_SEPARATE_DEPTH_STENCIL_LAYOUTS_NAME,
+        vk::KHR_SEPARATE_DEPTH_STENCIL_LAYOUTS_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_BUFFER_DEVICE_ADDRESS_NAME,
+        vk::EXT_BUFFER_DEVICE_ADDRESS_SPEC_VERSION,
+    ),
+    (vk::EXT_TOOLING_INFO_NAME, vk::EXT_TOOLING_INFO_SPEC_VERSION),
+    (
+        vk::EXT_SEPARATE_STENCIL_USAGE_NAME,
+        vk::EXT_SEPARATE_STENCIL_USAGE_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_VALIDATION_FEATURES_NAME,
+        vk::EXT_VALIDATION_FEATURES_SPEC_VERSION,
+    ),
+    (vk::KHR_PRESENT_WAIT_NAME, vk::KHR_PRESENT_WAIT_SPEC_VERSION),
+    (
+        vk::NV_COOPERATIVE_MATRIX_NAME,
+        vk::NV_COOPERATIVE_MATRIX_SPEC_VERSION,
+    ),
+    (
+        vk::NV_COVERAGE_REDUCTION_MODE_NAME,
+        vk::NV_COVERAGE_REDUCTION_MODE_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_FRAGMENT_SHADER_INTERLOCK_NAME,
+        vk::EXT_FRAGMENT_SHADER_INTERLOCK_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_YCBCR_IMAGE_ARRAYS_NAME,
+        vk::EXT_YCBCR_IMAGE_ARRAYS_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_UNIFORM_BUFFER_STANDARD_LAYOUT_NAME,
+        vk::KHR_UNIFORM_BUFFER_STANDARD_LAYOUT_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_PROVOKING_VERTEX_NAME,
+        vk::EXT_PROVOKING_VERTEX_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_FULL_SCREEN_EXCLUSIVE_NAME,
+        vk::EXT_FULL_SCREEN_EXCLUSIVE_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_HEADLESS_SURFACE_NAME,
+        vk::EXT_HEADLESS_SURFACE_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_BUFFER_DEVICE_ADDRESS_NAME,
+        vk::KHR_BUFFER_DEVICE_ADDRESS_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_LINE_RASTERIZATION_NAME,
+        vk::EXT_LINE_RASTERIZATION_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_SHADER_ATOMIC_FLOAT_NAME,
+        vk::EXT_SHADER_ATOMIC_FLOAT_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_HOST_QUERY_RESET_NAME,
+        vk::EXT_HOST_QUERY_RESET_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_INDEX_TYPE_UINT8_NAME,
+        vk::EXT_INDEX_TYPE_UINT8_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_EXTENDED_DYNAMIC_STATE_NAME,
+        vk::EXT_EXTENDED_DYNAMIC_STATE_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_DEFERRED_HOST_OPERATIONS_NAME,
+        vk::KHR_DEFERRED_HOST_OPERATIONS_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_PIPELINE_EXECUTABLE_PROPERTIES_NAME,
+        vk::KHR_PIPELINE_EXECUTABLE_PROPERTIES_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_HOST_IMAGE_COPY_NAME,
+        vk::EXT_HOST_IMAGE_COPY_SPEC_VERSION,
+    ),
+    (vk::KHR_MAP_MEMORY2_NAME, vk::KHR_MAP_MEMORY2_SPEC_VERSION),
+    (
+        vk::EXT_MAP_MEMORY_PLACED_NAME,
+        vk::EXT_MAP_MEMORY_PLACED_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_SHADER_ATOMIC_FLOAT2_NAME,
+        vk::EXT_SHADER_ATOMIC_FLOAT2_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_SURFACE_MAINTENANCE1_NAME,
+        vk::EXT_SURFACE_MAINTENANCE1_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_SWAPCHAIN_MAINTENANCE1_NAME,
+        vk::EXT_SWAPCHAIN_MAINTENANCE1_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_SHADER_DEMOTE_TO_HELPER_INVOCATION_NAME,
+        vk::EXT_SHADER_DEMOTE_TO_HELPER_INVOCATION_SPEC_VERSION,
+    ),
+    (
+        vk::NV_DEVICE_GENERATED_COMMANDS_NAME,
+        vk::NV_DEVICE_GENERATED_COMMANDS_SPEC_VERSION,
+    ),
+    (
+        vk::NV_INHERITED_VIEWPORT_SCISSOR_NAME,
+        vk::NV_INHERITED_VIEWPORT_SCISSOR_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_SHADER_INTEGER_DOT_PRODUCT_NAME,
+        vk::KHR_SHADER_INTEGER_DOT_PRODUCT_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_TEXEL_BUFFER_ALIGNMENT_NAME,
+        vk::EXT_TEXEL_BUFFER_ALIGNMENT_SPEC_VERSION,
+    ),
+    (
+        vk::QCOM_RENDER_PASS_TRANSFORM_NAME,
+        vk::QCOM_RENDER_PASS_TRANSFORM_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_DEPTH_BIAS_CONTROL_NAME,
+        vk::EXT_DEPTH_BIAS_CONTROL_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_DEVICE_MEMORY_REPORT_NAME,
+        vk::EXT_DEVICE_MEMORY_REPORT_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_ACQUIRE_DRM_DISPLAY_NAME,
+        vk::EXT_ACQUIRE_DRM_DISPLAY_SPEC_VERSION,
+    ),
+    (vk::EXT_ROBUSTNESS2_NAME, vk::EXT_ROBUSTNESS2_SPEC_VERSION),
+    (
+        vk::EXT_CUSTOM_BORDER_COLOR_NAME,
+        vk::EXT_CUSTOM_BORDER_COLOR_SPEC_VERSION,
+    ),
+    (vk::GOOGLE_USER_TYPE_NAME, vk::GOOGLE_USER_TYPE_SPEC_VERSION),
+    (
+        vk::KHR_PIPELINE_LIBRARY_NAME,
+        vk::KHR_PIPELINE_LIBRARY_SPEC_VERSION,
+    ),
+    (
+        vk::NV_PRESENT_BARRIER_NAME,
+        vk::NV_PRESENT_BARRIER_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_SHADER_NON_SEMANTIC_INFO_NAME,
+        vk::KHR_SHADER_NON_SEMANTIC_INFO_SPEC_VERSION,
+    ),
+    (vk::KHR_PRESENT_ID_NAME, vk::KHR_PRESENT_ID_SPEC_VERSION),
+    (vk::EXT_PRIVATE_DATA_NAME, vk::EXT_PRIVATE_DATA_SPEC_VERSION),
+    (
+        vk::EXT_PIPELINE_CREATION_CACHE_CONTROL_NAME,
+        vk::EXT_PIPELINE_CREATION_CACHE_CONTROL_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_VIDEO_ENCODE_QUEUE_NAME,
+        vk::KHR_VIDEO_ENCODE_QUEUE_SPEC_VERSION,
+    ),
+    (
+        vk::NV_DEVICE_DIAGNOSTICS_CONFIG_NAME,
+        vk::NV_DEVICE_DIAGNOSTICS_CONFIG_SPEC_VERSION,
+    ),
+    (
+        vk::QCOM_RENDER_PASS_STORE_OPS_NAME,
+        vk::QCOM_RENDER_PASS_STORE_OPS_SPEC_VERSION,
+    ),
+    (
+        vk::NV_CUDA_KERNEL_LAUNCH_NAME,
+        vk::NV_CUDA_KERNEL_LAUNCH_SPEC_VERSION,
+    ),
+    (vk::NV_LOW_LATENCY_NAME, vk::NV_LOW_LATENCY_SPEC_VERSION),
+    (
+        vk::EXT_METAL_OBJECTS_NAME,
+        vk::EXT_METAL_OBJECTS_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_SYNCHRONIZATION2_NAME,
+        vk::KHR_SYNCHRONIZATION2_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_DESCRIPTOR_BUFFER_NAME,
+        vk::EXT_DESCRIPTOR_BUFFER_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_GRAPHICS_PIPELINE_LIBRARY_NAME,
+        vk::EXT_GRAPHICS_PIPELINE_LIBRARY_SPEC_VERSION,
+    ),
+    (
+        vk::AMD_SHADER_EARLY_AND_LATE_FRAGMENT_TESTS_NAME,
+        vk::AMD_SHADER_EARLY_AND_LATE_FRAGMENT_TESTS_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_FRAGMENT_SHADER_BARYCENTRIC_NAME,
+        vk::KHR_FRAGMENT_SHADER_BARYCENTRIC_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_SHADER_SUBGROUP_UNIFORM_CONTROL_FLOW_NAME,
+        vk::KHR_SHADER_SUBGROUP_UNIFORM_CONTROL_FLOW_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_ZERO_INITIALIZE_WORKGROUP_MEMORY_NAME,
+        vk::KHR_ZERO_INITIALIZE_WORKGROUP_MEMORY_SPEC_VERSION,
+    ),
+    (
+        vk::NV_FRAGMENT_SHADING_RATE_ENUMS_NAME,
+        vk::NV_FRAGMENT_SHADING_RATE_ENUMS_SPEC_VERSION,
+    ),
+    (
+        vk::NV_RAY_TRACING_MOTION_BLUR_NAME,
+        vk::NV_RAY_TRACING_MOTION_BLUR_SPEC_VERSION,
+    ),
+    (vk::EXT_MESH_SHADER_NAME, vk::EXT_MESH_SHADER_SPEC_VERSION),
+    (
+        vk::EXT_YCBCR_2PLANE_444_FORMATS_NAME,
+        vk::EXT_YCBCR_2PLANE_444_FORMATS_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_FRAGMENT_DENSITY_MAP2_NAME,
+        vk::EXT_FRAGMENT_DENSITY_MAP2_SPEC_VERSION,
+    ),
+    (
+        vk::QCOM_ROTATED_COPY_COMMANDS_NAME,
+        vk::QCOM_ROTATED_COPY_COMMANDS_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_IMAGE_ROBUSTNESS_NAME,
+        vk::EXT_IMAGE_ROBUSTNESS_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_WORKGROUP_MEMORY_EXPLICIT_LAYOUT_NAME,
+        vk::KHR_WORKGROUP_MEMORY_EXPLICIT_LAYOUT_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_COPY_COMMANDS2_NAME,
+        vk::KHR_COPY_COMMANDS2_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_IMAGE_COMPRESSION_CONTROL_NAME,
+        vk::EXT_IMAGE_COMPRESSION_CONTROL_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_ATTACHMENT_FEEDBACK_LOOP_LAYOUT_NAME,
+        vk::EXT_ATTACHMENT_FEEDBACK_LOOP_LAYOUT_SPEC_VERSION,
+    ),
+    (vk::EXT_4444_FORMATS_NAME, vk::EXT_4444_FORMATS_SPEC_VERSION),
+    (vk::EXT_DEVICE_FAULT_NAME, vk::EXT_DEVICE_FAULT_SPEC_VERSION),
+    (
+        vk::ARM_RASTERIZATION_ORDER_ATTACHMENT_ACCESS_NAME,
+        vk::ARM_RASTERIZATION_ORDER_ATTACHMENT_ACCESS_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_RGBA10X6_FORMATS_NAME,
+        vk::EXT_RGBA10X6_FORMATS_SPEC_VERSION,
+    ),
+    (
+        vk::NV_ACQUIRE_WINRT_DISPLAY_NAME,
+        vk::NV_ACQUIRE_WINRT_DISPLAY_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_DIRECTFB_SURFACE_NAME,
+        vk::EXT_DIRECTFB_SURFACE_SPEC_VERSION,
+    ),
+    (
+        vk::VALVE_MUTABLE_DESCRIPTOR_TYPE_NAME,
+        vk::VALVE_MUTABLE_DESCRIPTOR_TYPE_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_VERTEX_INPUT_DYNAMIC_STATE_NAME,
+        vk::EXT_VERTEX_INPUT_DYNAMIC_STATE_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_PHYSICAL_DEVICE_DRM_NAME,
+        vk::EXT_PHYSICAL_DEVICE_DRM_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_DEVICE_ADDRESS_BINDING_REPORT_NAME,
+        vk::EXT_DEVICE_ADDRESS_BINDING_REPORT_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_DEPTH_CLIP_CONTROL_NAME,
+        vk::EXT_DEPTH_CLIP_CONTROL_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_PRIMITIVE_TOPOLOGY_LIST_RESTART_NAME,
+        vk::EXT_PRIMITIVE_TOPOLOGY_LIST_RESTART_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_FORMAT_FEATURE_FLAGS2_NAME,
+        vk::KHR_FORMAT_FEATURE_FLAGS2_SPEC_VERSION,
+    ),
+    (
+        vk::FUCHSIA_EXTERNAL_MEMORY_NAME,
+        vk::FUCHSIA_EXTERNAL_MEMORY_SPEC_VERSION,
+    ),
+    (
+        vk::FUCHSIA_EXTERNAL_SEMAPHORE_NAME,
+        vk::FUCHSIA_EXTERNAL_SEMAPHORE_SPEC_VERSION,
+    ),
+    (
+        vk::FUCHSIA_BUFFER_COLLECTION_NAME,
+        vk::FUCHSIA_BUFFER_COLLECTION_SPEC_VERSION,
+    ),
+    (
+        vk::HUAWEI_SUBPASS_SHADING_NAME,
+        vk::HUAWEI_SUBPASS_SHADING_SPEC_VERSION,
+    ),
+    (
+        vk::HUAWEI_INVOCATION_MASK_NAME,
+        vk::HUAWEI_INVOCATION_MASK_SPEC_VERSION,
+    ),
+    (
+        vk::NV_EXTERNAL_MEMORY_RDMA_NAME,
+        vk::NV_EXTERNAL_MEMORY_RDMA_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_PIPELINE_PROPERTIES_NAME,
+        vk::EXT_PIPELINE_PROPERTIES_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_FRAME_BOUNDARY_NAME,
+        vk::EXT_FRAME_BOUNDARY_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_MULTISAMPLED_RENDER_TO_SINGLE_SAMPLED_NAME,
+        vk::EXT_MULTISAMPLED_RENDER_TO_SINGLE_SAMPLED_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_EXTENDED_DYNAMIC_STATE2_NAME,
+        vk::EXT_EXTENDED_DYNAMIC_STATE2_SPEC_VERSION,
+    ),
+    (
+        vk::QNX_SCREEN_SURFACE_NAME,
+        vk::QNX_SCREEN_SURFACE_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_COLOR_WRITE_ENABLE_NAME,
+        vk::EXT_COLOR_WRITE_ENABLE_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_PRIMITIVES_GENERATED_QUERY_NAME,
+        vk::EXT_PRIMITIVES_GENERATED_QUERY_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_RAY_TRACING_MAINTENANCE1_NAME,
+        vk::KHR_RAY_TRACING_MAINTENANCE1_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_GLOBAL_PRIORITY_QUERY_NAME,
+        vk::EXT_GLOBAL_PRIORITY_QUERY_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_IMAGE_VIEW_MIN_LOD_NAME,
+        vk::EXT_IMAGE_VIEW_MIN_LOD_SPEC_VERSION,
+    ),
+    (vk::EXT_MULTI_DRAW_NAME, vk::EXT_MULTI_DRAW_SPEC_VERSION),
+    (
+        vk::EXT_IMAGE_2D_VIEW_OF_3D_NAME,
+        vk::EXT_IMAGE_2D_VIEW_OF_3D_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_PORTABILITY_ENUMERATION_NAME,
+        vk::KHR_PORTABILITY_ENUMERATION_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_SHADER_TILE_IMAGE_NAME,
+        vk::EXT_SHADER_TILE_IMAGE_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_OPACITY_MICROMAP_NAME,
+        vk::EXT_OPACITY_MICROMAP_SPEC_VERSION,
+    ),
+    (
+        vk::NV_DISPLACEMENT_MICROMAP_NAME,
+        vk::NV_DISPLACEMENT_MICROMAP_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_LOAD_STORE_OP_NONE_NAME,
+        vk::EXT_LOAD_STORE_OP_NONE_SPEC_VERSION,
+    ),
+    (
+        vk::HUAWEI_CLUSTER_CULLING_SHADER_NAME,
+        vk::HUAWEI_CLUSTER_CULLING_SHADER_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_BORDER_COLOR_SWIZZLE_NAME,
+        vk::EXT_BORDER_COLOR_SWIZZLE_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_PAGEABLE_DEVICE_LOCAL_MEMORY_NAME,
+        vk::EXT_PAGEABLE_DEVICE_LOCAL_MEMORY_SPEC_VERSION,
+    ),
+    (vk::KHR_MAINTENANCE4_NAME, vk::KHR_MAINTENANCE4_SPEC_VERSION),
+    (
+        vk::ARM_SHADER_CORE_PROPERTIES_NAME,
+        vk::ARM_SHADER_CORE_PROPERTIES_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_SHADER_SUBGROUP_ROTATE_NAME,
+        vk::KHR_SHADER_SUBGROUP_ROTATE_SPEC_VERSION,
+    ),
+    (
+        vk::ARM_SCHEDULING_CONTROLS_NAME,
+        vk::ARM_SCHEDULING_CONTROLS_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_IMAGE_SLICED_VIEW_OF_3D_NAME,
+        vk::EXT_IMAGE_SLICED_VIEW_OF_3D_SPEC_VERSION,
+    ),
+    (
+        vk::VALVE_DESCRIPTOR_SET_HOST_MAPPING_NAME,
+        vk::VALVE_DESCRIPTOR_SET_HOST_MAPPING_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_DEPTH_CLAMP_ZERO_ONE_NAME,
+        vk::EXT_DEPTH_CLAMP_ZERO_ONE_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_NON_SEAMLESS_CUBE_MAP_NAME,
+        vk::EXT_NON_SEAMLESS_CUBE_MAP_SPEC_VERSION,
+    ),
+    (
+        vk::ARM_RENDER_PASS_STRIPED_NAME,
+        vk::ARM_RENDER_PASS_STRIPED_SPEC_VERSION,
+    ),
+    (
+        vk::QCOM_FRAGMENT_DENSITY_MAP_OFFSET_NAME,
+        vk::QCOM_FRAGMENT_DENSITY_MAP_OFFSET_SPEC_VERSION,
+    ),
+    (
+        vk::NV_COPY_MEMORY_INDIRECT_NAME,
+        vk::NV_COPY_MEMORY_INDIRECT_SPEC_VERSION,
+    ),
+    (
+        vk::NV_MEMORY_DECOMPRESSION_NAME,
+        vk::NV_MEMORY_DECOMPRESSION_SPEC_VERSION,
+    ),
+    (
+        vk::NV_DEVICE_GENERATED_COMMANDS_COMPUTE_NAME,
+        vk::NV_DEVICE_GENERATED_COMMANDS_COMPUTE_SPEC_VERSION,
+    ),
+    (
+        vk::NV_LINEAR_COLOR_ATTACHMENT_NAME,
+        vk::NV_LINEAR_COLOR_ATTACHMENT_SPEC_VERSION,
+    ),
+    (
+        vk::GOOGLE_SURFACELESS_QUERY_NAME,
+        vk::GOOGLE_SURFACELESS_QUERY_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_SHADER_MAXIMAL_RECONVERGENCE_NAME,
+        vk::KHR_SHADER_MAXIMAL_RECONVERGENCE_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_IMAGE_COMPRESSION_CONTROL_SWAPCHAIN_NAME,
+        vk::EXT_IMAGE_COMPRESSION_CONTROL_SWAPCHAIN_SPEC_VERSION,
+    ),
+    (
+        vk::QCOM_IMAGE_PROCESSING_NAME,
+        vk::QCOM_IMAGE_PROCESSING_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_NESTED_COMMAND_BUFFER_NAME,
+        vk::EXT_NESTED_COMMAND_BUFFER_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_EXTERNAL_MEMORY_ACQUIRE_UNMODIFIED_NAME,
+        vk::EXT_EXTERNAL_MEMORY_ACQUIRE_UNMODIFIED_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_EXTENDED_DYNAMIC_STATE3_NAME,
+        vk::EXT_EXTENDED_DYNAMIC_STATE3_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_SUBPASS_MERGE_FEEDBACK_NAME,
+        vk::EXT_SUBPASS_MERGE_FEEDBACK_SPEC_VERSION,
+    ),
+    (
+        vk::LUNARG_DIRECT_DRIVER_LOADING_NAME,
+        vk::LUNARG_DIRECT_DRIVER_LOADING_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_SHADER_MODULE_IDENTIFIER_NAME,
+        vk::EXT_SHADER_MODULE_IDENTIFIER_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_RASTERIZATION_ORDER_ATTACHMENT_ACCESS_NAME,
+        vk::EXT_RASTERIZATION_ORDER_ATTACHMENT_ACCESS_SPEC_VERSION,
+    ),
+    (vk::NV_OPTICAL_FLOW_NAME, vk::NV_OPTICAL_FLOW_SPEC_VERSION),
+    (
+        vk::EXT_LEGACY_DITHERING_NAME,
+        vk::EXT_LEGACY_DITHERING_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_PIPELINE_PROTECTED_ACCESS_NAME,
+        vk::EXT_PIPELINE_PROTECTED_ACCESS_SPEC_VERSION,
+    ),
+    (
+        vk::ANDROID_EXTERNAL_FORMAT_RESOLVE_NAME,
+        vk::ANDROID_EXTERNAL_FORMAT_RESOLVE_SPEC_VERSION,
+    ),
+    (vk::KHR_MAINTENANCE5_NAME, vk::KHR_MAINTENANCE5_SPEC_VERSION),
+    (
+        vk::KHR_RAY_TRACING_POSITION_FETCH_NAME,
+        vk::KHR_RAY_TRACING_POSITION_FETCH_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_SHADER_OBJECT_NAME,
+        vk::EXT_SHADER_OBJECT_SPEC_VERSION,
+    ),
+    (
+        vk::QCOM_TILE_PROPERTIES_NAME,
+        vk::QCOM_TILE_PROPERTIES_SPEC_VERSION,
+    ),
+    (
+        vk::SEC_AMIGO_PROFILING_NAME,
+        vk::SEC_AMIGO_PROFILING_SPEC_VERSION,
+    ),
+    (
+        vk::QCOM_MULTIVIEW_PER_VIEW_VIEWPORTS_NAME,
+        vk::QCOM_MULTIVIEW_PER_VIEW_VIEWPORTS_SPEC_VERSION,
+    ),
+    (
+        vk::NV_RAY_TRACING_INVOCATION_REORDER_NAME,
+        vk::NV_RAY_TRACING_INVOCATION_REORDER_SPEC_VERSION,
+    ),
+    (
+        vk::NV_EXTENDED_SPARSE_ADDRESS_SPACE_NAME,
+        vk::NV_EXTENDED_SPARSE_ADDRESS_SPACE_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_MUTABLE_DESCRIPTOR_TYPE_NAME,
+        vk::EXT_MUTABLE_DESCRIPTOR_TYPE_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_LAYER_SETTINGS_NAME,
+        vk::EXT_LAYER_SETTINGS_SPEC_VERSION,
+    ),
+    (
+        vk::ARM_SHADER_CORE_BUILTINS_NAME,
+        vk::ARM_SHADER_CORE_BUILTINS_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_PIPELINE_LIBRARY_GROUP_HANDLES_NAME,
+        vk::EXT_PIPELINE_LIBRARY_GROUP_HANDLES_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_DYNAMIC_RENDERING_UNUSED_ATTACHMENTS_NAME,
+        vk::EXT_DYNAMIC_RENDERING_UNUSED_ATTACHMENTS_SPEC_VERSION,
+    ),
+    (vk::NV_LOW_LATENCY2_NAME, vk::NV_LOW_LATENCY2_SPEC_VERSION),
+    (
+        vk::KHR_COOPERATIVE_MATRIX_NAME,
+        vk::KHR_COOPERATIVE_MATRIX_SPEC_VERSION,
+    ),
+    (
+        vk::QCOM_MULTIVIEW_PER_VIEW_RENDER_AREAS_NAME,
+        vk::QCOM_MULTIVIEW_PER_VIEW_RENDER_AREAS_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_VIDEO_DECODE_AV1_NAME,
+        vk::KHR_VIDEO_DECODE_AV1_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_VIDEO_MAINTENANCE1_NAME,
+        vk::KHR_VIDEO_MAINTENANCE1_SPEC_VERSION,
+    ),
+    (
+        vk::NV_PER_STAGE_DESCRIPTOR_SET_NAME,
+        vk::NV_PER_STAGE_DESCRIPTOR_SET_SPEC_VERSION,
+    ),
+    (
+        vk::QCOM_IMAGE_PROCESSING2_NAME,
+        vk::QCOM_IMAGE_PROCESSING2_SPEC_VERSION,
+    ),
+    (
+        vk::QCOM_FILTER_CUBIC_WEIGHTS_NAME,
+        vk::QCOM_FILTER_CUBIC_WEIGHTS_SPEC_VERSION,
+    ),
+    (
+        vk::QCOM_YCBCR_DEGAMMA_NAME,
+        vk::QCOM_YCBCR_DEGAMMA_SPEC_VERSION,
+    ),
+    (
+        vk::QCOM_FILTER_CUBIC_CLAMP_NAME,
+        vk::QCOM_FILTER_CUBIC_CLAMP_SPEC_VERSION,
+    ),
+    (
+        vk::EXT_ATTACHMENT_FEEDBACK_LOOP_DYNAMIC_STATE_NAME,
+        vk::EXT_ATTACHMENT_FEEDBACK_LOOP_DYNAMIC_STATE_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_VERTEX_ATTRIBUTE_DIVISOR_NAME,
+        vk::KHR_VERTEX_ATTRIBUTE_DIVISOR_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_LOAD_STORE_OP_NONE_NAME,
+        vk::KHR_LOAD_STORE_OP_NONE_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_SHADER_FLOAT_CONTROLS2_NAME,
+        vk::KHR_SHADER_FLOAT_CONTROLS2_SPEC_VERSION,
+    ),
+    (
+        vk::QNX_EXTERNAL_MEMORY_SCREEN_BUFFER_NAME,
+        vk::QNX_EXTERNAL_MEMORY_SCREEN_BUFFER_SPEC_VERSION,
+    ),
+    (
+        vk::MSFT_LAYERED_DRIVER_NAME,
+        vk::MSFT_LAYERED_DRIVER_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_INDEX_TYPE_UINT8_NAME,
+        vk::KHR_INDEX_TYPE_UINT8_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_LINE_RASTERIZATION_NAME,
+        vk::KHR_LINE_RASTERIZATION_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_CALIBRATED_TIMESTAMPS_NAME,
+        vk::KHR_CALIBRATED_TIMESTAMPS_SPEC_VERSION,
+    ),
+    (
+        vk::KHR_SHADER_EXPECT_ASSUME_NAME,
+        vk::KHR_SHADER_EXPECT_ASSUME_SPEC_VERSION,
+    ),
+    (vk::KHR_MAINTENANCE6_NAME, vk::KHR_MAINTENANCE6_SPEC_VERSION),
+    (
+        vk::NV_DESCRIPTOR_POOL_OVERALLOCATION_NAME,
+        vk::NV_DESCRIPTOR_POOL_OVERALLOCATION_SPEC_VERSION,
+    ),
+    (
+        vk::NV_RAW_ACCESS_CHAINS_NAME,
+        vk::NV_RAW_ACCESS_CHAINS_SPEC_VERSION,
+    ),
+    (
+        vk::NV_SHADER_ATOMIC_FLOAT16_VECTOR_NAME,
+        vk::NV_SHADER_ATOMIC_FLOAT16_VECTOR_SPEC_VERSION,
+    ),
+    (
+        vk::NV_RAY_TRACING_VALIDATION_NAME,
+        vk::NV_RAY_TRACING_VALIDATION_SPEC_VERSION,
+    ),
+];
+
+/// The core versions and extensions which provide formats added after vulkan 1.0.
+pub(super) static FORMAT_PROVIDERS: &[(vk::Format, &[&str])] = &[
+    (
+        vk::Format::G8B8G8R8_422_UNORM,
+        &["VK_VERSION_1_1", "VK_KHR_sampler_ycbcr_conversion"],
+    ),
+    (
+        vk::Format::B8G8R8G8_422_UNORM,
+        &["VK_VERSION_1_1", "VK_KHR_sampler_ycbcr_conversion"],
+    ),
+    (
+        vk::Format::G8_B8_R8_3PLANE_420_UNORM,
+        &["VK_VERSION_1_1", "VK_KHR_sampler_ycbcr_conversion"],
+    ),
+    (
+        vk::Format::G8_B8R8_2PLANE_420_UNORM,
+        &["VK_VERSION_1_1", "VK_KHR_sampler_ycbcr_conversion"],
+    ),
+    (
+        vk::Format::G8_B8_R8_3PLANE_422_UNORM,
+        &["VK_VERSION_1_1", "VK_KHR_sampler_ycbcr_conversion"],
+    ),
+    (
+        vk::Format::G8_B8R8_2PLANE_422_UNORM,
+        &["VK_VERSION_1_1", "VK_KHR_sampler_ycbcr_conversion"],
+    ),
+    (
+        vk::Format::G8_B8_R8_3PLANE_444_UNORM,
+        &["VK_VERSION_1_1", "VK_KHR_sampler_ycbcr_conversion"],
+    ),
+    (
+        vk::Format::R10X6_UNORM_PACK16,
+        &["VK_VERSION_1_1", "VK_KHR_sampler_ycbcr_conversion"],
+    ),
+    (
+        vk::Format::R10X6G10X6_UNORM_2PACK16,
+        &["VK_VERSION_1_1", "VK_KHR_sampler_ycbcr_conversion"],
+    ),
+    (
+        vk::Format::R10X6G10X6B10X6A10X6_UNORM_4PACK16,
+        &["VK_VERSION_1_1", "VK_KHR_sampler_ycbcr_conversion"],
+    ),
+    (
+        vk::Format::G10X6B10X6G10X6R10X6_422_UNORM_4PACK16,
+        &["VK_VERSION_1_1", "VK_KHR_sampler_ycbcr_conversion"],
+    ),
+    (
+        vk::Format::B10X6G10X6R10X6G10X6_422_UNORM_4PACK16,
+        &["VK_VERSION_1_1", "VK_KHR_sampler_ycbcr_conversion"],
+    ),
+    (
+        vk::Format::G10X6_B10X6_R10X6_3PLANE_420_UNORM_3PACK16,
+        &["VK_VERSION_1_1", "VK_KHR_sampler_ycbcr_conversion"],
+    ),
+    (
+        vk::Format::G10X6_B10X6R10X6_2PLANE_420_UNORM_3PACK16,
+        &["VK_VERSION_1_1", "VK_KHR_sampler_ycbcr_conversion"],
+    ),
+    (
+        vk::Format::G10X6_B10X6_R10X6_3PLANE_422_UNORM_3PACK16,
+        &["VK_VERSION_1_1", "VK_KHR_sampler_ycbcr_conversion"],
+    ),
+    (
+        vk::Format::G10X6_B10X6R10X6_2PLANE_422_UNORM_3PACK16,
+        &["VK_VERSION_1_1", "VK_KHR_sampler_ycbcr_conversion"],
+    ),
+    (
+        vk::Format::G10X6_B10X6_R10X6_3PLANE_444_UNORM_3PACK16,
+        &["VK_VERSION_1_1", "VK_KHR_sampler_ycbcr_conversion"],
+    ),
+    (
+        vk::Format::R12X4_UNORM_PACK16,
+        &["VK_VERSION_1_1", "VK_KHR_sampler_ycbcr_conversion"],
+    ),
+    (
+        vk::Format::R12X4G12X4_UNORM_2PACK16,
+        &["VK_VERSION_1_1", "VK_KHR_sampler_ycbcr_conversion"],
+    ),
+    (
+        vk::Format::R12X4G12X4B12X4A12X4_UNORM_4PACK16,
+        &["VK_VERSION_1_1", "VK_KHR_sampler_ycbcr_conversion"],
+    ),
+    (
+        vk::Format::G12X4B12X4G12X4R12X4_422_UNORM_4PACK16,
+        &["VK_VERSION_1_1", "VK_KHR_sampler_ycbcr_conversion"],
+    ),
+    (
+        vk::Format::B12X4G12X4R12X4G12X4_422_UNORM_4PACK16,
+        &["VK_VERSION_1_1", "VK_KHR_sampler_ycbcr_conversion"],
+    ),
+    (
+        vk::Format::G12X4_B12X4_R12X4_3PLANE_420_UNORM_3PACK16,
+        &["VK_VERSION_1_1", "VK_KHR_sampler_ycbcr_conversion"],
+    ),
+    (
+        vk::Format::G12X4_B12X4R12X4_2PLANE_420_UNORM_3PACK16,
+        &["VK_VERSION_1_1", "VK_KHR_sampler_ycbcr_conversion"],
+    ),
+    (
+        vk::Format::G12X4_B12X4_R12X4_3PLANE_422_UNORM_3PACK16,
+        &["VK_VERSION_1_1", "VK_KHR_sampler_ycbcr_conversion"],
+    ),
+    (
+        vk::Format::G12X4_B12X4R12X4_2PLANE_422_UNORM_3PACK16,
+        &["VK_VERSION_1_1", "VK_KHR_sampler_ycbcr_conversion"],
+    ),
+    (
+        vk::Format::G12X4_B12X4_R12X4_3PLANE_444_UNORM_3PACK16,
+        &["VK_VERSION_1_1", "VK_KHR_sampler_ycbcr_conversion"],
+    ),
+    (
+        vk::Format::G16B16G16R16_422_UNORM,
+        &["VK_VERSION_1_1", "VK_KHR_sampler_ycbcr_conversion"],
+    ),
+    (
+        vk::Format::B16G16R16G16_422_UNORM,
+        &["VK_VERSION_1_1", "VK_KHR_sampler_ycbcr_conversion"],
+    ),
+    (
+        vk::Format::G16_B16_R16_3PLANE_420_UNORM,
+        &["VK_VERSION_1_1", "VK_KHR_sampler_ycbcr_conversion"],
+    ),
+    (
+        vk::Format::G16_B16R16_2PLANE_420_UNORM,
+        &["VK_VERSION_1_1", "VK_KHR_sampler_ycbcr_conversion"],
+    ),
+    (
+        vk::Format::G16_B16_R16_3PLANE_422_UNORM,
+        &["VK_VERSION_1_1", "VK_KHR_sampler_ycbcr_conversion"],
+    ),
+    (
+        vk::Format::G16_B16R16_2PLANE_422_UNORM,
+        &["VK_VERSION_1_1", "VK_KHR_sampler_ycbcr_conversion"],
+    ),
+    (
+        vk::Format::G16_B16_R16_3PLANE_444_UNORM,
+        &["VK_VERSION_1_1", "VK_KHR_sampler_ycbcr_conversion"],
+    ),
+    (
+        vk::Format::G8_B8R8_2PLANE_444_UNORM,
+        &["VK_VERSION_1_3", "VK_EXT_ycbcr_2plane_444_formats"],
+    ),
+    (
+        vk::Format::G10X6_B10X6R10X6_2PLANE_444_UNORM_3PACK16,
+        &["VK_VERSION_1_3", "VK_EXT_ycbcr_2plane_444_formats"],
+    ),
+    (
+        vk::Format::G12X4_B12X4R12X4_2PLANE_444_UNORM_3PACK16,
+        &["VK_VERSION_1_3", "VK_EXT_ycbcr_2plane_444_formats"],
+    ),
+    (
+        vk::Format::G16_B16R16_2PLANE_444_UNORM,
+        &["VK_VERSION_1_3", "VK_EXT_ycbcr_2plane_444_formats"],
+    ),
+    (
+        vk::Format::A4R4G4B4_UNORM_PACK16,
+        &["VK_VERSION_1_3", "VK_EXT_4444_formats"],
+    ),
+    (
+        vk::Format::A4B4G4R4_UNORM_PACK16,
+        &["VK_VERSION_1_3", "VK_EXT_4444_formats"],
+    ),
+    (
+        vk::Format::ASTC_4X4_SFLOAT_BLOCK,
+        &["VK_VERSION_1_3", "VK_EXT_texture_compression_astc_hdr"],
+    ),
+    (
+        vk::Format::ASTC_5X4_SFLOAT_BLOCK,
+        &["VK_VERSION_1_3", "VK_EXT_texture_compression_astc_hdr"],
+    ),
+    (
+        vk::Format::ASTC_5X5_SFLOAT_BLOCK,
+        &["VK_VERSION_1_3", "VK_EXT_texture_compression_astc_hdr"],
+    ),
+    (
+        vk::Format::ASTC_6X5_SFLOAT_BLOCK,
+        &["VK_VERSION_1_3", "VK_EXT_texture_compression_astc_hdr"],
+    ),
+    (
+        vk::Format::ASTC_6X6_SFLOAT_BLOCK,
+        &["VK_VERSION_1_3", "VK_EXT_texture_compression_astc_hdr"],
+    ),
+    (
+        vk::Format::ASTC_8X5_SFLOAT_BLOCK,
+        &["VK_VERSION_1_3", "VK_EXT_texture_compression_astc_hdr"],
+    ),
+    (
+        vk::Format::ASTC_8X6_SFLOAT_BLOCK,
+        &["VK_VERSION_1_3", "VK_EXT_texture_compression_astc_hdr"],
+    ),
+    (
+        vk::Format::ASTC_8X8_SFLOAT_BLOCK,
+        &["VK_VERSION_1_3", "VK_EXT_texture_compression_astc_hdr"],
+    ),
+    (
+        vk::Format::ASTC_10X5_SFLOAT_BLOCK,
+        &["VK_VERSION_1_3", "VK_EXT_texture_compression_astc_hdr"],
+    ),
+    (
+        vk::Format::ASTC_10X6_SFLOAT_BLOCK,
+        &["VK_VERSION_1_3", "VK_EXT_texture_compression_astc_hdr"],
+    ),
+    (
+        vk::Format::ASTC_10X8_SFLOAT_BLOCK,
+        &["VK_VERSION_1_3", "VK_EXT_texture_compression_astc_hdr"],
+    ),
+    (
+        vk::Format::ASTC_10X10_SFLOAT_BLOCK,
+        &["VK_VERSION_1_3", "VK_EXT_texture_compression_astc_hdr"],
+    ),
+    (
+        vk::Format::ASTC_12X10_SFLOAT_BLOCK,
+        &["VK_VERSION_1_3", "VK_EXT_texture_compression_astc_hdr"],
+    ),
+    (
+        vk::Format::ASTC_12X12_SFLOAT_BLOCK,
+        &["VK_VERSION_1_3", "VK_EXT_texture_compression_astc_hdr"],
+    ),
+    (
+        vk::Format::PVRTC1_2BPP_UNORM_BLOCK_IMG,
+        &["VK_IMG_format_pvrtc"],
+    ),
+    (
+        vk::Format::PVRTC1_4BPP_UNORM_BLOCK_IMG,
+        &["VK_IMG_format_pvrtc"],
+    ),
+    (
+        vk::Format::PVRTC2_2BPP_UNORM_BLOCK_IMG,
+        &["VK_IMG_format_pvrtc"],
+    ),
+    (
+        vk::Format::PVRTC2_4BPP_UNORM_BLOCK_IMG,
+        &["VK_IMG_format_pvrtc"],
+    ),
+    (
+        vk::Format::PVRTC1_2BPP_SRGB_BLOCK_IMG,
+        &["VK_IMG_format_pvrtc"],
+    ),
+    (
+        vk::Format::PVRTC1_4BPP_SRGB_BLOCK_IMG,
+        &["VK_IMG_format_pvrtc"],
+    ),
+    (
+        vk::Format::PVRTC2_2BPP_SRGB_BLOCK_IMG,
+        &["VK_IMG_format_pvrtc"],
+    ),
+    (
+        vk::Format::PVRTC2_4BPP_SRGB_BLOCK_IMG,
+        &["VK_IMG_format_pvrtc"],
+    ),
+    (vk::Format::R16G16_S10_5_NV, &["VK_NV_optical_flow"]),
+    (
+        vk::Format::A1B5G5R5_UNORM_PACK16_KHR,
+        &["VK_KHR_maintenance5"],
+    ),
+    (vk::Format::A8_UNORM_KHR, &["VK_KHR_maintenance5"]),
+];
+
+pub(super) static FLAGS: &[&FlagsInfo] = &[
+    &BUFFER_USAGE_FLAGS,
+    &FORMAT_FEATURE_FLAGS,
+    &FORMAT_FEATURE_FLAGS2,
+    &IMAGE_USAGE_FLAGS,
+    &MEMORY_DECOMPRESSION_METHOD_FLAGS_NV,
+    &OPTICAL_FLOW_GRID_SIZE_FLAGS_NV,
+    &PHYSICAL_DEVICE_SCHEDULING_CONTROLS_FLAGS_ARM,
+    &PIPELINE_STAGE_FLAGS,
+    &PIPELINE_STAGE_FLAGS2,
+    &QUEUE_FLAGS,
+    &RESOLVE_MODE_FLAGS,
+    &SAMPLE_COUNT_FLAGS,
+    &SHADER_CORE_PROPERTIES_FLAGS_AMD,
+    &SHADER_STAGE_FLAGS,
+    &SUBGROUP_FEATURE_FLAGS,
+    &VIDEO_CODEC_OPERATION_FLAGS_KHR,
+];
+
+pub(super) static ENUMS: &[&EnumInfo] = &[
+    &CHROMA_LOCATION,
+    &DRIVER_ID,
+    &FORMAT,
+    &LAYERED_DRIVER_UNDERLYING_API_MSFT,
+    &PHYSICAL_DEVICE_TYPE,
+    &PIPELINE_ROBUSTNESS_BUFFER_BEHAVIOR_EXT,
+    &PIPELINE_ROBUSTNESS_IMAGE_BEHAVIOR_EXT,
+    &POINT_CLIPPING_BEHAVIOR,
+    &QUEUE_GLOBAL_PRIORITY_KHR,
+    &RAY_TRACING_INVOCATION_REORDER_MODE_NV,
+    &SHADER_FLOAT_CONTROLS_INDEPENDENCE,
+];
+
+pub(super) static PHYSICAL_DEVICE_LIMITS: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceLimits",
+    structure_type: None,
+    category: None,
+    size: size_of::<vk::PhysicalDeviceLimits>(),
+    providers: &[],
+    members: &[
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_image_dimension1_d,
+            "maxImageDimension1D",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_image_dimension2_d,
+            "maxImageDimension2D",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_image_dimension3_d,
+            "maxImageDimension3D",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_image_dimension_cube,
+            "maxImageDimensionCube",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_image_array_layers,
+            "maxImageArrayLayers",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_texel_buffer_elements,
+            "maxTexelBufferElements",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_uniform_buffer_range,
+            "maxUniformBufferRange",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_storage_buffer_range,
+            "maxStorageBufferRange",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_push_constants_size,
+            "maxPushConstantsSize",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_memory_allocation_count,
+            "maxMemoryAllocationCount",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_sampler_allocation_count,
+            "maxSamplerAllocationCount",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            buffer_image_granularity,
+            "bufferImageGranularity",
+            T::Uint64
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            sparse_address_space_size,
+            "sparseAddressSpaceSize",
+            T::Uint64
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_bound_descriptor_sets,
+            "maxBoundDescriptorSets",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_per_stage_descriptor_samplers,
+            "maxPerStageDescriptorSamplers",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_per_stage_descriptor_uniform_buffers,
+            "maxPerStageDescriptorUniformBuffers",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_per_stage_descriptor_storage_buffers,
+            "maxPerStageDescriptorStorageBuffers",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_per_stage_descriptor_sampled_images,
+            "maxPerStageDescriptorSampledImages",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_per_stage_descriptor_storage_images,
+            "maxPerStageDescriptorStorageImages",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_per_stage_descriptor_input_attachments,
+            "maxPerStageDescriptorInputAttachments",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_per_stage_resources,
+            "maxPerStageResources",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_descriptor_set_samplers,
+            "maxDescriptorSetSamplers",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_descriptor_set_uniform_buffers,
+            "maxDescriptorSetUniformBuffers",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_descriptor_set_uniform_buffers_dynamic,
+            "maxDescriptorSetUniformBuffersDynamic",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_descriptor_set_storage_buffers,
+            "maxDescriptorSetStorageBuffers",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_descriptor_set_storage_buffers_dynamic,
+            "maxDescriptorSetStorageBuffersDynamic",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_descriptor_set_sampled_images,
+            "maxDescriptorSetSampledImages",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_descriptor_set_storage_images,
+            "maxDescriptorSetStorageImages",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_descriptor_set_input_attachments,
+            "maxDescriptorSetInputAttachments",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_vertex_input_attributes,
+            "maxVertexInputAttributes",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_vertex_input_bindings,
+            "maxVertexInputBindings",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_vertex_input_attribute_offset,
+            "maxVertexInputAttributeOffset",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_vertex_input_binding_stride,
+            "maxVertexInputBindingStride",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_vertex_output_components,
+            "maxVertexOutputComponents",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_tessellation_generation_level,
+            "maxTessellationGenerationLevel",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_tessellation_patch_size,
+            "maxTessellationPatchSize",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_tessellation_control_per_vertex_input_components,
+            "maxTessellationControlPerVertexInputComponents",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_tessellation_control_per_vertex_output_components,
+            "maxTessellationControlPerVertexOutputComponents",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_tessellation_control_per_patch_output_components,
+            "maxTessellationControlPerPatchOutputComponents",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_tessellation_control_total_output_components,
+            "maxTessellationControlTotalOutputComponents",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_tessellation_evaluation_input_components,
+            "maxTessellationEvaluationInputComponents",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_tessellation_evaluation_output_components,
+            "maxTessellationEvaluationOutputComponents",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_geometry_shader_invocations,
+            "maxGeometryShaderInvocations",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_geometry_input_components,
+            "maxGeometryInputComponents",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_geometry_output_components,
+            "maxGeometryOutputComponents",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_geometry_output_vertices,
+            "maxGeometryOutputVertices",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_geometry_total_output_components,
+            "maxGeometryTotalOutputComponents",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_fragment_input_components,
+            "maxFragmentInputComponents",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_fragment_output_attachments,
+            "maxFragmentOutputAttachments",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_fragment_dual_src_attachments,
+            "maxFragmentDualSrcAttachments",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_fragment_combined_output_resources,
+            "maxFragmentCombinedOutputResources",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_compute_shared_memory_size,
+            "maxComputeSharedMemorySize",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_compute_work_group_count,
+            "maxComputeWorkGroupCount",
+            T::Array(&T::Uint32, 3)
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_compute_work_group_invocations,
+            "maxComputeWorkGroupInvocations",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_compute_work_group_size,
+            "maxComputeWorkGroupSize",
+            T::Array(&T::Uint32, 3)
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            sub_pixel_precision_bits,
+            "subPixelPrecisionBits",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            sub_texel_precision_bits,
+            "subTexelPrecisionBits",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            mipmap_precision_bits,
+            "mipmapPrecisionBits",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_draw_indexed_index_value,
+            "maxDrawIndexedIndexValue",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_draw_indirect_count,
+            "maxDrawIndirectCount",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_sampler_lod_bias,
+            "maxSamplerLodBias",
+            T::Float32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_sampler_anisotropy,
+            "maxSamplerAnisotropy",
+            T::Float32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_viewports,
+            "maxViewports",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_viewport_dimensions,
+            "maxViewportDimensions",
+            T::Array(&T::Uint32, 2)
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            viewport_bounds_range,
+            "viewportBoundsRange",
+            T::Array(&T::Float32, 2)
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            viewport_sub_pixel_bits,
+            "viewportSubPixelBits",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            min_memory_map_alignment,
+            "minMemoryMapAlignment",
+            T::Size
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            min_texel_buffer_offset_alignment,
+            "minTexelBufferOffsetAlignment",
+            T::Uint64
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            min_uniform_buffer_offset_alignment,
+            "minUniformBufferOffsetAlignment",
+            T::Uint64
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            min_storage_buffer_offset_alignment,
+            "minStorageBufferOffsetAlignment",
+            T::Uint64
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            min_texel_offset,
+            "minTexelOffset",
+            T::Int32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_texel_offset,
+            "maxTexelOffset",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            min_texel_gather_offset,
+            "minTexelGatherOffset",
+            T::Int32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_texel_gather_offset,
+            "maxTexelGatherOffset",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            min_interpolation_offset,
+            "minInterpolationOffset",
+            T::Float32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_interpolation_offset,
+            "maxInterpolationOffset",
+            T::Float32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            sub_pixel_interpolation_offset_bits,
+            "subPixelInterpolationOffsetBits",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_framebuffer_width,
+            "maxFramebufferWidth",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_framebuffer_height,
+            "maxFramebufferHeight",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_framebuffer_layers,
+            "maxFramebufferLayers",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            framebuffer_color_sample_counts,
+            "framebufferColorSampleCounts",
+            T::Flags(&SAMPLE_COUNT_FLAGS)
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            framebuffer_depth_sample_counts,
+            "framebufferDepthSampleCounts",
+            T::Flags(&SAMPLE_COUNT_FLAGS)
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            framebuffer_stencil_sample_counts,
+            "framebufferStencilSampleCounts",
+            T::Flags(&SAMPLE_COUNT_FLAGS)
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            framebuffer_no_attachments_sample_counts,
+            "framebufferNoAttachmentsSampleCounts",
+            T::Flags(&SAMPLE_COUNT_FLAGS)
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_color_attachments,
+            "maxColorAttachments",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            sampled_image_color_sample_counts,
+            "sampledImageColorSampleCounts",
+            T::Flags(&SAMPLE_COUNT_FLAGS)
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            sampled_image_integer_sample_counts,
+            "sampledImageIntegerSampleCounts",
+            T::Flags(&SAMPLE_COUNT_FLAGS)
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            sampled_image_depth_sample_counts,
+            "sampledImageDepthSampleCounts",
+            T::Flags(&SAMPLE_COUNT_FLAGS)
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            sampled_image_stencil_sample_counts,
+            "sampledImageStencilSampleCounts",
+            T::Flags(&SAMPLE_COUNT_FLAGS)
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            storage_image_sample_counts,
+            "storageImageSampleCounts",
+            T::Flags(&SAMPLE_COUNT_FLAGS)
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_sample_mask_words,
+            "maxSampleMaskWords",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            timestamp_compute_and_graphics,
+            "timestampComputeAndGraphics",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            timestamp_period,
+            "timestampPeriod",
+            T::Float32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_clip_distances,
+            "maxClipDistances",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_cull_distances,
+            "maxCullDistances",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            max_combined_clip_and_cull_distances,
+            "maxCombinedClipAndCullDistances",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            discrete_queue_priorities,
+            "discreteQueuePriorities",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            point_size_range,
+            "pointSizeRange",
+            T::Array(&T::Float32, 2)
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            line_width_range,
+            "lineWidthRange",
+            T::Array(&T::Float32, 2)
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            point_size_granularity,
+            "pointSizeGranularity",
+            T::Float32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            line_width_granularity,
+            "lineWidthGranularity",
+            T::Float32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            strict_lines,
+            "strictLines",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            standard_sample_locations,
+            "standardSampleLocations",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            optimal_buffer_copy_offset_alignment,
+            "optimalBufferCopyOffsetAlignment",
+            T::Uint64
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            optimal_buffer_copy_row_pitch_alignment,
+            "optimalBufferCopyRowPitchAlignment",
+            T::Uint64
+        ),
+        member!(
+            vk::PhysicalDeviceLimits,
+            non_coherent_atom_size,
+            "nonCoherentAtomSize",
+            T::Uint64
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_SPARSE_PROPERTIES: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceSparseProperties",
+    structure_type: None,
+    category: None,
+    size: size_of::<vk::PhysicalDeviceSparseProperties>(),
+    providers: &[],
+    members: &[
+        member!(
+            vk::PhysicalDeviceSparseProperties,
+            residency_standard2_d_block_shape,
+            "residencyStandard2DBlockShape",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceSparseProperties,
+            residency_standard2_d_multisample_block_shape,
+            "residencyStandard2DMultisampleBlockShape",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceSparseProperties,
+            residency_standard3_d_block_shape,
+            "residencyStandard3DBlockShape",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceSparseProperties,
+            residency_aligned_mip_size,
+            "residencyAlignedMipSize",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceSparseProperties,
+            residency_non_resident_strict,
+            "residencyNonResidentStrict",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static CONFORMANCE_VERSION: StructInfo = StructInfo {
+    name: "VkConformanceVersion",
+    structure_type: None,
+    category: None,
+    size: size_of::<vk::ConformanceVersion>(),
+    providers: &[],
+    members: &[
+        member!(vk::ConformanceVersion, major, "major", T::Uint8),
+        member!(vk::ConformanceVersion, minor, "minor", T::Uint8),
+        member!(vk::ConformanceVersion, subminor, "subminor", T::Uint8),
+        member!(vk::ConformanceVersion, patch, "patch", T::Uint8),
+    ],
+};
+
+pub(super) static EXTENT2_D: StructInfo = StructInfo {
+    name: "VkExtent2D",
+    structure_type: None,
+    category: None,
+    size: size_of::<vk::Extent2D>(),
+    providers: &[],
+    members: &[
+        member!(vk::Extent2D, width, "width", T::Uint32),
+        member!(vk::Extent2D, height, "height", T::Uint32),
+    ],
+};
+
+pub(super) static EXTENT3_D: StructInfo = StructInfo {
+    name: "VkExtent3D",
+    structure_type: None,
+    category: None,
+    size: size_of::<vk::Extent3D>(),
+    providers: &[],
+    members: &[
+        member!(vk::Extent3D, width, "width", T::Uint32),
+        member!(vk::Extent3D, height, "height", T::Uint32),
+        member!(vk::Extent3D, depth, "depth", T::Uint32),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_FEATURES: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceFeatures",
+    structure_type: None,
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceFeatures>(),
+    providers: &[],
+    members: &[
+        member!(
+            vk::PhysicalDeviceFeatures,
+            robust_buffer_access,
+            "robustBufferAccess",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFeatures,
+            full_draw_index_uint32,
+            "fullDrawIndexUint32",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFeatures,
+            image_cube_array,
+            "imageCubeArray",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFeatures,
+            independent_blend,
+            "independentBlend",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFeatures,
+            geometry_shader,
+            "geometryShader",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFeatures,
+            tessellation_shader,
+            "tessellationShader",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFeatures,
+            sample_rate_shading,
+            "sampleRateShading",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFeatures,
+            dual_src_blend,
+            "dualSrcBlend",
+            T::Bool32
+        ),
+        member!(vk::PhysicalDeviceFeatures, logic_op, "logicOp", T::Bool32),
+        member!(
+            vk::PhysicalDeviceFeatures,
+            multi_draw_indirect,
+            "multiDrawIndirect",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFeatures,
+            draw_indirect_first_instance,
+            "drawIndirectFirstInstance",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFeatures,
+            depth_clamp,
+            "depthClamp",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFeatures,
+            depth_bias_clamp,
+            "depthBiasClamp",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFeatures,
+            fill_mode_non_solid,
+            "fillModeNonSolid",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFeatures,
+            depth_bounds,
+            "depthBounds",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFeatures,
+            wide_lines,
+            "wideLines",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFeatures,
+            large_points,
+            "largePoints",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFeatures,
+            alpha_to_one,
+            "alphaToOne",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFeatures,
+            multi_viewport,
+            "multiViewport",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFeatures,
+            sampler_anisotropy,
+            "samplerAnisotropy",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFeatures,
+            texture_compression_etc2,
+            "textureCompressionETC2",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFeatures,
+            texture_compression_astc_ldr,
+            "textureCompressionASTCLDR",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFeatures,
+            texture_compression_bc,
+            "textureCompressionBC",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFeatures,
+            occlusion_query_precise,
+            "occlusionQueryPrecise",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFeatures,
+            pipeline_statistics_query,
+            "pipelineStatisticsQuery",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFeatures,
+            vertex_pipeline_stores_and_atomics,
+            "vertexPipelineStoresAndAtomics",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFeatures,
+            fragment_stores_and_atomics,
+            "fragmentStoresAndAtomics",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFeatures,
+            shader_tessellation_and_geometry_point_size,
+            "shaderTessellationAndGeometryPointSize",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFeatures,
+            shader_image_gather_extended,
+            "shaderImageGatherExtended",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFeatures,
+            shader_storage_image_extended_formats,
+            "shaderStorageImageExtendedFormats",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFeatures,
+            shader_storage_image_multisample,
+            "shaderStorageImageMultisample",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFeatures,
+            shader_storage_image_read_without_format,
+            "shaderStorageImageReadWithoutFormat",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFeatures,
+            shader_storage_image_write_without_format,
+            "shaderStorageImageWriteWithoutFormat",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFeatures,
+            shader_uniform_buffer_array_dynamic_indexing,
+            "shaderUniformBufferArrayDynamicIndexing",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFeatures,
+            shader_sampled_image_array_dynamic_indexing,
+            "shaderSampledImageArrayDynamicIndexing",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFeatures,
+            shader_storage_buffer_array_dynamic_indexing,
+            "shaderStorageBufferArrayDynamicIndexing",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFeatures,
+            shader_storage_image_array_dynamic_indexing,
+            "shaderStorageImageArrayDynamicIndexing",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFeatures,
+            shader_clip_distance,
+            "shaderClipDistance",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFeatures,
+            shader_cull_distance,
+            "shaderCullDistance",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFeatures,
+            shader_float64,
+            "shaderFloat64",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFeatures,
+            shader_int64,
+            "shaderInt64",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFeatures,
+            shader_int16,
+            "shaderInt16",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFeatures,
+            shader_resource_residency,
+            "shaderResourceResidency",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFeatures,
+            shader_resource_min_lod,
+            "shaderResourceMinLod",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFeatures,
+            sparse_binding,
+            "sparseBinding",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFeatures,
+            sparse_residency_buffer,
+            "sparseResidencyBuffer",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFeatures,
+            sparse_residency_image2_d,
+            "sparseResidencyImage2D",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFeatures,
+            sparse_residency_image3_d,
+            "sparseResidencyImage3D",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFeatures,
+            sparse_residency2_samples,
+            "sparseResidency2Samples",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFeatures,
+            sparse_residency4_samples,
+            "sparseResidency4Samples",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFeatures,
+            sparse_residency8_samples,
+            "sparseResidency8Samples",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFeatures,
+            sparse_residency16_samples,
+            "sparseResidency16Samples",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFeatures,
+            sparse_residency_aliased,
+            "sparseResidencyAliased",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFeatures,
+            variable_multisample_rate,
+            "variableMultisampleRate",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFeatures,
+            inherited_queries,
+            "inheritedQueries",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE16_BIT_STORAGE_FEATURES: StructInfo = StructInfo {
+    name: "VkPhysicalDevice16BitStorageFeatures",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_16BIT_STORAGE_FEATURES),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDevice16BitStorageFeatures<'static>>(),
+    providers: &["VK_VERSION_1_1", "VK_KHR_16bit_storage"],
+    members: &[
+        member!(
+            vk::PhysicalDevice16BitStorageFeatures<'static>,
+            storage_buffer16_bit_access,
+            "storageBuffer16BitAccess",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDevice16BitStorageFeatures<'static>,
+            uniform_and_storage_buffer16_bit_access,
+            "uniformAndStorageBuffer16BitAccess",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDevice16BitStorageFeatures<'static>,
+            storage_push_constant16,
+            "storagePushConstant16",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDevice16BitStorageFeatures<'static>,
+            storage_input_output16,
+            "storageInputOutput16",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE4444_FORMATS_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDevice4444FormatsFeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_4444_FORMATS_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDevice4444FormatsFeaturesEXT<'static>>(),
+    providers: &["VK_EXT_4444_formats"],
+    members: &[
+        member!(
+            vk::PhysicalDevice4444FormatsFeaturesEXT<'static>,
+            format_a4r4g4b4,
+            "formatA4r4g4b4",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDevice4444FormatsFeaturesEXT<'static>,
+            format_a4b4g4r4,
+            "formatA4b4g4r4",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE8_BIT_STORAGE_FEATURES: StructInfo = StructInfo {
+    name: "VkPhysicalDevice8BitStorageFeatures",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_8BIT_STORAGE_FEATURES),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDevice8BitStorageFeatures<'static>>(),
+    providers: &["VK_VERSION_1_2", "VK_KHR_8bit_storage"],
+    members: &[
+        member!(
+            vk::PhysicalDevice8BitStorageFeatures<'static>,
+            storage_buffer8_bit_access,
+            "storageBuffer8BitAccess",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDevice8BitStorageFeatures<'static>,
+            uniform_and_storage_buffer8_bit_access,
+            "uniformAndStorageBuffer8BitAccess",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDevice8BitStorageFeatures<'static>,
+            storage_push_constant8,
+            "storagePushConstant8",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_ASTC_DECODE_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceASTCDecodeFeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_ASTC_DECODE_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceASTCDecodeFeaturesEXT<'static>>(),
+    providers: &["VK_EXT_astc_decode_mode"],
+    members: &[member!(
+        vk::PhysicalDeviceASTCDecodeFeaturesEXT<'static>,
+        decode_mode_shared_exponent,
+        "decodeModeSharedExponent",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_ACCELERATION_STRUCTURE_FEATURES_KHR: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceAccelerationStructureFeaturesKHR",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_ACCELERATION_STRUCTURE_FEATURES_KHR),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceAccelerationStructureFeaturesKHR<'static>>(),
+    providers: &["VK_KHR_acceleration_structure"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceAccelerationStructureFeaturesKHR<'static>,
+            acceleration_structure,
+            "accelerationStructure",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceAccelerationStructureFeaturesKHR<'static>,
+            acceleration_structure_capture_replay,
+            "accelerationStructureCaptureReplay",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceAccelerationStructureFeaturesKHR<'static>,
+            acceleration_structure_indirect_build,
+            "accelerationStructureIndirectBuild",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceAccelerationStructureFeaturesKHR<'static>,
+            acceleration_structure_host_commands,
+            "accelerationStructureHostCommands",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceAccelerationStructureFeaturesKHR<'static>,
+            descriptor_binding_acceleration_structure_update_after_bind,
+            "descriptorBindingAccelerationStructureUpdateAfterBind",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_ADDRESS_BINDING_REPORT_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceAddressBindingReportFeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_ADDRESS_BINDING_REPORT_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceAddressBindingReportFeaturesEXT<'static>>(),
+    providers: &["VK_EXT_device_address_binding_report"],
+    members: &[member!(
+        vk::PhysicalDeviceAddressBindingReportFeaturesEXT<'static>,
+        report_address_binding,
+        "reportAddressBinding",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_AMIGO_PROFILING_FEATURES_SEC: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceAmigoProfilingFeaturesSEC",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_AMIGO_PROFILING_FEATURES_SEC),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceAmigoProfilingFeaturesSEC<'static>>(),
+    providers: &["VK_SEC_amigo_profiling"],
+    members: &[member!(
+        vk::PhysicalDeviceAmigoProfilingFeaturesSEC<'static>,
+        amigo_profiling,
+        "amigoProfiling",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_ATTACHMENT_FEEDBACK_LOOP_DYNAMIC_STATE_FEATURES_EXT: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDeviceAttachmentFeedbackLoopDynamicStateFeaturesEXT",
+        structure_type: None,
+        category: Some(StructCategory::Features),
+        size: size_of::<vk::PhysicalDeviceAttachmentFeedbackLoopDynamicStateFeaturesEXT<'static>>(),
+        providers: &[],
+        members: &[member!(
+            vk::PhysicalDeviceAttachmentFeedbackLoopDynamicStateFeaturesEXT<'static>,
+            attachment_feedback_loop_dynamic_state,
+            "attachmentFeedbackLoopDynamicState",
+            T::Bool32
+        )],
+    };
+
+pub(super) static PHYSICAL_DEVICE_ATTACHMENT_FEEDBACK_LOOP_LAYOUT_FEATURES_EXT: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDeviceAttachmentFeedbackLoopLayoutFeaturesEXT",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_ATTACHMENT_FEEDBACK_LOOP_LAYOUT_FEATURES_EXT,
+        ),
+        category: Some(StructCategory::Features),
+        size: size_of::<vk::PhysicalDeviceAttachmentFeedbackLoopLayoutFeaturesEXT<'static>>(),
+        providers: &["VK_EXT_attachment_feedback_loop_layout"],
+        members: &[member!(
+            vk::PhysicalDeviceAttachmentFeedbackLoopLayoutFeaturesEXT<'static>,
+            attachment_feedback_loop_layout,
+            "attachmentFeedbackLoopLayout",
+            T::Bool32
+        )],
+    };
+
+pub(super) static PHYSICAL_DEVICE_BLEND_OPERATION_ADVANCED_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceBlendOperationAdvancedFeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_BLEND_OPERATION_ADVANCED_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceBlendOperationAdvancedFeaturesEXT<'static>>(),
+    providers: &["VK_EXT_blend_operation_advanced"],
+    members: &[member!(
+        vk::PhysicalDeviceBlendOperationAdvancedFeaturesEXT<'static>,
+        advanced_blend_coherent_operations,
+        "advancedBlendCoherentOperations",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_BORDER_COLOR_SWIZZLE_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceBorderColorSwizzleFeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_BORDER_COLOR_SWIZZLE_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceBorderColorSwizzleFeaturesEXT<'static>>(),
+    providers: &["VK_EXT_border_color_swizzle"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceBorderColorSwizzleFeaturesEXT<'static>,
+            border_color_swizzle,
+            "borderColorSwizzle",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceBorderColorSwizzleFeaturesEXT<'static>,
+            border_color_swizzle_from_image,
+            "borderColorSwizzleFromImage",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_BUFFER_DEVICE_ADDRESS_FEATURES: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceBufferDeviceAddressFeatures",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_BUFFER_DEVICE_ADDRESS_FEATURES),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceBufferDeviceAddressFeatures<'static>>(),
+    providers: &["VK_VERSION_1_2", "VK_KHR_buffer_device_address"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceBufferDeviceAddressFeatures<'static>,
+            buffer_device_address,
+            "bufferDeviceAddress",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceBufferDeviceAddressFeatures<'static>,
+            buffer_device_address_capture_replay,
+            "bufferDeviceAddressCaptureReplay",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceBufferDeviceAddressFeatures<'static>,
+            buffer_device_address_multi_device,
+            "bufferDeviceAddressMultiDevice",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_BUFFER_DEVICE_ADDRESS_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceBufferDeviceAddressFeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_BUFFER_DEVICE_ADDRESS_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceBufferDeviceAddressFeaturesEXT<'static>>(),
+    providers: &["VK_EXT_buffer_device_address"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceBufferDeviceAddressFeaturesEXT<'static>,
+            buffer_device_address,
+            "bufferDeviceAddress",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceBufferDeviceAddressFeaturesEXT<'static>,
+            buffer_device_address_capture_replay,
+            "bufferDeviceAddressCaptureReplay",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceBufferDeviceAddressFeaturesEXT<'static>,
+            buffer_device_address_multi_device,
+            "bufferDeviceAddressMultiDevice",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_CLUSTER_CULLING_SHADER_FEATURES_HUAWEI: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceClusterCullingShaderFeaturesHUAWEI",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_CLUSTER_CULLING_SHADER_FEATURES_HUAWEI),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceClusterCullingShaderFeaturesHUAWEI<'static>>(),
+    providers: &["VK_HUAWEI_cluster_culling_shader"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceClusterCullingShaderFeaturesHUAWEI<'static>,
+            clusterculling_shader,
+            "clustercullingShader",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceClusterCullingShaderFeaturesHUAWEI<'static>,
+            multiview_cluster_culling_shader,
+            "multiviewClusterCullingShader",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_COHERENT_MEMORY_FEATURES_AMD: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceCoherentMemoryFeaturesAMD",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_COHERENT_MEMORY_FEATURES_AMD),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceCoherentMemoryFeaturesAMD<'static>>(),
+    providers: &["VK_AMD_device_coherent_memory"],
+    members: &[member!(
+        vk::PhysicalDeviceCoherentMemoryFeaturesAMD<'static>,
+        device_coherent_memory,
+        "deviceCoherentMemory",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_COLOR_WRITE_ENABLE_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceColorWriteEnableFeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_COLOR_WRITE_ENABLE_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceColorWriteEnableFeaturesEXT<'static>>(),
+    providers: &["VK_EXT_color_write_enable"],
+    members: &[member!(
+        vk::PhysicalDeviceColorWriteEnableFeaturesEXT<'static>,
+        color_write_enable,
+        "colorWriteEnable",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_COMPUTE_SHADER_DERIVATIVES_FEATURES_NV: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceComputeShaderDerivativesFeaturesNV",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_COMPUTE_SHADER_DERIVATIVES_FEATURES_NV),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceComputeShaderDerivativesFeaturesNV<'static>>(),
+    providers: &["VK_NV_compute_shader_derivatives"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceComputeShaderDerivativesFeaturesNV<'static>,
+            compute_derivative_group_quads,
+            "computeDerivativeGroupQuads",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceComputeShaderDerivativesFeaturesNV<'static>,
+            compute_derivative_group_linear,
+            "computeDerivativeGroupLinear",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_CONDITIONAL_RENDERING_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceConditionalRenderingFeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_CONDITIONAL_RENDERING_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceConditionalRenderingFeaturesEXT<'static>>(),
+    providers: &["VK_EXT_conditional_rendering"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceConditionalRenderingFeaturesEXT<'static>,
+            conditional_rendering,
+            "conditionalRendering",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceConditionalRenderingFeaturesEXT<'static>,
+            inherited_conditional_rendering,
+            "inheritedConditionalRendering",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_COOPERATIVE_MATRIX_FEATURES_KHR: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceCooperativeMatrixFeaturesKHR",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_COOPERATIVE_MATRIX_FEATURES_KHR),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceCooperativeMatrixFeaturesKHR<'static>>(),
+    providers: &["VK_KHR_cooperative_matrix"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceCooperativeMatrixFeaturesKHR<'static>,
+            cooperative_matrix,
+            "cooperativeMatrix",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceCooperativeMatrixFeaturesKHR<'static>,
+            cooperative_matrix_robust_buffer_access,
+            "cooperativeMatrixRobustBufferAccess",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_COOPERATIVE_MATRIX_FEATURES_NV: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceCooperativeMatrixFeaturesNV",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_COOPERATIVE_MATRIX_FEATURES_NV),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceCooperativeMatrixFeaturesNV<'static>>(),
+    providers: &["VK_NV_cooperative_matrix"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceCooperativeMatrixFeaturesNV<'static>,
+            cooperative_matrix,
+            "cooperativeMatrix",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceCooperativeMatrixFeaturesNV<'static>,
+            cooperative_matrix_robust_buffer_access,
+            "cooperativeMatrixRobustBufferAccess",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_COPY_MEMORY_INDIRECT_FEATURES_NV: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceCopyMemoryIndirectFeaturesNV",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_COPY_MEMORY_INDIRECT_FEATURES_NV),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceCopyMemoryIndirectFeaturesNV<'static>>(),
+    providers: &["VK_NV_copy_memory_indirect"],
+    members: &[member!(
+        vk::PhysicalDeviceCopyMemoryIndirectFeaturesNV<'static>,
+        indirect_copy,
+        "indirectCopy",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_CORNER_SAMPLED_IMAGE_FEATURES_NV: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceCornerSampledImageFeaturesNV",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_CORNER_SAMPLED_IMAGE_FEATURES_NV),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceCornerSampledImageFeaturesNV<'static>>(),
+    providers: &["VK_NV_corner_sampled_image"],
+    members: &[member!(
+        vk::PhysicalDeviceCornerSampledImageFeaturesNV<'static>,
+        corner_sampled_image,
+        "cornerSampledImage",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_COVERAGE_REDUCTION_MODE_FEATURES_NV: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceCoverageReductionModeFeaturesNV",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_COVERAGE_REDUCTION_MODE_FEATURES_NV),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceCoverageReductionModeFeaturesNV<'static>>(),
+    providers: &["VK_NV_coverage_reduction_mode"],
+    members: &[member!(
+        vk::PhysicalDeviceCoverageReductionModeFeaturesNV<'static>,
+        coverage_reduction_mode,
+        "coverageReductionMode",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_CUBIC_CLAMP_FEATURES_QCOM: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceCubicClampFeaturesQCOM",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_CUBIC_CLAMP_FEATURES_QCOM),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceCubicClampFeaturesQCOM<'static>>(),
+    providers: &["VK_QCOM_filter_cubic_clamp"],
+    members: &[member!(
+        vk::PhysicalDeviceCubicClampFeaturesQCOM<'static>,
+        cubic_range_clamp,
+        "cubicRangeClamp",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_CUBIC_WEIGHTS_FEATURES_QCOM: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceCubicWeightsFeaturesQCOM",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_CUBIC_WEIGHTS_FEATURES_QCOM),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceCubicWeightsFeaturesQCOM<'static>>(),
+    providers: &["VK_QCOM_filter_cubic_weights"],
+    members: &[member!(
+        vk::PhysicalDeviceCubicWeightsFeaturesQCOM<'static>,
+        selectable_cubic_weights,
+        "selectableCubicWeights",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_CUDA_KERNEL_LAUNCH_FEATURES_NV: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceCudaKernelLaunchFeaturesNV",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_CUDA_KERNEL_LAUNCH_FEATURES_NV),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceCudaKernelLaunchFeaturesNV<'static>>(),
+    providers: &["VK_NV_cuda_kernel_launch"],
+    members: &[member!(
+        vk::PhysicalDeviceCudaKernelLaunchFeaturesNV<'static>,
+        cuda_kernel_launch_features,
+        "cudaKernelLaunchFeatures",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_CUSTOM_BORDER_COLOR_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceCustomBorderColorFeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_CUSTOM_BORDER_COLOR_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceCustomBorderColorFeaturesEXT<'static>>(),
+    providers: &["VK_EXT_custom_border_color"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceCustomBorderColorFeaturesEXT<'static>,
+            custom_border_colors,
+            "customBorderColors",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceCustomBorderColorFeaturesEXT<'static>,
+            custom_border_color_without_format,
+            "customBorderColorWithoutFormat",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_DEDICATED_ALLOCATION_IMAGE_ALIASING_FEATURES_NV: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDeviceDedicatedAllocationImageAliasingFeaturesNV",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_DEDICATED_ALLOCATION_IMAGE_ALIASING_FEATURES_NV,
+        ),
+        category: Some(StructCategory::Features),
+        size: size_of::<vk::PhysicalDeviceDedicatedAllocationImageAliasingFeaturesNV<'static>>(),
+        providers: &["VK_NV_dedicated_allocation_image_aliasing"],
+        members: &[member!(
+            vk::PhysicalDeviceDedicatedAllocationImageAliasingFeaturesNV<'static>,
+            dedicated_allocation_image_aliasing,
+            "dedicatedAllocationImageAliasing",
+            T::Bool32
+        )],
+    };
+
+pub(super) static PHYSICAL_DEVICE_DEPTH_BIAS_CONTROL_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceDepthBiasControlFeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_DEPTH_BIAS_CONTROL_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceDepthBiasControlFeaturesEXT<'static>>(),
+    providers: &["VK_EXT_depth_bias_control"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceDepthBiasControlFeaturesEXT<'static>,
+            depth_bias_control,
+            "depthBiasControl",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceDepthBiasControlFeaturesEXT<'static>,
+            least_representable_value_force_unorm_representation,
+            "leastRepresentableValueForceUnormRepresentation",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceDepthBiasControlFeaturesEXT<'static>,
+            float_representation,
+            "floatRepresentation",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceDepthBiasControlFeaturesEXT<'static>,
+            depth_bias_exact,
+            "depthBiasExact",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_DEPTH_CLAMP_ZERO_ONE_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceDepthClampZeroOneFeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_DEPTH_CLAMP_ZERO_ONE_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceDepthClampZeroOneFeaturesEXT<'static>>(),
+    providers: &["VK_EXT_depth_clamp_zero_one"],
+    members: &[member!(
+        vk::PhysicalDeviceDepthClampZeroOneFeaturesEXT<'static>,
+        depth_clamp_zero_one,
+        "depthClampZeroOne",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_DEPTH_CLIP_CONTROL_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceDepthClipControlFeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_DEPTH_CLIP_CONTROL_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceDepthClipControlFeaturesEXT<'static>>(),
+    providers: &["VK_EXT_depth_clip_control"],
+    members: &[member!(
+        vk::PhysicalDeviceDepthClipControlFeaturesEXT<'static>,
+        depth_clip_control,
+        "depthClipControl",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_DEPTH_CLIP_ENABLE_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceDepthClipEnableFeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_DEPTH_CLIP_ENABLE_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceDepthClipEnableFeaturesEXT<'static>>(),
+    providers: &["VK_EXT_depth_clip_enable"],
+    members: &[member!(
+        vk::PhysicalDeviceDepthClipEnableFeaturesEXT<'static>,
+        depth_clip_enable,
+        "depthClipEnable",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_DESCRIPTOR_BUFFER_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceDescriptorBufferFeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_DESCRIPTOR_BUFFER_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceDescriptorBufferFeaturesEXT<'static>>(),
+    providers: &["VK_EXT_descriptor_buffer"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceDescriptorBufferFeaturesEXT<'static>,
+            descriptor_buffer,
+            "descriptorBuffer",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorBufferFeaturesEXT<'static>,
+            descriptor_buffer_capture_replay,
+            "descriptorBufferCaptureReplay",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorBufferFeaturesEXT<'static>,
+            descriptor_buffer_image_layout_ignored,
+            "descriptorBufferImageLayoutIgnored",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorBufferFeaturesEXT<'static>,
+            descriptor_buffer_push_descriptors,
+            "descriptorBufferPushDescriptors",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_FEATURES: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceDescriptorIndexingFeatures",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_FEATURES),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceDescriptorIndexingFeatures<'static>>(),
+    providers: &["VK_VERSION_1_2", "VK_EXT_descriptor_indexing"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceDescriptorIndexingFeatures<'static>,
+            shader_input_attachment_array_dynamic_indexing,
+            "shaderInputAttachmentArrayDynamicIndexing",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorIndexingFeatures<'static>,
+            shader_uniform_texel_buffer_array_dynamic_indexing,
+            "shaderUniformTexelBufferArrayDynamicIndexing",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorIndexingFeatures<'static>,
+            shader_storage_texel_buffer_array_dynamic_indexing,
+            "shaderStorageTexelBufferArrayDynamicIndexing",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorIndexingFeatures<'static>,
+            shader_uniform_buffer_array_non_uniform_indexing,
+            "shaderUniformBufferArrayNonUniformIndexing",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorIndexingFeatures<'static>,
+            shader_sampled_image_array_non_uniform_indexing,
+            "shaderSampledImageArrayNonUniformIndexing",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorIndexingFeatures<'static>,
+            shader_storage_buffer_array_non_uniform_indexing,
+            "shaderStorageBufferArrayNonUniformIndexing",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorIndexingFeatures<'static>,
+            shader_storage_image_array_non_uniform_indexing,
+            "shaderStorageImageArrayNonUniformIndexing",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorIndexingFeatures<'static>,
+            shader_input_attachment_array_non_uniform_indexing,
+            "shaderInputAttachmentArrayNonUniformIndexing",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorIndexingFeatures<'static>,
+            shader_uniform_texel_buffer_array_non_uniform_indexing,
+            "shaderUniformTexelBufferArrayNonUniformIndexing",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorIndexingFeatures<'static>,
+            shader_storage_texel_buffer_array_non_uniform_indexing,
+            "shaderStorageTexelBufferArrayNonUniformIndexing",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorIndexingFeatures<'static>,
+            descriptor_binding_uniform_buffer_update_after_bind,
+            "descriptorBindingUniformBufferUpdateAfterBind",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorIndexingFeatures<'static>,
+            descriptor_binding_sampled_image_update_after_bind,
+            "descriptorBindingSampledImageUpdateAfterBind",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorIndexingFeatures<'static>,
+            descriptor_binding_storage_image_update_after_bind,
+            "descriptorBindingStorageImageUpdateAfterBind",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorIndexingFeatures<'static>,
+            descriptor_binding_storage_buffer_update_after_bind,
+            "descriptorBindingStorageBufferUpdateAfterBind",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorIndexingFeatures<'static>,
+            descriptor_binding_uniform_texel_buffer_update_after_bind,
+            "descriptorBindingUniformTexelBufferUpdateAfterBind",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorIndexingFeatures<'static>,
+            descriptor_binding_storage_texel_buffer_update_after_bind,
+            "descriptorBindingStorageTexelBufferUpdateAfterBind",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorIndexingFeatures<'static>,
+            descriptor_binding_update_unused_while_pending,
+            "descriptorBindingUpdateUnusedWhilePending",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorIndexingFeatures<'static>,
+            descriptor_binding_partially_bound,
+            "descriptorBindingPartiallyBound",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorIndexingFeatures<'static>,
+            descriptor_binding_variable_descriptor_count,
+            "descriptorBindingVariableDescriptorCount",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorIndexingFeatures<'static>,
+            runtime_descriptor_array,
+            "runtimeDescriptorArray",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_DESCRIPTOR_POOL_OVERALLOCATION_FEATURES_NV: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDeviceDescriptorPoolOverallocationFeaturesNV",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_DESCRIPTOR_POOL_OVERALLOCATION_FEATURES_NV,
+        ),
+        category: Some(StructCategory::Features),
+        size: size_of::<vk::PhysicalDeviceDescriptorPoolOverallocationFeaturesNV<'static>>(),
+        providers: &["VK_NV_descriptor_pool_overallocation"],
+        members: &[member!(
+            vk::PhysicalDeviceDescriptorPoolOverallocationFeaturesNV<'static>,
+            descriptor_pool_overallocation,
+            "descriptorPoolOverallocation",
+            T::Bool32
+        )],
+    };
+
+pub(super) static PHYSICAL_DEVICE_DESCRIPTOR_SET_HOST_MAPPING_FEATURES_VALVE: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDeviceDescriptorSetHostMappingFeaturesVALVE",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_DESCRIPTOR_SET_HOST_MAPPING_FEATURES_VALVE,
+        ),
+        category: Some(StructCategory::Features),
+        size: size_of::<vk::PhysicalDeviceDescriptorSetHostMappingFeaturesVALVE<'static>>(),
+        providers: &["VK_VALVE_descriptor_set_host_mapping"],
+        members: &[member!(
+            vk::PhysicalDeviceDescriptorSetHostMappingFeaturesVALVE<'static>,
+            descriptor_set_host_mapping,
+            "descriptorSetHostMapping",
+            T::Bool32
+        )],
+    };
+
+pub(super) static PHYSICAL_DEVICE_DEVICE_GENERATED_COMMANDS_COMPUTE_FEATURES_NV: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDeviceDeviceGeneratedCommandsComputeFeaturesNV",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_DEVICE_GENERATED_COMMANDS_COMPUTE_FEATURES_NV,
+        ),
+        category: Some(StructCategory::Features),
+        size: size_of::<vk::PhysicalDeviceDeviceGeneratedCommandsComputeFeaturesNV<'static>>(),
+        providers: &["VK_NV_device_generated_commands_compute"],
+        members: &[
+            member!(
+                vk::PhysicalDeviceDeviceGeneratedCommandsComputeFeaturesNV<'static>,
+                device_generated_compute,
+                "deviceGeneratedCompute",
+                T::Bool32
+            ),
+            member!(
+                vk::PhysicalDeviceDeviceGeneratedCommandsComputeFeaturesNV<'static>,
+                device_generated_compute_pipelines,
+                "deviceGeneratedComputePipelines",
+                T::Bool32
+            ),
+            member!(
+                vk::PhysicalDeviceDeviceGeneratedCommandsComputeFeaturesNV<'static>,
+                device_generated_compute_capture_replay,
+                "deviceGeneratedComputeCaptureReplay",
+                T::Bool32
+            ),
+        ],
+    };
+
+pub(super) static PHYSICAL_DEVICE_DEVICE_GENERATED_COMMANDS_FEATURES_NV: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceDeviceGeneratedCommandsFeaturesNV",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_DEVICE_GENERATED_COMMANDS_FEATURES_NV),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceDeviceGeneratedCommandsFeaturesNV<'static>>(),
+    providers: &["VK_NV_device_generated_commands"],
+    members: &[member!(
+        vk::PhysicalDeviceDeviceGeneratedCommandsFeaturesNV<'static>,
+        device_generated_commands,
+        "deviceGeneratedCommands",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_DEVICE_MEMORY_REPORT_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceDeviceMemoryReportFeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_DEVICE_MEMORY_REPORT_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceDeviceMemoryReportFeaturesEXT<'static>>(),
+    providers: &["VK_EXT_device_memory_report"],
+    members: &[member!(
+        vk::PhysicalDeviceDeviceMemoryReportFeaturesEXT<'static>,
+        device_memory_report,
+        "deviceMemoryReport",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_DIAGNOSTICS_CONFIG_FEATURES_NV: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceDiagnosticsConfigFeaturesNV",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_DIAGNOSTICS_CONFIG_FEATURES_NV),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceDiagnosticsConfigFeaturesNV<'static>>(),
+    providers: &["VK_NV_device_diagnostics_config"],
+    members: &[member!(
+        vk::PhysicalDeviceDiagnosticsConfigFeaturesNV<'static>,
+        diagnostics_config,
+        "diagnosticsConfig",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_DISPLACEMENT_MICROMAP_FEATURES_NV: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceDisplacementMicromapFeaturesNV",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_DISPLACEMENT_MICROMAP_FEATURES_NV),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceDisplacementMicromapFeaturesNV<'static>>(),
+    providers: &["VK_NV_displacement_micromap"],
+    members: &[member!(
+        vk::PhysicalDeviceDisplacementMicromapFeaturesNV<'static>,
+        displacement_micromap,
+        "displacementMicromap",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_DYNAMIC_RENDERING_FEATURES: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceDynamicRenderingFeatures",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_DYNAMIC_RENDERING_FEATURES),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceDynamicRenderingFeatures<'static>>(),
+    providers: &["VK_VERSION_1_3", "VK_KHR_dynamic_rendering"],
+    members: &[member!(
+        vk::PhysicalDeviceDynamicRenderingFeatures<'static>,
+        dynamic_rendering,
+        "dynamicRendering",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_DYNAMIC_RENDERING_LOCAL_READ_FEATURES_KHR: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDeviceDynamicRenderingLocalReadFeaturesKHR",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_DYNAMIC_RENDERING_LOCAL_READ_FEATURES_KHR,
+        ),
+        category: Some(StructCategory::Features),
+        size: size_of::<vk::PhysicalDeviceDynamicRenderingLocalReadFeaturesKHR<'static>>(),
+        providers: &["VK_KHR_dynamic_rendering_local_read"],
+        members: &[member!(
+            vk::PhysicalDeviceDynamicRenderingLocalReadFeaturesKHR<'static>,
+            dynamic_rendering_local_read,
+            "dynamicRenderingLocalRead",
+            T::Bool32
+        )],
+    };
+
+pub(super) static PHYSICAL_DEVICE_DYNAMIC_RENDERING_UNUSED_ATTACHMENTS_FEATURES_EXT: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDeviceDynamicRenderingUnusedAttachmentsFeaturesEXT",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_DYNAMIC_RENDERING_UNUSED_ATTACHMENTS_FEATURES_EXT,
+        ),
+        category: Some(StructCategory::Features),
+        size: size_of::<vk::PhysicalDeviceDynamicRenderingUnusedAttachmentsFeaturesEXT<'static>>(),
+        providers: &["VK_EXT_dynamic_rendering_unused_attachments"],
+        members: &[member!(
+            vk::PhysicalDeviceDynamicRenderingUnusedAttachmentsFeaturesEXT<'static>,
+            dynamic_rendering_unused_attachments,
+            "dynamicRenderingUnusedAttachments",
+            T::Bool32
+        )],
+    };
+
+pub(super) static PHYSICAL_DEVICE_EXCLUSIVE_SCISSOR_FEATURES_NV: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceExclusiveScissorFeaturesNV",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_EXCLUSIVE_SCISSOR_FEATURES_NV),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceExclusiveScissorFeaturesNV<'static>>(),
+    providers: &["VK_NV_scissor_exclusive"],
+    members: &[member!(
+        vk::PhysicalDeviceExclusiveScissorFeaturesNV<'static>,
+        exclusive_scissor,
+        "exclusiveScissor",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_EXTENDED_DYNAMIC_STATE2_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceExtendedDynamicState2FeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_EXTENDED_DYNAMIC_STATE_2_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceExtendedDynamicState2FeaturesEXT<'static>>(),
+    providers: &["VK_EXT_extended_dynamic_state2"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceExtendedDynamicState2FeaturesEXT<'static>,
+            extended_dynamic_state2,
+            "extendedDynamicState2",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceExtendedDynamicState2FeaturesEXT<'static>,
+            extended_dynamic_state2_logic_op,
+            "extendedDynamicState2LogicOp",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceExtendedDynamicState2FeaturesEXT<'static>,
+            extended_dynamic_state2_patch_control_points,
+            "extendedDynamicState2PatchControlPoints",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_EXTENDED_DYNAMIC_STATE3_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_EXTENDED_DYNAMIC_STATE_3_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceExtendedDynamicState3FeaturesEXT<'static>>(),
+    providers: &["VK_EXT_extended_dynamic_state3"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceExtendedDynamicState3FeaturesEXT<'static>,
+            extended_dynamic_state3_tessellation_domain_origin,
+            "extendedDynamicState3TessellationDomainOrigin",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceExtendedDynamicState3FeaturesEXT<'static>,
+            extended_dynamic_state3_depth_clamp_enable,
+            "extendedDynamicState3DepthClampEnable",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceExtendedDynamicState3FeaturesEXT<'static>,
+            extended_dynamic_state3_polygon_mode,
+            "extendedDynamicState3PolygonMode",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceExtendedDynamicState3FeaturesEXT<'static>,
+            extended_dynamic_state3_rasterization_samples,
+            "extendedDynamicState3RasterizationSamples",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceExtendedDynamicState3FeaturesEXT<'static>,
+            extended_dynamic_state3_sample_mask,
+            "extendedDynamicState3SampleMask",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceExtendedDynamicState3FeaturesEXT<'static>,
+            extended_dynamic_state3_alpha_to_coverage_enable,
+            "extendedDynamicState3AlphaToCoverageEnable",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceExtendedDynamicState3FeaturesEXT<'static>,
+            extended_dynamic_state3_alpha_to_one_enable,
+            "extendedDynamicState3AlphaToOneEnable",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceExtendedDynamicState3FeaturesEXT<'static>,
+            extended_dynamic_state3_logic_op_enable,
+            "extendedDynamicState3LogicOpEnable",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceExtendedDynamicState3FeaturesEXT<'static>,
+            extended_dynamic_state3_color_blend_enable,
+            "extendedDynamicState3ColorBlendEnable",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceExtendedDynamicState3FeaturesEXT<'static>,
+            extended_dynamic_state3_color_blend_equation,
+            "extendedDynamicState3ColorBlendEquation",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceExtendedDynamicState3FeaturesEXT<'static>,
+            extended_dynamic_state3_color_write_mask,
+            "extendedDynamicState3ColorWriteMask",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceExtendedDynamicState3FeaturesEXT<'static>,
+            extended_dynamic_state3_rasterization_stream,
+            "extendedDynamicState3RasterizationStream",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceExtendedDynamicState3FeaturesEXT<'static>,
+            extended_dynamic_state3_conservative_rasterization_mode,
+            "extendedDynamicState3ConservativeRasterizationMode",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceExtendedDynamicState3FeaturesEXT<'static>,
+            extended_dynamic_state3_extra_primitive_overestimation_size,
+            "extendedDynamicState3ExtraPrimitiveOverestimationSize",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceExtendedDynamicState3FeaturesEXT<'static>,
+            extended_dynamic_state3_depth_clip_enable,
+            "extendedDynamicState3DepthClipEnable",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceExtendedDynamicState3FeaturesEXT<'static>,
+            extended_dynamic_state3_sample_locations_enable,
+            "extendedDynamicState3SampleLocationsEnable",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceExtendedDynamicState3FeaturesEXT<'static>,
+            extended_dynamic_state3_color_blend_advanced,
+            "extendedDynamicState3ColorBlendAdvanced",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceExtendedDynamicState3FeaturesEXT<'static>,
+            extended_dynamic_state3_provoking_vertex_mode,
+            "extendedDynamicState3ProvokingVertexMode",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceExtendedDynamicState3FeaturesEXT<'static>,
+            extended_dynamic_state3_line_rasterization_mode,
+            "extendedDynamicState3LineRasterizationMode",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceExtendedDynamicState3FeaturesEXT<'static>,
+            extended_dynamic_state3_line_stipple_enable,
+            "extendedDynamicState3LineStippleEnable",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceExtendedDynamicState3FeaturesEXT<'static>,
+            extended_dynamic_state3_depth_clip_negative_one_to_one,
+            "extendedDynamicState3DepthClipNegativeOneToOne",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceExtendedDynamicState3FeaturesEXT<'static>,
+            extended_dynamic_state3_viewport_w_scaling_enable,
+            "extendedDynamicState3ViewportWScalingEnable",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceExtendedDynamicState3FeaturesEXT<'static>,
+            extended_dynamic_state3_viewport_swizzle,
+            "extendedDynamicState3ViewportSwizzle",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceExtendedDynamicState3FeaturesEXT<'static>,
+            extended_dynamic_state3_coverage_to_color_enable,
+            "extendedDynamicState3CoverageToColorEnable",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceExtendedDynamicState3FeaturesEXT<'static>,
+            extended_dynamic_state3_coverage_to_color_location,
+            "extendedDynamicState3CoverageToColorLocation",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceExtendedDynamicState3FeaturesEXT<'static>,
+            extended_dynamic_state3_coverage_modulation_mode,
+            "extendedDynamicState3CoverageModulationMode",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceExtendedDynamicState3FeaturesEXT<'static>,
+            extended_dynamic_state3_coverage_modulation_table_enable,
+            "extendedDynamicState3CoverageModulationTableEnable",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceExtendedDynamicState3FeaturesEXT<'static>,
+            extended_dynamic_state3_coverage_modulation_table,
+            "extendedDynamicState3CoverageModulationTable",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceExtendedDynamicState3FeaturesEXT<'static>,
+            extended_dynamic_state3_coverage_reduction_mode,
+            "extendedDynamicState3CoverageReductionMode",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceExtendedDynamicState3FeaturesEXT<'static>,
+            extended_dynamic_state3_representative_fragment_test_enable,
+            "extendedDynamicState3RepresentativeFragmentTestEnable",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceExtendedDynamicState3FeaturesEXT<'static>,
+            extended_dynamic_state3_shading_rate_image_enable,
+            "extendedDynamicState3ShadingRateImageEnable",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_EXTENDED_DYNAMIC_STATE_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceExtendedDynamicStateFeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_EXTENDED_DYNAMIC_STATE_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceExtendedDynamicStateFeaturesEXT<'static>>(),
+    providers: &["VK_EXT_extended_dynamic_state"],
+    members: &[member!(
+        vk::PhysicalDeviceExtendedDynamicStateFeaturesEXT<'static>,
+        extended_dynamic_state,
+        "extendedDynamicState",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_EXTENDED_SPARSE_ADDRESS_SPACE_FEATURES_NV: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDeviceExtendedSparseAddressSpaceFeaturesNV",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_EXTENDED_SPARSE_ADDRESS_SPACE_FEATURES_NV,
+        ),
+        category: Some(StructCategory::Features),
+        size: size_of::<vk::PhysicalDeviceExtendedSparseAddressSpaceFeaturesNV<'static>>(),
+        providers: &["VK_NV_extended_sparse_address_space"],
+        members: &[member!(
+            vk::PhysicalDeviceExtendedSparseAddressSpaceFeaturesNV<'static>,
+            extended_sparse_address_space,
+            "extendedSparseAddressSpace",
+            T::Bool32
+        )],
+    };
+
+pub(super) static PHYSICAL_DEVICE_EXTERNAL_FORMAT_RESOLVE_FEATURES_ANDROID: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDeviceExternalFormatResolveFeaturesANDROID",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_EXTERNAL_FORMAT_RESOLVE_FEATURES_ANDROID,
+        ),
+        category: Some(StructCategory::Features),
+        size: size_of::<vk::PhysicalDeviceExternalFormatResolveFeaturesANDROID<'static>>(),
+        providers: &["VK_ANDROID_external_format_resolve"],
+        members: &[member!(
+            vk::PhysicalDeviceExternalFormatResolveFeaturesANDROID<'static>,
+            external_format_resolve,
+            "externalFormatResolve",
+            T::Bool32
+        )],
+    };
+
+pub(super) static PHYSICAL_DEVICE_EXTERNAL_MEMORY_RDMA_FEATURES_NV: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceExternalMemoryRDMAFeaturesNV",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_EXTERNAL_MEMORY_RDMA_FEATURES_NV),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceExternalMemoryRDMAFeaturesNV<'static>>(),
+    providers: &["VK_NV_external_memory_rdma"],
+    members: &[member!(
+        vk::PhysicalDeviceExternalMemoryRDMAFeaturesNV<'static>,
+        external_memory_rdma,
+        "externalMemoryRdma",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_EXTERNAL_MEMORY_SCREEN_BUFFER_FEATURES_QNX: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDeviceExternalMemoryScreenBufferFeaturesQNX",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_EXTERNAL_MEMORY_SCREEN_BUFFER_FEATURES_QNX,
+        ),
+        category: Some(StructCategory::Features),
+        size: size_of::<vk::PhysicalDeviceExternalMemoryScreenBufferFeaturesQNX<'static>>(),
+        providers: &["VK_QNX_external_memory_screen_buffer"],
+        members: &[member!(
+            vk::PhysicalDeviceExternalMemoryScreenBufferFeaturesQNX<'static>,
+            screen_buffer_import,
+            "screenBufferImport",
+            T::Bool32
+        )],
+    };
+
+pub(super) static PHYSICAL_DEVICE_FAULT_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceFaultFeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_FAULT_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceFaultFeaturesEXT<'static>>(),
+    providers: &["VK_EXT_device_fault"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceFaultFeaturesEXT<'static>,
+            device_fault,
+            "deviceFault",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFaultFeaturesEXT<'static>,
+            device_fault_vendor_binary,
+            "deviceFaultVendorBinary",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP2_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceFragmentDensityMap2FeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_2_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceFragmentDensityMap2FeaturesEXT<'static>>(),
+    providers: &["VK_EXT_fragment_density_map2"],
+    members: &[member!(
+        vk::PhysicalDeviceFragmentDensityMap2FeaturesEXT<'static>,
+        fragment_density_map_deferred,
+        "fragmentDensityMapDeferred",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceFragmentDensityMapFeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceFragmentDensityMapFeaturesEXT<'static>>(),
+    providers: &["VK_EXT_fragment_density_map"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceFragmentDensityMapFeaturesEXT<'static>,
+            fragment_density_map,
+            "fragmentDensityMap",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFragmentDensityMapFeaturesEXT<'static>,
+            fragment_density_map_dynamic,
+            "fragmentDensityMapDynamic",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFragmentDensityMapFeaturesEXT<'static>,
+            fragment_density_map_non_subsampled_images,
+            "fragmentDensityMapNonSubsampledImages",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_OFFSET_FEATURES_QCOM: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDeviceFragmentDensityMapOffsetFeaturesQCOM",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_OFFSET_FEATURES_QCOM,
+        ),
+        category: Some(StructCategory::Features),
+        size: size_of::<vk::PhysicalDeviceFragmentDensityMapOffsetFeaturesQCOM<'static>>(),
+        providers: &["VK_QCOM_fragment_density_map_offset"],
+        members: &[member!(
+            vk::PhysicalDeviceFragmentDensityMapOffsetFeaturesQCOM<'static>,
+            fragment_density_map_offset,
+            "fragmentDensityMapOffset",
+            T::Bool32
+        )],
+    };
+
+pub(super) static PHYSICAL_DEVICE_FRAGMENT_SHADER_BARYCENTRIC_FEATURES_KHR: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDeviceFragmentShaderBarycentricFeaturesKHR",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_FRAGMENT_SHADER_BARYCENTRIC_FEATURES_KHR,
+        ),
+        category: Some(StructCategory::Features),
+        size: size_of::<vk::PhysicalDeviceFragmentShaderBarycentricFeaturesKHR<'static>>(),
+        providers: &[
+            "VK_NV_fragment_shader_barycentric",
+            "VK_KHR_fragment_shader_barycentric",
+        ],
+        members: &[member!(
+            vk::PhysicalDeviceFragmentShaderBarycentricFeaturesKHR<'static>,
+            fragment_shader_barycentric,
+            "fragmentShaderBarycentric",
+            T::Bool32
+        )],
+    };
+
+pub(super) static PHYSICAL_DEVICE_FRAGMENT_SHADER_INTERLOCK_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceFragmentShaderInterlockFeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_FRAGMENT_SHADER_INTERLOCK_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceFragmentShaderInterlockFeaturesEXT<'static>>(),
+    providers: &["VK_EXT_fragment_shader_interlock"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceFragmentShaderInterlockFeaturesEXT<'static>,
+            fragment_shader_sample_interlock,
+            "fragmentShaderSampleInterlock",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFragmentShaderInterlockFeaturesEXT<'static>,
+            fragment_shader_pixel_interlock,
+            "fragmentShaderPixelInterlock",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFragmentShaderInterlockFeaturesEXT<'static>,
+            fragment_shader_shading_rate_interlock,
+            "fragmentShaderShadingRateInterlock",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_ENUMS_FEATURES_NV: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDeviceFragmentShadingRateEnumsFeaturesNV",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_ENUMS_FEATURES_NV,
+        ),
+        category: Some(StructCategory::Features),
+        size: size_of::<vk::PhysicalDeviceFragmentShadingRateEnumsFeaturesNV<'static>>(),
+        providers: &["VK_NV_fragment_shading_rate_enums"],
+        members: &[
+            member!(
+                vk::PhysicalDeviceFragmentShadingRateEnumsFeaturesNV<'static>,
+                fragment_shading_rate_enums,
+                "fragmentShadingRateEnums",
+                T::Bool32
+            ),
+            member!(
+                vk::PhysicalDeviceFragmentShadingRateEnumsFeaturesNV<'static>,
+                supersample_fragment_shading_rates,
+                "supersampleFragmentShadingRates",
+                T::Bool32
+            ),
+            member!(
+                vk::PhysicalDeviceFragmentShadingRateEnumsFeaturesNV<'static>,
+                no_invocation_fragment_shading_rates,
+                "noInvocationFragmentShadingRates",
+                T::Bool32
+            ),
+        ],
+    };
+
+pub(super) static PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_FEATURES_KHR: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceFragmentShadingRateFeaturesKHR",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_FEATURES_KHR),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceFragmentShadingRateFeaturesKHR<'static>>(),
+    providers: &["VK_KHR_fragment_shading_rate"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceFragmentShadingRateFeaturesKHR<'static>,
+            pipeline_fragment_shading_rate,
+            "pipelineFragmentShadingRate",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFragmentShadingRateFeaturesKHR<'static>,
+            primitive_fragment_shading_rate,
+            "primitiveFragmentShadingRate",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFragmentShadingRateFeaturesKHR<'static>,
+            attachment_fragment_shading_rate,
+            "attachmentFragmentShadingRate",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_FRAME_BOUNDARY_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceFrameBoundaryFeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_FRAME_BOUNDARY_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceFrameBoundaryFeaturesEXT<'static>>(),
+    providers: &["VK_EXT_frame_boundary"],
+    members: &[member!(
+        vk::PhysicalDeviceFrameBoundaryFeaturesEXT<'static>,
+        frame_boundary,
+        "frameBoundary",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_GLOBAL_PRIORITY_QUERY_FEATURES_KHR: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceGlobalPriorityQueryFeaturesKHR",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_GLOBAL_PRIORITY_QUERY_FEATURES_KHR),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceGlobalPriorityQueryFeaturesKHR<'static>>(),
+    providers: &["VK_KHR_global_priority", "VK_EXT_global_priority_query"],
+    members: &[member!(
+        vk::PhysicalDeviceGlobalPriorityQueryFeaturesKHR<'static>,
+        global_priority_query,
+        "globalPriorityQuery",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_GRAPHICS_PIPELINE_LIBRARY_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceGraphicsPipelineLibraryFeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_GRAPHICS_PIPELINE_LIBRARY_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceGraphicsPipelineLibraryFeaturesEXT<'static>>(),
+    providers: &["VK_EXT_graphics_pipeline_library"],
+    members: &[member!(
+        vk::PhysicalDeviceGraphicsPipelineLibraryFeaturesEXT<'static>,
+        graphics_pipeline_library,
+        "graphicsPipelineLibrary",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_HOST_IMAGE_COPY_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceHostImageCopyFeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_HOST_IMAGE_COPY_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceHostImageCopyFeaturesEXT<'static>>(),
+    providers: &["VK_EXT_host_image_copy"],
+    members: &[member!(
+        vk::PhysicalDeviceHostImageCopyFeaturesEXT<'static>,
+        host_image_copy,
+        "hostImageCopy",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_HOST_QUERY_RESET_FEATURES: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceHostQueryResetFeatures",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_HOST_QUERY_RESET_FEATURES),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceHostQueryResetFeatures<'static>>(),
+    providers: &["VK_VERSION_1_2", "VK_EXT_host_query_reset"],
+    members: &[member!(
+        vk::PhysicalDeviceHostQueryResetFeatures<'static>,
+        host_query_reset,
+        "hostQueryReset",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_IMAGE2_D_VIEW_OF3_D_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceImage2DViewOf3DFeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_IMAGE_2D_VIEW_OF_3D_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceImage2DViewOf3DFeaturesEXT<'static>>(),
+    providers: &["VK_EXT_image_2d_view_of_3d"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceImage2DViewOf3DFeaturesEXT<'static>,
+            image2_d_view_of3_d,
+            "image2DViewOf3D",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceImage2DViewOf3DFeaturesEXT<'static>,
+            sampler2_d_view_of3_d,
+            "sampler2DViewOf3D",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_IMAGE_COMPRESSION_CONTROL_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceImageCompressionControlFeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_IMAGE_COMPRESSION_CONTROL_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceImageCompressionControlFeaturesEXT<'static>>(),
+    providers: &["VK_EXT_image_compression_control"],
+    members: &[member!(
+        vk::PhysicalDeviceImageCompressionControlFeaturesEXT<'static>,
+        image_compression_control,
+        "imageCompressionControl",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_IMAGE_COMPRESSION_CONTROL_SWAPCHAIN_FEATURES_EXT: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDeviceImageCompressionControlSwapchainFeaturesEXT",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_IMAGE_COMPRESSION_CONTROL_SWAPCHAIN_FEATURES_EXT,
+        ),
+        category: Some(StructCategory::Features),
+        size: size_of::<vk::PhysicalDeviceImageCompressionControlSwapchainFeaturesEXT<'static>>(),
+        providers: &["VK_EXT_image_compression_control_swapchain"],
+        members: &[member!(
+            vk::PhysicalDeviceImageCompressionControlSwapchainFeaturesEXT<'static>,
+            image_compression_control_swapchain,
+            "imageCompressionControlSwapchain",
+            T::Bool32
+        )],
+    };
+
+pub(super) static PHYSICAL_DEVICE_IMAGE_PROCESSING2_FEATURES_QCOM: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceImageProcessing2FeaturesQCOM",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_IMAGE_PROCESSING_2_FEATURES_QCOM),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceImageProcessing2FeaturesQCOM<'static>>(),
+    providers: &["VK_QCOM_image_processing2"],
+    members: &[member!(
+        vk::PhysicalDeviceImageProcessing2FeaturesQCOM<'static>,
+        texture_block_match2,
+        "textureBlockMatch2",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_IMAGE_PROCESSING_FEATURES_QCOM: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceImageProcessingFeaturesQCOM",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_IMAGE_PROCESSING_FEATURES_QCOM),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceImageProcessingFeaturesQCOM<'static>>(),
+    providers: &["VK_QCOM_image_processing"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceImageProcessingFeaturesQCOM<'static>,
+            texture_sample_weighted,
+            "textureSampleWeighted",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceImageProcessingFeaturesQCOM<'static>,
+            texture_box_filter,
+            "textureBoxFilter",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceImageProcessingFeaturesQCOM<'static>,
+            texture_block_match,
+            "textureBlockMatch",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_IMAGE_ROBUSTNESS_FEATURES: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceImageRobustnessFeatures",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_IMAGE_ROBUSTNESS_FEATURES),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceImageRobustnessFeatures<'static>>(),
+    providers: &["VK_VERSION_1_3", "VK_EXT_image_robustness"],
+    members: &[member!(
+        vk::PhysicalDeviceImageRobustnessFeatures<'static>,
+        robust_image_access,
+        "robustImageAccess",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_IMAGE_SLICED_VIEW_OF3_D_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceImageSlicedViewOf3DFeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_IMAGE_SLICED_VIEW_OF_3D_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceImageSlicedViewOf3DFeaturesEXT<'static>>(),
+    providers: &["VK_EXT_image_sliced_view_of_3d"],
+    members: &[member!(
+        vk::PhysicalDeviceImageSlicedViewOf3DFeaturesEXT<'static>,
+        image_sliced_view_of3_d,
+        "imageSlicedViewOf3D",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_IMAGE_VIEW_MIN_LOD_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceImageViewMinLodFeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_IMAGE_VIEW_MIN_LOD_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceImageViewMinLodFeaturesEXT<'static>>(),
+    providers: &["VK_EXT_image_view_min_lod"],
+    members: &[member!(
+        vk::PhysicalDeviceImageViewMinLodFeaturesEXT<'static>,
+        min_lod,
+        "minLod",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_IMAGELESS_FRAMEBUFFER_FEATURES: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceImagelessFramebufferFeatures",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_IMAGELESS_FRAMEBUFFER_FEATURES),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceImagelessFramebufferFeatures<'static>>(),
+    providers: &["VK_VERSION_1_2", "VK_KHR_imageless_framebuffer"],
+    members: &[member!(
+        vk::PhysicalDeviceImagelessFramebufferFeatures<'static>,
+        imageless_framebuffer,
+        "imagelessFramebuffer",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_INDEX_TYPE_UINT8_FEATURES_KHR: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceIndexTypeUint8FeaturesKHR",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_INDEX_TYPE_UINT8_FEATURES_KHR),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceIndexTypeUint8FeaturesKHR<'static>>(),
+    providers: &["VK_EXT_index_type_uint8", "VK_KHR_index_type_uint8"],
+    members: &[member!(
+        vk::PhysicalDeviceIndexTypeUint8FeaturesKHR<'static>,
+        index_type_uint8,
+        "indexTypeUint8",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_INHERITED_VIEWPORT_SCISSOR_FEATURES_NV: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceInheritedViewportScissorFeaturesNV",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_INHERITED_VIEWPORT_SCISSOR_FEATURES_NV),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceInheritedViewportScissorFeaturesNV<'static>>(),
+    providers: &["VK_NV_inherited_viewport_scissor"],
+    members: &[member!(
+        vk::PhysicalDeviceInheritedViewportScissorFeaturesNV<'static>,
+        inherited_viewport_scissor2_d,
+        "inheritedViewportScissor2D",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_FEATURES: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceInlineUniformBlockFeatures",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_FEATURES),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceInlineUniformBlockFeatures<'static>>(),
+    providers: &["VK_VERSION_1_3", "VK_EXT_inline_uniform_block"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceInlineUniformBlockFeatures<'static>,
+            inline_uniform_block,
+            "inlineUniformBlock",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceInlineUniformBlockFeatures<'static>,
+            descriptor_binding_inline_uniform_block_update_after_bind,
+            "descriptorBindingInlineUniformBlockUpdateAfterBind",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_INVOCATION_MASK_FEATURES_HUAWEI: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceInvocationMaskFeaturesHUAWEI",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_INVOCATION_MASK_FEATURES_HUAWEI),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceInvocationMaskFeaturesHUAWEI<'static>>(),
+    providers: &["VK_HUAWEI_invocation_mask"],
+    members: &[member!(
+        vk::PhysicalDeviceInvocationMaskFeaturesHUAWEI<'static>,
+        invocation_mask,
+        "invocationMask",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_LEGACY_DITHERING_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceLegacyDitheringFeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_LEGACY_DITHERING_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceLegacyDitheringFeaturesEXT<'static>>(),
+    providers: &["VK_EXT_legacy_dithering"],
+    members: &[member!(
+        vk::PhysicalDeviceLegacyDitheringFeaturesEXT<'static>,
+        legacy_dithering,
+        "legacyDithering",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_LINE_RASTERIZATION_FEATURES_KHR: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceLineRasterizationFeaturesKHR",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_LINE_RASTERIZATION_FEATURES_KHR),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceLineRasterizationFeaturesKHR<'static>>(),
+    providers: &["VK_EXT_line_rasterization", "VK_KHR_line_rasterization"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceLineRasterizationFeaturesKHR<'static>,
+            rectangular_lines,
+            "rectangularLines",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceLineRasterizationFeaturesKHR<'static>,
+            bresenham_lines,
+            "bresenhamLines",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceLineRasterizationFeaturesKHR<'static>,
+            smooth_lines,
+            "smoothLines",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceLineRasterizationFeaturesKHR<'static>,
+            stippled_rectangular_lines,
+            "stippledRectangularLines",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceLineRasterizationFeaturesKHR<'static>,
+            stippled_bresenham_lines,
+            "stippledBresenhamLines",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceLineRasterizationFeaturesKHR<'static>,
+            stippled_smooth_lines,
+            "stippledSmoothLines",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_LINEAR_COLOR_ATTACHMENT_FEATURES_NV: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceLinearColorAttachmentFeaturesNV",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_LINEAR_COLOR_ATTACHMENT_FEATURES_NV),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceLinearColorAttachmentFeaturesNV<'static>>(),
+    providers: &["VK_NV_linear_color_attachment"],
+    members: &[member!(
+        vk::PhysicalDeviceLinearColorAttachmentFeaturesNV<'static>,
+        linear_color_attachment,
+        "linearColorAttachment",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_MAINTENANCE4_FEATURES: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceMaintenance4Features",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_MAINTENANCE_4_FEATURES),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceMaintenance4Features<'static>>(),
+    providers: &["VK_VERSION_1_3", "VK_KHR_maintenance4"],
+    members: &[member!(
+        vk::PhysicalDeviceMaintenance4Features<'static>,
+        maintenance4,
+        "maintenance4",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_MAINTENANCE5_FEATURES_KHR: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceMaintenance5FeaturesKHR",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_MAINTENANCE_5_FEATURES_KHR),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceMaintenance5FeaturesKHR<'static>>(),
+    providers: &["VK_KHR_maintenance5"],
+    members: &[member!(
+        vk::PhysicalDeviceMaintenance5FeaturesKHR<'static>,
+        maintenance5,
+        "maintenance5",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_MAINTENANCE6_FEATURES_KHR: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceMaintenance6FeaturesKHR",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_MAINTENANCE_6_FEATURES_KHR),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceMaintenance6FeaturesKHR<'static>>(),
+    providers: &["VK_KHR_maintenance6"],
+    members: &[member!(
+        vk::PhysicalDeviceMaintenance6FeaturesKHR<'static>,
+        maintenance6,
+        "maintenance6",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_MAP_MEMORY_PLACED_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceMapMemoryPlacedFeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_MAP_MEMORY_PLACED_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceMapMemoryPlacedFeaturesEXT<'static>>(),
+    providers: &["VK_EXT_map_memory_placed"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceMapMemoryPlacedFeaturesEXT<'static>,
+            memory_map_placed,
+            "memoryMapPlaced",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceMapMemoryPlacedFeaturesEXT<'static>,
+            memory_map_range_placed,
+            "memoryMapRangePlaced",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceMapMemoryPlacedFeaturesEXT<'static>,
+            memory_unmap_reserve,
+            "memoryUnmapReserve",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_MEMORY_DECOMPRESSION_FEATURES_NV: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceMemoryDecompressionFeaturesNV",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_MEMORY_DECOMPRESSION_FEATURES_NV),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceMemoryDecompressionFeaturesNV<'static>>(),
+    providers: &["VK_NV_memory_decompression"],
+    members: &[member!(
+        vk::PhysicalDeviceMemoryDecompressionFeaturesNV<'static>,
+        memory_decompression,
+        "memoryDecompression",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_MEMORY_PRIORITY_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceMemoryPriorityFeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_MEMORY_PRIORITY_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceMemoryPriorityFeaturesEXT<'static>>(),
+    providers: &["VK_EXT_memory_priority"],
+    members: &[member!(
+        vk::PhysicalDeviceMemoryPriorityFeaturesEXT<'static>,
+        memory_priority,
+        "memoryPriority",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_MESH_SHADER_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceMeshShaderFeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_MESH_SHADER_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceMeshShaderFeaturesEXT<'static>>(),
+    providers: &["VK_EXT_mesh_shader"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceMeshShaderFeaturesEXT<'static>,
+            task_shader,
+            "taskShader",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceMeshShaderFeaturesEXT<'static>,
+            mesh_shader,
+            "meshShader",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceMeshShaderFeaturesEXT<'static>,
+            multiview_mesh_shader,
+            "multiviewMeshShader",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceMeshShaderFeaturesEXT<'static>,
+            primitive_fragment_shading_rate_mesh_shader,
+            "primitiveFragmentShadingRateMeshShader",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceMeshShaderFeaturesEXT<'static>,
+            mesh_shader_queries,
+            "meshShaderQueries",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_MESH_SHADER_FEATURES_NV: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceMeshShaderFeaturesNV",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_MESH_SHADER_FEATURES_NV),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceMeshShaderFeaturesNV<'static>>(),
+    providers: &["VK_NV_mesh_shader"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceMeshShaderFeaturesNV<'static>,
+            task_shader,
+            "taskShader",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceMeshShaderFeaturesNV<'static>,
+            mesh_shader,
+            "meshShader",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_MULTI_DRAW_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceMultiDrawFeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_MULTI_DRAW_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceMultiDrawFeaturesEXT<'static>>(),
+    providers: &["VK_EXT_multi_draw"],
+    members: &[member!(
+        vk::PhysicalDeviceMultiDrawFeaturesEXT<'static>,
+        multi_draw,
+        "multiDraw",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_MULTISAMPLED_RENDER_TO_SINGLE_SAMPLED_FEATURES_EXT: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDeviceMultisampledRenderToSingleSampledFeaturesEXT",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_MULTISAMPLED_RENDER_TO_SINGLE_SAMPLED_FEATURES_EXT,
+        ),
+        category: Some(StructCategory::Features),
+        size: size_of::<vk::PhysicalDeviceMultisampledRenderToSingleSampledFeaturesEXT<'static>>(),
+        providers: &["VK_EXT_multisampled_render_to_single_sampled"],
+        members: &[member!(
+            vk::PhysicalDeviceMultisampledRenderToSingleSampledFeaturesEXT<'static>,
+            multisampled_render_to_single_sampled,
+            "multisampledRenderToSingleSampled",
+            T::Bool32
+        )],
+    };
+
+pub(super) static PHYSICAL_DEVICE_MULTIVIEW_FEATURES: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceMultiviewFeatures",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_MULTIVIEW_FEATURES),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceMultiviewFeatures<'static>>(),
+    providers: &["VK_VERSION_1_1", "VK_KHR_multiview"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceMultiviewFeatures<'static>,
+            multiview,
+            "multiview",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceMultiviewFeatures<'static>,
+            multiview_geometry_shader,
+            "multiviewGeometryShader",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceMultiviewFeatures<'static>,
+            multiview_tessellation_shader,
+            "multiviewTessellationShader",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_MULTIVIEW_PER_VIEW_RENDER_AREAS_FEATURES_QCOM: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDeviceMultiviewPerViewRenderAreasFeaturesQCOM",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_MULTIVIEW_PER_VIEW_RENDER_AREAS_FEATURES_QCOM,
+        ),
+        category: Some(StructCategory::Features),
+        size: size_of::<vk::PhysicalDeviceMultiviewPerViewRenderAreasFeaturesQCOM<'static>>(),
+        providers: &["VK_QCOM_multiview_per_view_render_areas"],
+        members: &[member!(
+            vk::PhysicalDeviceMultiviewPerViewRenderAreasFeaturesQCOM<'static>,
+            multiview_per_view_render_areas,
+            "multiviewPerViewRenderAreas",
+            T::Bool32
+        )],
+    };
+
+pub(super) static PHYSICAL_DEVICE_MULTIVIEW_PER_VIEW_VIEWPORTS_FEATURES_QCOM: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDeviceMultiviewPerViewViewportsFeaturesQCOM",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_MULTIVIEW_PER_VIEW_VIEWPORTS_FEATURES_QCOM,
+        ),
+        category: Some(StructCategory::Features),
+        size: size_of::<vk::PhysicalDeviceMultiviewPerViewViewportsFeaturesQCOM<'static>>(),
+        providers: &["VK_QCOM_multiview_per_view_viewports"],
+        members: &[member!(
+            vk::PhysicalDeviceMultiviewPerViewViewportsFeaturesQCOM<'static>,
+            multiview_per_view_viewports,
+            "multiviewPerViewViewports",
+            T::Bool32
+        )],
+    };
+
+pub(super) static PHYSICAL_DEVICE_MUTABLE_DESCRIPTOR_TYPE_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceMutableDescriptorTypeFeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_MUTABLE_DESCRIPTOR_TYPE_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceMutableDescriptorTypeFeaturesEXT<'static>>(),
+    providers: &[
+        "VK_VALVE_mutable_descriptor_type",
+        "VK_EXT_mutable_descriptor_type",
+    ],
+    members: &[member!(
+        vk::PhysicalDeviceMutableDescriptorTypeFeaturesEXT<'static>,
+        mutable_descriptor_type,
+        "mutableDescriptorType",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_NESTED_COMMAND_BUFFER_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceNestedCommandBufferFeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_NESTED_COMMAND_BUFFER_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceNestedCommandBufferFeaturesEXT<'static>>(),
+    providers: &["VK_EXT_nested_command_buffer"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceNestedCommandBufferFeaturesEXT<'static>,
+            nested_command_buffer,
+            "nestedCommandBuffer",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceNestedCommandBufferFeaturesEXT<'static>,
+            nested_command_buffer_rendering,
+            "nestedCommandBufferRendering",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceNestedCommandBufferFeaturesEXT<'static>,
+            nested_command_buffer_simultaneous_use,
+            "nestedCommandBufferSimultaneousUse",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_NON_SEAMLESS_CUBE_MAP_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceNonSeamlessCubeMapFeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_NON_SEAMLESS_CUBE_MAP_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceNonSeamlessCubeMapFeaturesEXT<'static>>(),
+    providers: &["VK_EXT_non_seamless_cube_map"],
+    members: &[member!(
+        vk::PhysicalDeviceNonSeamlessCubeMapFeaturesEXT<'static>,
+        non_seamless_cube_map,
+        "nonSeamlessCubeMap",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_OPACITY_MICROMAP_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceOpacityMicromapFeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_OPACITY_MICROMAP_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceOpacityMicromapFeaturesEXT<'static>>(),
+    providers: &["VK_EXT_opacity_micromap"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceOpacityMicromapFeaturesEXT<'static>,
+            micromap,
+            "micromap",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceOpacityMicromapFeaturesEXT<'static>,
+            micromap_capture_replay,
+            "micromapCaptureReplay",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceOpacityMicromapFeaturesEXT<'static>,
+            micromap_host_commands,
+            "micromapHostCommands",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_OPTICAL_FLOW_FEATURES_NV: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceOpticalFlowFeaturesNV",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_OPTICAL_FLOW_FEATURES_NV),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceOpticalFlowFeaturesNV<'static>>(),
+    providers: &["VK_NV_optical_flow"],
+    members: &[member!(
+        vk::PhysicalDeviceOpticalFlowFeaturesNV<'static>,
+        optical_flow,
+        "opticalFlow",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_PAGEABLE_DEVICE_LOCAL_MEMORY_FEATURES_EXT: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDevicePageableDeviceLocalMemoryFeaturesEXT",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_PAGEABLE_DEVICE_LOCAL_MEMORY_FEATURES_EXT,
+        ),
+        category: Some(StructCategory::Features),
+        size: size_of::<vk::PhysicalDevicePageableDeviceLocalMemoryFeaturesEXT<'static>>(),
+        providers: &["VK_EXT_pageable_device_local_memory"],
+        members: &[member!(
+            vk::PhysicalDevicePageableDeviceLocalMemoryFeaturesEXT<'static>,
+            pageable_device_local_memory,
+            "pageableDeviceLocalMemory",
+            T::Bool32
+        )],
+    };
+
+pub(super) static PHYSICAL_DEVICE_PER_STAGE_DESCRIPTOR_SET_FEATURES_NV: StructInfo = StructInfo {
+    name: "VkPhysicalDevicePerStageDescriptorSetFeaturesNV",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_PER_STAGE_DESCRIPTOR_SET_FEATURES_NV),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDevicePerStageDescriptorSetFeaturesNV<'static>>(),
+    providers: &["VK_NV_per_stage_descriptor_set"],
+    members: &[
+        member!(
+            vk::PhysicalDevicePerStageDescriptorSetFeaturesNV<'static>,
+            per_stage_descriptor_set,
+            "perStageDescriptorSet",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDevicePerStageDescriptorSetFeaturesNV<'static>,
+            dynamic_pipeline_layout,
+            "dynamicPipelineLayout",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_PERFORMANCE_QUERY_FEATURES_KHR: StructInfo = StructInfo {
+    name: "VkPhysicalDevicePerformanceQueryFeaturesKHR",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_PERFORMANCE_QUERY_FEATURES_KHR),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDevicePerformanceQueryFeaturesKHR<'static>>(),
+    providers: &["VK_KHR_performance_query"],
+    members: &[
+        member!(
+            vk::PhysicalDevicePerformanceQueryFeaturesKHR<'static>,
+            performance_counter_query_pools,
+            "performanceCounterQueryPools",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDevicePerformanceQueryFeaturesKHR<'static>,
+            performance_counter_multiple_query_pools,
+            "performanceCounterMultipleQueryPools",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_PIPELINE_CREATION_CACHE_CONTROL_FEATURES: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDevicePipelineCreationCacheControlFeatures",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_PIPELINE_CREATION_CACHE_CONTROL_FEATURES,
+        ),
+        category: Some(StructCategory::Features),
+        size: size_of::<vk::PhysicalDevicePipelineCreationCacheControlFeatures<'static>>(),
+        providers: &["VK_VERSION_1_3", "VK_EXT_pipeline_creation_cache_control"],
+        members: &[member!(
+            vk::PhysicalDevicePipelineCreationCacheControlFeatures<'static>,
+            pipeline_creation_cache_control,
+            "pipelineCreationCacheControl",
+            T::Bool32
+        )],
+    };
+
+pub(super) static PHYSICAL_DEVICE_PIPELINE_EXECUTABLE_PROPERTIES_FEATURES_KHR: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDevicePipelineExecutablePropertiesFeaturesKHR",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_PIPELINE_EXECUTABLE_PROPERTIES_FEATURES_KHR,
+        ),
+        category: Some(StructCategory::Features),
+        size: size_of::<vk::PhysicalDevicePipelineExecutablePropertiesFeaturesKHR<'static>>(),
+        providers: &["VK_KHR_pipeline_executable_properties"],
+        members: &[member!(
+            vk::PhysicalDevicePipelineExecutablePropertiesFeaturesKHR<'static>,
+            pipeline_executable_info,
+            "pipelineExecutableInfo",
+            T::Bool32
+        )],
+    };
+
+pub(super) static PHYSICAL_DEVICE_PIPELINE_LIBRARY_GROUP_HANDLES_FEATURES_EXT: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDevicePipelineLibraryGroupHandlesFeaturesEXT",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_PIPELINE_LIBRARY_GROUP_HANDLES_FEATURES_EXT,
+        ),
+        category: Some(StructCategory::Features),
+        size: size_of::<vk::PhysicalDevicePipelineLibraryGroupHandlesFeaturesEXT<'static>>(),
+        providers: &["VK_EXT_pipeline_library_group_handles"],
+        members: &[member!(
+            vk::PhysicalDevicePipelineLibraryGroupHandlesFeaturesEXT<'static>,
+            pipeline_library_group_handles,
+            "pipelineLibraryGroupHandles",
+            T::Bool32
+        )],
+    };
+
+pub(super) static PHYSICAL_DEVICE_PIPELINE_PROPERTIES_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDevicePipelinePropertiesFeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_PIPELINE_PROPERTIES_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDevicePipelinePropertiesFeaturesEXT<'static>>(),
+    providers: &["VK_EXT_pipeline_properties"],
+    members: &[member!(
+        vk::PhysicalDevicePipelinePropertiesFeaturesEXT<'static>,
+        pipeline_properties_identifier,
+        "pipelinePropertiesIdentifier",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_PIPELINE_PROTECTED_ACCESS_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDevicePipelineProtectedAccessFeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_PIPELINE_PROTECTED_ACCESS_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDevicePipelineProtectedAccessFeaturesEXT<'static>>(),
+    providers: &["VK_EXT_pipeline_protected_access"],
+    members: &[member!(
+        vk::PhysicalDevicePipelineProtectedAccessFeaturesEXT<'static>,
+        pipeline_protected_access,
+        "pipelineProtectedAccess",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_PIPELINE_ROBUSTNESS_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDevicePipelineRobustnessFeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_PIPELINE_ROBUSTNESS_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDevicePipelineRobustnessFeaturesEXT<'static>>(),
+    providers: &["VK_EXT_pipeline_robustness"],
+    members: &[member!(
+        vk::PhysicalDevicePipelineRobustnessFeaturesEXT<'static>,
+        pipeline_robustness,
+        "pipelineRobustness",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_PORTABILITY_SUBSET_FEATURES_KHR: StructInfo = StructInfo {
+    name: "VkPhysicalDevicePortabilitySubsetFeaturesKHR",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_PORTABILITY_SUBSET_FEATURES_KHR),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDevicePortabilitySubsetFeaturesKHR<'static>>(),
+    providers: &["VK_KHR_portability_subset"],
+    members: &[
+        member!(
+            vk::PhysicalDevicePortabilitySubsetFeaturesKHR<'static>,
+            constant_alpha_color_blend_factors,
+            "constantAlphaColorBlendFactors",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDevicePortabilitySubsetFeaturesKHR<'static>,
+            events,
+            "events",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDevicePortabilitySubsetFeaturesKHR<'static>,
+            image_view_format_reinterpretation,
+            "imageViewFormatReinterpretation",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDevicePortabilitySubsetFeaturesKHR<'static>,
+            image_view_format_swizzle,
+            "imageViewFormatSwizzle",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDevicePortabilitySubsetFeaturesKHR<'static>,
+            image_view2_d_on3_d_image,
+            "imageView2DOn3DImage",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDevicePortabilitySubsetFeaturesKHR<'static>,
+            multisample_array_image,
+            "multisampleArrayImage",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDevicePortabilitySubsetFeaturesKHR<'static>,
+            mutable_comparison_samplers,
+            "mutableComparisonSamplers",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDevicePortabilitySubsetFeaturesKHR<'static>,
+            point_polygons,
+            "pointPolygons",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDevicePortabilitySubsetFeaturesKHR<'static>,
+            sampler_mip_lod_bias,
+            "samplerMipLodBias",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDevicePortabilitySubsetFeaturesKHR<'static>,
+            separate_stencil_mask_ref,
+            "separateStencilMaskRef",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDevicePortabilitySubsetFeaturesKHR<'static>,
+            shader_sample_rate_interpolation_functions,
+            "shaderSampleRateInterpolationFunctions",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDevicePortabilitySubsetFeaturesKHR<'static>,
+            tessellation_isolines,
+            "tessellationIsolines",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDevicePortabilitySubsetFeaturesKHR<'static>,
+            tessellation_point_mode,
+            "tessellationPointMode",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDevicePortabilitySubsetFeaturesKHR<'static>,
+            triangle_fans,
+            "triangleFans",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDevicePortabilitySubsetFeaturesKHR<'static>,
+            vertex_attribute_access_beyond_stride,
+            "vertexAttributeAccessBeyondStride",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_PRESENT_BARRIER_FEATURES_NV: StructInfo = StructInfo {
+    name: "VkPhysicalDevicePresentBarrierFeaturesNV",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_PRESENT_BARRIER_FEATURES_NV),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDevicePresentBarrierFeaturesNV<'static>>(),
+    providers: &["VK_NV_present_barrier"],
+    members: &[member!(
+        vk::PhysicalDevicePresentBarrierFeaturesNV<'static>,
+        present_barrier,
+        "presentBarrier",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_PRESENT_ID_FEATURES_KHR: StructInfo = StructInfo {
+    name: "VkPhysicalDevicePresentIdFeaturesKHR",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_PRESENT_ID_FEATURES_KHR),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDevicePresentIdFeaturesKHR<'static>>(),
+    providers: &["VK_KHR_present_id"],
+    members: &[member!(
+        vk::PhysicalDevicePresentIdFeaturesKHR<'static>,
+        present_id,
+        "presentId",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_PRESENT_WAIT_FEATURES_KHR: StructInfo = StructInfo {
+    name: "VkPhysicalDevicePresentWaitFeaturesKHR",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_PRESENT_WAIT_FEATURES_KHR),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDevicePresentWaitFeaturesKHR<'static>>(),
+    providers: &["VK_KHR_present_wait"],
+    members: &[member!(
+        vk::PhysicalDevicePresentWaitFeaturesKHR<'static>,
+        present_wait,
+        "presentWait",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_PRIMITIVE_TOPOLOGY_LIST_RESTART_FEATURES_EXT: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDevicePrimitiveTopologyListRestartFeaturesEXT",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_PRIMITIVE_TOPOLOGY_LIST_RESTART_FEATURES_EXT,
+        ),
+        category: Some(StructCategory::Features),
+        size: size_of::<vk::PhysicalDevicePrimitiveTopologyListRestartFeaturesEXT<'static>>(),
+        providers: &["VK_EXT_primitive_topology_list_restart"],
+        members: &[
+            member!(
+                vk::PhysicalDevicePrimitiveTopologyListRestartFeaturesEXT<'static>,
+                primitive_topology_list_restart,
+                "primitiveTopologyListRestart",
+                T::Bool32
+            ),
+            member!(
+                vk::PhysicalDevicePrimitiveTopologyListRestartFeaturesEXT<'static>,
+                primitive_topology_patch_list_restart,
+                "primitiveTopologyPatchListRestart",
+                T::Bool32
+            ),
+        ],
+    };
+
+pub(super) static PHYSICAL_DEVICE_PRIMITIVES_GENERATED_QUERY_FEATURES_EXT: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDevicePrimitivesGeneratedQueryFeaturesEXT",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_PRIMITIVES_GENERATED_QUERY_FEATURES_EXT,
+        ),
+        category: Some(StructCategory::Features),
+        size: size_of::<vk::PhysicalDevicePrimitivesGeneratedQueryFeaturesEXT<'static>>(),
+        providers: &["VK_EXT_primitives_generated_query"],
+        members: &[
+            member!(
+                vk::PhysicalDevicePrimitivesGeneratedQueryFeaturesEXT<'static>,
+                primitives_generated_query,
+                "primitivesGeneratedQuery",
+                T::Bool32
+            ),
+            member!(
+                vk::PhysicalDevicePrimitivesGeneratedQueryFeaturesEXT<'static>,
+                primitives_generated_query_with_rasterizer_discard,
+                "primitivesGeneratedQueryWithRasterizerDiscard",
+                T::Bool32
+            ),
+            member!(
+                vk::PhysicalDevicePrimitivesGeneratedQueryFeaturesEXT<'static>,
+                primitives_generated_query_with_non_zero_streams,
+                "primitivesGeneratedQueryWithNonZeroStreams",
+                T::Bool32
+            ),
+        ],
+    };
+
+pub(super) static PHYSICAL_DEVICE_PRIVATE_DATA_FEATURES: StructInfo = StructInfo {
+    name: "VkPhysicalDevicePrivateDataFeatures",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_PRIVATE_DATA_FEATURES),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDevicePrivateDataFeatures<'static>>(),
+    providers: &["VK_VERSION_1_3", "VK_EXT_private_data"],
+    members: &[member!(
+        vk::PhysicalDevicePrivateDataFeatures<'static>,
+        private_data,
+        "privateData",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_PROTECTED_MEMORY_FEATURES: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceProtectedMemoryFeatures",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_PROTECTED_MEMORY_FEATURES),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceProtectedMemoryFeatures<'static>>(),
+    providers: &["VK_VERSION_1_1"],
+    members: &[member!(
+        vk::PhysicalDeviceProtectedMemoryFeatures<'static>,
+        protected_memory,
+        "protectedMemory",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_PROVOKING_VERTEX_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceProvokingVertexFeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_PROVOKING_VERTEX_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceProvokingVertexFeaturesEXT<'static>>(),
+    providers: &["VK_EXT_provoking_vertex"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceProvokingVertexFeaturesEXT<'static>,
+            provoking_vertex_last,
+            "provokingVertexLast",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceProvokingVertexFeaturesEXT<'static>,
+            transform_feedback_preserves_provoking_vertex,
+            "transformFeedbackPreservesProvokingVertex",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_RGBA10_X6_FORMATS_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceRGBA10X6FormatsFeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_RGBA10X6_FORMATS_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceRGBA10X6FormatsFeaturesEXT<'static>>(),
+    providers: &["VK_EXT_rgba10x6_formats"],
+    members: &[member!(
+        vk::PhysicalDeviceRGBA10X6FormatsFeaturesEXT<'static>,
+        format_rgba10x6_without_y_cb_cr_sampler,
+        "formatRgba10x6WithoutYCbCrSampler",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_RASTERIZATION_ORDER_ATTACHMENT_ACCESS_FEATURES_EXT: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDeviceRasterizationOrderAttachmentAccessFeaturesEXT",
+        structure_type: None,
+        category: Some(StructCategory::Features),
+        size: size_of::<vk::PhysicalDeviceRasterizationOrderAttachmentAccessFeaturesEXT<'static>>(),
+        providers: &[],
+        members: &[
+            member!(
+                vk::PhysicalDeviceRasterizationOrderAttachmentAccessFeaturesEXT<'static>,
+                rasterization_order_color_attachment_access,
+                "rasterizationOrderColorAttachmentAccess",
+                T::Bool32
+            ),
+            member!(
+                vk::PhysicalDeviceRasterizationOrderAttachmentAccessFeaturesEXT<'static>,
+                rasterization_order_depth_attachment_access,
+                "rasterizationOrderDepthAttachmentAccess",
+                T::Bool32
+            ),
+            member!(
+                vk::PhysicalDeviceRasterizationOrderAttachmentAccessFeaturesEXT<'static>,
+                rasterization_order_stencil_attachment_access,
+                "rasterizationOrderStencilAttachmentAccess",
+                T::Bool32
+            ),
+        ],
+    };
+
+pub(super) static PHYSICAL_DEVICE_RAW_ACCESS_CHAINS_FEATURES_NV: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceRawAccessChainsFeaturesNV",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_RAW_ACCESS_CHAINS_FEATURES_NV),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceRawAccessChainsFeaturesNV<'static>>(),
+    providers: &["VK_NV_raw_access_chains"],
+    members: &[member!(
+        vk::PhysicalDeviceRawAccessChainsFeaturesNV<'static>,
+        shader_raw_access_chains,
+        "shaderRawAccessChains",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_RAY_QUERY_FEATURES_KHR: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceRayQueryFeaturesKHR",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_RAY_QUERY_FEATURES_KHR),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceRayQueryFeaturesKHR<'static>>(),
+    providers: &["VK_KHR_ray_query"],
+    members: &[member!(
+        vk::PhysicalDeviceRayQueryFeaturesKHR<'static>,
+        ray_query,
+        "rayQuery",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_RAY_TRACING_INVOCATION_REORDER_FEATURES_NV: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDeviceRayTracingInvocationReorderFeaturesNV",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_RAY_TRACING_INVOCATION_REORDER_FEATURES_NV,
+        ),
+        category: Some(StructCategory::Features),
+        size: size_of::<vk::PhysicalDeviceRayTracingInvocationReorderFeaturesNV<'static>>(),
+        providers: &["VK_NV_ray_tracing_invocation_reorder"],
+        members: &[member!(
+            vk::PhysicalDeviceRayTracingInvocationReorderFeaturesNV<'static>,
+            ray_tracing_invocation_reorder,
+            "rayTracingInvocationReorder",
+            T::Bool32
+        )],
+    };
+
+pub(super) static PHYSICAL_DEVICE_RAY_TRACING_MAINTENANCE1_FEATURES_KHR: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceRayTracingMaintenance1FeaturesKHR",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_RAY_TRACING_MAINTENANCE_1_FEATURES_KHR),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceRayTracingMaintenance1FeaturesKHR<'static>>(),
+    providers: &["VK_KHR_ray_tracing_maintenance1"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceRayTracingMaintenance1FeaturesKHR<'static>,
+            ray_tracing_maintenance1,
+            "rayTracingMaintenance1",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceRayTracingMaintenance1FeaturesKHR<'static>,
+            ray_tracing_pipeline_trace_rays_indirect2,
+            "rayTracingPipelineTraceRaysIndirect2",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_RAY_TRACING_MOTION_BLUR_FEATURES_NV: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceRayTracingMotionBlurFeaturesNV",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_RAY_TRACING_MOTION_BLUR_FEATURES_NV),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceRayTracingMotionBlurFeaturesNV<'static>>(),
+    providers: &["VK_NV_ray_tracing_motion_blur"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceRayTracingMotionBlurFeaturesNV<'static>,
+            ray_tracing_motion_blur,
+            "rayTracingMotionBlur",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceRayTracingMotionBlurFeaturesNV<'static>,
+            ray_tracing_motion_blur_pipeline_trace_rays_indirect,
+            "rayTracingMotionBlurPipelineTraceRaysIndirect",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_RAY_TRACING_PIPELINE_FEATURES_KHR: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceRayTracingPipelineFeaturesKHR",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_RAY_TRACING_PIPELINE_FEATURES_KHR),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceRayTracingPipelineFeaturesKHR<'static>>(),
+    providers: &["VK_KHR_ray_tracing_pipeline"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceRayTracingPipelineFeaturesKHR<'static>,
+            ray_tracing_pipeline,
+            "rayTracingPipeline",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceRayTracingPipelineFeaturesKHR<'static>,
+            ray_tracing_pipeline_shader_group_handle_capture_replay,
+            "rayTracingPipelineShaderGroupHandleCaptureReplay",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceRayTracingPipelineFeaturesKHR<'static>,
+            ray_tracing_pipeline_shader_group_handle_capture_replay_mixed,
+            "rayTracingPipelineShaderGroupHandleCaptureReplayMixed",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceRayTracingPipelineFeaturesKHR<'static>,
+            ray_tracing_pipeline_trace_rays_indirect,
+            "rayTracingPipelineTraceRaysIndirect",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceRayTracingPipelineFeaturesKHR<'static>,
+            ray_traversal_primitive_culling,
+            "rayTraversalPrimitiveCulling",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_RAY_TRACING_POSITION_FETCH_FEATURES_KHR: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDeviceRayTracingPositionFetchFeaturesKHR",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_RAY_TRACING_POSITION_FETCH_FEATURES_KHR,
+        ),
+        category: Some(StructCategory::Features),
+        size: size_of::<vk::PhysicalDeviceRayTracingPositionFetchFeaturesKHR<'static>>(),
+        providers: &["VK_KHR_ray_tracing_position_fetch"],
+        members: &[member!(
+            vk::PhysicalDeviceRayTracingPositionFetchFeaturesKHR<'static>,
+            ray_tracing_position_fetch,
+            "rayTracingPositionFetch",
+            T::Bool32
+        )],
+    };
+
+pub(super) static PHYSICAL_DEVICE_RAY_TRACING_VALIDATION_FEATURES_NV: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceRayTracingValidationFeaturesNV",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_RAY_TRACING_VALIDATION_FEATURES_NV),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceRayTracingValidationFeaturesNV<'static>>(),
+    providers: &["VK_NV_ray_tracing_validation"],
+    members: &[member!(
+        vk::PhysicalDeviceRayTracingValidationFeaturesNV<'static>,
+        ray_tracing_validation,
+        "rayTracingValidation",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_RELAXED_LINE_RASTERIZATION_FEATURES_IMG: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDeviceRelaxedLineRasterizationFeaturesIMG",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_RELAXED_LINE_RASTERIZATION_FEATURES_IMG,
+        ),
+        category: Some(StructCategory::Features),
+        size: size_of::<vk::PhysicalDeviceRelaxedLineRasterizationFeaturesIMG<'static>>(),
+        providers: &["VK_IMG_relaxed_line_rasterization"],
+        members: &[member!(
+            vk::PhysicalDeviceRelaxedLineRasterizationFeaturesIMG<'static>,
+            relaxed_line_rasterization,
+            "relaxedLineRasterization",
+            T::Bool32
+        )],
+    };
+
+pub(super) static PHYSICAL_DEVICE_RENDER_PASS_STRIPED_FEATURES_ARM: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceRenderPassStripedFeaturesARM",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_RENDER_PASS_STRIPED_FEATURES_ARM),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceRenderPassStripedFeaturesARM<'static>>(),
+    providers: &["VK_ARM_render_pass_striped"],
+    members: &[member!(
+        vk::PhysicalDeviceRenderPassStripedFeaturesARM<'static>,
+        render_pass_striped,
+        "renderPassStriped",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_REPRESENTATIVE_FRAGMENT_TEST_FEATURES_NV: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDeviceRepresentativeFragmentTestFeaturesNV",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_REPRESENTATIVE_FRAGMENT_TEST_FEATURES_NV,
+        ),
+        category: Some(StructCategory::Features),
+        size: size_of::<vk::PhysicalDeviceRepresentativeFragmentTestFeaturesNV<'static>>(),
+        providers: &["VK_NV_representative_fragment_test"],
+        members: &[member!(
+            vk::PhysicalDeviceRepresentativeFragmentTestFeaturesNV<'static>,
+            representative_fragment_test,
+            "representativeFragmentTest",
+            T::Bool32
+        )],
+    };
+
+pub(super) static PHYSICAL_DEVICE_ROBUSTNESS2_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceRobustness2FeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_ROBUSTNESS_2_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceRobustness2FeaturesEXT<'static>>(),
+    providers: &["VK_EXT_robustness2"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceRobustness2FeaturesEXT<'static>,
+            robust_buffer_access2,
+            "robustBufferAccess2",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceRobustness2FeaturesEXT<'static>,
+            robust_image_access2,
+            "robustImageAccess2",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceRobustness2FeaturesEXT<'static>,
+            null_descriptor,
+            "nullDescriptor",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_SAMPLER_YCBCR_CONVERSION_FEATURES: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceSamplerYcbcrConversionFeatures",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_SAMPLER_YCBCR_CONVERSION_FEATURES),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceSamplerYcbcrConversionFeatures<'static>>(),
+    providers: &["VK_VERSION_1_1", "VK_KHR_sampler_ycbcr_conversion"],
+    members: &[member!(
+        vk::PhysicalDeviceSamplerYcbcrConversionFeatures<'static>,
+        sampler_ycbcr_conversion,
+        "samplerYcbcrConversion",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_SCALAR_BLOCK_LAYOUT_FEATURES: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceScalarBlockLayoutFeatures",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_SCALAR_BLOCK_LAYOUT_FEATURES),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceScalarBlockLayoutFeatures<'static>>(),
+    providers: &["VK_VERSION_1_2", "VK_EXT_scalar_block_layout"],
+    members: &[member!(
+        vk::PhysicalDeviceScalarBlockLayoutFeatures<'static>,
+        scalar_block_layout,
+        "scalarBlockLayout",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_SCHEDULING_CONTROLS_FEATURES_ARM: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceSchedulingControlsFeaturesARM",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_SCHEDULING_CONTROLS_FEATURES_ARM),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceSchedulingControlsFeaturesARM<'static>>(),
+    providers: &["VK_ARM_scheduling_controls"],
+    members: &[member!(
+        vk::PhysicalDeviceSchedulingControlsFeaturesARM<'static>,
+        scheduling_controls,
+        "schedulingControls",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_SEPARATE_DEPTH_STENCIL_LAYOUTS_FEATURES: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDeviceSeparateDepthStencilLayoutsFeatures",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_SEPARATE_DEPTH_STENCIL_LAYOUTS_FEATURES,
+        ),
+        category: Some(StructCategory::Features),
+        size: size_of::<vk::PhysicalDeviceSeparateDepthStencilLayoutsFeatures<'static>>(),
+        providers: &["VK_VERSION_1_2", "VK_KHR_separate_depth_stencil_layouts"],
+        members: &[member!(
+            vk::PhysicalDeviceSeparateDepthStencilLayoutsFeatures<'static>,
+            separate_depth_stencil_layouts,
+            "separateDepthStencilLayouts",
+            T::Bool32
+        )],
+    };
+
+pub(super) static PHYSICAL_DEVICE_SHADER_ATOMIC_FLOAT16_VECTOR_FEATURES_NV: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDeviceShaderAtomicFloat16VectorFeaturesNV",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_SHADER_ATOMIC_FLOAT16_VECTOR_FEATURES_NV,
+        ),
+        category: Some(StructCategory::Features),
+        size: size_of::<vk::PhysicalDeviceShaderAtomicFloat16VectorFeaturesNV<'static>>(),
+        providers: &["VK_NV_shader_atomic_float16_vector"],
+        members: &[member!(
+            vk::PhysicalDeviceShaderAtomicFloat16VectorFeaturesNV<'static>,
+            shader_float16_vector_atomics,
+            "shaderFloat16VectorAtomics",
+            T::Bool32
+        )],
+    };
+
+pub(super) static PHYSICAL_DEVICE_SHADER_ATOMIC_FLOAT2_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceShaderAtomicFloat2FeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_SHADER_ATOMIC_FLOAT_2_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceShaderAtomicFloat2FeaturesEXT<'static>>(),
+    providers: &["VK_EXT_shader_atomic_float2"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceShaderAtomicFloat2FeaturesEXT<'static>,
+            shader_buffer_float16_atomics,
+            "shaderBufferFloat16Atomics",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderAtomicFloat2FeaturesEXT<'static>,
+            shader_buffer_float16_atomic_add,
+            "shaderBufferFloat16AtomicAdd",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderAtomicFloat2FeaturesEXT<'static>,
+            shader_buffer_float16_atomic_min_max,
+            "shaderBufferFloat16AtomicMinMax",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderAtomicFloat2FeaturesEXT<'static>,
+            shader_buffer_float32_atomic_min_max,
+            "shaderBufferFloat32AtomicMinMax",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderAtomicFloat2FeaturesEXT<'static>,
+            shader_buffer_float64_atomic_min_max,
+            "shaderBufferFloat64AtomicMinMax",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderAtomicFloat2FeaturesEXT<'static>,
+            shader_shared_float16_atomics,
+            "shaderSharedFloat16Atomics",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderAtomicFloat2FeaturesEXT<'static>,
+            shader_shared_float16_atomic_add,
+            "shaderSharedFloat16AtomicAdd",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderAtomicFloat2FeaturesEXT<'static>,
+            shader_shared_float16_atomic_min_max,
+            "shaderSharedFloat16AtomicMinMax",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderAtomicFloat2FeaturesEXT<'static>,
+            shader_shared_float32_atomic_min_max,
+            "shaderSharedFloat32AtomicMinMax",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderAtomicFloat2FeaturesEXT<'static>,
+            shader_shared_float64_atomic_min_max,
+            "shaderSharedFloat64AtomicMinMax",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderAtomicFloat2FeaturesEXT<'static>,
+            shader_image_float32_atomic_min_max,
+            "shaderImageFloat32AtomicMinMax",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderAtomicFloat2FeaturesEXT<'static>,
+            sparse_image_float32_atomic_min_max,
+            "sparseImageFloat32AtomicMinMax",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_SHADER_ATOMIC_FLOAT_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceShaderAtomicFloatFeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_SHADER_ATOMIC_FLOAT_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceShaderAtomicFloatFeaturesEXT<'static>>(),
+    providers: &["VK_EXT_shader_atomic_float"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceShaderAtomicFloatFeaturesEXT<'static>,
+            shader_buffer_float32_atomics,
+            "shaderBufferFloat32Atomics",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderAtomicFloatFeaturesEXT<'static>,
+            shader_buffer_float32_atomic_add,
+            "shaderBufferFloat32AtomicAdd",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderAtomicFloatFeaturesEXT<'static>,
+            shader_buffer_float64_atomics,
+            "shaderBufferFloat64Atomics",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderAtomicFloatFeaturesEXT<'static>,
+            shader_buffer_float64_atomic_add,
+            "shaderBufferFloat64AtomicAdd",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderAtomicFloatFeaturesEXT<'static>,
+            shader_shared_float32_atomics,
+            "shaderSharedFloat32Atomics",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderAtomicFloatFeaturesEXT<'static>,
+            shader_shared_float32_atomic_add,
+            "shaderSharedFloat32AtomicAdd",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderAtomicFloatFeaturesEXT<'static>,
+            shader_shared_float64_atomics,
+            "shaderSharedFloat64Atomics",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderAtomicFloatFeaturesEXT<'static>,
+            shader_shared_float64_atomic_add,
+            "shaderSharedFloat64AtomicAdd",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderAtomicFloatFeaturesEXT<'static>,
+            shader_image_float32_atomics,
+            "shaderImageFloat32Atomics",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderAtomicFloatFeaturesEXT<'static>,
+            shader_image_float32_atomic_add,
+            "shaderImageFloat32AtomicAdd",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderAtomicFloatFeaturesEXT<'static>,
+            sparse_image_float32_atomics,
+            "sparseImageFloat32Atomics",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderAtomicFloatFeaturesEXT<'static>,
+            sparse_image_float32_atomic_add,
+            "sparseImageFloat32AtomicAdd",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_SHADER_ATOMIC_INT64_FEATURES: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceShaderAtomicInt64Features",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_SHADER_ATOMIC_INT64_FEATURES),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceShaderAtomicInt64Features<'static>>(),
+    providers: &["VK_VERSION_1_2", "VK_KHR_shader_atomic_int64"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceShaderAtomicInt64Features<'static>,
+            shader_buffer_int64_atomics,
+            "shaderBufferInt64Atomics",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderAtomicInt64Features<'static>,
+            shader_shared_int64_atomics,
+            "shaderSharedInt64Atomics",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_SHADER_CLOCK_FEATURES_KHR: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceShaderClockFeaturesKHR",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_SHADER_CLOCK_FEATURES_KHR),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceShaderClockFeaturesKHR<'static>>(),
+    providers: &["VK_KHR_shader_clock"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceShaderClockFeaturesKHR<'static>,
+            shader_subgroup_clock,
+            "shaderSubgroupClock",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderClockFeaturesKHR<'static>,
+            shader_device_clock,
+            "shaderDeviceClock",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_SHADER_CORE_BUILTINS_FEATURES_ARM: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceShaderCoreBuiltinsFeaturesARM",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_SHADER_CORE_BUILTINS_FEATURES_ARM),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceShaderCoreBuiltinsFeaturesARM<'static>>(),
+    providers: &["VK_ARM_shader_core_builtins"],
+    members: &[member!(
+        vk::PhysicalDeviceShaderCoreBuiltinsFeaturesARM<'static>,
+        shader_core_builtins,
+        "shaderCoreBuiltins",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_SHADER_DEMOTE_TO_HELPER_INVOCATION_FEATURES: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDeviceShaderDemoteToHelperInvocationFeatures",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_SHADER_DEMOTE_TO_HELPER_INVOCATION_FEATURES,
+        ),
+        category: Some(StructCategory::Features),
+        size: size_of::<vk::PhysicalDeviceShaderDemoteToHelperInvocationFeatures<'static>>(),
+        providers: &[
+            "VK_VERSION_1_3",
+            "VK_EXT_shader_demote_to_helper_invocation",
+        ],
+        members: &[member!(
+            vk::PhysicalDeviceShaderDemoteToHelperInvocationFeatures<'static>,
+            shader_demote_to_helper_invocation,
+            "shaderDemoteToHelperInvocation",
+            T::Bool32
+        )],
+    };
+
+pub(super) static PHYSICAL_DEVICE_SHADER_DRAW_PARAMETERS_FEATURES: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceShaderDrawParametersFeatures",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_SHADER_DRAW_PARAMETERS_FEATURES),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceShaderDrawParametersFeatures<'static>>(),
+    providers: &["VK_VERSION_1_1"],
+    members: &[member!(
+        vk::PhysicalDeviceShaderDrawParametersFeatures<'static>,
+        shader_draw_parameters,
+        "shaderDrawParameters",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_SHADER_EARLY_AND_LATE_FRAGMENT_TESTS_FEATURES_AMD: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDeviceShaderEarlyAndLateFragmentTestsFeaturesAMD",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_SHADER_EARLY_AND_LATE_FRAGMENT_TESTS_FEATURES_AMD,
+        ),
+        category: Some(StructCategory::Features),
+        size: size_of::<vk::PhysicalDeviceShaderEarlyAndLateFragmentTestsFeaturesAMD<'static>>(),
+        providers: &["VK_AMD_shader_early_and_late_fragment_tests"],
+        members: &[member!(
+            vk::PhysicalDeviceShaderEarlyAndLateFragmentTestsFeaturesAMD<'static>,
+            shader_early_and_late_fragment_tests,
+            "shaderEarlyAndLateFragmentTests",
+            T::Bool32
+        )],
+    };
+
+pub(super) static PHYSICAL_DEVICE_SHADER_ENQUEUE_FEATURES_AMDX: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceShaderEnqueueFeaturesAMDX",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_SHADER_ENQUEUE_FEATURES_AMDX),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceShaderEnqueueFeaturesAMDX<'static>>(),
+    providers: &["VK_AMDX_shader_enqueue"],
+    members: &[member!(
+        vk::PhysicalDeviceShaderEnqueueFeaturesAMDX<'static>,
+        shader_enqueue,
+        "shaderEnqueue",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_SHADER_EXPECT_ASSUME_FEATURES_KHR: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceShaderExpectAssumeFeaturesKHR",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_SHADER_EXPECT_ASSUME_FEATURES_KHR),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceShaderExpectAssumeFeaturesKHR<'static>>(),
+    providers: &["VK_KHR_shader_expect_assume"],
+    members: &[member!(
+        vk::PhysicalDeviceShaderExpectAssumeFeaturesKHR<'static>,
+        shader_expect_assume,
+        "shaderExpectAssume",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_SHADER_FLOAT16_INT8_FEATURES: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceShaderFloat16Int8Features",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_SHADER_FLOAT16_INT8_FEATURES),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceShaderFloat16Int8Features<'static>>(),
+    providers: &["VK_VERSION_1_2", "VK_KHR_shader_float16_int8"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceShaderFloat16Int8Features<'static>,
+            shader_float16,
+            "shaderFloat16",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderFloat16Int8Features<'static>,
+            shader_int8,
+            "shaderInt8",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_SHADER_FLOAT_CONTROLS2_FEATURES_KHR: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceShaderFloatControls2FeaturesKHR",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_SHADER_FLOAT_CONTROLS_2_FEATURES_KHR),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceShaderFloatControls2FeaturesKHR<'static>>(),
+    providers: &["VK_KHR_shader_float_controls2"],
+    members: &[member!(
+        vk::PhysicalDeviceShaderFloatControls2FeaturesKHR<'static>,
+        shader_float_controls2,
+        "shaderFloatControls2",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_SHADER_IMAGE_ATOMIC_INT64_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceShaderImageAtomicInt64FeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_SHADER_IMAGE_ATOMIC_INT64_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceShaderImageAtomicInt64FeaturesEXT<'static>>(),
+    providers: &["VK_EXT_shader_image_atomic_int64"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceShaderImageAtomicInt64FeaturesEXT<'static>,
+            shader_image_int64_atomics,
+            "shaderImageInt64Atomics",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderImageAtomicInt64FeaturesEXT<'static>,
+            sparse_image_int64_atomics,
+            "sparseImageInt64Atomics",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_SHADER_IMAGE_FOOTPRINT_FEATURES_NV: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceShaderImageFootprintFeaturesNV",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_SHADER_IMAGE_FOOTPRINT_FEATURES_NV),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceShaderImageFootprintFeaturesNV<'static>>(),
+    providers: &["VK_NV_shader_image_footprint"],
+    members: &[member!(
+        vk::PhysicalDeviceShaderImageFootprintFeaturesNV<'static>,
+        image_footprint,
+        "imageFootprint",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_SHADER_INTEGER_DOT_PRODUCT_FEATURES: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceShaderIntegerDotProductFeatures",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_SHADER_INTEGER_DOT_PRODUCT_FEATURES),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceShaderIntegerDotProductFeatures<'static>>(),
+    providers: &["VK_VERSION_1_3", "VK_KHR_shader_integer_dot_product"],
+    members: &[member!(
+        vk::PhysicalDeviceShaderIntegerDotProductFeatures<'static>,
+        shader_integer_dot_product,
+        "shaderIntegerDotProduct",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_SHADER_INTEGER_FUNCTIONS2_FEATURES_INTEL: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDeviceShaderIntegerFunctions2FeaturesINTEL",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_SHADER_INTEGER_FUNCTIONS_2_FEATURES_INTEL,
+        ),
+        category: Some(StructCategory::Features),
+        size: size_of::<vk::PhysicalDeviceShaderIntegerFunctions2FeaturesINTEL<'static>>(),
+        providers: &["VK_INTEL_shader_integer_functions2"],
+        members: &[member!(
+            vk::PhysicalDeviceShaderIntegerFunctions2FeaturesINTEL<'static>,
+            shader_integer_functions2,
+            "shaderIntegerFunctions2",
+            T::Bool32
+        )],
+    };
+
+pub(super) static PHYSICAL_DEVICE_SHADER_MAXIMAL_RECONVERGENCE_FEATURES_KHR: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDeviceShaderMaximalReconvergenceFeaturesKHR",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_SHADER_MAXIMAL_RECONVERGENCE_FEATURES_KHR,
+        ),
+        category: Some(StructCategory::Features),
+        size: size_of::<vk::PhysicalDeviceShaderMaximalReconvergenceFeaturesKHR<'static>>(),
+        providers: &["VK_KHR_shader_maximal_reconvergence"],
+        members: &[member!(
+            vk::PhysicalDeviceShaderMaximalReconvergenceFeaturesKHR<'static>,
+            shader_maximal_reconvergence,
+            "shaderMaximalReconvergence",
+            T::Bool32
+        )],
+    };
+
+pub(super) static PHYSICAL_DEVICE_SHADER_MODULE_IDENTIFIER_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceShaderModuleIdentifierFeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_SHADER_MODULE_IDENTIFIER_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceShaderModuleIdentifierFeaturesEXT<'static>>(),
+    providers: &["VK_EXT_shader_module_identifier"],
+    members: &[member!(
+        vk::PhysicalDeviceShaderModuleIdentifierFeaturesEXT<'static>,
+        shader_module_identifier,
+        "shaderModuleIdentifier",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_SHADER_OBJECT_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceShaderObjectFeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_SHADER_OBJECT_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceShaderObjectFeaturesEXT<'static>>(),
+    providers: &["VK_EXT_shader_object"],
+    members: &[member!(
+        vk::PhysicalDeviceShaderObjectFeaturesEXT<'static>,
+        shader_object,
+        "shaderObject",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_SHADER_QUAD_CONTROL_FEATURES_KHR: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceShaderQuadControlFeaturesKHR",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_SHADER_QUAD_CONTROL_FEATURES_KHR),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceShaderQuadControlFeaturesKHR<'static>>(),
+    providers: &["VK_KHR_shader_quad_control"],
+    members: &[member!(
+        vk::PhysicalDeviceShaderQuadControlFeaturesKHR<'static>,
+        shader_quad_control,
+        "shaderQuadControl",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_SHADER_SM_BUILTINS_FEATURES_NV: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceShaderSMBuiltinsFeaturesNV",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_SHADER_SM_BUILTINS_FEATURES_NV),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceShaderSMBuiltinsFeaturesNV<'static>>(),
+    providers: &["VK_NV_shader_sm_builtins"],
+    members: &[member!(
+        vk::PhysicalDeviceShaderSMBuiltinsFeaturesNV<'static>,
+        shader_sm_builtins,
+        "shaderSMBuiltins",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_SHADER_SUBGROUP_EXTENDED_TYPES_FEATURES: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDeviceShaderSubgroupExtendedTypesFeatures",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_SHADER_SUBGROUP_EXTENDED_TYPES_FEATURES,
+        ),
+        category: Some(StructCategory::Features),
+        size: size_of::<vk::PhysicalDeviceShaderSubgroupExtendedTypesFeatures<'static>>(),
+        providers: &["VK_VERSION_1_2", "VK_KHR_shader_subgroup_extended_types"],
+        members: &[member!(
+            vk::PhysicalDeviceShaderSubgroupExtendedTypesFeatures<'static>,
+            shader_subgroup_extended_types,
+            "shaderSubgroupExtendedTypes",
+            T::Bool32
+        )],
+    };
+
+pub(super) static PHYSICAL_DEVICE_SHADER_SUBGROUP_ROTATE_FEATURES_KHR: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceShaderSubgroupRotateFeaturesKHR",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_SHADER_SUBGROUP_ROTATE_FEATURES_KHR),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceShaderSubgroupRotateFeaturesKHR<'static>>(),
+    providers: &["VK_KHR_shader_subgroup_rotate"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceShaderSubgroupRotateFeaturesKHR<'static>,
+            shader_subgroup_rotate,
+            "shaderSubgroupRotate",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderSubgroupRotateFeaturesKHR<'static>,
+            shader_subgroup_rotate_clustered,
+            "shaderSubgroupRotateClustered",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_SHADER_SUBGROUP_UNIFORM_CONTROL_FLOW_FEATURES_KHR: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDeviceShaderSubgroupUniformControlFlowFeaturesKHR",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_SHADER_SUBGROUP_UNIFORM_CONTROL_FLOW_FEATURES_KHR,
+        ),
+        category: Some(StructCategory::Features),
+        size: size_of::<vk::PhysicalDeviceShaderSubgroupUniformControlFlowFeaturesKHR<'static>>(),
+        providers: &["VK_KHR_shader_subgroup_uniform_control_flow"],
+        members: &[member!(
+            vk::PhysicalDeviceShaderSubgroupUniformControlFlowFeaturesKHR<'static>,
+            shader_subgroup_uniform_control_flow,
+            "shaderSubgroupUniformControlFlow",
+            T::Bool32
+        )],
+    };
+
+pub(super) static PHYSICAL_DEVICE_SHADER_TERMINATE_INVOCATION_FEATURES: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceShaderTerminateInvocationFeatures",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_SHADER_TERMINATE_INVOCATION_FEATURES),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceShaderTerminateInvocationFeatures<'static>>(),
+    providers: &["VK_VERSION_1_3", "VK_KHR_shader_terminate_invocation"],
+    members: &[member!(
+        vk::PhysicalDeviceShaderTerminateInvocationFeatures<'static>,
+        shader_terminate_invocation,
+        "shaderTerminateInvocation",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_SHADER_TILE_IMAGE_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceShaderTileImageFeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_SHADER_TILE_IMAGE_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceShaderTileImageFeaturesEXT<'static>>(),
+    providers: &["VK_EXT_shader_tile_image"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceShaderTileImageFeaturesEXT<'static>,
+            shader_tile_image_color_read_access,
+            "shaderTileImageColorReadAccess",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderTileImageFeaturesEXT<'static>,
+            shader_tile_image_depth_read_access,
+            "shaderTileImageDepthReadAccess",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderTileImageFeaturesEXT<'static>,
+            shader_tile_image_stencil_read_access,
+            "shaderTileImageStencilReadAccess",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_SHADING_RATE_IMAGE_FEATURES_NV: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceShadingRateImageFeaturesNV",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_SHADING_RATE_IMAGE_FEATURES_NV),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceShadingRateImageFeaturesNV<'static>>(),
+    providers: &["VK_NV_shading_rate_image"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceShadingRateImageFeaturesNV<'static>,
+            shading_rate_image,
+            "shadingRateImage",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShadingRateImageFeaturesNV<'static>,
+            shading_rate_coarse_sample_order,
+            "shadingRateCoarseSampleOrder",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_SUBGROUP_SIZE_CONTROL_FEATURES: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceSubgroupSizeControlFeatures",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_SUBGROUP_SIZE_CONTROL_FEATURES),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceSubgroupSizeControlFeatures<'static>>(),
+    providers: &["VK_VERSION_1_3", "VK_EXT_subgroup_size_control"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceSubgroupSizeControlFeatures<'static>,
+            subgroup_size_control,
+            "subgroupSizeControl",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceSubgroupSizeControlFeatures<'static>,
+            compute_full_subgroups,
+            "computeFullSubgroups",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_SUBPASS_MERGE_FEEDBACK_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceSubpassMergeFeedbackFeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_SUBPASS_MERGE_FEEDBACK_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceSubpassMergeFeedbackFeaturesEXT<'static>>(),
+    providers: &["VK_EXT_subpass_merge_feedback"],
+    members: &[member!(
+        vk::PhysicalDeviceSubpassMergeFeedbackFeaturesEXT<'static>,
+        subpass_merge_feedback,
+        "subpassMergeFeedback",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_SUBPASS_SHADING_FEATURES_HUAWEI: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceSubpassShadingFeaturesHUAWEI",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_SUBPASS_SHADING_FEATURES_HUAWEI),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceSubpassShadingFeaturesHUAWEI<'static>>(),
+    providers: &["VK_HUAWEI_subpass_shading"],
+    members: &[member!(
+        vk::PhysicalDeviceSubpassShadingFeaturesHUAWEI<'static>,
+        subpass_shading,
+        "subpassShading",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_SWAPCHAIN_MAINTENANCE1_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceSwapchainMaintenance1FeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_SWAPCHAIN_MAINTENANCE_1_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceSwapchainMaintenance1FeaturesEXT<'static>>(),
+    providers: &["VK_EXT_swapchain_maintenance1"],
+    members: &[member!(
+        vk::PhysicalDeviceSwapchainMaintenance1FeaturesEXT<'static>,
+        swapchain_maintenance1,
+        "swapchainMaintenance1",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_SYNCHRONIZATION2_FEATURES: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceSynchronization2Features",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_SYNCHRONIZATION_2_FEATURES),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceSynchronization2Features<'static>>(),
+    providers: &["VK_VERSION_1_3", "VK_KHR_synchronization2"],
+    members: &[member!(
+        vk::PhysicalDeviceSynchronization2Features<'static>,
+        synchronization2,
+        "synchronization2",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_TEXEL_BUFFER_ALIGNMENT_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceTexelBufferAlignmentFeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_TEXEL_BUFFER_ALIGNMENT_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceTexelBufferAlignmentFeaturesEXT<'static>>(),
+    providers: &["VK_EXT_texel_buffer_alignment"],
+    members: &[member!(
+        vk::PhysicalDeviceTexelBufferAlignmentFeaturesEXT<'static>,
+        texel_buffer_alignment,
+        "texelBufferAlignment",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_TEXTURE_COMPRESSION_ASTCHDR_FEATURES: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceTextureCompressionASTCHDRFeatures",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_TEXTURE_COMPRESSION_ASTC_HDR_FEATURES),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceTextureCompressionASTCHDRFeatures<'static>>(),
+    providers: &["VK_VERSION_1_3", "VK_EXT_texture_compression_astc_hdr"],
+    members: &[member!(
+        vk::PhysicalDeviceTextureCompressionASTCHDRFeatures<'static>,
+        texture_compression_astc_hdr,
+        "textureCompressionASTCHDR",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_TILE_PROPERTIES_FEATURES_QCOM: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceTilePropertiesFeaturesQCOM",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_TILE_PROPERTIES_FEATURES_QCOM),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceTilePropertiesFeaturesQCOM<'static>>(),
+    providers: &["VK_QCOM_tile_properties"],
+    members: &[member!(
+        vk::PhysicalDeviceTilePropertiesFeaturesQCOM<'static>,
+        tile_properties,
+        "tileProperties",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_FEATURES: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceTimelineSemaphoreFeatures",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_FEATURES),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceTimelineSemaphoreFeatures<'static>>(),
+    providers: &["VK_VERSION_1_2", "VK_KHR_timeline_semaphore"],
+    members: &[member!(
+        vk::PhysicalDeviceTimelineSemaphoreFeatures<'static>,
+        timeline_semaphore,
+        "timelineSemaphore",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_TRANSFORM_FEEDBACK_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceTransformFeedbackFeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_TRANSFORM_FEEDBACK_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceTransformFeedbackFeaturesEXT<'static>>(),
+    providers: &["VK_EXT_transform_feedback"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceTransformFeedbackFeaturesEXT<'static>,
+            transform_feedback,
+            "transformFeedback",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceTransformFeedbackFeaturesEXT<'static>,
+            geometry_streams,
+            "geometryStreams",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_UNIFORM_BUFFER_STANDARD_LAYOUT_FEATURES: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDeviceUniformBufferStandardLayoutFeatures",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_UNIFORM_BUFFER_STANDARD_LAYOUT_FEATURES,
+        ),
+        category: Some(StructCategory::Features),
+        size: size_of::<vk::PhysicalDeviceUniformBufferStandardLayoutFeatures<'static>>(),
+        providers: &["VK_VERSION_1_2", "VK_KHR_uniform_buffer_standard_layout"],
+        members: &[member!(
+            vk::PhysicalDeviceUniformBufferStandardLayoutFeatures<'static>,
+            uniform_buffer_standard_layout,
+            "uniformBufferStandardLayout",
+            T::Bool32
+        )],
+    };
+
+pub(super) static PHYSICAL_DEVICE_VARIABLE_POINTERS_FEATURES: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceVariablePointersFeatures",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_VARIABLE_POINTERS_FEATURES),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceVariablePointersFeatures<'static>>(),
+    providers: &["VK_VERSION_1_1", "VK_KHR_variable_pointers"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceVariablePointersFeatures<'static>,
+            variable_pointers_storage_buffer,
+            "variablePointersStorageBuffer",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVariablePointersFeatures<'static>,
+            variable_pointers,
+            "variablePointers",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_FEATURES_KHR: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceVertexAttributeDivisorFeaturesKHR",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_FEATURES_KHR),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceVertexAttributeDivisorFeaturesKHR<'static>>(),
+    providers: &[
+        "VK_EXT_vertex_attribute_divisor",
+        "VK_KHR_vertex_attribute_divisor",
+    ],
+    members: &[
+        member!(
+            vk::PhysicalDeviceVertexAttributeDivisorFeaturesKHR<'static>,
+            vertex_attribute_instance_rate_divisor,
+            "vertexAttributeInstanceRateDivisor",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVertexAttributeDivisorFeaturesKHR<'static>,
+            vertex_attribute_instance_rate_zero_divisor,
+            "vertexAttributeInstanceRateZeroDivisor",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_VERTEX_INPUT_DYNAMIC_STATE_FEATURES_EXT: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDeviceVertexInputDynamicStateFeaturesEXT",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_VERTEX_INPUT_DYNAMIC_STATE_FEATURES_EXT,
+        ),
+        category: Some(StructCategory::Features),
+        size: size_of::<vk::PhysicalDeviceVertexInputDynamicStateFeaturesEXT<'static>>(),
+        providers: &["VK_EXT_vertex_input_dynamic_state"],
+        members: &[member!(
+            vk::PhysicalDeviceVertexInputDynamicStateFeaturesEXT<'static>,
+            vertex_input_dynamic_state,
+            "vertexInputDynamicState",
+            T::Bool32
+        )],
+    };
+
+pub(super) static PHYSICAL_DEVICE_VIDEO_MAINTENANCE1_FEATURES_KHR: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceVideoMaintenance1FeaturesKHR",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_VIDEO_MAINTENANCE_1_FEATURES_KHR),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceVideoMaintenance1FeaturesKHR<'static>>(),
+    providers: &["VK_KHR_video_maintenance1"],
+    members: &[member!(
+        vk::PhysicalDeviceVideoMaintenance1FeaturesKHR<'static>,
+        video_maintenance1,
+        "videoMaintenance1",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_VULKAN11_FEATURES: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceVulkan11Features",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_VULKAN_1_1_FEATURES),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceVulkan11Features<'static>>(),
+    providers: &["VK_VERSION_1_2"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceVulkan11Features<'static>,
+            storage_buffer16_bit_access,
+            "storageBuffer16BitAccess",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan11Features<'static>,
+            uniform_and_storage_buffer16_bit_access,
+            "uniformAndStorageBuffer16BitAccess",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan11Features<'static>,
+            storage_push_constant16,
+            "storagePushConstant16",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan11Features<'static>,
+            storage_input_output16,
+            "storageInputOutput16",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan11Features<'static>,
+            multiview,
+            "multiview",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan11Features<'static>,
+            multiview_geometry_shader,
+            "multiviewGeometryShader",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan11Features<'static>,
+            multiview_tessellation_shader,
+            "multiviewTessellationShader",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan11Features<'static>,
+            variable_pointers_storage_buffer,
+            "variablePointersStorageBuffer",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan11Features<'static>,
+            variable_pointers,
+            "variablePointers",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan11Features<'static>,
+            protected_memory,
+            "protectedMemory",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan11Features<'static>,
+            sampler_ycbcr_conversion,
+            "samplerYcbcrConversion",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan11Features<'static>,
+            shader_draw_parameters,
+            "shaderDrawParameters",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_VULKAN12_FEATURES: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceVulkan12Features",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_VULKAN_1_2_FEATURES),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceVulkan12Features<'static>>(),
+    providers: &["VK_VERSION_1_2"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceVulkan12Features<'static>,
+            sampler_mirror_clamp_to_edge,
+            "samplerMirrorClampToEdge",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Features<'static>,
+            draw_indirect_count,
+            "drawIndirectCount",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Features<'static>,
+            storage_buffer8_bit_access,
+            "storageBuffer8BitAccess",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Features<'static>,
+            uniform_and_storage_buffer8_bit_access,
+            "uniformAndStorageBuffer8BitAccess",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Features<'static>,
+            storage_push_constant8,
+            "storagePushConstant8",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Features<'static>,
+            shader_buffer_int64_atomics,
+            "shaderBufferInt64Atomics",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Features<'static>,
+            shader_shared_int64_atomics,
+            "shaderSharedInt64Atomics",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Features<'static>,
+            shader_float16,
+            "shaderFloat16",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Features<'static>,
+            shader_int8,
+            "shaderInt8",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Features<'static>,
+            descriptor_indexing,
+            "descriptorIndexing",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Features<'static>,
+            shader_input_attachment_array_dynamic_indexing,
+            "shaderInputAttachmentArrayDynamicIndexing",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Features<'static>,
+            shader_uniform_texel_buffer_array_dynamic_indexing,
+            "shaderUniformTexelBufferArrayDynamicIndexing",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Features<'static>,
+            shader_storage_texel_buffer_array_dynamic_indexing,
+            "shaderStorageTexelBufferArrayDynamicIndexing",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Features<'static>,
+            shader_uniform_buffer_array_non_uniform_indexing,
+            "shaderUniformBufferArrayNonUniformIndexing",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Features<'static>,
+            shader_sampled_image_array_non_uniform_indexing,
+            "shaderSampledImageArrayNonUniformIndexing",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Features<'static>,
+            shader_storage_buffer_array_non_uniform_indexing,
+            "shaderStorageBufferArrayNonUniformIndexing",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Features<'static>,
+            shader_storage_image_array_non_uniform_indexing,
+            "shaderStorageImageArrayNonUniformIndexing",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Features<'static>,
+            shader_input_attachment_array_non_uniform_indexing,
+            "shaderInputAttachmentArrayNonUniformIndexing",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Features<'static>,
+            shader_uniform_texel_buffer_array_non_uniform_indexing,
+            "shaderUniformTexelBufferArrayNonUniformIndexing",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Features<'static>,
+            shader_storage_texel_buffer_array_non_uniform_indexing,
+            "shaderStorageTexelBufferArrayNonUniformIndexing",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Features<'static>,
+            descriptor_binding_uniform_buffer_update_after_bind,
+            "descriptorBindingUniformBufferUpdateAfterBind",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Features<'static>,
+            descriptor_binding_sampled_image_update_after_bind,
+            "descriptorBindingSampledImageUpdateAfterBind",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Features<'static>,
+            descriptor_binding_storage_image_update_after_bind,
+            "descriptorBindingStorageImageUpdateAfterBind",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Features<'static>,
+            descriptor_binding_storage_buffer_update_after_bind,
+            "descriptorBindingStorageBufferUpdateAfterBind",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Features<'static>,
+            descriptor_binding_uniform_texel_buffer_update_after_bind,
+            "descriptorBindingUniformTexelBufferUpdateAfterBind",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Features<'static>,
+            descriptor_binding_storage_texel_buffer_update_after_bind,
+            "descriptorBindingStorageTexelBufferUpdateAfterBind",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Features<'static>,
+            descriptor_binding_update_unused_while_pending,
+            "descriptorBindingUpdateUnusedWhilePending",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Features<'static>,
+            descriptor_binding_partially_bound,
+            "descriptorBindingPartiallyBound",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Features<'static>,
+            descriptor_binding_variable_descriptor_count,
+            "descriptorBindingVariableDescriptorCount",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Features<'static>,
+            runtime_descriptor_array,
+            "runtimeDescriptorArray",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Features<'static>,
+            sampler_filter_minmax,
+            "samplerFilterMinmax",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Features<'static>,
+            scalar_block_layout,
+            "scalarBlockLayout",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Features<'static>,
+            imageless_framebuffer,
+            "imagelessFramebuffer",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Features<'static>,
+            uniform_buffer_standard_layout,
+            "uniformBufferStandardLayout",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Features<'static>,
+            shader_subgroup_extended_types,
+            "shaderSubgroupExtendedTypes",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Features<'static>,
+            separate_depth_stencil_layouts,
+            "separateDepthStencilLayouts",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Features<'static>,
+            host_query_reset,
+            "hostQueryReset",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Features<'static>,
+            timeline_semaphore,
+            "timelineSemaphore",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Features<'static>,
+            buffer_device_address,
+            "bufferDeviceAddress",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Features<'static>,
+            buffer_device_address_capture_replay,
+            "bufferDeviceAddressCaptureReplay",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Features<'static>,
+            buffer_device_address_multi_device,
+            "bufferDeviceAddressMultiDevice",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Features<'static>,
+            vulkan_memory_model,
+            "vulkanMemoryModel",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Features<'static>,
+            vulkan_memory_model_device_scope,
+            "vulkanMemoryModelDeviceScope",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Features<'static>,
+            vulkan_memory_model_availability_visibility_chains,
+            "vulkanMemoryModelAvailabilityVisibilityChains",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Features<'static>,
+            shader_output_viewport_index,
+            "shaderOutputViewportIndex",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Features<'static>,
+            shader_output_layer,
+            "shaderOutputLayer",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Features<'static>,
+            subgroup_broadcast_dynamic_id,
+            "subgroupBroadcastDynamicId",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_VULKAN13_FEATURES: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceVulkan13Features",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_VULKAN_1_3_FEATURES),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceVulkan13Features<'static>>(),
+    providers: &["VK_VERSION_1_3"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceVulkan13Features<'static>,
+            robust_image_access,
+            "robustImageAccess",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Features<'static>,
+            inline_uniform_block,
+            "inlineUniformBlock",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Features<'static>,
+            descriptor_binding_inline_uniform_block_update_after_bind,
+            "descriptorBindingInlineUniformBlockUpdateAfterBind",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Features<'static>,
+            pipeline_creation_cache_control,
+            "pipelineCreationCacheControl",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Features<'static>,
+            private_data,
+            "privateData",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Features<'static>,
+            shader_demote_to_helper_invocation,
+            "shaderDemoteToHelperInvocation",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Features<'static>,
+            shader_terminate_invocation,
+            "shaderTerminateInvocation",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Features<'static>,
+            subgroup_size_control,
+            "subgroupSizeControl",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Features<'static>,
+            compute_full_subgroups,
+            "computeFullSubgroups",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Features<'static>,
+            synchronization2,
+            "synchronization2",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Features<'static>,
+            texture_compression_astc_hdr,
+            "textureCompressionASTCHDR",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Features<'static>,
+            shader_zero_initialize_workgroup_memory,
+            "shaderZeroInitializeWorkgroupMemory",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Features<'static>,
+            dynamic_rendering,
+            "dynamicRendering",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Features<'static>,
+            shader_integer_dot_product,
+            "shaderIntegerDotProduct",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Features<'static>,
+            maintenance4,
+            "maintenance4",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_VULKAN_MEMORY_MODEL_FEATURES: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceVulkanMemoryModelFeatures",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_VULKAN_MEMORY_MODEL_FEATURES),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceVulkanMemoryModelFeatures<'static>>(),
+    providers: &["VK_VERSION_1_2", "VK_KHR_vulkan_memory_model"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceVulkanMemoryModelFeatures<'static>,
+            vulkan_memory_model,
+            "vulkanMemoryModel",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkanMemoryModelFeatures<'static>,
+            vulkan_memory_model_device_scope,
+            "vulkanMemoryModelDeviceScope",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkanMemoryModelFeatures<'static>,
+            vulkan_memory_model_availability_visibility_chains,
+            "vulkanMemoryModelAvailabilityVisibilityChains",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_WORKGROUP_MEMORY_EXPLICIT_LAYOUT_FEATURES_KHR: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDeviceWorkgroupMemoryExplicitLayoutFeaturesKHR",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_WORKGROUP_MEMORY_EXPLICIT_LAYOUT_FEATURES_KHR,
+        ),
+        category: Some(StructCategory::Features),
+        size: size_of::<vk::PhysicalDeviceWorkgroupMemoryExplicitLayoutFeaturesKHR<'static>>(),
+        providers: &["VK_KHR_workgroup_memory_explicit_layout"],
+        members: &[
+            member!(
+                vk::PhysicalDeviceWorkgroupMemoryExplicitLayoutFeaturesKHR<'static>,
+                workgroup_memory_explicit_layout,
+                "workgroupMemoryExplicitLayout",
+                T::Bool32
+            ),
+            member!(
+                vk::PhysicalDeviceWorkgroupMemoryExplicitLayoutFeaturesKHR<'static>,
+                workgroup_memory_explicit_layout_scalar_block_layout,
+                "workgroupMemoryExplicitLayoutScalarBlockLayout",
+                T::Bool32
+            ),
+            member!(
+                vk::PhysicalDeviceWorkgroupMemoryExplicitLayoutFeaturesKHR<'static>,
+                workgroup_memory_explicit_layout8_bit_access,
+                "workgroupMemoryExplicitLayout8BitAccess",
+                T::Bool32
+            ),
+            member!(
+                vk::PhysicalDeviceWorkgroupMemoryExplicitLayoutFeaturesKHR<'static>,
+                workgroup_memory_explicit_layout16_bit_access,
+                "workgroupMemoryExplicitLayout16BitAccess",
+                T::Bool32
+            ),
+        ],
+    };
+
+pub(super) static PHYSICAL_DEVICE_YCBCR2_PLANE444_FORMATS_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceYcbcr2Plane444FormatsFeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_YCBCR_2_PLANE_444_FORMATS_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceYcbcr2Plane444FormatsFeaturesEXT<'static>>(),
+    providers: &["VK_EXT_ycbcr_2plane_444_formats"],
+    members: &[member!(
+        vk::PhysicalDeviceYcbcr2Plane444FormatsFeaturesEXT<'static>,
+        ycbcr2plane444_formats,
+        "ycbcr2plane444Formats",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_YCBCR_DEGAMMA_FEATURES_QCOM: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceYcbcrDegammaFeaturesQCOM",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_YCBCR_DEGAMMA_FEATURES_QCOM),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceYcbcrDegammaFeaturesQCOM<'static>>(),
+    providers: &["VK_QCOM_ycbcr_degamma"],
+    members: &[member!(
+        vk::PhysicalDeviceYcbcrDegammaFeaturesQCOM<'static>,
+        ycbcr_degamma,
+        "ycbcrDegamma",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_YCBCR_IMAGE_ARRAYS_FEATURES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceYcbcrImageArraysFeaturesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_YCBCR_IMAGE_ARRAYS_FEATURES_EXT),
+    category: Some(StructCategory::Features),
+    size: size_of::<vk::PhysicalDeviceYcbcrImageArraysFeaturesEXT<'static>>(),
+    providers: &["VK_EXT_ycbcr_image_arrays"],
+    members: &[member!(
+        vk::PhysicalDeviceYcbcrImageArraysFeaturesEXT<'static>,
+        ycbcr_image_arrays,
+        "ycbcrImageArrays",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_ZERO_INITIALIZE_WORKGROUP_MEMORY_FEATURES: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDeviceZeroInitializeWorkgroupMemoryFeatures",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_ZERO_INITIALIZE_WORKGROUP_MEMORY_FEATURES,
+        ),
+        category: Some(StructCategory::Features),
+        size: size_of::<vk::PhysicalDeviceZeroInitializeWorkgroupMemoryFeatures<'static>>(),
+        providers: &["VK_VERSION_1_3", "VK_KHR_zero_initialize_workgroup_memory"],
+        members: &[member!(
+            vk::PhysicalDeviceZeroInitializeWorkgroupMemoryFeatures<'static>,
+            shader_zero_initialize_workgroup_memory,
+            "shaderZeroInitializeWorkgroupMemory",
+            T::Bool32
+        )],
+    };
+
+pub(super) static PHYSICAL_DEVICE_PROPERTIES: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceProperties",
+    structure_type: None,
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceProperties>(),
+    providers: &[],
+    members: &[
+        member!(
+            vk::PhysicalDeviceProperties,
+            api_version,
+            "apiVersion",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceProperties,
+            driver_version,
+            "driverVersion",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceProperties,
+            vendor_id,
+            "vendorID",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceProperties,
+            device_id,
+            "deviceID",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceProperties,
+            device_type,
+            "deviceType",
+            T::Enum(&PHYSICAL_DEVICE_TYPE)
+        ),
+        member!(
+            vk::PhysicalDeviceProperties,
+            device_name,
+            "deviceName",
+            T::String(256)
+        ),
+        member!(
+            vk::PhysicalDeviceProperties,
+            pipeline_cache_uuid,
+            "pipelineCacheUUID",
+            T::Array(&T::Uint8, 16)
+        ),
+        member!(
+            vk::PhysicalDeviceProperties,
+            limits,
+            "limits",
+            T::Struct(&PHYSICAL_DEVICE_LIMITS)
+        ),
+        member!(
+            vk::PhysicalDeviceProperties,
+            sparse_properties,
+            "sparseProperties",
+            T::Struct(&PHYSICAL_DEVICE_SPARSE_PROPERTIES)
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_ACCELERATION_STRUCTURE_PROPERTIES_KHR: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceAccelerationStructurePropertiesKHR",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_ACCELERATION_STRUCTURE_PROPERTIES_KHR),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceAccelerationStructurePropertiesKHR<'static>>(),
+    providers: &["VK_KHR_acceleration_structure"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceAccelerationStructurePropertiesKHR<'static>,
+            max_geometry_count,
+            "maxGeometryCount",
+            T::Uint64
+        ),
+        member!(
+            vk::PhysicalDeviceAccelerationStructurePropertiesKHR<'static>,
+            max_instance_count,
+            "maxInstanceCount",
+            T::Uint64
+        ),
+        member!(
+            vk::PhysicalDeviceAccelerationStructurePropertiesKHR<'static>,
+            max_primitive_count,
+            "maxPrimitiveCount",
+            T::Uint64
+        ),
+        member!(
+            vk::PhysicalDeviceAccelerationStructurePropertiesKHR<'static>,
+            max_per_stage_descriptor_acceleration_structures,
+            "maxPerStageDescriptorAccelerationStructures",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceAccelerationStructurePropertiesKHR<'static>,
+            max_per_stage_descriptor_update_after_bind_acceleration_structures,
+            "maxPerStageDescriptorUpdateAfterBindAccelerationStructures",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceAccelerationStructurePropertiesKHR<'static>,
+            max_descriptor_set_acceleration_structures,
+            "maxDescriptorSetAccelerationStructures",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceAccelerationStructurePropertiesKHR<'static>,
+            max_descriptor_set_update_after_bind_acceleration_structures,
+            "maxDescriptorSetUpdateAfterBindAccelerationStructures",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceAccelerationStructurePropertiesKHR<'static>,
+            min_acceleration_structure_scratch_offset_alignment,
+            "minAccelerationStructureScratchOffsetAlignment",
+            T::Uint32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_BLEND_OPERATION_ADVANCED_PROPERTIES_EXT: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDeviceBlendOperationAdvancedPropertiesEXT",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_BLEND_OPERATION_ADVANCED_PROPERTIES_EXT,
+        ),
+        category: Some(StructCategory::Properties),
+        size: size_of::<vk::PhysicalDeviceBlendOperationAdvancedPropertiesEXT<'static>>(),
+        providers: &["VK_EXT_blend_operation_advanced"],
+        members: &[
+            member!(
+                vk::PhysicalDeviceBlendOperationAdvancedPropertiesEXT<'static>,
+                advanced_blend_max_color_attachments,
+                "advancedBlendMaxColorAttachments",
+                T::Uint32
+            ),
+            member!(
+                vk::PhysicalDeviceBlendOperationAdvancedPropertiesEXT<'static>,
+                advanced_blend_independent_blend,
+                "advancedBlendIndependentBlend",
+                T::Bool32
+            ),
+            member!(
+                vk::PhysicalDeviceBlendOperationAdvancedPropertiesEXT<'static>,
+                advanced_blend_non_premultiplied_src_color,
+                "advancedBlendNonPremultipliedSrcColor",
+                T::Bool32
+            ),
+            member!(
+                vk::PhysicalDeviceBlendOperationAdvancedPropertiesEXT<'static>,
+                advanced_blend_non_premultiplied_dst_color,
+                "advancedBlendNonPremultipliedDstColor",
+                T::Bool32
+            ),
+            member!(
+                vk::PhysicalDeviceBlendOperationAdvancedPropertiesEXT<'static>,
+                advanced_blend_correlated_overlap,
+                "advancedBlendCorrelatedOverlap",
+                T::Bool32
+            ),
+            member!(
+                vk::PhysicalDeviceBlendOperationAdvancedPropertiesEXT<'static>,
+                advanced_blend_all_operations,
+                "advancedBlendAllOperations",
+                T::Bool32
+            ),
+        ],
+    };
+
+pub(super) static PHYSICAL_DEVICE_CLUSTER_CULLING_SHADER_PROPERTIES_HUAWEI: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDeviceClusterCullingShaderPropertiesHUAWEI",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_CLUSTER_CULLING_SHADER_PROPERTIES_HUAWEI,
+        ),
+        category: Some(StructCategory::Properties),
+        size: size_of::<vk::PhysicalDeviceClusterCullingShaderPropertiesHUAWEI<'static>>(),
+        providers: &["VK_HUAWEI_cluster_culling_shader"],
+        members: &[
+            member!(
+                vk::PhysicalDeviceClusterCullingShaderPropertiesHUAWEI<'static>,
+                max_work_group_count,
+                "maxWorkGroupCount",
+                T::Array(&T::Uint32, 3)
+            ),
+            member!(
+                vk::PhysicalDeviceClusterCullingShaderPropertiesHUAWEI<'static>,
+                max_work_group_size,
+                "maxWorkGroupSize",
+                T::Array(&T::Uint32, 3)
+            ),
+            member!(
+                vk::PhysicalDeviceClusterCullingShaderPropertiesHUAWEI<'static>,
+                max_output_cluster_count,
+                "maxOutputClusterCount",
+                T::Uint32
+            ),
+            member!(
+                vk::PhysicalDeviceClusterCullingShaderPropertiesHUAWEI<'static>,
+                indirect_buffer_offset_alignment,
+                "indirectBufferOffsetAlignment",
+                T::Uint64
+            ),
+        ],
+    };
+
+pub(super) static PHYSICAL_DEVICE_CONSERVATIVE_RASTERIZATION_PROPERTIES_EXT: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDeviceConservativeRasterizationPropertiesEXT",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_CONSERVATIVE_RASTERIZATION_PROPERTIES_EXT,
+        ),
+        category: Some(StructCategory::Properties),
+        size: size_of::<vk::PhysicalDeviceConservativeRasterizationPropertiesEXT<'static>>(),
+        providers: &["VK_EXT_conservative_rasterization"],
+        members: &[
+            member!(
+                vk::PhysicalDeviceConservativeRasterizationPropertiesEXT<'static>,
+                primitive_overestimation_size,
+                "primitiveOverestimationSize",
+                T::Float32
+            ),
+            member!(
+                vk::PhysicalDeviceConservativeRasterizationPropertiesEXT<'static>,
+                max_extra_primitive_overestimation_size,
+                "maxExtraPrimitiveOverestimationSize",
+                T::Float32
+            ),
+            member!(
+                vk::PhysicalDeviceConservativeRasterizationPropertiesEXT<'static>,
+                extra_primitive_overestimation_size_granularity,
+                "extraPrimitiveOverestimationSizeGranularity",
+                T::Float32
+            ),
+            member!(
+                vk::PhysicalDeviceConservativeRasterizationPropertiesEXT<'static>,
+                primitive_underestimation,
+                "primitiveUnderestimation",
+                T::Bool32
+            ),
+            member!(
+                vk::PhysicalDeviceConservativeRasterizationPropertiesEXT<'static>,
+                conservative_point_and_line_rasterization,
+                "conservativePointAndLineRasterization",
+                T::Bool32
+            ),
+            member!(
+                vk::PhysicalDeviceConservativeRasterizationPropertiesEXT<'static>,
+                degenerate_triangles_rasterized,
+                "degenerateTrianglesRasterized",
+                T::Bool32
+            ),
+            member!(
+                vk::PhysicalDeviceConservativeRasterizationPropertiesEXT<'static>,
+                degenerate_lines_rasterized,
+                "degenerateLinesRasterized",
+                T::Bool32
+            ),
+            member!(
+                vk::PhysicalDeviceConservativeRasterizationPropertiesEXT<'static>,
+                fully_covered_fragment_shader_input_variable,
+                "fullyCoveredFragmentShaderInputVariable",
+                T::Bool32
+            ),
+            member!(
+                vk::PhysicalDeviceConservativeRasterizationPropertiesEXT<'static>,
+                conservative_rasterization_post_depth_coverage,
+                "conservativeRasterizationPostDepthCoverage",
+                T::Bool32
+            ),
+        ],
+    };
+
+pub(super) static PHYSICAL_DEVICE_COOPERATIVE_MATRIX_PROPERTIES_KHR: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceCooperativeMatrixPropertiesKHR",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_COOPERATIVE_MATRIX_PROPERTIES_KHR),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceCooperativeMatrixPropertiesKHR<'static>>(),
+    providers: &["VK_KHR_cooperative_matrix"],
+    members: &[member!(
+        vk::PhysicalDeviceCooperativeMatrixPropertiesKHR<'static>,
+        cooperative_matrix_supported_stages,
+        "cooperativeMatrixSupportedStages",
+        T::Flags(&SHADER_STAGE_FLAGS)
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_COOPERATIVE_MATRIX_PROPERTIES_NV: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceCooperativeMatrixPropertiesNV",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_COOPERATIVE_MATRIX_PROPERTIES_NV),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceCooperativeMatrixPropertiesNV<'static>>(),
+    providers: &["VK_NV_cooperative_matrix"],
+    members: &[member!(
+        vk::PhysicalDeviceCooperativeMatrixPropertiesNV<'static>,
+        cooperative_matrix_supported_stages,
+        "cooperativeMatrixSupportedStages",
+        T::Flags(&SHADER_STAGE_FLAGS)
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_COPY_MEMORY_INDIRECT_PROPERTIES_NV: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceCopyMemoryIndirectPropertiesNV",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_COPY_MEMORY_INDIRECT_PROPERTIES_NV),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceCopyMemoryIndirectPropertiesNV<'static>>(),
+    providers: &["VK_NV_copy_memory_indirect"],
+    members: &[member!(
+        vk::PhysicalDeviceCopyMemoryIndirectPropertiesNV<'static>,
+        supported_queues,
+        "supportedQueues",
+        T::Flags(&QUEUE_FLAGS)
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_CUDA_KERNEL_LAUNCH_PROPERTIES_NV: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceCudaKernelLaunchPropertiesNV",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_CUDA_KERNEL_LAUNCH_PROPERTIES_NV),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceCudaKernelLaunchPropertiesNV<'static>>(),
+    providers: &["VK_NV_cuda_kernel_launch"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceCudaKernelLaunchPropertiesNV<'static>,
+            compute_capability_minor,
+            "computeCapabilityMinor",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceCudaKernelLaunchPropertiesNV<'static>,
+            compute_capability_major,
+            "computeCapabilityMajor",
+            T::Uint32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_CUSTOM_BORDER_COLOR_PROPERTIES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceCustomBorderColorPropertiesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_CUSTOM_BORDER_COLOR_PROPERTIES_EXT),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceCustomBorderColorPropertiesEXT<'static>>(),
+    providers: &["VK_EXT_custom_border_color"],
+    members: &[member!(
+        vk::PhysicalDeviceCustomBorderColorPropertiesEXT<'static>,
+        max_custom_border_color_samplers,
+        "maxCustomBorderColorSamplers",
+        T::Uint32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_DEPTH_STENCIL_RESOLVE_PROPERTIES: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceDepthStencilResolveProperties",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_DEPTH_STENCIL_RESOLVE_PROPERTIES),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceDepthStencilResolveProperties<'static>>(),
+    providers: &["VK_VERSION_1_2", "VK_KHR_depth_stencil_resolve"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceDepthStencilResolveProperties<'static>,
+            supported_depth_resolve_modes,
+            "supportedDepthResolveModes",
+            T::Flags(&RESOLVE_MODE_FLAGS)
+        ),
+        member!(
+            vk::PhysicalDeviceDepthStencilResolveProperties<'static>,
+            supported_stencil_resolve_modes,
+            "supportedStencilResolveModes",
+            T::Flags(&RESOLVE_MODE_FLAGS)
+        ),
+        member!(
+            vk::PhysicalDeviceDepthStencilResolveProperties<'static>,
+            independent_resolve_none,
+            "independentResolveNone",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceDepthStencilResolveProperties<'static>,
+            independent_resolve,
+            "independentResolve",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_DESCRIPTOR_BUFFER_DENSITY_MAP_PROPERTIES_EXT: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDeviceDescriptorBufferDensityMapPropertiesEXT",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_DESCRIPTOR_BUFFER_DENSITY_MAP_PROPERTIES_EXT,
+        ),
+        category: Some(StructCategory::Properties),
+        size: size_of::<vk::PhysicalDeviceDescriptorBufferDensityMapPropertiesEXT<'static>>(),
+        providers: &["VK_EXT_descriptor_buffer"],
+        members: &[member!(
+            vk::PhysicalDeviceDescriptorBufferDensityMapPropertiesEXT<'static>,
+            combined_image_sampler_density_map_descriptor_size,
+            "combinedImageSamplerDensityMapDescriptorSize",
+            T::Size
+        )],
+    };
+
+pub(super) static PHYSICAL_DEVICE_DESCRIPTOR_BUFFER_PROPERTIES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceDescriptorBufferPropertiesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_DESCRIPTOR_BUFFER_PROPERTIES_EXT),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceDescriptorBufferPropertiesEXT<'static>>(),
+    providers: &["VK_EXT_descriptor_buffer"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceDescriptorBufferPropertiesEXT<'static>,
+            combined_image_sampler_descriptor_single_array,
+            "combinedImageSamplerDescriptorSingleArray",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorBufferPropertiesEXT<'static>,
+            bufferless_push_descriptors,
+            "bufferlessPushDescriptors",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorBufferPropertiesEXT<'static>,
+            allow_sampler_image_view_post_submit_creation,
+            "allowSamplerImageViewPostSubmitCreation",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorBufferPropertiesEXT<'static>,
+            descriptor_buffer_offset_alignment,
+            "descriptorBufferOffsetAlignment",
+            T::Uint64
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorBufferPropertiesEXT<'static>,
+            max_descriptor_buffer_bindings,
+            "maxDescriptorBufferBindings",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorBufferPropertiesEXT<'static>,
+            max_resource_descriptor_buffer_bindings,
+            "maxResourceDescriptorBufferBindings",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorBufferPropertiesEXT<'static>,
+            max_sampler_descriptor_buffer_bindings,
+            "maxSamplerDescriptorBufferBindings",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorBufferPropertiesEXT<'static>,
+            max_embedded_immutable_sampler_bindings,
+            "maxEmbeddedImmutableSamplerBindings",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorBufferPropertiesEXT<'static>,
+            max_embedded_immutable_samplers,
+            "maxEmbeddedImmutableSamplers",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorBufferPropertiesEXT<'static>,
+            buffer_capture_replay_descriptor_data_size,
+            "bufferCaptureReplayDescriptorDataSize",
+            T::Size
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorBufferPropertiesEXT<'static>,
+            image_capture_replay_descriptor_data_size,
+            "imageCaptureReplayDescriptorDataSize",
+            T::Size
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorBufferPropertiesEXT<'static>,
+            image_view_capture_replay_descriptor_data_size,
+            "imageViewCaptureReplayDescriptorDataSize",
+            T::Size
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorBufferPropertiesEXT<'static>,
+            sampler_capture_replay_descriptor_data_size,
+            "samplerCaptureReplayDescriptorDataSize",
+            T::Size
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorBufferPropertiesEXT<'static>,
+            acceleration_structure_capture_replay_descriptor_data_size,
+            "accelerationStructureCaptureReplayDescriptorDataSize",
+            T::Size
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorBufferPropertiesEXT<'static>,
+            sampler_descriptor_size,
+            "samplerDescriptorSize",
+            T::Size
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorBufferPropertiesEXT<'static>,
+            combined_image_sampler_descriptor_size,
+            "combinedImageSamplerDescriptorSize",
+            T::Size
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorBufferPropertiesEXT<'static>,
+            sampled_image_descriptor_size,
+            "sampledImageDescriptorSize",
+            T::Size
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorBufferPropertiesEXT<'static>,
+            storage_image_descriptor_size,
+            "storageImageDescriptorSize",
+            T::Size
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorBufferPropertiesEXT<'static>,
+            uniform_texel_buffer_descriptor_size,
+            "uniformTexelBufferDescriptorSize",
+            T::Size
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorBufferPropertiesEXT<'static>,
+            robust_uniform_texel_buffer_descriptor_size,
+            "robustUniformTexelBufferDescriptorSize",
+            T::Size
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorBufferPropertiesEXT<'static>,
+            storage_texel_buffer_descriptor_size,
+            "storageTexelBufferDescriptorSize",
+            T::Size
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorBufferPropertiesEXT<'static>,
+            robust_storage_texel_buffer_descriptor_size,
+            "robustStorageTexelBufferDescriptorSize",
+            T::Size
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorBufferPropertiesEXT<'static>,
+            uniform_buffer_descriptor_size,
+            "uniformBufferDescriptorSize",
+            T::Size
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorBufferPropertiesEXT<'static>,
+            robust_uniform_buffer_descriptor_size,
+            "robustUniformBufferDescriptorSize",
+            T::Size
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorBufferPropertiesEXT<'static>,
+            storage_buffer_descriptor_size,
+            "storageBufferDescriptorSize",
+            T::Size
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorBufferPropertiesEXT<'static>,
+            robust_storage_buffer_descriptor_size,
+            "robustStorageBufferDescriptorSize",
+            T::Size
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorBufferPropertiesEXT<'static>,
+            input_attachment_descriptor_size,
+            "inputAttachmentDescriptorSize",
+            T::Size
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorBufferPropertiesEXT<'static>,
+            acceleration_structure_descriptor_size,
+            "accelerationStructureDescriptorSize",
+            T::Size
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorBufferPropertiesEXT<'static>,
+            max_sampler_descriptor_buffer_range,
+            "maxSamplerDescriptorBufferRange",
+            T::Uint64
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorBufferPropertiesEXT<'static>,
+            max_resource_descriptor_buffer_range,
+            "maxResourceDescriptorBufferRange",
+            T::Uint64
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorBufferPropertiesEXT<'static>,
+            sampler_descriptor_buffer_address_space_size,
+            "samplerDescriptorBufferAddressSpaceSize",
+            T::Uint64
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorBufferPropertiesEXT<'static>,
+            resource_descriptor_buffer_address_space_size,
+            "resourceDescriptorBufferAddressSpaceSize",
+            T::Uint64
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorBufferPropertiesEXT<'static>,
+            descriptor_buffer_address_space_size,
+            "descriptorBufferAddressSpaceSize",
+            T::Uint64
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_PROPERTIES: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceDescriptorIndexingProperties",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_PROPERTIES),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceDescriptorIndexingProperties<'static>>(),
+    providers: &["VK_VERSION_1_2", "VK_EXT_descriptor_indexing"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceDescriptorIndexingProperties<'static>,
+            max_update_after_bind_descriptors_in_all_pools,
+            "maxUpdateAfterBindDescriptorsInAllPools",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorIndexingProperties<'static>,
+            shader_uniform_buffer_array_non_uniform_indexing_native,
+            "shaderUniformBufferArrayNonUniformIndexingNative",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorIndexingProperties<'static>,
+            shader_sampled_image_array_non_uniform_indexing_native,
+            "shaderSampledImageArrayNonUniformIndexingNative",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorIndexingProperties<'static>,
+            shader_storage_buffer_array_non_uniform_indexing_native,
+            "shaderStorageBufferArrayNonUniformIndexingNative",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorIndexingProperties<'static>,
+            shader_storage_image_array_non_uniform_indexing_native,
+            "shaderStorageImageArrayNonUniformIndexingNative",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorIndexingProperties<'static>,
+            shader_input_attachment_array_non_uniform_indexing_native,
+            "shaderInputAttachmentArrayNonUniformIndexingNative",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorIndexingProperties<'static>,
+            robust_buffer_access_update_after_bind,
+            "robustBufferAccessUpdateAfterBind",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorIndexingProperties<'static>,
+            quad_divergent_implicit_lod,
+            "quadDivergentImplicitLod",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorIndexingProperties<'static>,
+            max_per_stage_descriptor_update_after_bind_samplers,
+            "maxPerStageDescriptorUpdateAfterBindSamplers",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorIndexingProperties<'static>,
+            max_per_stage_descriptor_update_after_bind_uniform_buffers,
+            "maxPerStageDescriptorUpdateAfterBindUniformBuffers",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorIndexingProperties<'static>,
+            max_per_stage_descriptor_update_after_bind_storage_buffers,
+            "maxPerStageDescriptorUpdateAfterBindStorageBuffers",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorIndexingProperties<'static>,
+            max_per_stage_descriptor_update_after_bind_sampled_images,
+            "maxPerStageDescriptorUpdateAfterBindSampledImages",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorIndexingProperties<'static>,
+            max_per_stage_descriptor_update_after_bind_storage_images,
+            "maxPerStageDescriptorUpdateAfterBindStorageImages",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorIndexingProperties<'static>,
+            max_per_stage_descriptor_update_after_bind_input_attachments,
+            "maxPerStageDescriptorUpdateAfterBindInputAttachments",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorIndexingProperties<'static>,
+            max_per_stage_update_after_bind_resources,
+            "maxPerStageUpdateAfterBindResources",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorIndexingProperties<'static>,
+            max_descriptor_set_update_after_bind_samplers,
+            "maxDescriptorSetUpdateAfterBindSamplers",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorIndexingProperties<'static>,
+            max_descriptor_set_update_after_bind_uniform_buffers,
+            "maxDescriptorSetUpdateAfterBindUniformBuffers",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorIndexingProperties<'static>,
+            max_descriptor_set_update_after_bind_uniform_buffers_dynamic,
+            "maxDescriptorSetUpdateAfterBindUniformBuffersDynamic",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorIndexingProperties<'static>,
+            max_descriptor_set_update_after_bind_storage_buffers,
+            "maxDescriptorSetUpdateAfterBindStorageBuffers",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorIndexingProperties<'static>,
+            max_descriptor_set_update_after_bind_storage_buffers_dynamic,
+            "maxDescriptorSetUpdateAfterBindStorageBuffersDynamic",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorIndexingProperties<'static>,
+            max_descriptor_set_update_after_bind_sampled_images,
+            "maxDescriptorSetUpdateAfterBindSampledImages",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorIndexingProperties<'static>,
+            max_descriptor_set_update_after_bind_storage_images,
+            "maxDescriptorSetUpdateAfterBindStorageImages",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceDescriptorIndexingProperties<'static>,
+            max_descriptor_set_update_after_bind_input_attachments,
+            "maxDescriptorSetUpdateAfterBindInputAttachments",
+            T::Uint32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_DEVICE_GENERATED_COMMANDS_PROPERTIES_NV: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDeviceDeviceGeneratedCommandsPropertiesNV",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_DEVICE_GENERATED_COMMANDS_PROPERTIES_NV,
+        ),
+        category: Some(StructCategory::Properties),
+        size: size_of::<vk::PhysicalDeviceDeviceGeneratedCommandsPropertiesNV<'static>>(),
+        providers: &["VK_NV_device_generated_commands"],
+        members: &[
+            member!(
+                vk::PhysicalDeviceDeviceGeneratedCommandsPropertiesNV<'static>,
+                max_graphics_shader_group_count,
+                "maxGraphicsShaderGroupCount",
+                T::Uint32
+            ),
+            member!(
+                vk::PhysicalDeviceDeviceGeneratedCommandsPropertiesNV<'static>,
+                max_indirect_sequence_count,
+                "maxIndirectSequenceCount",
+                T::Uint32
+            ),
+            member!(
+                vk::PhysicalDeviceDeviceGeneratedCommandsPropertiesNV<'static>,
+                max_indirect_commands_token_count,
+                "maxIndirectCommandsTokenCount",
+                T::Uint32
+            ),
+            member!(
+                vk::PhysicalDeviceDeviceGeneratedCommandsPropertiesNV<'static>,
+                max_indirect_commands_stream_count,
+                "maxIndirectCommandsStreamCount",
+                T::Uint32
+            ),
+            member!(
+                vk::PhysicalDeviceDeviceGeneratedCommandsPropertiesNV<'static>,
+                max_indirect_commands_token_offset,
+                "maxIndirectCommandsTokenOffset",
+                T::Uint32
+            ),
+            member!(
+                vk::PhysicalDeviceDeviceGeneratedCommandsPropertiesNV<'static>,
+                max_indirect_commands_stream_stride,
+                "maxIndirectCommandsStreamStride",
+                T::Uint32
+            ),
+            member!(
+                vk::PhysicalDeviceDeviceGeneratedCommandsPropertiesNV<'static>,
+                min_sequences_count_buffer_offset_alignment,
+                "minSequencesCountBufferOffsetAlignment",
+                T::Uint32
+            ),
+            member!(
+                vk::PhysicalDeviceDeviceGeneratedCommandsPropertiesNV<'static>,
+                min_sequences_index_buffer_offset_alignment,
+                "minSequencesIndexBufferOffsetAlignment",
+                T::Uint32
+            ),
+            member!(
+                vk::PhysicalDeviceDeviceGeneratedCommandsPropertiesNV<'static>,
+                min_indirect_commands_buffer_offset_alignment,
+                "minIndirectCommandsBufferOffsetAlignment",
+                T::Uint32
+            ),
+        ],
+    };
+
+pub(super) static PHYSICAL_DEVICE_DISCARD_RECTANGLE_PROPERTIES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceDiscardRectanglePropertiesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_DISCARD_RECTANGLE_PROPERTIES_EXT),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceDiscardRectanglePropertiesEXT<'static>>(),
+    providers: &["VK_EXT_discard_rectangles"],
+    members: &[member!(
+        vk::PhysicalDeviceDiscardRectanglePropertiesEXT<'static>,
+        max_discard_rectangles,
+        "maxDiscardRectangles",
+        T::Uint32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_DISPLACEMENT_MICROMAP_PROPERTIES_NV: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceDisplacementMicromapPropertiesNV",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_DISPLACEMENT_MICROMAP_PROPERTIES_NV),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceDisplacementMicromapPropertiesNV<'static>>(),
+    providers: &["VK_NV_displacement_micromap"],
+    members: &[member!(
+        vk::PhysicalDeviceDisplacementMicromapPropertiesNV<'static>,
+        max_displacement_micromap_subdivision_level,
+        "maxDisplacementMicromapSubdivisionLevel",
+        T::Uint32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_DRIVER_PROPERTIES: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceDriverProperties",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_DRIVER_PROPERTIES),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceDriverProperties<'static>>(),
+    providers: &["VK_VERSION_1_2", "VK_KHR_driver_properties"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceDriverProperties<'static>,
+            driver_id,
+            "driverID",
+            T::Enum(&DRIVER_ID)
+        ),
+        member!(
+            vk::PhysicalDeviceDriverProperties<'static>,
+            driver_name,
+            "driverName",
+            T::String(256)
+        ),
+        member!(
+            vk::PhysicalDeviceDriverProperties<'static>,
+            driver_info,
+            "driverInfo",
+            T::String(256)
+        ),
+        member!(
+            vk::PhysicalDeviceDriverProperties<'static>,
+            conformance_version,
+            "conformanceVersion",
+            T::Struct(&CONFORMANCE_VERSION)
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_DRM_PROPERTIES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceDrmPropertiesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_DRM_PROPERTIES_EXT),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceDrmPropertiesEXT<'static>>(),
+    providers: &["VK_EXT_physical_device_drm"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceDrmPropertiesEXT<'static>,
+            has_primary,
+            "hasPrimary",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceDrmPropertiesEXT<'static>,
+            has_render,
+            "hasRender",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceDrmPropertiesEXT<'static>,
+            primary_major,
+            "primaryMajor",
+            T::Int64
+        ),
+        member!(
+            vk::PhysicalDeviceDrmPropertiesEXT<'static>,
+            primary_minor,
+            "primaryMinor",
+            T::Int64
+        ),
+        member!(
+            vk::PhysicalDeviceDrmPropertiesEXT<'static>,
+            render_major,
+            "renderMajor",
+            T::Int64
+        ),
+        member!(
+            vk::PhysicalDeviceDrmPropertiesEXT<'static>,
+            render_minor,
+            "renderMinor",
+            T::Int64
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_EXTENDED_DYNAMIC_STATE3_PROPERTIES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceExtendedDynamicState3PropertiesEXT",
+    structure_type: Some(
+        vk::StructureType::PHYSICAL_DEVICE_EXTENDED_DYNAMIC_STATE_3_PROPERTIES_EXT,
+    ),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceExtendedDynamicState3PropertiesEXT<'static>>(),
+    providers: &["VK_EXT_extended_dynamic_state3"],
+    members: &[member!(
+        vk::PhysicalDeviceExtendedDynamicState3PropertiesEXT<'static>,
+        dynamic_primitive_topology_unrestricted,
+        "dynamicPrimitiveTopologyUnrestricted",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_EXTENDED_SPARSE_ADDRESS_SPACE_PROPERTIES_NV: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDeviceExtendedSparseAddressSpacePropertiesNV",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_EXTENDED_SPARSE_ADDRESS_SPACE_PROPERTIES_NV,
+        ),
+        category: Some(StructCategory::Properties),
+        size: size_of::<vk::PhysicalDeviceExtendedSparseAddressSpacePropertiesNV<'static>>(),
+        providers: &["VK_NV_extended_sparse_address_space"],
+        members: &[
+            member!(
+                vk::PhysicalDeviceExtendedSparseAddressSpacePropertiesNV<'static>,
+                extended_sparse_address_space_size,
+                "extendedSparseAddressSpaceSize",
+                T::Uint64
+            ),
+            member!(
+                vk::PhysicalDeviceExtendedSparseAddressSpacePropertiesNV<'static>,
+                extended_sparse_image_usage_flags,
+                "extendedSparseImageUsageFlags",
+                T::Flags(&IMAGE_USAGE_FLAGS)
+            ),
+            member!(
+                vk::PhysicalDeviceExtendedSparseAddressSpacePropertiesNV<'static>,
+                extended_sparse_buffer_usage_flags,
+                "extendedSparseBufferUsageFlags",
+                T::Flags(&BUFFER_USAGE_FLAGS)
+            ),
+        ],
+    };
+
+pub(super) static PHYSICAL_DEVICE_EXTERNAL_FORMAT_RESOLVE_PROPERTIES_ANDROID: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDeviceExternalFormatResolvePropertiesANDROID",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_EXTERNAL_FORMAT_RESOLVE_PROPERTIES_ANDROID,
+        ),
+        category: Some(StructCategory::Properties),
+        size: size_of::<vk::PhysicalDeviceExternalFormatResolvePropertiesANDROID<'static>>(),
+        providers: &["VK_ANDROID_external_format_resolve"],
+        members: &[
+            member!(
+                vk::PhysicalDeviceExternalFormatResolvePropertiesANDROID<'static>,
+                null_color_attachment_with_external_format_resolve,
+                "nullColorAttachmentWithExternalFormatResolve",
+                T::Bool32
+            ),
+            member!(
+                vk::PhysicalDeviceExternalFormatResolvePropertiesANDROID<'static>,
+                external_format_resolve_chroma_offset_x,
+                "externalFormatResolveChromaOffsetX",
+                T::Enum(&CHROMA_LOCATION)
+            ),
+            member!(
+                vk::PhysicalDeviceExternalFormatResolvePropertiesANDROID<'static>,
+                external_format_resolve_chroma_offset_y,
+                "externalFormatResolveChromaOffsetY",
+                T::Enum(&CHROMA_LOCATION)
+            ),
+        ],
+    };
+
+pub(super) static PHYSICAL_DEVICE_EXTERNAL_MEMORY_HOST_PROPERTIES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceExternalMemoryHostPropertiesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_EXTERNAL_MEMORY_HOST_PROPERTIES_EXT),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceExternalMemoryHostPropertiesEXT<'static>>(),
+    providers: &["VK_EXT_external_memory_host"],
+    members: &[member!(
+        vk::PhysicalDeviceExternalMemoryHostPropertiesEXT<'static>,
+        min_imported_host_pointer_alignment,
+        "minImportedHostPointerAlignment",
+        T::Uint64
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_FLOAT_CONTROLS_PROPERTIES: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceFloatControlsProperties",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_FLOAT_CONTROLS_PROPERTIES),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceFloatControlsProperties<'static>>(),
+    providers: &["VK_VERSION_1_2", "VK_KHR_shader_float_controls"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceFloatControlsProperties<'static>,
+            denorm_behavior_independence,
+            "denormBehaviorIndependence",
+            T::Enum(&SHADER_FLOAT_CONTROLS_INDEPENDENCE)
+        ),
+        member!(
+            vk::PhysicalDeviceFloatControlsProperties<'static>,
+            rounding_mode_independence,
+            "roundingModeIndependence",
+            T::Enum(&SHADER_FLOAT_CONTROLS_INDEPENDENCE)
+        ),
+        member!(
+            vk::PhysicalDeviceFloatControlsProperties<'static>,
+            shader_signed_zero_inf_nan_preserve_float16,
+            "shaderSignedZeroInfNanPreserveFloat16",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFloatControlsProperties<'static>,
+            shader_signed_zero_inf_nan_preserve_float32,
+            "shaderSignedZeroInfNanPreserveFloat32",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFloatControlsProperties<'static>,
+            shader_signed_zero_inf_nan_preserve_float64,
+            "shaderSignedZeroInfNanPreserveFloat64",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFloatControlsProperties<'static>,
+            shader_denorm_preserve_float16,
+            "shaderDenormPreserveFloat16",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFloatControlsProperties<'static>,
+            shader_denorm_preserve_float32,
+            "shaderDenormPreserveFloat32",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFloatControlsProperties<'static>,
+            shader_denorm_preserve_float64,
+            "shaderDenormPreserveFloat64",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFloatControlsProperties<'static>,
+            shader_denorm_flush_to_zero_float16,
+            "shaderDenormFlushToZeroFloat16",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFloatControlsProperties<'static>,
+            shader_denorm_flush_to_zero_float32,
+            "shaderDenormFlushToZeroFloat32",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFloatControlsProperties<'static>,
+            shader_denorm_flush_to_zero_float64,
+            "shaderDenormFlushToZeroFloat64",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFloatControlsProperties<'static>,
+            shader_rounding_mode_rte_float16,
+            "shaderRoundingModeRTEFloat16",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFloatControlsProperties<'static>,
+            shader_rounding_mode_rte_float32,
+            "shaderRoundingModeRTEFloat32",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFloatControlsProperties<'static>,
+            shader_rounding_mode_rte_float64,
+            "shaderRoundingModeRTEFloat64",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFloatControlsProperties<'static>,
+            shader_rounding_mode_rtz_float16,
+            "shaderRoundingModeRTZFloat16",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFloatControlsProperties<'static>,
+            shader_rounding_mode_rtz_float32,
+            "shaderRoundingModeRTZFloat32",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFloatControlsProperties<'static>,
+            shader_rounding_mode_rtz_float64,
+            "shaderRoundingModeRTZFloat64",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP2_PROPERTIES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceFragmentDensityMap2PropertiesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_2_PROPERTIES_EXT),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceFragmentDensityMap2PropertiesEXT<'static>>(),
+    providers: &["VK_EXT_fragment_density_map2"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceFragmentDensityMap2PropertiesEXT<'static>,
+            subsampled_loads,
+            "subsampledLoads",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFragmentDensityMap2PropertiesEXT<'static>,
+            subsampled_coarse_reconstruction_early_access,
+            "subsampledCoarseReconstructionEarlyAccess",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFragmentDensityMap2PropertiesEXT<'static>,
+            max_subsampled_array_layers,
+            "maxSubsampledArrayLayers",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceFragmentDensityMap2PropertiesEXT<'static>,
+            max_descriptor_set_subsampled_samplers,
+            "maxDescriptorSetSubsampledSamplers",
+            T::Uint32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_OFFSET_PROPERTIES_QCOM: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDeviceFragmentDensityMapOffsetPropertiesQCOM",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_OFFSET_PROPERTIES_QCOM,
+        ),
+        category: Some(StructCategory::Properties),
+        size: size_of::<vk::PhysicalDeviceFragmentDensityMapOffsetPropertiesQCOM<'static>>(),
+        providers: &["VK_QCOM_fragment_density_map_offset"],
+        members: &[member!(
+            vk::PhysicalDeviceFragmentDensityMapOffsetPropertiesQCOM<'static>,
+            fragment_density_offset_granularity,
+            "fragmentDensityOffsetGranularity",
+            T::Struct(&EXTENT2_D)
+        )],
+    };
+
+pub(super) static PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_PROPERTIES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceFragmentDensityMapPropertiesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_PROPERTIES_EXT),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceFragmentDensityMapPropertiesEXT<'static>>(),
+    providers: &["VK_EXT_fragment_density_map"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceFragmentDensityMapPropertiesEXT<'static>,
+            min_fragment_density_texel_size,
+            "minFragmentDensityTexelSize",
+            T::Struct(&EXTENT2_D)
+        ),
+        member!(
+            vk::PhysicalDeviceFragmentDensityMapPropertiesEXT<'static>,
+            max_fragment_density_texel_size,
+            "maxFragmentDensityTexelSize",
+            T::Struct(&EXTENT2_D)
+        ),
+        member!(
+            vk::PhysicalDeviceFragmentDensityMapPropertiesEXT<'static>,
+            fragment_density_invocations,
+            "fragmentDensityInvocations",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_FRAGMENT_SHADER_BARYCENTRIC_PROPERTIES_KHR: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDeviceFragmentShaderBarycentricPropertiesKHR",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_FRAGMENT_SHADER_BARYCENTRIC_PROPERTIES_KHR,
+        ),
+        category: Some(StructCategory::Properties),
+        size: size_of::<vk::PhysicalDeviceFragmentShaderBarycentricPropertiesKHR<'static>>(),
+        providers: &["VK_KHR_fragment_shader_barycentric"],
+        members: &[member!(
+            vk::PhysicalDeviceFragmentShaderBarycentricPropertiesKHR<'static>,
+            tri_strip_vertex_order_independent_of_provoking_vertex,
+            "triStripVertexOrderIndependentOfProvokingVertex",
+            T::Bool32
+        )],
+    };
+
+pub(super) static PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_ENUMS_PROPERTIES_NV: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDeviceFragmentShadingRateEnumsPropertiesNV",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_ENUMS_PROPERTIES_NV,
+        ),
+        category: Some(StructCategory::Properties),
+        size: size_of::<vk::PhysicalDeviceFragmentShadingRateEnumsPropertiesNV<'static>>(),
+        providers: &["VK_NV_fragment_shading_rate_enums"],
+        members: &[member!(
+            vk::PhysicalDeviceFragmentShadingRateEnumsPropertiesNV<'static>,
+            max_fragment_shading_rate_invocation_count,
+            "maxFragmentShadingRateInvocationCount",
+            T::Flags(&SAMPLE_COUNT_FLAGS)
+        )],
+    };
+
+pub(super) static PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_PROPERTIES_KHR: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceFragmentShadingRatePropertiesKHR",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_PROPERTIES_KHR),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceFragmentShadingRatePropertiesKHR<'static>>(),
+    providers: &["VK_KHR_fragment_shading_rate"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceFragmentShadingRatePropertiesKHR<'static>,
+            min_fragment_shading_rate_attachment_texel_size,
+            "minFragmentShadingRateAttachmentTexelSize",
+            T::Struct(&EXTENT2_D)
+        ),
+        member!(
+            vk::PhysicalDeviceFragmentShadingRatePropertiesKHR<'static>,
+            max_fragment_shading_rate_attachment_texel_size,
+            "maxFragmentShadingRateAttachmentTexelSize",
+            T::Struct(&EXTENT2_D)
+        ),
+        member!(
+            vk::PhysicalDeviceFragmentShadingRatePropertiesKHR<'static>,
+            max_fragment_shading_rate_attachment_texel_size_aspect_ratio,
+            "maxFragmentShadingRateAttachmentTexelSizeAspectRatio",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceFragmentShadingRatePropertiesKHR<'static>,
+            primitive_fragment_shading_rate_with_multiple_viewports,
+            "primitiveFragmentShadingRateWithMultipleViewports",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFragmentShadingRatePropertiesKHR<'static>,
+            layered_shading_rate_attachments,
+            "layeredShadingRateAttachments",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFragmentShadingRatePropertiesKHR<'static>,
+            fragment_shading_rate_non_trivial_combiner_ops,
+            "fragmentShadingRateNonTrivialCombinerOps",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFragmentShadingRatePropertiesKHR<'static>,
+            max_fragment_size,
+            "maxFragmentSize",
+            T::Struct(&EXTENT2_D)
+        ),
+        member!(
+            vk::PhysicalDeviceFragmentShadingRatePropertiesKHR<'static>,
+            max_fragment_size_aspect_ratio,
+            "maxFragmentSizeAspectRatio",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceFragmentShadingRatePropertiesKHR<'static>,
+            max_fragment_shading_rate_coverage_samples,
+            "maxFragmentShadingRateCoverageSamples",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceFragmentShadingRatePropertiesKHR<'static>,
+            max_fragment_shading_rate_rasterization_samples,
+            "maxFragmentShadingRateRasterizationSamples",
+            T::Flags(&SAMPLE_COUNT_FLAGS)
+        ),
+        member!(
+            vk::PhysicalDeviceFragmentShadingRatePropertiesKHR<'static>,
+            fragment_shading_rate_with_shader_depth_stencil_writes,
+            "fragmentShadingRateWithShaderDepthStencilWrites",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFragmentShadingRatePropertiesKHR<'static>,
+            fragment_shading_rate_with_sample_mask,
+            "fragmentShadingRateWithSampleMask",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFragmentShadingRatePropertiesKHR<'static>,
+            fragment_shading_rate_with_shader_sample_mask,
+            "fragmentShadingRateWithShaderSampleMask",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFragmentShadingRatePropertiesKHR<'static>,
+            fragment_shading_rate_with_conservative_rasterization,
+            "fragmentShadingRateWithConservativeRasterization",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFragmentShadingRatePropertiesKHR<'static>,
+            fragment_shading_rate_with_fragment_shader_interlock,
+            "fragmentShadingRateWithFragmentShaderInterlock",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFragmentShadingRatePropertiesKHR<'static>,
+            fragment_shading_rate_with_custom_sample_locations,
+            "fragmentShadingRateWithCustomSampleLocations",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceFragmentShadingRatePropertiesKHR<'static>,
+            fragment_shading_rate_strict_multiply_combiner,
+            "fragmentShadingRateStrictMultiplyCombiner",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_GRAPHICS_PIPELINE_LIBRARY_PROPERTIES_EXT: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDeviceGraphicsPipelineLibraryPropertiesEXT",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_GRAPHICS_PIPELINE_LIBRARY_PROPERTIES_EXT,
+        ),
+        category: Some(StructCategory::Properties),
+        size: size_of::<vk::PhysicalDeviceGraphicsPipelineLibraryPropertiesEXT<'static>>(),
+        providers: &["VK_EXT_graphics_pipeline_library"],
+        members: &[
+            member!(
+                vk::PhysicalDeviceGraphicsPipelineLibraryPropertiesEXT<'static>,
+                graphics_pipeline_library_fast_linking,
+                "graphicsPipelineLibraryFastLinking",
+                T::Bool32
+            ),
+            member!(
+                vk::PhysicalDeviceGraphicsPipelineLibraryPropertiesEXT<'static>,
+                graphics_pipeline_library_independent_interpolation_decoration,
+                "graphicsPipelineLibraryIndependentInterpolationDecoration",
+                T::Bool32
+            ),
+        ],
+    };
+
+pub(super) static PHYSICAL_DEVICE_HOST_IMAGE_COPY_PROPERTIES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceHostImageCopyPropertiesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_HOST_IMAGE_COPY_PROPERTIES_EXT),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceHostImageCopyPropertiesEXT<'static>>(),
+    providers: &["VK_EXT_host_image_copy"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceHostImageCopyPropertiesEXT<'static>,
+            copy_src_layout_count,
+            "copySrcLayoutCount",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceHostImageCopyPropertiesEXT<'static>,
+            copy_dst_layout_count,
+            "copyDstLayoutCount",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceHostImageCopyPropertiesEXT<'static>,
+            optimal_tiling_layout_uuid,
+            "optimalTilingLayoutUUID",
+            T::Array(&T::Uint8, 16)
+        ),
+        member!(
+            vk::PhysicalDeviceHostImageCopyPropertiesEXT<'static>,
+            identical_memory_type_requirements,
+            "identicalMemoryTypeRequirements",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_ID_PROPERTIES: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceIDProperties",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_ID_PROPERTIES),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceIDProperties<'static>>(),
+    providers: &["VK_VERSION_1_1", "VK_KHR_external_memory_capabilities"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceIDProperties<'static>,
+            device_uuid,
+            "deviceUUID",
+            T::Array(&T::Uint8, 16)
+        ),
+        member!(
+            vk::PhysicalDeviceIDProperties<'static>,
+            driver_uuid,
+            "driverUUID",
+            T::Array(&T::Uint8, 16)
+        ),
+        member!(
+            vk::PhysicalDeviceIDProperties<'static>,
+            device_luid,
+            "deviceLUID",
+            T::Array(&T::Uint8, 8)
+        ),
+        member!(
+            vk::PhysicalDeviceIDProperties<'static>,
+            device_node_mask,
+            "deviceNodeMask",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceIDProperties<'static>,
+            device_luid_valid,
+            "deviceLUIDValid",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_IMAGE_PROCESSING2_PROPERTIES_QCOM: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceImageProcessing2PropertiesQCOM",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_IMAGE_PROCESSING_2_PROPERTIES_QCOM),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceImageProcessing2PropertiesQCOM<'static>>(),
+    providers: &["VK_QCOM_image_processing2"],
+    members: &[member!(
+        vk::PhysicalDeviceImageProcessing2PropertiesQCOM<'static>,
+        max_block_match_window,
+        "maxBlockMatchWindow",
+        T::Struct(&EXTENT2_D)
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_IMAGE_PROCESSING_PROPERTIES_QCOM: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceImageProcessingPropertiesQCOM",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_IMAGE_PROCESSING_PROPERTIES_QCOM),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceImageProcessingPropertiesQCOM<'static>>(),
+    providers: &["VK_QCOM_image_processing"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceImageProcessingPropertiesQCOM<'static>,
+            max_weight_filter_phases,
+            "maxWeightFilterPhases",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceImageProcessingPropertiesQCOM<'static>,
+            max_weight_filter_dimension,
+            "maxWeightFilterDimension",
+            T::Struct(&EXTENT2_D)
+        ),
+        member!(
+            vk::PhysicalDeviceImageProcessingPropertiesQCOM<'static>,
+            max_block_match_region,
+            "maxBlockMatchRegion",
+            T::Struct(&EXTENT2_D)
+        ),
+        member!(
+            vk::PhysicalDeviceImageProcessingPropertiesQCOM<'static>,
+            max_box_filter_block_size,
+            "maxBoxFilterBlockSize",
+            T::Struct(&EXTENT2_D)
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_PROPERTIES: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceInlineUniformBlockProperties",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_PROPERTIES),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceInlineUniformBlockProperties<'static>>(),
+    providers: &["VK_VERSION_1_3", "VK_EXT_inline_uniform_block"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceInlineUniformBlockProperties<'static>,
+            max_inline_uniform_block_size,
+            "maxInlineUniformBlockSize",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceInlineUniformBlockProperties<'static>,
+            max_per_stage_descriptor_inline_uniform_blocks,
+            "maxPerStageDescriptorInlineUniformBlocks",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceInlineUniformBlockProperties<'static>,
+            max_per_stage_descriptor_update_after_bind_inline_uniform_blocks,
+            "maxPerStageDescriptorUpdateAfterBindInlineUniformBlocks",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceInlineUniformBlockProperties<'static>,
+            max_descriptor_set_inline_uniform_blocks,
+            "maxDescriptorSetInlineUniformBlocks",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceInlineUniformBlockProperties<'static>,
+            max_descriptor_set_update_after_bind_inline_uniform_blocks,
+            "maxDescriptorSetUpdateAfterBindInlineUniformBlocks",
+            T::Uint32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_LAYERED_DRIVER_PROPERTIES_MSFT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceLayeredDriverPropertiesMSFT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_LAYERED_DRIVER_PROPERTIES_MSFT),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceLayeredDriverPropertiesMSFT<'static>>(),
+    providers: &["VK_MSFT_layered_driver"],
+    members: &[member!(
+        vk::PhysicalDeviceLayeredDriverPropertiesMSFT<'static>,
+        underlying_api,
+        "underlyingApi",
+        T::Enum(&LAYERED_DRIVER_UNDERLYING_API_MSFT)
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_LINE_RASTERIZATION_PROPERTIES_KHR: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceLineRasterizationPropertiesKHR",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_LINE_RASTERIZATION_PROPERTIES_KHR),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceLineRasterizationPropertiesKHR<'static>>(),
+    providers: &["VK_EXT_line_rasterization", "VK_KHR_line_rasterization"],
+    members: &[member!(
+        vk::PhysicalDeviceLineRasterizationPropertiesKHR<'static>,
+        line_sub_pixel_precision_bits,
+        "lineSubPixelPrecisionBits",
+        T::Uint32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_MAINTENANCE3_PROPERTIES: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceMaintenance3Properties",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_MAINTENANCE_3_PROPERTIES),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceMaintenance3Properties<'static>>(),
+    providers: &["VK_VERSION_1_1", "VK_KHR_maintenance3"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceMaintenance3Properties<'static>,
+            max_per_set_descriptors,
+            "maxPerSetDescriptors",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceMaintenance3Properties<'static>,
+            max_memory_allocation_size,
+            "maxMemoryAllocationSize",
+            T::Uint64
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_MAINTENANCE4_PROPERTIES: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceMaintenance4Properties",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_MAINTENANCE_4_PROPERTIES),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceMaintenance4Properties<'static>>(),
+    providers: &["VK_VERSION_1_3", "VK_KHR_maintenance4"],
+    members: &[member!(
+        vk::PhysicalDeviceMaintenance4Properties<'static>,
+        max_buffer_size,
+        "maxBufferSize",
+        T::Uint64
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_MAINTENANCE5_PROPERTIES_KHR: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceMaintenance5PropertiesKHR",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_MAINTENANCE_5_PROPERTIES_KHR),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceMaintenance5PropertiesKHR<'static>>(),
+    providers: &["VK_KHR_maintenance5"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceMaintenance5PropertiesKHR<'static>,
+            early_fragment_multisample_coverage_after_sample_counting,
+            "earlyFragmentMultisampleCoverageAfterSampleCounting",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceMaintenance5PropertiesKHR<'static>,
+            early_fragment_sample_mask_test_before_sample_counting,
+            "earlyFragmentSampleMaskTestBeforeSampleCounting",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceMaintenance5PropertiesKHR<'static>,
+            depth_stencil_swizzle_one_support,
+            "depthStencilSwizzleOneSupport",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceMaintenance5PropertiesKHR<'static>,
+            polygon_mode_point_size,
+            "polygonModePointSize",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceMaintenance5PropertiesKHR<'static>,
+            non_strict_single_pixel_wide_lines_use_parallelogram,
+            "nonStrictSinglePixelWideLinesUseParallelogram",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceMaintenance5PropertiesKHR<'static>,
+            non_strict_wide_lines_use_parallelogram,
+            "nonStrictWideLinesUseParallelogram",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_MAINTENANCE6_PROPERTIES_KHR: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceMaintenance6PropertiesKHR",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_MAINTENANCE_6_PROPERTIES_KHR),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceMaintenance6PropertiesKHR<'static>>(),
+    providers: &["VK_KHR_maintenance6"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceMaintenance6PropertiesKHR<'static>,
+            block_texel_view_compatible_multiple_layers,
+            "blockTexelViewCompatibleMultipleLayers",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceMaintenance6PropertiesKHR<'static>,
+            max_combined_image_sampler_descriptor_count,
+            "maxCombinedImageSamplerDescriptorCount",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceMaintenance6PropertiesKHR<'static>,
+            fragment_shading_rate_clamp_combiner_inputs,
+            "fragmentShadingRateClampCombinerInputs",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_MAP_MEMORY_PLACED_PROPERTIES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceMapMemoryPlacedPropertiesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_MAP_MEMORY_PLACED_PROPERTIES_EXT),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceMapMemoryPlacedPropertiesEXT<'static>>(),
+    providers: &["VK_EXT_map_memory_placed"],
+    members: &[member!(
+        vk::PhysicalDeviceMapMemoryPlacedPropertiesEXT<'static>,
+        min_placed_memory_map_alignment,
+        "minPlacedMemoryMapAlignment",
+        T::Uint64
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_MEMORY_DECOMPRESSION_PROPERTIES_NV: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceMemoryDecompressionPropertiesNV",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_MEMORY_DECOMPRESSION_PROPERTIES_NV),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceMemoryDecompressionPropertiesNV<'static>>(),
+    providers: &["VK_NV_memory_decompression"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceMemoryDecompressionPropertiesNV<'static>,
+            decompression_methods,
+            "decompressionMethods",
+            T::Flags(&MEMORY_DECOMPRESSION_METHOD_FLAGS_NV)
+        ),
+        member!(
+            vk::PhysicalDeviceMemoryDecompressionPropertiesNV<'static>,
+            max_decompression_indirect_count,
+            "maxDecompressionIndirectCount",
+            T::Uint64
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_MESH_SHADER_PROPERTIES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceMeshShaderPropertiesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_MESH_SHADER_PROPERTIES_EXT),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceMeshShaderPropertiesEXT<'static>>(),
+    providers: &["VK_EXT_mesh_shader"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceMeshShaderPropertiesEXT<'static>,
+            max_task_work_group_total_count,
+            "maxTaskWorkGroupTotalCount",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceMeshShaderPropertiesEXT<'static>,
+            max_task_work_group_count,
+            "maxTaskWorkGroupCount",
+            T::Array(&T::Uint32, 3)
+        ),
+        member!(
+            vk::PhysicalDeviceMeshShaderPropertiesEXT<'static>,
+            max_task_work_group_invocations,
+            "maxTaskWorkGroupInvocations",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceMeshShaderPropertiesEXT<'static>,
+            max_task_work_group_size,
+            "maxTaskWorkGroupSize",
+            T::Array(&T::Uint32, 3)
+        ),
+        member!(
+            vk::PhysicalDeviceMeshShaderPropertiesEXT<'static>,
+            max_task_payload_size,
+            "maxTaskPayloadSize",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceMeshShaderPropertiesEXT<'static>,
+            max_task_shared_memory_size,
+            "maxTaskSharedMemorySize",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceMeshShaderPropertiesEXT<'static>,
+            max_task_payload_and_shared_memory_size,
+            "maxTaskPayloadAndSharedMemorySize",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceMeshShaderPropertiesEXT<'static>,
+            max_mesh_work_group_total_count,
+            "maxMeshWorkGroupTotalCount",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceMeshShaderPropertiesEXT<'static>,
+            max_mesh_work_group_count,
+            "maxMeshWorkGroupCount",
+            T::Array(&T::Uint32, 3)
+        ),
+        member!(
+            vk::PhysicalDeviceMeshShaderPropertiesEXT<'static>,
+            max_mesh_work_group_invocations,
+            "maxMeshWorkGroupInvocations",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceMeshShaderPropertiesEXT<'static>,
+            max_mesh_work_group_size,
+            "maxMeshWorkGroupSize",
+            T::Array(&T::Uint32, 3)
+        ),
+        member!(
+            vk::PhysicalDeviceMeshShaderPropertiesEXT<'static>,
+            max_mesh_shared_memory_size,
+            "maxMeshSharedMemorySize",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceMeshShaderPropertiesEXT<'static>,
+            max_mesh_payload_and_shared_memory_size,
+            "maxMeshPayloadAndSharedMemorySize",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceMeshShaderPropertiesEXT<'static>,
+            max_mesh_output_memory_size,
+            "maxMeshOutputMemorySize",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceMeshShaderPropertiesEXT<'static>,
+            max_mesh_payload_and_output_memory_size,
+            "maxMeshPayloadAndOutputMemorySize",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceMeshShaderPropertiesEXT<'static>,
+            max_mesh_output_components,
+            "maxMeshOutputComponents",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceMeshShaderPropertiesEXT<'static>,
+            max_mesh_output_vertices,
+            "maxMeshOutputVertices",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceMeshShaderPropertiesEXT<'static>,
+            max_mesh_output_primitives,
+            "maxMeshOutputPrimitives",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceMeshShaderPropertiesEXT<'static>,
+            max_mesh_output_layers,
+            "maxMeshOutputLayers",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceMeshShaderPropertiesEXT<'static>,
+            max_mesh_multiview_view_count,
+            "maxMeshMultiviewViewCount",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceMeshShaderPropertiesEXT<'static>,
+            mesh_output_per_vertex_granularity,
+            "meshOutputPerVertexGranularity",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceMeshShaderPropertiesEXT<'static>,
+            mesh_output_per_primitive_granularity,
+            "meshOutputPerPrimitiveGranularity",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceMeshShaderPropertiesEXT<'static>,
+            max_preferred_task_work_group_invocations,
+            "maxPreferredTaskWorkGroupInvocations",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceMeshShaderPropertiesEXT<'static>,
+            max_preferred_mesh_work_group_invocations,
+            "maxPreferredMeshWorkGroupInvocations",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceMeshShaderPropertiesEXT<'static>,
+            prefers_local_invocation_vertex_output,
+            "prefersLocalInvocationVertexOutput",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceMeshShaderPropertiesEXT<'static>,
+            prefers_local_invocation_primitive_output,
+            "prefersLocalInvocationPrimitiveOutput",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceMeshShaderPropertiesEXT<'static>,
+            prefers_compact_vertex_output,
+            "prefersCompactVertexOutput",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceMeshShaderPropertiesEXT<'static>,
+            prefers_compact_primitive_output,
+            "prefersCompactPrimitiveOutput",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_MESH_SHADER_PROPERTIES_NV: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceMeshShaderPropertiesNV",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_MESH_SHADER_PROPERTIES_NV),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceMeshShaderPropertiesNV<'static>>(),
+    providers: &["VK_NV_mesh_shader"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceMeshShaderPropertiesNV<'static>,
+            max_draw_mesh_tasks_count,
+            "maxDrawMeshTasksCount",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceMeshShaderPropertiesNV<'static>,
+            max_task_work_group_invocations,
+            "maxTaskWorkGroupInvocations",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceMeshShaderPropertiesNV<'static>,
+            max_task_work_group_size,
+            "maxTaskWorkGroupSize",
+            T::Array(&T::Uint32, 3)
+        ),
+        member!(
+            vk::PhysicalDeviceMeshShaderPropertiesNV<'static>,
+            max_task_total_memory_size,
+            "maxTaskTotalMemorySize",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceMeshShaderPropertiesNV<'static>,
+            max_task_output_count,
+            "maxTaskOutputCount",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceMeshShaderPropertiesNV<'static>,
+            max_mesh_work_group_invocations,
+            "maxMeshWorkGroupInvocations",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceMeshShaderPropertiesNV<'static>,
+            max_mesh_work_group_size,
+            "maxMeshWorkGroupSize",
+            T::Array(&T::Uint32, 3)
+        ),
+        member!(
+            vk::PhysicalDeviceMeshShaderPropertiesNV<'static>,
+            max_mesh_total_memory_size,
+            "maxMeshTotalMemorySize",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceMeshShaderPropertiesNV<'static>,
+            max_mesh_output_vertices,
+            "maxMeshOutputVertices",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceMeshShaderPropertiesNV<'static>,
+            max_mesh_output_primitives,
+            "maxMeshOutputPrimitives",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceMeshShaderPropertiesNV<'static>,
+            max_mesh_multiview_view_count,
+            "maxMeshMultiviewViewCount",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceMeshShaderPropertiesNV<'static>,
+            mesh_output_per_vertex_granularity,
+            "meshOutputPerVertexGranularity",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceMeshShaderPropertiesNV<'static>,
+            mesh_output_per_primitive_granularity,
+            "meshOutputPerPrimitiveGranularity",
+            T::Uint32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_MULTI_DRAW_PROPERTIES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceMultiDrawPropertiesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_MULTI_DRAW_PROPERTIES_EXT),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceMultiDrawPropertiesEXT<'static>>(),
+    providers: &["VK_EXT_multi_draw"],
+    members: &[member!(
+        vk::PhysicalDeviceMultiDrawPropertiesEXT<'static>,
+        max_multi_draw_count,
+        "maxMultiDrawCount",
+        T::Uint32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_MULTIVIEW_PER_VIEW_ATTRIBUTES_PROPERTIES_NVX: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDeviceMultiviewPerViewAttributesPropertiesNVX",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_MULTIVIEW_PER_VIEW_ATTRIBUTES_PROPERTIES_NVX,
+        ),
+        category: Some(StructCategory::Properties),
+        size: size_of::<vk::PhysicalDeviceMultiviewPerViewAttributesPropertiesNVX<'static>>(),
+        providers: &["VK_NVX_multiview_per_view_attributes"],
+        members: &[member!(
+            vk::PhysicalDeviceMultiviewPerViewAttributesPropertiesNVX<'static>,
+            per_view_position_all_components,
+            "perViewPositionAllComponents",
+            T::Bool32
+        )],
+    };
+
+pub(super) static PHYSICAL_DEVICE_MULTIVIEW_PROPERTIES: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceMultiviewProperties",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_MULTIVIEW_PROPERTIES),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceMultiviewProperties<'static>>(),
+    providers: &["VK_VERSION_1_1", "VK_KHR_multiview"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceMultiviewProperties<'static>,
+            max_multiview_view_count,
+            "maxMultiviewViewCount",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceMultiviewProperties<'static>,
+            max_multiview_instance_index,
+            "maxMultiviewInstanceIndex",
+            T::Uint32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_NESTED_COMMAND_BUFFER_PROPERTIES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceNestedCommandBufferPropertiesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_NESTED_COMMAND_BUFFER_PROPERTIES_EXT),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceNestedCommandBufferPropertiesEXT<'static>>(),
+    providers: &["VK_EXT_nested_command_buffer"],
+    members: &[member!(
+        vk::PhysicalDeviceNestedCommandBufferPropertiesEXT<'static>,
+        max_command_buffer_nesting_level,
+        "maxCommandBufferNestingLevel",
+        T::Uint32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_OPACITY_MICROMAP_PROPERTIES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceOpacityMicromapPropertiesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_OPACITY_MICROMAP_PROPERTIES_EXT),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceOpacityMicromapPropertiesEXT<'static>>(),
+    providers: &["VK_EXT_opacity_micromap"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceOpacityMicromapPropertiesEXT<'static>,
+            max_opacity2_state_subdivision_level,
+            "maxOpacity2StateSubdivisionLevel",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceOpacityMicromapPropertiesEXT<'static>,
+            max_opacity4_state_subdivision_level,
+            "maxOpacity4StateSubdivisionLevel",
+            T::Uint32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_OPTICAL_FLOW_PROPERTIES_NV: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceOpticalFlowPropertiesNV",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_OPTICAL_FLOW_PROPERTIES_NV),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceOpticalFlowPropertiesNV<'static>>(),
+    providers: &["VK_NV_optical_flow"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceOpticalFlowPropertiesNV<'static>,
+            supported_output_grid_sizes,
+            "supportedOutputGridSizes",
+            T::Flags(&OPTICAL_FLOW_GRID_SIZE_FLAGS_NV)
+        ),
+        member!(
+            vk::PhysicalDeviceOpticalFlowPropertiesNV<'static>,
+            supported_hint_grid_sizes,
+            "supportedHintGridSizes",
+            T::Flags(&OPTICAL_FLOW_GRID_SIZE_FLAGS_NV)
+        ),
+        member!(
+            vk::PhysicalDeviceOpticalFlowPropertiesNV<'static>,
+            hint_supported,
+            "hintSupported",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceOpticalFlowPropertiesNV<'static>,
+            cost_supported,
+            "costSupported",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceOpticalFlowPropertiesNV<'static>,
+            bidirectional_flow_supported,
+            "bidirectionalFlowSupported",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceOpticalFlowPropertiesNV<'static>,
+            global_flow_supported,
+            "globalFlowSupported",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceOpticalFlowPropertiesNV<'static>,
+            min_width,
+            "minWidth",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceOpticalFlowPropertiesNV<'static>,
+            min_height,
+            "minHeight",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceOpticalFlowPropertiesNV<'static>,
+            max_width,
+            "maxWidth",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceOpticalFlowPropertiesNV<'static>,
+            max_height,
+            "maxHeight",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceOpticalFlowPropertiesNV<'static>,
+            max_num_regions_of_interest,
+            "maxNumRegionsOfInterest",
+            T::Uint32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_PCI_BUS_INFO_PROPERTIES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDevicePCIBusInfoPropertiesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_PCI_BUS_INFO_PROPERTIES_EXT),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDevicePCIBusInfoPropertiesEXT<'static>>(),
+    providers: &["VK_EXT_pci_bus_info"],
+    members: &[
+        member!(
+            vk::PhysicalDevicePCIBusInfoPropertiesEXT<'static>,
+            pci_domain,
+            "pciDomain",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDevicePCIBusInfoPropertiesEXT<'static>,
+            pci_bus,
+            "pciBus",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDevicePCIBusInfoPropertiesEXT<'static>,
+            pci_device,
+            "pciDevice",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDevicePCIBusInfoPropertiesEXT<'static>,
+            pci_function,
+            "pciFunction",
+            T::Uint32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_PERFORMANCE_QUERY_PROPERTIES_KHR: StructInfo = StructInfo {
+    name: "VkPhysicalDevicePerformanceQueryPropertiesKHR",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_PERFORMANCE_QUERY_PROPERTIES_KHR),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDevicePerformanceQueryPropertiesKHR<'static>>(),
+    providers: &["VK_KHR_performance_query"],
+    members: &[member!(
+        vk::PhysicalDevicePerformanceQueryPropertiesKHR<'static>,
+        allow_command_buffer_query_copies,
+        "allowCommandBufferQueryCopies",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_PIPELINE_ROBUSTNESS_PROPERTIES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDevicePipelineRobustnessPropertiesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_PIPELINE_ROBUSTNESS_PROPERTIES_EXT),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDevicePipelineRobustnessPropertiesEXT<'static>>(),
+    providers: &["VK_EXT_pipeline_robustness"],
+    members: &[
+        member!(
+            vk::PhysicalDevicePipelineRobustnessPropertiesEXT<'static>,
+            default_robustness_storage_buffers,
+            "defaultRobustnessStorageBuffers",
+            T::Enum(&PIPELINE_ROBUSTNESS_BUFFER_BEHAVIOR_EXT)
+        ),
+        member!(
+            vk::PhysicalDevicePipelineRobustnessPropertiesEXT<'static>,
+            default_robustness_uniform_buffers,
+            "defaultRobustnessUniformBuffers",
+            T::Enum(&PIPELINE_ROBUSTNESS_BUFFER_BEHAVIOR_EXT)
+        ),
+        member!(
+            vk::PhysicalDevicePipelineRobustnessPropertiesEXT<'static>,
+            default_robustness_vertex_inputs,
+            "defaultRobustnessVertexInputs",
+            T::Enum(&PIPELINE_ROBUSTNESS_BUFFER_BEHAVIOR_EXT)
+        ),
+        member!(
+            vk::PhysicalDevicePipelineRobustnessPropertiesEXT<'static>,
+            default_robustness_images,
+            "defaultRobustnessImages",
+            T::Enum(&PIPELINE_ROBUSTNESS_IMAGE_BEHAVIOR_EXT)
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_POINT_CLIPPING_PROPERTIES: StructInfo = StructInfo {
+    name: "VkPhysicalDevicePointClippingProperties",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_POINT_CLIPPING_PROPERTIES),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDevicePointClippingProperties<'static>>(),
+    providers: &["VK_VERSION_1_1", "VK_KHR_maintenance2"],
+    members: &[member!(
+        vk::PhysicalDevicePointClippingProperties<'static>,
+        point_clipping_behavior,
+        "pointClippingBehavior",
+        T::Enum(&POINT_CLIPPING_BEHAVIOR)
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_PORTABILITY_SUBSET_PROPERTIES_KHR: StructInfo = StructInfo {
+    name: "VkPhysicalDevicePortabilitySubsetPropertiesKHR",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_PORTABILITY_SUBSET_PROPERTIES_KHR),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDevicePortabilitySubsetPropertiesKHR<'static>>(),
+    providers: &["VK_KHR_portability_subset"],
+    members: &[member!(
+        vk::PhysicalDevicePortabilitySubsetPropertiesKHR<'static>,
+        min_vertex_input_binding_stride_alignment,
+        "minVertexInputBindingStrideAlignment",
+        T::Uint32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_PROTECTED_MEMORY_PROPERTIES: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceProtectedMemoryProperties",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_PROTECTED_MEMORY_PROPERTIES),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceProtectedMemoryProperties<'static>>(),
+    providers: &["VK_VERSION_1_1"],
+    members: &[member!(
+        vk::PhysicalDeviceProtectedMemoryProperties<'static>,
+        protected_no_fault,
+        "protectedNoFault",
+        T::Bool32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_PROVOKING_VERTEX_PROPERTIES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceProvokingVertexPropertiesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_PROVOKING_VERTEX_PROPERTIES_EXT),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceProvokingVertexPropertiesEXT<'static>>(),
+    providers: &["VK_EXT_provoking_vertex"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceProvokingVertexPropertiesEXT<'static>,
+            provoking_vertex_mode_per_pipeline,
+            "provokingVertexModePerPipeline",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceProvokingVertexPropertiesEXT<'static>,
+            transform_feedback_preserves_triangle_fan_provoking_vertex,
+            "transformFeedbackPreservesTriangleFanProvokingVertex",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_PUSH_DESCRIPTOR_PROPERTIES_KHR: StructInfo = StructInfo {
+    name: "VkPhysicalDevicePushDescriptorPropertiesKHR",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_PUSH_DESCRIPTOR_PROPERTIES_KHR),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDevicePushDescriptorPropertiesKHR<'static>>(),
+    providers: &["VK_KHR_push_descriptor"],
+    members: &[member!(
+        vk::PhysicalDevicePushDescriptorPropertiesKHR<'static>,
+        max_push_descriptors,
+        "maxPushDescriptors",
+        T::Uint32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_RAY_TRACING_INVOCATION_REORDER_PROPERTIES_NV: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDeviceRayTracingInvocationReorderPropertiesNV",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_RAY_TRACING_INVOCATION_REORDER_PROPERTIES_NV,
+        ),
+        category: Some(StructCategory::Properties),
+        size: size_of::<vk::PhysicalDeviceRayTracingInvocationReorderPropertiesNV<'static>>(),
+        providers: &["VK_NV_ray_tracing_invocation_reorder"],
+        members: &[member!(
+            vk::PhysicalDeviceRayTracingInvocationReorderPropertiesNV<'static>,
+            ray_tracing_invocation_reorder_reordering_hint,
+            "rayTracingInvocationReorderReorderingHint",
+            T::Enum(&RAY_TRACING_INVOCATION_REORDER_MODE_NV)
+        )],
+    };
+
+pub(super) static PHYSICAL_DEVICE_RAY_TRACING_PIPELINE_PROPERTIES_KHR: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceRayTracingPipelinePropertiesKHR",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_RAY_TRACING_PIPELINE_PROPERTIES_KHR),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceRayTracingPipelinePropertiesKHR<'static>>(),
+    providers: &["VK_KHR_ray_tracing_pipeline"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceRayTracingPipelinePropertiesKHR<'static>,
+            shader_group_handle_size,
+            "shaderGroupHandleSize",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceRayTracingPipelinePropertiesKHR<'static>,
+            max_ray_recursion_depth,
+            "maxRayRecursionDepth",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceRayTracingPipelinePropertiesKHR<'static>,
+            max_shader_group_stride,
+            "maxShaderGroupStride",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceRayTracingPipelinePropertiesKHR<'static>,
+            shader_group_base_alignment,
+            "shaderGroupBaseAlignment",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceRayTracingPipelinePropertiesKHR<'static>,
+            shader_group_handle_capture_replay_size,
+            "shaderGroupHandleCaptureReplaySize",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceRayTracingPipelinePropertiesKHR<'static>,
+            max_ray_dispatch_invocation_count,
+            "maxRayDispatchInvocationCount",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceRayTracingPipelinePropertiesKHR<'static>,
+            shader_group_handle_alignment,
+            "shaderGroupHandleAlignment",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceRayTracingPipelinePropertiesKHR<'static>,
+            max_ray_hit_attribute_size,
+            "maxRayHitAttributeSize",
+            T::Uint32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_RAY_TRACING_PROPERTIES_NV: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceRayTracingPropertiesNV",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_RAY_TRACING_PROPERTIES_NV),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceRayTracingPropertiesNV<'static>>(),
+    providers: &["VK_NV_ray_tracing"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceRayTracingPropertiesNV<'static>,
+            shader_group_handle_size,
+            "shaderGroupHandleSize",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceRayTracingPropertiesNV<'static>,
+            max_recursion_depth,
+            "maxRecursionDepth",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceRayTracingPropertiesNV<'static>,
+            max_shader_group_stride,
+            "maxShaderGroupStride",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceRayTracingPropertiesNV<'static>,
+            shader_group_base_alignment,
+            "shaderGroupBaseAlignment",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceRayTracingPropertiesNV<'static>,
+            max_geometry_count,
+            "maxGeometryCount",
+            T::Uint64
+        ),
+        member!(
+            vk::PhysicalDeviceRayTracingPropertiesNV<'static>,
+            max_instance_count,
+            "maxInstanceCount",
+            T::Uint64
+        ),
+        member!(
+            vk::PhysicalDeviceRayTracingPropertiesNV<'static>,
+            max_triangle_count,
+            "maxTriangleCount",
+            T::Uint64
+        ),
+        member!(
+            vk::PhysicalDeviceRayTracingPropertiesNV<'static>,
+            max_descriptor_set_acceleration_structures,
+            "maxDescriptorSetAccelerationStructures",
+            T::Uint32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_RENDER_PASS_STRIPED_PROPERTIES_ARM: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceRenderPassStripedPropertiesARM",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_RENDER_PASS_STRIPED_PROPERTIES_ARM),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceRenderPassStripedPropertiesARM<'static>>(),
+    providers: &["VK_ARM_render_pass_striped"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceRenderPassStripedPropertiesARM<'static>,
+            render_pass_stripe_granularity,
+            "renderPassStripeGranularity",
+            T::Struct(&EXTENT2_D)
+        ),
+        member!(
+            vk::PhysicalDeviceRenderPassStripedPropertiesARM<'static>,
+            max_render_pass_stripes,
+            "maxRenderPassStripes",
+            T::Uint32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_ROBUSTNESS2_PROPERTIES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceRobustness2PropertiesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_ROBUSTNESS_2_PROPERTIES_EXT),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceRobustness2PropertiesEXT<'static>>(),
+    providers: &["VK_EXT_robustness2"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceRobustness2PropertiesEXT<'static>,
+            robust_storage_buffer_access_size_alignment,
+            "robustStorageBufferAccessSizeAlignment",
+            T::Uint64
+        ),
+        member!(
+            vk::PhysicalDeviceRobustness2PropertiesEXT<'static>,
+            robust_uniform_buffer_access_size_alignment,
+            "robustUniformBufferAccessSizeAlignment",
+            T::Uint64
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_SAMPLE_LOCATIONS_PROPERTIES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceSampleLocationsPropertiesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_SAMPLE_LOCATIONS_PROPERTIES_EXT),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceSampleLocationsPropertiesEXT<'static>>(),
+    providers: &["VK_EXT_sample_locations"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceSampleLocationsPropertiesEXT<'static>,
+            sample_location_sample_counts,
+            "sampleLocationSampleCounts",
+            T::Flags(&SAMPLE_COUNT_FLAGS)
+        ),
+        member!(
+            vk::PhysicalDeviceSampleLocationsPropertiesEXT<'static>,
+            max_sample_location_grid_size,
+            "maxSampleLocationGridSize",
+            T::Struct(&EXTENT2_D)
+        ),
+        member!(
+            vk::PhysicalDeviceSampleLocationsPropertiesEXT<'static>,
+            sample_location_coordinate_range,
+            "sampleLocationCoordinateRange",
+            T::Array(&T::Float32, 2)
+        ),
+        member!(
+            vk::PhysicalDeviceSampleLocationsPropertiesEXT<'static>,
+            sample_location_sub_pixel_bits,
+            "sampleLocationSubPixelBits",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceSampleLocationsPropertiesEXT<'static>,
+            variable_sample_locations,
+            "variableSampleLocations",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_SAMPLER_FILTER_MINMAX_PROPERTIES: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceSamplerFilterMinmaxProperties",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_SAMPLER_FILTER_MINMAX_PROPERTIES),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceSamplerFilterMinmaxProperties<'static>>(),
+    providers: &["VK_VERSION_1_2", "VK_EXT_sampler_filter_minmax"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceSamplerFilterMinmaxProperties<'static>,
+            filter_minmax_single_component_formats,
+            "filterMinmaxSingleComponentFormats",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceSamplerFilterMinmaxProperties<'static>,
+            filter_minmax_image_component_mapping,
+            "filterMinmaxImageComponentMapping",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_SCHEDULING_CONTROLS_PROPERTIES_ARM: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceSchedulingControlsPropertiesARM",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_SCHEDULING_CONTROLS_PROPERTIES_ARM),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceSchedulingControlsPropertiesARM<'static>>(),
+    providers: &["VK_ARM_scheduling_controls"],
+    members: &[member!(
+        vk::PhysicalDeviceSchedulingControlsPropertiesARM<'static>,
+        scheduling_controls_flags,
+        "schedulingControlsFlags",
+        T::Flags(&PHYSICAL_DEVICE_SCHEDULING_CONTROLS_FLAGS_ARM)
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_SHADER_CORE_BUILTINS_PROPERTIES_ARM: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceShaderCoreBuiltinsPropertiesARM",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_SHADER_CORE_BUILTINS_PROPERTIES_ARM),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceShaderCoreBuiltinsPropertiesARM<'static>>(),
+    providers: &["VK_ARM_shader_core_builtins"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceShaderCoreBuiltinsPropertiesARM<'static>,
+            shader_core_mask,
+            "shaderCoreMask",
+            T::Uint64
+        ),
+        member!(
+            vk::PhysicalDeviceShaderCoreBuiltinsPropertiesARM<'static>,
+            shader_core_count,
+            "shaderCoreCount",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderCoreBuiltinsPropertiesARM<'static>,
+            shader_warps_per_core,
+            "shaderWarpsPerCore",
+            T::Uint32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES2_AMD: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceShaderCoreProperties2AMD",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES_2_AMD),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceShaderCoreProperties2AMD<'static>>(),
+    providers: &["VK_AMD_shader_core_properties2"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceShaderCoreProperties2AMD<'static>,
+            shader_core_features,
+            "shaderCoreFeatures",
+            T::Flags(&SHADER_CORE_PROPERTIES_FLAGS_AMD)
+        ),
+        member!(
+            vk::PhysicalDeviceShaderCoreProperties2AMD<'static>,
+            active_compute_unit_count,
+            "activeComputeUnitCount",
+            T::Uint32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES_AMD: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceShaderCorePropertiesAMD",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES_AMD),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceShaderCorePropertiesAMD<'static>>(),
+    providers: &["VK_AMD_shader_core_properties"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceShaderCorePropertiesAMD<'static>,
+            shader_engine_count,
+            "shaderEngineCount",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderCorePropertiesAMD<'static>,
+            shader_arrays_per_engine_count,
+            "shaderArraysPerEngineCount",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderCorePropertiesAMD<'static>,
+            compute_units_per_shader_array,
+            "computeUnitsPerShaderArray",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderCorePropertiesAMD<'static>,
+            simd_per_compute_unit,
+            "simdPerComputeUnit",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderCorePropertiesAMD<'static>,
+            wavefronts_per_simd,
+            "wavefrontsPerSimd",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderCorePropertiesAMD<'static>,
+            wavefront_size,
+            "wavefrontSize",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderCorePropertiesAMD<'static>,
+            sgprs_per_simd,
+            "sgprsPerSimd",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderCorePropertiesAMD<'static>,
+            min_sgpr_allocation,
+            "minSgprAllocation",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderCorePropertiesAMD<'static>,
+            max_sgpr_allocation,
+            "maxSgprAllocation",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderCorePropertiesAMD<'static>,
+            sgpr_allocation_granularity,
+            "sgprAllocationGranularity",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderCorePropertiesAMD<'static>,
+            vgprs_per_simd,
+            "vgprsPerSimd",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderCorePropertiesAMD<'static>,
+            min_vgpr_allocation,
+            "minVgprAllocation",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderCorePropertiesAMD<'static>,
+            max_vgpr_allocation,
+            "maxVgprAllocation",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderCorePropertiesAMD<'static>,
+            vgpr_allocation_granularity,
+            "vgprAllocationGranularity",
+            T::Uint32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES_ARM: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceShaderCorePropertiesARM",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES_ARM),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceShaderCorePropertiesARM<'static>>(),
+    providers: &["VK_ARM_shader_core_properties"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceShaderCorePropertiesARM<'static>,
+            pixel_rate,
+            "pixelRate",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderCorePropertiesARM<'static>,
+            texel_rate,
+            "texelRate",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderCorePropertiesARM<'static>,
+            fma_rate,
+            "fmaRate",
+            T::Uint32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_SHADER_ENQUEUE_PROPERTIES_AMDX: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceShaderEnqueuePropertiesAMDX",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_SHADER_ENQUEUE_PROPERTIES_AMDX),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceShaderEnqueuePropertiesAMDX<'static>>(),
+    providers: &["VK_AMDX_shader_enqueue"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceShaderEnqueuePropertiesAMDX<'static>,
+            max_execution_graph_depth,
+            "maxExecutionGraphDepth",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderEnqueuePropertiesAMDX<'static>,
+            max_execution_graph_shader_output_nodes,
+            "maxExecutionGraphShaderOutputNodes",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderEnqueuePropertiesAMDX<'static>,
+            max_execution_graph_shader_payload_size,
+            "maxExecutionGraphShaderPayloadSize",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderEnqueuePropertiesAMDX<'static>,
+            max_execution_graph_shader_payload_count,
+            "maxExecutionGraphShaderPayloadCount",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderEnqueuePropertiesAMDX<'static>,
+            execution_graph_dispatch_address_alignment,
+            "executionGraphDispatchAddressAlignment",
+            T::Uint32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_SHADER_INTEGER_DOT_PRODUCT_PROPERTIES: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceShaderIntegerDotProductProperties",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_SHADER_INTEGER_DOT_PRODUCT_PROPERTIES),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceShaderIntegerDotProductProperties<'static>>(),
+    providers: &["VK_VERSION_1_3", "VK_KHR_shader_integer_dot_product"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceShaderIntegerDotProductProperties<'static>,
+            integer_dot_product8_bit_unsigned_accelerated,
+            "integerDotProduct8BitUnsignedAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderIntegerDotProductProperties<'static>,
+            integer_dot_product8_bit_signed_accelerated,
+            "integerDotProduct8BitSignedAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderIntegerDotProductProperties<'static>,
+            integer_dot_product8_bit_mixed_signedness_accelerated,
+            "integerDotProduct8BitMixedSignednessAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderIntegerDotProductProperties<'static>,
+            integer_dot_product4x8_bit_packed_unsigned_accelerated,
+            "integerDotProduct4x8BitPackedUnsignedAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderIntegerDotProductProperties<'static>,
+            integer_dot_product4x8_bit_packed_signed_accelerated,
+            "integerDotProduct4x8BitPackedSignedAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderIntegerDotProductProperties<'static>,
+            integer_dot_product4x8_bit_packed_mixed_signedness_accelerated,
+            "integerDotProduct4x8BitPackedMixedSignednessAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderIntegerDotProductProperties<'static>,
+            integer_dot_product16_bit_unsigned_accelerated,
+            "integerDotProduct16BitUnsignedAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderIntegerDotProductProperties<'static>,
+            integer_dot_product16_bit_signed_accelerated,
+            "integerDotProduct16BitSignedAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderIntegerDotProductProperties<'static>,
+            integer_dot_product16_bit_mixed_signedness_accelerated,
+            "integerDotProduct16BitMixedSignednessAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderIntegerDotProductProperties<'static>,
+            integer_dot_product32_bit_unsigned_accelerated,
+            "integerDotProduct32BitUnsignedAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderIntegerDotProductProperties<'static>,
+            integer_dot_product32_bit_signed_accelerated,
+            "integerDotProduct32BitSignedAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderIntegerDotProductProperties<'static>,
+            integer_dot_product32_bit_mixed_signedness_accelerated,
+            "integerDotProduct32BitMixedSignednessAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderIntegerDotProductProperties<'static>,
+            integer_dot_product64_bit_unsigned_accelerated,
+            "integerDotProduct64BitUnsignedAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderIntegerDotProductProperties<'static>,
+            integer_dot_product64_bit_signed_accelerated,
+            "integerDotProduct64BitSignedAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderIntegerDotProductProperties<'static>,
+            integer_dot_product64_bit_mixed_signedness_accelerated,
+            "integerDotProduct64BitMixedSignednessAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderIntegerDotProductProperties<'static>,
+            integer_dot_product_accumulating_saturating8_bit_unsigned_accelerated,
+            "integerDotProductAccumulatingSaturating8BitUnsignedAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderIntegerDotProductProperties<'static>,
+            integer_dot_product_accumulating_saturating8_bit_signed_accelerated,
+            "integerDotProductAccumulatingSaturating8BitSignedAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderIntegerDotProductProperties<'static>,
+            integer_dot_product_accumulating_saturating8_bit_mixed_signedness_accelerated,
+            "integerDotProductAccumulatingSaturating8BitMixedSignednessAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderIntegerDotProductProperties<'static>,
+            integer_dot_product_accumulating_saturating4x8_bit_packed_unsigned_accelerated,
+            "integerDotProductAccumulatingSaturating4x8BitPackedUnsignedAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderIntegerDotProductProperties<'static>,
+            integer_dot_product_accumulating_saturating4x8_bit_packed_signed_accelerated,
+            "integerDotProductAccumulatingSaturating4x8BitPackedSignedAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderIntegerDotProductProperties<'static>,
+            integer_dot_product_accumulating_saturating16_bit_unsigned_accelerated,
+            "integerDotProductAccumulatingSaturating16BitUnsignedAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderIntegerDotProductProperties<'static>,
+            integer_dot_product_accumulating_saturating16_bit_signed_accelerated,
+            "integerDotProductAccumulatingSaturating16BitSignedAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderIntegerDotProductProperties<'static>,
+            integer_dot_product_accumulating_saturating16_bit_mixed_signedness_accelerated,
+            "integerDotProductAccumulatingSaturating16BitMixedSignednessAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderIntegerDotProductProperties<'static>,
+            integer_dot_product_accumulating_saturating32_bit_unsigned_accelerated,
+            "integerDotProductAccumulatingSaturating32BitUnsignedAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderIntegerDotProductProperties<'static>,
+            integer_dot_product_accumulating_saturating32_bit_signed_accelerated,
+            "integerDotProductAccumulatingSaturating32BitSignedAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderIntegerDotProductProperties<'static>,
+            integer_dot_product_accumulating_saturating32_bit_mixed_signedness_accelerated,
+            "integerDotProductAccumulatingSaturating32BitMixedSignednessAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderIntegerDotProductProperties<'static>,
+            integer_dot_product_accumulating_saturating64_bit_unsigned_accelerated,
+            "integerDotProductAccumulatingSaturating64BitUnsignedAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderIntegerDotProductProperties<'static>,
+            integer_dot_product_accumulating_saturating64_bit_signed_accelerated,
+            "integerDotProductAccumulatingSaturating64BitSignedAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderIntegerDotProductProperties<'static>,
+            integer_dot_product_accumulating_saturating64_bit_mixed_signedness_accelerated,
+            "integerDotProductAccumulatingSaturating64BitMixedSignednessAccelerated",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_SHADER_MODULE_IDENTIFIER_PROPERTIES_EXT: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDeviceShaderModuleIdentifierPropertiesEXT",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_SHADER_MODULE_IDENTIFIER_PROPERTIES_EXT,
+        ),
+        category: Some(StructCategory::Properties),
+        size: size_of::<vk::PhysicalDeviceShaderModuleIdentifierPropertiesEXT<'static>>(),
+        providers: &["VK_EXT_shader_module_identifier"],
+        members: &[member!(
+            vk::PhysicalDeviceShaderModuleIdentifierPropertiesEXT<'static>,
+            shader_module_identifier_algorithm_uuid,
+            "shaderModuleIdentifierAlgorithmUUID",
+            T::Array(&T::Uint8, 16)
+        )],
+    };
+
+pub(super) static PHYSICAL_DEVICE_SHADER_OBJECT_PROPERTIES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceShaderObjectPropertiesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_SHADER_OBJECT_PROPERTIES_EXT),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceShaderObjectPropertiesEXT<'static>>(),
+    providers: &["VK_EXT_shader_object"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceShaderObjectPropertiesEXT<'static>,
+            shader_binary_uuid,
+            "shaderBinaryUUID",
+            T::Array(&T::Uint8, 16)
+        ),
+        member!(
+            vk::PhysicalDeviceShaderObjectPropertiesEXT<'static>,
+            shader_binary_version,
+            "shaderBinaryVersion",
+            T::Uint32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_SHADER_SM_BUILTINS_PROPERTIES_NV: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceShaderSMBuiltinsPropertiesNV",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_SHADER_SM_BUILTINS_PROPERTIES_NV),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceShaderSMBuiltinsPropertiesNV<'static>>(),
+    providers: &["VK_NV_shader_sm_builtins"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceShaderSMBuiltinsPropertiesNV<'static>,
+            shader_sm_count,
+            "shaderSMCount",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderSMBuiltinsPropertiesNV<'static>,
+            shader_warps_per_sm,
+            "shaderWarpsPerSM",
+            T::Uint32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_SHADER_TILE_IMAGE_PROPERTIES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceShaderTileImagePropertiesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_SHADER_TILE_IMAGE_PROPERTIES_EXT),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceShaderTileImagePropertiesEXT<'static>>(),
+    providers: &["VK_EXT_shader_tile_image"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceShaderTileImagePropertiesEXT<'static>,
+            shader_tile_image_coherent_read_accelerated,
+            "shaderTileImageCoherentReadAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderTileImagePropertiesEXT<'static>,
+            shader_tile_image_read_sample_from_pixel_rate_invocation,
+            "shaderTileImageReadSampleFromPixelRateInvocation",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceShaderTileImagePropertiesEXT<'static>,
+            shader_tile_image_read_from_helper_invocation,
+            "shaderTileImageReadFromHelperInvocation",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_SHADING_RATE_IMAGE_PROPERTIES_NV: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceShadingRateImagePropertiesNV",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_SHADING_RATE_IMAGE_PROPERTIES_NV),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceShadingRateImagePropertiesNV<'static>>(),
+    providers: &["VK_NV_shading_rate_image"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceShadingRateImagePropertiesNV<'static>,
+            shading_rate_texel_size,
+            "shadingRateTexelSize",
+            T::Struct(&EXTENT2_D)
+        ),
+        member!(
+            vk::PhysicalDeviceShadingRateImagePropertiesNV<'static>,
+            shading_rate_palette_size,
+            "shadingRatePaletteSize",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceShadingRateImagePropertiesNV<'static>,
+            shading_rate_max_coarse_samples,
+            "shadingRateMaxCoarseSamples",
+            T::Uint32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_SUBGROUP_PROPERTIES: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceSubgroupProperties",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_SUBGROUP_PROPERTIES),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceSubgroupProperties<'static>>(),
+    providers: &["VK_VERSION_1_1"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceSubgroupProperties<'static>,
+            subgroup_size,
+            "subgroupSize",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceSubgroupProperties<'static>,
+            supported_stages,
+            "supportedStages",
+            T::Flags(&SHADER_STAGE_FLAGS)
+        ),
+        member!(
+            vk::PhysicalDeviceSubgroupProperties<'static>,
+            supported_operations,
+            "supportedOperations",
+            T::Flags(&SUBGROUP_FEATURE_FLAGS)
+        ),
+        member!(
+            vk::PhysicalDeviceSubgroupProperties<'static>,
+            quad_operations_in_all_stages,
+            "quadOperationsInAllStages",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_SUBGROUP_SIZE_CONTROL_PROPERTIES: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceSubgroupSizeControlProperties",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_SUBGROUP_SIZE_CONTROL_PROPERTIES),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceSubgroupSizeControlProperties<'static>>(),
+    providers: &["VK_VERSION_1_3", "VK_EXT_subgroup_size_control"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceSubgroupSizeControlProperties<'static>,
+            min_subgroup_size,
+            "minSubgroupSize",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceSubgroupSizeControlProperties<'static>,
+            max_subgroup_size,
+            "maxSubgroupSize",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceSubgroupSizeControlProperties<'static>,
+            max_compute_workgroup_subgroups,
+            "maxComputeWorkgroupSubgroups",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceSubgroupSizeControlProperties<'static>,
+            required_subgroup_size_stages,
+            "requiredSubgroupSizeStages",
+            T::Flags(&SHADER_STAGE_FLAGS)
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_SUBPASS_SHADING_PROPERTIES_HUAWEI: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceSubpassShadingPropertiesHUAWEI",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_SUBPASS_SHADING_PROPERTIES_HUAWEI),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceSubpassShadingPropertiesHUAWEI<'static>>(),
+    providers: &["VK_HUAWEI_subpass_shading"],
+    members: &[member!(
+        vk::PhysicalDeviceSubpassShadingPropertiesHUAWEI<'static>,
+        max_subpass_shading_workgroup_size_aspect_ratio,
+        "maxSubpassShadingWorkgroupSizeAspectRatio",
+        T::Uint32
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_TEXEL_BUFFER_ALIGNMENT_PROPERTIES: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceTexelBufferAlignmentProperties",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_TEXEL_BUFFER_ALIGNMENT_PROPERTIES),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceTexelBufferAlignmentProperties<'static>>(),
+    providers: &["VK_VERSION_1_3", "VK_EXT_texel_buffer_alignment"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceTexelBufferAlignmentProperties<'static>,
+            storage_texel_buffer_offset_alignment_bytes,
+            "storageTexelBufferOffsetAlignmentBytes",
+            T::Uint64
+        ),
+        member!(
+            vk::PhysicalDeviceTexelBufferAlignmentProperties<'static>,
+            storage_texel_buffer_offset_single_texel_alignment,
+            "storageTexelBufferOffsetSingleTexelAlignment",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceTexelBufferAlignmentProperties<'static>,
+            uniform_texel_buffer_offset_alignment_bytes,
+            "uniformTexelBufferOffsetAlignmentBytes",
+            T::Uint64
+        ),
+        member!(
+            vk::PhysicalDeviceTexelBufferAlignmentProperties<'static>,
+            uniform_texel_buffer_offset_single_texel_alignment,
+            "uniformTexelBufferOffsetSingleTexelAlignment",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_PROPERTIES: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceTimelineSemaphoreProperties",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_PROPERTIES),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceTimelineSemaphoreProperties<'static>>(),
+    providers: &["VK_VERSION_1_2", "VK_KHR_timeline_semaphore"],
+    members: &[member!(
+        vk::PhysicalDeviceTimelineSemaphoreProperties<'static>,
+        max_timeline_semaphore_value_difference,
+        "maxTimelineSemaphoreValueDifference",
+        T::Uint64
+    )],
+};
+
+pub(super) static PHYSICAL_DEVICE_TRANSFORM_FEEDBACK_PROPERTIES_EXT: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceTransformFeedbackPropertiesEXT",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_TRANSFORM_FEEDBACK_PROPERTIES_EXT),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceTransformFeedbackPropertiesEXT<'static>>(),
+    providers: &["VK_EXT_transform_feedback"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceTransformFeedbackPropertiesEXT<'static>,
+            max_transform_feedback_streams,
+            "maxTransformFeedbackStreams",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceTransformFeedbackPropertiesEXT<'static>,
+            max_transform_feedback_buffers,
+            "maxTransformFeedbackBuffers",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceTransformFeedbackPropertiesEXT<'static>,
+            max_transform_feedback_buffer_size,
+            "maxTransformFeedbackBufferSize",
+            T::Uint64
+        ),
+        member!(
+            vk::PhysicalDeviceTransformFeedbackPropertiesEXT<'static>,
+            max_transform_feedback_stream_data_size,
+            "maxTransformFeedbackStreamDataSize",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceTransformFeedbackPropertiesEXT<'static>,
+            max_transform_feedback_buffer_data_size,
+            "maxTransformFeedbackBufferDataSize",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceTransformFeedbackPropertiesEXT<'static>,
+            max_transform_feedback_buffer_data_stride,
+            "maxTransformFeedbackBufferDataStride",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceTransformFeedbackPropertiesEXT<'static>,
+            transform_feedback_queries,
+            "transformFeedbackQueries",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceTransformFeedbackPropertiesEXT<'static>,
+            transform_feedback_streams_lines_triangles,
+            "transformFeedbackStreamsLinesTriangles",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceTransformFeedbackPropertiesEXT<'static>,
+            transform_feedback_rasterization_stream_select,
+            "transformFeedbackRasterizationStreamSelect",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceTransformFeedbackPropertiesEXT<'static>,
+            transform_feedback_draw,
+            "transformFeedbackDraw",
+            T::Bool32
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_PROPERTIES_EXT: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDeviceVertexAttributeDivisorPropertiesEXT",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_PROPERTIES_EXT,
+        ),
+        category: Some(StructCategory::Properties),
+        size: size_of::<vk::PhysicalDeviceVertexAttributeDivisorPropertiesEXT<'static>>(),
+        providers: &["VK_EXT_vertex_attribute_divisor"],
+        members: &[member!(
+            vk::PhysicalDeviceVertexAttributeDivisorPropertiesEXT<'static>,
+            max_vertex_attrib_divisor,
+            "maxVertexAttribDivisor",
+            T::Uint32
+        )],
+    };
+
+pub(super) static PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_PROPERTIES_KHR: StructInfo =
+    StructInfo {
+        name: "VkPhysicalDeviceVertexAttributeDivisorPropertiesKHR",
+        structure_type: Some(
+            vk::StructureType::PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_PROPERTIES_KHR,
+        ),
+        category: Some(StructCategory::Properties),
+        size: size_of::<vk::PhysicalDeviceVertexAttributeDivisorPropertiesKHR<'static>>(),
+        providers: &["VK_KHR_vertex_attribute_divisor"],
+        members: &[
+            member!(
+                vk::PhysicalDeviceVertexAttributeDivisorPropertiesKHR<'static>,
+                max_vertex_attrib_divisor,
+                "maxVertexAttribDivisor",
+                T::Uint32
+            ),
+            member!(
+                vk::PhysicalDeviceVertexAttributeDivisorPropertiesKHR<'static>,
+                supports_non_zero_first_instance,
+                "supportsNonZeroFirstInstance",
+                T::Bool32
+            ),
+        ],
+    };
+
+pub(super) static PHYSICAL_DEVICE_VULKAN11_PROPERTIES: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceVulkan11Properties",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_VULKAN_1_1_PROPERTIES),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceVulkan11Properties<'static>>(),
+    providers: &["VK_VERSION_1_2"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceVulkan11Properties<'static>,
+            device_uuid,
+            "deviceUUID",
+            T::Array(&T::Uint8, 16)
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan11Properties<'static>,
+            driver_uuid,
+            "driverUUID",
+            T::Array(&T::Uint8, 16)
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan11Properties<'static>,
+            device_luid,
+            "deviceLUID",
+            T::Array(&T::Uint8, 8)
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan11Properties<'static>,
+            device_node_mask,
+            "deviceNodeMask",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan11Properties<'static>,
+            device_luid_valid,
+            "deviceLUIDValid",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan11Properties<'static>,
+            subgroup_size,
+            "subgroupSize",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan11Properties<'static>,
+            subgroup_supported_stages,
+            "subgroupSupportedStages",
+            T::Flags(&SHADER_STAGE_FLAGS)
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan11Properties<'static>,
+            subgroup_supported_operations,
+            "subgroupSupportedOperations",
+            T::Flags(&SUBGROUP_FEATURE_FLAGS)
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan11Properties<'static>,
+            subgroup_quad_operations_in_all_stages,
+            "subgroupQuadOperationsInAllStages",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan11Properties<'static>,
+            point_clipping_behavior,
+            "pointClippingBehavior",
+            T::Enum(&POINT_CLIPPING_BEHAVIOR)
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan11Properties<'static>,
+            max_multiview_view_count,
+            "maxMultiviewViewCount",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan11Properties<'static>,
+            max_multiview_instance_index,
+            "maxMultiviewInstanceIndex",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan11Properties<'static>,
+            protected_no_fault,
+            "protectedNoFault",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan11Properties<'static>,
+            max_per_set_descriptors,
+            "maxPerSetDescriptors",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan11Properties<'static>,
+            max_memory_allocation_size,
+            "maxMemoryAllocationSize",
+            T::Uint64
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_VULKAN12_PROPERTIES: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceVulkan12Properties",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_VULKAN_1_2_PROPERTIES),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceVulkan12Properties<'static>>(),
+    providers: &["VK_VERSION_1_2"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceVulkan12Properties<'static>,
+            driver_id,
+            "driverID",
+            T::Enum(&DRIVER_ID)
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Properties<'static>,
+            driver_name,
+            "driverName",
+            T::String(256)
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Properties<'static>,
+            driver_info,
+            "driverInfo",
+            T::String(256)
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Properties<'static>,
+            conformance_version,
+            "conformanceVersion",
+            T::Struct(&CONFORMANCE_VERSION)
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Properties<'static>,
+            denorm_behavior_independence,
+            "denormBehaviorIndependence",
+            T::Enum(&SHADER_FLOAT_CONTROLS_INDEPENDENCE)
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Properties<'static>,
+            rounding_mode_independence,
+            "roundingModeIndependence",
+            T::Enum(&SHADER_FLOAT_CONTROLS_INDEPENDENCE)
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Properties<'static>,
+            shader_signed_zero_inf_nan_preserve_float16,
+            "shaderSignedZeroInfNanPreserveFloat16",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Properties<'static>,
+            shader_signed_zero_inf_nan_preserve_float32,
+            "shaderSignedZeroInfNanPreserveFloat32",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Properties<'static>,
+            shader_signed_zero_inf_nan_preserve_float64,
+            "shaderSignedZeroInfNanPreserveFloat64",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Properties<'static>,
+            shader_denorm_preserve_float16,
+            "shaderDenormPreserveFloat16",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Properties<'static>,
+            shader_denorm_preserve_float32,
+            "shaderDenormPreserveFloat32",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Properties<'static>,
+            shader_denorm_preserve_float64,
+            "shaderDenormPreserveFloat64",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Properties<'static>,
+            shader_denorm_flush_to_zero_float16,
+            "shaderDenormFlushToZeroFloat16",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Properties<'static>,
+            shader_denorm_flush_to_zero_float32,
+            "shaderDenormFlushToZeroFloat32",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Properties<'static>,
+            shader_denorm_flush_to_zero_float64,
+            "shaderDenormFlushToZeroFloat64",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Properties<'static>,
+            shader_rounding_mode_rte_float16,
+            "shaderRoundingModeRTEFloat16",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Properties<'static>,
+            shader_rounding_mode_rte_float32,
+            "shaderRoundingModeRTEFloat32",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Properties<'static>,
+            shader_rounding_mode_rte_float64,
+            "shaderRoundingModeRTEFloat64",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Properties<'static>,
+            shader_rounding_mode_rtz_float16,
+            "shaderRoundingModeRTZFloat16",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Properties<'static>,
+            shader_rounding_mode_rtz_float32,
+            "shaderRoundingModeRTZFloat32",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Properties<'static>,
+            shader_rounding_mode_rtz_float64,
+            "shaderRoundingModeRTZFloat64",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Properties<'static>,
+            max_update_after_bind_descriptors_in_all_pools,
+            "maxUpdateAfterBindDescriptorsInAllPools",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Properties<'static>,
+            shader_uniform_buffer_array_non_uniform_indexing_native,
+            "shaderUniformBufferArrayNonUniformIndexingNative",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Properties<'static>,
+            shader_sampled_image_array_non_uniform_indexing_native,
+            "shaderSampledImageArrayNonUniformIndexingNative",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Properties<'static>,
+            shader_storage_buffer_array_non_uniform_indexing_native,
+            "shaderStorageBufferArrayNonUniformIndexingNative",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Properties<'static>,
+            shader_storage_image_array_non_uniform_indexing_native,
+            "shaderStorageImageArrayNonUniformIndexingNative",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Properties<'static>,
+            shader_input_attachment_array_non_uniform_indexing_native,
+            "shaderInputAttachmentArrayNonUniformIndexingNative",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Properties<'static>,
+            robust_buffer_access_update_after_bind,
+            "robustBufferAccessUpdateAfterBind",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Properties<'static>,
+            quad_divergent_implicit_lod,
+            "quadDivergentImplicitLod",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Properties<'static>,
+            max_per_stage_descriptor_update_after_bind_samplers,
+            "maxPerStageDescriptorUpdateAfterBindSamplers",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Properties<'static>,
+            max_per_stage_descriptor_update_after_bind_uniform_buffers,
+            "maxPerStageDescriptorUpdateAfterBindUniformBuffers",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Properties<'static>,
+            max_per_stage_descriptor_update_after_bind_storage_buffers,
+            "maxPerStageDescriptorUpdateAfterBindStorageBuffers",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Properties<'static>,
+            max_per_stage_descriptor_update_after_bind_sampled_images,
+            "maxPerStageDescriptorUpdateAfterBindSampledImages",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Properties<'static>,
+            max_per_stage_descriptor_update_after_bind_storage_images,
+            "maxPerStageDescriptorUpdateAfterBindStorageImages",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Properties<'static>,
+            max_per_stage_descriptor_update_after_bind_input_attachments,
+            "maxPerStageDescriptorUpdateAfterBindInputAttachments",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Properties<'static>,
+            max_per_stage_update_after_bind_resources,
+            "maxPerStageUpdateAfterBindResources",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Properties<'static>,
+            max_descriptor_set_update_after_bind_samplers,
+            "maxDescriptorSetUpdateAfterBindSamplers",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Properties<'static>,
+            max_descriptor_set_update_after_bind_uniform_buffers,
+            "maxDescriptorSetUpdateAfterBindUniformBuffers",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Properties<'static>,
+            max_descriptor_set_update_after_bind_uniform_buffers_dynamic,
+            "maxDescriptorSetUpdateAfterBindUniformBuffersDynamic",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Properties<'static>,
+            max_descriptor_set_update_after_bind_storage_buffers,
+            "maxDescriptorSetUpdateAfterBindStorageBuffers",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Properties<'static>,
+            max_descriptor_set_update_after_bind_storage_buffers_dynamic,
+            "maxDescriptorSetUpdateAfterBindStorageBuffersDynamic",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Properties<'static>,
+            max_descriptor_set_update_after_bind_sampled_images,
+            "maxDescriptorSetUpdateAfterBindSampledImages",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Properties<'static>,
+            max_descriptor_set_update_after_bind_storage_images,
+            "maxDescriptorSetUpdateAfterBindStorageImages",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Properties<'static>,
+            max_descriptor_set_update_after_bind_input_attachments,
+            "maxDescriptorSetUpdateAfterBindInputAttachments",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Properties<'static>,
+            supported_depth_resolve_modes,
+            "supportedDepthResolveModes",
+            T::Flags(&RESOLVE_MODE_FLAGS)
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Properties<'static>,
+            supported_stencil_resolve_modes,
+            "supportedStencilResolveModes",
+            T::Flags(&RESOLVE_MODE_FLAGS)
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Properties<'static>,
+            independent_resolve_none,
+            "independentResolveNone",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Properties<'static>,
+            independent_resolve,
+            "independentResolve",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Properties<'static>,
+            filter_minmax_single_component_formats,
+            "filterMinmaxSingleComponentFormats",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Properties<'static>,
+            filter_minmax_image_component_mapping,
+            "filterMinmaxImageComponentMapping",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Properties<'static>,
+            max_timeline_semaphore_value_difference,
+            "maxTimelineSemaphoreValueDifference",
+            T::Uint64
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan12Properties<'static>,
+            framebuffer_integer_color_sample_counts,
+            "framebufferIntegerColorSampleCounts",
+            T::Flags(&SAMPLE_COUNT_FLAGS)
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_VULKAN13_PROPERTIES: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceVulkan13Properties",
+    structure_type: Some(vk::StructureType::PHYSICAL_DEVICE_VULKAN_1_3_PROPERTIES),
+    category: Some(StructCategory::Properties),
+    size: size_of::<vk::PhysicalDeviceVulkan13Properties<'static>>(),
+    providers: &["VK_VERSION_1_3"],
+    members: &[
+        member!(
+            vk::PhysicalDeviceVulkan13Properties<'static>,
+            min_subgroup_size,
+            "minSubgroupSize",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Properties<'static>,
+            max_subgroup_size,
+            "maxSubgroupSize",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Properties<'static>,
+            max_compute_workgroup_subgroups,
+            "maxComputeWorkgroupSubgroups",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Properties<'static>,
+            required_subgroup_size_stages,
+            "requiredSubgroupSizeStages",
+            T::Flags(&SHADER_STAGE_FLAGS)
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Properties<'static>,
+            max_inline_uniform_block_size,
+            "maxInlineUniformBlockSize",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Properties<'static>,
+            max_per_stage_descriptor_inline_uniform_blocks,
+            "maxPerStageDescriptorInlineUniformBlocks",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Properties<'static>,
+            max_per_stage_descriptor_update_after_bind_inline_uniform_blocks,
+            "maxPerStageDescriptorUpdateAfterBindInlineUniformBlocks",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Properties<'static>,
+            max_descriptor_set_inline_uniform_blocks,
+            "maxDescriptorSetInlineUniformBlocks",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Properties<'static>,
+            max_descriptor_set_update_after_bind_inline_uniform_blocks,
+            "maxDescriptorSetUpdateAfterBindInlineUniformBlocks",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Properties<'static>,
+            max_inline_uniform_total_size,
+            "maxInlineUniformTotalSize",
+            T::Uint32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Properties<'static>,
+            integer_dot_product8_bit_unsigned_accelerated,
+            "integerDotProduct8BitUnsignedAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Properties<'static>,
+            integer_dot_product8_bit_signed_accelerated,
+            "integerDotProduct8BitSignedAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Properties<'static>,
+            integer_dot_product8_bit_mixed_signedness_accelerated,
+            "integerDotProduct8BitMixedSignednessAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Properties<'static>,
+            integer_dot_product4x8_bit_packed_unsigned_accelerated,
+            "integerDotProduct4x8BitPackedUnsignedAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Properties<'static>,
+            integer_dot_product4x8_bit_packed_signed_accelerated,
+            "integerDotProduct4x8BitPackedSignedAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Properties<'static>,
+            integer_dot_product4x8_bit_packed_mixed_signedness_accelerated,
+            "integerDotProduct4x8BitPackedMixedSignednessAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Properties<'static>,
+            integer_dot_product16_bit_unsigned_accelerated,
+            "integerDotProduct16BitUnsignedAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Properties<'static>,
+            integer_dot_product16_bit_signed_accelerated,
+            "integerDotProduct16BitSignedAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Properties<'static>,
+            integer_dot_product16_bit_mixed_signedness_accelerated,
+            "integerDotProduct16BitMixedSignednessAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Properties<'static>,
+            integer_dot_product32_bit_unsigned_accelerated,
+            "integerDotProduct32BitUnsignedAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Properties<'static>,
+            integer_dot_product32_bit_signed_accelerated,
+            "integerDotProduct32BitSignedAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Properties<'static>,
+            integer_dot_product32_bit_mixed_signedness_accelerated,
+            "integerDotProduct32BitMixedSignednessAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Properties<'static>,
+            integer_dot_product64_bit_unsigned_accelerated,
+            "integerDotProduct64BitUnsignedAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Properties<'static>,
+            integer_dot_product64_bit_signed_accelerated,
+            "integerDotProduct64BitSignedAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Properties<'static>,
+            integer_dot_product64_bit_mixed_signedness_accelerated,
+            "integerDotProduct64BitMixedSignednessAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Properties<'static>,
+            integer_dot_product_accumulating_saturating8_bit_unsigned_accelerated,
+            "integerDotProductAccumulatingSaturating8BitUnsignedAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Properties<'static>,
+            integer_dot_product_accumulating_saturating8_bit_signed_accelerated,
+            "integerDotProductAccumulatingSaturating8BitSignedAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Properties<'static>,
+            integer_dot_product_accumulating_saturating8_bit_mixed_signedness_accelerated,
+            "integerDotProductAccumulatingSaturating8BitMixedSignednessAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Properties<'static>,
+            integer_dot_product_accumulating_saturating4x8_bit_packed_unsigned_accelerated,
+            "integerDotProductAccumulatingSaturating4x8BitPackedUnsignedAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Properties<'static>,
+            integer_dot_product_accumulating_saturating4x8_bit_packed_signed_accelerated,
+            "integerDotProductAccumulatingSaturating4x8BitPackedSignedAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Properties<'static>,
+            integer_dot_product_accumulating_saturating16_bit_unsigned_accelerated,
+            "integerDotProductAccumulatingSaturating16BitUnsignedAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Properties<'static>,
+            integer_dot_product_accumulating_saturating16_bit_signed_accelerated,
+            "integerDotProductAccumulatingSaturating16BitSignedAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Properties<'static>,
+            integer_dot_product_accumulating_saturating16_bit_mixed_signedness_accelerated,
+            "integerDotProductAccumulatingSaturating16BitMixedSignednessAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Properties<'static>,
+            integer_dot_product_accumulating_saturating32_bit_unsigned_accelerated,
+            "integerDotProductAccumulatingSaturating32BitUnsignedAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Properties<'static>,
+            integer_dot_product_accumulating_saturating32_bit_signed_accelerated,
+            "integerDotProductAccumulatingSaturating32BitSignedAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Properties<'static>,
+            integer_dot_product_accumulating_saturating32_bit_mixed_signedness_accelerated,
+            "integerDotProductAccumulatingSaturating32BitMixedSignednessAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Properties<'static>,
+            integer_dot_product_accumulating_saturating64_bit_unsigned_accelerated,
+            "integerDotProductAccumulatingSaturating64BitUnsignedAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Properties<'static>,
+            integer_dot_product_accumulating_saturating64_bit_signed_accelerated,
+            "integerDotProductAccumulatingSaturating64BitSignedAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Properties<'static>,
+            integer_dot_product_accumulating_saturating64_bit_mixed_signedness_accelerated,
+            "integerDotProductAccumulatingSaturating64BitMixedSignednessAccelerated",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Properties<'static>,
+            storage_texel_buffer_offset_alignment_bytes,
+            "storageTexelBufferOffsetAlignmentBytes",
+            T::Uint64
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Properties<'static>,
+            storage_texel_buffer_offset_single_texel_alignment,
+            "storageTexelBufferOffsetSingleTexelAlignment",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Properties<'static>,
+            uniform_texel_buffer_offset_alignment_bytes,
+            "uniformTexelBufferOffsetAlignmentBytes",
+            T::Uint64
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Properties<'static>,
+            uniform_texel_buffer_offset_single_texel_alignment,
+            "uniformTexelBufferOffsetSingleTexelAlignment",
+            T::Bool32
+        ),
+        member!(
+            vk::PhysicalDeviceVulkan13Properties<'static>,
+            max_buffer_size,
+            "maxBufferSize",
+            T::Uint64
+        ),
+    ],
+};
+
+pub(super) static FORMAT_PROPERTIES: StructInfo = StructInfo {
+    name: "VkFormatProperties",
+    structure_type: None,
+    category: Some(StructCategory::FormatProperties),
+    size: size_of::<vk::FormatProperties>(),
+    providers: &[],
+    members: &[
+        member!(
+            vk::FormatProperties,
+            linear_tiling_features,
+            "linearTilingFeatures",
+            T::Flags(&FORMAT_FEATURE_FLAGS)
+        ),
+        member!(
+            vk::FormatProperties,
+            optimal_tiling_features,
+            "optimalTilingFeatures",
+            T::Flags(&FORMAT_FEATURE_FLAGS)
+        ),
+        member!(
+            vk::FormatProperties,
+            buffer_features,
+            "bufferFeatures",
+            T::Flags(&FORMAT_FEATURE_FLAGS)
+        ),
+    ],
+};
+
+pub(super) static DRM_FORMAT_MODIFIER_PROPERTIES_LIST2_EXT: StructInfo = StructInfo {
+    name: "VkDrmFormatModifierPropertiesList2EXT",
+    structure_type: Some(vk::StructureType::DRM_FORMAT_MODIFIER_PROPERTIES_LIST_2_EXT),
+    category: Some(StructCategory::FormatProperties),
+    size: size_of::<vk::DrmFormatModifierPropertiesList2EXT<'static>>(),
+    providers: &["VK_EXT_image_drm_format_modifier"],
+    members: &[member!(
+        vk::DrmFormatModifierPropertiesList2EXT<'static>,
+        drm_format_modifier_count,
+        "drmFormatModifierCount",
+        T::Uint32
+    )],
+};
+
+pub(super) static DRM_FORMAT_MODIFIER_PROPERTIES_LIST_EXT: StructInfo = StructInfo {
+    name: "VkDrmFormatModifierPropertiesListEXT",
+    structure_type: Some(vk::StructureType::DRM_FORMAT_MODIFIER_PROPERTIES_LIST_EXT),
+    category: Some(StructCategory::FormatProperties),
+    size: size_of::<vk::DrmFormatModifierPropertiesListEXT<'static>>(),
+    providers: &["VK_EXT_image_drm_format_modifier"],
+    members: &[member!(
+        vk::DrmFormatModifierPropertiesListEXT<'static>,
+        drm_format_modifier_count,
+        "drmFormatModifierCount",
+        T::Uint32
+    )],
+};
+
+pub(super) static FORMAT_PROPERTIES3: StructInfo = StructInfo {
+    name: "VkFormatProperties3",
+    structure_type: Some(vk::StructureType::FORMAT_PROPERTIES_3),
+    category: Some(StructCategory::FormatProperties),
+    size: size_of::<vk::FormatProperties3<'static>>(),
+    providers: &["VK_VERSION_1_3", "VK_KHR_format_feature_flags2"],
+    members: &[
+        member!(
+            vk::FormatProperties3<'static>,
+            linear_tiling_features,
+            "linearTilingFeatures",
+            T::Flags(&FORMAT_FEATURE_FLAGS2)
+        ),
+        member!(
+            vk::FormatProperties3<'static>,
+            optimal_tiling_features,
+            "optimalTilingFeatures",
+            T::Flags(&FORMAT_FEATURE_FLAGS2)
+        ),
+        member!(
+            vk::FormatProperties3<'static>,
+            buffer_features,
+            "bufferFeatures",
+            T::Flags(&FORMAT_FEATURE_FLAGS2)
+        ),
+    ],
+};
+
+pub(super) static SUBPASS_RESOLVE_PERFORMANCE_QUERY_EXT: StructInfo = StructInfo {
+    name: "VkSubpassResolvePerformanceQueryEXT",
+    structure_type: Some(vk::StructureType::SUBPASS_RESOLVE_PERFORMANCE_QUERY_EXT),
+    category: Some(StructCategory::FormatProperties),
+    size: size_of::<vk::SubpassResolvePerformanceQueryEXT<'static>>(),
+    providers: &["VK_EXT_multisampled_render_to_single_sampled"],
+    members: &[member!(
+        vk::SubpassResolvePerformanceQueryEXT<'static>,
+        optimal,
+        "optimal",
+        T::Bool32
+    )],
+};
+
+pub(super) static QUEUE_FAMILY_PROPERTIES: StructInfo = StructInfo {
+    name: "VkQueueFamilyProperties",
+    structure_type: None,
+    category: Some(StructCategory::QueueFamilyProperties),
+    size: size_of::<vk::QueueFamilyProperties>(),
+    providers: &[],
+    members: &[
+        member!(
+            vk::QueueFamilyProperties,
+            queue_flags,
+            "queueFlags",
+            T::Flags(&QUEUE_FLAGS)
+        ),
+        member!(
+            vk::QueueFamilyProperties,
+            queue_count,
+            "queueCount",
+            T::Uint32
+        ),
+        member!(
+            vk::QueueFamilyProperties,
+            timestamp_valid_bits,
+            "timestampValidBits",
+            T::Uint32
+        ),
+        member!(
+            vk::QueueFamilyProperties,
+            min_image_transfer_granularity,
+            "minImageTransferGranularity",
+            T::Struct(&EXTENT3_D)
+        ),
+    ],
+};
+
+pub(super) static QUEUE_FAMILY_CHECKPOINT_PROPERTIES2_NV: StructInfo = StructInfo {
+    name: "VkQueueFamilyCheckpointProperties2NV",
+    structure_type: Some(vk::StructureType::QUEUE_FAMILY_CHECKPOINT_PROPERTIES_2_NV),
+    category: Some(StructCategory::QueueFamilyProperties),
+    size: size_of::<vk::QueueFamilyCheckpointProperties2NV<'static>>(),
+    providers: &["VK_KHR_synchronization2"],
+    members: &[member!(
+        vk::QueueFamilyCheckpointProperties2NV<'static>,
+        checkpoint_execution_stage_mask,
+        "checkpointExecutionStageMask",
+        T::Flags(&PIPELINE_STAGE_FLAGS2)
+    )],
+};
+
+pub(super) static QUEUE_FAMILY_CHECKPOINT_PROPERTIES_NV: StructInfo = StructInfo {
+    name: "VkQueueFamilyCheckpointPropertiesNV",
+    structure_type: Some(vk::StructureType::QUEUE_FAMILY_CHECKPOINT_PROPERTIES_NV),
+    category: Some(StructCategory::QueueFamilyProperties),
+    size: size_of::<vk::QueueFamilyCheckpointPropertiesNV<'static>>(),
+    providers: &["VK_NV_device_diagnostic_checkpoints"],
+    members: &[member!(
+        vk::QueueFamilyCheckpointPropertiesNV<'static>,
+        checkpoint_execution_stage_mask,
+        "checkpointExecutionStageMask",
+        T::Flags(&PIPELINE_STAGE_FLAGS)
+    )],
+};
+
+pub(super) static QUEUE_FAMILY_GLOBAL_PRIORITY_PROPERTIES_KHR: StructInfo = StructInfo {
+    name: "VkQueueFamilyGlobalPriorityPropertiesKHR",
+    structure_type: Some(vk::StructureType::QUEUE_FAMILY_GLOBAL_PRIORITY_PROPERTIES_KHR),
+    category: Some(StructCategory::QueueFamilyProperties),
+    size: size_of::<vk::QueueFamilyGlobalPriorityPropertiesKHR<'static>>(),
+    providers: &["VK_KHR_global_priority", "VK_EXT_global_priority_query"],
+    members: &[
+        member!(
+            vk::QueueFamilyGlobalPriorityPropertiesKHR<'static>,
+            priority_count,
+            "priorityCount",
+            T::Uint32
+        ),
+        member!(
+            vk::QueueFamilyGlobalPriorityPropertiesKHR<'static>,
+            priorities,
+            "priorities",
+            T::Array(&T::Enum(&QUEUE_GLOBAL_PRIORITY_KHR), 16)
+        ),
+    ],
+};
+
+pub(super) static QUEUE_FAMILY_QUERY_RESULT_STATUS_PROPERTIES_KHR: StructInfo = StructInfo {
+    name: "VkQueueFamilyQueryResultStatusPropertiesKHR",
+    structure_type: Some(vk::StructureType::QUEUE_FAMILY_QUERY_RESULT_STATUS_PROPERTIES_KHR),
+    category: Some(StructCategory::QueueFamilyProperties),
+    size: size_of::<vk::QueueFamilyQueryResultStatusPropertiesKHR<'static>>(),
+    providers: &["VK_KHR_video_queue"],
+    members: &[member!(
+        vk::QueueFamilyQueryResultStatusPropertiesKHR<'static>,
+        query_result_status_support,
+        "queryResultStatusSupport",
+        T::Bool32
+    )],
+};
+
+pub(super) static QUEUE_FAMILY_VIDEO_PROPERTIES_KHR: StructInfo = StructInfo {
+    name: "VkQueueFamilyVideoPropertiesKHR",
+    structure_type: Some(vk::StructureType::QUEUE_FAMILY_VIDEO_PROPERTIES_KHR),
+    category: Some(StructCategory::QueueFamilyProperties),
+    size: size_of::<vk::QueueFamilyVideoPropertiesKHR<'static>>(),
+    providers: &["VK_KHR_video_queue"],
+    members: &[member!(
+        vk::QueueFamilyVideoPropertiesKHR<'static>,
+        video_codec_operations,
+        "videoCodecOperations",
+        T::Flags(&VIDEO_CODEC_OPERATION_FLAGS_KHR)
+    )],
+};
+
+pub(super) static BUFFER_USAGE_FLAGS: FlagsInfo = FlagsInfo {
+    name: "VkBufferUsageFlags",
+    size: size_of::<vk::BufferUsageFlags>(),
+    bits: &[
+        (
+            "VK_BUFFER_USAGE_TRANSFER_SRC_BIT",
+            vk::BufferUsageFlags::TRANSFER_SRC.as_raw() as u64,
+        ),
+        (
+            "VK_BUFFER_USAGE_TRANSFER_DST_BIT",
+            vk::BufferUsageFlags::TRANSFER_DST.as_raw() as u64,
+        ),
+        (
+            "VK_BUFFER_USAGE_UNIFORM_TEXEL_BUFFER_BIT",
+            vk::BufferUsageFlags::UNIFORM_TEXEL_BUFFER.as_raw() as u64,
+        ),
+        (
+            "VK_BUFFER_USAGE_STORAGE_TEXEL_BUFFER_BIT",
+            vk::BufferUsageFlags::STORAGE_TEXEL_BUFFER.as_raw() as u64,
+        ),
+        (
+            "VK_BUFFER_USAGE_UNIFORM_BUFFER_BIT",
+            vk::BufferUsageFlags::UNIFORM_BUFFER.as_raw() as u64,
+        ),
+        (
+            "VK_BUFFER_USAGE_STORAGE_BUFFER_BIT",
+            vk::BufferUsageFlags::STORAGE_BUFFER.as_raw() as u64,
+        ),
+        (
+            "VK_BUFFER_USAGE_INDEX_BUFFER_BIT",
+            vk::BufferUsageFlags::INDEX_BUFFER.as_raw() as u64,
+        ),
+        (
+            "VK_BUFFER_USAGE_VERTEX_BUFFER_BIT",
+            vk::BufferUsageFlags::VERTEX_BUFFER.as_raw() as u64,
+        ),
+        (
+            "VK_BUFFER_USAGE_INDIRECT_BUFFER_BIT",
+            vk::BufferUsageFlags::INDIRECT_BUFFER.as_raw() as u64,
+        ),
+        (
+            "VK_BUFFER_USAGE_VIDEO_DECODE_SRC_BIT_KHR",
+            vk::BufferUsageFlags::VIDEO_DECODE_SRC_KHR.as_raw() as u64,
+        ),
+        (
+            "VK_BUFFER_USAGE_VIDEO_DECODE_DST_BIT_KHR",
+            vk::BufferUsageFlags::VIDEO_DECODE_DST_KHR.as_raw() as u64,
+        ),
+        (
+            "VK_BUFFER_USAGE_TRANSFORM_FEEDBACK_BUFFER_BIT_EXT",
+            vk::BufferUsageFlags::TRANSFORM_FEEDBACK_BUFFER_EXT.as_raw() as u64,
+        ),
+        (
+            "VK_BUFFER_USAGE_TRANSFORM_FEEDBACK_COUNTER_BUFFER_BIT_EXT",
+            vk::BufferUsageFlags::TRANSFORM_FEEDBACK_COUNTER_BUFFER_EXT.as_raw() as u64,
+        ),
+        (
+            "VK_BUFFER_USAGE_CONDITIONAL_RENDERING_BIT_EXT",
+            vk::BufferUsageFlags::CONDITIONAL_RENDERING_EXT.as_raw() as u64,
+        ),
+        (
+            "VK_BUFFER_USAGE_EXECUTION_GRAPH_SCRATCH_BIT_AMDX",
+            vk::BufferUsageFlags::EXECUTION_GRAPH_SCRATCH_AMDX.as_raw() as u64,
+        ),
+        (
+            "VK_BUFFER_USAGE_ACCELERATION_STRUCTURE_BUILD_INPUT_READ_ONLY_BIT_KHR",
+            vk::BufferUsageFlags::ACCELERATION_STRUCTURE_BUILD_INPUT_READ_ONLY_KHR.as_raw() as u64,
+        ),
+        (
+            "VK_BUFFER_USAGE_ACCELERATION_STRUCTURE_STORAGE_BIT_KHR",
+            vk::BufferUsageFlags::ACCELERATION_STRUCTURE_STORAGE_KHR.as_raw() as u64,
+        ),
+        (
+            "VK_BUFFER_USAGE_SHADER_BINDING_TABLE_BIT_KHR",
+            vk::BufferUsageFlags::SHADER_BINDING_TABLE_KHR.as_raw() as u64,
+        ),
+        (
+            "VK_BUFFER_USAGE_RAY_TRACING_BIT_NV",
+            vk::BufferUsageFlags::RAY_TRACING_NV.as_raw() as u64,
+        ),
+        (
+            "VK_BUFFER_USAGE_SHADER_DEVICE_ADDRESS_BIT_EXT",
+            vk::BufferUsageFlags::SHADER_DEVICE_ADDRESS_EXT.as_raw() as u64,
+        ),
+        (
+            "VK_BUFFER_USAGE_SHADER_DEVICE_ADDRESS_BIT_KHR",
+            vk::BufferUsageFlags::SHADER_DEVICE_ADDRESS_KHR.as_raw() as u64,
+        ),
+        (
+            "VK_BUFFER_USAGE_VIDEO_ENCODE_DST_BIT_KHR",
+            vk::BufferUsageFlags::VIDEO_ENCODE_DST_KHR.as_raw() as u64,
+        ),
+        (
+            "VK_BUFFER_USAGE_VIDEO_ENCODE_SRC_BIT_KHR",
+            vk::BufferUsageFlags::VIDEO_ENCODE_SRC_KHR.as_raw() as u64,
+        ),
+        (
+            "VK_BUFFER_USAGE_SAMPLER_DESCRIPTOR_BUFFER_BIT_EXT",
+            vk::BufferUsageFlags::SAMPLER_DESCRIPTOR_BUFFER_EXT.as_raw() as u64,
+        ),
+        (
+            "VK_BUFFER_USAGE_RESOURCE_DESCRIPTOR_BUFFER_BIT_EXT",
+            vk::BufferUsageFlags::RESOURCE_DESCRIPTOR_BUFFER_EXT.as_raw() as u64,
+        ),
+        (
+            "VK_BUFFER_USAGE_PUSH_DESCRIPTORS_DESCRIPTOR_BUFFER_BIT_EXT",
+            vk::BufferUsageFlags::PUSH_DESCRIPTORS_DESCRIPTOR_BUFFER_EXT.as_raw() as u64,
+        ),
+        (
+            "VK_BUFFER_USAGE_MICROMAP_BUILD_INPUT_READ_ONLY_BIT_EXT",
+            vk::BufferUsageFlags::MICROMAP_BUILD_INPUT_READ_ONLY_EXT.as_raw() as u64,
+        ),
+        (
+            "VK_BUFFER_USAGE_MICROMAP_STORAGE_BIT_EXT",
+            vk::BufferUsageFlags::MICROMAP_STORAGE_EXT.as_raw() as u64,
+        ),
+        (
+            "VK_BUFFER_USAGE_SHADER_DEVICE_ADDRESS_BIT",
+            vk::BufferUsageFlags::SHADER_DEVICE_ADDRESS.as_raw() as u64,
+        ),
+    ],
+};
+
+pub(super) static FORMAT_FEATURE_FLAGS: FlagsInfo = FlagsInfo {
+    name: "VkFormatFeatureFlags",
+    size: size_of::<vk::FormatFeatureFlags>(),
+    bits: &[
+        ("VK_FORMAT_FEATURE_SAMPLED_IMAGE_BIT", vk::FormatFeatureFlags::SAMPLED_IMAGE.as_raw() as u64),
+        ("VK_FORMAT_FEATURE_STORAGE_IMAGE_BIT", vk::FormatFeatureFlags::STORAGE_IMAGE.as_raw() as u64),
+        ("VK_FORMAT_FEATURE_STORAGE_IMAGE_ATOMIC_BIT", vk::FormatFeatureFlags::STORAGE_IMAGE_ATOMIC.as_raw() as u64),
+        ("VK_FORMAT_FEATURE_UNIFORM_TEXEL_BUFFER_BIT", vk::FormatFeatureFlags::UNIFORM_TEXEL_BUFFER.as_raw() as u64),
+        ("VK_FORMAT_FEATURE_STORAGE_TEXEL_BUFFER_BIT", vk::FormatFeatureFlags::STORAGE_TEXEL_BUFFER.as_raw() as u64),
+        ("VK_FORMAT_FEATURE_STORAGE_TEXEL_BUFFER_ATOMIC_BIT", vk::FormatFeatureFlags::STORAGE_TEXEL_BUFFER_ATOMIC.as_raw() as u64),
+        ("VK_FORMAT_FEATURE_VERTEX_BUFFER_BIT", vk::FormatFeatureFlags::VERTEX_BUFFER.as_raw() as u64),
+        ("VK_FORMAT_FEATURE_COLOR_ATTACHMENT_BIT", vk::FormatFeatureFlags::COLOR_ATTACHMENT.as_raw() as u64),
+        ("VK_FORMAT_FEATURE_COLOR_ATTACHMENT_BLEND_BIT", vk::FormatFeatureFlags::COLOR_ATTACHMENT_BLEND.as_raw() as u64),
+        ("VK_FORMAT_FEATURE_DEPTH_STENCIL_ATTACHMENT_BIT", vk::FormatFeatureFlags::DEPTH_STENCIL_ATTACHMENT.as_raw() as u64),
+        ("VK_FORMAT_FEATURE_BLIT_SRC_BIT", vk::FormatFeatureFlags::BLIT_SRC.as_raw() as u64),
+        ("VK_FORMAT_FEATURE_BLIT_DST_BIT", vk::FormatFeatureFlags::BLIT_DST.as_raw() as u64),
+        ("VK_FORMAT_FEATURE_SAMPLED_IMAGE_FILTER_LINEAR_BIT", vk::FormatFeatureFlags::SAMPLED_IMAGE_FILTER_LINEAR.as_raw() as u64),
+        ("VK_FORMAT_FEATURE_SAMPLED_IMAGE_FILTER_CUBIC_BIT_IMG", vk::FormatFeatureFlags::SAMPLED_IMAGE_FILTER_CUBIC_IMG.as_raw() as u64),
+        ("VK_FORMAT_FEATURE_VIDEO_DECODE_OUTPUT_BIT_KHR", vk::FormatFeatureFlags::VIDEO_DECODE_OUTPUT_KHR.as_raw() as u64),
+        ("VK_FORMAT_FEATURE_VIDEO_DECODE_DPB_BIT_KHR", vk::FormatFeatureFlags::VIDEO_DECODE_DPB_KHR.as_raw() as u64),
+        ("VK_FORMAT_FEATURE_TRANSFER_SRC_BIT_KHR", vk::FormatFeatureFlags::TRANSFER_SRC_KHR.as_raw() as u64),
+        ("VK_FORMAT_FEATURE_TRANSFER_DST_BIT_KHR", vk::FormatFeatureFlags::TRANSFER_DST_KHR.as_raw() as u64),
+        ("VK_FORMAT_FEATURE_SAMPLED_IMAGE_FILTER_MINMAX_BIT_EXT", vk::FormatFeatureFlags::SAMPLED_IMAGE_FILTER_MINMAX_EXT.as_raw() as u64),
+        ("VK_FORMAT_FEATURE_ACCELERATION_STRUCTURE_VERTEX_BUFFER_BIT_KHR", vk::FormatFeatureFlags::ACCELERATION_STRUCTURE_VERTEX_BUFFER_KHR.as_raw() as u64),
+        ("VK_FORMAT_FEATURE_MIDPOINT_CHROMA_SAMPLES_BIT_KHR", vk::FormatFeatureFlags::MIDPOINT_CHROMA_SAMPLES_KHR.as_raw() as u64),
+        ("VK_FORMAT_FEATURE_SAMPLED_IMAGE_YCBCR_CONVERSION_LINEAR_FILTER_BIT_KHR", vk::FormatFeatureFlags::SAMPLED_IMAGE_YCBCR_CONVERSION_LINEAR_FILTER_KHR.as_raw() as u64),
+        ("VK_FORMAT_FEATURE_SAMPLED_IMAGE_YCBCR_CONVERSION_SEPARATE_RECONSTRUCTION_FILTER_BIT_KHR", vk::FormatFeatureFlags::SAMPLED_IMAGE_YCBCR_CONVERSION_SEPARATE_RECONSTRUCTION_FILTER_KHR.as_raw() as u64),
+        ("VK_FORMAT_FEATURE_SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_BIT_KHR", vk::FormatFeatureFlags::SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_KHR.as_raw() as u64),
+        ("VK_FORMAT_FEATURE_SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_FORCEABLE_BIT_KHR", vk::FormatFeatureFlags::SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_FORCEABLE_KHR.as_raw() as u64),
+        ("VK_FORMAT_FEATURE_DISJOINT_BIT_KHR", vk::FormatFeatureFlags::DISJOINT_KHR.as_raw() as u64),
+        ("VK_FORMAT_FEATURE_COSITED_CHROMA_SAMPLES_BIT_KHR", vk::FormatFeatureFlags::COSITED_CHROMA_SAMPLES_KHR.as_raw() as u64),
+        ("VK_FORMAT_FEATURE_SAMPLED_IMAGE_FILTER_CUBIC_BIT_EXT", vk::FormatFeatureFlags::SAMPLED_IMAGE_FILTER_CUBIC_EXT.as_raw() as u64),
+        ("VK_FORMAT_FEATURE_FRAGMENT_DENSITY_MAP_BIT_EXT", vk::FormatFeatureFlags::FRAGMENT_DENSITY_MAP_EXT.as_raw() as u64),
+        ("VK_FORMAT_FEATURE_FRAGMENT_SHADING_RATE_ATTACHMENT_BIT_KHR", vk::FormatFeatureFlags::FRAGMENT_SHADING_RATE_ATTACHMENT_KHR.as_raw() as u64),
+        ("VK_FORMAT_FEATURE_VIDEO_ENCODE_INPUT_BIT_KHR", vk::FormatFeatureFlags::VIDEO_ENCODE_INPUT_KHR.as_raw() as u64),
+        ("VK_FORMAT_FEATURE_VIDEO_ENCODE_DPB_BIT_KHR", vk::FormatFeatureFlags::VIDEO_ENCODE_DPB_KHR.as_raw() as u64),
+        ("VK_FORMAT_FEATURE_TRANSFER_SRC_BIT", vk::FormatFeatureFlags::TRANSFER_SRC.as_raw() as u64),
+        ("VK_FORMAT_FEATURE_TRANSFER_DST_BIT", vk::FormatFeatureFlags::TRANSFER_DST.as_raw() as u64),
+        ("VK_FORMAT_FEATURE_MIDPOINT_CHROMA_SAMPLES_BIT", vk::FormatFeatureFlags::MIDPOINT_CHROMA_SAMPLES.as_raw() as u64),
+        ("VK_FORMAT_FEATURE_SAMPLED_IMAGE_YCBCR_CONVERSION_LINEAR_FILTER_BIT", vk::FormatFeatureFlags::SAMPLED_IMAGE_YCBCR_CONVERSION_LINEAR_FILTER.as_raw() as u64),
+        ("VK_FORMAT_FEATURE_SAMPLED_IMAGE_YCBCR_CONVERSION_SEPARATE_RECONSTRUCTION_FILTER_BIT", vk::FormatFeatureFlags::SAMPLED_IMAGE_YCBCR_CONVERSION_SEPARATE_RECONSTRUCTION_FILTER.as_raw() as u64),
+        ("VK_FORMAT_FEATURE_SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_BIT", vk::FormatFeatureFlags::SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT.as_raw() as u64),
+        ("VK_FORMAT_FEATURE_SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_FORCEABLE_BIT", vk::FormatFeatureFlags::SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_FORCEABLE.as_raw() as u64),
+        ("VK_FORMAT_FEATURE_DISJOINT_BIT", vk::FormatFeatureFlags::DISJOINT.as_raw() as u64),
+        ("VK_FORMAT_FEATURE_COSITED_CHROMA_SAMPLES_BIT", vk::FormatFeatureFlags::COSITED_CHROMA_SAMPLES.as_raw() as u64),
+        ("VK_FORMAT_FEATURE_SAMPLED_IMAGE_FILTER_MINMAX_BIT", vk::FormatFeatureFlags::SAMPLED_IMAGE_FILTER_MINMAX.as_raw() as u64),
+    ],
+};
+
+pub(super) static FORMAT_FEATURE_FLAGS2: FlagsInfo = FlagsInfo {
+    name: "VkFormatFeatureFlags2",
+    size: size_of::<vk::FormatFeatureFlags2>(),
+    bits: &[
+        ("VK_FORMAT_FEATURE_2_SAMPLED_IMAGE_BIT", vk::FormatFeatureFlags2::SAMPLED_IMAGE.as_raw()),
+        ("VK_FORMAT_FEATURE_2_SAMPLED_IMAGE_BIT_KHR", vk::FormatFeatureFlags2::SAMPLED_IMAGE_KHR.as_raw()),
+        ("VK_FORMAT_FEATURE_2_STORAGE_IMAGE_BIT", vk::FormatFeatureFlags2::STORAGE_IMAGE.as_raw()),
+        ("VK_FORMAT_FEATURE_2_STORAGE_IMAGE_BIT_KHR", vk::FormatFeatureFlags2::STORAGE_IMAGE_KHR.as_raw()),
+        ("VK_FORMAT_FEATURE_2_STORAGE_IMAGE_ATOMIC_BIT", vk::FormatFeatureFlags2::STORAGE_IMAGE_ATOMIC.as_raw()),
+        ("VK_FORMAT_FEATURE_2_STORAGE_IMAGE_ATOMIC_BIT_KHR", vk::FormatFeatureFlags2::STORAGE_IMAGE_ATOMIC_KHR.as_raw()),
+        ("VK_FORMAT_FEATURE_2_UNIFORM_TEXEL_BUFFER_BIT", vk::FormatFeatureFlags2::UNIFORM_TEXEL_BUFFER.as_raw()),
+        ("VK_FORMAT_FEATURE_2_UNIFORM_TEXEL_BUFFER_BIT_KHR", vk::FormatFeatureFlags2::UNIFORM_TEXEL_BUFFER_KHR.as_raw()),
+        ("VK_FORMAT_FEATURE_2_STORAGE_TEXEL_BUFFER_BIT", vk::FormatFeatureFlags2::STORAGE_TEXEL_BUFFER.as_raw()),
+        ("VK_FORMAT_FEATURE_2_STORAGE_TEXEL_BUFFER_BIT_KHR", vk::FormatFeatureFlags2::STORAGE_TEXEL_BUFFER_KHR.as_raw()),
+        ("VK_FORMAT_FEATURE_2_STORAGE_TEXEL_BUFFER_ATOMIC_BIT", vk::FormatFeatureFlags2::STORAGE_TEXEL_BUFFER_ATOMIC.as_raw()),
+        ("VK_FORMAT_FEATURE_2_STORAGE_TEXEL_BUFFER_ATOMIC_BIT_KHR", vk::FormatFeatureFlags2::STORAGE_TEXEL_BUFFER_ATOMIC_KHR.as_raw()),
+        ("VK_FORMAT_FEATURE_2_VERTEX_BUFFER_BIT", vk::FormatFeatureFlags2::VERTEX_BUFFER.as_raw()),
+        ("VK_FORMAT_FEATURE_2_VERTEX_BUFFER_BIT_KHR", vk::FormatFeatureFlags2::VERTEX_BUFFER_KHR.as_raw()),
+        ("VK_FORMAT_FEATURE_2_COLOR_ATTACHMENT_BIT", vk::FormatFeatureFlags2::COLOR_ATTACHMENT.as_raw()),
+        ("VK_FORMAT_FEATURE_2_COLOR_ATTACHMENT_BIT_KHR", vk::FormatFeatureFlags2::COLOR_ATTACHMENT_KHR.as_raw()),
+        ("VK_FORMAT_FEATURE_2_COLOR_ATTACHMENT_BLEND_BIT", vk::FormatFeatureFlags2::COLOR_ATTACHMENT_BLEND.as_raw()),
+        ("VK_FORMAT_FEATURE_2_COLOR_ATTACHMENT_BLEND_BIT_KHR", vk::FormatFeatureFlags2::COLOR_ATTACHMENT_BLEND_KHR.as_raw()),
+        ("VK_FORMAT_FEATURE_2_DEPTH_STENCIL_ATTACHMENT_BIT", vk::FormatFeatureFlags2::DEPTH_STENCIL_ATTACHMENT.as_raw()),
+        ("VK_FORMAT_FEATURE_2_DEPTH_STENCIL_ATTACHMENT_BIT_KHR", vk::FormatFeatureFlags2::DEPTH_STENCIL_ATTACHMENT_KHR.as_raw()),
+        ("VK_FORMAT_FEATURE_2_BLIT_SRC_BIT", vk::FormatFeatureFlags2::BLIT_SRC.as_raw()),
+        ("VK_FORMAT_FEATURE_2_BLIT_SRC_BIT_KHR", vk::FormatFeatureFlags2::BLIT_SRC_KHR.as_raw()),
+        ("VK_FORMAT_FEATURE_2_BLIT_DST_BIT", vk::FormatFeatureFlags2::BLIT_DST.as_raw()),
+        ("VK_FORMAT_FEATURE_2_BLIT_DST_BIT_KHR", vk::FormatFeatureFlags2::BLIT_DST_KHR.as_raw()),
+        ("VK_FORMAT_FEATURE_2_SAMPLED_IMAGE_FILTER_LINEAR_BIT", vk::FormatFeatureFlags2::SAMPLED_IMAGE_FILTER_LINEAR.as_raw()),
+        ("VK_FORMAT_FEATURE_2_SAMPLED_IMAGE_FILTER_LINEAR_BIT_KHR", vk::FormatFeatureFlags2::SAMPLED_IMAGE_FILTER_LINEAR_KHR.as_raw()),
+        ("VK_FORMAT_FEATURE_2_SAMPLED_IMAGE_FILTER_CUBIC_BIT", vk::FormatFeatureFlags2::SAMPLED_IMAGE_FILTER_CUBIC.as_raw()),
+        ("VK_FORMAT_FEATURE_2_SAMPLED_IMAGE_FILTER_CUBIC_BIT_EXT", vk::FormatFeatureFlags2::SAMPLED_IMAGE_FILTER_CUBIC_EXT.as_raw()),
+        ("VK_FORMAT_FEATURE_2_TRANSFER_SRC_BIT", vk::FormatFeatureFlags2::TRANSFER_SRC.as_raw()),
+        ("VK_FORMAT_FEATURE_2_TRANSFER_SRC_BIT_KHR", vk::FormatFeatureFlags2::TRANSFER_SRC_KHR.as_raw()),
+        ("VK_FORMAT_FEATURE_2_TRANSFER_DST_BIT", vk::FormatFeatureFlags2::TRANSFER_DST.as_raw()),
+        ("VK_FORMAT_FEATURE_2_TRANSFER_DST_BIT_KHR", vk::FormatFeatureFlags2::TRANSFER_DST_KHR.as_raw()),
+        ("VK_FORMAT_FEATURE_2_SAMPLED_IMAGE_FILTER_MINMAX_BIT", vk::FormatFeatureFlags2::SAMPLED_IMAGE_FILTER_MINMAX.as_raw()),
+        ("VK_FORMAT_FEATURE_2_SAMPLED_IMAGE_FILTER_MINMAX_BIT_KHR", vk::FormatFeatureFlags2::SAMPLED_IMAGE_FILTER_MINMAX_KHR.as_raw()),
+        ("VK_FORMAT_FEATURE_2_MIDPOINT_CHROMA_SAMPLES_BIT", vk::FormatFeatureFlags2::MIDPOINT_CHROMA_SAMPLES.as_raw()),
+        ("VK_FORMAT_FEATURE_2_MIDPOINT_CHROMA_SAMPLES_BIT_KHR", vk::FormatFeatureFlags2::MIDPOINT_CHROMA_SAMPLES_KHR.as_raw()),
+        ("VK_FORMAT_FEATURE_2_SAMPLED_IMAGE_YCBCR_CONVERSION_LINEAR_FILTER_BIT", vk::FormatFeatureFlags2::SAMPLED_IMAGE_YCBCR_CONVERSION_LINEAR_FILTER.as_raw()),
+        ("VK_FORMAT_FEATURE_2_SAMPLED_IMAGE_YCBCR_CONVERSION_LINEAR_FILTER_BIT_KHR", vk::FormatFeatureFlags2::SAMPLED_IMAGE_YCBCR_CONVERSION_LINEAR_FILTER_KHR.as_raw()),
+        ("VK_FORMAT_FEATURE_2_SAMPLED_IMAGE_YCBCR_CONVERSION_SEPARATE_RECONSTRUCTION_FILTER_BIT", vk::FormatFeatureFlags2::SAMPLED_IMAGE_YCBCR_CONVERSION_SEPARATE_RECONSTRUCTION_FILTER.as_raw()),
+        ("VK_FORMAT_FEATURE_2_SAMPLED_IMAGE_YCBCR_CONVERSION_SEPARATE_RECONSTRUCTION_FILTER_BIT_KHR", vk::FormatFeatureFlags2::SAMPLED_IMAGE_YCBCR_CONVERSION_SEPARATE_RECONSTRUCTION_FILTER_KHR.as_raw()),
+        ("VK_FORMAT_FEATURE_2_SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_BIT", vk::FormatFeatureFlags2::SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT.as_raw()),
+        ("VK_FORMAT_FEATURE_2_SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_BIT_KHR", vk::FormatFeatureFlags2::SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_KHR.as_raw()),
+        ("VK_FORMAT_FEATURE_2_SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_FORCEABLE_BIT", vk::FormatFeatureFlags2::SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_FORCEABLE.as_raw()),
+        ("VK_FORMAT_FEATURE_2_SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_FORCEABLE_BIT_KHR", vk::FormatFeatureFlags2::SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_FORCEABLE_KHR.as_raw()),
+        ("VK_FORMAT_FEATURE_2_DISJOINT_BIT", vk::FormatFeatureFlags2::DISJOINT.as_raw()),
+        ("VK_FORMAT_FEATURE_2_DISJOINT_BIT_KHR", vk::FormatFeatureFlags2::DISJOINT_KHR.as_raw()),
+        ("VK_FORMAT_FEATURE_2_COSITED_CHROMA_SAMPLES_BIT", vk::FormatFeatureFlags2::COSITED_CHROMA_SAMPLES.as_raw()),
+        ("VK_FORMAT_FEATURE_2_COSITED_CHROMA_SAMPLES_BIT_KHR", vk::FormatFeatureFlags2::COSITED_CHROMA_SAMPLES_KHR.as_raw()),
+        ("VK_FORMAT_FEATURE_2_STORAGE_READ_WITHOUT_FORMAT_BIT", vk::FormatFeatureFlags2::STORAGE_READ_WITHOUT_FORMAT.as_raw()),
+        ("VK_FORMAT_FEATURE_2_STORAGE_READ_WITHOUT_FORMAT_BIT_KHR", vk::FormatFeatureFlags2::STORAGE_READ_WITHOUT_FORMAT_KHR.as_raw()),
+        ("VK_FORMAT_FEATURE_2_STORAGE_WRITE_WITHOUT_FORMAT_BIT", vk::FormatFeatureFlags2::STORAGE_WRITE_WITHOUT_FORMAT.as_raw()),
+        ("VK_FORMAT_FEATURE_2_STORAGE_WRITE_WITHOUT_FORMAT_BIT_KHR", vk::FormatFeatureFlags2::STORAGE_WRITE_WITHOUT_FORMAT_KHR.as_raw()),
+        ("VK_FORMAT_FEATURE_2_SAMPLED_IMAGE_DEPTH_COMPARISON_BIT", vk::FormatFeatureFlags2::SAMPLED_IMAGE_DEPTH_COMPARISON.as_raw()),
+        ("VK_FORMAT_FEATURE_2_SAMPLED_IMAGE_DEPTH_COMPARISON_BIT_KHR", vk::FormatFeatureFlags2::SAMPLED_IMAGE_DEPTH_COMPARISON_KHR.as_raw()),
+        ("VK_FORMAT_FEATURE_2_VIDEO_DECODE_OUTPUT_BIT_KHR", vk::FormatFeatureFlags2::VIDEO_DECODE_OUTPUT_KHR.as_raw()),
+        ("VK_FORMAT_FEATURE_2_VIDEO_DECODE_DPB_BIT_KHR", vk::FormatFeatureFlags2::VIDEO_DECODE_DPB_KHR.as_raw()),
+        ("VK_FORMAT_FEATURE_2_ACCELERATION_STRUCTURE_VERTEX_BUFFER_BIT_KHR", vk::FormatFeatureFlags2::ACCELERATION_STRUCTURE_VERTEX_BUFFER_KHR.as_raw()),
+        ("VK_FORMAT_FEATURE_2_FRAGMENT_DENSITY_MAP_BIT_EXT", vk::FormatFeatureFlags2::FRAGMENT_DENSITY_MAP_EXT.as_raw()),
+        ("VK_FORMAT_FEATURE_2_FRAGMENT_SHADING_RATE_ATTACHMENT_BIT_KHR", vk::FormatFeatureFlags2::FRAGMENT_SHADING_RATE_ATTACHMENT_KHR.as_raw()),
+        ("VK_FORMAT_FEATURE_2_HOST_IMAGE_TRANSFER_BIT_EXT", vk::FormatFeatureFlags2::HOST_IMAGE_TRANSFER_EXT.as_raw()),
+        ("VK_FORMAT_FEATURE_2_VIDEO_ENCODE_INPUT_BIT_KHR", vk::FormatFeatureFlags2::VIDEO_ENCODE_INPUT_KHR.as_raw()),
+        ("VK_FORMAT_FEATURE_2_VIDEO_ENCODE_DPB_BIT_KHR", vk::FormatFeatureFlags2::VIDEO_ENCODE_DPB_KHR.as_raw()),
+        ("VK_FORMAT_FEATURE_2_LINEAR_COLOR_ATTACHMENT_BIT_NV", vk::FormatFeatureFlags2::LINEAR_COLOR_ATTACHMENT_NV.as_raw()),
+        ("VK_FORMAT_FEATURE_2_WEIGHT_IMAGE_BIT_QCOM", vk::FormatFeatureFlags2::WEIGHT_IMAGE_QCOM.as_raw()),
+        ("VK_FORMAT_FEATURE_2_WEIGHT_SAMPLED_IMAGE_BIT_QCOM", vk::FormatFeatureFlags2::WEIGHT_SAMPLED_IMAGE_QCOM.as_raw()),
+        ("VK_FORMAT_FEATURE_2_BLOCK_MATCHING_BIT_QCOM", vk::FormatFeatureFlags2::BLOCK_MATCHING_QCOM.as_raw()),
+        ("VK_FORMAT_FEATURE_2_BOX_FILTER_SAMPLED_BIT_QCOM", vk::FormatFeatureFlags2::BOX_FILTER_SAMPLED_QCOM.as_raw()),
+        ("VK_FORMAT_FEATURE_2_OPTICAL_FLOW_IMAGE_BIT_NV", vk::FormatFeatureFlags2::OPTICAL_FLOW_IMAGE_NV.as_raw()),
+        ("VK_FORMAT_FEATURE_2_OPTICAL_FLOW_VECTOR_BIT_NV", vk::FormatFeatureFlags2::OPTICAL_FLOW_VECTOR_NV.as_raw()),
+        ("VK_FORMAT_FEATURE_2_OPTICAL_FLOW_COST_BIT_NV", vk::FormatFeatureFlags2::OPTICAL_FLOW_COST_NV.as_raw()),
+    ],
+};
+
+pub(super) static IMAGE_USAGE_FLAGS: FlagsInfo = FlagsInfo {
+    name: "VkImageUsageFlags",
+    size: size_of::<vk::ImageUsageFlags>(),
+    bits: &[
+        (
+            "VK_IMAGE_USAGE_TRANSFER_SRC_BIT",
+            vk::ImageUsageFlags::TRANSFER_SRC.as_raw() as u64,
+        ),
+        (
+            "VK_IMAGE_USAGE_TRANSFER_DST_BIT",
+            vk::ImageUsageFlags::TRANSFER_DST.as_raw() as u64,
+        ),
+        (
+            "VK_IMAGE_USAGE_SAMPLED_BIT",
+            vk::ImageUsageFlags::SAMPLED.as_raw() as u64,
+        ),
+        (
+            "VK_IMAGE_USAGE_STORAGE_BIT",
+            vk::ImageUsageFlags::STORAGE.as_raw() as u64,
+        ),
+        (
+            "VK_IMAGE_USAGE_COLOR_ATTACHMENT_BIT",
+            vk::ImageUsageFlags::COLOR_ATTACHMENT.as_raw() as u64,
+        ),
+        (
+            "VK_IMAGE_USAGE_DEPTH_STENCIL_ATTACHMENT_BIT",
+            vk::ImageUsageFlags::DEPTH_STENCIL_ATTACHMENT.as_raw() as u64,
+        ),
+        (
+            "VK_IMAGE_USAGE_TRANSIENT_ATTACHMENT_BIT",
+            vk::ImageUsageFlags::TRANSIENT_ATTACHMENT.as_raw() as u64,
+        ),
+        (
+            "VK_IMAGE_USAGE_INPUT_ATTACHMENT_BIT",
+            vk::ImageUsageFlags::INPUT_ATTACHMENT.as_raw() as u64,
+        ),
+        (
+            "VK_IMAGE_USAGE_VIDEO_DECODE_DST_BIT_KHR",
+            vk::ImageUsageFlags::VIDEO_DECODE_DST_KHR.as_raw() as u64,
+        ),
+        (
+            "VK_IMAGE_USAGE_VIDEO_DECODE_SRC_BIT_KHR",
+            vk::ImageUsageFlags::VIDEO_DECODE_SRC_KHR.as_raw() as u64,
+        ),
+        (
+            "VK_IMAGE_USAGE_VIDEO_DECODE_DPB_BIT_KHR",
+            vk::ImageUsageFlags::VIDEO_DECODE_DPB_KHR.as_raw() as u64,
+        ),
+        (
+            "VK_IMAGE_USAGE_SHADING_RATE_IMAGE_BIT_NV",
+            vk::ImageUsageFlags::SHADING_RATE_IMAGE_NV.as_raw() as u64,
+        ),
+        (
+            "VK_IMAGE_USAGE_FRAGMENT_DENSITY_MAP_BIT_EXT",
+            vk::ImageUsageFlags::FRAGMENT_DENSITY_MAP_EXT.as_raw() as u64,
+        ),
+        (
+            "VK_IMAGE_USAGE_FRAGMENT_SHADING_RATE_ATTACHMENT_BIT_KHR",
+            vk::ImageUsageFlags::FRAGMENT_SHADING_RATE_ATTACHMENT_KHR.as_raw() as u64,
+        ),
+        (
+            "VK_IMAGE_USAGE_HOST_TRANSFER_BIT_EXT",
+            vk::ImageUsageFlags::HOST_TRANSFER_EXT.as_raw() as u64,
+        ),
+        (
+            "VK_IMAGE_USAGE_VIDEO_ENCODE_DST_BIT_KHR",
+            vk::ImageUsageFlags::VIDEO_ENCODE_DST_KHR.as_raw() as u64,
+        ),
+        (
+            "VK_IMAGE_USAGE_VIDEO_ENCODE_SRC_BIT_KHR",
+            vk::ImageUsageFlags::VIDEO_ENCODE_SRC_KHR.as_raw() as u64,
+        ),
+        (
+            "VK_IMAGE_USAGE_VIDEO_ENCODE_DPB_BIT_KHR",
+            vk::ImageUsageFlags::VIDEO_ENCODE_DPB_KHR.as_raw() as u64,
+        ),
+        (
+            "VK_IMAGE_USAGE_ATTACHMENT_FEEDBACK_LOOP_BIT_EXT",
+            vk::ImageUsageFlags::ATTACHMENT_FEEDBACK_LOOP_EXT.as_raw() as u64,
+        ),
+        (
+            "VK_IMAGE_USAGE_INVOCATION_MASK_BIT_HUAWEI",
+            vk::ImageUsageFlags::INVOCATION_MASK_HUAWEI.as_raw() as u64,
+        ),
+        (
+            "VK_IMAGE_USAGE_SAMPLE_WEIGHT_BIT_QCOM",
+            vk::ImageUsageFlags::SAMPLE_WEIGHT_QCOM.as_raw() as u64,
+        ),
+        (
+            "VK_IMAGE_USAGE_SAMPLE_BLOCK_MATCH_BIT_QCOM",
+            vk::ImageUsageFlags::SAMPLE_BLOCK_MATCH_QCOM.as_raw() as u64,
+        ),
+    ],
+};
+
+pub(super) static MEMORY_DECOMPRESSION_METHOD_FLAGS_NV: FlagsInfo = FlagsInfo {
+    name: "VkMemoryDecompressionMethodFlagsNV",
+    size: size_of::<vk::MemoryDecompressionMethodFlagsNV>(),
+    bits: &[(
+        "VK_MEMORY_DECOMPRESSION_METHOD_GDEFLATE_1_0_BIT_NV",
+        vk::MemoryDecompressionMethodFlagsNV::GDEFLATE_1_0.as_raw(),
+    )],
+};
+
+pub(super) static OPTICAL_FLOW_GRID_SIZE_FLAGS_NV: FlagsInfo = FlagsInfo {
+    name: "VkOpticalFlowGridSizeFlagsNV",
+    size: size_of::<vk::OpticalFlowGridSizeFlagsNV>(),
+    bits: &[
+        (
+            "VK_OPTICAL_FLOW_GRID_SIZE_UNKNOWN_NV",
+            vk::OpticalFlowGridSizeFlagsNV::UNKNOWN.as_raw() as u64,
+        ),
+        (
+            "VK_OPTICAL_FLOW_GRID_SIZE_1X1_BIT_NV",
+            vk::OpticalFlowGridSizeFlagsNV::TYPE_1X1.as_raw() as u64,
+        ),
+        (
+            "VK_OPTICAL_FLOW_GRID_SIZE_2X2_BIT_NV",
+            vk::OpticalFlowGridSizeFlagsNV::TYPE_2X2.as_raw() as u64,
+        ),
+        (
+            "VK_OPTICAL_FLOW_GRID_SIZE_4X4_BIT_NV",
+            vk::OpticalFlowGridSizeFlagsNV::TYPE_4X4.as_raw() as u64,
+        ),
+        (
+            "VK_OPTICAL_FLOW_GRID_SIZE_8X8_BIT_NV",
+            vk::OpticalFlowGridSizeFlagsNV::TYPE_8X8.as_raw() as u64,
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_SCHEDULING_CONTROLS_FLAGS_ARM: FlagsInfo = FlagsInfo {
+    name: "VkPhysicalDeviceSchedulingControlsFlagsARM",
+    size: size_of::<vk::PhysicalDeviceSchedulingControlsFlagsARM>(),
+    bits: &[(
+        "VK_PHYSICAL_DEVICE_SCHEDULING_CONTROLS_SHADER_CORE_COUNT_BIT_ARM",
+        vk::PhysicalDeviceSchedulingControlsFlagsARM::SHADER_CORE_COUNT.as_raw(),
+    )],
+};
+
+pub(super) static PIPELINE_STAGE_FLAGS: FlagsInfo = FlagsInfo {
+    name: "VkPipelineStageFlags",
+    size: size_of::<vk::PipelineStageFlags>(),
+    bits: &[
+        (
+            "VK_PIPELINE_STAGE_TOP_OF_PIPE_BIT",
+            vk::PipelineStageFlags::TOP_OF_PIPE.as_raw() as u64,
+        ),
+        (
+            "VK_PIPELINE_STAGE_DRAW_INDIRECT_BIT",
+            vk::PipelineStageFlags::DRAW_INDIRECT.as_raw() as u64,
+        ),
+        (
+            "VK_PIPELINE_STAGE_VERTEX_INPUT_BIT",
+            vk::PipelineStageFlags::VERTEX_INPUT.as_raw() as u64,
+        ),
+        (
+            "VK_PIPELINE_STAGE_VERTEX_SHADER_BIT",
+            vk::PipelineStageFlags::VERTEX_SHADER.as_raw() as u64,
+        ),
+        (
+            "VK_PIPELINE_STAGE_TESSELLATION_CONTROL_SHADER_BIT",
+            vk::PipelineStageFlags::TESSELLATION_CONTROL_SHADER.as_raw() as u64,
+        ),
+        (
+            "VK_PIPELINE_STAGE_TESSELLATION_EVALUATION_SHADER_BIT",
+            vk::PipelineStageFlags::TESSELLATION_EVALUATION_SHADER.as_raw() as u64,
+        ),
+        (
+            "VK_PIPELINE_STAGE_GEOMETRY_SHADER_BIT",
+            vk::PipelineStageFlags::GEOMETRY_SHADER.as_raw() as u64,
+        ),
+        (
+            "VK_PIPELINE_STAGE_FRAGMENT_SHADER_BIT",
+            vk::PipelineStageFlags::FRAGMENT_SHADER.as_raw() as u64,
+        ),
+        (
+            "VK_PIPELINE_STAGE_EARLY_FRAGMENT_TESTS_BIT",
+            vk::PipelineStageFlags::EARLY_FRAGMENT_TESTS.as_raw() as u64,
+        ),
+        (
+            "VK_PIPELINE_STAGE_LATE_FRAGMENT_TESTS_BIT",
+            vk::PipelineStageFlags::LATE_FRAGMENT_TESTS.as_raw() as u64,
+        ),
+        (
+            "VK_PIPELINE_STAGE_COLOR_ATTACHMENT_OUTPUT_BIT",
+            vk::PipelineStageFlags::COLOR_ATTACHMENT_OUTPUT.as_raw() as u64,
+        ),
+        (
+            "VK_PIPELINE_STAGE_COMPUTE_SHADER_BIT",
+            vk::PipelineStageFlags::COMPUTE_SHADER.as_raw() as u64,
+        ),
+        (
+            "VK_PIPELINE_STAGE_TRANSFER_BIT",
+            vk::PipelineStageFlags::TRANSFER.as_raw() as u64,
+        ),
+        (
+            "VK_PIPELINE_STAGE_BOTTOM_OF_PIPE_BIT",
+            vk::PipelineStageFlags::BOTTOM_OF_PIPE.as_raw() as u64,
+        ),
+        (
+            "VK_PIPELINE_STAGE_HOST_BIT",
+            vk::PipelineStageFlags::HOST.as_raw() as u64,
+        ),
+        (
+            "VK_PIPELINE_STAGE_ALL_GRAPHICS_BIT",
+            vk::PipelineStageFlags::ALL_GRAPHICS.as_raw() as u64,
+        ),
+        (
+            "VK_PIPELINE_STAGE_ALL_COMMANDS_BIT",
+            vk::PipelineStageFlags::ALL_COMMANDS.as_raw() as u64,
+        ),
+        (
+            "VK_PIPELINE_STAGE_TRANSFORM_FEEDBACK_BIT_EXT",
+            vk::PipelineStageFlags::TRANSFORM_FEEDBACK_EXT.as_raw() as u64,
+        ),
+        (
+            "VK_PIPELINE_STAGE_CONDITIONAL_RENDERING_BIT_EXT",
+            vk::PipelineStageFlags::CONDITIONAL_RENDERING_EXT.as_raw() as u64,
+        ),
+        (
+            "VK_PIPELINE_STAGE_ACCELERATION_STRUCTURE_BUILD_BIT_KHR",
+            vk::PipelineStageFlags::ACCELERATION_STRUCTURE_BUILD_KHR.as_raw() as u64,
+        ),
+        (
+            "VK_PIPELINE_STAGE_RAY_TRACING_SHADER_BIT_KHR",
+            vk::PipelineStageFlags::RAY_TRACING_SHADER_KHR.as_raw() as u64,
+        ),
+        (
+            "VK_PIPELINE_STAGE_SHADING_RATE_IMAGE_BIT_NV",
+            vk::PipelineStageFlags::SHADING_RATE_IMAGE_NV.as_raw() as u64,
+        ),
+        (
+            "VK_PIPELINE_STAGE_RAY_TRACING_SHADER_BIT_NV",
+            vk::PipelineStageFlags::RAY_TRACING_SHADER_NV.as_raw() as u64,
+        ),
+        (
+            "VK_PIPELINE_STAGE_ACCELERATION_STRUCTURE_BUILD_BIT_NV",
+            vk::PipelineStageFlags::ACCELERATION_STRUCTURE_BUILD_NV.as_raw() as u64,
+        ),
+        (
+            "VK_PIPELINE_STAGE_TASK_SHADER_BIT_NV",
+            vk::PipelineStageFlags::TASK_SHADER_NV.as_raw() as u64,
+        ),
+        (
+            "VK_PIPELINE_STAGE_MESH_SHADER_BIT_NV",
+            vk::PipelineStageFlags::MESH_SHADER_NV.as_raw() as u64,
+        ),
+        (
+            "VK_PIPELINE_STAGE_FRAGMENT_DENSITY_PROCESS_BIT_EXT",
+            vk::PipelineStageFlags::FRAGMENT_DENSITY_PROCESS_EXT.as_raw() as u64,
+        ),
+        (
+            "VK_PIPELINE_STAGE_FRAGMENT_SHADING_RATE_ATTACHMENT_BIT_KHR",
+            vk::PipelineStageFlags::FRAGMENT_SHADING_RATE_ATTACHMENT_KHR.as_raw() as u64,
+        ),
+        (
+            "VK_PIPELINE_STAGE_COMMAND_PREPROCESS_BIT_NV",
+            vk::PipelineStageFlags::COMMAND_PREPROCESS_NV.as_raw() as u64,
+        ),
+        (
+            "VK_PIPELINE_STAGE_NONE_KHR",
+            vk::PipelineStageFlags::NONE_KHR.as_raw() as u64,
+        ),
+        (
+            "VK_PIPELINE_STAGE_TASK_SHADER_BIT_EXT",
+            vk::PipelineStageFlags::TASK_SHADER_EXT.as_raw() as u64,
+        ),
+        (
+            "VK_PIPELINE_STAGE_MESH_SHADER_BIT_EXT",
+            vk::PipelineStageFlags::MESH_SHADER_EXT.as_raw() as u64,
+        ),
+        (
+            "VK_PIPELINE_STAGE_NONE",
+            vk::PipelineStageFlags::NONE.as_raw() as u64,
+        ),
+    ],
+};
+
+pub(super) static PIPELINE_STAGE_FLAGS2: FlagsInfo = FlagsInfo {
+    name: "VkPipelineStageFlags2",
+    size: size_of::<vk::PipelineStageFlags2>(),
+    bits: &[
+        (
+            "VK_PIPELINE_STAGE_2_NONE",
+            vk::PipelineStageFlags2::NONE.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_NONE_KHR",
+            vk::PipelineStageFlags2::NONE_KHR.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_TOP_OF_PIPE_BIT",
+            vk::PipelineStageFlags2::TOP_OF_PIPE.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_TOP_OF_PIPE_BIT_KHR",
+            vk::PipelineStageFlags2::TOP_OF_PIPE_KHR.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_DRAW_INDIRECT_BIT",
+            vk::PipelineStageFlags2::DRAW_INDIRECT.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_DRAW_INDIRECT_BIT_KHR",
+            vk::PipelineStageFlags2::DRAW_INDIRECT_KHR.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_VERTEX_INPUT_BIT",
+            vk::PipelineStageFlags2::VERTEX_INPUT.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_VERTEX_INPUT_BIT_KHR",
+            vk::PipelineStageFlags2::VERTEX_INPUT_KHR.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_VERTEX_SHADER_BIT",
+            vk::PipelineStageFlags2::VERTEX_SHADER.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_VERTEX_SHADER_BIT_KHR",
+            vk::PipelineStageFlags2::VERTEX_SHADER_KHR.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_TESSELLATION_CONTROL_SHADER_BIT",
+            vk::PipelineStageFlags2::TESSELLATION_CONTROL_SHADER.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_TESSELLATION_CONTROL_SHADER_BIT_KHR",
+            vk::PipelineStageFlags2::TESSELLATION_CONTROL_SHADER_KHR.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_TESSELLATION_EVALUATION_SHADER_BIT",
+            vk::PipelineStageFlags2::TESSELLATION_EVALUATION_SHADER.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_TESSELLATION_EVALUATION_SHADER_BIT_KHR",
+            vk::PipelineStageFlags2::TESSELLATION_EVALUATION_SHADER_KHR.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_GEOMETRY_SHADER_BIT",
+            vk::PipelineStageFlags2::GEOMETRY_SHADER.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_GEOMETRY_SHADER_BIT_KHR",
+            vk::PipelineStageFlags2::GEOMETRY_SHADER_KHR.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_FRAGMENT_SHADER_BIT",
+            vk::PipelineStageFlags2::FRAGMENT_SHADER.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_FRAGMENT_SHADER_BIT_KHR",
+            vk::PipelineStageFlags2::FRAGMENT_SHADER_KHR.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_EARLY_FRAGMENT_TESTS_BIT",
+            vk::PipelineStageFlags2::EARLY_FRAGMENT_TESTS.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_EARLY_FRAGMENT_TESTS_BIT_KHR",
+            vk::PipelineStageFlags2::EARLY_FRAGMENT_TESTS_KHR.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_LATE_FRAGMENT_TESTS_BIT",
+            vk::PipelineStageFlags2::LATE_FRAGMENT_TESTS.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_LATE_FRAGMENT_TESTS_BIT_KHR",
+            vk::PipelineStageFlags2::LATE_FRAGMENT_TESTS_KHR.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_COLOR_ATTACHMENT_OUTPUT_BIT",
+            vk::PipelineStageFlags2::COLOR_ATTACHMENT_OUTPUT.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_COLOR_ATTACHMENT_OUTPUT_BIT_KHR",
+            vk::PipelineStageFlags2::COLOR_ATTACHMENT_OUTPUT_KHR.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_COMPUTE_SHADER_BIT",
+            vk::PipelineStageFlags2::COMPUTE_SHADER.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_COMPUTE_SHADER_BIT_KHR",
+            vk::PipelineStageFlags2::COMPUTE_SHADER_KHR.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_ALL_TRANSFER_BIT",
+            vk::PipelineStageFlags2::ALL_TRANSFER.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_ALL_TRANSFER_BIT_KHR",
+            vk::PipelineStageFlags2::ALL_TRANSFER_KHR.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_TRANSFER_BIT",
+            vk::PipelineStageFlags2::TRANSFER.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_TRANSFER_BIT_KHR",
+            vk::PipelineStageFlags2::TRANSFER_KHR.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_BOTTOM_OF_PIPE_BIT",
+            vk::PipelineStageFlags2::BOTTOM_OF_PIPE.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_BOTTOM_OF_PIPE_BIT_KHR",
+            vk::PipelineStageFlags2::BOTTOM_OF_PIPE_KHR.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_HOST_BIT",
+            vk::PipelineStageFlags2::HOST.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_HOST_BIT_KHR",
+            vk::PipelineStageFlags2::HOST_KHR.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_ALL_GRAPHICS_BIT",
+            vk::PipelineStageFlags2::ALL_GRAPHICS.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_ALL_GRAPHICS_BIT_KHR",
+            vk::PipelineStageFlags2::ALL_GRAPHICS_KHR.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_ALL_COMMANDS_BIT",
+            vk::PipelineStageFlags2::ALL_COMMANDS.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_ALL_COMMANDS_BIT_KHR",
+            vk::PipelineStageFlags2::ALL_COMMANDS_KHR.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_COPY_BIT",
+            vk::PipelineStageFlags2::COPY.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_COPY_BIT_KHR",
+            vk::PipelineStageFlags2::COPY_KHR.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_RESOLVE_BIT",
+            vk::PipelineStageFlags2::RESOLVE.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_RESOLVE_BIT_KHR",
+            vk::PipelineStageFlags2::RESOLVE_KHR.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_BLIT_BIT",
+            vk::PipelineStageFlags2::BLIT.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_BLIT_BIT_KHR",
+            vk::PipelineStageFlags2::BLIT_KHR.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_CLEAR_BIT",
+            vk::PipelineStageFlags2::CLEAR.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_CLEAR_BIT_KHR",
+            vk::PipelineStageFlags2::CLEAR_KHR.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_INDEX_INPUT_BIT",
+            vk::PipelineStageFlags2::INDEX_INPUT.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_INDEX_INPUT_BIT_KHR",
+            vk::PipelineStageFlags2::INDEX_INPUT_KHR.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_VERTEX_ATTRIBUTE_INPUT_BIT",
+            vk::PipelineStageFlags2::VERTEX_ATTRIBUTE_INPUT.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_VERTEX_ATTRIBUTE_INPUT_BIT_KHR",
+            vk::PipelineStageFlags2::VERTEX_ATTRIBUTE_INPUT_KHR.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_PRE_RASTERIZATION_SHADERS_BIT",
+            vk::PipelineStageFlags2::PRE_RASTERIZATION_SHADERS.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_PRE_RASTERIZATION_SHADERS_BIT_KHR",
+            vk::PipelineStageFlags2::PRE_RASTERIZATION_SHADERS_KHR.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_VIDEO_DECODE_BIT_KHR",
+            vk::PipelineStageFlags2::VIDEO_DECODE_KHR.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_VIDEO_ENCODE_BIT_KHR",
+            vk::PipelineStageFlags2::VIDEO_ENCODE_KHR.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_TRANSFORM_FEEDBACK_BIT_EXT",
+            vk::PipelineStageFlags2::TRANSFORM_FEEDBACK_EXT.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_CONDITIONAL_RENDERING_BIT_EXT",
+            vk::PipelineStageFlags2::CONDITIONAL_RENDERING_EXT.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_COMMAND_PREPROCESS_BIT_NV",
+            vk::PipelineStageFlags2::COMMAND_PREPROCESS_NV.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_FRAGMENT_SHADING_RATE_ATTACHMENT_BIT_KHR",
+            vk::PipelineStageFlags2::FRAGMENT_SHADING_RATE_ATTACHMENT_KHR.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_SHADING_RATE_IMAGE_BIT_NV",
+            vk::PipelineStageFlags2::SHADING_RATE_IMAGE_NV.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_ACCELERATION_STRUCTURE_BUILD_BIT_KHR",
+            vk::PipelineStageFlags2::ACCELERATION_STRUCTURE_BUILD_KHR.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_RAY_TRACING_SHADER_BIT_KHR",
+            vk::PipelineStageFlags2::RAY_TRACING_SHADER_KHR.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_RAY_TRACING_SHADER_BIT_NV",
+            vk::PipelineStageFlags2::RAY_TRACING_SHADER_NV.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_ACCELERATION_STRUCTURE_BUILD_BIT_NV",
+            vk::PipelineStageFlags2::ACCELERATION_STRUCTURE_BUILD_NV.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_FRAGMENT_DENSITY_PROCESS_BIT_EXT",
+            vk::PipelineStageFlags2::FRAGMENT_DENSITY_PROCESS_EXT.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_TASK_SHADER_BIT_NV",
+            vk::PipelineStageFlags2::TASK_SHADER_NV.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_MESH_SHADER_BIT_NV",
+            vk::PipelineStageFlags2::MESH_SHADER_NV.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_TASK_SHADER_BIT_EXT",
+            vk::PipelineStageFlags2::TASK_SHADER_EXT.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_MESH_SHADER_BIT_EXT",
+            vk::PipelineStageFlags2::MESH_SHADER_EXT.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_SUBPASS_SHADER_BIT_HUAWEI",
+            vk::PipelineStageFlags2::SUBPASS_SHADER_HUAWEI.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_INVOCATION_MASK_BIT_HUAWEI",
+            vk::PipelineStageFlags2::INVOCATION_MASK_HUAWEI.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_ACCELERATION_STRUCTURE_COPY_BIT_KHR",
+            vk::PipelineStageFlags2::ACCELERATION_STRUCTURE_COPY_KHR.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_MICROMAP_BUILD_BIT_EXT",
+            vk::PipelineStageFlags2::MICROMAP_BUILD_EXT.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_CLUSTER_CULLING_SHADER_BIT_HUAWEI",
+            vk::PipelineStageFlags2::CLUSTER_CULLING_SHADER_HUAWEI.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_STAGE_2_OPTICAL_FLOW_BIT_NV",
+            vk::PipelineStageFlags2::OPTICAL_FLOW_NV.as_raw(),
+        ),
+    ],
+};
+
+pub(super) static QUEUE_FLAGS: FlagsInfo = FlagsInfo {
+    name: "VkQueueFlags",
+    size: size_of::<vk::QueueFlags>(),
+    bits: &[
+        (
+            "VK_QUEUE_GRAPHICS_BIT",
+            vk::QueueFlags::GRAPHICS.as_raw() as u64,
+        ),
+        (
+            "VK_QUEUE_COMPUTE_BIT",
+            vk::QueueFlags::COMPUTE.as_raw() as u64,
+        ),
+        (
+            "VK_QUEUE_TRANSFER_BIT",
+            vk::QueueFlags::TRANSFER.as_raw() as u64,
+        ),
+        (
+            "VK_QUEUE_SPARSE_BINDING_BIT",
+            vk::QueueFlags::SPARSE_BINDING.as_raw() as u64,
+        ),
+        (
+            "VK_QUEUE_VIDEO_DECODE_BIT_KHR",
+            vk::QueueFlags::VIDEO_DECODE_KHR.as_raw() as u64,
+        ),
+        (
+            "VK_QUEUE_VIDEO_ENCODE_BIT_KHR",
+            vk::QueueFlags::VIDEO_ENCODE_KHR.as_raw() as u64,
+        ),
+        (
+            "VK_QUEUE_OPTICAL_FLOW_BIT_NV",
+            vk::QueueFlags::OPTICAL_FLOW_NV.as_raw() as u64,
+        ),
+        (
+            "VK_QUEUE_PROTECTED_BIT",
+            vk::QueueFlags::PROTECTED.as_raw() as u64,
+        ),
+    ],
+};
+
+pub(super) static RESOLVE_MODE_FLAGS: FlagsInfo = FlagsInfo {
+    name: "VkResolveModeFlags",
+    size: size_of::<vk::ResolveModeFlags>(),
+    bits: &[
+        (
+            "VK_RESOLVE_MODE_NONE",
+            vk::ResolveModeFlags::NONE.as_raw() as u64,
+        ),
+        (
+            "VK_RESOLVE_MODE_SAMPLE_ZERO_BIT",
+            vk::ResolveModeFlags::SAMPLE_ZERO.as_raw() as u64,
+        ),
+        (
+            "VK_RESOLVE_MODE_AVERAGE_BIT",
+            vk::ResolveModeFlags::AVERAGE.as_raw() as u64,
+        ),
+        (
+            "VK_RESOLVE_MODE_MIN_BIT",
+            vk::ResolveModeFlags::MIN.as_raw() as u64,
+        ),
+        (
+            "VK_RESOLVE_MODE_MAX_BIT",
+            vk::ResolveModeFlags::MAX.as_raw() as u64,
+        ),
+        (
+            "VK_RESOLVE_MODE_NONE_KHR",
+            vk::ResolveModeFlags::NONE_KHR.as_raw() as u64,
+        ),
+        (
+            "VK_RESOLVE_MODE_SAMPLE_ZERO_BIT_KHR",
+            vk::ResolveModeFlags::SAMPLE_ZERO_KHR.as_raw() as u64,
+        ),
+        (
+            "VK_RESOLVE_MODE_AVERAGE_BIT_KHR",
+            vk::ResolveModeFlags::AVERAGE_KHR.as_raw() as u64,
+        ),
+        (
+            "VK_RESOLVE_MODE_MIN_BIT_KHR",
+            vk::ResolveModeFlags::MIN_KHR.as_raw() as u64,
+        ),
+        (
+            "VK_RESOLVE_MODE_MAX_BIT_KHR",
+            vk::ResolveModeFlags::MAX_KHR.as_raw() as u64,
+        ),
+        (
+            "VK_RESOLVE_MODE_EXTERNAL_FORMAT_DOWNSAMPLE_ANDROID",
+            vk::ResolveModeFlags::EXTERNAL_FORMAT_DOWNSAMPLE_ANDROID.as_raw() as u64,
+        ),
+    ],
+};
+
+pub(super) static SAMPLE_COUNT_FLAGS: FlagsInfo = FlagsInfo {
+    name: "VkSampleCountFlags",
+    size: size_of::<vk::SampleCountFlags>(),
+    bits: &[
+        (
+            "VK_SAMPLE_COUNT_1_BIT",
+            vk::SampleCountFlags::TYPE_1.as_raw() as u64,
+        ),
+        (
+            "VK_SAMPLE_COUNT_2_BIT",
+            vk::SampleCountFlags::TYPE_2.as_raw() as u64,
+        ),
+        (
+            "VK_SAMPLE_COUNT_4_BIT",
+            vk::SampleCountFlags::TYPE_4.as_raw() as u64,
+        ),
+        (
+            "VK_SAMPLE_COUNT_8_BIT",
+            vk::SampleCountFlags::TYPE_8.as_raw() as u64,
+        ),
+        (
+            "VK_SAMPLE_COUNT_16_BIT",
+            vk::SampleCountFlags::TYPE_16.as_raw() as u64,
+        ),
+        (
+            "VK_SAMPLE_COUNT_32_BIT",
+            vk::SampleCountFlags::TYPE_32.as_raw() as u64,
+        ),
+        (
+            "VK_SAMPLE_COUNT_64_BIT",
+            vk::SampleCountFlags::TYPE_64.as_raw() as u64,
+        ),
+    ],
+};
+
+pub(super) static SHADER_CORE_PROPERTIES_FLAGS_AMD: FlagsInfo = FlagsInfo {
+    name: "VkShaderCorePropertiesFlagsAMD",
+    size: size_of::<vk::ShaderCorePropertiesFlagsAMD>(),
+    bits: &[],
+};
+
+pub(super) static SHADER_STAGE_FLAGS: FlagsInfo = FlagsInfo {
+    name: "VkShaderStageFlags",
+    size: size_of::<vk::ShaderStageFlags>(),
+    bits: &[
+        (
+            "VK_SHADER_STAGE_VERTEX_BIT",
+            vk::ShaderStageFlags::VERTEX.as_raw() as u64,
+        ),
+        (
+            "VK_SHADER_STAGE_TESSELLATION_CONTROL_BIT",
+            vk::ShaderStageFlags::TESSELLATION_CONTROL.as_raw() as u64,
+        ),
+        (
+            "VK_SHADER_STAGE_TESSELLATION_EVALUATION_BIT",
+            vk::ShaderStageFlags::TESSELLATION_EVALUATION.as_raw() as u64,
+        ),
+        (
+            "VK_SHADER_STAGE_GEOMETRY_BIT",
+            vk::ShaderStageFlags::GEOMETRY.as_raw() as u64,
+        ),
+        (
+            "VK_SHADER_STAGE_FRAGMENT_BIT",
+            vk::ShaderStageFlags::FRAGMENT.as_raw() as u64,
+        ),
+        (
+            "VK_SHADER_STAGE_COMPUTE_BIT",
+            vk::ShaderStageFlags::COMPUTE.as_raw() as u64,
+        ),
+        (
+            "VK_SHADER_STAGE_ALL_GRAPHICS",
+            vk::ShaderStageFlags::ALL_GRAPHICS.as_raw() as u64,
+        ),
+        (
+            "VK_SHADER_STAGE_ALL",
+            vk::ShaderStageFlags::ALL.as_raw() as u64,
+        ),
+        (
+            "VK_SHADER_STAGE_RAYGEN_BIT_KHR",
+            vk::ShaderStageFlags::RAYGEN_KHR.as_raw() as u64,
+        ),
+        (
+            "VK_SHADER_STAGE_ANY_HIT_BIT_KHR",
+            vk::ShaderStageFlags::ANY_HIT_KHR.as_raw() as u64,
+        ),
+        (
+            "VK_SHADER_STAGE_CLOSEST_HIT_BIT_KHR",
+            vk::ShaderStageFlags::CLOSEST_HIT_KHR.as_raw() as u64,
+        ),
+        (
+            "VK_SHADER_STAGE_MISS_BIT_KHR",
+            vk::ShaderStageFlags::MISS_KHR.as_raw() as u64,
+        ),
+        (
+            "VK_SHADER_STAGE_INTERSECTION_BIT_KHR",
+            vk::ShaderStageFlags::INTERSECTION_KHR.as_raw() as u64,
+        ),
+        (
+            "VK_SHADER_STAGE_CALLABLE_BIT_KHR",
+            vk::ShaderStageFlags::CALLABLE_KHR.as_raw() as u64,
+        ),
+        (
+            "VK_SHADER_STAGE_RAYGEN_BIT_NV",
+            vk::ShaderStageFlags::RAYGEN_NV.as_raw() as u64,
+        ),
+        (
+            "VK_SHADER_STAGE_ANY_HIT_BIT_NV",
+            vk::ShaderStageFlags::ANY_HIT_NV.as_raw() as u64,
+        ),
+        (
+            "VK_SHADER_STAGE_CLOSEST_HIT_BIT_NV",
+            vk::ShaderStageFlags::CLOSEST_HIT_NV.as_raw() as u64,
+        ),
+        (
+            "VK_SHADER_STAGE_MISS_BIT_NV",
+            vk::ShaderStageFlags::MISS_NV.as_raw() as u64,
+        ),
+        (
+            "VK_SHADER_STAGE_INTERSECTION_BIT_NV",
+            vk::ShaderStageFlags::INTERSECTION_NV.as_raw() as u64,
+        ),
+        (
+            "VK_SHADER_STAGE_CALLABLE_BIT_NV",
+            vk::ShaderStageFlags::CALLABLE_NV.as_raw() as u64,
+        ),
+        (
+            "VK_SHADER_STAGE_TASK_BIT_NV",
+            vk::ShaderStageFlags::TASK_NV.as_raw() as u64,
+        ),
+        (
+            "VK_SHADER_STAGE_MESH_BIT_NV",
+            vk::ShaderStageFlags::MESH_NV.as_raw() as u64,
+        ),
+        (
+            "VK_SHADER_STAGE_TASK_BIT_EXT",
+            vk::ShaderStageFlags::TASK_EXT.as_raw() as u64,
+        ),
+        (
+            "VK_SHADER_STAGE_MESH_BIT_EXT",
+            vk::ShaderStageFlags::MESH_EXT.as_raw() as u64,
+        ),
+        (
+            "VK_SHADER_STAGE_SUBPASS_SHADING_BIT_HUAWEI",
+            vk::ShaderStageFlags::SUBPASS_SHADING_HUAWEI.as_raw() as u64,
+        ),
+        (
+            "VK_SHADER_STAGE_CLUSTER_CULLING_BIT_HUAWEI",
+            vk::ShaderStageFlags::CLUSTER_CULLING_HUAWEI.as_raw() as u64,
+        ),
+    ],
+};
+
+pub(super) static SUBGROUP_FEATURE_FLAGS: FlagsInfo = FlagsInfo {
+    name: "VkSubgroupFeatureFlags",
+    size: size_of::<vk::SubgroupFeatureFlags>(),
+    bits: &[
+        (
+            "VK_SUBGROUP_FEATURE_BASIC_BIT",
+            vk::SubgroupFeatureFlags::BASIC.as_raw() as u64,
+        ),
+        (
+            "VK_SUBGROUP_FEATURE_VOTE_BIT",
+            vk::SubgroupFeatureFlags::VOTE.as_raw() as u64,
+        ),
+        (
+            "VK_SUBGROUP_FEATURE_ARITHMETIC_BIT",
+            vk::SubgroupFeatureFlags::ARITHMETIC.as_raw() as u64,
+        ),
+        (
+            "VK_SUBGROUP_FEATURE_BALLOT_BIT",
+            vk::SubgroupFeatureFlags::BALLOT.as_raw() as u64,
+        ),
+        (
+            "VK_SUBGROUP_FEATURE_SHUFFLE_BIT",
+            vk::SubgroupFeatureFlags::SHUFFLE.as_raw() as u64,
+        ),
+        (
+            "VK_SUBGROUP_FEATURE_SHUFFLE_RELATIVE_BIT",
+            vk::SubgroupFeatureFlags::SHUFFLE_RELATIVE.as_raw() as u64,
+        ),
+        (
+            "VK_SUBGROUP_FEATURE_CLUSTERED_BIT",
+            vk::SubgroupFeatureFlags::CLUSTERED.as_raw() as u64,
+        ),
+        (
+            "VK_SUBGROUP_FEATURE_QUAD_BIT",
+            vk::SubgroupFeatureFlags::QUAD.as_raw() as u64,
+        ),
+        (
+            "VK_SUBGROUP_FEATURE_PARTITIONED_BIT_NV",
+            vk::SubgroupFeatureFlags::PARTITIONED_NV.as_raw() as u64,
+        ),
+        (
+            "VK_SUBGROUP_FEATURE_ROTATE_BIT_KHR",
+            vk::SubgroupFeatureFlags::ROTATE_KHR.as_raw() as u64,
+        ),
+        (
+            "VK_SUBGROUP_FEATURE_ROTATE_CLUSTERED_BIT_KHR",
+            vk::SubgroupFeatureFlags::ROTATE_CLUSTERED_KHR.as_raw() as u64,
+        ),
+    ],
+};
+
+pub(super) static VIDEO_CODEC_OPERATION_FLAGS_KHR: FlagsInfo = FlagsInfo {
+    name: "VkVideoCodecOperationFlagsKHR",
+    size: size_of::<vk::VideoCodecOperationFlagsKHR>(),
+    bits: &[
+        (
+            "VK_VIDEO_CODEC_OPERATION_NONE_KHR",
+            vk::VideoCodecOperationFlagsKHR::NONE.as_raw() as u64,
+        ),
+        (
+            "VK_VIDEO_CODEC_OPERATION_ENCODE_H264_BIT_KHR",
+            vk::VideoCodecOperationFlagsKHR::ENCODE_H264.as_raw() as u64,
+        ),
+        (
+            "VK_VIDEO_CODEC_OPERATION_ENCODE_H265_BIT_KHR",
+            vk::VideoCodecOperationFlagsKHR::ENCODE_H265.as_raw() as u64,
+        ),
+        (
+            "VK_VIDEO_CODEC_OPERATION_DECODE_H264_BIT_KHR",
+            vk::VideoCodecOperationFlagsKHR::DECODE_H264.as_raw() as u64,
+        ),
+        (
+            "VK_VIDEO_CODEC_OPERATION_DECODE_H265_BIT_KHR",
+            vk::VideoCodecOperationFlagsKHR::DECODE_H265.as_raw() as u64,
+        ),
+        (
+            "VK_VIDEO_CODEC_OPERATION_DECODE_AV1_BIT_KHR",
+            vk::VideoCodecOperationFlagsKHR::DECODE_AV1.as_raw() as u64,
+        ),
+    ],
+};
+
+pub(super) static CHROMA_LOCATION: EnumInfo = EnumInfo {
+    name: "VkChromaLocation",
+    values: &[
+        (
+            "VK_CHROMA_LOCATION_COSITED_EVEN",
+            vk::ChromaLocation::COSITED_EVEN.as_raw(),
+        ),
+        (
+            "VK_CHROMA_LOCATION_MIDPOINT",
+            vk::ChromaLocation::MIDPOINT.as_raw(),
+        ),
+        (
+            "VK_CHROMA_LOCATION_COSITED_EVEN_KHR",
+            vk::ChromaLocation::COSITED_EVEN_KHR.as_raw(),
+        ),
+        (
+            "VK_CHROMA_LOCATION_MIDPOINT_KHR",
+            vk::ChromaLocation::MIDPOINT_KHR.as_raw(),
+        ),
+    ],
+};
+
+pub(super) static DRIVER_ID: EnumInfo = EnumInfo {
+    name: "VkDriverId",
+    values: &[
+        (
+            "VK_DRIVER_ID_AMD_PROPRIETARY",
+            vk::DriverId::AMD_PROPRIETARY.as_raw(),
+        ),
+        (
+            "VK_DRIVER_ID_AMD_OPEN_SOURCE",
+            vk::DriverId::AMD_OPEN_SOURCE.as_raw(),
+        ),
+        ("VK_DRIVER_ID_MESA_RADV", vk::DriverId::MESA_RADV.as_raw()),
+        (
+            "VK_DRIVER_ID_NVIDIA_PROPRIETARY",
+            vk::DriverId::NVIDIA_PROPRIETARY.as_raw(),
+        ),
+        (
+            "VK_DRIVER_ID_INTEL_PROPRIETARY_WINDOWS",
+            vk::DriverId::INTEL_PROPRIETARY_WINDOWS.as_raw(),
+        ),
+        (
+            "VK_DRIVER_ID_INTEL_OPEN_SOURCE_MESA",
+            vk::DriverId::INTEL_OPEN_SOURCE_MESA.as_raw(),
+        ),
+        (
+            "VK_DRIVER_ID_IMAGINATION_PROPRIETARY",
+            vk::DriverId::IMAGINATION_PROPRIETARY.as_raw(),
+        ),
+        (
+            "VK_DRIVER_ID_QUALCOMM_PROPRIETARY",
+            vk::DriverId::QUALCOMM_PROPRIETARY.as_raw(),
+        ),
+        (
+            "VK_DRIVER_ID_ARM_PROPRIETARY",
+            vk::DriverId::ARM_PROPRIETARY.as_raw(),
+        ),
+        (
+            "VK_DRIVER_ID_GOOGLE_SWIFTSHADER",
+            vk::DriverId::GOOGLE_SWIFTSHADER.as_raw(),
+        ),
+        (
+            "VK_DRIVER_ID_GGP_PROPRIETARY",
+            vk::DriverId::GGP_PROPRIETARY.as_raw(),
+        ),
+        (
+            "VK_DRIVER_ID_BROADCOM_PROPRIETARY",
+            vk::DriverId::BROADCOM_PROPRIETARY.as_raw(),
+        ),
+        (
+            "VK_DRIVER_ID_MESA_LLVMPIPE",
+            vk::DriverId::MESA_LLVMPIPE.as_raw(),
+        ),
+        ("VK_DRIVER_ID_MOLTENVK", vk::DriverId::MOLTENVK.as_raw()),
+        (
+            "VK_DRIVER_ID_COREAVI_PROPRIETARY",
+            vk::DriverId::COREAVI_PROPRIETARY.as_raw(),
+        ),
+        (
+            "VK_DRIVER_ID_JUICE_PROPRIETARY",
+            vk::DriverId::JUICE_PROPRIETARY.as_raw(),
+        ),
+        (
+            "VK_DRIVER_ID_VERISILICON_PROPRIETARY",
+            vk::DriverId::VERISILICON_PROPRIETARY.as_raw(),
+        ),
+        (
+            "VK_DRIVER_ID_MESA_TURNIP",
+            vk::DriverId::MESA_TURNIP.as_raw(),
+        ),
+        ("VK_DRIVER_ID_MESA_V3DV", vk::DriverId::MESA_V3DV.as_raw()),
+        ("VK_DRIVER_ID_MESA_PANVK", vk::DriverId::MESA_PANVK.as_raw()),
+        (
+            "VK_DRIVER_ID_SAMSUNG_PROPRIETARY",
+            vk::DriverId::SAMSUNG_PROPRIETARY.as_raw(),
+        ),
+        ("VK_DRIVER_ID_MESA_VENUS", vk::DriverId::MESA_VENUS.as_raw()),
+        ("VK_DRIVER_ID_MESA_DOZEN", vk::DriverId::MESA_DOZEN.as_raw()),
+        ("VK_DRIVER_ID_MESA_NVK", vk::DriverId::MESA_NVK.as_raw()),
+        (
+            "VK_DRIVER_ID_IMAGINATION_OPEN_SOURCE_MESA",
+            vk::DriverId::IMAGINATION_OPEN_SOURCE_MESA.as_raw(),
+        ),
+        ("VK_DRIVER_ID_MESA_AGXV", vk::DriverId::MESA_AGXV.as_raw()),
+        (
+            "VK_DRIVER_ID_AMD_PROPRIETARY_KHR",
+            vk::DriverId::AMD_PROPRIETARY_KHR.as_raw(),
+        ),
+        (
+            "VK_DRIVER_ID_AMD_OPEN_SOURCE_KHR",
+            vk::DriverId::AMD_OPEN_SOURCE_KHR.as_raw(),
+        ),
+        (
+            "VK_DRIVER_ID_MESA_RADV_KHR",
+            vk::DriverId::MESA_RADV_KHR.as_raw(),
+        ),
+        (
+            "VK_DRIVER_ID_NVIDIA_PROPRIETARY_KHR",
+            vk::DriverId::NVIDIA_PROPRIETARY_KHR.as_raw(),
+        ),
+        (
+            "VK_DRIVER_ID_INTEL_PROPRIETARY_WINDOWS_KHR",
+            vk::DriverId::INTEL_PROPRIETARY_WINDOWS_KHR.as_raw(),
+        ),
+        (
+            "VK_DRIVER_ID_INTEL_OPEN_SOURCE_MESA_KHR",
+            vk::DriverId::INTEL_OPEN_SOURCE_MESA_KHR.as_raw(),
+        ),
+        (
+            "VK_DRIVER_ID_IMAGINATION_PROPRIETARY_KHR",
+            vk::DriverId::IMAGINATION_PROPRIETARY_KHR.as_raw(),
+        ),
+        (
+            "VK_DRIVER_ID_QUALCOMM_PROPRIETARY_KHR",
+            vk::DriverId::QUALCOMM_PROPRIETARY_KHR.as_raw(),
+        ),
+        (
+            "VK_DRIVER_ID_ARM_PROPRIETARY_KHR",
+            vk::DriverId::ARM_PROPRIETARY_KHR.as_raw(),
+        ),
+        (
+            "VK_DRIVER_ID_GOOGLE_SWIFTSHADER_KHR",
+            vk::DriverId::GOOGLE_SWIFTSHADER_KHR.as_raw(),
+        ),
+        (
+            "VK_DRIVER_ID_GGP_PROPRIETARY_KHR",
+            vk::DriverId::GGP_PROPRIETARY_KHR.as_raw(),
+        ),
+        (
+            "VK_DRIVER_ID_BROADCOM_PROPRIETARY_KHR",
+            vk::DriverId::BROADCOM_PROPRIETARY_KHR.as_raw(),
+        ),
+    ],
+};
+
+pub(super) static FORMAT: EnumInfo = EnumInfo {
+    name: "VkFormat",
+    values: &[
+        ("VK_FORMAT_UNDEFINED", vk::Format::UNDEFINED.as_raw()),
+        (
+            "VK_FORMAT_R4G4_UNORM_PACK8",
+            vk::Format::R4G4_UNORM_PACK8.as_raw(),
+        ),
+        (
+            "VK_FORMAT_R4G4B4A4_UNORM_PACK16",
+            vk::Format::R4G4B4A4_UNORM_PACK16.as_raw(),
+        ),
+        (
+            "VK_FORMAT_B4G4R4A4_UNORM_PACK16",
+            vk::Format::B4G4R4A4_UNORM_PACK16.as_raw(),
+        ),
+        (
+            "VK_FORMAT_R5G6B5_UNORM_PACK16",
+            vk::Format::R5G6B5_UNORM_PACK16.as_raw(),
+        ),
+        (
+            "VK_FORMAT_B5G6R5_UNORM_PACK16",
+            vk::Format::B5G6R5_UNORM_PACK16.as_raw(),
+        ),
+        (
+            "VK_FORMAT_R5G5B5A1_UNORM_PACK16",
+            vk::Format::R5G5B5A1_UNORM_PACK16.as_raw(),
+        ),
+        (
+            "VK_FORMAT_B5G5R5A1_UNORM_PACK16",
+            vk::Format::B5G5R5A1_UNORM_PACK16.as_raw(),
+        ),
+        (
+            "VK_FORMAT_A1R5G5B5_UNORM_PACK16",
+            vk::Format::A1R5G5B5_UNORM_PACK16.as_raw(),
+        ),
+        ("VK_FORMAT_R8_UNORM", vk::Format::R8_UNORM.as_raw()),
+        ("VK_FORMAT_R8_SNORM", vk::Format::R8_SNORM.as_raw()),
+        ("VK_FORMAT_R8_USCALED", vk::Format::R8_USCALED.as_raw()),
+        ("VK_FORMAT_R8_SSCALED", vk::Format::R8_SSCALED.as_raw()),
+        ("VK_FORMAT_R8_UINT", vk::Format::R8_UINT.as_raw()),
+        ("VK_FORMAT_R8_SINT", vk::Format::R8_SINT.as_raw()),
+        ("VK_FORMAT_R8_SRGB", vk::Format::R8_SRGB.as_raw()),
+        ("VK_FORMAT_R8G8_UNORM", vk::Format::R8G8_UNORM.as_raw()),
+        ("VK_FORMAT_R8G8_SNORM", vk::Format::R8G8_SNORM.as_raw()),
+        ("VK_FORMAT_R8G8_USCALED", vk::Format::R8G8_USCALED.as_raw()),
+        ("VK_FORMAT_R8G8_SSCALED", vk::Format::R8G8_SSCALED.as_raw()),
+        ("VK_FORMAT_R8G8_UINT", vk::Format::R8G8_UINT.as_raw()),
+        ("VK_FORMAT_R8G8_SINT", vk::Format::R8G8_SINT.as_raw()),
+        ("VK_FORMAT_R8G8_SRGB", vk::Format::R8G8_SRGB.as_raw()),
+        ("VK_FORMAT_R8G8B8_UNORM", vk::Format::R8G8B8_UNORM.as_raw()),
+        ("VK_FORMAT_R8G8B8_SNORM", vk::Format::R8G8B8_SNORM.as_raw()),
+        (
+            "VK_FORMAT_R8G8B8_USCALED",
+            vk::Format::R8G8B8_USCALED.as_raw(),
+        ),
+        (
+            "VK_FORMAT_R8G8B8_SSCALED",
+            vk::Format::R8G8B8_SSCALED.as_raw(),
+        ),
+        ("VK_FORMAT_R8G8B8_UINT", vk::Format::R8G8B8_UINT.as_raw()),
+        ("VK_FORMAT_R8G8B8_SINT", vk::Format::R8G8B8_SINT.as_raw()),
+        ("VK_FORMAT_R8G8B8_SRGB", vk::Format::R8G8B8_SRGB.as_raw()),
+        ("VK_FORMAT_B8G8R8_UNORM", vk::Format::B8G8R8_UNORM.as_raw()),
+        ("VK_FORMAT_B8G8R8_SNORM", vk::Format::B8G8R8_SNORM.as_raw()),
+        (
+            "VK_FORMAT_B8G8R8_USCALED",
+            vk::Format::B8G8R8_USCALED.as_raw(),
+        ),
+        (
+            "VK_FORMAT_B8G8R8_SSCALED",
+            vk::Format::B8G8R8_SSCALED.as_raw(),
+        ),
+        ("VK_FORMAT_B8G8R8_UINT", vk::Format::B8G8R8_UINT.as_raw()),
+        ("VK_FORMAT_B8G8R8_SINT", vk::Format::B8G8R8_SINT.as_raw()),
+        ("VK_FORMAT_B8G8R8_SRGB", vk::Format::B8G8R8_SRGB.as_raw()),
+        (
+            "VK_FORMAT_R8G8B8A8_UNORM",
+            vk::Format::R8G8B8A8_UNORM.as_raw(),
+        ),
+        (
+            "VK_FORMAT_R8G8B8A8_SNORM",
+            vk::Format::R8G8B8A8_SNORM.as_raw(),
+        ),
+        (
+            "VK_FORMAT_R8G8B8A8_USCALED",
+            vk::Format::R8G8B8A8_USCALED.as_raw(),
+        ),
+        (
+            "VK_FORMAT_R8G8B8A8_SSCALED",
+            vk::Format::R8G8B8A8_SSCALED.as_raw(),
+        ),
+        (
+            "VK_FORMAT_R8G8B8A8_UINT",
+            vk::Format::R8G8B8A8_UINT.as_raw(),
+        ),
+        (
+            "VK_FORMAT_R8G8B8A8_SINT",
+            vk::Format::R8G8B8A8_SINT.as_raw(),
+        ),
+        (
+            "VK_FORMAT_R8G8B8A8_SRGB",
+            vk::Format::R8G8B8A8_SRGB.as_raw(),
+        ),
+        (
+            "VK_FORMAT_B8G8R8A8_UNORM",
+            vk::Format::B8G8R8A8_UNORM.as_raw(),
+        ),
+        (
+            "VK_FORMAT_B8G8R8A8_SNORM",
+            vk::Format::B8G8R8A8_SNORM.as_raw(),
+        ),
+        (
+            "VK_FORMAT_B8G8R8A8_USCALED",
+            vk::Format::B8G8R8A8_USCALED.as_raw(),
+        ),
+        (
+            "VK_FORMAT_B8G8R8A8_SSCALED",
+            vk::Format::B8G8R8A8_SSCALED.as_raw(),
+        ),
+        (
+            "VK_FORMAT_B8G8R8A8_UINT",
+            vk::Format::B8G8R8A8_UINT.as_raw(),
+        ),
+        (
+            "VK_FORMAT_B8G8R8A8_SINT",
+            vk::Format::B8G8R8A8_SINT.as_raw(),
+        ),
+        (
+            "VK_FORMAT_B8G8R8A8_SRGB",
+            vk::Format::B8G8R8A8_SRGB.as_raw(),
+        ),
+        (
+            "VK_FORMAT_A8B8G8R8_UNORM_PACK32",
+            vk::Format::A8B8G8R8_UNORM_PACK32.as_raw(),
+        ),
+        (
+            "VK_FORMAT_A8B8G8R8_SNORM_PACK32",
+            vk::Format::A8B8G8R8_SNORM_PACK32.as_raw(),
+        ),
+        (
+            "VK_FORMAT_A8B8G8R8_USCALED_PACK32",
+            vk::Format::A8B8G8R8_USCALED_PACK32.as_raw(),
+        ),
+        (
+            "VK_FORMAT_A8B8G8R8_SSCALED_PACK32",
+            vk::Format::A8B8G8R8_SSCALED_PACK32.as_raw(),
+        ),
+        (
+            "VK_FORMAT_A8B8G8R8_UINT_PACK32",
+            vk::Format::A8B8G8R8_UINT_PACK32.as_raw(),
+        ),
+        (
+            "VK_FORMAT_A8B8G8R8_SINT_PACK32",
+            vk::Format::A8B8G8R8_SINT_PACK32.as_raw(),
+        ),
+        (
+            "VK_FORMAT_A8B8G8R8_SRGB_PACK32",
+            vk::Format::A8B8G8R8_SRGB_PACK32.as_raw(),
+        ),
+        (
+            "VK_FORMAT_A2R10G10B10_UNORM_PACK32",
+            vk::Format::A2R10G10B10_UNORM_PACK32.as_raw(),
+        ),
+        (
+            "VK_FORMAT_A2R10G10B10_SNORM_PACK32",
+            vk::Format::A2R10G10B10_SNORM_PACK32.as_raw(),
+        ),
+        (
+            "VK_FORMAT_A2R10G10B10_USCALED_PACK32",
+            vk::Format::A2R10G10B10_USCALED_PACK32.as_raw(),
+        ),
+        (
+            "VK_FORMAT_A2R10G10B10_SSCALED_PACK32",
+            vk::Format::A2R10G10B10_SSCALED_PACK32.as_raw(),
+        ),
+        (
+            "VK_FORMAT_A2R10G10B10_UINT_PACK32",
+            vk::Format::A2R10G10B10_UINT_PACK32.as_raw(),
+        ),
+        (
+            "VK_FORMAT_A2R10G10B10_SINT_PACK32",
+            vk::Format::A2R10G10B10_SINT_PACK32.as_raw(),
+        ),
+        (
+            "VK_FORMAT_A2B10G10R10_UNORM_PACK32",
+            vk::Format::A2B10G10R10_UNORM_PACK32.as_raw(),
+        ),
+        (
+            "VK_FORMAT_A2B10G10R10_SNORM_PACK32",
+            vk::Format::A2B10G10R10_SNORM_PACK32.as_raw(),
+        ),
+        (
+            "VK_FORMAT_A2B10G10R10_USCALED_PACK32",
+            vk::Format::A2B10G10R10_USCALED_PACK32.as_raw(),
+        ),
+        (
+            "VK_FORMAT_A2B10G10R10_SSCALED_PACK32",
+            vk::Format::A2B10G10R10_SSCALED_PACK32.as_raw(),
+        ),
+        (
+            "VK_FORMAT_A2B10G10R10_UINT_PACK32",
+            vk::Format::A2B10G10R10_UINT_PACK32.as_raw(),
+        ),
+        (
+            "VK_FORMAT_A2B10G10R10_SINT_PACK32",
+            vk::Format::A2B10G10R10_SINT_PACK32.as_raw(),
+        ),
+        ("VK_FORMAT_R16_UNORM", vk::Format::R16_UNORM.as_raw()),
+        ("VK_FORMAT_R16_SNORM", vk::Format::R16_SNORM.as_raw()),
+        ("VK_FORMAT_R16_USCALED", vk::Format::R16_USCALED.as_raw()),
+        ("VK_FORMAT_R16_SSCALED", vk::Format::R16_SSCALED.as_raw()),
+        ("VK_FORMAT_R16_UINT", vk::Format::R16_UINT.as_raw()),
+        ("VK_FORMAT_R16_SINT", vk::Format::R16_SINT.as_raw()),
+        ("VK_FORMAT_R16_SFLOAT", vk::Format::R16_SFLOAT.as_raw()),
+        ("VK_FORMAT_R16G16_UNORM", vk::Format::R16G16_UNORM.as_raw()),
+        ("VK_FORMAT_R16G16_SNORM", vk::Format::R16G16_SNORM.as_raw()),
+        (
+            "VK_FORMAT_R16G16_USCALED",
+            vk::Format::R16G16_USCALED.as_raw(),
+        ),
+        (
+            "VK_FORMAT_R16G16_SSCALED",
+            vk::Format::R16G16_SSCALED.as_raw(),
+        ),
+        ("VK_FORMAT_R16G16_UINT", vk::Format::R16G16_UINT.as_raw()),
+        ("VK_FORMAT_R16G16_SINT", vk::Format::R16G16_SINT.as_raw()),
+        (
+            "VK_FORMAT_R16G16_SFLOAT",
+            vk::Format::R16G16_SFLOAT.as_raw(),
+        ),
+        (
+            "VK_FORMAT_R16G16B16_UNORM",
+            vk::Format::R16G16B16_UNORM.as_raw(),
+        ),
+        (
+            "VK_FORMAT_R16G16B16_SNORM",
+            vk::Format::R16G16B16_SNORM.as_raw(),
+        ),
+        (
+            "VK_FORMAT_R16G16B16_USCALED",
+            vk::Format::R16G16B16_USCALED.as_raw(),
+        ),
+        (
+            "VK_FORMAT_R16G16B16_SSCALED",
+            vk::Format::R16G16B16_SSCALED.as_raw(),
+        ),
+        (
+            "VK_FORMAT_R16G16B16_UINT",
+            vk::Format::R16G16B16_UINT.as_raw(),
+        ),
+        (
+            "VK_FORMAT_R16G16B16_SINT",
+            vk::Format::R16G16B16_SINT.as_raw(),
+        ),
+        (
+            "VK_FORMAT_R16G16B16_SFLOAT",
+            vk::Format::R16G16B16_SFLOAT.as_raw(),
+        ),
+        (
+            "VK_FORMAT_R16G16B16A16_UNORM",
+            vk::Format::R16G16B16A16_UNORM.as_raw(),
+        ),
+        (
+            "VK_FORMAT_R16G16B16A16_SNORM",
+            vk::Format::R16G16B16A16_SNORM.as_raw(),
+        ),
+        (
+            "VK_FORMAT_R16G16B16A16_USCALED",
+            vk::Format::R16G16B16A16_USCALED.as_raw(),
+        ),
+        (
+            "VK_FORMAT_R16G16B16A16_SSCALED",
+            vk::Format::R16G16B16A16_SSCALED.as_raw(),
+        ),
+        (
+            "VK_FORMAT_R16G16B16A16_UINT",
+            vk::Format::R16G16B16A16_UINT.as_raw(),
+        ),
+        (
+            "VK_FORMAT_R16G16B16A16_SINT",
+            vk::Format::R16G16B16A16_SINT.as_raw(),
+        ),
+        (
+            "VK_FORMAT_R16G16B16A16_SFLOAT",
+            vk::Format::R16G16B16A16_SFLOAT.as_raw(),
+        ),
+        ("VK_FORMAT_R32_UINT", vk::Format::R32_UINT.as_raw()),
+        ("VK_FORMAT_R32_SINT", vk::Format::R32_SINT.as_raw()),
+        ("VK_FORMAT_R32_SFLOAT", vk::Format::R32_SFLOAT.as_raw()),
+        ("VK_FORMAT_R32G32_UINT", vk::Format::R32G32_UINT.as_raw()),
+        ("VK_FORMAT_R32G32_SINT", vk::Format::R32G32_SINT.as_raw()),
+        (
+            "VK_FORMAT_R32G32_SFLOAT",
+            vk::Format::R32G32_SFLOAT.as_raw(),
+        ),
+        (
+            "VK_FORMAT_R32G32B32_UINT",
+            vk::Format::R32G32B32_UINT.as_raw(),
+        ),
+        (
+            "VK_FORMAT_R32G32B32_SINT",
+            vk::Format::R32G32B32_SINT.as_raw(),
+        ),
+        (
+            "VK_FORMAT_R32G32B32_SFLOAT",
+            vk::Format::R32G32B32_SFLOAT.as_raw(),
+        ),
+        (
+            "VK_FORMAT_R32G32B32A32_UINT",
+            vk::Format::R32G32B32A32_UINT.as_raw(),
+        ),
+        (
+            "VK_FORMAT_R32G32B32A32_SINT",
+            vk::Format::R32G32B32A32_SINT.as_raw(),
+        ),
+        (
+            "VK_FORMAT_R32G32B32A32_SFLOAT",
+            vk::Format::R32G32B32A32_SFLOAT.as_raw(),
+        ),
+        ("VK_FORMAT_R64_UINT", vk::Format::R64_UINT.as_raw()),
+        ("VK_FORMAT_R64_SINT", vk::Format::R64_SINT.as_raw()),
+        ("VK_FORMAT_R64_SFLOAT", vk::Format::R64_SFLOAT.as_raw()),
+        ("VK_FORMAT_R64G64_UINT", vk::Format::R64G64_UINT.as_raw()),
+        ("VK_FORMAT_R64G64_SINT", vk::Format::R64G64_SINT.as_raw()),
+        (
+            "VK_FORMAT_R64G64_SFLOAT",
+            vk::Format::R64G64_SFLOAT.as_raw(),
+        ),
+        (
+            "VK_FORMAT_R64G64B64_UINT",
+            vk::Format::R64G64B64_UINT.as_raw(),
+        ),
+        (
+            "VK_FORMAT_R64G64B64_SINT",
+            vk::Format::R64G64B64_SINT.as_raw(),
+        ),
+        (
+            "VK_FORMAT_R64G64B64_SFLOAT",
+            vk::Format::R64G64B64_SFLOAT.as_raw(),
+        ),
+        (
+            "VK_FORMAT_R64G64B64A64_UINT",
+            vk::Format::R64G64B64A64_UINT.as_raw(),
+        ),
+        (
+            "VK_FORMAT_R64G64B64A64_SINT",
+            vk::Format::R64G64B64A64_SINT.as_raw(),
+        ),
+        (
+            "VK_FORMAT_R64G64B64A64_SFLOAT",
+            vk::Format::R64G64B64A64_SFLOAT.as_raw(),
+        ),
+        (
+            "VK_FORMAT_B10G11R11_UFLOAT_PACK32",
+            vk::Format::B10G11R11_UFLOAT_PACK32.as_raw(),
+        ),
+        (
+            "VK_FORMAT_E5B9G9R9_UFLOAT_PACK32",
+            vk::Format::E5B9G9R9_UFLOAT_PACK32.as_raw(),
+        ),
+        ("VK_FORMAT_D16_UNORM", vk::Format::D16_UNORM.as_raw()),
+        (
+            "VK_FORMAT_X8_D24_UNORM_PACK32",
+            vk::Format::X8_D24_UNORM_PACK32.as_raw(),
+        ),
+        ("VK_FORMAT_D32_SFLOAT", vk::Format::D32_SFLOAT.as_raw()),
+        ("VK_FORMAT_S8_UINT", vk::Format::S8_UINT.as_raw()),
+        (
+            "VK_FORMAT_D16_UNORM_S8_UINT",
+            vk::Format::D16_UNORM_S8_UINT.as_raw(),
+        ),
+        (
+            "VK_FORMAT_D24_UNORM_S8_UINT",
+            vk::Format::D24_UNORM_S8_UINT.as_raw(),
+        ),
+        (
+            "VK_FORMAT_D32_SFLOAT_S8_UINT",
+            vk::Format::D32_SFLOAT_S8_UINT.as_raw(),
+        ),
+        (
+            "VK_FORMAT_BC1_RGB_UNORM_BLOCK",
+            vk::Format::BC1_RGB_UNORM_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_BC1_RGB_SRGB_BLOCK",
+            vk::Format::BC1_RGB_SRGB_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_BC1_RGBA_UNORM_BLOCK",
+            vk::Format::BC1_RGBA_UNORM_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_BC1_RGBA_SRGB_BLOCK",
+            vk::Format::BC1_RGBA_SRGB_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_BC2_UNORM_BLOCK",
+            vk::Format::BC2_UNORM_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_BC2_SRGB_BLOCK",
+            vk::Format::BC2_SRGB_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_BC3_UNORM_BLOCK",
+            vk::Format::BC3_UNORM_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_BC3_SRGB_BLOCK",
+            vk::Format::BC3_SRGB_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_BC4_UNORM_BLOCK",
+            vk::Format::BC4_UNORM_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_BC4_SNORM_BLOCK",
+            vk::Format::BC4_SNORM_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_BC5_UNORM_BLOCK",
+            vk::Format::BC5_UNORM_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_BC5_SNORM_BLOCK",
+            vk::Format::BC5_SNORM_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_BC6H_UFLOAT_BLOCK",
+            vk::Format::BC6H_UFLOAT_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_BC6H_SFLOAT_BLOCK",
+            vk::Format::BC6H_SFLOAT_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_BC7_UNORM_BLOCK",
+            vk::Format::BC7_UNORM_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_BC7_SRGB_BLOCK",
+            vk::Format::BC7_SRGB_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ETC2_R8G8B8_UNORM_BLOCK",
+            vk::Format::ETC2_R8G8B8_UNORM_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ETC2_R8G8B8_SRGB_BLOCK",
+            vk::Format::ETC2_R8G8B8_SRGB_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ETC2_R8G8B8A1_UNORM_BLOCK",
+            vk::Format::ETC2_R8G8B8A1_UNORM_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ETC2_R8G8B8A1_SRGB_BLOCK",
+            vk::Format::ETC2_R8G8B8A1_SRGB_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ETC2_R8G8B8A8_UNORM_BLOCK",
+            vk::Format::ETC2_R8G8B8A8_UNORM_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ETC2_R8G8B8A8_SRGB_BLOCK",
+            vk::Format::ETC2_R8G8B8A8_SRGB_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_EAC_R11_UNORM_BLOCK",
+            vk::Format::EAC_R11_UNORM_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_EAC_R11_SNORM_BLOCK",
+            vk::Format::EAC_R11_SNORM_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_EAC_R11G11_UNORM_BLOCK",
+            vk::Format::EAC_R11G11_UNORM_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_EAC_R11G11_SNORM_BLOCK",
+            vk::Format::EAC_R11G11_SNORM_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_4x4_UNORM_BLOCK",
+            vk::Format::ASTC_4X4_UNORM_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_4x4_SRGB_BLOCK",
+            vk::Format::ASTC_4X4_SRGB_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_5x4_UNORM_BLOCK",
+            vk::Format::ASTC_5X4_UNORM_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_5x4_SRGB_BLOCK",
+            vk::Format::ASTC_5X4_SRGB_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_5x5_UNORM_BLOCK",
+            vk::Format::ASTC_5X5_UNORM_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_5x5_SRGB_BLOCK",
+            vk::Format::ASTC_5X5_SRGB_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_6x5_UNORM_BLOCK",
+            vk::Format::ASTC_6X5_UNORM_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_6x5_SRGB_BLOCK",
+            vk::Format::ASTC_6X5_SRGB_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_6x6_UNORM_BLOCK",
+            vk::Format::ASTC_6X6_UNORM_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_6x6_SRGB_BLOCK",
+            vk::Format::ASTC_6X6_SRGB_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_8x5_UNORM_BLOCK",
+            vk::Format::ASTC_8X5_UNORM_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_8x5_SRGB_BLOCK",
+            vk::Format::ASTC_8X5_SRGB_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_8x6_UNORM_BLOCK",
+            vk::Format::ASTC_8X6_UNORM_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_8x6_SRGB_BLOCK",
+            vk::Format::ASTC_8X6_SRGB_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_8x8_UNORM_BLOCK",
+            vk::Format::ASTC_8X8_UNORM_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_8x8_SRGB_BLOCK",
+            vk::Format::ASTC_8X8_SRGB_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_10x5_UNORM_BLOCK",
+            vk::Format::ASTC_10X5_UNORM_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_10x5_SRGB_BLOCK",
+            vk::Format::ASTC_10X5_SRGB_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_10x6_UNORM_BLOCK",
+            vk::Format::ASTC_10X6_UNORM_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_10x6_SRGB_BLOCK",
+            vk::Format::ASTC_10X6_SRGB_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_10x8_UNORM_BLOCK",
+            vk::Format::ASTC_10X8_UNORM_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_10x8_SRGB_BLOCK",
+            vk::Format::ASTC_10X8_SRGB_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_10x10_UNORM_BLOCK",
+            vk::Format::ASTC_10X10_UNORM_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_10x10_SRGB_BLOCK",
+            vk::Format::ASTC_10X10_SRGB_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_12x10_UNORM_BLOCK",
+            vk::Format::ASTC_12X10_UNORM_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_12x10_SRGB_BLOCK",
+            vk::Format::ASTC_12X10_SRGB_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_12x12_UNORM_BLOCK",
+            vk::Format::ASTC_12X12_UNORM_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_12x12_SRGB_BLOCK",
+            vk::Format::ASTC_12X12_SRGB_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_PVRTC1_2BPP_UNORM_BLOCK_IMG",
+            vk::Format::PVRTC1_2BPP_UNORM_BLOCK_IMG.as_raw(),
+        ),
+        (
+            "VK_FORMAT_PVRTC1_4BPP_UNORM_BLOCK_IMG",
+            vk::Format::PVRTC1_4BPP_UNORM_BLOCK_IMG.as_raw(),
+        ),
+        (
+            "VK_FORMAT_PVRTC2_2BPP_UNORM_BLOCK_IMG",
+            vk::Format::PVRTC2_2BPP_UNORM_BLOCK_IMG.as_raw(),
+        ),
+        (
+            "VK_FORMAT_PVRTC2_4BPP_UNORM_BLOCK_IMG",
+            vk::Format::PVRTC2_4BPP_UNORM_BLOCK_IMG.as_raw(),
+        ),
+        (
+            "VK_FORMAT_PVRTC1_2BPP_SRGB_BLOCK_IMG",
+            vk::Format::PVRTC1_2BPP_SRGB_BLOCK_IMG.as_raw(),
+        ),
+        (
+            "VK_FORMAT_PVRTC1_4BPP_SRGB_BLOCK_IMG",
+            vk::Format::PVRTC1_4BPP_SRGB_BLOCK_IMG.as_raw(),
+        ),
+        (
+            "VK_FORMAT_PVRTC2_2BPP_SRGB_BLOCK_IMG",
+            vk::Format::PVRTC2_2BPP_SRGB_BLOCK_IMG.as_raw(),
+        ),
+        (
+            "VK_FORMAT_PVRTC2_4BPP_SRGB_BLOCK_IMG",
+            vk::Format::PVRTC2_4BPP_SRGB_BLOCK_IMG.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_4x4_SFLOAT_BLOCK_EXT",
+            vk::Format::ASTC_4X4_SFLOAT_BLOCK_EXT.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_5x4_SFLOAT_BLOCK_EXT",
+            vk::Format::ASTC_5X4_SFLOAT_BLOCK_EXT.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_5x5_SFLOAT_BLOCK_EXT",
+            vk::Format::ASTC_5X5_SFLOAT_BLOCK_EXT.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_6x5_SFLOAT_BLOCK_EXT",
+            vk::Format::ASTC_6X5_SFLOAT_BLOCK_EXT.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_6x6_SFLOAT_BLOCK_EXT",
+            vk::Format::ASTC_6X6_SFLOAT_BLOCK_EXT.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_8x5_SFLOAT_BLOCK_EXT",
+            vk::Format::ASTC_8X5_SFLOAT_BLOCK_EXT.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_8x6_SFLOAT_BLOCK_EXT",
+            vk::Format::ASTC_8X6_SFLOAT_BLOCK_EXT.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_8x8_SFLOAT_BLOCK_EXT",
+            vk::Format::ASTC_8X8_SFLOAT_BLOCK_EXT.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_10x5_SFLOAT_BLOCK_EXT",
+            vk::Format::ASTC_10X5_SFLOAT_BLOCK_EXT.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_10x6_SFLOAT_BLOCK_EXT",
+            vk::Format::ASTC_10X6_SFLOAT_BLOCK_EXT.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_10x8_SFLOAT_BLOCK_EXT",
+            vk::Format::ASTC_10X8_SFLOAT_BLOCK_EXT.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_10x10_SFLOAT_BLOCK_EXT",
+            vk::Format::ASTC_10X10_SFLOAT_BLOCK_EXT.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_12x10_SFLOAT_BLOCK_EXT",
+            vk::Format::ASTC_12X10_SFLOAT_BLOCK_EXT.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_12x12_SFLOAT_BLOCK_EXT",
+            vk::Format::ASTC_12X12_SFLOAT_BLOCK_EXT.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G8B8G8R8_422_UNORM_KHR",
+            vk::Format::G8B8G8R8_422_UNORM_KHR.as_raw(),
+        ),
+        (
+            "VK_FORMAT_B8G8R8G8_422_UNORM_KHR",
+            vk::Format::B8G8R8G8_422_UNORM_KHR.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G8_B8_R8_3PLANE_420_UNORM_KHR",
+            vk::Format::G8_B8_R8_3PLANE_420_UNORM_KHR.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G8_B8R8_2PLANE_420_UNORM_KHR",
+            vk::Format::G8_B8R8_2PLANE_420_UNORM_KHR.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G8_B8_R8_3PLANE_422_UNORM_KHR",
+            vk::Format::G8_B8_R8_3PLANE_422_UNORM_KHR.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G8_B8R8_2PLANE_422_UNORM_KHR",
+            vk::Format::G8_B8R8_2PLANE_422_UNORM_KHR.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G8_B8_R8_3PLANE_444_UNORM_KHR",
+            vk::Format::G8_B8_R8_3PLANE_444_UNORM_KHR.as_raw(),
+        ),
+        (
+            "VK_FORMAT_R10X6_UNORM_PACK16_KHR",
+            vk::Format::R10X6_UNORM_PACK16_KHR.as_raw(),
+        ),
+        (
+            "VK_FORMAT_R10X6G10X6_UNORM_2PACK16_KHR",
+            vk::Format::R10X6G10X6_UNORM_2PACK16_KHR.as_raw(),
+        ),
+        (
+            "VK_FORMAT_R10X6G10X6B10X6A10X6_UNORM_4PACK16_KHR",
+            vk::Format::R10X6G10X6B10X6A10X6_UNORM_4PACK16_KHR.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G10X6B10X6G10X6R10X6_422_UNORM_4PACK16_KHR",
+            vk::Format::G10X6B10X6G10X6R10X6_422_UNORM_4PACK16_KHR.as_raw(),
+        ),
+        (
+            "VK_FORMAT_B10X6G10X6R10X6G10X6_422_UNORM_4PACK16_KHR",
+            vk::Format::B10X6G10X6R10X6G10X6_422_UNORM_4PACK16_KHR.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G10X6_B10X6_R10X6_3PLANE_420_UNORM_3PACK16_KHR",
+            vk::Format::G10X6_B10X6_R10X6_3PLANE_420_UNORM_3PACK16_KHR.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G10X6_B10X6R10X6_2PLANE_420_UNORM_3PACK16_KHR",
+            vk::Format::G10X6_B10X6R10X6_2PLANE_420_UNORM_3PACK16_KHR.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G10X6_B10X6_R10X6_3PLANE_422_UNORM_3PACK16_KHR",
+            vk::Format::G10X6_B10X6_R10X6_3PLANE_422_UNORM_3PACK16_KHR.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G10X6_B10X6R10X6_2PLANE_422_UNORM_3PACK16_KHR",
+            vk::Format::G10X6_B10X6R10X6_2PLANE_422_UNORM_3PACK16_KHR.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G10X6_B10X6_R10X6_3PLANE_444_UNORM_3PACK16_KHR",
+            vk::Format::G10X6_B10X6_R10X6_3PLANE_444_UNORM_3PACK16_KHR.as_raw(),
+        ),
+        (
+            "VK_FORMAT_R12X4_UNORM_PACK16_KHR",
+            vk::Format::R12X4_UNORM_PACK16_KHR.as_raw(),
+        ),
+        (
+            "VK_FORMAT_R12X4G12X4_UNORM_2PACK16_KHR",
+            vk::Format::R12X4G12X4_UNORM_2PACK16_KHR.as_raw(),
+        ),
+        (
+            "VK_FORMAT_R12X4G12X4B12X4A12X4_UNORM_4PACK16_KHR",
+            vk::Format::R12X4G12X4B12X4A12X4_UNORM_4PACK16_KHR.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G12X4B12X4G12X4R12X4_422_UNORM_4PACK16_KHR",
+            vk::Format::G12X4B12X4G12X4R12X4_422_UNORM_4PACK16_KHR.as_raw(),
+        ),
+        (
+            "VK_FORMAT_B12X4G12X4R12X4G12X4_422_UNORM_4PACK16_KHR",
+            vk::Format::B12X4G12X4R12X4G12X4_422_UNORM_4PACK16_KHR.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G12X4_B12X4_R12X4_3PLANE_420_UNORM_3PACK16_KHR",
+            vk::Format::G12X4_B12X4_R12X4_3PLANE_420_UNORM_3PACK16_KHR.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G12X4_B12X4R12X4_2PLANE_420_UNORM_3PACK16_KHR",
+            vk::Format::G12X4_B12X4R12X4_2PLANE_420_UNORM_3PACK16_KHR.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G12X4_B12X4_R12X4_3PLANE_422_UNORM_3PACK16_KHR",
+            vk::Format::G12X4_B12X4_R12X4_3PLANE_422_UNORM_3PACK16_KHR.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G12X4_B12X4R12X4_2PLANE_422_UNORM_3PACK16_KHR",
+            vk::Format::G12X4_B12X4R12X4_2PLANE_422_UNORM_3PACK16_KHR.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G12X4_B12X4_R12X4_3PLANE_444_UNORM_3PACK16_KHR",
+            vk::Format::G12X4_B12X4_R12X4_3PLANE_444_UNORM_3PACK16_KHR.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G16B16G16R16_422_UNORM_KHR",
+            vk::Format::G16B16G16R16_422_UNORM_KHR.as_raw(),
+        ),
+        (
+            "VK_FORMAT_B16G16R16G16_422_UNORM_KHR",
+            vk::Format::B16G16R16G16_422_UNORM_KHR.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G16_B16_R16_3PLANE_420_UNORM_KHR",
+            vk::Format::G16_B16_R16_3PLANE_420_UNORM_KHR.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G16_B16R16_2PLANE_420_UNORM_KHR",
+            vk::Format::G16_B16R16_2PLANE_420_UNORM_KHR.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G16_B16_R16_3PLANE_422_UNORM_KHR",
+            vk::Format::G16_B16_R16_3PLANE_422_UNORM_KHR.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G16_B16R16_2PLANE_422_UNORM_KHR",
+            vk::Format::G16_B16R16_2PLANE_422_UNORM_KHR.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G16_B16_R16_3PLANE_444_UNORM_KHR",
+            vk::Format::G16_B16_R16_3PLANE_444_UNORM_KHR.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G8_B8R8_2PLANE_444_UNORM_EXT",
+            vk::Format::G8_B8R8_2PLANE_444_UNORM_EXT.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G10X6_B10X6R10X6_2PLANE_444_UNORM_3PACK16_EXT",
+            vk::Format::G10X6_B10X6R10X6_2PLANE_444_UNORM_3PACK16_EXT.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G12X4_B12X4R12X4_2PLANE_444_UNORM_3PACK16_EXT",
+            vk::Format::G12X4_B12X4R12X4_2PLANE_444_UNORM_3PACK16_EXT.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G16_B16R16_2PLANE_444_UNORM_EXT",
+            vk::Format::G16_B16R16_2PLANE_444_UNORM_EXT.as_raw(),
+        ),
+        (
+            "VK_FORMAT_A4R4G4B4_UNORM_PACK16_EXT",
+            vk::Format::A4R4G4B4_UNORM_PACK16_EXT.as_raw(),
+        ),
+        (
+            "VK_FORMAT_A4B4G4R4_UNORM_PACK16_EXT",
+            vk::Format::A4B4G4R4_UNORM_PACK16_EXT.as_raw(),
+        ),
+        (
+            "VK_FORMAT_R16G16_S10_5_NV",
+            vk::Format::R16G16_S10_5_NV.as_raw(),
+        ),
+        (
+            "VK_FORMAT_A1B5G5R5_UNORM_PACK16_KHR",
+            vk::Format::A1B5G5R5_UNORM_PACK16_KHR.as_raw(),
+        ),
+        ("VK_FORMAT_A8_UNORM_KHR", vk::Format::A8_UNORM_KHR.as_raw()),
+        (
+            "VK_FORMAT_G8B8G8R8_422_UNORM",
+            vk::Format::G8B8G8R8_422_UNORM.as_raw(),
+        ),
+        (
+            "VK_FORMAT_B8G8R8G8_422_UNORM",
+            vk::Format::B8G8R8G8_422_UNORM.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G8_B8_R8_3PLANE_420_UNORM",
+            vk::Format::G8_B8_R8_3PLANE_420_UNORM.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G8_B8R8_2PLANE_420_UNORM",
+            vk::Format::G8_B8R8_2PLANE_420_UNORM.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G8_B8_R8_3PLANE_422_UNORM",
+            vk::Format::G8_B8_R8_3PLANE_422_UNORM.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G8_B8R8_2PLANE_422_UNORM",
+            vk::Format::G8_B8R8_2PLANE_422_UNORM.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G8_B8_R8_3PLANE_444_UNORM",
+            vk::Format::G8_B8_R8_3PLANE_444_UNORM.as_raw(),
+        ),
+        (
+            "VK_FORMAT_R10X6_UNORM_PACK16",
+            vk::Format::R10X6_UNORM_PACK16.as_raw(),
+        ),
+        (
+            "VK_FORMAT_R10X6G10X6_UNORM_2PACK16",
+            vk::Format::R10X6G10X6_UNORM_2PACK16.as_raw(),
+        ),
+        (
+            "VK_FORMAT_R10X6G10X6B10X6A10X6_UNORM_4PACK16",
+            vk::Format::R10X6G10X6B10X6A10X6_UNORM_4PACK16.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G10X6B10X6G10X6R10X6_422_UNORM_4PACK16",
+            vk::Format::G10X6B10X6G10X6R10X6_422_UNORM_4PACK16.as_raw(),
+        ),
+        (
+            "VK_FORMAT_B10X6G10X6R10X6G10X6_422_UNORM_4PACK16",
+            vk::Format::B10X6G10X6R10X6G10X6_422_UNORM_4PACK16.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G10X6_B10X6_R10X6_3PLANE_420_UNORM_3PACK16",
+            vk::Format::G10X6_B10X6_R10X6_3PLANE_420_UNORM_3PACK16.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G10X6_B10X6R10X6_2PLANE_420_UNORM_3PACK16",
+            vk::Format::G10X6_B10X6R10X6_2PLANE_420_UNORM_3PACK16.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G10X6_B10X6_R10X6_3PLANE_422_UNORM_3PACK16",
+            vk::Format::G10X6_B10X6_R10X6_3PLANE_422_UNORM_3PACK16.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G10X6_B10X6R10X6_2PLANE_422_UNORM_3PACK16",
+            vk::Format::G10X6_B10X6R10X6_2PLANE_422_UNORM_3PACK16.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G10X6_B10X6_R10X6_3PLANE_444_UNORM_3PACK16",
+            vk::Format::G10X6_B10X6_R10X6_3PLANE_444_UNORM_3PACK16.as_raw(),
+        ),
+        (
+            "VK_FORMAT_R12X4_UNORM_PACK16",
+            vk::Format::R12X4_UNORM_PACK16.as_raw(),
+        ),
+        (
+            "VK_FORMAT_R12X4G12X4_UNORM_2PACK16",
+            vk::Format::R12X4G12X4_UNORM_2PACK16.as_raw(),
+        ),
+        (
+            "VK_FORMAT_R12X4G12X4B12X4A12X4_UNORM_4PACK16",
+            vk::Format::R12X4G12X4B12X4A12X4_UNORM_4PACK16.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G12X4B12X4G12X4R12X4_422_UNORM_4PACK16",
+            vk::Format::G12X4B12X4G12X4R12X4_422_UNORM_4PACK16.as_raw(),
+        ),
+        (
+            "VK_FORMAT_B12X4G12X4R12X4G12X4_422_UNORM_4PACK16",
+            vk::Format::B12X4G12X4R12X4G12X4_422_UNORM_4PACK16.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G12X4_B12X4_R12X4_3PLANE_420_UNORM_3PACK16",
+            vk::Format::G12X4_B12X4_R12X4_3PLANE_420_UNORM_3PACK16.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G12X4_B12X4R12X4_2PLANE_420_UNORM_3PACK16",
+            vk::Format::G12X4_B12X4R12X4_2PLANE_420_UNORM_3PACK16.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G12X4_B12X4_R12X4_3PLANE_422_UNORM_3PACK16",
+            vk::Format::G12X4_B12X4_R12X4_3PLANE_422_UNORM_3PACK16.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G12X4_B12X4R12X4_2PLANE_422_UNORM_3PACK16",
+            vk::Format::G12X4_B12X4R12X4_2PLANE_422_UNORM_3PACK16.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G12X4_B12X4_R12X4_3PLANE_444_UNORM_3PACK16",
+            vk::Format::G12X4_B12X4_R12X4_3PLANE_444_UNORM_3PACK16.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G16B16G16R16_422_UNORM",
+            vk::Format::G16B16G16R16_422_UNORM.as_raw(),
+        ),
+        (
+            "VK_FORMAT_B16G16R16G16_422_UNORM",
+            vk::Format::B16G16R16G16_422_UNORM.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G16_B16_R16_3PLANE_420_UNORM",
+            vk::Format::G16_B16_R16_3PLANE_420_UNORM.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G16_B16R16_2PLANE_420_UNORM",
+            vk::Format::G16_B16R16_2PLANE_420_UNORM.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G16_B16_R16_3PLANE_422_UNORM",
+            vk::Format::G16_B16_R16_3PLANE_422_UNORM.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G16_B16R16_2PLANE_422_UNORM",
+            vk::Format::G16_B16R16_2PLANE_422_UNORM.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G16_B16_R16_3PLANE_444_UNORM",
+            vk::Format::G16_B16_R16_3PLANE_444_UNORM.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G8_B8R8_2PLANE_444_UNORM",
+            vk::Format::G8_B8R8_2PLANE_444_UNORM.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G10X6_B10X6R10X6_2PLANE_444_UNORM_3PACK16",
+            vk::Format::G10X6_B10X6R10X6_2PLANE_444_UNORM_3PACK16.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G12X4_B12X4R12X4_2PLANE_444_UNORM_3PACK16",
+            vk::Format::G12X4_B12X4R12X4_2PLANE_444_UNORM_3PACK16.as_raw(),
+        ),
+        (
+            "VK_FORMAT_G16_B16R16_2PLANE_444_UNORM",
+            vk::Format::G16_B16R16_2PLANE_444_UNORM.as_raw(),
+        ),
+        (
+            "VK_FORMAT_A4R4G4B4_UNORM_PACK16",
+            vk::Format::A4R4G4B4_UNORM_PACK16.as_raw(),
+        ),
+        (
+            "VK_FORMAT_A4B4G4R4_UNORM_PACK16",
+            vk::Format::A4B4G4R4_UNORM_PACK16.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_4x4_SFLOAT_BLOCK",
+            vk::Format::ASTC_4X4_SFLOAT_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_5x4_SFLOAT_BLOCK",
+            vk::Format::ASTC_5X4_SFLOAT_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_5x5_SFLOAT_BLOCK",
+            vk::Format::ASTC_5X5_SFLOAT_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_6x5_SFLOAT_BLOCK",
+            vk::Format::ASTC_6X5_SFLOAT_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_6x6_SFLOAT_BLOCK",
+            vk::Format::ASTC_6X6_SFLOAT_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_8x5_SFLOAT_BLOCK",
+            vk::Format::ASTC_8X5_SFLOAT_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_8x6_SFLOAT_BLOCK",
+            vk::Format::ASTC_8X6_SFLOAT_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_8x8_SFLOAT_BLOCK",
+            vk::Format::ASTC_8X8_SFLOAT_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_10x5_SFLOAT_BLOCK",
+            vk::Format::ASTC_10X5_SFLOAT_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_10x6_SFLOAT_BLOCK",
+            vk::Format::ASTC_10X6_SFLOAT_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_10x8_SFLOAT_BLOCK",
+            vk::Format::ASTC_10X8_SFLOAT_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_10x10_SFLOAT_BLOCK",
+            vk::Format::ASTC_10X10_SFLOAT_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_12x10_SFLOAT_BLOCK",
+            vk::Format::ASTC_12X10_SFLOAT_BLOCK.as_raw(),
+        ),
+        (
+            "VK_FORMAT_ASTC_12x12_SFLOAT_BLOCK",
+            vk::Format::ASTC_12X12_SFLOAT_BLOCK.as_raw(),
+        ),
+    ],
+};
+
+pub(super) static LAYERED_DRIVER_UNDERLYING_API_MSFT: EnumInfo = EnumInfo {
+    name: "VkLayeredDriverUnderlyingApiMSFT",
+    values: &[
+        (
+            "VK_LAYERED_DRIVER_UNDERLYING_API_NONE_MSFT",
+            vk::LayeredDriverUnderlyingApiMSFT::NONE.as_raw(),
+        ),
+        (
+            "VK_LAYERED_DRIVER_UNDERLYING_API_D3D12_MSFT",
+            vk::LayeredDriverUnderlyingApiMSFT::D3D12.as_raw(),
+        ),
+    ],
+};
+
+pub(super) static PHYSICAL_DEVICE_TYPE: EnumInfo = EnumInfo {
+    name: "VkPhysicalDeviceType",
+    values: &[
+        (
+            "VK_PHYSICAL_DEVICE_TYPE_OTHER",
+            vk::PhysicalDeviceType::OTHER.as_raw(),
+        ),
+        (
+            "VK_PHYSICAL_DEVICE_TYPE_INTEGRATED_GPU",
+            vk::PhysicalDeviceType::INTEGRATED_GPU.as_raw(),
+        ),
+        (
+            "VK_PHYSICAL_DEVICE_TYPE_DISCRETE_GPU",
+            vk::PhysicalDeviceType::DISCRETE_GPU.as_raw(),
+        ),
+        (
+            "VK_PHYSICAL_DEVICE_TYPE_VIRTUAL_GPU",
+            vk::PhysicalDeviceType::VIRTUAL_GPU.as_raw(),
+        ),
+        (
+            "VK_PHYSICAL_DEVICE_TYPE_CPU",
+            vk::PhysicalDeviceType::CPU.as_raw(),
+        ),
+    ],
+};
+
+pub(super) static PIPELINE_ROBUSTNESS_BUFFER_BEHAVIOR_EXT: EnumInfo = EnumInfo {
+    name: "VkPipelineRobustnessBufferBehaviorEXT",
+    values: &[
+        (
+            "VK_PIPELINE_ROBUSTNESS_BUFFER_BEHAVIOR_DEVICE_DEFAULT_EXT",
+            vk::PipelineRobustnessBufferBehaviorEXT::DEVICE_DEFAULT.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_ROBUSTNESS_BUFFER_BEHAVIOR_DISABLED_EXT",
+            vk::PipelineRobustnessBufferBehaviorEXT::DISABLED.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_ROBUSTNESS_BUFFER_BEHAVIOR_ROBUST_BUFFER_ACCESS_EXT",
+            vk::PipelineRobustnessBufferBehaviorEXT::ROBUST_BUFFER_ACCESS.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_ROBUSTNESS_BUFFER_BEHAVIOR_ROBUST_BUFFER_ACCESS_2_EXT",
+            vk::PipelineRobustnessBufferBehaviorEXT::ROBUST_BUFFER_ACCESS_2.as_raw(),
+        ),
+    ],
+};
+
+pub(super) static PIPELINE_ROBUSTNESS_IMAGE_BEHAVIOR_EXT: EnumInfo = EnumInfo {
+    name: "VkPipelineRobustnessImageBehaviorEXT",
+    values: &[
+        (
+            "VK_PIPELINE_ROBUSTNESS_IMAGE_BEHAVIOR_DEVICE_DEFAULT_EXT",
+            vk::PipelineRobustnessImageBehaviorEXT::DEVICE_DEFAULT.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_ROBUSTNESS_IMAGE_BEHAVIOR_DISABLED_EXT",
+            vk::PipelineRobustnessImageBehaviorEXT::DISABLED.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_ROBUSTNESS_IMAGE_BEHAVIOR_ROBUST_IMAGE_ACCESS_EXT",
+            vk::PipelineRobustnessImageBehaviorEXT::ROBUST_IMAGE_ACCESS.as_raw(),
+        ),
+        (
+            "VK_PIPELINE_ROBUSTNESS_IMAGE_BEHAVIOR_ROBUST_IMAGE_ACCESS_2_EXT",
+            vk::PipelineRobustnessImageBehaviorEXT::ROBUST_IMAGE_ACCESS_2.as_raw(),
+        ),
+    ],
+};
+
+pub(super) static POINT_CLIPPING_BEHAVIOR: EnumInfo = EnumInfo {
+    name: "VkPointClippingBehavior",
+    values: &[
+        (
+            "VK_POINT_CLIPPING_BEHAVIOR_ALL_CLIP_PLANES",
+            vk::PointClippingBehavior::ALL_CLIP_PLANES.as_raw(),
+        ),
+        (
+            "VK_POINT_CLIPPING_BEHAVIOR_USER_CLIP_PLANES_ONLY",
+            vk::PointClippingBehavior::USER_CLIP_PLANES_ONLY.as_raw(),
+        ),
+        (
+            "VK_POINT_CLIPPING_BEHAVIOR_ALL_CLIP_PLANES_KHR",
+            vk::PointClippingBehavior::ALL_CLIP_PLANES_KHR.as_raw(),
+        ),
+        (
+            "VK_POINT_CLIPPING_BEHAVIOR_USER_CLIP_PLANES_ONLY_KHR",
+            vk::PointClippingBehavior::USER_CLIP_PLANES_ONLY_KHR.as_raw(),
+        ),
+    ],
+};
+
+pub(super) static QUEUE_GLOBAL_PRIORITY_KHR: EnumInfo = EnumInfo {
+    name: "VkQueueGlobalPriorityKHR",
+    values: &[
+        (
+            "VK_QUEUE_GLOBAL_PRIORITY_LOW_KHR",
+            vk::QueueGlobalPriorityKHR::LOW.as_raw(),
+        ),
+        (
+            "VK_QUEUE_GLOBAL_PRIORITY_MEDIUM_KHR",
+            vk::QueueGlobalPriorityKHR::MEDIUM.as_raw(),
+        ),
+        (
+            "VK_QUEUE_GLOBAL_PRIORITY_HIGH_KHR",
+            vk::QueueGlobalPriorityKHR::HIGH.as_raw(),
+        ),
+        (
+            "VK_QUEUE_GLOBAL_PRIORITY_REALTIME_KHR",
+            vk::QueueGlobalPriorityKHR::REALTIME.as_raw(),
+        ),
+        (
+            "VK_QUEUE_GLOBAL_PRIORITY_LOW_EXT",
+            vk::QueueGlobalPriorityKHR::LOW_EXT.as_raw(),
+        ),
+        (
+            "VK_QUEUE_GLOBAL_PRIORITY_MEDIUM_EXT",
+            vk::QueueGlobalPriorityKHR::MEDIUM_EXT.as_raw(),
+        ),
+        (
+            "VK_QUEUE_GLOBAL_PRIORITY_HIGH_EXT",
+            vk::QueueGlobalPriorityKHR::HIGH_EXT.as_raw(),
+        ),
+        (
+            "VK_QUEUE_GLOBAL_PRIORITY_REALTIME_EXT",
+            vk::QueueGlobalPriorityKHR::REALTIME_EXT.as_raw(),
+        ),
+    ],
+};
+
+pub(super) static RAY_TRACING_INVOCATION_REORDER_MODE_NV: EnumInfo = EnumInfo {
+    name: "VkRayTracingInvocationReorderModeNV",
+    values: &[
+        (
+            "VK_RAY_TRACING_INVOCATION_REORDER_MODE_NONE_NV",
+            vk::RayTracingInvocationReorderModeNV::NONE.as_raw(),
+        ),
+        (
+            "VK_RAY_TRACING_INVOCATION_REORDER_MODE_REORDER_NV",
+            vk::RayTracingInvocationReorderModeNV::REORDER.as_raw(),
+        ),
+    ],
+};
+
+pub(super) static SHADER_FLOAT_CONTROLS_INDEPENDENCE: EnumInfo = EnumInfo {
+    name: "VkShaderFloatControlsIndependence",
+    values: &[
+        (
+            "VK_SHADER_FLOAT_CONTROLS_INDEPENDENCE_32_BIT_ONLY",
+            vk::ShaderFloatControlsIndependence::TYPE_32_ONLY.as_raw(),
+        ),
+        (
+            "VK_SHADER_FLOAT_CONTROLS_INDEPENDENCE_ALL",
+            vk::ShaderFloatControlsIndependence::ALL.as_raw(),
+        ),
+        (
+            "VK_SHADER_FLOAT_CONTROLS_INDEPENDENCE_NONE",
+            vk::ShaderFloatControlsIndependence::NONE.as_raw(),
+        ),
+        (
+            "VK_SHADER_FLOAT_CONTROLS_INDEPENDENCE_32_ONLY_KHR",
+            vk::ShaderFloatControlsIndependence::TYPE_32_ONLY_KHR.as_raw(),
+        ),
+        (
+            "VK_SHADER_FLOAT_CONTROLS_INDEPENDENCE_ALL_KHR",
+            vk::ShaderFloatControlsIndependence::ALL_KHR.as_raw(),
+        ),
+        (
+            "VK_SHADER_FLOAT_CONTROLS_INDEPENDENCE_NONE_KHR",
+            vk::ShaderFloatControlsIndependence::NONE_KHR.as_raw(),
+        ),
+    ],
+};
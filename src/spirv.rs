@@ -0,0 +1,727 @@
+//! Compatibility checks of SPIR-V modules against profiles.
+//!
+//! [`SpirvModule`] reads the `OpCapability` and `OpExtension` instructions of a SPIR-V binary.
+//! [`check_spirv`] then uses the SPIR-V mapping of the vulkan registry to check that every declared
+//! capability and extension is allowed by the features, properties, extensions and api version of a profile.
+//! Missing capabilities are reported together with the features which would allow them.
+//! [`ShaderTargetInfo`] describes the same guarantees as settings for shader compilers:
+//!
+//! ```no_run
+//! use vk_profiles_rs::spirv::{check_library_profile, SpirvModule};
+//! use vk_profiles_rs::{profiles, VulkanProfiles};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let module = SpirvModule::parse(&std::fs::read("shader.spv")?)?;
+//! let issues = unsafe {
+//!     check_library_profile(
+//!         &VulkanProfiles::linked(),
+//!         &profiles::KhrRoadmap2022::profile_properties(),
+//!         &module,
+//!     )?
+//! };
+//! for issue in &issues {
+//!     println!("{issue}");
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+
+use ash::vk;
+use serde_json::Value;
+
+use crate::builder::profiles_api_version;
+use crate::capabilities::value_satisfies;
+use crate::json::{Capability, JsonError, ProfilesDocument};
+use crate::reflect::{self, SpirvCapabilityInfo, SpirvEnable, SpirvExtensionInfo};
+use crate::vp::ProfileProperties;
+use crate::{ProfilesResult, VulkanProfiles};
+
+const MAGIC: u32 = 0x0723_0203;
+const HEADER_WORDS: usize = 5;
+const OP_EXTENSION: u32 = 10;
+const OP_CAPABILITY: u32 = 17;
+
+/// An error returned when a SPIR-V binary can not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SpirvError {
+    /// The binary is shorter than the header or its length is not a multiple of 4.
+    InvalidLength(usize),
+    InvalidMagic(u32),
+    /// The instruction starting at the word offset has an invalid word count.
+    TruncatedInstruction(usize),
+    /// The string operand of the instruction at the word offset is not null terminated UTF-8.
+    InvalidString(usize),
+}
+
+impl fmt::Display for SpirvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength(len) => write!(f, "invalid SPIR-V binary length {len}"),
+            Self::InvalidMagic(magic) => write!(f, "invalid SPIR-V magic number {magic:#010x}"),
+            Self::TruncatedInstruction(offset) => {
+                write!(f, "truncated SPIR-V instruction at word {offset}")
+            }
+            Self::InvalidString(offset) => {
+                write!(
+                    f,
+                    "invalid string operand in SPIR-V instruction at word {offset}"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for SpirvError {}
+
+/// The capabilities and extensions declared by a SPIR-V module.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SpirvModule {
+    /// The SPIR-V version from the module header, for example `0x10300` for SPIR-V 1.3.
+    pub version: u32,
+    /// The operands of all `OpCapability` instructions.
+    pub capabilities: Vec<u32>,
+    /// The names of all `OpExtension` instructions.
+    pub extensions: Vec<String>,
+}
+
+impl SpirvModule {
+    /// Parses a SPIR-V binary. The endianness is detected from the magic number.
+    pub fn parse(bytes: &[u8]) -> Result<Self, SpirvError> {
+        if !bytes.chunks_exact(4).remainder().is_empty() || bytes.len() < HEADER_WORDS * 4 {
+            return Err(SpirvError::InvalidLength(bytes.len()));
+        }
+        let little_endian = u32::from_le_bytes(bytes[..4].try_into().unwrap()) == MAGIC;
+        let words: Vec<u32> = bytes
+            .chunks_exact(4)
+            .map(|word| {
+                let word = word.try_into().unwrap();
+                if little_endian {
+                    u32::from_le_bytes(word)
+                } else {
+                    u32::from_be_bytes(word)
+                }
+            })
+            .collect();
+        Self::from_words(&words)
+    }
+
+    /// Parses a SPIR-V module from words in host endianness.
+    pub fn from_words(words: &[u32]) -> Result<Self, SpirvError> {
+        if words.len() < HEADER_WORDS {
+            return Err(SpirvError::InvalidLength(words.len() * 4));
+        }
+        if words[0] != MAGIC {
+            return Err(SpirvError::InvalidMagic(words[0]));
+        }
+
+        let mut module = Self {
+            version: words[1],
+            ..Default::default()
+        };
+        let mut offset = HEADER_WORDS;
+        while offset < words.len() {
+            let word_count = (words[offset] >> 16) as usize;
+            if word_count == 0 || offset + word_count > words.len() {
+                return Err(SpirvError::TruncatedInstruction(offset));
+            }
+            let operands = &words[offset + 1..offset + word_count];
+            match words[offset] & 0xffff {
+                OP_CAPABILITY => module.capabilities.push(
+                    *operands
+                        .first()
+                        .ok_or(SpirvError::TruncatedInstruction(offset))?,
+                ),
+                OP_EXTENSION => module
+                    .extensions
+                    .push(literal_string(operands).ok_or(SpirvError::InvalidString(offset))?),
+                _ => {}
+            }
+            offset += word_count;
+        }
+        Ok(module)
+    }
+}
+
+/// Decodes a null terminated literal string operand.
+fn literal_string(operands: &[u32]) -> Option<String> {
+    let bytes: Vec<u8> = operands
+        .iter()
+        .flat_map(|word| word.to_le_bytes())
+        .collect();
+    let len = bytes.iter().position(|byte| *byte == 0)?;
+    String::from_utf8(bytes[..len].to_vec()).ok()
+}
+
+/// A SPIR-V capability or extension of a module which is not allowed by a profile.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SpirvIssue {
+    /// The capability may not be used in vulkan or is not known.
+    UnknownCapability(u32),
+    /// None of the features, properties, extensions or versions which allow the capability is guaranteed.
+    MissingCapability(&'static SpirvCapabilityInfo),
+    /// The extension may not be used in vulkan or is not known.
+    UnknownExtension(String),
+    /// None of the extensions or versions which allow the extension is guaranteed.
+    MissingExtension(&'static SpirvExtensionInfo),
+    /// The SPIR-V version of the module is higher than the highest version guaranteed by the profile.
+    /// Both versions are encoded like [`SpirvModule::version`].
+    UnsupportedVersion { version: u32, max_version: u32 },
+}
+
+impl SpirvIssue {
+    /// Returns the alternatives which would allow the capability or extension, any one of them is sufficient.
+    pub fn enables(&self) -> &'static [SpirvEnable] {
+        match self {
+            Self::MissingCapability(info) => info.enables,
+            Self::MissingExtension(info) => info.enables,
+            Self::UnknownCapability(_)
+            | Self::UnknownExtension(_)
+            | Self::UnsupportedVersion { .. } => &[],
+        }
+    }
+}
+
+impl fmt::Display for SpirvIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (kind, name) = match self {
+            Self::UnknownCapability(id) => {
+                return write!(f, "SPIR-V capability {id} is not supported by vulkan")
+            }
+            Self::UnknownExtension(name) => {
+                return write!(f, "SPIR-V extension {name} is not supported by vulkan")
+            }
+            Self::UnsupportedVersion {
+                version,
+                max_version,
+            } => {
+                return write!(
+                f,
+                "SPIR-V version {}.{} is not supported, the highest guaranteed version is {}.{}",
+                (version >> 16) & 0xff,
+                (version >> 8) & 0xff,
+                (max_version >> 16) & 0xff,
+                (max_version >> 8) & 0xff,
+            )
+            }
+            Self::MissingCapability(info) => ("capability", info.name),
+            Self::MissingExtension(info) => ("extension", info.name),
+        };
+        write!(f, "SPIR-V {kind} {name} requires ")?;
+        for (i, enable) in self.enables().iter().enumerate() {
+            if i > 0 {
+                f.write_str(" or ")?;
+            }
+            write!(f, "{enable}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Checks the capabilities and extensions of `module` against the requirements of a profile.
+///
+/// A capability or extension is allowed if any of its registry enables is guaranteed: a core version up to
+/// `api_version`, a device extension of `capability` or a feature or property required by `capability`
+/// whose struct is available. The SPIR-V version of the module is checked against
+/// [`ShaderTargetInfo::spirv_version`]. Issues are returned in module order.
+pub fn check_spirv(
+    module: &SpirvModule,
+    api_version: u32,
+    capability: &Capability,
+) -> Vec<SpirvIssue> {
+    let guarantees = Guarantees::new(api_version, std::slice::from_ref(&capability));
+
+    let max_version = guarantees.spirv_version();
+    let version = (module.version > max_version).then_some(SpirvIssue::UnsupportedVersion {
+        version: module.version,
+        max_version,
+    });
+
+    let capabilities =
+        module
+            .capabilities
+            .iter()
+            .filter_map(|id| match reflect::find_spirv_capability(*id) {
+                None => Some(SpirvIssue::UnknownCapability(*id)),
+                Some(info) if !guarantees.allows(info.enables) => {
+                    Some(SpirvIssue::MissingCapability(info))
+                }
+                Some(_) => None,
+            });
+    let module_extensions =
+        module
+            .extensions
+            .iter()
+            .filter_map(|name| match reflect::find_spirv_extension(name) {
+                None => Some(SpirvIssue::UnknownExtension(name.clone())),
+                Some(info) if !guarantees.allows(info.enables) => {
+                    Some(SpirvIssue::MissingExtension(info))
+                }
+                Some(_) => None,
+            });
+    version
+        .into_iter()
+        .chain(capabilities)
+        .chain(module_extensions)
+        .collect()
+}
+
+/// Checks the capabilities and extensions of `module` against a profile of the profiles library.
+///
+/// The requirements of all blocks of the profile are combined. The api version is the minimum api version
+/// of profiles defined in [`crate::profiles`] and `1.0` for all other profiles.
+///
+/// # Safety
+/// The profile name of `profile` must be null terminated.
+pub unsafe fn check_library_profile(
+    vk_profiles: &VulkanProfiles,
+    profile: &ProfileProperties,
+    module: &SpirvModule,
+) -> ProfilesResult<Vec<SpirvIssue>> {
+    let capability = Capability::from_library(vk_profiles, profile, None)?;
+    let api_version = profiles_api_version(std::slice::from_ref(profile), &[]);
+    Ok(check_spirv(module, api_version, &capability))
+}
+
+/// Shader toolchain settings guaranteed by a profile.
+///
+/// The shader features are derived from the SPIR-V capabilities the profile allows, so a shader compiled
+/// with these settings passes [`check_spirv`]. For example the target environment of glslang and DXC is
+/// [`Self::target_env`] and naga's SPIR-V version is the major and minor part of [`Self::spirv_version`].
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct ShaderTargetInfo {
+    pub api_version: u32,
+    /// The highest SPIR-V version shader modules may use, encoded like [`SpirvModule::version`].
+    pub spirv_version: u32,
+    /// The shader stages which support subgroup operations.
+    pub subgroup_stages: vk::ShaderStageFlags,
+    pub subgroup_operations: vk::SubgroupFeatureFlags,
+    pub shader_float16: bool,
+    pub shader_float64: bool,
+    pub shader_int8: bool,
+    pub shader_int16: bool,
+    pub shader_int64: bool,
+    /// 16 bit types may be used in storage buffers.
+    pub storage_buffer_16bit_access: bool,
+    /// 8 bit types may be used in storage buffers.
+    pub storage_buffer_8bit_access: bool,
+    /// Runtime descriptor arrays and non-uniform indexing of sampled images, storage images and storage
+    /// buffers are available.
+    pub descriptor_indexing: bool,
+    pub vulkan_memory_model: bool,
+    /// All SPIR-V capabilities shader modules may declare.
+    pub capabilities: Vec<&'static SpirvCapabilityInfo>,
+    /// All SPIR-V extensions shader modules may declare.
+    pub extensions: Vec<&'static SpirvExtensionInfo>,
+}
+
+impl ShaderTargetInfo {
+    /// Derives the settings from an api version and the capability blocks of a profile.
+    pub fn from_capabilities(api_version: u32, capabilities: &[&Capability]) -> Self {
+        let guarantees = Guarantees::new(api_version, capabilities);
+        let spirv_capabilities: Vec<_> = reflect::spirv_capabilities()
+            .iter()
+            .filter(|info| guarantees.allows(info.enables))
+            .collect();
+        let has = |names: &[&str]| {
+            names
+                .iter()
+                .all(|name| spirv_capabilities.iter().any(|info| info.name == *name))
+        };
+
+        // Every vulkan 1.1 implementation supports basic subgroup operations in compute shaders
+        let (mut stages, mut operations) = if api_version >= vk::API_VERSION_1_1 {
+            (
+                vk::ShaderStageFlags::COMPUTE,
+                vk::SubgroupFeatureFlags::BASIC,
+            )
+        } else {
+            Default::default()
+        };
+        for capability in capabilities {
+            for (name, value) in &capability.properties {
+                let (stages_member, operations_member) =
+                    match reflect::find_struct(name).map(|info| info.name) {
+                        Some("VkPhysicalDeviceVulkan11Properties") => {
+                            ("subgroupSupportedStages", "subgroupSupportedOperations")
+                        }
+                        Some("VkPhysicalDeviceSubgroupProperties") => {
+                            ("supportedStages", "supportedOperations")
+                        }
+                        _ => continue,
+                    };
+                stages |= vk::ShaderStageFlags::from_raw(flag_bits(
+                    "VkShaderStageFlags",
+                    value.get(stages_member),
+                ) as u32);
+                operations |= vk::SubgroupFeatureFlags::from_raw(flag_bits(
+                    "VkSubgroupFeatureFlags",
+                    value.get(operations_member),
+                ) as u32);
+            }
+        }
+
+        Self {
+            api_version,
+            spirv_version: guarantees.spirv_version(),
+            subgroup_stages: stages,
+            subgroup_operations: operations,
+            shader_float16: has(&["Float16"]),
+            shader_float64: has(&["Float64"]),
+            shader_int8: has(&["Int8"]),
+            shader_int16: has(&["Int16"]),
+            shader_int64: has(&["Int64"]),
+            storage_buffer_16bit_access: has(&["StorageBuffer16BitAccess"]),
+            storage_buffer_8bit_access: has(&["StorageBuffer8BitAccess"]),
+            descriptor_indexing: has(&[
+                "ShaderNonUniform",
+                "RuntimeDescriptorArray",
+                "SampledImageArrayNonUniformIndexing",
+                "StorageImageArrayNonUniformIndexing",
+                "StorageBufferArrayNonUniformIndexing",
+            ]),
+            vulkan_memory_model: has(&["VulkanMemoryModel"]),
+            capabilities: spirv_capabilities,
+            extensions: reflect::spirv_extensions()
+                .iter()
+                .filter(|info| guarantees.allows(info.enables))
+                .collect(),
+        }
+    }
+
+    /// Derives the settings from a profile of a JSON document.
+    ///
+    /// For lists of alternative capability blocks only the first block is used.
+    pub fn from_document(
+        document: &ProfilesDocument,
+        profile_name: &str,
+    ) -> Result<Self, JsonError> {
+        let api_version = document
+            .profile(profile_name)?
+            .api_version()
+            .unwrap_or(vk::API_VERSION_1_0);
+        Ok(Self::from_capabilities(
+            api_version,
+            &document.profile_capabilities(profile_name, None)?,
+        ))
+    }
+
+    /// Derives the settings from a profile of the profiles library.
+    ///
+    /// The api version is the minimum api version of profiles defined in [`crate::profiles`] and `1.0` for
+    /// all other profiles.
+    ///
+    /// # Safety
+    /// The profile name of `profile` must be null terminated.
+    pub unsafe fn from_library(
+        vk_profiles: &VulkanProfiles,
+        profile: &ProfileProperties,
+    ) -> ProfilesResult<Self> {
+        let capability = Capability::from_library(vk_profiles, profile, None)?;
+        let api_version = profiles_api_version(std::slice::from_ref(profile), &[]);
+        Ok(Self::from_capabilities(api_version, &[&capability]))
+    }
+
+    /// Returns the target environment name used by glslang and DXC, for example `vulkan1.2`.
+    pub fn target_env(&self) -> String {
+        format!(
+            "vulkan{}.{}",
+            vk::api_version_major(self.api_version),
+            vk::api_version_minor(self.api_version)
+        )
+    }
+
+    /// Returns true if shader modules may declare the SPIR-V capability with the specified name.
+    pub fn has_capability(&self, name: &str) -> bool {
+        self.capabilities.iter().any(|info| info.name == name)
+    }
+}
+
+/// Returns the combined value of an array of flag bit names.
+fn flag_bits(flags_name: &str, value: Option<&Value>) -> u64 {
+    let Some(info) = reflect::find_flags(flags_name) else {
+        return 0;
+    };
+    value
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+        .filter_map(|name| info.bit(name))
+        .fold(0, |bits, bit| bits | bit)
+}
+
+/// The api version, device extensions and features guaranteed by the capability blocks of a profile.
+struct Guarantees<'a> {
+    api_version: u32,
+    extensions: Vec<&'a str>,
+    capabilities: &'a [&'a Capability],
+}
+
+impl<'a> Guarantees<'a> {
+    fn new(api_version: u32, capabilities: &'a [&'a Capability]) -> Self {
+        Self {
+            api_version,
+            extensions: capabilities
+                .iter()
+                .flat_map(|capability| capability.device_extensions())
+                .map(|(name, _)| name.as_str())
+                .collect(),
+            capabilities,
+        }
+    }
+
+    /// Returns the highest SPIR-V version vulkan guarantees for the api version and extensions.
+    fn spirv_version(&self) -> u32 {
+        match vk::api_version_minor(self.api_version) {
+            0 => 0x10000,
+            1 if self.extensions.contains(&"VK_KHR_spirv_1_4") => 0x10400,
+            1 => 0x10300,
+            2 => 0x10500,
+            _ => 0x10600,
+        }
+    }
+
+    /// Returns true if any of the `enables` is guaranteed.
+    fn allows(&self, enables: &[SpirvEnable]) -> bool {
+        enables.iter().any(|enable| self.is_enabled(enable))
+    }
+
+    fn is_enabled(&self, enable: &SpirvEnable) -> bool {
+        let (struct_name, member, required, properties) = match *enable {
+            SpirvEnable::Version(provider) | SpirvEnable::Extension(provider) => {
+                return reflect::is_provided(&[provider], self.api_version, &self.extensions)
+            }
+            SpirvEnable::Feature {
+                struct_name,
+                feature,
+                ..
+            } => (struct_name, feature, Value::Bool(true), false),
+            SpirvEnable::Property {
+                struct_name,
+                member,
+                value: "VK_TRUE",
+                ..
+            } => (struct_name, member, Value::Bool(true), true),
+            SpirvEnable::Property {
+                struct_name,
+                member,
+                value,
+                ..
+            } => (
+                struct_name,
+                member,
+                Value::Array(vec![Value::String(value.to_string())]),
+                true,
+            ),
+        };
+        if !enable
+            .requires()
+            .is_some_and(|requires| requires.is_satisfied(self.api_version, &self.extensions))
+        {
+            return false;
+        }
+        let Some(info) = reflect::find_struct(struct_name) else {
+            return false;
+        };
+        self.capabilities.iter().any(|capability| {
+            let structs = if properties {
+                &capability.properties
+            } else {
+                &capability.features
+            };
+            structs.iter().any(|(name, value)| {
+                reflect::find_struct(name).is_some_and(|found| std::ptr::eq(found, info))
+                    && value.get(member).is_some_and(|actual| {
+                        value_satisfies(struct_name, member, &required, actual) == Some(true)
+                    })
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn instruction(opcode: u32, operands: &[u32]) -> Vec<u32> {
+        let mut words = vec![((operands.len() as u32 + 1) << 16) | opcode];
+        words.extend_from_slice(operands);
+        words
+    }
+
+    fn string_operands(string: &str) -> Vec<u32> {
+        let mut bytes = string.as_bytes().to_vec();
+        bytes.resize((bytes.len() / 4 + 1) * 4, 0);
+        bytes
+            .chunks_exact(4)
+            .map(|word| u32::from_le_bytes(word.try_into().unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn test_check_spirv() {
+        let mut words = vec![MAGIC, 0x10300, 0, 16, 0];
+        for capability in [1, 10, 61, 64, 4439, 5] {
+            words.extend(instruction(OP_CAPABILITY, &[capability]));
+        }
+        words.extend(instruction(
+            OP_EXTENSION,
+            &string_operands("SPV_KHR_multiview"),
+        ));
+        words.extend(instruction(
+            OP_EXTENSION,
+            &string_operands("SPV_KHR_ray_query"),
+        ));
+        // OpMemoryModel Logical GLSL450
+        words.extend(instruction(14, &[0, 1]));
+
+        let bytes: Vec<u8> = words.iter().flat_map(|word| word.to_be_bytes()).collect();
+        let module = SpirvModule::parse(&bytes).unwrap();
+        assert_eq!(module.version, 0x10300);
+        assert_eq!(module.capabilities, [1, 10, 61, 64, 4439, 5]);
+        assert_eq!(
+            module.extensions,
+            ["SPV_KHR_multiview", "SPV_KHR_ray_query"]
+        );
+        assert_eq!(
+            SpirvModule::from_words(&words[..words.len() - 1]),
+            Err(SpirvError::TruncatedInstruction(words.len() - 3))
+        );
+
+        let capability: Capability = serde_json::from_value(json!({
+            "features": {
+                "VkPhysicalDeviceFeatures": { "shaderFloat64": false },
+                "VkPhysicalDeviceMultiviewFeaturesKHR": { "multiview": true }
+            },
+            "properties": {
+                "VkPhysicalDeviceVulkan11Properties": {
+                    "subgroupSupportedOperations": ["VK_SUBGROUP_FEATURE_BASIC_BIT"]
+                }
+            }
+        }))
+        .unwrap();
+        let issues = check_spirv(&module, vk::API_VERSION_1_1, &capability);
+        let find = |id| reflect::find_spirv_capability(id).unwrap();
+        assert_eq!(
+            issues,
+            [
+                SpirvIssue::MissingCapability(find(10)),
+                SpirvIssue::MissingCapability(find(64)),
+                SpirvIssue::UnknownCapability(5),
+                SpirvIssue::MissingExtension(
+                    reflect::find_spirv_extension("SPV_KHR_ray_query").unwrap()
+                ),
+            ]
+        );
+        assert_eq!(
+            issues[0].to_string(),
+            "SPIR-V capability Float64 requires VkPhysicalDeviceFeatures.shaderFloat64"
+        );
+        assert_eq!(
+            issues[1].to_string(),
+            "SPIR-V capability GroupNonUniformBallot requires VK_SUBGROUP_FEATURE_BALLOT_BIT in \
+             VkPhysicalDeviceVulkan11Properties.subgroupSupportedOperations"
+        );
+
+        // The features of the vulkan 1.2 structs require the matching api version
+        let capability: Capability = serde_json::from_value(json!({
+            "features": { "VkPhysicalDeviceVulkan11Features": { "multiview": true } }
+        }))
+        .unwrap();
+        let module = SpirvModule {
+            capabilities: vec![4439],
+            ..Default::default()
+        };
+        assert_eq!(
+            check_spirv(&module, vk::API_VERSION_1_1, &capability).len(),
+            1
+        );
+        assert!(check_spirv(&module, vk::API_VERSION_1_2, &capability).is_empty());
+
+        // SPIR-V 1.4 requires vulkan 1.2 or VK_KHR_spirv_1_4
+        let module = SpirvModule {
+            version: 0x10400,
+            ..Default::default()
+        };
+        let issues = check_spirv(&module, vk::API_VERSION_1_1, &Capability::default());
+        assert_eq!(
+            issues,
+            [SpirvIssue::UnsupportedVersion {
+                version: 0x10400,
+                max_version: 0x10300
+            }]
+        );
+        assert_eq!(
+            issues[0].to_string(),
+            "SPIR-V version 1.4 is not supported, the highest guaranteed version is 1.3"
+        );
+        let capability: Capability = serde_json::from_value(json!({
+            "extensions": { "VK_KHR_spirv_1_4": 1 }
+        }))
+        .unwrap();
+        assert!(check_spirv(&module, vk::API_VERSION_1_1, &capability).is_empty());
+        assert!(check_spirv(&module, vk::API_VERSION_1_2, &Capability::default()).is_empty());
+    }
+
+    #[test]
+    fn test_shader_target_info() {
+        let capability: Capability = serde_json::from_value(json!({
+            "features": {
+                "VkPhysicalDeviceFeatures": { "shaderInt16": true },
+                "VkPhysicalDeviceVulkan12Features": {
+                    "shaderFloat16": true,
+                    "shaderInt8": true,
+                    "storageBuffer8BitAccess": true,
+                    "runtimeDescriptorArray": true,
+                    "shaderSampledImageArrayNonUniformIndexing": true,
+                    "shaderStorageImageArrayNonUniformIndexing": true,
+                    "shaderStorageBufferArrayNonUniformIndexing": true
+                }
+            },
+            "properties": {
+                "VkPhysicalDeviceVulkan11Properties": {
+                    "subgroupSupportedStages": ["VK_SHADER_STAGE_FRAGMENT_BIT"],
+                    "subgroupSupportedOperations": ["VK_SUBGROUP_FEATURE_BALLOT_BIT"]
+                }
+            }
+        }))
+        .unwrap();
+        let info = ShaderTargetInfo::from_capabilities(vk::API_VERSION_1_2, &[&capability]);
+        assert_eq!(info.spirv_version, 0x10500);
+        assert_eq!(info.target_env(), "vulkan1.2");
+        assert_eq!(
+            info.subgroup_stages,
+            vk::ShaderStageFlags::COMPUTE | vk::ShaderStageFlags::FRAGMENT
+        );
+        assert_eq!(
+            info.subgroup_operations,
+            vk::SubgroupFeatureFlags::BASIC | vk::SubgroupFeatureFlags::BALLOT
+        );
+        assert!(info.shader_float16 && info.shader_int8 && info.shader_int16);
+        assert!(!info.shader_float64 && !info.shader_int64);
+        assert!(info.storage_buffer_8bit_access && !info.storage_buffer_16bit_access);
+        assert!(info.descriptor_indexing);
+        assert!(!info.vulkan_memory_model);
+        assert!(info.has_capability("GroupNonUniformBallot"));
+        assert!(info
+            .extensions
+            .iter()
+            .any(|info| info.name == "SPV_KHR_8bit_storage"));
+
+        let capability: Capability = serde_json::from_value(json!({
+            "extensions": { "VK_KHR_spirv_1_4": 1 }
+        }))
+        .unwrap();
+        let info = ShaderTargetInfo::from_capabilities(vk::API_VERSION_1_1, &[&capability]);
+        assert_eq!(info.spirv_version, 0x10400);
+        assert!(!info.descriptor_indexing);
+        let info = ShaderTargetInfo::from_capabilities(vk::API_VERSION_1_0, &[]);
+        assert_eq!(info.spirv_version, 0x10000);
+        assert!(info.subgroup_operations.is_empty());
+    }
+}
@@ -2,7 +2,9 @@
 //!
 //! See the vulkan profiles documentation for more details <https://vulkan.lunarg.com/doc/sdk/1.3.204.1/windows/profiles_api_library.html>
 
+use crate::prelude::c_char_array_as_c_str;
 use ash::vk;
+use std::ffi::{CStr, FromBytesUntilNulError};
 use std::{ffi::c_void, ptr};
 
 const VP_MAX_PROFILE_NAME_SIZE: usize = 256;
@@ -24,6 +26,19 @@ impl std::fmt::Debug for ProfileProperties {
             .finish()
     }
 }
+impl ProfileProperties {
+    #[inline]
+    pub fn profile_name_as_c_str(&self) -> Result<&CStr, FromBytesUntilNulError> {
+        c_char_array_as_c_str(&self.profile_name)
+    }
+
+    /// Returns the profile name as an owned string, replacing invalid UTF-8 sequences.
+    pub fn profile_name_to_string(&self) -> String {
+        self.profile_name_as_c_str()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default()
+    }
+}
 impl ::std::default::Default for ProfileProperties {
     fn default() -> Self {
         Self {
@@ -40,6 +55,19 @@ pub struct BlockProperties {
     pub api_version: u32,
     pub block_name: [std::os::raw::c_char; VP_MAX_PROFILE_NAME_SIZE],
 }
+impl BlockProperties {
+    #[inline]
+    pub fn block_name_as_c_str(&self) -> Result<&CStr, FromBytesUntilNulError> {
+        c_char_array_as_c_str(&self.block_name)
+    }
+
+    /// Returns the block name as an owned string, replacing invalid UTF-8 sequences.
+    pub fn block_name_to_string(&self) -> String {
+        self.block_name_as_c_str()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default()
+    }
+}
 
 #[repr(transparent)]
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -0,0 +1,183 @@
+//! Generates `src/reflect/extensions.rs` from the `<extensions>` of vk.xml.
+
+use std::collections::BTreeSet;
+use std::fmt::Write;
+
+use roxmltree::Document;
+
+use crate::is_vulkan;
+
+const MAX_WIDTH: usize = 100;
+
+const PROPERTIES2: &str = "VK_KHR_get_physical_device_properties2,VK_VERSION_1_1";
+
+const HELPERS: &str = r#"use super::{ExtensionInfo, ExtensionType};
+
+const fn instance(name: &'static str, depends: &'static str) -> ExtensionInfo {
+    ExtensionInfo {
+        name,
+        ty: ExtensionType::Instance,
+        depends,
+        promoted_to: None,
+    }
+}
+
+const fn device(name: &'static str, depends: &'static str) -> ExtensionInfo {
+    ExtensionInfo {
+        name,
+        ty: ExtensionType::Device,
+        depends,
+        promoted_to: None,
+    }
+}
+
+impl ExtensionInfo {
+    const fn promoted_to(mut self, version: &'static str) -> Self {
+        self.promoted_to = Some(version);
+        self
+    }
+}
+"#;
+
+/// Returns the name of the constant used for `value` if there is one.
+fn constant(value: &str, used: &mut BTreeSet<String>) -> Option<String> {
+    let name = if value == PROPERTIES2 {
+        "PROPERTIES2".to_string()
+    } else {
+        let version = value.strip_prefix("VK_VERSION_")?;
+        if !version.bytes().all(|c| c.is_ascii_digit() || c == b'_') {
+            return None;
+        }
+        format!("V{}", version)
+    };
+    used.insert(name.clone());
+    Some(name)
+}
+
+fn string_or_constant(value: &str, used: &mut BTreeSet<String>) -> String {
+    constant(value, used).unwrap_or_else(|| format!("{:?}", value))
+}
+
+pub(crate) fn generate(registry: &Document, version: &str) -> Result<String, String> {
+    let mut used = BTreeSet::new();
+    let mut entries = String::new();
+
+    let extensions = registry
+        .descendants()
+        .filter(|node| {
+            node.has_tag_name("extension")
+                && node.parent().is_some_and(|p| p.has_tag_name("extensions"))
+        })
+        .filter(|node| is_vulkan(*node, "supported"));
+    for extension in extensions {
+        let name = extension
+            .attribute("name")
+            .ok_or("extension without a name")?;
+        let ty = match extension.attribute("type") {
+            Some("instance") => "instance",
+            Some("device") => "device",
+            ty => return Err(format!("extension {} has unknown type {:?}", name, ty)),
+        };
+        let depends = string_or_constant(extension.attribute("depends").unwrap_or(""), &mut used);
+
+        let promoted_to = extension
+            .attribute("promotedto")
+            .filter(|to| to.starts_with("VK_VERSION_"))
+            .map(|to| format!(".promoted_to({})", string_or_constant(to, &mut used)))
+            .unwrap_or_default();
+
+        // rustfmt leaves the table alone if any entry is too long, so wrap long entries like it would.
+        let entry = format!("    {}({:?}, {}){},", ty, name, depends, promoted_to);
+        if entry.len() <= MAX_WIDTH {
+            writeln!(entries, "{}", entry).unwrap();
+        } else {
+            write!(
+                entries,
+                "    {}(\n        {:?},\n        {},\n    )",
+                ty, name, depends
+            )
+            .unwrap();
+            if !promoted_to.is_empty() {
+                write!(entries, "\n    {}", promoted_to).unwrap();
+            }
+            entries.push_str(",\n");
+        }
+    }
+
+    let mut out = String::new();
+    writeln!(
+        out,
+        "//! Extension types, dependencies and core promotions from the vulkan registry (vk.xml {}).",
+        version
+    )
+    .unwrap();
+    out.push_str(
+        "//!
+//! Dependencies use the syntax of the `depends` attribute: `+` is a logical and, `,` a logical or and
+//! parentheses group sub expressions. Extensions which are neither promoted nor depend on anything are
+//! listed as well so that their type is known.
+//!
+//! Generated by `cargo run --manifest-path xtask/Cargo.toml -- <path to vk.xml> <path to grammar>`, do not
+//! edit by hand.
+
+",
+    );
+    out.push_str(HELPERS);
+    out.push('\n');
+    if used.contains("PROPERTIES2") {
+        out.push_str("/// The most common dependency of extensions introduced after vulkan 1.0.\n");
+        writeln!(out, "const PROPERTIES2: &str = {:?};\n", PROPERTIES2).unwrap();
+    }
+    for name in used.iter().filter(|name| name.starts_with('V')) {
+        writeln!(out, "const {}: &str = \"VK_VERSION_{}\";", name, &name[1..]).unwrap();
+    }
+    out.push_str("\npub(super) static EXTENSION_INFOS: &[ExtensionInfo] = &[\n");
+    out.push_str(&entries);
+    out.push_str("];\n");
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_registry;
+    use crate::tests::REGISTRY;
+
+    #[test]
+    fn test_generate() {
+        let registry = parse_registry(REGISTRY).unwrap();
+        let out = generate(&registry, "1.3.281").unwrap();
+
+        assert!(out.starts_with("//! Extension types, dependencies and core promotions from the vulkan registry (vk.xml 1.3.281)."));
+        assert!(out.contains(
+            "pub(super) static EXTENSION_INFOS: &[ExtensionInfo] = &[
+    instance(\"VK_KHR_surface\", \"\"),
+    instance(\"VK_KHR_get_physical_device_properties2\", \"\").promoted_to(V1_1),
+    device(\"VK_KHR_multiview\", PROPERTIES2).promoted_to(V1_1),
+    device(\"VK_KHR_maintenance4\", V1_1).promoted_to(V1_3),
+    device(\"VK_EXT_pipeline_properties\", PROPERTIES2),
+    device(
+        \"VK_KHR_swapchain_mutable_format\",
+        \"VK_KHR_swapchain+(VK_KHR_maintenance2+VK_KHR_image_format_list),VK_VERSION_1_2\",
+    ),
+    device(\"VK_KHR_shader_maximal_reconvergence\", V1_1),
+    device(
+        \"VK_KHR_shader_quad_control\",
+        \"VK_VERSION_1_1+VK_KHR_vulkan_memory_model+VK_KHR_shader_maximal_reconvergence\",
+    ),
+    device(\"VK_KHR_shader_float16_int8\", PROPERTIES2).promoted_to(V1_2),
+];"
+        ));
+        assert!(out.contains(
+            "const PROPERTIES2: &str = \"VK_KHR_get_physical_device_properties2,VK_VERSION_1_1\";"
+        ));
+        assert!(out.contains(
+            "const V1_1: &str = \"VK_VERSION_1_1\";
+const V1_2: &str = \"VK_VERSION_1_2\";
+const V1_3: &str = \"VK_VERSION_1_3\";
+"
+        ));
+        assert!(!out.contains("V1_0"));
+        assert!(!out.contains("VK_NV_disabled"));
+    }
+}
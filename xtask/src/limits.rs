@@ -0,0 +1,193 @@
+//! Generates `src/reflect/limits.rs` from the `limittype` attributes of the struct members in vk.xml.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+
+use roxmltree::{Document, Node};
+
+use crate::{child_text, is_vulkan};
+
+/// Returns the names of the structs reflected by `src/reflect/tables.rs`.
+pub(crate) fn reflected_structs(tables: &str) -> Vec<&str> {
+    let mut lines = tables.lines();
+    let mut names = Vec::new();
+    while let Some(line) = lines.next() {
+        if !line.ends_with("StructInfo = StructInfo {") {
+            continue;
+        }
+        let name = lines
+            .next()
+            .and_then(|line| line.trim().strip_prefix("name: \""))
+            .and_then(|name| name.strip_suffix("\","));
+        names.extend(name);
+    }
+    names
+}
+
+/// Returns the name of the `LimitType` variant of a `limittype` attribute.
+///
+/// `pot` only states that the limit is a power of two and is dropped.
+fn variant(limittype: &str) -> Result<&'static str, String> {
+    let parts: BTreeSet<_> = limittype.split(',').filter(|part| *part != "pot").collect();
+    let parts: Vec<_> = parts.into_iter().collect();
+    Ok(match parts.as_slice() {
+        ["min"] => "Min",
+        ["max"] => "Max",
+        ["not"] => "Not",
+        ["bits"] => "Bits",
+        ["bitmask"] => "Bitmask",
+        ["range"] => "Range",
+        ["exact"] => "Exact",
+        ["min", "mul"] => "MinMul",
+        ["struct"] => "Struct",
+        ["noauto"] => "NoAuto",
+        _ => return Err(format!("unknown limittype {:?}", limittype)),
+    })
+}
+
+pub(crate) fn generate(
+    registry: &Document,
+    version: &str,
+    structs: &[&str],
+) -> Result<String, String> {
+    let definitions: BTreeMap<&str, Node> = registry
+        .descendants()
+        .filter(|node| {
+            node.has_tag_name("type")
+                && node.attribute("category") == Some("struct")
+                && is_vulkan(*node, "api")
+        })
+        .filter_map(|node| Some((node.attribute("name")?, node)))
+        .collect();
+
+    let mut entries: BTreeMap<&str, BTreeMap<&str, &str>> = BTreeMap::new();
+    for name in structs {
+        // The reflected name may be an alias of the struct defining the members
+        let mut definition = definitions.get(name);
+        while let Some(alias) = definition.and_then(|node| node.attribute("alias")) {
+            definition = definitions.get(alias);
+        }
+        let Some(definition) = definition else {
+            continue;
+        };
+
+        let members = definition
+            .children()
+            .filter(|node| node.has_tag_name("member") && is_vulkan(*node, "api"));
+        for member in members {
+            let Some(limittype) = member.attribute("limittype") else {
+                continue;
+            };
+            let member_name = child_text(member, "name")
+                .ok_or_else(|| format!("member of {} without a name", name))?;
+            let variant =
+                variant(limittype).map_err(|err| format!("{}.{}: {}", name, member_name, err))?;
+            entries
+                .entry(*name)
+                .or_default()
+                .insert(member_name, variant);
+        }
+    }
+
+    let mut out = String::new();
+    writeln!(
+        out,
+        "//! Limit types of the reflected struct members from the vulkan registry (vk.xml {}).",
+        version
+    )
+    .unwrap();
+    out.push_str(
+        "//!
+//! Structs and their members are sorted by name for binary searches. Members without a `limittype`
+//! attribute are not listed.
+//!
+//! Generated by `cargo run --manifest-path xtask/Cargo.toml -- <path to vk.xml> <path to grammar>`, do not
+//! edit by hand.
+
+use super::LimitType::{self, *};
+
+pub(super) static LIMIT_TYPES: &[(&str, &[(&str, LimitType)])] = &[
+",
+    );
+    for (name, members) in &entries {
+        writeln!(out, "    (\n        {:?},\n        &[", name).unwrap();
+        for (member, variant) in members {
+            writeln!(out, "            ({:?}, {}),", member, variant).unwrap();
+        }
+        out.push_str("        ],\n    ),\n");
+    }
+    out.push_str("];\n");
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_registry;
+    use crate::tests::REGISTRY;
+
+    #[test]
+    fn test_reflected_structs() {
+        let tables = r#"
+pub(super) static PHYSICAL_DEVICE_LIMITS: StructInfo = StructInfo {
+    name: "VkPhysicalDeviceLimits",
+    structure_type: None,
+};
+
+pub(super) static QUEUE_FAMILY_PROPERTIES: StructInfo = StructInfo {
+    name: "VkQueueFamilyProperties",
+"#;
+        assert_eq!(
+            reflected_structs(tables),
+            ["VkPhysicalDeviceLimits", "VkQueueFamilyProperties"]
+        );
+    }
+
+    #[test]
+    fn test_generate() {
+        let registry = parse_registry(REGISTRY).unwrap();
+        let structs = [
+            "VkPhysicalDeviceProperties",
+            "VkQueueFamilyProperties",
+            "VkPhysicalDeviceLimits",
+            "VkPhysicalDeviceShaderFloat16Int8FeaturesKHR",
+        ];
+        let out = generate(&registry, "1.3.281", &structs).unwrap();
+
+        assert!(out.starts_with("//! Limit types of the reflected struct members from the vulkan registry (vk.xml 1.3.281)."));
+        assert!(out.ends_with(
+            "pub(super) static LIMIT_TYPES: &[(&str, &[(&str, LimitType)])] = &[
+    (
+        \"VkPhysicalDeviceLimits\",
+        &[
+            (\"maxImageDimension1D\", Max),
+            (\"minMemoryMapAlignment\", Min),
+            (\"pointSizeRange\", Range),
+        ],
+    ),
+    (
+        \"VkPhysicalDeviceProperties\",
+        &[
+            (\"deviceName\", NoAuto),
+            (\"limits\", Struct),
+        ],
+    ),
+    (
+        \"VkQueueFamilyProperties\",
+        &[
+            (\"minImageTransferGranularity\", MinMul),
+            (\"queueFlags\", Bitmask),
+        ],
+    ),
+];
+"
+        ));
+
+        let invalid = REGISTRY.replace("limittype=\"min,mul\"", "limittype=\"max,mul\"");
+        let registry = parse_registry(&invalid).unwrap();
+        assert_eq!(
+            generate(&registry, "1.3.281", &structs).unwrap_err(),
+            "VkQueueFamilyProperties.minImageTransferGranularity: unknown limittype \"max,mul\""
+        );
+    }
+}
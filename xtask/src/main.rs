@@ -0,0 +1,257 @@
+//! Generates the registry tables in `src/reflect` from the vulkan registry and the SPIR-V grammar.
+//!
+//! Usage: `cargo run --manifest-path xtask/Cargo.toml -- <path to vk.xml> <path to spirv.core.grammar.json>`
+//!
+//! The generated files are formatted with rustfmt and must not be edited by hand.
+
+mod extensions;
+mod limits;
+mod spirv;
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use roxmltree::{Document, Node, ParsingOptions};
+
+fn main() {
+    if let Err(err) = run() {
+        eprintln!("error: {}", err);
+        std::process::exit(1);
+    }
+}
+
+fn run() -> Result<(), String> {
+    let mut args = std::env::args().skip(1);
+    let (Some(registry_path), Some(grammar_path), None) = (args.next(), args.next(), args.next())
+    else {
+        return Err("usage: xtask <path to vk.xml> <path to spirv.core.grammar.json>".to_string());
+    };
+
+    let registry_source = read(Path::new(&registry_path))?;
+    let registry = parse_registry(&registry_source)?;
+    let version = registry_version(&registry)?;
+    let capability_ids = spirv::capability_ids(&read(Path::new(&grammar_path))?)?;
+
+    let out_dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .join("src/reflect");
+    let tables = read(&out_dir.join("tables.rs"))?;
+    let files = [
+        (
+            out_dir.join("extensions.rs"),
+            extensions::generate(&registry, &version)?,
+        ),
+        (
+            out_dir.join("limits.rs"),
+            limits::generate(&registry, &version, &limits::reflected_structs(&tables))?,
+        ),
+        (
+            out_dir.join("spirv.rs"),
+            spirv::generate(&registry, &version, &capability_ids)?,
+        ),
+    ];
+
+    for (path, contents) in &files {
+        std::fs::write(path, contents)
+            .map_err(|err| format!("failed to write {}: {}", path.display(), err))?;
+    }
+    rustfmt(files.iter().map(|(path, _)| path))
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    std::fs::read_to_string(path)
+        .map_err(|err| format!("failed to read {}: {}", path.display(), err))
+}
+
+pub(crate) fn parse_registry(source: &str) -> Result<Document<'_>, String> {
+    let options = ParsingOptions {
+        allow_dtd: true,
+        ..ParsingOptions::default()
+    };
+    Document::parse_with_options(source, options).map_err(|err| format!("invalid vk.xml: {}", err))
+}
+
+/// Returns the registry version in the form `1.3.281` from the `VK_HEADER_VERSION_COMPLETE` define.
+pub(crate) fn registry_version(registry: &Document) -> Result<String, String> {
+    let define = |name: &str| {
+        registry
+            .descendants()
+            .filter(|node| {
+                node.has_tag_name("type")
+                    && node.attribute("category") == Some("define")
+                    && is_vulkan(*node, "api")
+            })
+            .find(|node| child_text(*node, "name") == Some(name))
+            .map(|node| text(node))
+    };
+
+    let patch = define("VK_HEADER_VERSION")
+        .and_then(|text| text.split_whitespace().last().map(str::to_string))
+        .ok_or("vk.xml does not define VK_HEADER_VERSION")?;
+    let complete = define("VK_HEADER_VERSION_COMPLETE")
+        .ok_or("vk.xml does not define VK_HEADER_VERSION_COMPLETE")?;
+
+    // VK_MAKE_API_VERSION(0, 1, 3, VK_HEADER_VERSION)
+    let args = complete
+        .split_once('(')
+        .and_then(|(_, args)| args.split_once(')'))
+        .map(|(args, _)| args.split(',').map(str::trim).collect::<Vec<_>>())
+        .unwrap_or_default();
+    match args.as_slice() {
+        [_, major, minor, "VK_HEADER_VERSION"] => Ok(format!("{}.{}.{}", major, minor, patch)),
+        _ => Err(format!(
+            "unexpected VK_HEADER_VERSION_COMPLETE {:?}",
+            complete
+        )),
+    }
+}
+
+/// Returns whether the comma separated `supported` or `api` attribute includes vulkan.
+/// A missing attribute applies to every api.
+pub(crate) fn is_vulkan(node: Node, attribute: &str) -> bool {
+    node.attribute(attribute)
+        .is_none_or(|apis| apis.split(',').any(|api| api == "vulkan"))
+}
+
+pub(crate) fn child_text<'a>(node: Node<'a, '_>, tag: &str) -> Option<&'a str> {
+    node.children()
+        .find(|child| child.has_tag_name(tag))
+        .and_then(|child| child.text())
+}
+
+/// Concatenates all text inside of `node` including the text of its children.
+pub(crate) fn text(node: Node) -> String {
+    node.descendants().filter_map(|node| node.text()).collect()
+}
+
+fn rustfmt<'a>(paths: impl Iterator<Item = &'a PathBuf>) -> Result<(), String> {
+    let status = Command::new("rustfmt")
+        .args(["--edition", "2021"])
+        .args(paths)
+        .status()
+        .map_err(|err| format!("failed to run rustfmt: {}", err))?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(format!("rustfmt failed with {}", status))
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// A heavily reduced vk.xml used by the generator tests.
+    pub(crate) const REGISTRY: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<registry>
+    <types>
+        <type api="vulkan" category="define">// Version of this file
+#define <name>VK_HEADER_VERSION</name> 281</type>
+        <type api="vulkan" category="define" requires="VK_HEADER_VERSION">// Complete version of this file
+#define <name>VK_HEADER_VERSION_COMPLETE</name> <type>VK_MAKE_API_VERSION</type>(0, 1, 3, VK_HEADER_VERSION)</type>
+        <type category="struct" name="VkPhysicalDeviceLimits" returnedonly="true">
+            <member limittype="max"><type>uint32_t</type> <name>maxImageDimension1D</name></member>
+            <member limittype="min,pot"><type>size_t</type> <name>minMemoryMapAlignment</name></member>
+            <member limittype="range"><type>float</type> <name>pointSizeRange</name>[2]</member>
+            <member api="vulkansc" limittype="max"><type>uint32_t</type> <name>maxCommandBufferSize</name></member>
+        </type>
+        <type category="struct" name="VkPhysicalDeviceProperties" returnedonly="true">
+            <member limittype="noauto"><type>char</type> <name>deviceName</name>[<enum>VK_MAX_PHYSICAL_DEVICE_NAME_SIZE</enum>]</member>
+            <member limittype="struct"><type>VkPhysicalDeviceLimits</type> <name>limits</name></member>
+        </type>
+        <type category="struct" name="VkQueueFamilyProperties" returnedonly="true">
+            <member optional="true" limittype="bitmask"><type>VkQueueFlags</type> <name>queueFlags</name></member>
+            <member limittype="min,mul"><type>VkExtent3D</type> <name>minImageTransferGranularity</name></member>
+        </type>
+        <type category="struct" name="VkPhysicalDeviceVulkan12Features" structextends="VkPhysicalDeviceFeatures2,VkDeviceCreateInfo">
+            <member><type>VkBool32</type> <name>shaderFloat16</name></member>
+        </type>
+        <type category="struct" name="VkPhysicalDeviceShaderFloat16Int8Features" structextends="VkPhysicalDeviceFeatures2,VkDeviceCreateInfo">
+            <member><type>VkBool32</type> <name>shaderFloat16</name></member>
+        </type>
+        <type category="struct" name="VkPhysicalDeviceShaderFloat16Int8FeaturesKHR" alias="VkPhysicalDeviceShaderFloat16Int8Features"/>
+    </types>
+    <feature api="vulkan,vulkansc" name="VK_VERSION_1_0" number="1.0"/>
+    <feature api="vulkan,vulkansc" name="VK_VERSION_1_2" number="1.2">
+        <require>
+            <type name="VkPhysicalDeviceVulkan12Features"/>
+            <type name="VkPhysicalDeviceShaderFloat16Int8Features"/>
+        </require>
+    </feature>
+    <feature api="vulkansc" name="VKSC_VERSION_1_0" number="1.0">
+        <require>
+            <type name="VkPhysicalDeviceShaderFloat16Int8Features"/>
+        </require>
+    </feature>
+    <extensions comment="Vulkan extension interface definitions">
+        <extension name="VK_KHR_surface" number="1" type="instance" supported="vulkan,vulkansc"/>
+        <extension name="VK_KHR_get_physical_device_properties2" number="60" type="instance" supported="vulkan" promotedto="VK_VERSION_1_1"/>
+        <extension name="VK_KHR_multiview" number="54" type="device" depends="VK_KHR_get_physical_device_properties2,VK_VERSION_1_1" supported="vulkan,vulkansc" promotedto="VK_VERSION_1_1"/>
+        <extension name="VK_NV_disabled" number="55" type="device" supported="disabled"/>
+        <extension name="VK_KHR_maintenance4" number="414" type="device" depends="VK_VERSION_1_1" supported="vulkan" promotedto="VK_VERSION_1_3"/>
+        <extension name="VK_EXT_pipeline_properties" number="373" type="device" depends="VK_KHR_get_physical_device_properties2,VK_VERSION_1_1" supported="vulkan" promotedto="VK_KHR_pipeline_binary"/>
+        <extension name="VK_KHR_swapchain_mutable_format" number="201" type="device" depends="VK_KHR_swapchain+(VK_KHR_maintenance2+VK_KHR_image_format_list),VK_VERSION_1_2" supported="vulkan"/>
+        <extension name="VK_KHR_shader_maximal_reconvergence" number="435" type="device" depends="VK_VERSION_1_1" supported="vulkan"/>
+        <extension name="VK_KHR_shader_quad_control" number="236" type="device" depends="VK_VERSION_1_1+VK_KHR_vulkan_memory_model+VK_KHR_shader_maximal_reconvergence" supported="vulkan"/>
+        <extension name="VK_KHR_shader_float16_int8" number="83" type="device" depends="VK_KHR_get_physical_device_properties2,VK_VERSION_1_1" supported="vulkan" promotedto="VK_VERSION_1_2">
+            <require>
+                <type name="VkPhysicalDeviceShaderFloat16Int8FeaturesKHR"/>
+            </require>
+        </extension>
+    </extensions>
+    <spirvextensions comment="SPIR-V Extensions allowed in Vulkan and what is required to use it">
+        <spirvextension name="SPV_KHR_multiview">
+            <enable version="VK_VERSION_1_1"/>
+            <enable extension="VK_KHR_multiview"/>
+        </spirvextension>
+    </spirvextensions>
+    <spirvcapabilities comment="SPIR-V Capabilities allowed in Vulkan and what is required to use it">
+        <spirvcapability name="ShaderViewportIndexLayerEXT">
+            <enable extension="VK_EXT_shader_viewport_index_layer"/>
+        </spirvcapability>
+        <spirvcapability name="Shader">
+            <enable version="VK_VERSION_1_0"/>
+        </spirvcapability>
+        <spirvcapability name="Float16">
+            <enable struct="VkPhysicalDeviceVulkan12Features" feature="shaderFloat16" requires="VK_VERSION_1_2,VK_KHR_shader_float16_int8" alias="VkPhysicalDeviceShaderFloat16Int8FeaturesKHR::shaderFloat16"/>
+            <enable extension="VK_AMD_gpu_shader_half_float"/>
+        </spirvcapability>
+        <spirvcapability name="GroupNonUniform">
+            <enable property="VkPhysicalDeviceVulkan11Properties" member="subgroupSupportedOperations" value="VK_SUBGROUP_FEATURE_BASIC_BIT" requires="VK_VERSION_1_1"/>
+        </spirvcapability>
+        <spirvcapability name="ShaderViewportIndexLayerNV">
+            <enable extension="VK_NV_viewport_array2"/>
+        </spirvcapability>
+    </spirvcapabilities>
+</registry>
+"#;
+
+    /// The capability operand kind of a reduced `spirv.core.grammar.json`.
+    pub(crate) const GRAMMAR: &str = r#"{
+    "major_version": 1,
+    "minor_version": 6,
+    "operand_kinds": [
+        {
+            "category": "ValueEnum",
+            "kind": "Capability",
+            "enumerants": [
+                { "enumerant": "Shader", "value": 1, "capabilities": ["Matrix"] },
+                { "enumerant": "Float16", "value": 9 },
+                { "enumerant": "GroupNonUniform", "value": 61, "version": "1.3" },
+                {
+                    "enumerant": "ShaderViewportIndexLayerEXT",
+                    "aliases": ["ShaderViewportIndexLayerNV"],
+                    "value": 5254
+                }
+            ]
+        }
+    ]
+}"#;
+
+    #[test]
+    fn test_registry_version() {
+        let registry = parse_registry(REGISTRY).unwrap();
+        assert_eq!(registry_version(&registry).unwrap(), "1.3.281");
+    }
+}
@@ -0,0 +1,367 @@
+//! Generates `src/reflect/spirv.rs` from the `<spirvcapabilities>` and `<spirvextensions>` of vk.xml and the
+//! capability ids of the SPIR-V grammar.
+
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::Write;
+
+use roxmltree::{Document, Node};
+
+use crate::is_vulkan;
+
+const HELPERS: &str = r#"use super::{SpirvCapabilityInfo, SpirvEnable, SpirvExtensionInfo};
+
+const fn capability(
+    name: &'static str,
+    id: u32,
+    enables: &'static [SpirvEnable],
+) -> SpirvCapabilityInfo {
+    SpirvCapabilityInfo { name, id, enables }
+}
+
+const fn extension(name: &'static str, enables: &'static [SpirvEnable]) -> SpirvExtensionInfo {
+    SpirvExtensionInfo { name, enables }
+}
+
+const fn feature(
+    struct_name: &'static str,
+    feature: &'static str,
+    requires: &'static str,
+) -> SpirvEnable {
+    SpirvEnable::Feature {
+        struct_name,
+        feature,
+        requires,
+    }
+}
+
+const fn property(
+    struct_name: &'static str,
+    member: &'static str,
+    value: &'static str,
+    requires: &'static str,
+) -> SpirvEnable {
+    SpirvEnable::Property {
+        struct_name,
+        member,
+        value,
+        requires,
+    }
+}
+
+use SpirvEnable::{Extension as Ext, Version};
+"#;
+
+/// Returns the capability ids by name, including the aliases, from `spirv.core.grammar.json`.
+pub(crate) fn capability_ids(grammar: &str) -> Result<HashMap<String, u32>, String> {
+    let grammar: serde_json::Value =
+        serde_json::from_str(grammar).map_err(|err| format!("invalid SPIR-V grammar: {}", err))?;
+    let enumerants = grammar["operand_kinds"]
+        .as_array()
+        .and_then(|kinds| kinds.iter().find(|kind| kind["kind"] == "Capability"))
+        .and_then(|kind| kind["enumerants"].as_array())
+        .ok_or("the SPIR-V grammar has no Capability operand kind")?;
+
+    let mut ids = HashMap::new();
+    for enumerant in enumerants {
+        let (Some(name), Some(id)) = (enumerant["enumerant"].as_str(), enumerant["value"].as_u64())
+        else {
+            return Err(format!("invalid capability enumerant {}", enumerant));
+        };
+        let aliases = enumerant["aliases"].as_array().into_iter().flatten();
+        for name in std::iter::once(name).chain(aliases.filter_map(|alias| alias.as_str())) {
+            ids.insert(name.to_string(), id as u32);
+        }
+    }
+    Ok(ids)
+}
+
+/// The core versions and extensions which provide each struct, in registry order.
+struct Providers<'a> {
+    aliases: HashMap<&'a str, &'a str>,
+    providers: HashMap<&'a str, Vec<&'a str>>,
+}
+
+impl<'a> Providers<'a> {
+    fn new(registry: &'a Document) -> Self {
+        let aliases = registry
+            .descendants()
+            .filter(|node| {
+                node.has_tag_name("type") && node.attribute("category") == Some("struct")
+            })
+            .filter_map(|node| Some((node.attribute("name")?, node.attribute("alias")?)))
+            .collect();
+
+        let mut providers = Self {
+            aliases,
+            providers: HashMap::new(),
+        };
+        let features = registry
+            .descendants()
+            .filter(|node| node.has_tag_name("feature") && is_vulkan(*node, "api"));
+        let extensions = registry.descendants().filter(|node| {
+            node.has_tag_name("extension")
+                && node
+                    .parent()
+                    .is_some_and(|parent| parent.has_tag_name("extensions"))
+                && is_vulkan(*node, "supported")
+        });
+        for provider in features.chain(extensions) {
+            let Some(name) = provider.attribute("name") else {
+                continue;
+            };
+            let types = provider
+                .children()
+                .filter(|node| node.has_tag_name("require") && is_vulkan(*node, "api"))
+                .flat_map(|require| require.children())
+                .filter(|node| node.has_tag_name("type"))
+                .filter_map(|node| node.attribute("name"));
+            for ty in types {
+                let list = providers
+                    .providers
+                    .entry(providers.canonical(ty))
+                    .or_default();
+                if !list.contains(&name) {
+                    list.push(name);
+                }
+            }
+        }
+        providers
+    }
+
+    fn canonical(&self, name: &'a str) -> &'a str {
+        self.aliases.get(name).copied().unwrap_or(name)
+    }
+
+    fn requires(&self, struct_name: &str) -> Option<String> {
+        self.providers
+            .get(struct_name)
+            .map(|providers| providers.join(","))
+    }
+}
+
+/// Returns the constant used for a core version or the quoted string.
+fn version_or_string(value: &str, used: &mut BTreeSet<String>) -> String {
+    match value.strip_prefix("VK_VERSION_") {
+        Some(version) if version.bytes().all(|c| c.is_ascii_digit() || c == b'_') => {
+            let name = format!("V{}", version);
+            used.insert(name.clone());
+            name
+        }
+        _ => format!("{:?}", value),
+    }
+}
+
+fn attribute<'a>(node: Node<'a, '_>, name: &str) -> Result<&'a str, String> {
+    node.attribute(name)
+        .ok_or_else(|| format!("enable without a {} attribute", name))
+}
+
+/// Returns the rust expressions of the `<enable>` elements of a capability or extension.
+fn enables(
+    node: Node,
+    providers: &Providers,
+    used: &mut BTreeSet<String>,
+) -> Result<Vec<String>, String> {
+    let mut enables = Vec::new();
+    for enable in node.children().filter(|node| node.has_tag_name("enable")) {
+        if let Some(version) = enable.attribute("version") {
+            enables.push(format!("Version({})", version_or_string(version, used)));
+        } else if let Some(extension) = enable.attribute("extension") {
+            enables.push(format!("Ext({:?})", extension));
+        } else if let Some(struct_name) = enable.attribute("struct") {
+            let feature = attribute(enable, "feature")?;
+            let requires = attribute(enable, "requires")?;
+            let Some(alias) = enable.attribute("alias") else {
+                enables.push(format!(
+                    "feature({:?}, {:?}, {})",
+                    struct_name,
+                    feature,
+                    version_or_string(requires, used)
+                ));
+                continue;
+            };
+
+            // The feature of a core struct like VkPhysicalDeviceVulkan12Features which is also available in
+            // the struct of the promoted extension. The core struct only requires the core version.
+            let versions = requires
+                .split(',')
+                .filter(|provider| provider.starts_with("VK_VERSION_"))
+                .collect::<Vec<_>>()
+                .join(",");
+            if versions.is_empty() {
+                return Err(format!(
+                    "{}.{} has an alias but no core version",
+                    struct_name, feature
+                ));
+            }
+            enables.push(format!(
+                "feature({:?}, {:?}, {})",
+                struct_name,
+                feature,
+                version_or_string(&versions, used)
+            ));
+
+            let (alias_struct, alias_feature) = alias
+                .split_once("::")
+                .ok_or_else(|| format!("invalid feature alias {}", alias))?;
+            let alias_struct = providers.canonical(alias_struct);
+            let alias_requires = providers.requires(alias_struct).ok_or_else(|| {
+                format!(
+                    "{} is not provided by any version or extension",
+                    alias_struct
+                )
+            })?;
+            enables.push(format!(
+                "feature({:?}, {:?}, {})",
+                alias_struct,
+                alias_feature,
+                version_or_string(&alias_requires, used)
+            ));
+        } else if let Some(struct_name) = enable.attribute("property") {
+            enables.push(format!(
+                "property({:?}, {:?}, {:?}, {})",
+                struct_name,
+                attribute(enable, "member")?,
+                attribute(enable, "value")?,
+                version_or_string(attribute(enable, "requires")?, used)
+            ));
+        } else {
+            return Err(format!("unknown enable in {:?}", node.attribute("name")));
+        }
+    }
+    Ok(enables)
+}
+
+fn push_entry(out: &mut String, call: &str, enables: &[String]) {
+    writeln!(out, "    {}&[{}]),", call, enables.join(", ")).unwrap();
+}
+
+pub(crate) fn generate(
+    registry: &Document,
+    version: &str,
+    capability_ids: &HashMap<String, u32>,
+) -> Result<String, String> {
+    let providers = Providers::new(registry);
+    let mut used = BTreeSet::new();
+
+    // Capabilities which share an id are listed under the first name with the enables of all aliases
+    let mut capabilities: Vec<(u32, &str, Vec<String>)> = Vec::new();
+    for node in registry
+        .descendants()
+        .filter(|node| node.has_tag_name("spirvcapability"))
+    {
+        let name = node
+            .attribute("name")
+            .ok_or("spirvcapability without a name")?;
+        let id = *capability_ids
+            .get(name)
+            .ok_or_else(|| format!("SPIR-V capability {} is not in the grammar", name))?;
+        let enables = enables(node, &providers, &mut used)?;
+        match capabilities.iter_mut().find(|(other, _, _)| *other == id) {
+            Some((_, _, existing)) => existing.extend(enables),
+            None => capabilities.push((id, name, enables)),
+        }
+    }
+    capabilities.sort_by_key(|(id, _, _)| *id);
+
+    let mut entries =
+        String::from("pub(super) static SPIRV_CAPABILITIES: &[SpirvCapabilityInfo] = &[\n");
+    for (id, name, enables) in &capabilities {
+        push_entry(
+            &mut entries,
+            &format!("capability({:?}, {}, ", name, id),
+            enables,
+        );
+    }
+    entries.push_str("];\n\npub(super) static SPIRV_EXTENSIONS: &[SpirvExtensionInfo] = &[\n");
+    for node in registry
+        .descendants()
+        .filter(|node| node.has_tag_name("spirvextension"))
+    {
+        let name = node
+            .attribute("name")
+            .ok_or("spirvextension without a name")?;
+        let enables = enables(node, &providers, &mut used)?;
+        push_entry(&mut entries, &format!("extension({:?}, ", name), &enables);
+    }
+    entries.push_str("];\n");
+
+    let mut out = String::new();
+    writeln!(
+        out,
+        "//! The SPIR-V capabilities and extensions allowed in vulkan and what enables them, from the
+//! `spirvcapabilities` and `spirvextensions` sections of the vulkan registry (vk.xml {}).
+//!
+//! Capability ids are taken from the SPIR-V grammar. Capabilities which share an id, for example
+//! `ShaderViewportIndexLayerEXT` and `ShaderViewportIndexLayerNV`, are listed once under the first name with
+//! the enables of all aliases. Features of core structs like `VkPhysicalDeviceVulkan12Features` are listed
+//! for the struct of the promoted extension as well. The `requires` attributes use the syntax of extension
+//! dependencies.
+//!
+//! Generated by `cargo run --manifest-path xtask/Cargo.toml -- <path to vk.xml> <path to grammar>` from
+//! vk.xml and `spirv.core.grammar.json`, do not edit by hand.
+",
+        version
+    )
+    .unwrap();
+    out.push_str(HELPERS);
+    out.push('\n');
+    for name in &used {
+        writeln!(out, "const {}: &str = \"VK_VERSION_{}\";", name, &name[1..]).unwrap();
+    }
+    out.push('\n');
+    out.push_str(&entries);
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_registry;
+    use crate::tests::{GRAMMAR, REGISTRY};
+
+    #[test]
+    fn test_capability_ids() {
+        let ids = capability_ids(GRAMMAR).unwrap();
+        assert_eq!(ids["Float16"], 9);
+        assert_eq!(ids["ShaderViewportIndexLayerEXT"], 5254);
+        assert_eq!(ids["ShaderViewportIndexLayerNV"], 5254);
+    }
+
+    #[test]
+    fn test_generate() {
+        let registry = parse_registry(REGISTRY).unwrap();
+        let ids = capability_ids(GRAMMAR).unwrap();
+        let out = generate(&registry, "1.3.281", &ids).unwrap();
+
+        assert!(out.contains("(vk.xml 1.3.281)"));
+        assert!(out.contains("const V1_0: &str = \"VK_VERSION_1_0\";\nconst V1_1: &str = \"VK_VERSION_1_1\";\nconst V1_2: &str = \"VK_VERSION_1_2\";\n"));
+        assert!(out.contains(
+            "pub(super) static SPIRV_CAPABILITIES: &[SpirvCapabilityInfo] = &[
+    capability(\"Shader\", 1, &[Version(V1_0)]),
+    capability(\"Float16\", 9, &[feature(\"VkPhysicalDeviceVulkan12Features\", \"shaderFloat16\", V1_2), \
+feature(\"VkPhysicalDeviceShaderFloat16Int8Features\", \"shaderFloat16\", \"VK_VERSION_1_2,VK_KHR_shader_float16_int8\"), \
+Ext(\"VK_AMD_gpu_shader_half_float\")]),
+    capability(\"GroupNonUniform\", 61, &[property(\"VkPhysicalDeviceVulkan11Properties\", \"subgroupSupportedOperations\", \
+\"VK_SUBGROUP_FEATURE_BASIC_BIT\", V1_1)]),
+    capability(\"ShaderViewportIndexLayerEXT\", 5254, &[Ext(\"VK_EXT_shader_viewport_index_layer\"), Ext(\"VK_NV_viewport_array2\")]),
+];
+
+pub(super) static SPIRV_EXTENSIONS: &[SpirvExtensionInfo] = &[
+    extension(\"SPV_KHR_multiview\", &[Version(V1_1), Ext(\"VK_KHR_multiview\")]),
+];
+"
+        ));
+    }
+
+    #[test]
+    fn test_unknown_capability() {
+        let registry = parse_registry(REGISTRY).unwrap();
+        let mut ids = capability_ids(GRAMMAR).unwrap();
+        ids.remove("GroupNonUniform");
+        assert_eq!(
+            generate(&registry, "1.3.281", &ids).unwrap_err(),
+            "SPIR-V capability GroupNonUniform is not in the grammar"
+        );
+    }
+}